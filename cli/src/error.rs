@@ -1,9 +1,11 @@
 use std::{fmt::Display, io};
 
 #[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum SamambaiaCliError {
     ArgumentError(String),
     GeneratorError(String),
+    ImportError(String),
 }
 
 impl SamambaiaCliError {
@@ -11,6 +13,7 @@ impl SamambaiaCliError {
         match self {
             SamambaiaCliError::ArgumentError(msg) => msg,
             SamambaiaCliError::GeneratorError(msg) => msg,
+            SamambaiaCliError::ImportError(msg) => msg,
         }
     }
 }
@@ -0,0 +1,3 @@
+mod wordpress;
+
+pub use wordpress::import_wordpress as wordpress;
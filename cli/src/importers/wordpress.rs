@@ -0,0 +1,92 @@
+use std::path::Path;
+
+use migration::{Migrator, MigratorTrait};
+use samambaia::domain::factories::imports::import_wordpress_service_factory;
+use samambaia::domain::services::imports::import_wordpress_service::{
+    ImportReport, ImportTally, ImportWordpressParams,
+};
+use samambaia::infra::imports::wxr_parser::WxrParser;
+use samambaia::infra::sea::sea_service::SeaService;
+use samambaia::tokio::runtime::Runtime;
+
+use crate::error::SamambaiaCliError;
+
+pub fn import_wordpress(args: &[String], current_dir: &Path) -> Result<(), SamambaiaCliError> {
+    let dry_run = args.iter().skip(3).any(|arg| arg == "--dry-run");
+    let file = args.iter().skip(3).find(|arg| !arg.starts_with("--"));
+
+    let file = match file {
+        None => {
+            return Err(SamambaiaCliError::ArgumentError(
+                "No WordPress export file has been specified. Cancelling the operation.".into(),
+            ))
+        }
+        Some(file) => current_dir.join(file),
+    };
+
+    let xml = std::fs::read_to_string(&file).map_err(|err| {
+        SamambaiaCliError::ImportError(format!("Error on reading {}: {}", file.display(), err))
+    })?;
+
+    let export = WxrParser::parse(&xml).map_err(|err| {
+        SamambaiaCliError::ImportError(format!("Error on parsing the WordPress export: {}", err))
+    })?;
+
+    let runtime = Runtime::new().map_err(|err| {
+        SamambaiaCliError::ImportError(format!("Error on starting the async runtime: {}", err))
+    })?;
+
+    let report = runtime.block_on(async {
+        let sea_service = SeaService::new().await.map_err(|err| {
+            SamambaiaCliError::ImportError(format!("Error on connecting to the database: {}", err))
+        })?;
+
+        // the import relies on the table recording what has already been imported
+        Migrator::up(&sea_service.db, None).await.map_err(|err| {
+            SamambaiaCliError::ImportError(format!("Error on applying pending migrations: {}", err))
+        })?;
+
+        import_wordpress_service_factory::exec(&sea_service)
+            .exec(ImportWordpressParams { export, dry_run })
+            .await
+            .map_err(|err| SamambaiaCliError::ImportError(err.to_string()))
+    })?;
+
+    print_report(&report);
+
+    Ok(())
+}
+
+fn print_report(report: &ImportReport) {
+    if report.dry_run {
+        println!("Dry run: nothing has been written to the database.\n");
+    }
+
+    print_tally("Users", &report.users);
+    print_tally("Tags", &report.tags);
+    print_tally("Articles", &report.articles);
+    print_tally("Comments", &report.comments);
+
+    if !report.renamed_users.is_empty() {
+        println!("\nRenamed users:");
+
+        for (name, nickname) in report.renamed_users.iter() {
+            println!("  {name} -> {nickname}");
+        }
+    }
+
+    if !report.warnings.is_empty() {
+        println!("\nWarnings:");
+
+        for warning in report.warnings.iter() {
+            println!("  {warning}");
+        }
+    }
+}
+
+fn print_tally(label: &str, tally: &ImportTally) {
+    println!(
+        "{:<10} {} created, {} already existing, {} skipped",
+        label, tally.created, tally.existing, tally.skipped
+    );
+}
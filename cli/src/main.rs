@@ -5,6 +5,7 @@ use error::{IntoIoError, SamambaiaCliError};
 mod error;
 mod generators;
 mod helpers;
+mod importers;
 mod templates;
 
 static DEFAULT_CONTROLLERS_DIR: &str = "src/infra/http/controllers/";
@@ -50,6 +51,22 @@ fn main() -> io::Result<()> {
         };
     }
 
+    if args[1] == "import" {
+        let importers = ["wordpress"];
+
+        if args.len() < 3 || !importers.contains(&&args[2][..]) {
+            return Err(SamambaiaCliError::ArgumentError(
+                "Not any valid importer has been specified. Cancelling the operation.".into(),
+            )
+            .into_io_err());
+        }
+
+        dotenvy::dotenv().ok();
+        env_logger::Builder::new().parse_default_env().init();
+
+        return importers::wordpress(&args, &current_dir).map_err(IntoIoError::into_io_err);
+    }
+
     Err(
         SamambaiaCliError::ArgumentError(format!("Invalid command provided: {}.", args[1]))
             .into_io_err(),
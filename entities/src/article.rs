@@ -19,10 +19,17 @@ pub struct Model {
     pub slug: String,
     pub tag_id: Option<i32>,
     pub tag_value: Option<String>,
+    pub featured: bool,
+    pub pinned: bool,
+    pub pin_order: i32,
+    pub pinned_until: Option<DateTime>,
+    pub comment_mode: String,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::article_coauthor::Entity")]
+    ArticleCoauthor,
     #[sea_orm(
         belongs_to = "super::article_tag::Entity",
         from = "Column::TagId",
@@ -31,8 +38,12 @@ pub enum Relation {
         on_delete = "SetNull"
     )]
     ArticleTag,
+    #[sea_orm(has_many = "super::article_translation::Entity")]
+    ArticleTranslation,
     #[sea_orm(has_many = "super::comment::Entity")]
     Comment,
+    #[sea_orm(has_many = "super::series_article::Entity")]
+    SeriesArticle,
     #[sea_orm(
         belongs_to = "super::user::Entity",
         from = "Column::AuthorId",
@@ -43,18 +54,36 @@ pub enum Relation {
     User,
 }
 
+impl Related<super::article_coauthor::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ArticleCoauthor.def()
+    }
+}
+
 impl Related<super::article_tag::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::ArticleTag.def()
     }
 }
 
+impl Related<super::article_translation::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ArticleTranslation.def()
+    }
+}
+
 impl Related<super::comment::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Comment.def()
     }
 }
 
+impl Related<super::series_article::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SeriesArticle.def()
+    }
+}
+
 impl Related<super::user::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::User.def()
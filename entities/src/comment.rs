@@ -13,6 +13,8 @@ pub struct Model {
     pub created_at: DateTime,
     pub is_active: bool,
     pub article_id: Option<Uuid>,
+    pub parent_id: Option<Uuid>,
+    pub updated_at: Option<DateTime>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -25,8 +27,22 @@ pub enum Relation {
         on_delete = "NoAction"
     )]
     Article,
+    #[sea_orm(
+        belongs_to = "Entity",
+        from = "Column::ParentId",
+        to = "Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    SelfRef,
+    #[sea_orm(has_many = "super::comment_edit::Entity")]
+    CommentEdit,
+    #[sea_orm(has_many = "super::comment_mention::Entity")]
+    CommentMention,
     #[sea_orm(has_many = "super::comment_report::Entity")]
     CommentReport,
+    #[sea_orm(has_one = "super::comment_review::Entity")]
+    CommentReview,
     #[sea_orm(
         belongs_to = "super::user::Entity",
         from = "Column::AuthorId",
@@ -43,12 +59,30 @@ impl Related<super::article::Entity> for Entity {
     }
 }
 
+impl Related<super::comment_edit::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommentEdit.def()
+    }
+}
+
+impl Related<super::comment_mention::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommentMention.def()
+    }
+}
+
 impl Related<super::comment_report::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::CommentReport.def()
     }
 }
 
+impl Related<super::comment_review::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommentReview.def()
+    }
+}
+
 impl Related<super::user::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::User.def()
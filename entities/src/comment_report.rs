@@ -7,12 +7,19 @@ use sea_orm::entity::prelude::*;
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
-    pub comment_id: Uuid,
+    pub comment_id: Option<Uuid>,
     pub user_id: Uuid,
     #[sea_orm(column_type = "Text")]
     pub message: String,
     pub created_at: DateTime,
     pub solved_by: Option<Uuid>,
+    pub comment_auto_hidden: bool,
+    pub reason: String,
+    pub priority: i16,
+    pub assigned_to: Option<Uuid>,
+    pub resolution: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub resolution_notes: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -22,7 +29,7 @@ pub enum Relation {
         from = "Column::CommentId",
         to = "super::comment::Column::Id",
         on_update = "NoAction",
-        on_delete = "Cascade"
+        on_delete = "SetNull"
     )]
     Comment,
     #[sea_orm(
@@ -33,6 +40,14 @@ pub enum Relation {
         on_delete = "NoAction"
     )]
     User2,
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::AssignedTo",
+        to = "super::user::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    User3,
     #[sea_orm(
         belongs_to = "super::user::Entity",
         from = "Column::UserId",
@@ -4,11 +4,24 @@ pub mod prelude;
 
 pub mod announcement;
 pub mod article;
+pub mod article_coauthor;
 pub mod article_tag;
+pub mod article_translation;
+pub mod blocked_word;
 pub mod comment;
+pub mod comment_edit;
+pub mod comment_mention;
 pub mod comment_report;
+pub mod comment_review;
 pub mod free_badge;
+pub mod imported_content;
+pub mod media;
+pub mod media_tag;
+pub mod reaction;
+pub mod reaction_count;
 pub mod sea_orm_active_enums;
+pub mod series;
+pub mod series_article;
 pub mod team_role;
 pub mod team_user;
 pub mod user;
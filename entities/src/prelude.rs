@@ -2,10 +2,23 @@
 
 pub use super::announcement::Entity as Announcement;
 pub use super::article::Entity as Article;
+pub use super::article_coauthor::Entity as ArticleCoauthor;
 pub use super::article_tag::Entity as ArticleTag;
+pub use super::article_translation::Entity as ArticleTranslation;
+pub use super::blocked_word::Entity as BlockedWord;
 pub use super::comment::Entity as Comment;
+pub use super::comment_edit::Entity as CommentEdit;
+pub use super::comment_mention::Entity as CommentMention;
 pub use super::comment_report::Entity as CommentReport;
+pub use super::comment_review::Entity as CommentReview;
 pub use super::free_badge::Entity as FreeBadge;
+pub use super::imported_content::Entity as ImportedContent;
+pub use super::media::Entity as Media;
+pub use super::media_tag::Entity as MediaTag;
+pub use super::reaction::Entity as Reaction;
+pub use super::reaction_count::Entity as ReactionCount;
+pub use super::series::Entity as Series;
+pub use super::series_article::Entity as SeriesArticle;
 pub use super::team_role::Entity as TeamRole;
 pub use super::team_user::Entity as TeamUser;
 pub use super::user::Entity as User;
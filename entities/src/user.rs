@@ -14,6 +14,11 @@ pub struct Model {
     pub created_at: DateTime,
     pub last_login: Option<DateTime>,
     pub role: Option<Role>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub bio: Option<String>,
+    pub habbo_hotel: Option<String>,
+    pub avatar_direction: i16,
+    pub avatar_head_only: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -22,8 +27,22 @@ pub enum Relation {
     Announcement,
     #[sea_orm(has_many = "super::article::Entity")]
     Article,
+    #[sea_orm(has_many = "super::article_coauthor::Entity")]
+    ArticleCoauthor,
+    #[sea_orm(has_many = "super::blocked_word::Entity")]
+    BlockedWord,
     #[sea_orm(has_many = "super::comment::Entity")]
     Comment,
+    #[sea_orm(has_many = "super::comment_edit::Entity")]
+    CommentEdit,
+    #[sea_orm(has_many = "super::comment_mention::Entity")]
+    CommentMention,
+    #[sea_orm(has_many = "super::media::Entity")]
+    Media,
+    #[sea_orm(has_many = "super::reaction::Entity")]
+    Reaction,
+    #[sea_orm(has_many = "super::series::Entity")]
+    Series,
 }
 
 impl Related<super::announcement::Entity> for Entity {
@@ -38,10 +57,52 @@ impl Related<super::article::Entity> for Entity {
     }
 }
 
+impl Related<super::article_coauthor::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ArticleCoauthor.def()
+    }
+}
+
+impl Related<super::blocked_word::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BlockedWord.def()
+    }
+}
+
 impl Related<super::comment::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Comment.def()
     }
 }
 
+impl Related<super::comment_edit::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommentEdit.def()
+    }
+}
+
+impl Related<super::comment_mention::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommentMention.def()
+    }
+}
+
+impl Related<super::media::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Media.def()
+    }
+}
+
+impl Related<super::reaction::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Reaction.def()
+    }
+}
+
+impl Related<super::series::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Series.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -26,6 +26,23 @@ mod m20240725_022019_create_free_badges_table;
 mod m20240726_175757_rename_user_role_enum_writter_property_to_writer;
 mod m20241124_033241_add_adsense_table;
 mod m20241124_154522_add_extra_fields_to_announcement_table;
+mod m20261019_140512_add_pinning_fields_to_article;
+mod m20261019_152247_create_series_tables;
+mod m20261019_171930_create_article_coauthor_table;
+mod m20261019_184510_create_media_tables;
+mod m20261019_201305_create_imported_content_table;
+mod m20261019_223140_create_article_translation_table;
+mod m20261019_235012_add_parent_id_to_comment;
+mod m20261020_003218_create_comment_edit_table;
+mod m20261020_011045_create_comment_mention_table;
+mod m20261020_024507_add_author_created_at_index_to_comment;
+mod m20261020_041530_create_blocked_word_table;
+mod m20261020_053310_create_comment_review_table;
+mod m20261020_070214_add_comment_auto_hidden_to_comment_report;
+mod m20261020_084127_create_reaction_tables;
+mod m20261020_093518_add_comment_mode_to_article;
+mod m20261020_110204_add_profile_fields_to_user;
+mod m20261020_124415_add_moderation_fields_to_comment_report;
 
 pub struct Migrator;
 
@@ -63,6 +80,23 @@ impl MigratorTrait for Migrator {
             Box::new(m20240726_175757_rename_user_role_enum_writter_property_to_writer::Migration),
             Box::new(m20241124_033241_add_adsense_table::Migration),
             Box::new(m20241124_154522_add_extra_fields_to_announcement_table::Migration),
+            Box::new(m20261019_140512_add_pinning_fields_to_article::Migration),
+            Box::new(m20261019_152247_create_series_tables::Migration),
+            Box::new(m20261019_171930_create_article_coauthor_table::Migration),
+            Box::new(m20261019_184510_create_media_tables::Migration),
+            Box::new(m20261019_201305_create_imported_content_table::Migration),
+            Box::new(m20261019_223140_create_article_translation_table::Migration),
+            Box::new(m20261019_235012_add_parent_id_to_comment::Migration),
+            Box::new(m20261020_003218_create_comment_edit_table::Migration),
+            Box::new(m20261020_011045_create_comment_mention_table::Migration),
+            Box::new(m20261020_024507_add_author_created_at_index_to_comment::Migration),
+            Box::new(m20261020_041530_create_blocked_word_table::Migration),
+            Box::new(m20261020_053310_create_comment_review_table::Migration),
+            Box::new(m20261020_070214_add_comment_auto_hidden_to_comment_report::Migration),
+            Box::new(m20261020_084127_create_reaction_tables::Migration),
+            Box::new(m20261020_093518_add_comment_mode_to_article::Migration),
+            Box::new(m20261020_110204_add_profile_fields_to_user::Migration),
+            Box::new(m20261020_124415_add_moderation_fields_to_comment_report::Migration),
         ]
     }
 }
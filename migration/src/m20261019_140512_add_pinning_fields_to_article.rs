@@ -0,0 +1,44 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Article::Table)
+                    .add_column(boolean(Article::Featured).default(false))
+                    .add_column(boolean(Article::Pinned).default(false))
+                    .add_column(integer(Article::PinOrder).default(0))
+                    .add_column(date_time_null(Article::PinnedUntil))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Article::Table)
+                    .drop_column(Article::Featured)
+                    .drop_column(Article::Pinned)
+                    .drop_column(Article::PinOrder)
+                    .drop_column(Article::PinnedUntil)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Article {
+    Table,
+    Featured,
+    Pinned,
+    PinOrder,
+    PinnedUntil,
+}
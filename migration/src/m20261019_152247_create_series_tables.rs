@@ -0,0 +1,108 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Series::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(Series::Id).not_null())
+                    .col(uuid(Series::AuthorId))
+                    .col(string(Series::Title))
+                    .col(text(Series::Description))
+                    .col(string_uniq(Series::Slug))
+                    .col(date_time(Series::CreatedAt))
+                    .col(date_time_null(Series::UpdatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-series-author-id")
+                            .from(Series::Table, Series::AuthorId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::NoAction)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(SeriesArticle::Table)
+                    .if_not_exists()
+                    .col(uuid(SeriesArticle::SeriesId))
+                    .col(uuid_uniq(SeriesArticle::ArticleId))
+                    .col(integer(SeriesArticle::Position))
+                    .primary_key(
+                        Index::create()
+                            .col(SeriesArticle::SeriesId)
+                            .col(SeriesArticle::ArticleId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-series-article-series-id")
+                            .from(SeriesArticle::Table, SeriesArticle::SeriesId)
+                            .to(Series::Table, Series::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-series-article-article-id")
+                            .from(SeriesArticle::Table, SeriesArticle::ArticleId)
+                            .to(Article::Table, Article::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SeriesArticle::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Series::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Article {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Series {
+    Table,
+    Id,
+    AuthorId,
+    Title,
+    Description,
+    Slug,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum SeriesArticle {
+    Table,
+    SeriesId,
+    ArticleId,
+    Position,
+}
@@ -0,0 +1,70 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ArticleCoauthor::Table)
+                    .if_not_exists()
+                    .col(uuid(ArticleCoauthor::ArticleId))
+                    .col(uuid(ArticleCoauthor::UserId))
+                    .col(string(ArticleCoauthor::Contribution))
+                    .col(integer(ArticleCoauthor::Position))
+                    .primary_key(
+                        Index::create()
+                            .col(ArticleCoauthor::ArticleId)
+                            .col(ArticleCoauthor::UserId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-article-coauthor-article-id")
+                            .from(ArticleCoauthor::Table, ArticleCoauthor::ArticleId)
+                            .to(Article::Table, Article::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-article-coauthor-user-id")
+                            .from(ArticleCoauthor::Table, ArticleCoauthor::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ArticleCoauthor::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Article {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum ArticleCoauthor {
+    Table,
+    ArticleId,
+    UserId,
+    Contribution,
+    Position,
+}
@@ -0,0 +1,106 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Media::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(Media::Id).not_null())
+                    .col(uuid(Media::UploaderId))
+                    .col(string_uniq(Media::StorageKey))
+                    .col(string(Media::FileName))
+                    .col(string(Media::MimeType))
+                    .col(big_integer(Media::Size))
+                    .col(integer(Media::Width))
+                    .col(integer(Media::Height))
+                    .col(string(Media::AltText))
+                    .col(date_time(Media::CreatedAt))
+                    .col(date_time_null(Media::UpdatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-media-uploader-id")
+                            .from(Media::Table, Media::UploaderId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::NoAction)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(MediaTag::Table)
+                    .if_not_exists()
+                    .col(uuid(MediaTag::MediaId))
+                    .col(string(MediaTag::Tag))
+                    .primary_key(Index::create().col(MediaTag::MediaId).col(MediaTag::Tag))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-media-tag-media-id")
+                            .from(MediaTag::Table, MediaTag::MediaId)
+                            .to(Media::Table, Media::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-media-tag-tag")
+                    .table(MediaTag::Table)
+                    .col(MediaTag::Tag)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(MediaTag::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Media::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Media {
+    Table,
+    Id,
+    UploaderId,
+    StorageKey,
+    FileName,
+    MimeType,
+    Size,
+    Width,
+    Height,
+    AltText,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum MediaTag {
+    Table,
+    MediaId,
+    Tag,
+}
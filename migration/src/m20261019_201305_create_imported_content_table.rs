@@ -0,0 +1,45 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ImportedContent::Table)
+                    .if_not_exists()
+                    .col(string(ImportedContent::Source))
+                    .col(string(ImportedContent::Kind))
+                    .col(string(ImportedContent::ExternalId))
+                    .col(string(ImportedContent::InternalId))
+                    .col(date_time(ImportedContent::ImportedAt))
+                    .primary_key(
+                        Index::create()
+                            .col(ImportedContent::Source)
+                            .col(ImportedContent::Kind)
+                            .col(ImportedContent::ExternalId),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ImportedContent::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ImportedContent {
+    Table,
+    Source,
+    Kind,
+    ExternalId,
+    InternalId,
+    ImportedAt,
+}
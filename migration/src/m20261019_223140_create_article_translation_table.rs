@@ -0,0 +1,72 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ArticleTranslation::Table)
+                    .if_not_exists()
+                    .col(uuid(ArticleTranslation::ArticleId))
+                    .col(string(ArticleTranslation::Locale))
+                    .col(string(ArticleTranslation::Title))
+                    .col(text(ArticleTranslation::Content))
+                    .col(string(ArticleTranslation::Slug))
+                    .col(date_time(ArticleTranslation::CreatedAt))
+                    .col(date_time_null(ArticleTranslation::UpdatedAt))
+                    .primary_key(
+                        Index::create()
+                            .col(ArticleTranslation::ArticleId)
+                            .col(ArticleTranslation::Locale),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-article-translation-article-id")
+                            .from(ArticleTranslation::Table, ArticleTranslation::ArticleId)
+                            .to(Article::Table, Article::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-article-translation-slug")
+                    .table(ArticleTranslation::Table)
+                    .col(ArticleTranslation::Slug)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ArticleTranslation::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Article {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum ArticleTranslation {
+    Table,
+    ArticleId,
+    Locale,
+    Title,
+    Content,
+    Slug,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,96 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comment::Table)
+                    .add_column(date_time_null(Comment::UpdatedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommentEdit::Table)
+                    .if_not_exists()
+                    .col(uuid(CommentEdit::Id).primary_key())
+                    .col(uuid(CommentEdit::CommentId))
+                    .col(uuid(CommentEdit::EditorId))
+                    .col(text(CommentEdit::PreviousContent))
+                    .col(date_time(CommentEdit::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-comment-edit-comment-id")
+                            .from(CommentEdit::Table, CommentEdit::CommentId)
+                            .to(Comment::Table, Comment::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-comment-edit-editor-id")
+                            .from(CommentEdit::Table, CommentEdit::EditorId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-comment-edit-comment-id")
+                    .table(CommentEdit::Table)
+                    .col(CommentEdit::CommentId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CommentEdit::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Comment::Table)
+                    .drop_column(Comment::UpdatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CommentEdit {
+    Table,
+    Id,
+    CommentId,
+    EditorId,
+    PreviousContent,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Comment {
+    Table,
+    Id,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
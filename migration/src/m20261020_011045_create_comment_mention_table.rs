@@ -0,0 +1,82 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommentMention::Table)
+                    .if_not_exists()
+                    .col(uuid(CommentMention::CommentId))
+                    .col(uuid(CommentMention::UserId))
+                    .col(integer(CommentMention::SpanStart))
+                    .col(integer(CommentMention::SpanEnd))
+                    .col(date_time(CommentMention::CreatedAt))
+                    .primary_key(
+                        Index::create()
+                            .col(CommentMention::CommentId)
+                            .col(CommentMention::SpanStart),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-comment-mention-comment-id")
+                            .from(CommentMention::Table, CommentMention::CommentId)
+                            .to(Comment::Table, Comment::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-comment-mention-user-id")
+                            .from(CommentMention::Table, CommentMention::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-comment-mention-user-id")
+                    .table(CommentMention::Table)
+                    .col(CommentMention::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CommentMention::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CommentMention {
+    Table,
+    CommentId,
+    UserId,
+    SpanStart,
+    SpanEnd,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Comment {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
@@ -0,0 +1,57 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BlockedWord::Table)
+                    .if_not_exists()
+                    .col(uuid(BlockedWord::Id).primary_key())
+                    .col(string(BlockedWord::Pattern))
+                    .col(boolean(BlockedWord::IsRegex).default(false))
+                    .col(string(BlockedWord::Action))
+                    .col(uuid(BlockedWord::AuthorId))
+                    .col(date_time(BlockedWord::CreatedAt))
+                    .col(date_time_null(BlockedWord::UpdatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-blocked-word-author-id")
+                            .from(BlockedWord::Table, BlockedWord::AuthorId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::NoAction)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BlockedWord::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum BlockedWord {
+    Table,
+    Id,
+    Pattern,
+    IsRegex,
+    Action,
+    AuthorId,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
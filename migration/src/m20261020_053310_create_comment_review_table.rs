@@ -0,0 +1,87 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommentReview::Table)
+                    .if_not_exists()
+                    .col(uuid(CommentReview::CommentId).primary_key())
+                    .col(uuid(CommentReview::AuthorId))
+                    .col(date_time(CommentReview::CreatedAt))
+                    .col(uuid_null(CommentReview::ReviewedBy))
+                    .col(date_time_null(CommentReview::ReviewedAt))
+                    .col(boolean_null(CommentReview::Approved))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-comment-review-comment-id")
+                            .from(CommentReview::Table, CommentReview::CommentId)
+                            .to(Comment::Table, Comment::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-comment-review-author-id")
+                            .from(CommentReview::Table, CommentReview::AuthorId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-comment-review-reviewed-by")
+                            .from(CommentReview::Table, CommentReview::ReviewedBy)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-comment-review-author-id")
+                    .table(CommentReview::Table)
+                    .col(CommentReview::AuthorId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CommentReview::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CommentReview {
+    Table,
+    CommentId,
+    AuthorId,
+    CreatedAt,
+    ReviewedBy,
+    ReviewedAt,
+    Approved,
+}
+
+#[derive(DeriveIden)]
+enum Comment {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
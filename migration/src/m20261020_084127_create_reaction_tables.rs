@@ -0,0 +1,91 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Reaction::Table)
+                    .if_not_exists()
+                    .col(uuid(Reaction::UserId))
+                    .col(string(Reaction::Target))
+                    .col(uuid(Reaction::TargetId))
+                    .col(string(Reaction::Kind))
+                    .col(date_time(Reaction::CreatedAt))
+                    .primary_key(
+                        Index::create()
+                            .col(Reaction::UserId)
+                            .col(Reaction::Target)
+                            .col(Reaction::TargetId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-reaction-user-id")
+                            .from(Reaction::Table, Reaction::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ReactionCount::Table)
+                    .if_not_exists()
+                    .col(string(ReactionCount::Target))
+                    .col(uuid(ReactionCount::TargetId))
+                    .col(string(ReactionCount::Kind))
+                    .col(big_integer(ReactionCount::Amount).default(0))
+                    .primary_key(
+                        Index::create()
+                            .col(ReactionCount::Target)
+                            .col(ReactionCount::TargetId)
+                            .col(ReactionCount::Kind),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ReactionCount::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Reaction::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Reaction {
+    Table,
+    UserId,
+    Target,
+    TargetId,
+    Kind,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum ReactionCount {
+    Table,
+    Target,
+    TargetId,
+    Kind,
+    Amount,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
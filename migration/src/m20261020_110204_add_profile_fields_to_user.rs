@@ -0,0 +1,44 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .add_column(text_null(User::Bio))
+                    .add_column(string_null(User::HabboHotel))
+                    .add_column(small_integer(User::AvatarDirection).default(2))
+                    .add_column(boolean(User::AvatarHeadOnly).default(false))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .drop_column(User::Bio)
+                    .drop_column(User::HabboHotel)
+                    .drop_column(User::AvatarDirection)
+                    .drop_column(User::AvatarHeadOnly)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Bio,
+    HabboHotel,
+    AvatarDirection,
+    AvatarHeadOnly,
+}
@@ -0,0 +1,182 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CommentReport::Table)
+                    .add_column(string(CommentReport::Reason).default("other"))
+                    .add_column(small_integer(CommentReport::Priority).default(1))
+                    .add_column(uuid_null(CommentReport::AssignedTo))
+                    .add_column(string_null(CommentReport::Resolution))
+                    .add_column(text_null(CommentReport::ResolutionNotes))
+                    .to_owned(),
+            )
+            .await?;
+
+        // reports of comments hidden by reports rank higher, see `CommentReportPolitics::priority`
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(CommentReport::Table)
+                    .value(CommentReport::Priority, 2)
+                    .and_where(Expr::col(CommentReport::CommentAutoHidden).eq(true))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk-comment-report-assigned-to")
+                    .from(CommentReport::Table, CommentReport::AssignedTo)
+                    .to(User::Table, User::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            )
+            .await?;
+
+        // reports outlive their comment when moderators delete it, so what was done about them is kept
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .table(CommentReport::Table)
+                    .name("fk-comment-id")
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CommentReport::Table)
+                    .modify_column(uuid_null(CommentReport::CommentId))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk-comment-id")
+                    .from(CommentReport::Table, CommentReport::CommentId)
+                    .to(Comment::Table, Comment::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-comment-report-priority")
+                    .table(CommentReport::Table)
+                    .col(CommentReport::Priority)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-comment-report-priority")
+                    .table(CommentReport::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .table(CommentReport::Table)
+                    .name("fk-comment-id")
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .exec_stmt(
+                Query::delete()
+                    .from_table(CommentReport::Table)
+                    .and_where(Expr::col(CommentReport::CommentId).is_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CommentReport::Table)
+                    .modify_column(uuid(CommentReport::CommentId))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk-comment-id")
+                    .from(CommentReport::Table, CommentReport::CommentId)
+                    .to(Comment::Table, Comment::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::NoAction)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .table(CommentReport::Table)
+                    .name("fk-comment-report-assigned-to")
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CommentReport::Table)
+                    .drop_column(CommentReport::Reason)
+                    .drop_column(CommentReport::Priority)
+                    .drop_column(CommentReport::AssignedTo)
+                    .drop_column(CommentReport::Resolution)
+                    .drop_column(CommentReport::ResolutionNotes)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CommentReport {
+    Table,
+    CommentId,
+    CommentAutoHidden,
+    Reason,
+    Priority,
+    AssignedTo,
+    Resolution,
+    ResolutionNotes,
+}
+
+#[derive(DeriveIden)]
+enum Comment {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
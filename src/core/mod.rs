@@ -1,4 +1,5 @@
 mod nickname_regex;
 
 pub mod pagination;
-pub use nickname_regex::NICKNAME_REGX;
+pub mod query;
+pub use nickname_regex::{MENTION_REGX, NICKNAME_REGX};
@@ -2,5 +2,13 @@ use std::sync::LazyLock;
 
 use regex::Regex;
 
+/// The characters a nickname can be made of.
+const NICKNAME_CHARS: &str = r"a-zA-Z0-9\_\-\=\?\!\@\:\;\.\,";
+
 pub static NICKNAME_REGX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^[a-zA-Z0-9\_\-\=\?\!\@\:\;\.\,]+$").unwrap());
+    LazyLock::new(|| Regex::new(&format!(r"^[{NICKNAME_CHARS}]+$")).unwrap());
+
+/// Matches "@nickname" at the start of a text or after a whitespace, so that e-mails aren't taken as mentions. The
+/// nickname is captured by the `nickname` group.
+pub static MENTION_REGX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"(?:^|\s)@(?<nickname>[{NICKNAME_CHARS}]+)")).unwrap());
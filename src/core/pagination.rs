@@ -1,3 +1,11 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::NaiveDateTime as DateTime;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::DomainError;
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct PaginationResponse {
     pub current_page: u32,
@@ -23,3 +31,202 @@ pub struct PaginationParameters<QT: Clone> {
 }
 
 pub const DEFAULT_PER_PAGE: u8 = 9;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CursorDirection {
+    /// Towards older items.
+    Next,
+    /// Towards newer items.
+    Prev,
+}
+
+/// Points to where a page of items, ordered from the most recent to the oldest, starts: right after (or, when going
+/// backwards, right before) the item with the given creation date and id.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cursor<ID> {
+    pub created_at: DateTime,
+    pub id: ID,
+    pub direction: CursorDirection,
+}
+
+impl<ID: Display + FromStr> Cursor<ID> {
+    /// Clients must not rely on the format of the encoded cursor, which might change at any time.
+    pub fn encode(&self) -> String {
+        let direction = match self.direction {
+            CursorDirection::Next => "n",
+            CursorDirection::Prev => "p",
+        };
+
+        URL_SAFE_NO_PAD.encode(format!(
+            "{direction}.{}.{}",
+            self.created_at.and_utc().timestamp_micros(),
+            self.id
+        ))
+    }
+
+    pub fn decode(value: &str) -> Result<Self, DomainError> {
+        let invalid_cursor = || DomainError::bad_request_err().with_message("Invalid cursor.");
+
+        let decoded = URL_SAFE_NO_PAD
+            .decode(value)
+            .ok()
+            .and_then(|decoded| String::from_utf8(decoded).ok())
+            .ok_or_else(invalid_cursor)?;
+
+        let mut parts = decoded.splitn(3, '.');
+
+        let direction = match parts.next() {
+            Some("n") => CursorDirection::Next,
+            Some("p") => CursorDirection::Prev,
+            _ => return Err(invalid_cursor()),
+        };
+
+        let created_at = parts
+            .next()
+            .and_then(|micros| micros.parse::<i64>().ok())
+            .and_then(chrono::DateTime::from_timestamp_micros)
+            .ok_or_else(invalid_cursor)?
+            .naive_utc();
+
+        let id = parts
+            .next()
+            .and_then(|id| id.parse::<ID>().ok())
+            .ok_or_else(invalid_cursor)?;
+
+        Ok(Cursor {
+            created_at,
+            id,
+            direction,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct CursorParameters<QT: Clone, ID> {
+    /// The first page is fetched when there's no cursor.
+    pub cursor: Option<Cursor<ID>>,
+    pub items_per_page: u32,
+    /// Items must match all of them.
+    pub filters: Vec<QT>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct CursorPaginationResponse<ID> {
+    pub next_cursor: Option<Cursor<ID>>,
+    pub prev_cursor: Option<Cursor<ID>>,
+}
+
+#[derive(Debug)]
+pub struct CursorPage<T, ID> {
+    pub data: Vec<T>,
+    pub pagination: CursorPaginationResponse<ID>,
+}
+
+impl<T, ID: Clone> CursorPage<T, ID> {
+    /// Builds a page out of the items following the cursor in its direction, which must be fetched with one extra item
+    /// (`items_per_page + 1`) so that it's known whether there's any further page.
+    pub fn from_window(
+        mut items: Vec<T>,
+        cursor: Option<&Cursor<ID>>,
+        items_per_page: u32,
+        key: impl Fn(&T) -> (DateTime, ID),
+    ) -> Self {
+        let has_more = items.len() > items_per_page as usize;
+        items.truncate(items_per_page as usize);
+
+        let direction = cursor.map_or(CursorDirection::Next, |cursor| cursor.direction);
+
+        if direction == CursorDirection::Prev {
+            items.reverse();
+        }
+
+        let cursor_to = |item: Option<&T>, direction: CursorDirection| {
+            // an empty page still lets the client head back where it came from
+            let (created_at, id) = match (item, cursor) {
+                (Some(item), _) => key(item),
+                (None, Some(cursor)) => (cursor.created_at, cursor.id.clone()),
+                (None, None) => return None,
+            };
+
+            Some(Cursor {
+                created_at,
+                id,
+                direction,
+            })
+        };
+
+        let (has_next, has_prev) = match direction {
+            CursorDirection::Next => (has_more, cursor.is_some()),
+            CursorDirection::Prev => (true, has_more),
+        };
+
+        let pagination = CursorPaginationResponse {
+            next_cursor: has_next
+                .then(|| cursor_to(items.last(), CursorDirection::Next))
+                .flatten(),
+            prev_cursor: has_prev
+                .then(|| cursor_to(items.first(), CursorDirection::Prev))
+                .flatten(),
+        };
+
+        CursorPage {
+            data: items,
+            pagination,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test() {
+        let cursor = Cursor {
+            created_at: chrono::NaiveDate::from_ymd_opt(2024, 5, 1)
+                .unwrap()
+                .and_hms_micro_opt(10, 30, 0, 123_456)
+                .unwrap(),
+            id: Uuid::new_v4(),
+            direction: CursorDirection::Prev,
+        };
+
+        assert_eq!(Cursor::decode(&cursor.encode()).unwrap(), cursor);
+        assert!(Cursor::<i32>::decode(&cursor.encode()).is_err());
+        assert!(Cursor::<Uuid>::decode("not a cursor").is_err());
+
+        let day = |day: u32| {
+            chrono::NaiveDate::from_ymd_opt(2024, 1, day)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+        };
+        let key = |item: &i32| (day(*item as u32), *item);
+
+        // the first page, fetched with an extra item
+        let page = CursorPage::from_window(vec![5, 4, 3], None, 2, key);
+
+        assert_eq!(page.data, vec![5, 4]);
+        assert_eq!(page.pagination.prev_cursor, None);
+
+        let next = page.pagination.next_cursor.unwrap();
+        assert_eq!((next.id, next.direction), (4, CursorDirection::Next));
+
+        // the last page
+        let page = CursorPage::from_window(vec![3], Some(&next), 2, key);
+
+        assert_eq!(page.data, vec![3]);
+        assert_eq!(page.pagination.next_cursor, None);
+
+        let prev = page.pagination.prev_cursor.unwrap();
+        assert_eq!((prev.id, prev.direction), (3, CursorDirection::Prev));
+
+        // going backwards, items are fetched from the oldest to the most recent
+        let page = CursorPage::from_window(vec![4, 5], Some(&prev), 2, key);
+
+        assert_eq!(page.data, vec![5, 4]);
+        assert_eq!(page.pagination.prev_cursor, None);
+        assert_eq!(page.pagination.next_cursor.unwrap().id, 4);
+    }
+}
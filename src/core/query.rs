@@ -0,0 +1,223 @@
+use chrono::NaiveDateTime as DateTime;
+use std::cmp::Ordering;
+
+use crate::error::DomainError;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortDirection {
+    Asc,
+    #[default]
+    Desc,
+}
+
+impl SortDirection {
+    /// Reverses an ascending ordering when sorting in descending direction.
+    pub fn apply(&self, ordering: Ordering) -> Ordering {
+        match self {
+            SortDirection::Asc => ordering,
+            SortDirection::Desc => ordering.reverse(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sort<SF> {
+    pub field: SF,
+    pub direction: SortDirection,
+}
+
+/// An inclusive range of dates, which might be open on either end.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DateRange {
+    pub from: Option<DateTime>,
+    pub to: Option<DateTime>,
+}
+
+impl DateRange {
+    pub fn new(from: Option<DateTime>, to: Option<DateTime>) -> Result<Self, DomainError> {
+        if let (Some(from), Some(to)) = (from, to) {
+            if from > to {
+                return Err(DomainError::bad_request_err()
+                    .with_message("The start of a date range can't come after its end."));
+            }
+        }
+
+        Ok(DateRange { from, to })
+    }
+
+    pub fn is_unbounded(&self) -> bool {
+        self.from.is_none() && self.to.is_none()
+    }
+
+    pub fn contains(&self, date: &DateTime) -> bool {
+        self.from.is_none_or(|from| date >= &from) && self.to.is_none_or(|to| date <= &to)
+    }
+}
+
+/// The criteria of a listing: items must match every filter, and are ordered by each sort in turn.
+/// Repositories fall back to their default order (usually the most recent first) when there's no sort.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QuerySpec<F, SF> {
+    pub filters: Vec<F>,
+    pub sort: Vec<Sort<SF>>,
+}
+
+impl<F, SF> Default for QuerySpec<F, SF> {
+    fn default() -> Self {
+        QuerySpec {
+            filters: vec![],
+            sort: vec![],
+        }
+    }
+}
+
+impl<F, SF> QuerySpec<F, SF> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn filter(mut self, filter: F) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    pub fn filter_if(self, filter: Option<F>) -> Self {
+        match filter {
+            Some(filter) => self.filter(filter),
+            None => self,
+        }
+    }
+
+    pub fn sort_by(mut self, field: SF, direction: SortDirection) -> Self {
+        self.sort.push(Sort { field, direction });
+        self
+    }
+
+    /// Compares two items by each sort in turn, for repositories that sort in memory.
+    pub fn compare<T>(
+        &self,
+        a: &T,
+        b: &T,
+        compare_by: impl Fn(&T, &T, &SF) -> Ordering,
+    ) -> Ordering {
+        self.sort.iter().fold(Ordering::Equal, |ordering, sort| {
+            ordering.then_with(|| sort.direction.apply(compare_by(a, b, &sort.field)))
+        })
+    }
+}
+
+/// How many fields a listing can be sorted by at once.
+pub const MAX_SORT_FIELDS: usize = 3;
+
+/// Fields a listing can be sorted by, as they're named on the API.
+pub trait SortField: Sized + Clone + 'static {
+    const ALLOWED: &'static [(&'static str, Self)];
+
+    /// Parses a comma-separated list of fields, each one prefixed with `-` to be sorted in descending direction
+    /// (e.g. `-createdAt,title`).
+    fn parse_expression(expression: &str) -> Result<Vec<Sort<Self>>, DomainError> {
+        let sort = expression
+            .split(',')
+            .map(str::trim)
+            .filter(|field| !field.is_empty())
+            .map(|field| match field.strip_prefix('-') {
+                Some(field) => Self::parse(field).map(|field| Sort {
+                    field,
+                    direction: SortDirection::Desc,
+                }),
+                None => Self::parse(field).map(|field| Sort {
+                    field,
+                    direction: SortDirection::Asc,
+                }),
+            })
+            .collect::<Result<Vec<Sort<Self>>, DomainError>>()?;
+
+        if sort.len() > MAX_SORT_FIELDS {
+            return Err(DomainError::bad_request_err().with_message(format!(
+                "Can't sort by more than {MAX_SORT_FIELDS} fields at once."
+            )));
+        }
+
+        Ok(sort)
+    }
+
+    fn parse(field: &str) -> Result<Self, DomainError> {
+        Self::ALLOWED
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(field))
+            .map(|(_, field)| field.clone())
+            .ok_or_else(|| {
+                let allowed = Self::ALLOWED
+                    .iter()
+                    .map(|(name, _)| *name)
+                    .collect::<Vec<&str>>()
+                    .join(", ");
+
+                DomainError::bad_request_err().with_message(format!(
+                    "Can't sort by \"{field}\". Allowed fields: {allowed}."
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    enum Field {
+        Name,
+        Age,
+    }
+
+    impl SortField for Field {
+        const ALLOWED: &'static [(&'static str, Self)] =
+            &[("name", Field::Name), ("age", Field::Age)];
+    }
+
+    #[test]
+    fn test() {
+        let people = [("b", 20), ("a", 30), ("c", 20)];
+        let spec: QuerySpec<(), Field> = QuerySpec::new()
+            .sort_by(Field::parse("AGE").unwrap(), SortDirection::Asc)
+            .sort_by(Field::Name, SortDirection::Desc);
+
+        let mut sorted = people.to_vec();
+        sorted.sort_by(|a, b| {
+            spec.compare(a, b, |a, b, field| match field {
+                Field::Name => a.0.cmp(b.0),
+                Field::Age => a.1.cmp(&b.1),
+            })
+        });
+
+        assert_eq!(sorted, vec![("c", 20), ("b", 20), ("a", 30)]);
+        assert!(Field::parse("height").is_err());
+        assert_eq!(
+            Field::parse_expression("-age, name").unwrap(),
+            vec![
+                Sort {
+                    field: Field::Age,
+                    direction: SortDirection::Desc
+                },
+                Sort {
+                    field: Field::Name,
+                    direction: SortDirection::Asc
+                }
+            ]
+        );
+        assert!(Field::parse_expression("name,age,name,age").is_err());
+
+        let day = |day: u32| {
+            chrono::NaiveDate::from_ymd_opt(2024, 1, day)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+        };
+        let range = DateRange::new(Some(day(2)), Some(day(4))).unwrap();
+
+        assert!(range.contains(&day(2)) && range.contains(&day(4)));
+        assert!(!range.contains(&day(1)) && !range.contains(&day(5)));
+        assert!(DateRange::new(Some(day(4)), Some(day(2))).is_err());
+        assert!(DateRange::default().contains(&day(1)));
+    }
+}
@@ -0,0 +1 @@
+pub mod related_articles_cache;
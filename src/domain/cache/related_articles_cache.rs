@@ -0,0 +1,14 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article::Article;
+
+pub trait RelatedArticlesCacheTrait {
+    /// Gets the cached ranking of articles related to the article of the given id, if any.
+    fn get(&self, article_id: Uuid) -> Option<Vec<Article>>;
+
+    fn set(&self, article_id: Uuid, related_articles: Vec<Article>);
+
+    /// Drops every cached ranking. Must be called whenever an article is created, edited or deleted,
+    /// as it might affect the ranking of every other article.
+    fn invalidate(&self);
+}
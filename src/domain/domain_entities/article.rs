@@ -1,10 +1,64 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
 use chrono::NaiveDateTime as DateTime;
 use uuid::Uuid;
 
+use crate::error::DomainError;
 use crate::libs::time::TimeHelper;
 
+use super::article_translation::ArticleTranslation;
 use super::slug::Slug;
 
+/// Who can comment on an article.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CommentMode {
+    #[default]
+    Open,
+    /// Only team members (Writers and above) can comment.
+    StaffOnly,
+    /// Nobody can comment, but the existing comments are still listed.
+    Closed,
+}
+
+impl CommentMode {
+    pub const ALL: [CommentMode; 3] = [
+        CommentMode::Open,
+        CommentMode::StaffOnly,
+        CommentMode::Closed,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            CommentMode::Open => "open",
+            CommentMode::StaffOnly => "staff_only",
+            CommentMode::Closed => "closed",
+        }
+    }
+}
+
+impl FromStr for CommentMode {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, DomainError> {
+        CommentMode::ALL
+            .into_iter()
+            .find(|mode| mode.code().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                DomainError::enum_coercion_err("CommentMode").with_message(format!(
+                    "Unsupported comment mode, expected one of: {}.",
+                    CommentMode::ALL.map(|mode| mode.code()).join(", ")
+                ))
+            })
+    }
+}
+
+impl Display for CommentMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Article {
     id: Uuid,
@@ -18,6 +72,11 @@ pub struct Article {
     created_at: DateTime,
     updated_at: Option<DateTime>,
     slug: Slug,
+    featured: bool,
+    pinned: bool,
+    pin_order: i32,
+    pinned_until: Option<DateTime>,
+    comment_mode: CommentMode,
 }
 
 impl Article {
@@ -49,6 +108,11 @@ impl Article {
             created_at,
             updated_at,
             slug,
+            featured: false,
+            pinned: false,
+            pin_order: 0,
+            pinned_until: None,
+            comment_mode: CommentMode::Open,
         }
     }
 
@@ -65,6 +129,11 @@ impl Article {
         tag_id: Option<i32>,
         tag_value: Option<String>,
         slug: Slug,
+        featured: bool,
+        pinned: bool,
+        pin_order: i32,
+        pinned_until: Option<DateTime>,
+        comment_mode: CommentMode,
     ) -> Self {
         Article {
             id,
@@ -78,6 +147,11 @@ impl Article {
             created_at,
             updated_at,
             slug,
+            featured,
+            pinned,
+            pin_order,
+            pinned_until,
+            comment_mode,
         }
     }
 
@@ -87,6 +161,14 @@ impl Article {
         self.updated_at = Some(TimeHelper::now());
     }
 
+    /// Swaps the article's title, content and slug by the translated ones. It's meant for reading only: saving a
+    /// translated article would overwrite its original fields.
+    pub fn translate(&mut self, translation: &ArticleTranslation) {
+        self.title = translation.title().to_owned();
+        self.content = translation.content().to_owned();
+        self.slug = translation.slug();
+    }
+
     // GETTERS
 
     pub fn id(&self) -> Uuid {
@@ -133,6 +215,27 @@ impl Article {
         self.tag_value.clone()
     }
 
+    pub fn featured(&self) -> bool {
+        self.featured
+    }
+
+    pub fn pinned(&self) -> bool {
+        self.pinned
+    }
+
+    pub fn pin_order(&self) -> i32 {
+        self.pin_order
+    }
+
+    pub fn pinned_until(&self) -> Option<DateTime> {
+        self.pinned_until
+    }
+
+    /// The mode set on the article, which the auto-close might override, see `ArticlePolitics::comment_mode`.
+    pub fn comment_mode(&self) -> CommentMode {
+        self.comment_mode
+    }
+
     // SETTERS
 
     pub fn set_author_id(&mut self, author_id: Uuid) {
@@ -168,4 +271,24 @@ impl Article {
     pub fn set_tag_value(&mut self, tag_value: String) {
         self.tag_value = Some(tag_value)
     }
+
+    pub fn set_featured(&mut self, featured: bool) {
+        self.featured = featured;
+    }
+
+    pub fn pin(&mut self, pin_order: i32, pinned_until: Option<DateTime>) {
+        self.pinned = true;
+        self.pin_order = pin_order;
+        self.pinned_until = pinned_until;
+    }
+
+    pub fn set_comment_mode(&mut self, comment_mode: CommentMode) {
+        self.comment_mode = comment_mode;
+    }
+
+    pub fn unpin(&mut self) {
+        self.pinned = false;
+        self.pin_order = 0;
+        self.pinned_until = None;
+    }
 }
@@ -0,0 +1,33 @@
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ArticleCoauthor {
+    article_id: Uuid,
+    user_id: Uuid,
+    contribution: String,
+}
+
+impl ArticleCoauthor {
+    // CONSTRUCTORS
+    pub fn new(article_id: Uuid, user_id: Uuid, contribution: String) -> Self {
+        ArticleCoauthor {
+            article_id,
+            user_id,
+            contribution,
+        }
+    }
+
+    // GETTERS
+    pub fn article_id(&self) -> Uuid {
+        self.article_id
+    }
+
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    /// A short label describing what the co-author did on the article, e.g. "texto", "arte" or "revisão".
+    pub fn contribution(&self) -> &str {
+        self.contribution.as_ref()
+    }
+}
@@ -0,0 +1,111 @@
+use uuid::Uuid;
+
+use crate::domain::storage::file_storage::FileStorageTrait;
+
+const COVERS_FOLDER: &str = "covers";
+const THUMBNAIL_EXTENSION: &str = "jpg";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArticleCoverThumbnail {
+    /// Used by the articles' cards on listings.
+    Card,
+    /// Used on top of the expanded article.
+    Hero,
+}
+
+impl ArticleCoverThumbnail {
+    pub const ALL: [ArticleCoverThumbnail; 2] =
+        [ArticleCoverThumbnail::Card, ArticleCoverThumbnail::Hero];
+
+    /// Width and height, in pixels.
+    pub fn dimensions(&self) -> (u32, u32) {
+        match self {
+            ArticleCoverThumbnail::Card => (480, 270),
+            ArticleCoverThumbnail::Hero => (1280, 720),
+        }
+    }
+
+    fn suffix(&self) -> &'static str {
+        match self {
+            ArticleCoverThumbnail::Card => "card",
+            ArticleCoverThumbnail::Hero => "hero",
+        }
+    }
+}
+
+/// Storage keys of an uploaded cover and its thumbnails, which are derived from the original's key so that
+/// only the latter needs to be persisted as the article's `cover_url`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArticleCoverKeys {
+    id: Uuid,
+    extension: String,
+}
+
+impl ArticleCoverKeys {
+    // CONSTRUCTORS
+    pub fn new(extension: &str) -> Self {
+        ArticleCoverKeys {
+            id: Uuid::new_v4(),
+            extension: extension.to_owned(),
+        }
+    }
+
+    /// Parses keys in the `covers/<uuid>.<extension>` format, the one `original` returns.
+    pub fn from_original_key(key: &str) -> Option<Self> {
+        let file_name = key.strip_prefix(COVERS_FOLDER)?.strip_prefix('/')?;
+        let (id, extension) = file_name.split_once('.')?;
+
+        Some(ArticleCoverKeys {
+            id: Uuid::parse_str(id).ok()?,
+            extension: extension.to_owned(),
+        })
+    }
+
+    // GETTERS
+    pub fn original(&self) -> String {
+        format!("{COVERS_FOLDER}/{}.{}", self.id, self.extension)
+    }
+
+    pub fn thumbnail(&self, thumbnail: ArticleCoverThumbnail) -> String {
+        format!(
+            "{COVERS_FOLDER}/{}-{}.{THUMBNAIL_EXTENSION}",
+            self.id,
+            thumbnail.suffix()
+        )
+    }
+}
+
+/// URLs of every variant of an article's cover.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArticleCoverUrls {
+    pub original: String,
+    pub card: String,
+    pub hero: String,
+}
+
+impl ArticleCoverUrls {
+    pub fn from_keys<FS: FileStorageTrait>(keys: &ArticleCoverKeys, storage: &FS) -> Self {
+        ArticleCoverUrls {
+            original: storage.url(&keys.original()),
+            card: storage.url(&keys.thumbnail(ArticleCoverThumbnail::Card)),
+            hero: storage.url(&keys.thumbnail(ArticleCoverThumbnail::Hero)),
+        }
+    }
+
+    /// Covers hosted elsewhere (e.g. the ones set before uploads existed) have no thumbnails, so every variant
+    /// falls back to the original URL.
+    pub fn from_cover_url<FS: FileStorageTrait>(cover_url: &str, storage: &FS) -> Self {
+        let keys = storage
+            .key_from_url(cover_url)
+            .and_then(|key| ArticleCoverKeys::from_original_key(&key));
+
+        match keys {
+            Some(keys) => Self::from_keys(&keys, storage),
+            None => ArticleCoverUrls {
+                original: cover_url.to_owned(),
+                card: cover_url.to_owned(),
+                hero: cover_url.to_owned(),
+            },
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::libs::time::TimeHelper;
+
+use super::locale::Locale;
+use super::slug::Slug;
+
+/// An article's title, content and slug in a locale other than the one it has been written in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArticleTranslation {
+    article_id: Uuid,
+    locale: Locale,
+    title: String,
+    content: String,
+    slug: Slug,
+    created_at: DateTime,
+    updated_at: Option<DateTime>,
+}
+
+impl ArticleTranslation {
+    // CONSTRUCTORS
+    pub fn new(article_id: Uuid, locale: Locale, title: String, content: String) -> Self {
+        let slug = Slug::new(article_id, title.clone());
+
+        ArticleTranslation {
+            article_id,
+            locale,
+            title,
+            content,
+            slug,
+            created_at: TimeHelper::now(),
+            updated_at: None,
+        }
+    }
+
+    pub fn new_from_existing(
+        article_id: Uuid,
+        locale: Locale,
+        title: String,
+        content: String,
+        slug: Slug,
+        created_at: DateTime,
+        updated_at: Option<DateTime>,
+    ) -> Self {
+        ArticleTranslation {
+            article_id,
+            locale,
+            title,
+            content,
+            slug,
+            created_at,
+            updated_at,
+        }
+    }
+
+    // GETTERS
+    pub fn article_id(&self) -> Uuid {
+        self.article_id
+    }
+
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    pub fn title(&self) -> &str {
+        self.title.as_ref()
+    }
+
+    pub fn content(&self) -> &str {
+        self.content.as_ref()
+    }
+
+    pub fn slug(&self) -> Slug {
+        self.slug.clone()
+    }
+
+    pub fn created_at(&self) -> DateTime {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> Option<DateTime> {
+        self.updated_at
+    }
+
+    // SETTERS
+    pub fn set_title(&mut self, title: String) {
+        self.slug = Slug::new(self.article_id, title.clone());
+        self.title = title;
+        self.updated_at = Some(TimeHelper::now());
+    }
+
+    pub fn set_content(&mut self, content: String) {
+        self.content = content;
+        self.updated_at = Some(TimeHelper::now());
+    }
+}
@@ -0,0 +1,158 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::error::DomainError;
+use crate::libs::time::TimeHelper;
+
+/// What happens to a text containing a blocked word.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum BlockedWordAction {
+    /// The word is replaced by asterisks.
+    Mask,
+    /// The whole text is refused.
+    Reject,
+    /// The text is accepted, but hidden and reported for moderators to review it.
+    Flag,
+}
+
+impl BlockedWordAction {
+    pub const ALL: [BlockedWordAction; 3] = [
+        BlockedWordAction::Mask,
+        BlockedWordAction::Reject,
+        BlockedWordAction::Flag,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            BlockedWordAction::Mask => "mask",
+            BlockedWordAction::Reject => "reject",
+            BlockedWordAction::Flag => "flag",
+        }
+    }
+}
+
+impl FromStr for BlockedWordAction {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, DomainError> {
+        BlockedWordAction::ALL
+            .into_iter()
+            .find(|action| action.code().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                DomainError::enum_coercion_err("BlockedWordAction").with_message(format!(
+                    "Unsupported blocked word action, expected one of: {}.",
+                    BlockedWordAction::ALL
+                        .map(|action| action.code())
+                        .join(", ")
+                ))
+            })
+    }
+}
+
+impl Display for BlockedWordAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// An entry of the staff-managed word filter: either a word, matched as a whole word, or a regular expression.
+/// Both are matched against the normalized text, see `WordFilterPolitics`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BlockedWord {
+    id: Uuid,
+    pattern: String,
+    is_regex: bool,
+    action: BlockedWordAction,
+    author_id: Uuid,
+    created_at: DateTime,
+    updated_at: Option<DateTime>,
+}
+
+impl BlockedWord {
+    // CONSTRUCTORS
+    pub fn new(
+        pattern: String,
+        is_regex: bool,
+        action: BlockedWordAction,
+        author_id: Uuid,
+    ) -> Self {
+        BlockedWord {
+            id: Uuid::new_v4(),
+            pattern,
+            is_regex,
+            action,
+            author_id,
+            created_at: TimeHelper::now(),
+            updated_at: None,
+        }
+    }
+
+    pub fn new_from_existing(
+        id: Uuid,
+        pattern: String,
+        is_regex: bool,
+        action: BlockedWordAction,
+        author_id: Uuid,
+        created_at: DateTime,
+        updated_at: Option<DateTime>,
+    ) -> Self {
+        BlockedWord {
+            id,
+            pattern,
+            is_regex,
+            action,
+            author_id,
+            created_at,
+            updated_at,
+        }
+    }
+
+    // GETTERS
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn pattern(&self) -> &str {
+        self.pattern.as_ref()
+    }
+
+    pub fn is_regex(&self) -> bool {
+        self.is_regex
+    }
+
+    pub fn action(&self) -> BlockedWordAction {
+        self.action
+    }
+
+    /// The staff member who added the word, who is also the reporter of the comments it flags.
+    pub fn author_id(&self) -> Uuid {
+        self.author_id
+    }
+
+    pub fn created_at(&self) -> DateTime {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> Option<DateTime> {
+        self.updated_at
+    }
+
+    // SETTERS
+    pub fn set_pattern(&mut self, pattern: String, is_regex: bool) {
+        self.pattern = pattern;
+        self.is_regex = is_regex;
+        self.touch();
+    }
+
+    pub fn set_action(&mut self, action: BlockedWordAction) {
+        self.action = action;
+        self.touch();
+    }
+
+    fn touch(&mut self) {
+        self.updated_at = Some(TimeHelper::now());
+    }
+}
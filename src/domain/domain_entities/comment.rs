@@ -3,14 +3,19 @@ use uuid::Uuid;
 
 use crate::libs::time::TimeHelper;
 
+/// How deep replies can be nested, top-level comments being at depth 0.
+pub const MAX_REPLY_DEPTH: usize = 3;
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Comment {
     id: Uuid,
     article_id: Option<Uuid>,
+    parent_id: Option<Uuid>,
     author_id: Uuid,
     content: String,
     is_active: bool,
     created_at: DateTime,
+    updated_at: Option<DateTime>,
 }
 
 impl Comment {
@@ -19,32 +24,48 @@ impl Comment {
         let id = Uuid::new_v4();
         let created_at = TimeHelper::now();
         let is_active = true;
+        let updated_at = None;
 
         Comment {
             id,
             article_id,
+            parent_id: None,
             author_id,
             content,
             is_active,
             created_at,
+            updated_at,
         }
     }
 
+    /// A reply is made on the same article its parent comment is.
+    pub fn new_reply(author_id: Uuid, parent: &Comment, content: String) -> Self {
+        let mut reply = Self::new(author_id, parent.article_id(), content);
+        reply.parent_id = Some(parent.id());
+
+        reply
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn new_from_existing(
         id: Uuid,
         article_id: Option<Uuid>,
+        parent_id: Option<Uuid>,
         author_id: Uuid,
         content: String,
         is_active: bool,
         created_at: DateTime,
+        updated_at: Option<DateTime>,
     ) -> Self {
         Comment {
             id,
             article_id,
+            parent_id,
             author_id,
             content,
             is_active,
             created_at,
+            updated_at,
         }
     }
 
@@ -58,6 +79,10 @@ impl Comment {
         self.article_id
     }
 
+    pub fn parent_id(&self) -> Option<Uuid> {
+        self.parent_id
+    }
+
     pub fn author_id(&self) -> Uuid {
         self.author_id
     }
@@ -74,13 +99,23 @@ impl Comment {
         self.created_at
     }
 
+    pub fn updated_at(&self) -> Option<DateTime> {
+        self.updated_at
+    }
+
     // SETTERS
 
     pub fn set_content(&mut self, content: String) {
         self.content = content;
+        self.touch();
     }
 
     pub fn set_is_active(&mut self, is_active: bool) {
         self.is_active = is_active
     }
+
+    // helpers
+    fn touch(&mut self) {
+        self.updated_at = Some(TimeHelper::now());
+    }
 }
@@ -0,0 +1,65 @@
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::libs::time::TimeHelper;
+
+/// A record of a comment's content before one of its edits.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CommentEdit {
+    id: Uuid,
+    comment_id: Uuid,
+    editor_id: Uuid,
+    previous_content: String,
+    created_at: DateTime,
+}
+
+impl CommentEdit {
+    // CONSTRUCTORS
+    pub fn new(comment_id: Uuid, editor_id: Uuid, previous_content: String) -> Self {
+        CommentEdit {
+            id: Uuid::new_v4(),
+            comment_id,
+            editor_id,
+            previous_content,
+            created_at: TimeHelper::now(),
+        }
+    }
+
+    pub fn new_from_existing(
+        id: Uuid,
+        comment_id: Uuid,
+        editor_id: Uuid,
+        previous_content: String,
+        created_at: DateTime,
+    ) -> Self {
+        CommentEdit {
+            id,
+            comment_id,
+            editor_id,
+            previous_content,
+            created_at,
+        }
+    }
+
+    // GETTERS
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn comment_id(&self) -> Uuid {
+        self.comment_id
+    }
+
+    /// Either the comment's author or the moderator who edited it.
+    pub fn editor_id(&self) -> Uuid {
+        self.editor_id
+    }
+
+    pub fn previous_content(&self) -> &str {
+        self.previous_content.as_ref()
+    }
+
+    pub fn created_at(&self) -> DateTime {
+        self.created_at
+    }
+}
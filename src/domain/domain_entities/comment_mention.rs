@@ -0,0 +1,149 @@
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::core::MENTION_REGX;
+use crate::libs::time::TimeHelper;
+
+/// Nickname characters that are more likely to end the sentence than the nickname, e.g. "valeu @Floricultor!".
+const TRAILING_PUNCTUATION: &[char] = &['.', ',', '!', '?', ':', ';'];
+
+/// A user mentioned in a comment through "@nickname". The span covers the "@" and the nickname, counted in
+/// characters (not bytes) from the start of the comment's content, its end being exclusive.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CommentMention {
+    comment_id: Uuid,
+    user_id: Uuid,
+    start: u32,
+    end: u32,
+    created_at: DateTime,
+}
+
+impl CommentMention {
+    // CONSTRUCTORS
+    pub fn new(comment_id: Uuid, user_id: Uuid, start: u32, end: u32) -> Self {
+        CommentMention {
+            comment_id,
+            user_id,
+            start,
+            end,
+            created_at: TimeHelper::now(),
+        }
+    }
+
+    pub fn new_from_existing(
+        comment_id: Uuid,
+        user_id: Uuid,
+        start: u32,
+        end: u32,
+        created_at: DateTime,
+    ) -> Self {
+        CommentMention {
+            comment_id,
+            user_id,
+            start,
+            end,
+            created_at,
+        }
+    }
+
+    // GETTERS
+    pub fn comment_id(&self) -> Uuid {
+        self.comment_id
+    }
+
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn start(&self) -> u32 {
+        self.start
+    }
+
+    pub fn end(&self) -> u32 {
+        self.end
+    }
+
+    pub fn created_at(&self) -> DateTime {
+        self.created_at
+    }
+}
+
+/// An "@nickname" found in a text, not yet resolved to a user.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MentionCandidate {
+    /// Where the "@" is, in characters.
+    pub start: u32,
+    pub nickname: String,
+}
+
+impl MentionCandidate {
+    /// The nicknames the candidate might stand for, from the longest to the shortest, since punctuation at its end
+    /// may or may not be part of the nickname.
+    pub fn nicknames(&self) -> Vec<&str> {
+        let mut nicknames = vec![self.nickname.as_str()];
+        let mut nickname = self.nickname.as_str();
+
+        while let Some(trimmed) = nickname.strip_suffix(TRAILING_PUNCTUATION) {
+            if trimmed.is_empty() {
+                break;
+            }
+
+            nicknames.push(trimmed);
+            nickname = trimmed;
+        }
+
+        nicknames
+    }
+
+    pub fn find_all(text: &str) -> Vec<MentionCandidate> {
+        MENTION_REGX
+            .captures_iter(text)
+            .filter_map(|captures| captures.name("nickname"))
+            .map(|nickname| MentionCandidate {
+                // the "@" is right before the nickname
+                start: text[..nickname.start()].chars().count() as u32 - 1,
+                nickname: nickname.as_str().to_owned(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let candidates = MentionCandidate::find_all(
+            "Valeu @Floricultor! Olá, @Ana.Clara. e-mail: contato@samambaia.com @",
+        );
+
+        assert_eq!(
+            vec![
+                MentionCandidate {
+                    start: 6,
+                    nickname: "Floricultor!".into()
+                },
+                MentionCandidate {
+                    start: 25,
+                    nickname: "Ana.Clara.".into()
+                },
+            ],
+            candidates
+        );
+
+        assert_eq!(
+            vec!["Floricultor!", "Floricultor"],
+            candidates[0].nicknames()
+        );
+        assert_eq!(vec!["Ana.Clara.", "Ana.Clara"], candidates[1].nicknames());
+
+        // offsets are counted in characters
+        let candidates = MentionCandidate::find_all("Ótimo, @Floricultor");
+        assert_eq!(7, candidates[0].start);
+        assert_eq!(
+            vec!["!?", "!"],
+            MentionCandidate::find_all("@!?")[0].nicknames()
+        );
+    }
+}
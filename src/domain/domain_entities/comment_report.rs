@@ -1,8 +1,171 @@
 use chrono::NaiveDateTime as DateTime;
+use std::fmt::Display;
+use std::str::FromStr;
 use uuid::Uuid;
 
+use crate::domain::politics::comment_report_politics::CommentReportPolitics;
+use crate::error::DomainError;
 use crate::libs::time::TimeHelper;
 
+/// What a comment is being reported for.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ReportReason {
+    Spam,
+    Offensive,
+    ScamLink,
+    OffTopic,
+    #[default]
+    Other,
+}
+
+impl ReportReason {
+    pub const ALL: [ReportReason; 5] = [
+        ReportReason::Spam,
+        ReportReason::Offensive,
+        ReportReason::ScamLink,
+        ReportReason::OffTopic,
+        ReportReason::Other,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            ReportReason::Spam => "spam",
+            ReportReason::Offensive => "offensive",
+            ReportReason::ScamLink => "scam_link",
+            ReportReason::OffTopic => "off_topic",
+            ReportReason::Other => "other",
+        }
+    }
+}
+
+impl FromStr for ReportReason {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, DomainError> {
+        ReportReason::ALL
+            .into_iter()
+            .find(|reason| reason.code() == s)
+            .ok_or_else(|| {
+                DomainError::enum_coercion_err("ReportReason").with_message(format!(
+                    "Unsupported report reason, expected one of: {}.",
+                    ReportReason::ALL.map(|reason| reason.code()).join(", ")
+                ))
+            })
+    }
+}
+
+impl Display for ReportReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// How soon moderators should look into a report, see `CommentReportPolitics::priority`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ReportPriority {
+    Low,
+    Normal,
+    High,
+    Urgent,
+}
+
+impl ReportPriority {
+    pub const ALL: [ReportPriority; 4] = [
+        ReportPriority::Low,
+        ReportPriority::Normal,
+        ReportPriority::High,
+        ReportPriority::Urgent,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            ReportPriority::Low => "low",
+            ReportPriority::Normal => "normal",
+            ReportPriority::High => "high",
+            ReportPriority::Urgent => "urgent",
+        }
+    }
+
+    /// The priority as stored, so reports can be sorted by it.
+    pub fn level(&self) -> i16 {
+        match self {
+            ReportPriority::Low => 0,
+            ReportPriority::Normal => 1,
+            ReportPriority::High => 2,
+            ReportPriority::Urgent => 3,
+        }
+    }
+
+    /// The next priority up, `Urgent` being the highest.
+    pub fn raised(&self) -> Self {
+        match self {
+            ReportPriority::Low => ReportPriority::Normal,
+            ReportPriority::Normal => ReportPriority::High,
+            ReportPriority::High | ReportPriority::Urgent => ReportPriority::Urgent,
+        }
+    }
+}
+
+impl Display for ReportPriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// What moderators did about a report.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ReportResolution {
+    CommentHidden,
+    CommentDeleted,
+    /// The comment was fine, and is left as it is.
+    Rejected,
+}
+
+impl ReportResolution {
+    pub const ALL: [ReportResolution; 3] = [
+        ReportResolution::CommentHidden,
+        ReportResolution::CommentDeleted,
+        ReportResolution::Rejected,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            ReportResolution::CommentHidden => "comment_hidden",
+            ReportResolution::CommentDeleted => "comment_deleted",
+            ReportResolution::Rejected => "rejected",
+        }
+    }
+
+    /// Whether the reported comment is taken down.
+    pub fn acts_on_comment(&self) -> bool {
+        !matches!(self, ReportResolution::Rejected)
+    }
+}
+
+impl FromStr for ReportResolution {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, DomainError> {
+        ReportResolution::ALL
+            .into_iter()
+            .find(|resolution| resolution.code() == s)
+            .ok_or_else(|| {
+                DomainError::enum_coercion_err("ReportResolution").with_message(format!(
+                    "Unsupported report resolution, expected one of: {}.",
+                    ReportResolution::ALL
+                        .map(|resolution| resolution.code())
+                        .join(", ")
+                ))
+            })
+    }
+}
+
+impl Display for ReportResolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct DraftCommentReport {
     comment_id: Uuid,
@@ -10,11 +173,13 @@ pub struct DraftCommentReport {
     message: String,
     solved_by: Option<Uuid>,
     created_at: DateTime,
+    comment_auto_hidden: bool,
+    reason: ReportReason,
 }
 
 impl DraftCommentReport {
     // CONSTRUCTOR
-    pub fn new(comment_id: Uuid, user_id: Uuid, message: String) -> Self {
+    pub fn new(comment_id: Uuid, user_id: Uuid, message: String, reason: ReportReason) -> Self {
         let solved_by = None;
         let created_at = TimeHelper::now();
 
@@ -24,18 +189,31 @@ impl DraftCommentReport {
             message,
             solved_by,
             created_at,
+            comment_auto_hidden: false,
+            reason,
         }
     }
 
+    // GETTERS
+
+    pub fn comment_id(&self) -> Uuid {
+        self.comment_id
+    }
+
     // METHODS
     pub fn to_comment_report(self, id: i32) -> CommentReport {
         CommentReport {
             id,
             user_id: self.user_id,
-            comment_id: self.comment_id,
+            comment_id: Some(self.comment_id),
             message: self.message,
             solved_by: self.solved_by,
             created_at: self.created_at,
+            comment_auto_hidden: self.comment_auto_hidden,
+            reason: self.reason,
+            assigned_to: None,
+            resolution: None,
+            resolution_notes: None,
         }
     }
 }
@@ -43,11 +221,16 @@ impl DraftCommentReport {
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CommentReport {
     id: i32,
-    comment_id: Uuid,
+    comment_id: Option<Uuid>,
     user_id: Uuid,
     message: String,
     solved_by: Option<Uuid>,
     created_at: DateTime,
+    comment_auto_hidden: bool,
+    reason: ReportReason,
+    assigned_to: Option<Uuid>,
+    resolution: Option<ReportResolution>,
+    resolution_notes: Option<String>,
 }
 
 impl CommentReportIdTrait for CommentReport {
@@ -57,13 +240,19 @@ impl CommentReportIdTrait for CommentReport {
 }
 
 impl CommentReport {
+    #[allow(clippy::too_many_arguments)]
     pub fn new_from_existing(
         id: i32,
-        comment_id: Uuid,
+        comment_id: Option<Uuid>,
         user_id: Uuid,
         message: String,
         solved_by: Option<Uuid>,
         created_at: DateTime,
+        comment_auto_hidden: bool,
+        reason: ReportReason,
+        assigned_to: Option<Uuid>,
+        resolution: Option<ReportResolution>,
+        resolution_notes: Option<String>,
     ) -> Self {
         CommentReport {
             id,
@@ -72,22 +261,70 @@ impl CommentReport {
             message,
             solved_by,
             created_at,
+            comment_auto_hidden,
+            reason,
+            assigned_to,
+            resolution,
+            resolution_notes,
         }
     }
 
+    // GETTERS
+
+    /// The reported comment, `None` once it has been deleted.
+    pub fn comment_id(&self) -> Option<Uuid> {
+        self.comment_id
+    }
+
+    /// The moderator who is looking into the report.
+    pub fn assigned_to(&self) -> Option<Uuid> {
+        self.assigned_to
+    }
+
+    /// What was done about the report, once solved. Reports solved before resolutions existed have none.
+    pub fn resolution(&self) -> Option<ReportResolution> {
+        self.resolution
+    }
+
+    pub fn resolution_notes(&self) -> Option<&str> {
+        self.resolution_notes.as_deref()
+    }
+
     // SETTERS
 
     pub fn set_solved_by(&mut self, value: Option<Uuid>) {
         self.solved_by = value;
     }
+
+    pub fn set_comment_id(&mut self, value: Option<Uuid>) {
+        self.comment_id = value;
+    }
+
+    pub fn set_assigned_to(&mut self, value: Option<Uuid>) {
+        self.assigned_to = value;
+    }
+
+    // METHODS
+
+    /// Marks the report as solved by the staff member the way they did it.
+    pub fn resolve(
+        &mut self,
+        staff_id: Uuid,
+        resolution: ReportResolution,
+        resolution_notes: Option<String>,
+    ) {
+        self.solved_by = Some(staff_id);
+        self.resolution = Some(resolution);
+        self.resolution_notes = resolution_notes;
+    }
+
+    pub fn set_comment_auto_hidden(&mut self, value: bool) {
+        self.comment_auto_hidden = value;
+    }
 }
 
 #[duplicate::duplicate_item(comment_report; [DraftCommentReport]; [CommentReport])]
 impl CommentReportTrait for comment_report {
-    fn comment_id(&self) -> Uuid {
-        self.comment_id
-    }
-
     fn user_id(&self) -> Uuid {
         self.user_id
     }
@@ -103,14 +340,30 @@ impl CommentReportTrait for comment_report {
     fn created_at(&self) -> DateTime {
         self.created_at
     }
+
+    fn comment_auto_hidden(&self) -> bool {
+        self.comment_auto_hidden
+    }
+
+    fn reason(&self) -> ReportReason {
+        self.reason
+    }
+
+    fn priority(&self) -> ReportPriority {
+        CommentReportPolitics::priority(self.reason, self.comment_auto_hidden)
+    }
 }
 
 pub trait CommentReportTrait {
-    fn comment_id(&self) -> Uuid;
     fn user_id(&self) -> Uuid;
     fn message(&self) -> String;
     fn solved_by(&self) -> Option<Uuid>;
     fn created_at(&self) -> DateTime;
+    /// Whether the reported comment has been hidden for having been reported by too many users, see
+    /// `CommentReportPolitics`.
+    fn comment_auto_hidden(&self) -> bool;
+    fn reason(&self) -> ReportReason;
+    fn priority(&self) -> ReportPriority;
 }
 
 pub trait CommentReportIdTrait {
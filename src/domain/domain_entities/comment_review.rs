@@ -0,0 +1,85 @@
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::libs::time::TimeHelper;
+
+/// A comment held for a moderator's review before being published, see `CommentReviewPolitics`. Reviewed entries
+/// are kept, since an approved one lets its author publish without review from then on.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CommentReview {
+    comment_id: Uuid,
+    author_id: Uuid,
+    created_at: DateTime,
+    reviewed_by: Option<Uuid>,
+    reviewed_at: Option<DateTime>,
+    approved: Option<bool>,
+}
+
+impl CommentReview {
+    // CONSTRUCTORS
+    pub fn new(comment_id: Uuid, author_id: Uuid) -> Self {
+        CommentReview {
+            comment_id,
+            author_id,
+            created_at: TimeHelper::now(),
+            reviewed_by: None,
+            reviewed_at: None,
+            approved: None,
+        }
+    }
+
+    pub fn new_from_existing(
+        comment_id: Uuid,
+        author_id: Uuid,
+        created_at: DateTime,
+        reviewed_by: Option<Uuid>,
+        reviewed_at: Option<DateTime>,
+        approved: Option<bool>,
+    ) -> Self {
+        CommentReview {
+            comment_id,
+            author_id,
+            created_at,
+            reviewed_by,
+            reviewed_at,
+            approved,
+        }
+    }
+
+    // GETTERS
+    pub fn comment_id(&self) -> Uuid {
+        self.comment_id
+    }
+
+    pub fn author_id(&self) -> Uuid {
+        self.author_id
+    }
+
+    pub fn created_at(&self) -> DateTime {
+        self.created_at
+    }
+
+    pub fn reviewed_by(&self) -> Option<Uuid> {
+        self.reviewed_by
+    }
+
+    pub fn reviewed_at(&self) -> Option<DateTime> {
+        self.reviewed_at
+    }
+
+    /// `None` while the comment is pending review.
+    pub fn approved(&self) -> Option<bool> {
+        self.approved
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.approved.is_none()
+    }
+
+    // METHODS
+    pub fn review(&mut self, moderator_id: Uuid, approved: bool) {
+        self.reviewed_by = Some(moderator_id);
+        self.reviewed_at = Some(TimeHelper::now());
+        self.approved = Some(approved);
+    }
+}
@@ -3,16 +3,26 @@ use uuid::Uuid;
 
 use crate::libs::time::TimeHelper;
 
+use super::comment_mention::CommentMention;
+use super::reaction::ReactionSummary;
 use super::user::User;
 
+/// Shown in place of the content of removed comments that are kept for their replies.
+pub const REMOVED_COMMENT_PLACEHOLDER: &str = "[removed]";
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CommentWithAuthor {
     id: Uuid,
     article_id: Option<Uuid>,
+    parent_id: Option<Uuid>,
     content: String,
     is_active: bool,
     created_at: DateTime,
+    updated_at: Option<DateTime>,
     author: User,
+    replies_count: u64,
+    mentions: Vec<CommentMention>,
+    reactions: ReactionSummary,
 }
 
 impl CommentWithAuthor {
@@ -21,32 +31,46 @@ impl CommentWithAuthor {
         let id = Uuid::new_v4();
         let created_at = TimeHelper::now();
         let is_active = true;
+        let updated_at = None;
 
         CommentWithAuthor {
             id,
             article_id,
+            parent_id: None,
             content,
             is_active,
             created_at,
+            updated_at,
             author,
+            replies_count: 0,
+            mentions: vec![],
+            reactions: ReactionSummary::default(),
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn new_from_existing(
         id: Uuid,
         article_id: Option<Uuid>,
+        parent_id: Option<Uuid>,
         content: String,
         is_active: bool,
         created_at: DateTime,
+        updated_at: Option<DateTime>,
         author: User,
     ) -> Self {
         CommentWithAuthor {
             id,
             article_id,
+            parent_id,
             content,
             is_active,
             created_at,
+            updated_at,
             author,
+            replies_count: 0,
+            mentions: vec![],
+            reactions: ReactionSummary::default(),
         }
     }
 
@@ -59,6 +83,10 @@ impl CommentWithAuthor {
         self.article_id
     }
 
+    pub fn parent_id(&self) -> Option<Uuid> {
+        self.parent_id
+    }
+
     pub fn content(&self) -> &str {
         self.content.as_ref()
     }
@@ -71,7 +99,47 @@ impl CommentWithAuthor {
         self.created_at
     }
 
+    pub fn updated_at(&self) -> Option<DateTime> {
+        self.updated_at
+    }
+
     pub fn author(&self) -> &User {
         &self.author
     }
+
+    pub fn replies_count(&self) -> u64 {
+        self.replies_count
+    }
+
+    pub fn mentions(&self) -> &[CommentMention] {
+        &self.mentions
+    }
+
+    pub fn reactions(&self) -> &ReactionSummary {
+        &self.reactions
+    }
+
+    // SETTERS
+    pub fn set_replies_count(&mut self, replies_count: u64) {
+        self.replies_count = replies_count;
+    }
+
+    pub fn set_mentions(&mut self, mentions: Vec<CommentMention>) {
+        self.mentions = mentions;
+    }
+
+    pub fn set_reactions(&mut self, reactions: ReactionSummary) {
+        self.reactions = reactions;
+    }
+
+    /// Swaps the content of an inactive comment for `REMOVED_COMMENT_PLACEHOLDER`, so that it can still be listed
+    /// along with its replies.
+    pub fn redacted(mut self) -> Self {
+        if !self.is_active {
+            self.content = REMOVED_COMMENT_PLACEHOLDER.into();
+            self.mentions.clear();
+        }
+
+        self
+    }
 }
@@ -0,0 +1,162 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::DomainError;
+use crate::util::SiteUrl;
+
+/// A Habbo hotel users can pick to have their avatar shown from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum HabboHotel {
+    ComBr,
+    Com,
+    Es,
+    De,
+    Fi,
+    Fr,
+    It,
+    Nl,
+    ComTr,
+}
+
+impl HabboHotel {
+    pub const ALL: [HabboHotel; 9] = [
+        HabboHotel::ComBr,
+        HabboHotel::Com,
+        HabboHotel::Es,
+        HabboHotel::De,
+        HabboHotel::Fi,
+        HabboHotel::Fr,
+        HabboHotel::It,
+        HabboHotel::Nl,
+        HabboHotel::ComTr,
+    ];
+
+    /// The hotel's top-level domain, which also identifies it.
+    pub fn code(&self) -> &'static str {
+        match self {
+            HabboHotel::ComBr => "com.br",
+            HabboHotel::Com => "com",
+            HabboHotel::Es => "es",
+            HabboHotel::De => "de",
+            HabboHotel::Fi => "fi",
+            HabboHotel::Fr => "fr",
+            HabboHotel::It => "it",
+            HabboHotel::Nl => "nl",
+            HabboHotel::ComTr => "com.tr",
+        }
+    }
+}
+
+impl FromStr for HabboHotel {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, DomainError> {
+        HabboHotel::ALL
+            .into_iter()
+            .find(|hotel| hotel.code().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                DomainError::enum_coercion_err("HabboHotel").with_message(format!(
+                    "Unsupported Habbo hotel, expected one of: {}.",
+                    HabboHotel::ALL.map(|hotel| hotel.code()).join(", ")
+                ))
+            })
+    }
+}
+
+impl Display for HabboHotel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// How a user wants their Habbo avatar to be shown. Their Habbo is assumed to have the same nickname as them.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HabboAvatar {
+    hotel: Option<HabboHotel>,
+    direction: u8,
+    head_only: bool,
+}
+
+impl Default for HabboAvatar {
+    fn default() -> Self {
+        HabboAvatar {
+            hotel: None,
+            direction: Self::DEFAULT_DIRECTION,
+            head_only: false,
+        }
+    }
+}
+
+impl HabboAvatar {
+    /// Facing the viewer, slightly to the right.
+    pub const DEFAULT_DIRECTION: u8 = 2;
+
+    /// The imager draws avatars in eight directions, counted clockwise from the back-left.
+    pub const MAX_DIRECTION: u8 = 7;
+
+    pub fn new(
+        hotel: Option<HabboHotel>,
+        direction: u8,
+        head_only: bool,
+    ) -> Result<Self, DomainError> {
+        if direction > Self::MAX_DIRECTION {
+            return Err(DomainError::bad_request_err().with_message(format!(
+                "The avatar's direction must be between 0 and {}.",
+                Self::MAX_DIRECTION
+            )));
+        }
+
+        Ok(HabboAvatar {
+            hotel,
+            direction,
+            head_only,
+        })
+    }
+
+    pub fn hotel(&self) -> Option<HabboHotel> {
+        self.hotel
+    }
+
+    pub fn direction(&self) -> u8 {
+        self.direction
+    }
+
+    pub fn head_only(&self) -> bool {
+        self.head_only
+    }
+
+    /// The avatar's picture on the hotel's imager, if a hotel has been picked.
+    pub fn imager_url(&self, nickname: &str) -> Option<String> {
+        self.hotel.map(|hotel| {
+            format!(
+                "https://www.habbo.{}/habbo-imaging/avatarimage?user={}&direction={}&head_direction={}&headonly={}&size=l",
+                hotel.code(),
+                SiteUrl::encode_path_segment(nickname),
+                self.direction,
+                self.direction,
+                u8::from(self.head_only),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_habbo_avatar() {
+        assert_eq!(HabboHotel::from_str("COM.BR").unwrap(), HabboHotel::ComBr);
+        assert!(HabboHotel::from_str("habbo.com").is_err());
+
+        assert!(HabboAvatar::new(None, 8, false).is_err());
+        assert!(HabboAvatar::default().imager_url("Floricultor").is_none());
+
+        let avatar = HabboAvatar::new(Some(HabboHotel::Es), 4, true).unwrap();
+
+        assert_eq!(
+            avatar.imager_url("Flor@!").unwrap(),
+            "https://www.habbo.es/habbo-imaging/avatarimage?user=Flor%40%21&direction=4&head_direction=4&headonly=1&size=l"
+        );
+    }
+}
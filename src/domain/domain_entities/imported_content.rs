@@ -0,0 +1,105 @@
+use chrono::NaiveDateTime as DateTime;
+use std::str::FromStr;
+
+use crate::error::DomainError;
+use crate::libs::time::TimeHelper;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ImportedContentKind {
+    User,
+    Tag,
+    Article,
+    Comment,
+}
+
+impl ImportedContentKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportedContentKind::User => "user",
+            ImportedContentKind::Tag => "tag",
+            ImportedContentKind::Article => "article",
+            ImportedContentKind::Comment => "comment",
+        }
+    }
+}
+
+impl FromStr for ImportedContentKind {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, DomainError> {
+        match s {
+            "user" => Ok(ImportedContentKind::User),
+            "tag" => Ok(ImportedContentKind::Tag),
+            "article" => Ok(ImportedContentKind::Article),
+            "comment" => Ok(ImportedContentKind::Comment),
+            _ => Err(DomainError::enum_coercion_err("ImportedContentKind")),
+        }
+    }
+}
+
+/// Links a record from a legacy platform (e.g. a WordPress post) to the entity it has been imported as,
+/// so running the same import again doesn't duplicate anything.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportedContent {
+    source: String,
+    kind: ImportedContentKind,
+    external_id: String,
+    internal_id: String,
+    imported_at: DateTime,
+}
+
+impl ImportedContent {
+    // CONSTRUCTORS
+    pub fn new(
+        source: String,
+        kind: ImportedContentKind,
+        external_id: String,
+        internal_id: String,
+    ) -> Self {
+        ImportedContent {
+            source,
+            kind,
+            external_id,
+            internal_id,
+            imported_at: TimeHelper::now(),
+        }
+    }
+
+    pub fn new_from_existing(
+        source: String,
+        kind: ImportedContentKind,
+        external_id: String,
+        internal_id: String,
+        imported_at: DateTime,
+    ) -> Self {
+        ImportedContent {
+            source,
+            kind,
+            external_id,
+            internal_id,
+            imported_at,
+        }
+    }
+
+    // GETTERS
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn kind(&self) -> ImportedContentKind {
+        self.kind
+    }
+
+    pub fn external_id(&self) -> &str {
+        &self.external_id
+    }
+
+    /// The id of the imported entity, as a string since tags have integer ids.
+    pub fn internal_id(&self) -> &str {
+        &self.internal_id
+    }
+
+    pub fn imported_at(&self) -> DateTime {
+        self.imported_at
+    }
+}
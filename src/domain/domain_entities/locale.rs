@@ -0,0 +1,83 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::DomainError;
+
+/// A language an article can be read in, one per Habbo hotel we cover.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Locale {
+    /// Habbo.com.br
+    PtBr,
+    /// Habbo.es
+    Es,
+    /// Habbo.com
+    En,
+}
+
+impl Locale {
+    /// The locale articles are written in. Their own title, content and slug are the ones of this locale, so it's
+    /// the one served whenever an article hasn't been translated to the requested locale.
+    pub const FALLBACK: Locale = Locale::PtBr;
+
+    pub const ALL: [Locale; 3] = [Locale::PtBr, Locale::Es, Locale::En];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::PtBr => "pt-BR",
+            Locale::Es => "es",
+            Locale::En => "en",
+        }
+    }
+
+    /// Matches a language tag (e.g. `es-AR`) by its primary language, since each language is spoken by a single
+    /// hotel.
+    pub fn from_language_tag(tag: &str) -> Option<Locale> {
+        let primary_language = tag.split(['-', '_']).next()?.to_lowercase();
+
+        match primary_language.as_str() {
+            "pt" => Some(Locale::PtBr),
+            "es" => Some(Locale::Es),
+            "en" => Some(Locale::En),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, DomainError> {
+        Locale::ALL
+            .into_iter()
+            .find(|locale| locale.code().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                DomainError::enum_coercion_err("Locale").with_message(format!(
+                    "Unsupported locale, expected one of: {}.",
+                    Locale::ALL.map(|locale| locale.code()).join(", ")
+                ))
+            })
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(Locale::from_str("pt-br").unwrap(), Locale::PtBr);
+        assert_eq!(Locale::from_str("ES").unwrap(), Locale::Es);
+        assert!(Locale::from_str("pt").is_err());
+
+        assert_eq!(Locale::from_language_tag("en-US"), Some(Locale::En));
+        assert_eq!(Locale::from_language_tag("es_AR"), Some(Locale::Es));
+        assert_eq!(Locale::from_language_tag("pt-PT"), Some(Locale::PtBr));
+        assert_eq!(Locale::from_language_tag("fr"), None);
+    }
+}
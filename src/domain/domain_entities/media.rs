@@ -0,0 +1,181 @@
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::libs::time::TimeHelper;
+
+const MEDIA_FOLDER: &str = "media";
+
+/// A file of the staff's shared media library (e.g. event banners, announcement and free badge images).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Media {
+    id: Uuid,
+    uploader_id: Uuid,
+    storage_key: String,
+    file_name: String,
+    mime_type: String,
+    size: u64,
+    width: u32,
+    height: u32,
+    alt_text: String,
+    tags: Vec<String>,
+    created_at: DateTime,
+    updated_at: Option<DateTime>,
+}
+
+/// Something that displays a media, which therefore can't be deleted while it's in use.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MediaUsage {
+    Article { id: Uuid, title: String },
+    Announcement { id: Uuid },
+    FreeBadge { id: Uuid, code: String },
+}
+
+/// Dimensions and type of an uploaded file, which are read from the file itself.
+pub struct MediaFileInfo {
+    pub mime_type: String,
+    pub extension: String,
+    pub size: u64,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Media {
+    // CONSTRUCTORS
+    pub fn new(
+        uploader_id: Uuid,
+        file_name: String,
+        file_info: MediaFileInfo,
+        alt_text: String,
+        tags: Vec<String>,
+    ) -> Self {
+        let id = Uuid::new_v4();
+
+        Media {
+            id,
+            uploader_id,
+            storage_key: format!("{MEDIA_FOLDER}/{id}.{}", file_info.extension),
+            file_name,
+            mime_type: file_info.mime_type,
+            size: file_info.size,
+            width: file_info.width,
+            height: file_info.height,
+            alt_text,
+            tags: Self::normalize_tags(tags),
+            created_at: TimeHelper::now(),
+            updated_at: None,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_from_existing(
+        id: Uuid,
+        uploader_id: Uuid,
+        storage_key: String,
+        file_name: String,
+        mime_type: String,
+        size: u64,
+        width: u32,
+        height: u32,
+        alt_text: String,
+        tags: Vec<String>,
+        created_at: DateTime,
+        updated_at: Option<DateTime>,
+    ) -> Self {
+        Media {
+            id,
+            uploader_id,
+            storage_key,
+            file_name,
+            mime_type,
+            size,
+            width,
+            height,
+            alt_text,
+            tags,
+            created_at,
+            updated_at,
+        }
+    }
+
+    // METHODS
+
+    fn touch(&mut self) {
+        self.updated_at = Some(TimeHelper::now());
+    }
+
+    /// Tags are case-insensitive, so they're kept trimmed, lowercase, sorted and without duplicates.
+    pub fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+        let mut tags: Vec<String> = tags
+            .into_iter()
+            .map(|tag| tag.trim().to_lowercase())
+            .filter(|tag| !tag.is_empty())
+            .collect();
+
+        tags.sort();
+        tags.dedup();
+
+        tags
+    }
+
+    // GETTERS
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn uploader_id(&self) -> Uuid {
+        self.uploader_id
+    }
+
+    pub fn storage_key(&self) -> &str {
+        self.storage_key.as_ref()
+    }
+
+    pub fn file_name(&self) -> &str {
+        self.file_name.as_ref()
+    }
+
+    pub fn mime_type(&self) -> &str {
+        self.mime_type.as_ref()
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn alt_text(&self) -> &str {
+        self.alt_text.as_ref()
+    }
+
+    pub fn tags(&self) -> &[String] {
+        self.tags.as_ref()
+    }
+
+    pub fn created_at(&self) -> DateTime {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> Option<DateTime> {
+        self.updated_at
+    }
+
+    // SETTERS
+
+    pub fn set_alt_text(&mut self, alt_text: String) {
+        self.alt_text = alt_text;
+        self.touch();
+    }
+
+    pub fn set_tags(&mut self, tags: Vec<String>) {
+        self.tags = Self::normalize_tags(tags);
+        self.touch();
+    }
+}
@@ -1,12 +1,26 @@
 pub mod announcement;
 pub mod article;
+pub mod article_coauthor;
+pub mod article_cover;
 pub mod article_tag;
+pub mod article_translation;
+pub mod blocked_word;
 pub mod comment;
+pub mod comment_edit;
+pub mod comment_mention;
 pub mod comment_report;
+pub mod comment_review;
 pub mod comment_with_author;
 pub mod free_badge;
+pub mod habbo_avatar;
+pub mod imported_content;
+pub mod locale;
+pub mod media;
+pub mod reaction;
 pub mod role;
+pub mod series;
 pub mod slug;
 pub mod team_role;
 pub mod team_user;
 pub mod user;
+pub mod wordpress_export;
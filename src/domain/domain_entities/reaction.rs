@@ -0,0 +1,119 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::error::DomainError;
+use crate::libs::time::TimeHelper;
+
+/// What can be reacted to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ReactionTarget {
+    Article,
+    Comment,
+}
+
+impl ReactionTarget {
+    pub const ALL: [ReactionTarget; 2] = [ReactionTarget::Article, ReactionTarget::Comment];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            ReactionTarget::Article => "article",
+            ReactionTarget::Comment => "comment",
+        }
+    }
+}
+
+impl FromStr for ReactionTarget {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, DomainError> {
+        ReactionTarget::ALL
+            .into_iter()
+            .find(|target| target.code().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                DomainError::enum_coercion_err("ReactionTarget").with_message(format!(
+                    "Unsupported reaction target, expected one of: {}.",
+                    ReactionTarget::ALL.map(|target| target.code()).join(", ")
+                ))
+            })
+    }
+}
+
+impl Display for ReactionTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// A user's reaction to an article or a comment. Users have at most one reaction per target, so reacting again
+/// replaces it.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Reaction {
+    user_id: Uuid,
+    target: ReactionTarget,
+    target_id: Uuid,
+    kind: String,
+    created_at: DateTime,
+}
+
+impl Reaction {
+    // CONSTRUCTORS
+    pub fn new(user_id: Uuid, target: ReactionTarget, target_id: Uuid, kind: String) -> Self {
+        Reaction {
+            user_id,
+            target,
+            target_id,
+            kind,
+            created_at: TimeHelper::now(),
+        }
+    }
+
+    pub fn new_from_existing(
+        user_id: Uuid,
+        target: ReactionTarget,
+        target_id: Uuid,
+        kind: String,
+        created_at: DateTime,
+    ) -> Self {
+        Reaction {
+            user_id,
+            target,
+            target_id,
+            kind,
+            created_at,
+        }
+    }
+
+    // GETTERS
+    pub fn user_id(&self) -> Uuid {
+        self.user_id
+    }
+
+    pub fn target(&self) -> ReactionTarget {
+        self.target
+    }
+
+    pub fn target_id(&self) -> Uuid {
+        self.target_id
+    }
+
+    /// One of the configured reactions, e.g. "heart".
+    pub fn kind(&self) -> &str {
+        self.kind.as_ref()
+    }
+
+    pub fn created_at(&self) -> DateTime {
+        self.created_at
+    }
+}
+
+/// How many times an article or a comment has been reacted to with each reaction, along with the reaction of the
+/// user asking for it, if any.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ReactionSummary {
+    pub counts: BTreeMap<String, u64>,
+    pub own: Option<String>,
+}
@@ -0,0 +1,105 @@
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::libs::time::TimeHelper;
+
+use super::slug::Slug;
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Series {
+    id: Uuid,
+    author_id: Uuid,
+    title: String,
+    description: String,
+    slug: Slug,
+    created_at: DateTime,
+    updated_at: Option<DateTime>,
+}
+
+impl Series {
+    // CONSTRUCTORS
+    pub fn new(author_id: Uuid, title: String, description: String) -> Self {
+        let id = Uuid::new_v4();
+        let slug = Slug::new(id, title.clone());
+
+        Series {
+            id,
+            author_id,
+            title,
+            description,
+            slug,
+            created_at: TimeHelper::now(),
+            updated_at: None,
+        }
+    }
+
+    pub fn new_from_existing(
+        id: Uuid,
+        author_id: Uuid,
+        title: String,
+        description: String,
+        slug: Slug,
+        created_at: DateTime,
+        updated_at: Option<DateTime>,
+    ) -> Self {
+        Series {
+            id,
+            author_id,
+            title,
+            description,
+            slug,
+            created_at,
+            updated_at,
+        }
+    }
+
+    // METHODS
+
+    fn touch(&mut self) {
+        self.updated_at = Some(TimeHelper::now());
+    }
+
+    // GETTERS
+
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn author_id(&self) -> Uuid {
+        self.author_id
+    }
+
+    pub fn title(&self) -> &str {
+        self.title.as_ref()
+    }
+
+    pub fn description(&self) -> &str {
+        self.description.as_ref()
+    }
+
+    pub fn slug(&self) -> Slug {
+        self.slug.clone()
+    }
+
+    pub fn created_at(&self) -> DateTime {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> Option<DateTime> {
+        self.updated_at
+    }
+
+    // SETTERS
+
+    pub fn set_title(&mut self, title: String) {
+        self.title = title.clone();
+        self.slug = Slug::new(self.id, title);
+
+        self.touch();
+    }
+
+    pub fn set_description(&mut self, description: String) {
+        self.description = description;
+        self.touch();
+    }
+}
@@ -2,6 +2,7 @@ use crate::libs::time::TimeHelper;
 use chrono::NaiveDateTime as DateTime;
 use uuid::Uuid;
 
+use super::habbo_avatar::HabboAvatar;
 use super::role::Role;
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -12,9 +13,18 @@ pub struct User {
     created_at: DateTime,
     last_login: Option<DateTime>,
     role: Option<Role>,
+    bio: Option<String>,
+    avatar: HabboAvatar,
 }
 
 impl User {
+    /// The account the content of removed users is handed over to. No one can sign up with its nickname, as
+    /// nicknames can't have spaces nor accents, or sign in to it, as its password is unusable.
+    pub const REMOVED_USER_NICKNAME: &'static str = "usuário removido";
+
+    /// Not a valid password hash, so no password will ever match it.
+    pub const UNUSABLE_PASSWORD: &'static str = "!";
+
     // CONSTRUCTOR
     pub fn new(nickname: String, password: String, role: Option<Role>) -> Self {
         let id = Uuid::new_v4();
@@ -29,9 +39,20 @@ impl User {
             created_at,
             last_login,
             role,
+            bio: None,
+            avatar: HabboAvatar::default(),
         }
     }
 
+    pub fn new_removed_user_placeholder() -> Self {
+        User::new(
+            Self::REMOVED_USER_NICKNAME.into(),
+            Self::UNUSABLE_PASSWORD.into(),
+            Some(Role::User),
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn new_from_existing(
         id: Uuid,
         nickname: String,
@@ -39,6 +60,8 @@ impl User {
         created_at: DateTime,
         last_login: Option<DateTime>,
         role: Option<Role>,
+        bio: Option<String>,
+        avatar: HabboAvatar,
     ) -> Self {
         User {
             id,
@@ -47,6 +70,8 @@ impl User {
             created_at,
             last_login,
             role,
+            bio,
+            avatar,
         }
     }
 
@@ -75,6 +100,14 @@ impl User {
         self.role.clone()
     }
 
+    pub fn bio(&self) -> Option<&str> {
+        self.bio.as_deref()
+    }
+
+    pub fn avatar(&self) -> &HabboAvatar {
+        &self.avatar
+    }
+
     // SETTERS
 
     pub fn set_nickname(&mut self, nickname: String) {
@@ -92,4 +125,12 @@ impl User {
     pub fn set_password(&mut self, password: String) {
         self.password = password;
     }
+
+    pub fn set_bio(&mut self, bio: Option<String>) {
+        self.bio = bio;
+    }
+
+    pub fn set_avatar(&mut self, avatar: HabboAvatar) {
+        self.avatar = avatar;
+    }
 }
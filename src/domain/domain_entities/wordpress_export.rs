@@ -0,0 +1,56 @@
+use chrono::NaiveDateTime as DateTime;
+
+/// The content of a WordPress export (WXR) file that can be imported into Samambaia.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WordpressExport {
+    /// The exported site's URL, which tells imports of different sites apart.
+    pub site_url: String,
+    pub authors: Vec<WordpressAuthor>,
+    pub categories: Vec<WordpressCategory>,
+    /// Every exported item, including pages and attachments, which aren't imported.
+    pub posts: Vec<WordpressPost>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WordpressAuthor {
+    pub id: String,
+    pub login: String,
+    pub display_name: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WordpressCategory {
+    pub slug: String,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WordpressPost {
+    pub id: String,
+    pub post_type: String,
+    /// e.g. `publish`, `draft`, `pending`, `private` or `trash`.
+    pub status: String,
+    pub title: String,
+    pub content: String,
+    pub author_login: String,
+    pub published_at: Option<DateTime>,
+    pub modified_at: Option<DateTime>,
+    /// Slugs of the post's categories, in the order they were exported.
+    pub category_slugs: Vec<String>,
+    /// The URL of the post's featured image, if it has one.
+    pub cover_url: Option<String>,
+    pub comments: Vec<WordpressComment>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WordpressComment {
+    pub id: String,
+    pub author_name: String,
+    pub author_email: String,
+    /// The id of the commenting author, or `0` for guests.
+    pub user_id: String,
+    pub content: String,
+    /// `1` for approved comments, `0` for held ones, `spam` or `trash`.
+    pub approved: String,
+    pub created_at: Option<DateTime>,
+}
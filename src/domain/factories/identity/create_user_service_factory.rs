@@ -1,13 +1,19 @@
 use crate::domain::services::identity::create_user_service::CreateUserService;
 use crate::infra::cryptography::PasswordAuthHasherAndVerifier;
+use crate::infra::sea::repositories::sea_blocked_word_repository::SeaBlockedWordRepository;
 use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
 use crate::infra::sea::sea_service::SeaService;
 
 pub fn exec(
     db_conn: &SeaService,
-) -> CreateUserService<SeaUserRepository, PasswordAuthHasherAndVerifier> {
+) -> CreateUserService<
+    SeaUserRepository<'_>,
+    PasswordAuthHasherAndVerifier,
+    SeaBlockedWordRepository<'_>,
+> {
     let user_repository = SeaUserRepository::new(db_conn);
     let hasher = PasswordAuthHasherAndVerifier;
+    let blocked_word_repository = SeaBlockedWordRepository::new(db_conn);
 
-    CreateUserService::new(user_repository, hasher)
+    CreateUserService::new(user_repository, hasher, blocked_word_repository)
 }
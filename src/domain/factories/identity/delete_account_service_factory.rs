@@ -0,0 +1,24 @@
+use crate::domain::services::identity::delete_account_service::DeleteAccountService;
+use crate::infra::cache::in_memory_related_articles_cache::{
+    InMemoryRelatedArticlesCache, RELATED_ARTICLES_CACHE,
+};
+use crate::infra::cryptography::PasswordAuthHasherAndVerifier;
+use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(
+    db_conn: &SeaService,
+) -> DeleteAccountService<
+    SeaUserRepository<'_>,
+    PasswordAuthHasherAndVerifier,
+    InMemoryRelatedArticlesCache,
+> {
+    let user_repository = SeaUserRepository::new(db_conn);
+    let hasher_and_comparer = PasswordAuthHasherAndVerifier;
+
+    DeleteAccountService::new(
+        user_repository,
+        hasher_and_comparer,
+        RELATED_ARTICLES_CACHE.clone(),
+    )
+}
@@ -0,0 +1,27 @@
+use crate::domain::services::identity::export_personal_data_service::ExportPersonalDataService;
+use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::repositories::sea_comment_report_repository::SeaCommentReportRepository;
+use crate::infra::sea::repositories::sea_comment_repository::SeaCommentRepository;
+use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(
+    db_conn: &SeaService,
+) -> ExportPersonalDataService<
+    SeaUserRepository<'_>,
+    SeaCommentRepository<'_>,
+    SeaCommentReportRepository<'_>,
+    SeaArticleRepository<'_>,
+> {
+    let user_repository = SeaUserRepository::new(db_conn);
+    let comment_repository = SeaCommentRepository::new(db_conn);
+    let comment_report_repository = SeaCommentReportRepository::new(db_conn);
+    let article_repository = SeaArticleRepository::new(db_conn);
+
+    ExportPersonalDataService::new(
+        user_repository,
+        comment_repository,
+        comment_report_repository,
+        article_repository,
+    )
+}
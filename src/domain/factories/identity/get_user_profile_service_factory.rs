@@ -0,0 +1,31 @@
+use crate::domain::services::identity::get_user_profile_service::GetUserProfileService;
+use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::repositories::sea_comment_repository::SeaCommentRepository;
+use crate::infra::sea::repositories::sea_reaction_repository::SeaReactionRepository;
+use crate::infra::sea::repositories::sea_team_user_repository::SeaTeamUserRepository;
+use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(
+    db_conn: &SeaService,
+) -> GetUserProfileService<
+    SeaUserRepository<'_>,
+    SeaTeamUserRepository<'_>,
+    SeaArticleRepository<'_>,
+    SeaCommentRepository<'_>,
+    SeaReactionRepository<'_>,
+> {
+    let user_repository = SeaUserRepository::new(db_conn);
+    let team_user_repository = SeaTeamUserRepository::new(db_conn);
+    let article_repository = SeaArticleRepository::new(db_conn);
+    let comment_repository = SeaCommentRepository::new(db_conn);
+    let reaction_repository = SeaReactionRepository::new(db_conn);
+
+    GetUserProfileService::new(
+        user_repository,
+        team_user_repository,
+        article_repository,
+        comment_repository,
+        reaction_repository,
+    )
+}
@@ -1,6 +1,10 @@
 pub mod authenticate_user_service_factory;
 pub mod change_password_service_factory;
 pub mod create_user_service_factory;
+pub mod delete_account_service_factory;
+pub mod export_personal_data_service_factory;
 pub mod fetch_many_users_service_factory;
+pub mod get_user_profile_service_factory;
 pub mod get_user_service_factory;
+pub mod update_profile_service_factory;
 pub mod update_user_service_factory;
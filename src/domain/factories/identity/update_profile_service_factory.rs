@@ -0,0 +1,8 @@
+use crate::domain::services::identity::update_profile_service::UpdateProfileService;
+use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(db_conn: &SeaService) -> UpdateProfileService<SeaUserRepository<'_>> {
+    let user_repository = SeaUserRepository::new(db_conn);
+    UpdateProfileService::new(user_repository)
+}
@@ -0,0 +1,36 @@
+use crate::domain::services::imports::import_wordpress_service::ImportWordpressService;
+use crate::infra::cache::in_memory_related_articles_cache::{
+    InMemoryRelatedArticlesCache, RELATED_ARTICLES_CACHE,
+};
+use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::repositories::sea_article_tag_repository::SeaArticleTagRepository;
+use crate::infra::sea::repositories::sea_comment_repository::SeaCommentRepository;
+use crate::infra::sea::repositories::sea_imported_content_repository::SeaImportedContentRepository;
+use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(
+    db_conn: &SeaService,
+) -> ImportWordpressService<
+    SeaUserRepository<'_>,
+    SeaArticleTagRepository<'_>,
+    SeaArticleRepository<'_>,
+    SeaCommentRepository<'_>,
+    SeaImportedContentRepository<'_>,
+    InMemoryRelatedArticlesCache,
+> {
+    let user_repository = SeaUserRepository::new(db_conn);
+    let article_tag_repository = SeaArticleTagRepository::new(db_conn);
+    let article_repository = SeaArticleRepository::new(db_conn);
+    let comment_repository = SeaCommentRepository::new(db_conn);
+    let imported_content_repository = SeaImportedContentRepository::new(db_conn);
+
+    ImportWordpressService::new(
+        user_repository,
+        article_tag_repository,
+        article_repository,
+        comment_repository,
+        imported_content_repository,
+        RELATED_ARTICLES_CACHE.clone(),
+    )
+}
@@ -0,0 +1 @@
+pub mod import_wordpress_service_factory;
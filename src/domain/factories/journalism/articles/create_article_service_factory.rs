@@ -1,4 +1,7 @@
 use crate::domain::services::journalism::articles::create_article_service::CreateArticleService;
+use crate::infra::cache::in_memory_related_articles_cache::{
+    InMemoryRelatedArticlesCache, RELATED_ARTICLES_CACHE,
+};
 use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
 use crate::infra::sea::repositories::sea_article_tag_repository::SeaArticleTagRepository;
 use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
@@ -6,7 +9,12 @@ use crate::infra::sea::sea_service::SeaService;
 
 pub fn exec(
     db_conn: &SeaService,
-) -> CreateArticleService<SeaArticleRepository, SeaArticleTagRepository, SeaUserRepository> {
+) -> CreateArticleService<
+    SeaArticleRepository,
+    SeaArticleTagRepository,
+    SeaUserRepository,
+    InMemoryRelatedArticlesCache,
+> {
     let sea_article_repository = SeaArticleRepository::new(db_conn);
     let sea_article_tag_repository = SeaArticleTagRepository::new(db_conn);
     let sea_user_repository = SeaUserRepository::new(db_conn);
@@ -15,5 +23,6 @@ pub fn exec(
         sea_article_repository,
         sea_article_tag_repository,
         sea_user_repository,
+        RELATED_ARTICLES_CACHE.clone(),
     )
 }
@@ -1,4 +1,7 @@
 use crate::domain::services::journalism::articles::delete_article_service::DeleteArticleService;
+use crate::infra::cache::in_memory_related_articles_cache::{
+    InMemoryRelatedArticlesCache, RELATED_ARTICLES_CACHE,
+};
 use crate::infra::sea::repositories::sea_article_comment_repository::SeaArticleCommentRepository;
 use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
 use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
@@ -6,7 +9,12 @@ use crate::infra::sea::sea_service::SeaService;
 
 pub fn exec(
     db_conn: &SeaService,
-) -> DeleteArticleService<SeaArticleRepository, SeaArticleCommentRepository, SeaUserRepository> {
+) -> DeleteArticleService<
+    SeaArticleRepository,
+    SeaArticleCommentRepository,
+    SeaUserRepository,
+    InMemoryRelatedArticlesCache,
+> {
     let user_repository = SeaUserRepository::new(db_conn);
     let article_comment_repository = SeaArticleCommentRepository::new(db_conn);
     let article_repository = SeaArticleRepository::new(db_conn);
@@ -15,5 +23,6 @@ pub fn exec(
         article_repository,
         article_comment_repository,
         user_repository,
+        RELATED_ARTICLES_CACHE.clone(),
     )
 }
@@ -0,0 +1,19 @@
+use crate::domain::services::journalism::articles::fetch_articles_feed_service::FetchArticlesFeedService;
+use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::repositories::sea_article_tag_repository::SeaArticleTagRepository;
+use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(
+    db_conn: &SeaService,
+) -> FetchArticlesFeedService<
+    SeaArticleRepository<'_>,
+    SeaArticleTagRepository<'_>,
+    SeaUserRepository<'_>,
+> {
+    let article_repository = SeaArticleRepository::new(db_conn);
+    let article_tag_repository = SeaArticleTagRepository::new(db_conn);
+    let user_repository = SeaUserRepository::new(db_conn);
+
+    FetchArticlesFeedService::new(article_repository, article_tag_repository, user_repository)
+}
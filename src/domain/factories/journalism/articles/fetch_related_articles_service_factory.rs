@@ -0,0 +1,15 @@
+use crate::domain::services::journalism::articles::fetch_related_articles_service::FetchRelatedArticlesService;
+use crate::infra::cache::in_memory_related_articles_cache::{
+    InMemoryRelatedArticlesCache, RELATED_ARTICLES_CACHE,
+};
+use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(
+    db_conn: &SeaService,
+) -> FetchRelatedArticlesService<SeaArticleRepository<'_>, InMemoryRelatedArticlesCache> {
+    let article_repository = SeaArticleRepository::new(db_conn);
+    let related_articles_cache = RELATED_ARTICLES_CACHE.clone();
+
+    FetchRelatedArticlesService::new(article_repository, related_articles_cache)
+}
@@ -1,6 +1,12 @@
 use crate::domain::services::journalism::articles::get_expanded_article_service::GetExpandedArticleService;
+use crate::infra::cache::in_memory_related_articles_cache::{
+    InMemoryRelatedArticlesCache, RELATED_ARTICLES_CACHE,
+};
+use crate::infra::sea::repositories::sea_article_coauthor_repository::SeaArticleCoauthorRepository;
 use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::repositories::sea_article_translation_repository::SeaArticleTranslationRepository;
 use crate::infra::sea::repositories::sea_comment_user_article_repository::SeaCommentUserArticleRepository;
+use crate::infra::sea::repositories::sea_series_repository::SeaSeriesRepository;
 use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
 use crate::infra::sea::sea_service::SeaService;
 
@@ -10,14 +16,26 @@ pub fn exec(
     SeaUserRepository,
     SeaArticleRepository,
     SeaCommentUserArticleRepository,
+    SeaSeriesRepository,
+    SeaArticleCoauthorRepository,
+    SeaArticleTranslationRepository,
+    InMemoryRelatedArticlesCache,
 > {
     let user_repository = SeaUserRepository::new(db_conn);
     let article_repository = SeaArticleRepository::new(db_conn);
     let comment_user_article_repository = SeaCommentUserArticleRepository::new(db_conn);
+    let series_repository = SeaSeriesRepository::new(db_conn);
+    let article_coauthor_repository = SeaArticleCoauthorRepository::new(db_conn);
+    let article_translation_repository = SeaArticleTranslationRepository::new(db_conn);
+    let related_articles_cache = RELATED_ARTICLES_CACHE.clone();
 
     GetExpandedArticleService::new(
         user_repository,
         article_repository,
         comment_user_article_repository,
+        series_repository,
+        article_coauthor_repository,
+        article_translation_repository,
+        related_articles_cache,
     )
 }
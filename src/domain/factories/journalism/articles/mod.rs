@@ -1,6 +1,15 @@
 pub mod create_article_service_factory;
 pub mod delete_article_service_factory;
+pub mod delete_article_translation_service_factory;
+pub mod fetch_articles_feed_service_factory;
 pub mod fetch_home_page_articles_service_factory;
 pub mod fetch_many_articles_service_factory;
+pub mod fetch_related_articles_service_factory;
 pub mod get_expanded_article_service_factory;
+pub mod pin_article_service_factory;
+pub mod resolve_article_covers_service_factory;
+pub mod set_article_comment_mode_service_factory;
+pub mod translate_articles_service_factory;
 pub mod update_article_service_factory;
+pub mod upload_article_cover_service_factory;
+pub mod upsert_article_translation_service_factory;
@@ -0,0 +1,8 @@
+use crate::domain::services::journalism::articles::pin_article_service::PinArticleService;
+use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(db_conn: &SeaService) -> PinArticleService<SeaArticleRepository<'_>> {
+    let article_repository = SeaArticleRepository::new(db_conn);
+    PinArticleService::new(article_repository)
+}
@@ -0,0 +1,7 @@
+use crate::domain::services::journalism::articles::resolve_article_covers_service::ResolveArticleCoversService;
+use crate::infra::storage::local_file_storage::LocalFileStorage;
+
+pub fn exec() -> ResolveArticleCoversService<LocalFileStorage> {
+    let file_storage = LocalFileStorage::new();
+    ResolveArticleCoversService::new(file_storage)
+}
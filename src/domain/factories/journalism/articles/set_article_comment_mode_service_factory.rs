@@ -0,0 +1,8 @@
+use crate::domain::services::journalism::articles::set_article_comment_mode_service::SetArticleCommentModeService;
+use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(db_conn: &SeaService) -> SetArticleCommentModeService<SeaArticleRepository<'_>> {
+    let article_repository = SeaArticleRepository::new(db_conn);
+    SetArticleCommentModeService::new(article_repository)
+}
@@ -0,0 +1,8 @@
+use crate::domain::services::journalism::articles::translate_articles_service::TranslateArticlesService;
+use crate::infra::sea::repositories::sea_article_translation_repository::SeaArticleTranslationRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(db_conn: &SeaService) -> TranslateArticlesService<SeaArticleTranslationRepository<'_>> {
+    let article_translation_repository = SeaArticleTranslationRepository::new(db_conn);
+    TranslateArticlesService::new(article_translation_repository)
+}
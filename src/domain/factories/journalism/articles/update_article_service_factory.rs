@@ -1,13 +1,32 @@
 use crate::domain::services::journalism::articles::update_article_service::UpdateArticleService;
+use crate::infra::cache::in_memory_related_articles_cache::{
+    InMemoryRelatedArticlesCache, RELATED_ARTICLES_CACHE,
+};
+use crate::infra::sea::repositories::sea_article_coauthor_repository::SeaArticleCoauthorRepository;
 use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
 use crate::infra::sea::repositories::sea_article_tag_repository::SeaArticleTagRepository;
+use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
 use crate::infra::sea::sea_service::SeaService;
 
 pub fn exec(
     db_conn: &SeaService,
-) -> UpdateArticleService<SeaArticleRepository, SeaArticleTagRepository> {
+) -> UpdateArticleService<
+    SeaArticleRepository<'_>,
+    SeaArticleTagRepository<'_>,
+    SeaArticleCoauthorRepository<'_>,
+    SeaUserRepository<'_>,
+    InMemoryRelatedArticlesCache,
+> {
     let article_repository = SeaArticleRepository::new(db_conn);
     let article_tag_repository = SeaArticleTagRepository::new(db_conn);
+    let article_coauthor_repository = SeaArticleCoauthorRepository::new(db_conn);
+    let user_repository = SeaUserRepository::new(db_conn);
 
-    UpdateArticleService::new(article_repository, article_tag_repository)
+    UpdateArticleService::new(
+        article_repository,
+        article_tag_repository,
+        article_coauthor_repository,
+        user_repository,
+        RELATED_ARTICLES_CACHE.clone(),
+    )
 }
@@ -0,0 +1,7 @@
+use crate::domain::services::journalism::articles::upload_article_cover_service::UploadArticleCoverService;
+use crate::infra::storage::local_file_storage::LocalFileStorage;
+
+pub fn exec() -> UploadArticleCoverService<LocalFileStorage> {
+    let file_storage = LocalFileStorage::new();
+    UploadArticleCoverService::new(file_storage)
+}
@@ -0,0 +1,23 @@
+use crate::domain::services::journalism::articles::upsert_article_translation_service::UpsertArticleTranslationService;
+use crate::infra::sea::repositories::sea_article_coauthor_repository::SeaArticleCoauthorRepository;
+use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::repositories::sea_article_translation_repository::SeaArticleTranslationRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(
+    db_conn: &SeaService,
+) -> UpsertArticleTranslationService<
+    SeaArticleRepository<'_>,
+    SeaArticleTranslationRepository<'_>,
+    SeaArticleCoauthorRepository<'_>,
+> {
+    let article_repository = SeaArticleRepository::new(db_conn);
+    let article_translation_repository = SeaArticleTranslationRepository::new(db_conn);
+    let article_coauthor_repository = SeaArticleCoauthorRepository::new(db_conn);
+
+    UpsertArticleTranslationService::new(
+        article_repository,
+        article_translation_repository,
+        article_coauthor_repository,
+    )
+}
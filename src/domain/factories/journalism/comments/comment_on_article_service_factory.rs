@@ -1,13 +1,39 @@
 use crate::domain::services::journalism::comments::comment_on_article_service::CommentOnArticleService;
 use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::repositories::sea_blocked_word_repository::SeaBlockedWordRepository;
+use crate::infra::sea::repositories::sea_comment_mention_repository::SeaCommentMentionRepository;
+use crate::infra::sea::repositories::sea_comment_report_repository::SeaCommentReportRepository;
 use crate::infra::sea::repositories::sea_comment_repository::SeaCommentRepository;
+use crate::infra::sea::repositories::sea_comment_review_repository::SeaCommentReviewRepository;
+use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
 use crate::infra::sea::sea_service::SeaService;
 
 pub fn exec(
     db_conn: &SeaService,
-) -> CommentOnArticleService<SeaCommentRepository, SeaArticleRepository> {
+) -> CommentOnArticleService<
+    SeaCommentRepository<'_>,
+    SeaArticleRepository<'_>,
+    SeaUserRepository<'_>,
+    SeaCommentMentionRepository<'_>,
+    SeaBlockedWordRepository<'_>,
+    SeaCommentReportRepository<'_>,
+    SeaCommentReviewRepository<'_>,
+> {
     let comment_repository = SeaCommentRepository::new(db_conn);
     let article_repository = SeaArticleRepository::new(db_conn);
+    let user_repository = SeaUserRepository::new(db_conn);
+    let comment_mention_repository = SeaCommentMentionRepository::new(db_conn);
+    let blocked_word_repository = SeaBlockedWordRepository::new(db_conn);
+    let comment_report_repository = SeaCommentReportRepository::new(db_conn);
+    let comment_review_repository = SeaCommentReviewRepository::new(db_conn);
 
-    CommentOnArticleService::new(comment_repository, article_repository)
+    CommentOnArticleService::new(
+        comment_repository,
+        article_repository,
+        user_repository,
+        comment_mention_repository,
+        blocked_word_repository,
+        comment_report_repository,
+        comment_review_repository,
+    )
 }
@@ -0,0 +1,35 @@
+use crate::domain::services::journalism::comments::edit_comment_service::EditCommentService;
+use crate::infra::sea::repositories::sea_blocked_word_repository::SeaBlockedWordRepository;
+use crate::infra::sea::repositories::sea_comment_edit_repository::SeaCommentEditRepository;
+use crate::infra::sea::repositories::sea_comment_mention_repository::SeaCommentMentionRepository;
+use crate::infra::sea::repositories::sea_comment_report_repository::SeaCommentReportRepository;
+use crate::infra::sea::repositories::sea_comment_repository::SeaCommentRepository;
+use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(
+    db_conn: &SeaService,
+) -> EditCommentService<
+    SeaCommentRepository<'_>,
+    SeaCommentEditRepository<'_>,
+    SeaUserRepository<'_>,
+    SeaCommentMentionRepository<'_>,
+    SeaBlockedWordRepository<'_>,
+    SeaCommentReportRepository<'_>,
+> {
+    let comment_repository = SeaCommentRepository::new(db_conn);
+    let comment_edit_repository = SeaCommentEditRepository::new(db_conn);
+    let user_repository = SeaUserRepository::new(db_conn);
+    let comment_mention_repository = SeaCommentMentionRepository::new(db_conn);
+    let blocked_word_repository = SeaBlockedWordRepository::new(db_conn);
+    let comment_report_repository = SeaCommentReportRepository::new(db_conn);
+
+    EditCommentService::new(
+        comment_repository,
+        comment_edit_repository,
+        user_repository,
+        comment_mention_repository,
+        blocked_word_repository,
+        comment_report_repository,
+    )
+}
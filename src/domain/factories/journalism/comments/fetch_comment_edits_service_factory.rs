@@ -0,0 +1,9 @@
+use crate::domain::services::journalism::comments::fetch_comment_edits_service::FetchCommentEditsService;
+use crate::infra::sea::repositories::sea_comment_edit_repository::SeaCommentEditRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(db_conn: &SeaService) -> FetchCommentEditsService<SeaCommentEditRepository<'_>> {
+    let comment_edit_repository = SeaCommentEditRepository::new(db_conn);
+
+    FetchCommentEditsService::new(comment_edit_repository)
+}
@@ -0,0 +1,9 @@
+use crate::domain::services::journalism::comments::fetch_user_mentions_service::FetchUserMentionsService;
+use crate::infra::sea::repositories::sea_comment_mention_repository::SeaCommentMentionRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(db_conn: &SeaService) -> FetchUserMentionsService<SeaCommentMentionRepository<'_>> {
+    let comment_mention_repository = SeaCommentMentionRepository::new(db_conn);
+
+    FetchUserMentionsService::new(comment_mention_repository)
+}
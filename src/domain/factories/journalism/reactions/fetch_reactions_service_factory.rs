@@ -0,0 +1,9 @@
+use crate::domain::services::journalism::reactions::fetch_reactions_service::FetchReactionsService;
+use crate::infra::sea::repositories::sea_reaction_repository::SeaReactionRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(db_conn: &SeaService) -> FetchReactionsService<SeaReactionRepository<'_>> {
+    let reaction_repository = SeaReactionRepository::new(db_conn);
+
+    FetchReactionsService::new(reaction_repository)
+}
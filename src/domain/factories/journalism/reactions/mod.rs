@@ -0,0 +1,2 @@
+pub mod fetch_reactions_service_factory;
+pub mod react_service_factory;
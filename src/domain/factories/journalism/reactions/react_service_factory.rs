@@ -0,0 +1,15 @@
+use crate::domain::services::journalism::reactions::react_service::ReactService;
+use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::repositories::sea_comment_repository::SeaCommentRepository;
+use crate::infra::sea::repositories::sea_reaction_repository::SeaReactionRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(
+    db_conn: &SeaService,
+) -> ReactService<SeaReactionRepository<'_>, SeaArticleRepository<'_>, SeaCommentRepository<'_>> {
+    let reaction_repository = SeaReactionRepository::new(db_conn);
+    let article_repository = SeaArticleRepository::new(db_conn);
+    let comment_repository = SeaCommentRepository::new(db_conn);
+
+    ReactService::new(reaction_repository, article_repository, comment_repository)
+}
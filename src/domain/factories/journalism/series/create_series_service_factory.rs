@@ -0,0 +1,13 @@
+use crate::domain::services::journalism::series::create_series_service::CreateSeriesService;
+use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::repositories::sea_series_repository::SeaSeriesRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(
+    db_conn: &SeaService,
+) -> CreateSeriesService<SeaSeriesRepository<'_>, SeaArticleRepository<'_>> {
+    let series_repository = SeaSeriesRepository::new(db_conn);
+    let article_repository = SeaArticleRepository::new(db_conn);
+
+    CreateSeriesService::new(series_repository, article_repository)
+}
@@ -0,0 +1,8 @@
+use crate::domain::services::journalism::series::delete_series_service::DeleteSeriesService;
+use crate::infra::sea::repositories::sea_series_repository::SeaSeriesRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(db_conn: &SeaService) -> DeleteSeriesService<SeaSeriesRepository<'_>> {
+    let series_repository = SeaSeriesRepository::new(db_conn);
+    DeleteSeriesService::new(series_repository)
+}
@@ -0,0 +1,8 @@
+use crate::domain::services::journalism::series::get_series_service::GetSeriesService;
+use crate::infra::sea::repositories::sea_series_repository::SeaSeriesRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(db_conn: &SeaService) -> GetSeriesService<SeaSeriesRepository<'_>> {
+    let series_repository = SeaSeriesRepository::new(db_conn);
+    GetSeriesService::new(series_repository)
+}
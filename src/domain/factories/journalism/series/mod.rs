@@ -0,0 +1,4 @@
+pub mod create_series_service_factory;
+pub mod delete_series_service_factory;
+pub mod get_series_service_factory;
+pub mod update_series_service_factory;
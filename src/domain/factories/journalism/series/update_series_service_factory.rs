@@ -0,0 +1,13 @@
+use crate::domain::services::journalism::series::update_series_service::UpdateSeriesService;
+use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::repositories::sea_series_repository::SeaSeriesRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(
+    db_conn: &SeaService,
+) -> UpdateSeriesService<SeaSeriesRepository<'_>, SeaArticleRepository<'_>> {
+    let series_repository = SeaSeriesRepository::new(db_conn);
+    let article_repository = SeaArticleRepository::new(db_conn);
+
+    UpdateSeriesService::new(series_repository, article_repository)
+}
@@ -0,0 +1,11 @@
+use crate::domain::services::media::delete_media_service::DeleteMediaService;
+use crate::infra::sea::repositories::sea_media_repository::SeaMediaRepository;
+use crate::infra::sea::sea_service::SeaService;
+use crate::infra::storage::local_file_storage::LocalFileStorage;
+
+pub fn exec(db_conn: &SeaService) -> DeleteMediaService<SeaMediaRepository<'_>, LocalFileStorage> {
+    let media_repository = SeaMediaRepository::new(db_conn);
+    let file_storage = LocalFileStorage::new();
+
+    DeleteMediaService::new(media_repository, file_storage)
+}
@@ -0,0 +1,8 @@
+use crate::domain::services::media::fetch_many_media_service::FetchManyMediaService;
+use crate::infra::sea::repositories::sea_media_repository::SeaMediaRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(db_conn: &SeaService) -> FetchManyMediaService<SeaMediaRepository<'_>> {
+    let media_repository = SeaMediaRepository::new(db_conn);
+    FetchManyMediaService::new(media_repository)
+}
@@ -0,0 +1,11 @@
+use crate::domain::services::media::get_media_service::GetMediaService;
+use crate::infra::sea::repositories::sea_media_repository::SeaMediaRepository;
+use crate::infra::sea::sea_service::SeaService;
+use crate::infra::storage::local_file_storage::LocalFileStorage;
+
+pub fn exec(db_conn: &SeaService) -> GetMediaService<SeaMediaRepository<'_>, LocalFileStorage> {
+    let media_repository = SeaMediaRepository::new(db_conn);
+    let file_storage = LocalFileStorage::new();
+
+    GetMediaService::new(media_repository, file_storage)
+}
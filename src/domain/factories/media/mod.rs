@@ -0,0 +1,5 @@
+pub mod delete_media_service_factory;
+pub mod fetch_many_media_service_factory;
+pub mod get_media_service_factory;
+pub mod update_media_service_factory;
+pub mod upload_media_service_factory;
@@ -0,0 +1,8 @@
+use crate::domain::services::media::update_media_service::UpdateMediaService;
+use crate::infra::sea::repositories::sea_media_repository::SeaMediaRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(db_conn: &SeaService) -> UpdateMediaService<SeaMediaRepository<'_>> {
+    let media_repository = SeaMediaRepository::new(db_conn);
+    UpdateMediaService::new(media_repository)
+}
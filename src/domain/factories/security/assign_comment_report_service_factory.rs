@@ -0,0 +1,13 @@
+use crate::domain::services::security::assign_comment_report_service::AssignCommentReportService;
+use crate::infra::sea::repositories::sea_comment_report_repository::SeaCommentReportRepository;
+use crate::infra::sea::repositories::sea_user_repository::SeaUserRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(
+    db_conn: &SeaService,
+) -> AssignCommentReportService<SeaCommentReportRepository<'_>, SeaUserRepository<'_>> {
+    let comment_report_repository = SeaCommentReportRepository::new(db_conn);
+    let user_repository = SeaUserRepository::new(db_conn);
+
+    AssignCommentReportService::new(comment_report_repository, user_repository)
+}
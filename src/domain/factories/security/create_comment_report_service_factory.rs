@@ -1,13 +1,27 @@
 use crate::domain::services::security::create_comment_report_service::CreateCommentReportService;
+use crate::infra::sea::repositories::sea_blocked_word_repository::SeaBlockedWordRepository;
 use crate::infra::sea::repositories::sea_comment_report_repository::SeaCommentReportRepository;
 use crate::infra::sea::repositories::sea_comment_repository::SeaCommentRepository;
+use crate::infra::sea::repositories::sea_comment_review_repository::SeaCommentReviewRepository;
 use crate::infra::sea::sea_service::SeaService;
 
 pub fn exec(
     db_conn: &SeaService,
-) -> CreateCommentReportService<SeaCommentRepository, SeaCommentReportRepository> {
+) -> CreateCommentReportService<
+    SeaCommentRepository<'_>,
+    SeaCommentReportRepository<'_>,
+    SeaBlockedWordRepository<'_>,
+    SeaCommentReviewRepository<'_>,
+> {
     let comment_repository = SeaCommentRepository::new(db_conn);
     let comment_report_repository = SeaCommentReportRepository::new(db_conn);
+    let blocked_word_repository = SeaBlockedWordRepository::new(db_conn);
+    let comment_review_repository = SeaCommentReviewRepository::new(db_conn);
 
-    CreateCommentReportService::new(comment_repository, comment_report_repository)
+    CreateCommentReportService::new(
+        comment_repository,
+        comment_report_repository,
+        blocked_word_repository,
+        comment_review_repository,
+    )
 }
@@ -0,0 +1,9 @@
+use crate::domain::services::security::delete_blocked_word_service::DeleteBlockedWordService;
+use crate::infra::sea::repositories::sea_blocked_word_repository::SeaBlockedWordRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(db_conn: &SeaService) -> DeleteBlockedWordService<SeaBlockedWordRepository<'_>> {
+    let blocked_word_repository = SeaBlockedWordRepository::new(db_conn);
+
+    DeleteBlockedWordService::new(blocked_word_repository)
+}
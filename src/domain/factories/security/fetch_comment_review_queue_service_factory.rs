@@ -0,0 +1,11 @@
+use crate::domain::services::security::fetch_comment_review_queue_service::FetchCommentReviewQueueService;
+use crate::infra::sea::repositories::sea_comment_review_repository::SeaCommentReviewRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(
+    db_conn: &SeaService,
+) -> FetchCommentReviewQueueService<SeaCommentReviewRepository<'_>> {
+    let comment_review_repository = SeaCommentReviewRepository::new(db_conn);
+
+    FetchCommentReviewQueueService::new(comment_review_repository)
+}
@@ -0,0 +1,9 @@
+use crate::domain::services::security::fetch_many_blocked_words_service::FetchManyBlockedWordsService;
+use crate::infra::sea::repositories::sea_blocked_word_repository::SeaBlockedWordRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(db_conn: &SeaService) -> FetchManyBlockedWordsService<SeaBlockedWordRepository<'_>> {
+    let blocked_word_repository = SeaBlockedWordRepository::new(db_conn);
+
+    FetchManyBlockedWordsService::new(blocked_word_repository)
+}
@@ -1,5 +1,13 @@
+pub mod assign_comment_report_service_factory;
+pub mod create_blocked_word_service_factory;
 pub mod create_comment_report_service_factory;
+pub mod delete_blocked_word_service_factory;
 pub mod delete_comment_report_service_factory;
+pub mod fetch_comment_review_queue_service_factory;
+pub mod fetch_many_blocked_words_service_factory;
 pub mod fetch_many_comment_reports_service_factory;
+pub mod restore_comment_service_factory;
+pub mod review_comments_service_factory;
 pub mod solve_comment_report_service_factory;
 pub mod toggle_comment_visibility_service_factory;
+pub mod update_blocked_word_service_factory;
@@ -0,0 +1,13 @@
+use crate::domain::services::security::restore_comment_service::RestoreCommentService;
+use crate::infra::sea::repositories::sea_comment_report_repository::SeaCommentReportRepository;
+use crate::infra::sea::repositories::sea_comment_repository::SeaCommentRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(
+    db_conn: &SeaService,
+) -> RestoreCommentService<SeaCommentRepository<'_>, SeaCommentReportRepository<'_>> {
+    let comment_repository = SeaCommentRepository::new(db_conn);
+    let comment_report_repository = SeaCommentReportRepository::new(db_conn);
+
+    RestoreCommentService::new(comment_repository, comment_report_repository)
+}
@@ -0,0 +1,9 @@
+use crate::domain::services::security::review_comments_service::ReviewCommentsService;
+use crate::infra::sea::repositories::sea_comment_review_repository::SeaCommentReviewRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(db_conn: &SeaService) -> ReviewCommentsService<SeaCommentReviewRepository<'_>> {
+    let comment_review_repository = SeaCommentReviewRepository::new(db_conn);
+
+    ReviewCommentsService::new(comment_review_repository)
+}
@@ -0,0 +1,9 @@
+use crate::domain::services::security::update_blocked_word_service::UpdateBlockedWordService;
+use crate::infra::sea::repositories::sea_blocked_word_repository::SeaBlockedWordRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(db_conn: &SeaService) -> UpdateBlockedWordService<SeaBlockedWordRepository<'_>> {
+    let blocked_word_repository = SeaBlockedWordRepository::new(db_conn);
+
+    UpdateBlockedWordService::new(blocked_word_repository)
+}
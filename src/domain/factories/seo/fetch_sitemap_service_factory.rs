@@ -0,0 +1,23 @@
+use crate::domain::services::seo::fetch_sitemap_service::FetchSitemapService;
+use crate::infra::sea::repositories::sea_article_repository::SeaArticleRepository;
+use crate::infra::sea::repositories::sea_article_tag_repository::SeaArticleTagRepository;
+use crate::infra::sea::repositories::sea_free_badge_repository::SeaFreeBadgeRepository;
+use crate::infra::sea::sea_service::SeaService;
+
+pub fn exec(
+    db_conn: &SeaService,
+) -> FetchSitemapService<
+    SeaArticleRepository<'_>,
+    SeaArticleTagRepository<'_>,
+    SeaFreeBadgeRepository<'_>,
+> {
+    let article_repository = SeaArticleRepository::new(db_conn);
+    let article_tag_repository = SeaArticleTagRepository::new(db_conn);
+    let free_badge_repository = SeaFreeBadgeRepository::new(db_conn);
+
+    FetchSitemapService::new(
+        article_repository,
+        article_tag_repository,
+        free_badge_repository,
+    )
+}
@@ -0,0 +1,2 @@
+pub mod fetch_sitemap_index_service_factory;
+pub mod fetch_sitemap_service_factory;
@@ -1,6 +1,8 @@
+pub mod cache;
 pub mod cryptography;
 pub mod domain_entities;
 pub mod factories;
 pub mod politics;
 pub mod repositories;
 pub mod services;
+pub mod storage;
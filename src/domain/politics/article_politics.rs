@@ -1,5 +1,6 @@
-use crate::domain::domain_entities::article::Article;
-use chrono::NaiveDateTime;
+use crate::domain::domain_entities::article::{Article, CommentMode};
+use crate::domain::domain_entities::role::Role;
+use chrono::{NaiveDateTime, TimeDelta};
 
 pub struct ArticlePolitics;
 
@@ -13,4 +14,97 @@ impl ArticlePolitics {
 
         hours_difference <= hours_til_no_longer_recent
     }
+
+    pub fn article_is_pinned(article: &Article, now: NaiveDateTime) -> bool {
+        article.pinned()
+            && article
+                .pinned_until()
+                .is_none_or(|pinned_until| pinned_until > now)
+    }
+
+    /// The article's comment mode, which is closed once the article is older than `auto_close_days`, whatever mode it
+    /// has been set to. Articles are never closed automatically if it's not set or 0.
+    pub fn comment_mode(
+        article: &Article,
+        auto_close_days: Option<u32>,
+        now: NaiveDateTime,
+    ) -> CommentMode {
+        let is_auto_closed = auto_close_days.is_some_and(|days| {
+            days > 0
+                && now.signed_duration_since(article.created_at()) >= TimeDelta::days(days.into())
+        });
+
+        if is_auto_closed {
+            return CommentMode::Closed;
+        }
+
+        article.comment_mode()
+    }
+
+    /// Whether someone of the role can comment under the mode. Visitors are told whether they could comment once
+    /// logged in as regular users.
+    pub fn can_comment(comment_mode: CommentMode, role: Option<&Role>) -> bool {
+        match comment_mode {
+            CommentMode::Open => true,
+            CommentMode::StaffOnly => role.is_some_and(|role| *role != Role::User),
+            CommentMode::Closed => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::libs::time::TimeHelper;
+
+    #[test]
+    fn test_comment_mode() {
+        let mut article = Article::new(
+            Uuid::new_v4(),
+            "Título".into(),
+            "Conteúdo".into(),
+            "url".into(),
+            1,
+            "Foo".into(),
+        );
+        let now = TimeHelper::now();
+        let later = now + TimeDelta::days(31);
+
+        assert_eq!(
+            CommentMode::Open,
+            ArticlePolitics::comment_mode(&article, Some(30), now)
+        );
+        assert_eq!(
+            CommentMode::Closed,
+            ArticlePolitics::comment_mode(&article, Some(30), later)
+        );
+        assert_eq!(
+            CommentMode::Open,
+            ArticlePolitics::comment_mode(&article, Some(0), later)
+        );
+        assert_eq!(
+            CommentMode::Open,
+            ArticlePolitics::comment_mode(&article, None, later)
+        );
+
+        article.set_comment_mode(CommentMode::StaffOnly);
+        let comment_mode = ArticlePolitics::comment_mode(&article, None, now);
+
+        assert!(!ArticlePolitics::can_comment(comment_mode, None));
+        assert!(!ArticlePolitics::can_comment(
+            comment_mode,
+            Some(&Role::User)
+        ));
+        assert!(ArticlePolitics::can_comment(
+            comment_mode,
+            Some(&Role::Writer)
+        ));
+        assert!(!ArticlePolitics::can_comment(
+            CommentMode::Closed,
+            Some(&Role::Ceo)
+        ));
+        assert!(ArticlePolitics::can_comment(CommentMode::Open, None));
+    }
 }
@@ -0,0 +1,359 @@
+use chrono::{NaiveDateTime, TimeDelta};
+
+use crate::domain::domain_entities::comment::Comment;
+use crate::domain::domain_entities::role::Role;
+use crate::error::DomainError;
+use crate::libs::text::TextHelper;
+
+/// Comments whose trigrams overlap at least this much are taken as the same comment posted again.
+const NEAR_DUPLICATE_SIMILARITY: f64 = 0.8;
+
+pub const DEFAULT_COMMENT_FLOOD_USER_COOLDOWN_SECONDS: u32 = 30;
+pub const DEFAULT_COMMENT_FLOOD_WRITER_COOLDOWN_SECONDS: u32 = 10;
+pub const DEFAULT_COMMENT_FLOOD_USER_MAX_PER_WINDOW: u32 = 5;
+pub const DEFAULT_COMMENT_FLOOD_WRITER_MAX_PER_WINDOW: u32 = 15;
+pub const DEFAULT_COMMENT_FLOOD_WINDOW_MINUTES: u32 = 10;
+pub const DEFAULT_COMMENT_FLOOD_DUPLICATE_WINDOW_MINUTES: u32 = 60;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CommentFloodLimits {
+    /// How long a user must wait between two comments.
+    pub cooldown: TimeDelta,
+    /// The sliding window in which at most `max_comments_per_window` comments may be posted.
+    pub window: TimeDelta,
+    /// 0 doesn't limit them.
+    pub max_comments_per_window: usize,
+    /// For how long a comment can't be posted again by the same user.
+    pub duplicate_window: TimeDelta,
+}
+
+/// The flood limits of the roles that have them. Users and writers share the windows, but not their cooldown nor
+/// how many comments fit in a window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CommentFloodPolicy {
+    pub user: CommentFloodLimits,
+    pub writer: CommentFloodLimits,
+}
+
+/// What `CommentFloodPolicy` is built from, each setting left out taking its default.
+#[derive(Default)]
+pub struct CommentFloodPolicySettings {
+    pub user_cooldown_seconds: Option<u32>,
+    pub writer_cooldown_seconds: Option<u32>,
+    pub user_max_per_window: Option<u32>,
+    pub writer_max_per_window: Option<u32>,
+    pub window_minutes: Option<u32>,
+    pub duplicate_window_minutes: Option<u32>,
+}
+
+impl CommentFloodPolicy {
+    pub fn new(settings: CommentFloodPolicySettings) -> Self {
+        let window = TimeDelta::minutes(
+            settings
+                .window_minutes
+                .unwrap_or(DEFAULT_COMMENT_FLOOD_WINDOW_MINUTES)
+                .into(),
+        );
+        let duplicate_window = TimeDelta::minutes(
+            settings
+                .duplicate_window_minutes
+                .unwrap_or(DEFAULT_COMMENT_FLOOD_DUPLICATE_WINDOW_MINUTES)
+                .into(),
+        );
+
+        let limits = |cooldown_seconds: u32, max_per_window: u32| CommentFloodLimits {
+            cooldown: TimeDelta::seconds(cooldown_seconds.into()),
+            window,
+            max_comments_per_window: max_per_window as usize,
+            duplicate_window,
+        };
+
+        CommentFloodPolicy {
+            user: limits(
+                settings
+                    .user_cooldown_seconds
+                    .unwrap_or(DEFAULT_COMMENT_FLOOD_USER_COOLDOWN_SECONDS),
+                settings
+                    .user_max_per_window
+                    .unwrap_or(DEFAULT_COMMENT_FLOOD_USER_MAX_PER_WINDOW),
+            ),
+            writer: limits(
+                settings
+                    .writer_cooldown_seconds
+                    .unwrap_or(DEFAULT_COMMENT_FLOOD_WRITER_COOLDOWN_SECONDS),
+                settings
+                    .writer_max_per_window
+                    .unwrap_or(DEFAULT_COMMENT_FLOOD_WRITER_MAX_PER_WINDOW),
+            ),
+        }
+    }
+}
+
+impl Default for CommentFloodPolicy {
+    fn default() -> Self {
+        CommentFloodPolicy::new(CommentFloodPolicySettings::default())
+    }
+}
+
+pub struct CommentFloodPolitics;
+
+impl CommentFloodPolitics {
+    /// The limits the role's comments are subject to, staff (editors and above) having none.
+    pub fn limits(policy: &CommentFloodPolicy, role: &Role) -> Option<CommentFloodLimits> {
+        match role {
+            Role::User => Some(policy.user),
+            Role::Writer => Some(policy.writer),
+            Role::Editor | Role::Coord | Role::Admin | Role::Principal | Role::Ceo => None,
+        }
+    }
+
+    /// How far back the user's comments must be looked at to check them against the limits.
+    pub fn lookback(limits: &CommentFloodLimits) -> TimeDelta {
+        limits
+            .cooldown
+            .max(limits.window)
+            .max(limits.duplicate_window)
+    }
+
+    /// Checks a new comment against the ones the same user posted within `lookback`, failing with a retry-after hint
+    /// if the user is commenting too fast, or with a bad request if the comment is a near-duplicate of a recent one.
+    pub fn check(
+        limits: &CommentFloodLimits,
+        content: &str,
+        recent_comments: &[Comment],
+        now: NaiveDateTime,
+    ) -> Result<(), DomainError> {
+        Self::check_cooldown(limits, recent_comments, now)?;
+        Self::check_window(limits, recent_comments, now)?;
+        Self::check_duplicate(limits, content, recent_comments, now)
+    }
+
+    /// Fails if the user's latest comment was posted less than `cooldown` ago.
+    pub fn check_cooldown(
+        limits: &CommentFloodLimits,
+        recent_comments: &[Comment],
+        now: NaiveDateTime,
+    ) -> Result<(), DomainError> {
+        let latest = recent_comments
+            .iter()
+            .map(|comment| comment.created_at())
+            .max();
+
+        if let Some(latest) = latest {
+            let elapsed = now.signed_duration_since(latest);
+
+            if elapsed < limits.cooldown {
+                return Err(DomainError::too_many_requests_err(retry_after_secs(
+                    limits.cooldown - elapsed,
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fails if the user has already posted `max_comments_per_window` comments within the sliding window.
+    pub fn check_window(
+        limits: &CommentFloodLimits,
+        recent_comments: &[Comment],
+        now: NaiveDateTime,
+    ) -> Result<(), DomainError> {
+        let mut in_window = recent_comments
+            .iter()
+            .map(|comment| comment.created_at())
+            .filter(|created_at| now.signed_duration_since(*created_at) < limits.window)
+            .collect::<Vec<NaiveDateTime>>();
+
+        if limits.max_comments_per_window > 0 && in_window.len() >= limits.max_comments_per_window {
+            in_window.sort_unstable_by(|a, b| b.cmp(a));
+
+            // The window frees up a slot once the oldest of the last `max_comments_per_window` comments leaves it.
+            let oldest = in_window[limits.max_comments_per_window - 1];
+
+            return Err(DomainError::too_many_requests_err(retry_after_secs(
+                limits.window - now.signed_duration_since(oldest),
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Fails if the comment is a near-duplicate of one the user posted within `duplicate_window`.
+    pub fn check_duplicate(
+        limits: &CommentFloodLimits,
+        content: &str,
+        recent_comments: &[Comment],
+        now: NaiveDateTime,
+    ) -> Result<(), DomainError> {
+        let is_duplicate = recent_comments
+            .iter()
+            .filter(|comment| {
+                now.signed_duration_since(comment.created_at()) < limits.duplicate_window
+            })
+            .any(|comment| Self::is_near_duplicate(content, comment.content()));
+
+        if is_duplicate {
+            return Err(DomainError::bad_request_err()
+                .with_message("You've already posted this comment recently."));
+        }
+
+        Ok(())
+    }
+
+    /// Whether two comments are the same but for casing, punctuation and small edits.
+    pub fn is_near_duplicate(a: &str, b: &str) -> bool {
+        let words_a = TextHelper::normalized_words(a).collect::<Vec<String>>();
+        let words_b = TextHelper::normalized_words(b).collect::<Vec<String>>();
+
+        // e.g. comments made of emojis only
+        if words_a.is_empty() || words_b.is_empty() {
+            return a.trim() == b.trim();
+        }
+
+        if words_a == words_b {
+            return true;
+        }
+
+        TextHelper::jaccard_index(&TextHelper::trigrams(a), &TextHelper::trigrams(b))
+            >= NEAR_DUPLICATE_SIMILARITY
+    }
+}
+
+/// Rounds up, so that retrying after the hinted seconds is never too early.
+fn retry_after_secs(remaining: TimeDelta) -> u64 {
+    let millis = remaining.num_milliseconds().max(0) as u64;
+
+    millis.div_ceil(1000).max(1)
+}
+
+#[cfg(test)]
+mod test {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::libs::time::TimeHelper;
+
+    fn comment(content: &str, created_at: NaiveDateTime) -> Comment {
+        Comment::new_from_existing(
+            Uuid::new_v4(),
+            Some(Uuid::new_v4()),
+            None,
+            Uuid::new_v4(),
+            content.into(),
+            true,
+            created_at,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_limits() {
+        let policy = CommentFloodPolicy::new(CommentFloodPolicySettings {
+            user_cooldown_seconds: Some(60),
+            duplicate_window_minutes: Some(5),
+            ..Default::default()
+        });
+
+        let limits = CommentFloodPolitics::limits(&policy, &Role::User).unwrap();
+        assert_eq!(TimeDelta::seconds(60), limits.cooldown);
+        assert_eq!(
+            TimeDelta::minutes(10),
+            CommentFloodPolitics::lookback(&limits)
+        );
+
+        let limits = CommentFloodPolitics::limits(&policy, &Role::Writer).unwrap();
+        assert_eq!(
+            TimeDelta::seconds(DEFAULT_COMMENT_FLOOD_WRITER_COOLDOWN_SECONDS.into()),
+            limits.cooldown
+        );
+
+        assert!(CommentFloodPolitics::limits(&policy, &Role::Editor).is_none());
+    }
+
+    #[test]
+    fn test_cooldown() {
+        let limits = CommentFloodPolicy::default().user;
+        let now = TimeHelper::now();
+
+        let recent_comments = [comment("Primeiro!", now - TimeDelta::milliseconds(10_500))];
+
+        let err = CommentFloodPolitics::check_cooldown(&limits, &recent_comments, now).unwrap_err();
+        assert_eq!(Some(20), err.get_retry_after());
+
+        let recent_comments = [comment("Primeiro!", now - limits.cooldown)];
+        assert!(CommentFloodPolitics::check_cooldown(&limits, &recent_comments, now).is_ok());
+        assert!(CommentFloodPolitics::check_cooldown(&limits, &[], now).is_ok());
+    }
+
+    #[test]
+    fn test_window() {
+        let mut limits = CommentFloodPolicy::default().user;
+        let now = TimeHelper::now();
+
+        let recent_comments = (1..=limits.max_comments_per_window as i64)
+            .map(|minutes| comment("Oi", now - TimeDelta::minutes(minutes)))
+            .collect::<Vec<Comment>>();
+
+        // the oldest comment leaves the window in 5 minutes
+        let err = CommentFloodPolitics::check_window(&limits, &recent_comments, now).unwrap_err();
+        assert_eq!(Some(5 * 60), err.get_retry_after());
+
+        assert!(CommentFloodPolitics::check_window(&limits, &recent_comments[1..], now).is_ok());
+
+        limits.max_comments_per_window = 0;
+        assert!(CommentFloodPolitics::check_window(&limits, &recent_comments, now).is_ok());
+    }
+
+    #[test]
+    fn test_duplicate() {
+        let limits = CommentFloodPolicy::default().user;
+        let now = TimeHelper::now();
+
+        let recent_comments = [comment("Que notícia boa!", now - TimeDelta::minutes(30))];
+
+        let err = CommentFloodPolitics::check_duplicate(
+            &limits,
+            "que notícia boa",
+            &recent_comments,
+            now,
+        )
+        .unwrap_err();
+        assert!(err.get_retry_after().is_none());
+
+        assert!(CommentFloodPolitics::check_duplicate(
+            &limits,
+            "Que notícia ruim!",
+            &recent_comments,
+            now
+        )
+        .is_ok());
+
+        // past the duplicate window
+        let recent_comments = [comment("Que notícia boa!", now - limits.duplicate_window)];
+        assert!(CommentFloodPolitics::check_duplicate(
+            &limits,
+            "Que notícia boa!",
+            &recent_comments,
+            now
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_near_duplicates() {
+        assert!(CommentFloodPolitics::is_near_duplicate(
+            "Parabéns pela matéria!",
+            "parabéns, pela matéria"
+        ));
+        assert!(CommentFloodPolitics::is_near_duplicate(
+            "Parabéns pela matéria!!!",
+            "Parabéns pela matérias!"
+        ));
+        assert!(!CommentFloodPolitics::is_near_duplicate(
+            "Parabéns pela matéria!",
+            "Não gostei da matéria."
+        ));
+        assert!(CommentFloodPolitics::is_near_duplicate("😂😂", " 😂😂 "));
+        assert!(!CommentFloodPolitics::is_near_duplicate("😂😂", "😭"));
+    }
+}
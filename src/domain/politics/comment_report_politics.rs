@@ -0,0 +1,47 @@
+use crate::domain::domain_entities::comment_report::{ReportPriority, ReportReason};
+use crate::domain::domain_entities::role::Role;
+
+/// How much weight of distinct reporters hides a comment when `COMMENT_REPORT_HIDE_THRESHOLD` isn't set.
+pub const DEFAULT_COMMENT_REPORT_HIDE_THRESHOLD: u32 = 5;
+
+pub struct CommentReportPolitics;
+
+impl CommentReportPolitics {
+    /// How much a user's report counts towards hiding a comment: team members (writers and above) are the most
+    /// trusted, then users a moderator has vouched for by approving one of their comments, then everyone else.
+    pub fn reporter_weight(role: &Role, is_trusted: bool) -> u32 {
+        match role {
+            Role::User if is_trusted => 2,
+            Role::User => 1,
+            Role::Writer
+            | Role::Editor
+            | Role::Coord
+            | Role::Admin
+            | Role::Principal
+            | Role::Ceo => 3,
+        }
+    }
+
+    /// Whether the reports' summed weight is enough to hide the comment. A threshold of 0 never hides it.
+    pub fn should_hide(total_weight: u32, threshold: u32) -> bool {
+        threshold > 0 && total_weight >= threshold
+    }
+
+    /// How soon moderators should look into a report: scam links put readers' accounts at risk, so they come first,
+    /// while off-topic comments can wait. A comment hidden by reports has many users agreeing on it, raising the
+    /// priority of its reports.
+    pub fn priority(reason: ReportReason, comment_auto_hidden: bool) -> ReportPriority {
+        let priority = match reason {
+            ReportReason::ScamLink => ReportPriority::Urgent,
+            ReportReason::Offensive => ReportPriority::High,
+            ReportReason::Spam | ReportReason::Other => ReportPriority::Normal,
+            ReportReason::OffTopic => ReportPriority::Low,
+        };
+
+        if comment_auto_hidden {
+            priority.raised()
+        } else {
+            priority
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use chrono::{NaiveDateTime, TimeDelta};
+
+use crate::domain::domain_entities::role::Role;
+
+pub const DEFAULT_COMMENT_REVIEW_ACCOUNT_AGE_DAYS: u32 = 7;
+pub const DEFAULT_COMMENT_REVIEW_MIN_APPROVED_COMMENTS: u32 = 3;
+
+/// Which authors have their comments held for review before being published.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PreModerationPolicy {
+    /// Accounts younger than this are reviewed.
+    pub account_age_days: u32,
+    /// Authors with fewer published comments than this are reviewed.
+    pub min_approved_comments: u32,
+}
+
+impl PreModerationPolicy {
+    /// Publishes every comment right away.
+    pub const DISABLED: PreModerationPolicy = PreModerationPolicy {
+        account_age_days: 0,
+        min_approved_comments: 0,
+    };
+
+    pub fn new(account_age_days: Option<u32>, min_approved_comments: Option<u32>) -> Self {
+        PreModerationPolicy {
+            account_age_days: account_age_days.unwrap_or(DEFAULT_COMMENT_REVIEW_ACCOUNT_AGE_DAYS),
+            min_approved_comments: min_approved_comments
+                .unwrap_or(DEFAULT_COMMENT_REVIEW_MIN_APPROVED_COMMENTS),
+        }
+    }
+}
+
+pub struct CommentReviewPolitics;
+
+impl CommentReviewPolitics {
+    /// Only regular users can have their comments held, staff and writers publish right away.
+    pub fn applies_to(role: &Role) -> bool {
+        matches!(role, Role::User)
+    }
+
+    /// Whether the author is too new to publish without review, by the age of their account or by how many of their
+    /// comments are published. Authors who have had a comment approved by a moderator are never new again.
+    pub fn is_newcomer(
+        policy: &PreModerationPolicy,
+        account_created_at: NaiveDateTime,
+        published_comments: u64,
+        now: NaiveDateTime,
+    ) -> bool {
+        let account_age = now.signed_duration_since(account_created_at);
+
+        account_age < TimeDelta::days(policy.account_age_days as i64)
+            || published_comments < policy.min_approved_comments as u64
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::libs::time::TimeHelper;
+
+    #[test]
+    fn test_is_newcomer() {
+        let policy = PreModerationPolicy::new(Some(7), Some(3));
+        let now = TimeHelper::now();
+
+        assert!(CommentReviewPolitics::is_newcomer(
+            &policy,
+            now - TimeDelta::days(2),
+            10,
+            now
+        ));
+        assert!(CommentReviewPolitics::is_newcomer(
+            &policy,
+            now - TimeDelta::days(30),
+            2,
+            now
+        ));
+        assert!(!CommentReviewPolitics::is_newcomer(
+            &policy,
+            now - TimeDelta::days(30),
+            3,
+            now
+        ));
+        assert!(!CommentReviewPolitics::is_newcomer(
+            &PreModerationPolicy::DISABLED,
+            now,
+            0,
+            now
+        ));
+    }
+}
@@ -0,0 +1,61 @@
+use crate::error::DomainError;
+use crate::libs::image::ImageHelper;
+
+/// 5 MiB.
+pub const MAX_UPLOADED_IMAGE_SIZE: usize = 5 * 1024 * 1024;
+pub const ALLOWED_IMAGE_MIME_TYPES: [&str; 4] =
+    ["image/jpeg", "image/png", "image/webp", "image/gif"];
+
+pub struct ImageUploadPolitics;
+
+impl ImageUploadPolitics {
+    /// Checks the uploaded image's size and type, the latter being sniffed from its content and compared to the
+    /// one declared by the client, if any. `subject` names the image on error messages (e.g. "Cover").
+    ///
+    /// Returns the image's MIME type and file extension.
+    pub fn validate(
+        content: &[u8],
+        declared_content_type: Option<&str>,
+        subject: &str,
+    ) -> Result<(&'static str, &'static str), DomainError> {
+        if content.is_empty() {
+            return Err(DomainError::bad_request_err()
+                .with_message(format!("{subject} must not be empty.")));
+        }
+
+        if content.len() > MAX_UPLOADED_IMAGE_SIZE {
+            return Err(DomainError::bad_request_err().with_message(format!(
+                "{subject} must not be larger than {} MiB.",
+                MAX_UPLOADED_IMAGE_SIZE / 1024 / 1024
+            )));
+        }
+
+        if let Some(content_type) = declared_content_type {
+            if !ALLOWED_IMAGE_MIME_TYPES.contains(&content_type) {
+                return Err(DomainError::bad_request_err().with_message(format!(
+                    "Unsupported {} type '{content_type}'. Allowed types are: {}.",
+                    subject.to_lowercase(),
+                    ALLOWED_IMAGE_MIME_TYPES.join(", ")
+                )));
+            }
+        }
+
+        let (mime_type, extension) = match ImageHelper::detect_format(content) {
+            Some(format) if ALLOWED_IMAGE_MIME_TYPES.contains(&format.0) => format,
+            _ => {
+                return Err(DomainError::bad_request_err().with_message(format!(
+                    "{subject} must be one of the following types: {}.",
+                    ALLOWED_IMAGE_MIME_TYPES.join(", ")
+                )))
+            }
+        };
+
+        if declared_content_type.is_some_and(|content_type| content_type != mime_type) {
+            return Err(DomainError::bad_request_err().with_message(format!(
+                "{subject}'s content doesn't match its declared type."
+            )));
+        }
+
+        Ok((mime_type, extension))
+    }
+}
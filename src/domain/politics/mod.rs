@@ -1 +1,8 @@
 pub mod article_politics;
+pub mod comment_flood_politics;
+pub mod comment_report_politics;
+pub mod comment_review_politics;
+pub mod image_upload_politics;
+pub mod reaction_politics;
+pub mod related_articles_politics;
+pub mod word_filter_politics;
@@ -0,0 +1,75 @@
+use crate::error::DomainError;
+
+/// The reactions users can give when `REACTIONS` isn't set.
+pub const DEFAULT_REACTIONS: &str = "heart,laugh,wow,sad";
+
+const MAX_REACTION_LENGTH: usize = 32;
+
+pub struct ReactionPolitics;
+
+impl ReactionPolitics {
+    /// Parses the comma-separated list of allowed reactions, falling back to the default ones if it's empty.
+    pub fn allowed(reactions: Option<&str>) -> Vec<String> {
+        let parse = |reactions: &str| {
+            let mut allowed = Vec::<String>::new();
+
+            for reaction in reactions.split(',') {
+                let reaction = reaction.trim().to_lowercase();
+
+                if !reaction.is_empty()
+                    && reaction.len() <= MAX_REACTION_LENGTH
+                    && !allowed.contains(&reaction)
+                {
+                    allowed.push(reaction);
+                }
+            }
+
+            allowed
+        };
+
+        match reactions.map(parse) {
+            Some(allowed) if !allowed.is_empty() => allowed,
+            _ => parse(DEFAULT_REACTIONS),
+        }
+    }
+
+    /// Checks the reaction is one of the allowed ones, returning it as it's stored.
+    pub fn validate(allowed: &[String], reaction: &str) -> Result<String, DomainError> {
+        let reaction = reaction.trim().to_lowercase();
+
+        if allowed.contains(&reaction) {
+            return Ok(reaction);
+        }
+
+        Err(DomainError::bad_request_err().with_message(format!(
+            "Unsupported reaction, expected one of: {}.",
+            allowed.join(", ")
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(
+            vec!["heart", "laugh", "wow", "sad"],
+            ReactionPolitics::allowed(None)
+        );
+        assert_eq!(
+            vec!["heart", "laugh", "wow", "sad"],
+            ReactionPolitics::allowed(Some(" , "))
+        );
+
+        let allowed = ReactionPolitics::allowed(Some("Heart, fire,heart,,"));
+        assert_eq!(vec!["heart", "fire"], allowed);
+
+        assert_eq!(
+            "fire",
+            ReactionPolitics::validate(&allowed, " FIRE ").unwrap()
+        );
+        assert!(ReactionPolitics::validate(&allowed, "sad").is_err());
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashSet;
+
+use chrono::NaiveDateTime;
+
+use crate::domain::domain_entities::article::Article;
+use crate::libs::text::TextHelper;
+
+const SHARED_TAG_WEIGHT: f64 = 3.0;
+const SAME_AUTHOR_WEIGHT: f64 = 1.5;
+const TITLE_SIMILARITY_WEIGHT: f64 = 3.0;
+const CONTENT_SIMILARITY_WEIGHT: f64 = 2.0;
+const RECENCY_WEIGHT: f64 = 1.0;
+const RECENCY_HALF_LIFE_IN_DAYS: f64 = 30.0;
+
+/// Words this short are mostly articles, prepositions and html tags, which would make any two contents look alike.
+const MIN_CONTENT_WORD_LENGTH: usize = 4;
+
+pub struct RelatedArticlesPolitics;
+
+impl RelatedArticlesPolitics {
+    /// Scores how related `candidate` is to `article`, taking into account whether they share the tag and the author,
+    /// how similar their titles (trigrams) and contents (words) are, and how recent the candidate is.
+    pub fn relatedness_score(article: &Article, candidate: &Article, now: NaiveDateTime) -> f64 {
+        let mut score = 0.0;
+
+        if article.tag_id().is_some() && article.tag_id() == candidate.tag_id() {
+            score += SHARED_TAG_WEIGHT;
+        }
+
+        if article.author_id() == candidate.author_id() {
+            score += SAME_AUTHOR_WEIGHT;
+        }
+
+        score += TITLE_SIMILARITY_WEIGHT
+            * TextHelper::jaccard_index(
+                &TextHelper::trigrams(article.title()),
+                &TextHelper::trigrams(candidate.title()),
+            );
+
+        score += CONTENT_SIMILARITY_WEIGHT
+            * TextHelper::jaccard_index(&words(article.content()), &words(candidate.content()));
+
+        let age_in_days = now
+            .signed_duration_since(candidate.created_at())
+            .num_hours()
+            .max(0) as f64
+            / 24.0;
+
+        score += RECENCY_WEIGHT * 0.5_f64.powf(age_in_days / RECENCY_HALF_LIFE_IN_DAYS);
+
+        score
+    }
+
+    /// Ranks the approved candidates by their relatedness to `article`, keeping the `limit` most related ones.
+    pub fn rank(
+        article: &Article,
+        candidates: Vec<Article>,
+        now: NaiveDateTime,
+        limit: usize,
+    ) -> Vec<Article> {
+        let mut scored_candidates = candidates
+            .into_iter()
+            .filter(|candidate| candidate.approved() && candidate.id() != article.id())
+            .map(|candidate| (Self::relatedness_score(article, &candidate, now), candidate))
+            .collect::<Vec<(f64, Article)>>();
+
+        scored_candidates.sort_by(|(score_a, article_a), (score_b, article_b)| {
+            score_b
+                .total_cmp(score_a)
+                .then_with(|| article_b.created_at().cmp(&article_a.created_at()))
+        });
+
+        scored_candidates
+            .into_iter()
+            .take(limit)
+            .map(|(_, candidate)| candidate)
+            .collect()
+    }
+}
+
+fn words(text: &str) -> HashSet<String> {
+    TextHelper::normalized_words(text)
+        .filter(|word| word.chars().count() >= MIN_CONTENT_WORD_LENGTH)
+        .collect()
+}
@@ -0,0 +1,271 @@
+use log::error;
+use regex::{Regex, RegexBuilder};
+use unicode_normalization::UnicodeNormalization;
+
+use crate::domain::domain_entities::blocked_word::{BlockedWord, BlockedWordAction};
+use crate::error::DomainError;
+use crate::{LOG_SEP, R_EOL};
+
+/// Characters commonly typed in place of letters, e.g. "m3rd4" for "merda".
+const LEETSPEAK: [(char, &str); 7] = [
+    ('a', "a4@"),
+    ('e', "e3"),
+    ('i', "i1!"),
+    ('o', "o0"),
+    ('s', "s5$"),
+    ('t', "t7"),
+    ('g', "g9"),
+];
+
+/// Compiled regular expressions can't be bigger than this, so that a single pattern can't slow every comment down.
+const MAX_COMPILED_REGEX_SIZE: usize = 64 * 1024;
+
+const MASK_CHAR: char = '*';
+
+pub struct WordFilterOutcome {
+    /// The text with every match of a `Mask` blocked word replaced by asterisks.
+    pub text: String,
+    pub masked: bool,
+    /// The first matched blocked word whose action is `Reject`, if any.
+    pub rejected_by: Option<BlockedWord>,
+    /// The first matched blocked word whose action is `Flag`, if any.
+    pub flagged_by: Option<BlockedWord>,
+}
+
+impl WordFilterOutcome {
+    /// Whether no blocked word has been found at all.
+    pub fn is_clean(&self) -> bool {
+        !self.masked && self.rejected_by.is_none() && self.flagged_by.is_none()
+    }
+}
+
+pub struct WordFilterPolitics;
+
+impl WordFilterPolitics {
+    /// Lowercases the text and strips its accents, one char at a time, so that the normalized text has as many chars
+    /// as the original one and a match can be traced back to it.
+    pub fn normalize(text: &str) -> String {
+        text.chars()
+            .map(|char| strip_accent(char.to_lowercase().next().unwrap_or(char)))
+            .collect()
+    }
+
+    /// Compiles a blocked word into the case-insensitive regular expression that is looked for in normalized texts.
+    ///
+    /// Words are matched with any of their letters repeated or swapped for their leetspeak counterparts, e.g. "merda"
+    /// matches "M3RDAAA" too; regular expressions are taken as they are, but for their accents.
+    pub fn compile(pattern: &str, is_regex: bool) -> Result<Regex, regex::Error> {
+        let source = if is_regex {
+            pattern.chars().map(strip_accent).collect()
+        } else {
+            Self::normalize(pattern.trim())
+                .chars()
+                .map(
+                    |char| match LEETSPEAK.iter().find(|(letter, _)| *letter == char) {
+                        Some((_, variants)) => format!("[{}]+", regex::escape(variants)),
+                        None => format!("{}+", regex::escape(&char.to_string())),
+                    },
+                )
+                .collect::<String>()
+        };
+
+        RegexBuilder::new(&source)
+            .case_insensitive(true)
+            .size_limit(MAX_COMPILED_REGEX_SIZE)
+            .build()
+    }
+
+    /// Checks whether a pattern can be added to the word filter: words must have some letter or digit, and regular
+    /// expressions must compile.
+    pub fn validate(pattern: &str, is_regex: bool) -> Result<(), DomainError> {
+        if !is_regex && !pattern.chars().any(char::is_alphanumeric) {
+            return Err(DomainError::bad_request_err()
+                .with_message("A blocked word must contain at least one letter or digit."));
+        }
+
+        Self::compile(pattern, is_regex).map(|_| ()).map_err(|err| {
+            DomainError::bad_request_err().with_message(format!("Invalid pattern: {err}"))
+        })
+    }
+
+    /// Compiles the blocked words, e.g. the ones the repository found, into a filter that can be applied to any number
+    /// of texts. Blocked words that no longer compile are skipped.
+    pub fn filter(blocked_words: &[BlockedWord]) -> WordFilter {
+        let blocked_words = blocked_words
+            .iter()
+            .filter_map(|blocked_word| {
+                match Self::compile(blocked_word.pattern(), blocked_word.is_regex()) {
+                    Ok(regex) => Some((blocked_word.clone(), regex)),
+                    Err(err) => {
+                        error!("{R_EOL}{LOG_SEP}{R_EOL}Error occurred on Word Filter Politics, while compiling blocked word {}:{R_EOL}{:#?}{R_EOL}{LOG_SEP}{R_EOL}", blocked_word.id(), err);
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        WordFilter { blocked_words }
+    }
+}
+
+/// Blocked words along with their compiled patterns, see `WordFilterPolitics::filter`.
+pub struct WordFilter {
+    blocked_words: Vec<(BlockedWord, Regex)>,
+}
+
+impl WordFilter {
+    /// Looks for every blocked word in the text, masking the ones to be masked and telling whether any other one
+    /// rejects or flags it.
+    pub fn apply(&self, text: &str) -> WordFilterOutcome {
+        let normalized = WordFilterPolitics::normalize(text);
+        let mut chars = text.chars().collect::<Vec<char>>();
+
+        let mut masked = false;
+        let mut rejected_by = None;
+        let mut flagged_by = None;
+
+        for (blocked_word, regex) in self.blocked_words.iter() {
+            let matches = find_matches(regex, &normalized, !blocked_word.is_regex());
+
+            if matches.is_empty() {
+                continue;
+            }
+
+            match blocked_word.action() {
+                BlockedWordAction::Mask => {
+                    for (start, end) in matches {
+                        for char in chars[start..end].iter_mut() {
+                            if !char.is_whitespace() {
+                                *char = MASK_CHAR;
+                            }
+                        }
+                    }
+
+                    masked = true;
+                }
+                BlockedWordAction::Reject => {
+                    rejected_by.get_or_insert_with(|| blocked_word.clone());
+                }
+                BlockedWordAction::Flag => {
+                    flagged_by.get_or_insert_with(|| blocked_word.clone());
+                }
+            }
+        }
+
+        WordFilterOutcome {
+            text: chars.into_iter().collect(),
+            masked,
+            rejected_by,
+            flagged_by,
+        }
+    }
+}
+
+fn strip_accent(char: char) -> char {
+    char.nfd().next().unwrap_or(char)
+}
+
+/// Finds the char ranges of the regex's matches, which must stand on their own (not within a bigger word) if
+/// `whole_words` is set.
+fn find_matches(regex: &Regex, text: &str, whole_words: bool) -> Vec<(usize, usize)> {
+    let is_word_char = |char: Option<char>| char.is_some_and(|char| char.is_alphanumeric());
+    let mut matches = vec![];
+    let mut position = 0;
+
+    while let Some(found) = regex.find_at(text, position) {
+        if found.is_empty() {
+            position = found.end() + text[found.end()..].chars().next().map_or(1, char::len_utf8);
+
+            if position > text.len() {
+                break;
+            }
+
+            continue;
+        }
+
+        let stands_alone = !is_word_char(text[..found.start()].chars().next_back())
+            && !is_word_char(text[found.end()..].chars().next());
+
+        if whole_words && !stands_alone {
+            position = found.start()
+                + text[found.start()..]
+                    .chars()
+                    .next()
+                    .map_or(1, char::len_utf8);
+            continue;
+        }
+
+        let start = text[..found.start()].chars().count();
+        let end = start + found.as_str().chars().count();
+
+        matches.push((start, end));
+        position = found.end();
+    }
+
+    matches
+}
+
+#[cfg(test)]
+mod test {
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn blocked_word(pattern: &str, is_regex: bool, action: BlockedWordAction) -> BlockedWord {
+        BlockedWord::new(pattern.into(), is_regex, action, Uuid::new_v4())
+    }
+
+    #[test]
+    fn test_normalization() {
+        assert_eq!(
+            "acao e legal",
+            WordFilterPolitics::normalize("AÇÃO É Legal")
+        );
+
+        let blocked_words = [blocked_word("merda", false, BlockedWordAction::Mask)];
+
+        let outcome = WordFilterPolitics::filter(&blocked_words)
+            .apply("Que MÉRDA, que m3rd4 e que merdaaa! Mas merdalhada não.");
+
+        assert!(outcome.masked);
+        assert_eq!(
+            "Que *****, que ***** e que *******! Mas merdalhada não.",
+            outcome.text
+        );
+    }
+
+    #[test]
+    fn test_actions() {
+        let blocked_words = [
+            blocked_word("bobo", false, BlockedWordAction::Mask),
+            blocked_word(r"compre\s+moedas", true, BlockedWordAction::Reject),
+            blocked_word("golpe", false, BlockedWordAction::Flag),
+        ];
+        let filter = WordFilterPolitics::filter(&blocked_words);
+
+        let outcome = filter.apply("Que notícia boa!");
+        assert!(outcome.is_clean());
+        assert_eq!("Que notícia boa!", outcome.text);
+
+        let outcome = filter.apply("Compre   MOEDAS aqui, b0b0");
+        assert_eq!(
+            Some("compre\\s+moedas"),
+            outcome.rejected_by.as_ref().map(|word| word.pattern())
+        );
+        assert_eq!("Compre   MOEDAS aqui, ****", outcome.text);
+
+        let outcome = filter.apply("Isso é um g0lp3");
+        assert!(outcome.rejected_by.is_none());
+        assert!(outcome.flagged_by.is_some());
+    }
+
+    #[test]
+    fn test_invalid_regex() {
+        assert!(WordFilterPolitics::compile("(unclosed", true).is_err());
+
+        let blocked_words = [blocked_word("(unclosed", true, BlockedWordAction::Reject)];
+        assert!(WordFilterPolitics::filter(&blocked_words)
+            .apply("(unclosed")
+            .is_clean());
+    }
+}
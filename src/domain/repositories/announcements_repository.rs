@@ -6,14 +6,29 @@ use uuid::Uuid;
 use mockall::automock;
 
 use crate::{
-    core::pagination::PaginationParameters, domain::domain_entities::announcement::Announcement,
+    core::pagination::PaginationParameters,
+    core::query::{DateRange, QuerySpec, SortField},
+    domain::domain_entities::announcement::Announcement,
 };
 
 #[derive(Clone)]
 pub enum AnnouncementQueryType {
     Description(String),
+    CreatedAt(DateRange),
 }
 
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum AnnouncementSortField {
+    CreatedAt,
+}
+
+impl SortField for AnnouncementSortField {
+    const ALLOWED: &'static [(&'static str, Self)] =
+        &[("createdAt", AnnouncementSortField::CreatedAt)];
+}
+
+pub type AnnouncementQuery = QuerySpec<AnnouncementQueryType, AnnouncementSortField>;
+
 pub struct FindManyAnnouncementsResponse(pub Vec<Announcement>, pub u64);
 
 #[cfg_attr(test, automock)]
@@ -24,7 +39,7 @@ pub trait AnnouncementRepositoryTrait {
     async fn find_by_id(&self, id: &Uuid) -> Result<Option<Announcement>, Box<dyn Error>>;
     async fn find_many(
         &self,
-        params: PaginationParameters<AnnouncementQueryType>,
+        params: PaginationParameters<AnnouncementQuery>,
     ) -> Result<FindManyAnnouncementsResponse, Box<dyn Error>>;
     async fn delete(&self, id: &Uuid) -> Result<(), Box<dyn Error>>;
 }
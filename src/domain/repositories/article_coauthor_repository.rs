@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_coauthor::ArticleCoauthor;
+use crate::domain::domain_entities::user::User;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ArticleCoauthorRepositoryTrait {
+    /// Gets the article's co-authors, in the order they should be credited.
+    async fn find_by_article_id(
+        &self,
+        article_id: Uuid,
+    ) -> Result<Vec<ArticleCoauthor>, Box<dyn Error>>;
+
+    /// Same as `find_by_article_id`, but also brings each co-author's user.
+    async fn find_with_users_by_article_id(
+        &self,
+        article_id: Uuid,
+    ) -> Result<Vec<(ArticleCoauthor, User)>, Box<dyn Error>>;
+
+    async fn is_coauthor(&self, article_id: Uuid, user_id: Uuid) -> Result<bool, Box<dyn Error>>;
+
+    /// Saves the article and replaces its co-authors, crediting them in the given order,
+    /// in a single transaction.
+    async fn save_article_with_coauthors(
+        &self,
+        article: Article,
+        coauthors: Vec<ArticleCoauthor>,
+    ) -> Result<Article, Box<dyn Error>>;
+}
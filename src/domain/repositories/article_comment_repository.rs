@@ -1,4 +1,5 @@
 use crate::core::pagination::PaginationParameters;
+use crate::core::query::{DateRange, QuerySpec, SortField};
 use async_trait::async_trait;
 use std::error::Error;
 use uuid::Uuid;
@@ -18,8 +19,20 @@ pub struct FindManyCommentsResponse(
 pub enum CommentQueryType {
     Author(Uuid),
     Content(String),
+    CreatedAt(DateRange),
 }
 
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum CommentSortField {
+    CreatedAt,
+}
+
+impl SortField for CommentSortField {
+    const ALLOWED: &'static [(&'static str, Self)] = &[("createdAt", CommentSortField::CreatedAt)];
+}
+
+pub type CommentQuery = QuerySpec<CommentQueryType, CommentSortField>;
+
 #[cfg_attr(test, automock)]
 #[async_trait]
 pub trait ArticleCommentRepositoryTrait {
@@ -27,7 +40,7 @@ pub trait ArticleCommentRepositoryTrait {
         &self,
         article_id: Option<Uuid>,
         include_inactive: bool,
-        params: PaginationParameters<CommentQueryType>,
+        params: PaginationParameters<CommentQuery>,
     ) -> Result<FindManyCommentsResponse, Box<dyn Error>>;
 
     async fn delete_many_comments_by_article_id(
@@ -1,8 +1,10 @@
 use async_trait::async_trait;
+use chrono::NaiveDateTime as DateTime;
 use std::error::Error;
 use uuid::Uuid;
 
-use crate::core::pagination::PaginationParameters;
+use crate::core::pagination::{CursorParameters, PaginationParameters};
+use crate::core::query::{DateRange, QuerySpec, SortField};
 use crate::domain::domain_entities::article::Article;
 use crate::domain::domain_entities::slug::Slug;
 
@@ -17,8 +19,24 @@ pub enum ArticleQueryType {
     Title(String),
     Tag(i32),
     Author(Uuid),
+    CreatedAt(DateRange),
 }
 
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ArticleSortField {
+    CreatedAt,
+    Title,
+}
+
+impl SortField for ArticleSortField {
+    const ALLOWED: &'static [(&'static str, Self)] = &[
+        ("createdAt", ArticleSortField::CreatedAt),
+        ("title", ArticleSortField::Title),
+    ];
+}
+
+pub type ArticleQuery = QuerySpec<ArticleQueryType, ArticleSortField>;
+
 #[cfg_attr(test, automock)]
 #[async_trait]
 pub trait ArticleRepositoryTrait {
@@ -28,13 +46,40 @@ pub trait ArticleRepositoryTrait {
 
     async fn find_by_slug(&self, slug: &Slug) -> Result<Option<Article>, Box<dyn Error>>;
 
+    /// Finds every article the user authored, approved or not, the most recent first.
+    async fn find_many_by_author(&self, author_id: Uuid) -> Result<Vec<Article>, Box<dyn Error>>;
+
     async fn find_many(
         &self,
-        params: PaginationParameters<ArticleQueryType>,
+        params: PaginationParameters<ArticleQuery>,
         show_only_approved_state: Option<bool>,
     ) -> Result<FindManyArticlesResponse, Box<dyn Error>>;
 
-    async fn get_home_articles(&self) -> Result<Vec<Article>, Box<dyn Error>>;
+    /// Returns one article more than a page when there's any left, see `CursorPage::from_window`.
+    async fn find_many_by_cursor(
+        &self,
+        params: CursorParameters<ArticleQueryType, Uuid>,
+        show_only_approved_state: Option<bool>,
+    ) -> Result<Vec<Article>, Box<dyn Error>>;
+
+    /// Finds approved articles whose pin hasn't expired by `now`, ordered by their pin order.
+    async fn find_pinned_articles(
+        &self,
+        now: DateTime,
+        limit: u64,
+    ) -> Result<Vec<Article>, Box<dyn Error>>;
+
+    /// Finds approved articles for the home page, the featured ones first and then the most recent.
+    async fn get_home_articles(&self, limit: u64) -> Result<Vec<Article>, Box<dyn Error>>;
+
+    /// Finds approved articles, other than the given one, that might be related to it: the ones sharing its tag or
+    /// its author, and the ones created since `since`. The most recent come first.
+    async fn find_related_candidates(
+        &self,
+        article: &Article,
+        since: DateTime,
+        limit: u64,
+    ) -> Result<Vec<Article>, Box<dyn Error>>;
 
     async fn save(&self, article: Article) -> Result<Article, Box<dyn Error>>;
 }
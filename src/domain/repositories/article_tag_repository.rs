@@ -1,6 +1,7 @@
 use std::error::Error;
 
 use crate::core::pagination::PaginationParameters;
+use crate::core::query::{QuerySpec, SortField};
 use crate::domain::domain_entities::article_tag::{ArticleTag, DraftArticleTag};
 use async_trait::async_trait;
 
@@ -15,6 +16,22 @@ pub enum ArticleTagQueryType {
     Value(String),
 }
 
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ArticleTagSortField {
+    /// The order the tags were created in.
+    Id,
+    Value,
+}
+
+impl SortField for ArticleTagSortField {
+    const ALLOWED: &'static [(&'static str, Self)] = &[
+        ("id", ArticleTagSortField::Id),
+        ("value", ArticleTagSortField::Value),
+    ];
+}
+
+pub type ArticleTagQuery = QuerySpec<ArticleTagQueryType, ArticleTagSortField>;
+
 #[cfg_attr(test, automock)]
 #[async_trait]
 pub trait ArticleTagRepositoryTrait {
@@ -26,7 +43,7 @@ pub trait ArticleTagRepositoryTrait {
 
     async fn find_many(
         &self,
-        params: PaginationParameters<ArticleTagQueryType>,
+        params: PaginationParameters<ArticleTagQuery>,
     ) -> Result<FindManyArticleTagsResponse, Box<dyn Error>>;
 
     async fn save(&self, article_tag: ArticleTag) -> Result<ArticleTag, Box<dyn Error>>;
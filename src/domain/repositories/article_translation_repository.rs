@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article_translation::ArticleTranslation;
+use crate::domain::domain_entities::locale::Locale;
+use crate::domain::domain_entities::slug::Slug;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ArticleTranslationRepositoryTrait {
+    async fn find_by_article_id_and_locale(
+        &self,
+        article_id: Uuid,
+        locale: Locale,
+    ) -> Result<Option<ArticleTranslation>, Box<dyn Error>>;
+
+    /// Gets the translations of all the given articles, in any locale.
+    async fn find_many_by_article_ids(
+        &self,
+        article_ids: Vec<Uuid>,
+    ) -> Result<Vec<ArticleTranslation>, Box<dyn Error>>;
+
+    async fn find_by_slug(&self, slug: &Slug)
+        -> Result<Option<ArticleTranslation>, Box<dyn Error>>;
+
+    /// Creates the translation, or replaces the article's existing one in the same locale.
+    async fn save(
+        &self,
+        translation: ArticleTranslation,
+    ) -> Result<ArticleTranslation, Box<dyn Error>>;
+
+    async fn delete(&self, translation: ArticleTranslation) -> Result<(), Box<dyn Error>>;
+}
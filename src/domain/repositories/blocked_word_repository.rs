@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::blocked_word::BlockedWord;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait BlockedWordRepositoryTrait {
+    async fn create(&self, blocked_word: BlockedWord) -> Result<BlockedWord, Box<dyn Error>>;
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<BlockedWord>, Box<dyn Error>>;
+
+    /// Lists the whole word filter, from the oldest to the most recent entry.
+    async fn find_all(&self) -> Result<Vec<BlockedWord>, Box<dyn Error>>;
+
+    async fn save(&self, blocked_word: BlockedWord) -> Result<BlockedWord, Box<dyn Error>>;
+
+    async fn delete(&self, blocked_word: BlockedWord) -> Result<(), Box<dyn Error>>;
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::comment_edit::CommentEdit;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait CommentEditRepositoryTrait {
+    async fn create(&self, comment_edit: CommentEdit) -> Result<CommentEdit, Box<dyn Error>>;
+
+    /// Lists the comment's edits from the most recent to the oldest.
+    async fn find_many_by_comment_id(
+        &self,
+        comment_id: Uuid,
+    ) -> Result<Vec<CommentEdit>, Box<dyn Error>>;
+}
@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::core::pagination::PaginationParameters;
+use crate::domain::domain_entities::comment_mention::CommentMention;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[derive(Debug)]
+pub struct FindManyCommentMentionsResponse(pub Vec<CommentMention>, pub u64);
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait CommentMentionRepositoryTrait {
+    /// Replaces every mention of the comment by the given ones.
+    async fn set_mentions(
+        &self,
+        comment_id: Uuid,
+        mentions: Vec<CommentMention>,
+    ) -> Result<(), Box<dyn Error>>;
+
+    async fn find_many_by_comment_ids(
+        &self,
+        comment_ids: Vec<Uuid>,
+    ) -> Result<Vec<CommentMention>, Box<dyn Error>>;
+
+    /// Lists the mentions of the user on active comments, from the most recent to the oldest.
+    async fn find_many_by_user_id(
+        &self,
+        user_id: Uuid,
+        params: PaginationParameters<()>,
+    ) -> Result<FindManyCommentMentionsResponse, Box<dyn Error>>;
+}
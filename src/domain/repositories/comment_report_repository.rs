@@ -2,8 +2,11 @@ use async_trait::async_trait;
 use std::error::Error;
 use uuid::Uuid;
 
-use crate::core::pagination::PaginationParameters;
-use crate::domain::domain_entities::comment_report::{CommentReport, DraftCommentReport};
+use crate::core::pagination::{CursorParameters, PaginationParameters};
+use crate::core::query::{DateRange, QuerySpec, SortField};
+use crate::domain::domain_entities::comment_report::{
+    CommentReport, DraftCommentReport, ReportReason,
+};
 
 #[cfg(test)]
 use mockall::automock;
@@ -16,8 +19,26 @@ pub enum CommentReportQueryType {
     SolvedBy(Uuid),
     Solved(bool),
     Content(String),
+    CreatedAt(DateRange),
+    Reason(ReportReason),
+    AssignedTo(Uuid),
 }
 
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum CommentReportSortField {
+    CreatedAt,
+    Priority,
+}
+
+impl SortField for CommentReportSortField {
+    const ALLOWED: &'static [(&'static str, Self)] = &[
+        ("createdAt", CommentReportSortField::CreatedAt),
+        ("priority", CommentReportSortField::Priority),
+    ];
+}
+
+pub type CommentReportQuery = QuerySpec<CommentReportQueryType, CommentReportSortField>;
+
 #[cfg_attr(test, automock)]
 #[async_trait]
 pub trait CommentReportRepositoryTrait {
@@ -33,10 +54,40 @@ pub trait CommentReportRepositoryTrait {
 
     async fn find_many(
         &self,
-        params: PaginationParameters<CommentReportQueryType>,
+        params: PaginationParameters<CommentReportQuery>,
     ) -> Result<FindManyCommentReportsResponse, Box<dyn Error>>;
 
+    /// Returns one report more than a page when there's any left, see `CursorPage::from_window`.
+    async fn find_many_by_cursor(
+        &self,
+        params: CursorParameters<CommentReportQueryType, i32>,
+    ) -> Result<Vec<CommentReport>, Box<dyn Error>>;
+
+    /// The reports of a comment that no moderator has solved yet.
+    async fn find_many_unsolved_by_comment_id(
+        &self,
+        comment_id: Uuid,
+    ) -> Result<Vec<CommentReport>, Box<dyn Error>>;
+
+    /// Every report the user made, solved or not.
+    async fn find_many_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<CommentReport>, Box<dyn Error>>;
+
     async fn save(&self, comment_report: CommentReport) -> Result<CommentReport, Box<dyn Error>>;
 
+    /// Saves the reports and hides the comment they were made on at once, so that a comment is never hidden without
+    /// its reports telling so.
+    async fn save_many_hiding_comment(
+        &self,
+        comment_id: Uuid,
+        comment_reports: Vec<CommentReport>,
+    ) -> Result<Vec<CommentReport>, Box<dyn Error>>;
+
+    /// Saves a resolved report and applies its resolution to the reported comment at once, either hiding or deleting
+    /// it. The comment's other unsolved reports get solved the same way, as there's nothing left to do about them.
+    async fn solve(&self, comment_report: CommentReport) -> Result<CommentReport, Box<dyn Error>>;
+
     async fn delete(&self, comment_report: CommentReport) -> Result<(), Box<dyn Error>>;
 }
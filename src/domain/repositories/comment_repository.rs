@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use chrono::NaiveDateTime as DateTime;
 use std::error::Error;
 use uuid::Uuid;
 
@@ -14,6 +15,27 @@ pub trait CommentRepositoryTrait {
 
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Comment>, Box<dyn Error>>;
 
+    /// Finds every comment, active or not, the author posted from `since` onwards.
+    async fn find_many_by_author_since(
+        &self,
+        author_id: Uuid,
+        since: DateTime,
+    ) -> Result<Vec<Comment>, Box<dyn Error>>;
+
+    /// Finds every comment, active or not, the author posted, the most recent first.
+    async fn find_many_by_author(&self, author_id: Uuid) -> Result<Vec<Comment>, Box<dyn Error>>;
+
+    /// Counts the author's published (active) comments.
+    async fn count_active_by_author(&self, author_id: Uuid) -> Result<u64, Box<dyn Error>>;
+
+    /// Finds the author's latest published (active) comments on approved articles, the most recent first.
+    async fn find_recent_active_by_author(
+        &self,
+        author_id: Uuid,
+        limit: u64,
+    ) -> Result<Vec<Comment>, Box<dyn Error>>;
+
+    /// Deletes the comment. Its replies are kept, becoming top-level comments.
     async fn delete(&self, comment: Comment) -> Result<(), Box<dyn Error>>;
 
     async fn save(&self, comment: Comment) -> Result<Comment, Box<dyn Error>>;
@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::core::pagination::PaginationParameters;
+use crate::domain::domain_entities::comment::Comment;
+use crate::domain::domain_entities::comment_review::CommentReview;
+use crate::domain::domain_entities::role::Role;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[derive(Debug)]
+pub struct FindManyPendingCommentsResponse(pub Vec<Comment>, pub u64);
+
+/// What tells how much an author is trusted: their role, and whether a moderator has ever approved one of their
+/// comments.
+#[derive(Debug, Clone)]
+pub struct AuthorTrust {
+    pub author_id: Uuid,
+    pub role: Role,
+    pub has_approved_review: bool,
+}
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait CommentReviewRepositoryTrait {
+    async fn create(&self, comment_review: CommentReview) -> Result<CommentReview, Box<dyn Error>>;
+
+    async fn find_many_by_comment_ids(
+        &self,
+        comment_ids: Vec<Uuid>,
+    ) -> Result<Vec<CommentReview>, Box<dyn Error>>;
+
+    /// Pages through the comments pending review, from the oldest to the most recent.
+    async fn find_many_pending_comments(
+        &self,
+        params: PaginationParameters<()>,
+    ) -> Result<FindManyPendingCommentsResponse, Box<dyn Error>>;
+
+    /// Whether a moderator has ever approved a comment of the author's.
+    async fn author_has_approved_review(&self, author_id: Uuid) -> Result<bool, Box<dyn Error>>;
+
+    /// Same as `author_has_approved_review`, but for many authors at once, along with their roles. Authors that
+    /// don't exist are left out.
+    async fn find_many_authors_trust(
+        &self,
+        author_ids: Vec<Uuid>,
+    ) -> Result<Vec<AuthorTrust>, Box<dyn Error>>;
+
+    async fn save(&self, comment_review: CommentReview) -> Result<CommentReview, Box<dyn Error>>;
+
+    /// Saves the reviews and publishes the comments of the approved ones at once, so that a batch is never left
+    /// half reviewed.
+    async fn save_many_publishing_approved(
+        &self,
+        comment_reviews: Vec<CommentReview>,
+    ) -> Result<Vec<CommentReview>, Box<dyn Error>>;
+}
@@ -1,9 +1,11 @@
-use crate::core::pagination::PaginationParameters;
+use crate::core::pagination::{CursorParameters, PaginationParameters};
+use crate::core::query::{DateRange, QuerySpec};
 use async_trait::async_trait;
 use std::error::Error;
 use uuid::Uuid;
 
 use crate::domain::domain_entities::comment_with_author::CommentWithAuthor;
+use crate::domain::repositories::article_comment_repository::CommentSortField;
 
 #[cfg(test)]
 use mockall::automock;
@@ -18,15 +20,37 @@ pub struct FindManyCommentsWithAuthorResponse(
 pub enum CommentWithAuthorQueryType {
     Author(Uuid),
     Content(String),
+    CreatedAt(DateRange),
 }
 
+pub type CommentWithAuthorQuery = QuerySpec<CommentWithAuthorQueryType, CommentSortField>;
+
 #[cfg_attr(test, automock)]
 #[async_trait]
 pub trait CommentUserArticleRepositoryTrait {
+    /// Lists the article's top-level comments along with their replies count. Unless `include_inactive` is set, only
+    /// active comments (and their active replies) are taken, but an inactive comment with active replies is kept so
+    /// that its thread isn't lost.
     async fn find_many_comments(
         &self,
         article_id: Uuid,
         include_inactive: bool,
-        params: PaginationParameters<CommentWithAuthorQueryType>,
+        params: PaginationParameters<CommentWithAuthorQuery>,
+    ) -> Result<FindManyCommentsWithAuthorResponse, Box<dyn Error>>;
+
+    /// Returns one comment more than a page when there's any left, see `CursorPage::from_window`.
+    async fn find_many_comments_by_cursor(
+        &self,
+        article_id: Uuid,
+        include_inactive: bool,
+        params: CursorParameters<CommentWithAuthorQueryType, Uuid>,
+    ) -> Result<Vec<CommentWithAuthor>, Box<dyn Error>>;
+
+    /// Lists the direct replies to a comment, in the same way `find_many_comments` does.
+    async fn find_many_replies(
+        &self,
+        parent_id: Uuid,
+        include_inactive: bool,
+        params: PaginationParameters<CommentWithAuthorQuery>,
     ) -> Result<FindManyCommentsWithAuthorResponse, Box<dyn Error>>;
 }
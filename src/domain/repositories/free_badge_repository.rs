@@ -1,12 +1,19 @@
 use crate::core::pagination::PaginationParameters;
 use crate::domain::domain_entities::free_badge::FreeBadge;
 use async_trait::async_trait;
+use chrono::NaiveDateTime as DateTime;
 use std::error::Error;
 
 #[cfg(test)]
 use mockall::automock;
 use uuid::Uuid;
 
+#[derive(Clone)]
+pub enum FreeBadgeQuery {
+    /// Only the badges that haven't expired by the given moment.
+    AvailableAt(DateTime),
+}
+
 #[derive(Debug)]
 pub struct FindManyFreeBadgesResponse(pub Vec<FreeBadge>, pub u64);
 
@@ -18,7 +25,7 @@ pub trait FreeBadgeRepositoryTrait {
     async fn delete(&self, free_badge: FreeBadge) -> Result<(), Box<dyn Error>>;
     async fn find_many(
         &self,
-        params: PaginationParameters<()>,
+        params: PaginationParameters<FreeBadgeQuery>,
     ) -> Result<FindManyFreeBadgesResponse, Box<dyn Error>>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<FreeBadge>, Box<dyn Error>>;
 }
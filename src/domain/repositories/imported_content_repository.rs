@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use std::error::Error;
+
+use crate::domain::domain_entities::imported_content::ImportedContent;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ImportedContentRepositoryTrait {
+    async fn create(
+        &self,
+        imported_content: ImportedContent,
+    ) -> Result<ImportedContent, Box<dyn Error>>;
+
+    /// Finds every record already imported from the given source (e.g. a WordPress site URL).
+    async fn find_many_by_source(
+        &self,
+        source: String,
+    ) -> Result<Vec<ImportedContent>, Box<dyn Error>>;
+}
@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::core::pagination::PaginationParameters;
+use crate::core::query::{DateRange, QuerySpec, SortField};
+use crate::domain::domain_entities::media::{Media, MediaUsage};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[derive(Debug)]
+pub struct FindManyMediaResponse(pub Vec<Media>, pub u64);
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum MediaQueryType {
+    /// Matches the file name or the alternative text.
+    Text(String),
+    Tag(String),
+    Uploader(Uuid),
+    CreatedAt(DateRange),
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum MediaSortField {
+    CreatedAt,
+    FileName,
+    Size,
+}
+
+impl SortField for MediaSortField {
+    const ALLOWED: &'static [(&'static str, Self)] = &[
+        ("createdAt", MediaSortField::CreatedAt),
+        ("fileName", MediaSortField::FileName),
+        ("size", MediaSortField::Size),
+    ];
+}
+
+pub type MediaQuery = QuerySpec<MediaQueryType, MediaSortField>;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait MediaRepositoryTrait {
+    async fn create(&self, media: Media) -> Result<Media, Box<dyn Error>>;
+
+    async fn save(&self, media: Media) -> Result<Media, Box<dyn Error>>;
+
+    async fn delete(&self, media: Media) -> Result<(), Box<dyn Error>>;
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Media>, Box<dyn Error>>;
+
+    async fn find_many(
+        &self,
+        params: PaginationParameters<MediaQuery>,
+    ) -> Result<FindManyMediaResponse, Box<dyn Error>>;
+
+    /// Finds the articles (as cover or within their content), announcements and free badges displaying the given URL.
+    async fn find_usages(&self, url: &str) -> Result<Vec<MediaUsage>, Box<dyn Error>>;
+}
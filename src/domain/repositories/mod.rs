@@ -1,11 +1,21 @@
 pub mod announcements_repository;
+pub mod article_coauthor_repository;
 pub mod article_comment_repository;
 pub mod article_repository;
 pub mod article_tag_repository;
+pub mod article_translation_repository;
+pub mod blocked_word_repository;
+pub mod comment_edit_repository;
+pub mod comment_mention_repository;
 pub mod comment_report_repository;
 pub mod comment_repository;
+pub mod comment_review_repository;
 pub mod comment_user_article_repository;
 pub mod free_badge_repository;
+pub mod imported_content_repository;
+pub mod media_repository;
+pub mod reaction_repository;
+pub mod series_repository;
 pub mod team_role_repository;
 pub mod team_user_repository;
 pub mod user_repository;
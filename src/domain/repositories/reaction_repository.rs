@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::reaction::{Reaction, ReactionTarget};
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ReactionRepositoryTrait {
+    async fn find(
+        &self,
+        user_id: Uuid,
+        target: ReactionTarget,
+        target_id: Uuid,
+    ) -> Result<Option<Reaction>, Box<dyn Error>>;
+
+    /// Stores the reaction, replacing the user's previous reaction to the same target, and updates the target's
+    /// reaction counts accordingly.
+    async fn set(&self, reaction: Reaction) -> Result<(), Box<dyn Error>>;
+
+    /// Removes the user's reaction to the target, if any, and updates the target's reaction counts accordingly.
+    async fn remove(
+        &self,
+        user_id: Uuid,
+        target: ReactionTarget,
+        target_id: Uuid,
+    ) -> Result<(), Box<dyn Error>>;
+
+    /// How many times each of the targets has been reacted to with each reaction. Targets without any reaction are
+    /// left out.
+    async fn find_counts(
+        &self,
+        target: ReactionTarget,
+        target_ids: Vec<Uuid>,
+    ) -> Result<HashMap<Uuid, BTreeMap<String, u64>>, Box<dyn Error>>;
+
+    async fn find_many_by_user_id(
+        &self,
+        user_id: Uuid,
+        target: ReactionTarget,
+        target_ids: Vec<Uuid>,
+    ) -> Result<Vec<Reaction>, Box<dyn Error>>;
+
+    /// How many times the user has reacted with each reaction.
+    async fn count_given_by_user(
+        &self,
+        user_id: Uuid,
+    ) -> Result<BTreeMap<String, u64>, Box<dyn Error>>;
+
+    /// How many times the author's published content, that is their approved articles and active comments, has been
+    /// reacted to with each reaction.
+    async fn count_received_by_author(
+        &self,
+        author_id: Uuid,
+    ) -> Result<BTreeMap<String, u64>, Box<dyn Error>>;
+}
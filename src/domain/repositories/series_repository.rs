@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::series::Series;
+use crate::domain::domain_entities::slug::Slug;
+
+#[cfg(test)]
+use mockall::automock;
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait SeriesRepositoryTrait {
+    async fn create(&self, series: Series) -> Result<Series, Box<dyn Error>>;
+
+    async fn save(&self, series: Series) -> Result<Series, Box<dyn Error>>;
+
+    async fn delete(&self, series: Series) -> Result<(), Box<dyn Error>>;
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Series>, Box<dyn Error>>;
+
+    async fn find_by_slug(&self, slug: &Slug) -> Result<Option<Series>, Box<dyn Error>>;
+
+    async fn find_by_article_id(&self, article_id: Uuid) -> Result<Option<Series>, Box<dyn Error>>;
+
+    /// Gets the series' articles, ordered by their position in the series.
+    async fn find_articles(
+        &self,
+        series_id: Uuid,
+        only_approved: bool,
+    ) -> Result<Vec<Article>, Box<dyn Error>>;
+
+    /// Replaces the series' articles, positioning them in the given order.
+    async fn set_articles(
+        &self,
+        series_id: Uuid,
+        articles_ids: Vec<Uuid>,
+    ) -> Result<(), Box<dyn Error>>;
+}
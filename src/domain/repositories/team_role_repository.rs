@@ -5,7 +5,11 @@ use uuid::Uuid;
 #[cfg(test)]
 use mockall::automock;
 
-use crate::{core::pagination::PaginationParameters, domain::domain_entities::team_role::TeamRole};
+use crate::{
+    core::pagination::PaginationParameters,
+    core::query::{QuerySpec, SortField},
+    domain::domain_entities::team_role::TeamRole,
+};
 
 #[derive(Debug)]
 pub struct FindManyTeamRolesResponse(pub Vec<TeamRole>, pub u64);
@@ -15,6 +19,21 @@ pub enum TeamRoleQueryType {
     Title(String),
 }
 
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum TeamRoleSortField {
+    CreatedAt,
+    Title,
+}
+
+impl SortField for TeamRoleSortField {
+    const ALLOWED: &'static [(&'static str, Self)] = &[
+        ("createdAt", TeamRoleSortField::CreatedAt),
+        ("title", TeamRoleSortField::Title),
+    ];
+}
+
+pub type TeamRoleQuery = QuerySpec<TeamRoleQueryType, TeamRoleSortField>;
+
 #[cfg_attr(test, automock)]
 #[async_trait]
 pub trait TeamRoleRepositoryTrait {
@@ -28,6 +47,6 @@ pub trait TeamRoleRepositoryTrait {
 
     async fn find_many(
         &self,
-        params: PaginationParameters<TeamRoleQueryType>,
+        params: PaginationParameters<TeamRoleQuery>,
     ) -> Result<FindManyTeamRolesResponse, Box<dyn Error>>;
 }
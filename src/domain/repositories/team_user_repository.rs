@@ -8,6 +8,7 @@ use uuid::Uuid;
 use mockall::automock;
 
 use crate::core::pagination::PaginationParameters;
+use crate::core::query::{QuerySpec, SortField};
 
 #[derive(Debug)]
 pub struct FindManyTeamUsersResponse(pub Vec<TeamUser>, pub u64);
@@ -18,6 +19,21 @@ pub enum TeamUserQueryType {
     Nickname(String),
 }
 
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum TeamUserSortField {
+    CreatedAt,
+    Nickname,
+}
+
+impl SortField for TeamUserSortField {
+    const ALLOWED: &'static [(&'static str, Self)] = &[
+        ("createdAt", TeamUserSortField::CreatedAt),
+        ("nickname", TeamUserSortField::Nickname),
+    ];
+}
+
+pub type TeamUserQuery = QuerySpec<TeamUserQueryType, TeamUserSortField>;
+
 #[cfg_attr(test, automock)]
 #[async_trait]
 pub trait TeamUserRepositoryTrait {
@@ -25,12 +41,15 @@ pub trait TeamUserRepositoryTrait {
 
     async fn find_by_id(&self, id: Uuid) -> Result<Option<TeamUser>, Box<dyn Error>>;
 
+    /// Nicknames are matched case-insensitively.
+    async fn find_by_nickname(&self, nickname: &str) -> Result<Option<TeamUser>, Box<dyn Error>>;
+
     async fn save(&self, team_user: TeamUser) -> Result<TeamUser, Box<dyn Error>>;
 
     async fn delete(&self, team_role: TeamUser) -> Result<(), Box<dyn Error>>;
 
     async fn find_many(
         &self,
-        params: PaginationParameters<TeamUserQueryType>,
+        params: PaginationParameters<TeamUserQuery>,
     ) -> Result<FindManyTeamUsersResponse, Box<dyn Error>>;
 }
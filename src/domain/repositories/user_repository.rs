@@ -4,6 +4,7 @@ use uuid::Uuid;
 
 use crate::{
     core::pagination::PaginationParameters,
+    core::query::{DateRange, QuerySpec, SortField},
     domain::domain_entities::{role::Role, user::User},
 };
 
@@ -17,8 +18,26 @@ pub struct FindManyUsersResponse(pub Vec<User>, pub u64);
 pub enum UserQueryType {
     Role(Role),
     Nickname(String),
+    CreatedAt(DateRange),
 }
 
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum UserSortField {
+    CreatedAt,
+    Nickname,
+    LastLogin,
+}
+
+impl SortField for UserSortField {
+    const ALLOWED: &'static [(&'static str, Self)] = &[
+        ("createdAt", UserSortField::CreatedAt),
+        ("nickname", UserSortField::Nickname),
+        ("lastLogin", UserSortField::LastLogin),
+    ];
+}
+
+pub type UserQuery = QuerySpec<UserQueryType, UserSortField>;
+
 #[cfg_attr(test, automock)]
 #[async_trait]
 pub trait UserRepositoryTrait {
@@ -31,8 +50,16 @@ pub trait UserRepositoryTrait {
 
     async fn save(&self, user: User) -> Result<User, Box<dyn Error>>;
 
+    /// Deletes the user, handing everything they authored, moderated or uploaded over to the heir so that it's kept.
+    /// Their reactions and mentions go away along with them.
+    async fn delete_transferring_content(
+        &self,
+        user: User,
+        heir_id: Uuid,
+    ) -> Result<(), Box<dyn Error>>;
+
     async fn find_many(
         &self,
-        params: PaginationParameters<UserQueryType>,
+        params: PaginationParameters<UserQuery>,
     ) -> Result<FindManyUsersResponse, Box<dyn Error>>;
 }
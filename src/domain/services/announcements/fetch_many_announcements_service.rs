@@ -1,9 +1,11 @@
 use crate::{
     core::pagination::{PaginationParameters, PaginationResponse, DEFAULT_PER_PAGE},
+    core::query::Sort,
     domain::{
         domain_entities::announcement::Announcement,
         repositories::announcements_repository::{
-            AnnouncementQueryType, AnnouncementRepositoryTrait,
+            AnnouncementQuery, AnnouncementQueryType, AnnouncementRepositoryTrait,
+            AnnouncementSortField,
         },
     },
     error::DomainError,
@@ -13,7 +15,9 @@ use crate::{
 pub struct FetchManyAnnouncementsParams {
     pub per_page: Option<u32>,
     pub page: Option<u32>,
-    pub query: Option<String>,
+    /// Announcements must match all of them.
+    pub filters: Vec<AnnouncementQueryType>,
+    pub sort: Vec<Sort<AnnouncementSortField>>,
 }
 
 pub struct FetchManyAnnouncementsResponse {
@@ -38,14 +42,17 @@ impl<AR: AnnouncementRepositoryTrait> FetchManyAnnouncementsService<AR> {
     ) -> Result<FetchManyAnnouncementsResponse, DomainError> {
         let items_per_page = params.per_page.unwrap_or(DEFAULT_PER_PAGE as u32);
         let page = params.page.unwrap_or(1).max(1);
-        let query = params.query.map(AnnouncementQueryType::Description);
+        let query = AnnouncementQuery {
+            filters: params.filters,
+            sort: params.sort,
+        };
 
         let announcements = self
             .announcements_repository
             .find_many(PaginationParameters {
                 items_per_page,
                 page,
-                query,
+                query: Some(query),
             })
             .await
             .map_err(|err| generate_service_internal_error(
@@ -66,6 +73,7 @@ mod test {
 
     use crate::{
         core::pagination::PaginationResponse, domain::domain_entities::announcement::Announcement,
+        domain::repositories::announcements_repository::AnnouncementQueryType,
         tests::repositories::announcements_repository::get_announcements_repository,
     };
 
@@ -119,7 +127,8 @@ mod test {
             .exec(super::FetchManyAnnouncementsParams {
                 page: None,
                 per_page: None,
-                query: None,
+                filters: vec![],
+                sort: vec![],
             })
             .await
             .unwrap();
@@ -151,7 +160,8 @@ mod test {
             .exec(super::FetchManyAnnouncementsParams {
                 page: Some(2),
                 per_page: Some(2),
-                query: None,
+                filters: vec![],
+                sort: vec![],
             })
             .await
             .unwrap();
@@ -177,7 +187,8 @@ mod test {
             .exec(super::FetchManyAnnouncementsParams {
                 page: None,
                 per_page: Some(1),
-                query: Some("third".to_string()),
+                filters: vec![AnnouncementQueryType::Description("third".to_string())],
+                sort: vec![],
             })
             .await
             .unwrap();
@@ -1,6 +1,8 @@
 use crate::domain::cryptography::hasher::HasherTrait;
 use crate::domain::domain_entities::role::Role;
 use crate::domain::domain_entities::user::User;
+use crate::domain::politics::word_filter_politics::WordFilterPolitics;
+use crate::domain::repositories::blocked_word_repository::BlockedWordRepositoryTrait;
 use crate::domain::repositories::user_repository::UserRepositoryTrait;
 use crate::error::DomainError;
 use crate::util::generate_service_internal_error;
@@ -9,18 +11,31 @@ pub struct CreateUserParams {
     pub nickname: String,
     pub password: String,
 }
-pub struct CreateUserService<UserRepository: UserRepositoryTrait, Hasher: HasherTrait> {
+pub struct CreateUserService<
+    UserRepository: UserRepositoryTrait,
+    Hasher: HasherTrait,
+    BlockedWordRepository: BlockedWordRepositoryTrait,
+> {
     user_repository: UserRepository,
     hasher: Hasher,
+    blocked_word_repository: BlockedWordRepository,
 }
 
-impl<UserRepositoryType: UserRepositoryTrait, Hasher: HasherTrait>
-    CreateUserService<UserRepositoryType, Hasher>
+impl<
+        UserRepositoryType: UserRepositoryTrait,
+        Hasher: HasherTrait,
+        BlockedWordRepository: BlockedWordRepositoryTrait,
+    > CreateUserService<UserRepositoryType, Hasher, BlockedWordRepository>
 {
-    pub fn new(user_repository: UserRepositoryType, hasher: Hasher) -> Self {
+    pub fn new(
+        user_repository: UserRepositoryType,
+        hasher: Hasher,
+        blocked_word_repository: BlockedWordRepository,
+    ) -> Self {
         CreateUserService {
             user_repository,
             hasher,
+            blocked_word_repository,
         }
     }
 
@@ -53,6 +68,26 @@ impl<UserRepositoryType: UserRepositoryTrait, Hasher: HasherTrait>
             return Err(DomainError::user_already_exists_err(&params.nickname));
         }
 
+        let blocked_words = self
+            .blocked_word_repository
+            .find_all()
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Create User Service, while fetching the blocked words",
+                    err,
+                )
+            })?;
+
+        // a nickname can't be masked nor hidden, so any blocked word keeps it from being taken
+        if !WordFilterPolitics::filter(&blocked_words)
+            .apply(&params.nickname)
+            .is_clean()
+        {
+            return Err(DomainError::bad_request_err()
+                .with_message("The nickname contains words that aren't allowed."));
+        }
+
         let hashed_password = self.hasher.hash(params.password);
 
         let user = User::new(params.nickname, hashed_password, Some(role));
@@ -68,11 +103,15 @@ impl<UserRepositoryType: UserRepositoryTrait, Hasher: HasherTrait>
 
 #[cfg(test)]
 mod test {
-    use super::{CreateUserParams, User};
+    use uuid::Uuid;
+
+    use super::{CreateUserParams, DomainError, User};
+    use crate::domain::domain_entities::blocked_word::{BlockedWord, BlockedWordAction};
     use crate::domain::{
         cryptography::hasher::MockHasherTrait,
         repositories::user_repository::MockUserRepositoryTrait,
     };
+    use crate::tests::repositories::blocked_word_repository::get_blocked_word_repository;
 
     #[tokio::test]
     async fn test() {
@@ -99,11 +138,30 @@ mod test {
             .expect_hash()
             .returning(|param_password| format!("{}--hashed", param_password));
 
+        let (blocked_word_db, blocked_word_repository) = get_blocked_word_repository();
+
+        blocked_word_db.lock().unwrap().push(BlockedWord::new(
+            "admin".into(),
+            false,
+            BlockedWordAction::Flag,
+            Uuid::new_v4(),
+        ));
+
         let service = super::CreateUserService {
             user_repository: mocked_repo,
             hasher: mocked_hasher,
+            blocked_word_repository,
         };
 
+        let result = service
+            .exec(CreateUserParams {
+                nickname: "4dm1n".to_string(),
+                password: "madalena123".to_string(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(DomainError::BadRequest(_))));
+
         let result = service
             .exec(CreateUserParams {
                 nickname: "Madalena".to_string(),
@@ -0,0 +1,172 @@
+use uuid::Uuid;
+
+use crate::domain::cache::related_articles_cache::RelatedArticlesCacheTrait;
+use crate::domain::cryptography::both::HasherAndComparerTrait;
+use crate::domain::domain_entities::user::User;
+use crate::domain::repositories::user_repository::UserRepositoryTrait;
+use crate::error::DomainError;
+use crate::util::generate_service_internal_error;
+
+/// The password is asked once more, so that a stolen session isn't enough to remove an account.
+pub struct DeleteAccountParams {
+    pub user_id: Uuid,
+    pub password: String,
+}
+
+pub struct DeleteAccountService<UserRepository, HasherAndComparer, RelatedArticlesCache>
+where
+    UserRepository: UserRepositoryTrait,
+    HasherAndComparer: HasherAndComparerTrait,
+    RelatedArticlesCache: RelatedArticlesCacheTrait,
+{
+    user_repository: UserRepository,
+    hasher_and_comparer: HasherAndComparer,
+    related_articles_cache: RelatedArticlesCache,
+}
+
+impl<UserRepository, HasherAndComparer, RelatedArticlesCache>
+    DeleteAccountService<UserRepository, HasherAndComparer, RelatedArticlesCache>
+where
+    UserRepository: UserRepositoryTrait,
+    HasherAndComparer: HasherAndComparerTrait,
+    RelatedArticlesCache: RelatedArticlesCacheTrait,
+{
+    pub fn new(
+        user_repository: UserRepository,
+        hasher_and_comparer: HasherAndComparer,
+        related_articles_cache: RelatedArticlesCache,
+    ) -> Self {
+        DeleteAccountService {
+            user_repository,
+            hasher_and_comparer,
+            related_articles_cache,
+        }
+    }
+
+    /// Removes the user along with their credentials. Their articles, comments and the like are kept, but from then
+    /// on attributed to the removed user placeholder, see `User::REMOVED_USER_NICKNAME`.
+    pub async fn exec(&self, params: DeleteAccountParams) -> Result<(), DomainError> {
+        let user = self
+            .user_repository
+            .find_by_id(&params.user_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Delete Account Service, while fetching user from database",
+                    err,
+                )
+            })?
+            .ok_or(DomainError::resource_not_found_err())?;
+
+        if !self
+            .hasher_and_comparer
+            .compare(&params.password, user.password())
+        {
+            return Err(DomainError::invalid_credentials_err());
+        }
+
+        let heir = self.get_removed_user_placeholder().await?;
+
+        self.user_repository
+            .delete_transferring_content(user, heir.id())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Delete Account Service, while deleting the user",
+                    err,
+                )
+            })?;
+
+        // the articles changed authors, which weighs on their rankings
+        self.related_articles_cache.invalidate();
+
+        Ok(())
+    }
+
+    /// The placeholder is created along with the first account removal.
+    async fn get_removed_user_placeholder(&self) -> Result<User, DomainError> {
+        let placeholder = self
+            .user_repository
+            .find_by_nickname(User::REMOVED_USER_NICKNAME)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Delete Account Service, while fetching the removed user placeholder",
+                    err,
+                )
+            })?;
+
+        if let Some(placeholder) = placeholder {
+            return Ok(placeholder);
+        }
+
+        self.user_repository
+            .create(User::new_removed_user_placeholder())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Delete Account Service, while creating the removed user placeholder",
+                    err,
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::domain::cryptography::{comparer::ComparerTrait, hasher::HasherTrait};
+    use crate::domain::domain_entities::role::Role;
+    use crate::infra::cache::in_memory_related_articles_cache::InMemoryRelatedArticlesCache;
+    use crate::infra::cryptography::MockedAuthHasherAndVerifier;
+    use crate::tests::repositories::users_repository::get_user_repository;
+
+    #[tokio::test]
+    async fn test_delete_account() {
+        let (user_db, user_repository) = get_user_repository();
+
+        let password = MockedAuthHasherAndVerifier.hash("password".into());
+        let user = User::new("Floricultor".into(), password.clone(), Some(Role::User));
+        let other_user = User::new("Samambaia".into(), password, Some(Role::User));
+        user_db.lock().unwrap().push(user.clone());
+        user_db.lock().unwrap().push(other_user.clone());
+
+        let sut = DeleteAccountService::new(
+            user_repository,
+            MockedAuthHasherAndVerifier,
+            InMemoryRelatedArticlesCache::new(),
+        );
+
+        let wrong_password = sut
+            .exec(DeleteAccountParams {
+                user_id: user.id(),
+                password: "wrong".into(),
+            })
+            .await;
+
+        assert!(wrong_password.is_err());
+        assert_eq!(user_db.lock().unwrap().len(), 2);
+
+        sut.exec(DeleteAccountParams {
+            user_id: user.id(),
+            password: "password".into(),
+        })
+        .await
+        .unwrap();
+
+        sut.exec(DeleteAccountParams {
+            user_id: other_user.id(),
+            password: "password".into(),
+        })
+        .await
+        .unwrap();
+
+        let users = user_db.lock().unwrap().clone();
+
+        // a single placeholder inherits from every removed user
+        assert_eq!(users.len(), 1);
+        assert_eq!(users[0].nickname(), User::REMOVED_USER_NICKNAME);
+        assert_eq!(users[0].password(), User::UNUSABLE_PASSWORD);
+        assert!(!MockedAuthHasherAndVerifier.compare("", users[0].password()));
+    }
+}
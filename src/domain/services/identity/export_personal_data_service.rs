@@ -0,0 +1,197 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::{
+    article::Article, comment::Comment, comment_report::CommentReport, user::User,
+};
+use crate::domain::repositories::{
+    article_repository::ArticleRepositoryTrait,
+    comment_report_repository::CommentReportRepositoryTrait,
+    comment_repository::CommentRepositoryTrait, user_repository::UserRepositoryTrait,
+};
+use crate::error::DomainError;
+use crate::util::generate_service_internal_error;
+
+pub struct ExportPersonalDataParams {
+    pub user_id: Uuid,
+}
+
+/// Everything kept about a user, as the LGPD entitles them to have it.
+#[derive(Debug)]
+pub struct PersonalDataExport {
+    pub user: User,
+    /// Every comment of the user, including the removed or hidden ones.
+    pub comments: Vec<Comment>,
+    pub reports: Vec<CommentReport>,
+    /// Every article the user authored, including the unapproved ones.
+    pub articles: Vec<Article>,
+}
+
+pub struct ExportPersonalDataService<UR, CR, CRR, AR>
+where
+    UR: UserRepositoryTrait,
+    CR: CommentRepositoryTrait,
+    CRR: CommentReportRepositoryTrait,
+    AR: ArticleRepositoryTrait,
+{
+    user_repository: UR,
+    comment_repository: CR,
+    comment_report_repository: CRR,
+    article_repository: AR,
+}
+
+impl<UR, CR, CRR, AR> ExportPersonalDataService<UR, CR, CRR, AR>
+where
+    UR: UserRepositoryTrait,
+    CR: CommentRepositoryTrait,
+    CRR: CommentReportRepositoryTrait,
+    AR: ArticleRepositoryTrait,
+{
+    pub fn new(
+        user_repository: UR,
+        comment_repository: CR,
+        comment_report_repository: CRR,
+        article_repository: AR,
+    ) -> Self {
+        ExportPersonalDataService {
+            user_repository,
+            comment_repository,
+            comment_report_repository,
+            article_repository,
+        }
+    }
+
+    pub async fn exec(
+        &self,
+        params: ExportPersonalDataParams,
+    ) -> Result<PersonalDataExport, DomainError> {
+        let user = self
+            .user_repository
+            .find_by_id(&params.user_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Export Personal Data Service, while fetching user from database",
+                    err,
+                )
+            })?
+            .ok_or(DomainError::resource_not_found_err())?;
+
+        let comments = self
+            .comment_repository
+            .find_many_by_author(user.id())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Export Personal Data Service, while fetching the user's comments",
+                    err,
+                )
+            })?;
+
+        let reports = self
+            .comment_report_repository
+            .find_many_by_user_id(user.id())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Export Personal Data Service, while fetching the user's reports",
+                    err,
+                )
+            })?;
+
+        let articles = self
+            .article_repository
+            .find_many_by_author(user.id())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Export Personal Data Service, while fetching the user's articles",
+                    err,
+                )
+            })?;
+
+        Ok(PersonalDataExport {
+            user,
+            comments,
+            reports,
+            articles,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::domain::domain_entities::comment_report::{DraftCommentReport, ReportReason};
+    use crate::domain::domain_entities::role::Role;
+    use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::comment_report_repository::get_comment_report_repository;
+    use crate::tests::repositories::comment_repository::get_comment_repository;
+    use crate::tests::repositories::users_repository::get_user_repository;
+
+    #[tokio::test]
+    async fn test_export_personal_data() {
+        let (user_db, user_repository) = get_user_repository();
+        let (comment_db, comment_repository) = get_comment_repository();
+        let (report_db, comment_report_repository) =
+            get_comment_report_repository(comment_db.clone());
+        let (article_db, article_repository) = get_article_repository();
+
+        let user = User::new("Floricultor".into(), "password".into(), Some(Role::Writer));
+        let other_user = User::new("Samambaia".into(), "password".into(), Some(Role::User));
+        user_db.lock().unwrap().push(user.clone());
+        user_db.lock().unwrap().push(other_user.clone());
+
+        let article = Article::new(
+            user.id(),
+            "Unapproved article".into(),
+            "content".into(),
+            "url".into(),
+            1,
+            "tag".into(),
+        );
+        article_db.lock().unwrap().push(article.clone());
+
+        let mut hidden_comment = Comment::new(user.id(), Some(article.id()), "hidden".into());
+        hidden_comment.set_is_active(false);
+        let other_comment = Comment::new(other_user.id(), Some(article.id()), "other".into());
+        comment_db.lock().unwrap().push(hidden_comment.clone());
+        comment_db.lock().unwrap().push(other_comment.clone());
+
+        let report = DraftCommentReport::new(
+            other_comment.id(),
+            user.id(),
+            "spam".into(),
+            ReportReason::Spam,
+        )
+        .to_comment_report(1);
+        report_db.lock().unwrap().push(report.clone());
+
+        let sut = ExportPersonalDataService::new(
+            user_repository,
+            comment_repository,
+            comment_report_repository,
+            article_repository,
+        );
+
+        let export = sut
+            .exec(ExportPersonalDataParams { user_id: user.id() })
+            .await
+            .unwrap();
+
+        assert_eq!(export.user, user);
+        assert_eq!(export.comments, vec![hidden_comment]);
+        assert_eq!(export.reports, vec![report]);
+        assert_eq!(export.articles, vec![article]);
+
+        let other_export = sut
+            .exec(ExportPersonalDataParams {
+                user_id: other_user.id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(other_export.comments, vec![other_comment]);
+        assert!(other_export.reports.is_empty());
+        assert!(other_export.articles.is_empty());
+    }
+}
@@ -1,7 +1,8 @@
 use crate::core::pagination::{PaginationParameters, PaginationResponse, DEFAULT_PER_PAGE};
+use crate::core::query::Sort;
 use crate::domain::domain_entities::user::User;
 use crate::domain::repositories::user_repository::{
-    FindManyUsersResponse, UserQueryType, UserRepositoryTrait,
+    FindManyUsersResponse, UserQuery, UserQueryType, UserRepositoryTrait, UserSortField,
 };
 use crate::error::DomainError;
 use crate::util::generate_service_internal_error;
@@ -15,7 +16,9 @@ pub struct FetchManyUsersResponse {
 pub struct FetchManyUsersParams {
     pub page: Option<u32>,
     pub per_page: Option<u32>,
-    pub query: Option<UserQueryType>,
+    /// Users must match all of them.
+    pub filters: Vec<UserQueryType>,
+    pub sort: Vec<Sort<UserSortField>>,
 }
 
 pub struct FetchManyUsersService<UserRepository: UserRepositoryTrait> {
@@ -53,7 +56,10 @@ impl<UserRepository: UserRepositoryTrait> FetchManyUsersService<UserRepository>
             .find_many(PaginationParameters {
                 items_per_page,
                 page,
-                query: params.query,
+                query: Some(UserQuery {
+                    filters: params.filters,
+                    sort: params.sort,
+                }),
             })
             .await;
 
@@ -76,11 +82,11 @@ impl<UserRepository: UserRepositoryTrait> FetchManyUsersService<UserRepository>
 
 #[cfg(test)]
 mod test {
+    use crate::core::query::SortDirection;
     use crate::domain::domain_entities::role::Role;
-    use crate::domain::repositories::user_repository::MockUserRepositoryTrait;
+    use crate::tests::repositories::users_repository::get_user_repository;
 
     use super::*;
-    use std::sync::{Arc, Mutex};
     use tokio;
 
     #[tokio::test]
@@ -88,68 +94,14 @@ mod test {
         // MOCKING THE DATABASE SEED ROLES
         let user_1 = User::new("Flori".into(), "vsjkvdsknjsd".into(), Some(Role::Admin));
         let user_2 = User::new("Parme".into(), "vsjkvdsknjsd".into(), Some(Role::Coord));
-
-        // MOCKING THE DATABASE
-        let user_db: Arc<Mutex<Vec<User>>> =
-            Arc::new(Mutex::new(vec![user_1.clone(), user_2.clone()]));
+        let user_3 = User::new("Pamela".into(), "vsjkvdsknjsd".into(), Some(Role::Admin));
 
         // MOCKING THE REPOSITORY
-        let mut mocked_user_repository = MockUserRepositoryTrait::new();
-
-        let to_move_tr_db = Arc::clone(&user_db);
-        mocked_user_repository
-            .expect_find_many()
-            .returning(move |params| {
-                let PaginationParameters {
-                    page,
-                    items_per_page,
-                    query,
-                } = params;
-
-                let mut users = Vec::new();
-
-                if query.is_none() {
-                    users = to_move_tr_db.lock().unwrap().clone();
-                } else {
-                    match query.unwrap() {
-                        UserQueryType::Nickname(nickname) => {
-                            for tr in to_move_tr_db.lock().unwrap().iter() {
-                                if tr
-                                    .nickname()
-                                    .to_lowercase()
-                                    .contains(&nickname.to_lowercase())
-                                {
-                                    users.push(tr.clone());
-                                }
-                            }
-                        }
-                        UserQueryType::Role(role) => {
-                            for tr in to_move_tr_db.lock().unwrap().iter() {
-                                if tr.role().unwrap().eq(&role) {
-                                    users.push(tr.clone());
-                                }
-                            }
-                        }
-                    }
-                }
-
-                let total_before_paginating = users.len();
-
-                let leap = (page - 1) * items_per_page;
-
-                let mut res_users = vec![];
-
-                for (index, item) in users.iter().enumerate() {
-                    if index >= leap as usize {
-                        res_users.push(item.to_owned());
-                    }
-                }
-
-                Ok(FindManyUsersResponse(
-                    res_users,
-                    total_before_paginating as u64,
-                ))
-            });
+        let (user_db, mocked_user_repository) = get_user_repository();
+        user_db
+            .lock()
+            .unwrap()
+            .extend([user_1.clone(), user_2.clone()]);
 
         // TESTING
 
@@ -159,7 +111,8 @@ mod test {
             .exec(FetchManyUsersParams {
                 page: None,
                 per_page: None,
-                query: Some(UserQueryType::Nickname("parme".to_string())),
+                filters: vec![UserQueryType::Nickname("parme".to_string())],
+                sort: vec![],
             })
             .await;
 
@@ -174,7 +127,8 @@ mod test {
             .exec(FetchManyUsersParams {
                 page: None,
                 per_page: None,
-                query: None,
+                filters: vec![],
+                sort: vec![],
             })
             .await;
 
@@ -185,5 +139,27 @@ mod test {
         assert_eq!(result_2.pagination.total_items, 2);
         assert_eq!(result_2.data[0].nickname(), "Flori".to_string());
         assert_eq!(result_2.data[1].nickname(), "Parme".to_string());
+
+        user_db.lock().unwrap().push(user_3);
+
+        let result_3 = sut
+            .exec(FetchManyUsersParams {
+                page: None,
+                per_page: None,
+                filters: vec![
+                    UserQueryType::Role(Role::Admin),
+                    UserQueryType::Nickname("l".to_string()),
+                ],
+                sort: vec![Sort {
+                    field: UserSortField::Nickname,
+                    direction: SortDirection::Desc,
+                }],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result_3.pagination.total_items, 2);
+        assert_eq!(result_3.data[0].nickname(), "Pamela".to_string());
+        assert_eq!(result_3.data[1].nickname(), "Flori".to_string());
     }
 }
@@ -0,0 +1,345 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::core::pagination::PaginationParameters;
+use crate::core::query::QuerySpec;
+use crate::domain::domain_entities::{
+    article::Article, comment::Comment, team_user::TeamUser, user::User,
+};
+use crate::domain::repositories::{
+    article_repository::{ArticleQueryType, ArticleRepositoryTrait},
+    comment_repository::CommentRepositoryTrait,
+    reaction_repository::ReactionRepositoryTrait,
+    team_user_repository::TeamUserRepositoryTrait,
+    user_repository::UserRepositoryTrait,
+};
+use crate::error::DomainError;
+use crate::util::generate_service_internal_error;
+
+/// How many of the user's latest articles and comments a profile shows.
+pub const PROFILE_ARTICLES_AMOUNT: u32 = 5;
+pub const PROFILE_COMMENTS_AMOUNT: u64 = 5;
+
+pub struct GetUserProfileParams {
+    pub nickname: String,
+}
+
+/// Everything about a user that anyone can see.
+#[derive(Debug)]
+pub struct UserProfile {
+    pub user: User,
+    /// The user's entry on the team page, if they are on the team.
+    pub team_user: Option<TeamUser>,
+    /// The latest approved articles the user authored.
+    pub articles: Vec<Article>,
+    pub articles_count: u64,
+    /// The latest published comments of the user, along with the articles they were made on.
+    pub comments: Vec<(Comment, Article)>,
+    pub comments_count: u64,
+    pub reactions_given: BTreeMap<String, u64>,
+    pub reactions_received: BTreeMap<String, u64>,
+}
+
+pub struct GetUserProfileService<UR, TUR, AR, CR, RR>
+where
+    UR: UserRepositoryTrait,
+    TUR: TeamUserRepositoryTrait,
+    AR: ArticleRepositoryTrait,
+    CR: CommentRepositoryTrait,
+    RR: ReactionRepositoryTrait,
+{
+    user_repository: UR,
+    team_user_repository: TUR,
+    article_repository: AR,
+    comment_repository: CR,
+    reaction_repository: RR,
+}
+
+impl<UR, TUR, AR, CR, RR> GetUserProfileService<UR, TUR, AR, CR, RR>
+where
+    UR: UserRepositoryTrait,
+    TUR: TeamUserRepositoryTrait,
+    AR: ArticleRepositoryTrait,
+    CR: CommentRepositoryTrait,
+    RR: ReactionRepositoryTrait,
+{
+    pub fn new(
+        user_repository: UR,
+        team_user_repository: TUR,
+        article_repository: AR,
+        comment_repository: CR,
+        reaction_repository: RR,
+    ) -> Self {
+        GetUserProfileService {
+            user_repository,
+            team_user_repository,
+            article_repository,
+            comment_repository,
+            reaction_repository,
+        }
+    }
+
+    pub async fn exec(&self, params: GetUserProfileParams) -> Result<UserProfile, DomainError> {
+        let user = self
+            .user_repository
+            .find_by_nickname(&params.nickname)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Get User Profile Service, while finding the user by nickname",
+                    err,
+                )
+            })?
+            .filter(|user| user.nickname() != User::REMOVED_USER_NICKNAME)
+            .ok_or(DomainError::resource_not_found_err())?;
+
+        let team_user = self
+            .team_user_repository
+            .find_by_nickname(user.nickname())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Get User Profile Service, while finding the team user by nickname",
+                    err,
+                )
+            })?;
+
+        let articles = self
+            .article_repository
+            .find_many(
+                PaginationParameters {
+                    page: 1,
+                    items_per_page: PROFILE_ARTICLES_AMOUNT,
+                    query: Some(QuerySpec::new().filter(ArticleQueryType::Author(user.id()))),
+                },
+                Some(true),
+            )
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Get User Profile Service, while fetching the user's articles",
+                    err,
+                )
+            })?;
+
+        let comments = self.get_comments(&user).await?;
+
+        let comments_count = self
+            .comment_repository
+            .count_active_by_author(user.id())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Get User Profile Service, while counting the user's comments",
+                    err,
+                )
+            })?;
+
+        let reactions_given = self
+            .reaction_repository
+            .count_given_by_user(user.id())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Get User Profile Service, while counting the user's reactions",
+                    err,
+                )
+            })?;
+
+        let reactions_received = self
+            .reaction_repository
+            .count_received_by_author(user.id())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Get User Profile Service, while counting the reactions the user got",
+                    err,
+                )
+            })?;
+
+        Ok(UserProfile {
+            user,
+            team_user,
+            articles: articles
+                .0
+                .into_iter()
+                .take(PROFILE_ARTICLES_AMOUNT as usize)
+                .collect(),
+            articles_count: articles.1,
+            comments,
+            comments_count,
+            reactions_given,
+            reactions_received,
+        })
+    }
+
+    /// Pairs the user's latest comments with their articles, leaving out the ones on articles no longer public.
+    async fn get_comments(&self, user: &User) -> Result<Vec<(Comment, Article)>, DomainError> {
+        let comments = self
+            .comment_repository
+            .find_recent_active_by_author(user.id(), PROFILE_COMMENTS_AMOUNT)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Get User Profile Service, while fetching the user's comments",
+                    err,
+                )
+            })?;
+
+        let mut articles: HashMap<_, Option<Article>> = HashMap::new();
+        let mut comments_with_articles = vec![];
+
+        for comment in comments {
+            let Some(article_id) = comment.article_id() else {
+                continue;
+            };
+
+            let article = match articles.get(&article_id) {
+                Some(article) => article.clone(),
+                None => {
+                    let article = self
+                        .article_repository
+                        .find_by_id(article_id)
+                        .await
+                        .map_err(|err| {
+                            generate_service_internal_error(
+                                "Error occurred on Get User Profile Service, while finding a commented article",
+                                err,
+                            )
+                        })?
+                        .filter(Article::approved);
+
+                    articles.insert(article_id, article.clone());
+                    article
+                }
+            };
+
+            if let Some(article) = article {
+                comments_with_articles.push((comment, article));
+            }
+        }
+
+        Ok(comments_with_articles)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::domain::domain_entities::reaction::{Reaction, ReactionTarget};
+    use crate::domain::domain_entities::role::Role;
+    use crate::domain::repositories::team_user_repository::MockTeamUserRepositoryTrait;
+    use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::comment_repository::get_comment_repository;
+    use crate::tests::repositories::reaction_repository::get_reaction_repository;
+    use crate::tests::repositories::users_repository::get_user_repository;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_get_user_profile() {
+        let (user_db, user_repository) = get_user_repository();
+        let (article_db, article_repository) = get_article_repository();
+        let (comment_db, comment_repository) = get_comment_repository();
+        let (reaction_db, reaction_repository) = get_reaction_repository();
+
+        let team_user = TeamUser::new(
+            "Floricultor".into(),
+            "Writer".into(),
+            None,
+            None,
+            Uuid::new_v4(),
+        );
+        let team_user_clone = team_user.clone();
+        let mut team_user_repository = MockTeamUserRepositoryTrait::new();
+        team_user_repository
+            .expect_find_by_nickname()
+            .returning(move |nickname| {
+                Ok(team_user_clone
+                    .nickname()
+                    .eq_ignore_ascii_case(nickname)
+                    .then(|| team_user_clone.clone()))
+            });
+
+        let user = User::new("floricultor".into(), "password".into(), Some(Role::Writer));
+        let reader = User::new("reader".into(), "password".into(), Some(Role::User));
+        user_db.lock().unwrap().push(user.clone());
+        user_db.lock().unwrap().push(reader.clone());
+
+        let mut article = Article::new(
+            user.id(),
+            "Approved article".into(),
+            "content".into(),
+            "url".into(),
+            1,
+            "tag".into(),
+        );
+        article.set_approved(true);
+        let unapproved_article = Article::new(
+            user.id(),
+            "Unapproved article".into(),
+            "content".into(),
+            "url".into(),
+            1,
+            "tag".into(),
+        );
+        article_db.lock().unwrap().push(article.clone());
+        article_db.lock().unwrap().push(unapproved_article.clone());
+
+        let comment = Comment::new(user.id(), Some(article.id()), "public".into());
+        let mut inactive_comment = Comment::new(user.id(), Some(article.id()), "hidden".into());
+        inactive_comment.set_is_active(false);
+        let hidden_article_comment =
+            Comment::new(user.id(), Some(unapproved_article.id()), "draft".into());
+        comment_db.lock().unwrap().push(comment.clone());
+        comment_db.lock().unwrap().push(inactive_comment);
+        comment_db.lock().unwrap().push(hidden_article_comment);
+
+        reaction_db.lock().unwrap().push(Reaction::new(
+            user.id(),
+            ReactionTarget::Comment,
+            Uuid::new_v4(),
+            "heart".into(),
+        ));
+
+        let sut = GetUserProfileService::new(
+            user_repository,
+            team_user_repository,
+            article_repository,
+            comment_repository,
+            reaction_repository,
+        );
+
+        let profile = sut
+            .exec(GetUserProfileParams {
+                nickname: "floricultor".into(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(profile.user.id(), user.id());
+        assert_eq!(profile.team_user, Some(team_user));
+        assert_eq!(profile.articles, vec![article.clone()]);
+        assert_eq!(profile.articles_count, 1);
+        assert_eq!(profile.comments, vec![(comment, article)]);
+        assert_eq!(profile.comments_count, 2);
+        assert_eq!(profile.reactions_given.get("heart"), Some(&1));
+
+        let profile = sut
+            .exec(GetUserProfileParams {
+                nickname: "reader".into(),
+            })
+            .await
+            .unwrap();
+
+        assert!(profile.team_user.is_none());
+        assert!(profile.articles.is_empty());
+        assert!(profile.comments.is_empty());
+
+        let result = sut
+            .exec(GetUserProfileParams {
+                nickname: "nobody".into(),
+            })
+            .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -1,6 +1,10 @@
 pub mod authenticate_user_service;
 pub mod change_password_service;
 pub mod create_user_service;
+pub mod delete_account_service;
+pub mod export_personal_data_service;
 pub mod fetch_many_users_service;
+pub mod get_user_profile_service;
 pub mod get_user_service;
+pub mod update_profile_service;
 pub mod update_user_service;
@@ -0,0 +1,135 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::habbo_avatar::{HabboAvatar, HabboHotel};
+use crate::domain::domain_entities::user::User;
+use crate::domain::repositories::user_repository::UserRepositoryTrait;
+use crate::error::DomainError;
+use crate::util::generate_service_internal_error;
+
+/// Replaces the profile fields of the user, leaving out their nickname, password and role, which are changed
+/// elsewhere.
+pub struct UpdateProfileParams {
+    pub user_id: Uuid,
+    pub bio: Option<String>,
+    pub habbo_hotel: Option<HabboHotel>,
+    pub avatar_direction: u8,
+    pub avatar_head_only: bool,
+}
+
+pub struct UpdateProfileService<UserRepository: UserRepositoryTrait> {
+    user_repository: UserRepository,
+}
+
+impl<UserRepository: UserRepositoryTrait> UpdateProfileService<UserRepository> {
+    pub fn new(user_repository: UserRepository) -> Self {
+        UpdateProfileService { user_repository }
+    }
+
+    pub async fn exec(&self, params: UpdateProfileParams) -> Result<User, DomainError> {
+        let avatar = HabboAvatar::new(
+            params.habbo_hotel,
+            params.avatar_direction,
+            params.avatar_head_only,
+        )?;
+
+        let mut user = self
+            .user_repository
+            .find_by_id(&params.user_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Update Profile Service, while fetching user from database",
+                    err,
+                )
+            })?
+            .ok_or(DomainError::resource_not_found_err())?;
+
+        let bio = params
+            .bio
+            .map(|bio| bio.trim().to_owned())
+            .filter(|bio| !bio.is_empty());
+
+        user.set_bio(bio);
+        user.set_avatar(avatar);
+
+        self.user_repository.save(user).await.map_err(|err| {
+            generate_service_internal_error(
+                "Error occurred on Update Profile Service, while saving user on database",
+                err,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::domain::domain_entities::role::Role;
+    use crate::tests::repositories::users_repository::get_user_repository;
+
+    #[tokio::test]
+    async fn test_update_profile() {
+        let (user_db, user_repository) = get_user_repository();
+
+        let user = User::new("Floricultor".into(), "password".into(), Some(Role::User));
+        user_db.lock().unwrap().push(user.clone());
+
+        let sut = UpdateProfileService::new(user_repository);
+
+        let updated_user = sut
+            .exec(UpdateProfileParams {
+                user_id: user.id(),
+                bio: Some("  Colecionador de emblemas.  ".into()),
+                habbo_hotel: Some(HabboHotel::ComBr),
+                avatar_direction: 4,
+                avatar_head_only: true,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(updated_user.bio(), Some("Colecionador de emblemas."));
+        assert_eq!(updated_user.avatar().hotel(), Some(HabboHotel::ComBr));
+        assert_eq!(updated_user.avatar().direction(), 4);
+        assert!(updated_user.avatar().head_only());
+        assert_eq!(user_db.lock().unwrap()[0], updated_user);
+
+        let updated_user = sut
+            .exec(UpdateProfileParams {
+                user_id: user.id(),
+                bio: Some("   ".into()),
+                habbo_hotel: None,
+                avatar_direction: HabboAvatar::DEFAULT_DIRECTION,
+                avatar_head_only: false,
+            })
+            .await
+            .unwrap();
+
+        assert!(updated_user.bio().is_none());
+        assert_eq!(updated_user.avatar(), &HabboAvatar::default());
+
+        let invalid_direction = sut
+            .exec(UpdateProfileParams {
+                user_id: user.id(),
+                bio: None,
+                habbo_hotel: None,
+                avatar_direction: 8,
+                avatar_head_only: false,
+            })
+            .await;
+
+        assert!(invalid_direction.is_err());
+        assert!(user_db.lock().unwrap()[0].avatar().hotel().is_none());
+
+        let unknown_user = sut
+            .exec(UpdateProfileParams {
+                user_id: Uuid::new_v4(),
+                bio: None,
+                habbo_hotel: None,
+                avatar_direction: HabboAvatar::DEFAULT_DIRECTION,
+                avatar_head_only: false,
+            })
+            .await;
+
+        assert!(unknown_user.is_err());
+    }
+}
@@ -0,0 +1,894 @@
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+use unicode_normalization::UnicodeNormalization;
+use uuid::Uuid;
+
+use crate::domain::cache::related_articles_cache::RelatedArticlesCacheTrait;
+use crate::domain::domain_entities::article::{Article, CommentMode};
+use crate::domain::domain_entities::article_tag::DraftArticleTag;
+use crate::domain::domain_entities::comment::Comment;
+use crate::domain::domain_entities::imported_content::{ImportedContent, ImportedContentKind};
+use crate::domain::domain_entities::role::Role;
+use crate::domain::domain_entities::slug::Slug;
+use crate::domain::domain_entities::user::User;
+use crate::domain::domain_entities::wordpress_export::{
+    WordpressComment, WordpressExport, WordpressPost,
+};
+use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::domain::repositories::article_tag_repository::ArticleTagRepositoryTrait;
+use crate::domain::repositories::comment_repository::CommentRepositoryTrait;
+use crate::domain::repositories::imported_content_repository::ImportedContentRepositoryTrait;
+use crate::domain::repositories::user_repository::UserRepositoryTrait;
+use crate::error::DomainError;
+use crate::libs::time::TimeHelper;
+use crate::util::generate_service_internal_error;
+
+/// The tag of the posts exported without any category.
+pub const UNCATEGORIZED_TAG: &str = "Uncategorized";
+
+const UNTITLED_ARTICLE: &str = "Untitled";
+const GUEST_NICKNAME: &str = "guest";
+const MIN_NICKNAME_LENGTH: usize = 3;
+const MAX_NICKNAME_LENGTH: usize = 22;
+
+static FIRST_IMAGE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"<img[^>]+src\s*=\s*["']([^"']+)["']"#).unwrap());
+
+pub struct ImportWordpressParams {
+    pub export: WordpressExport,
+    /// Reports what would be imported without writing anything.
+    pub dry_run: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImportTally {
+    pub created: u32,
+    /// Records imported by a previous run, or matching an existing tag.
+    pub existing: u32,
+    pub skipped: u32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub users: ImportTally,
+    pub tags: ImportTally,
+    pub articles: ImportTally,
+    pub comments: ImportTally,
+    /// WordPress names which weren't valid or available nicknames, along with the nickname used instead.
+    pub renamed_users: Vec<(String, String)>,
+    pub warnings: Vec<String>,
+}
+
+/// The state of a single import run.
+struct ImportRun {
+    source: String,
+    dry_run: bool,
+    /// Records imported by previous runs.
+    imported: HashMap<(ImportedContentKind, String), String>,
+    users: HashMap<String, Uuid>,
+    tags: HashMap<String, (i32, String)>,
+    taken_nicknames: HashSet<String>,
+    report: ImportReport,
+}
+
+impl ImportRun {
+    fn previously_imported(&self, kind: ImportedContentKind, external_id: &str) -> Option<&str> {
+        self.imported
+            .get(&(kind, external_id.to_owned()))
+            .map(String::as_str)
+    }
+}
+
+pub struct ImportWordpressService<UR, ATR, AR, CR, ICR, RAC>
+where
+    UR: UserRepositoryTrait,
+    ATR: ArticleTagRepositoryTrait,
+    AR: ArticleRepositoryTrait,
+    CR: CommentRepositoryTrait,
+    ICR: ImportedContentRepositoryTrait,
+    RAC: RelatedArticlesCacheTrait,
+{
+    user_repository: UR,
+    article_tag_repository: ATR,
+    article_repository: AR,
+    comment_repository: CR,
+    imported_content_repository: ICR,
+    related_articles_cache: RAC,
+}
+
+impl<UR, ATR, AR, CR, ICR, RAC> ImportWordpressService<UR, ATR, AR, CR, ICR, RAC>
+where
+    UR: UserRepositoryTrait,
+    ATR: ArticleTagRepositoryTrait,
+    AR: ArticleRepositoryTrait,
+    CR: CommentRepositoryTrait,
+    ICR: ImportedContentRepositoryTrait,
+    RAC: RelatedArticlesCacheTrait,
+{
+    pub fn new(
+        user_repository: UR,
+        article_tag_repository: ATR,
+        article_repository: AR,
+        comment_repository: CR,
+        imported_content_repository: ICR,
+        related_articles_cache: RAC,
+    ) -> Self {
+        ImportWordpressService {
+            user_repository,
+            article_tag_repository,
+            article_repository,
+            comment_repository,
+            imported_content_repository,
+            related_articles_cache,
+        }
+    }
+
+    pub async fn exec(&self, params: ImportWordpressParams) -> Result<ImportReport, DomainError> {
+        let ImportWordpressParams { export, dry_run } = params;
+
+        let site_url = export.site_url.trim().trim_end_matches('/');
+
+        if site_url.is_empty() {
+            return Err(DomainError::bad_request_err()
+                .with_message("The export file doesn't tell which site it came from."));
+        }
+
+        let source = format!("wordpress:{site_url}");
+
+        let imported = self
+            .imported_content_repository
+            .find_many_by_source(source.clone())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Import Wordpress Service, while fetching previously imported content",
+                    err,
+                )
+            })?
+            .into_iter()
+            .map(|record| {
+                (
+                    (record.kind(), record.external_id().to_owned()),
+                    record.internal_id().to_owned(),
+                )
+            })
+            .collect();
+
+        let mut run = ImportRun {
+            source,
+            dry_run,
+            imported,
+            users: HashMap::new(),
+            tags: HashMap::new(),
+            taken_nicknames: HashSet::new(),
+            report: ImportReport {
+                dry_run,
+                ..Default::default()
+            },
+        };
+
+        for author in export.authors.iter() {
+            let name = if author.login.trim().is_empty() {
+                &author.display_name
+            } else {
+                &author.login
+            };
+
+            self.resolve_user(&mut run, &author_external_id(&author.login), name)
+                .await?;
+        }
+
+        for category in export.categories.iter() {
+            self.resolve_tag(&mut run, &category.slug, &category.name)
+                .await?;
+        }
+
+        let author_logins: HashMap<&str, &str> = export
+            .authors
+            .iter()
+            .map(|author| (author.id.as_str(), author.login.as_str()))
+            .collect();
+
+        for post in export.posts.iter().filter(|post| post.post_type == "post") {
+            let Some(article_id) = self.import_post(&mut run, &export, post).await? else {
+                continue;
+            };
+
+            for comment in post.comments.iter() {
+                self.import_comment(&mut run, &author_logins, article_id, comment)
+                    .await?;
+            }
+        }
+
+        if !dry_run && run.report.articles.created > 0 {
+            self.related_articles_cache.invalidate();
+        }
+
+        Ok(run.report)
+    }
+
+    async fn import_post(
+        &self,
+        run: &mut ImportRun,
+        export: &WordpressExport,
+        post: &WordpressPost,
+    ) -> Result<Option<Uuid>, DomainError> {
+        if let Some(article_id) = run
+            .previously_imported(ImportedContentKind::Article, &post.id)
+            .map(Uuid::parse_str)
+        {
+            run.report.articles.existing += 1;
+
+            return Ok(article_id.ok());
+        }
+
+        let approved = match post.status.as_str() {
+            "publish" => true,
+            "draft" | "pending" | "private" | "future" => false,
+            _ => {
+                run.report.articles.skipped += 1;
+                return Ok(None);
+            }
+        };
+
+        let author_id = self
+            .resolve_user(
+                run,
+                &author_external_id(&post.author_login),
+                &post.author_login,
+            )
+            .await?;
+
+        let (tag_id, tag_value) = match post.category_slugs.first() {
+            Some(slug) => {
+                let name = export
+                    .categories
+                    .iter()
+                    .find(|category| &category.slug == slug)
+                    .map(|category| category.name.as_str())
+                    .unwrap_or(slug);
+
+                self.resolve_tag(run, slug, name).await?
+            }
+            None => {
+                self.resolve_tag(run, UNCATEGORIZED_TAG, UNCATEGORIZED_TAG)
+                    .await?
+            }
+        };
+
+        if post.category_slugs.len() > 1 {
+            run.report.warnings.push(format!(
+                "Post {} has many categories, only \"{tag_value}\" has been kept.",
+                post.id
+            ));
+        }
+
+        let title = match post.title.trim() {
+            "" => UNTITLED_ARTICLE.to_owned(),
+            title => title.to_owned(),
+        };
+
+        let cover_url = post
+            .cover_url
+            .clone()
+            .or_else(|| {
+                FIRST_IMAGE_REGEX
+                    .captures(&post.content)
+                    .map(|captures| captures[1].to_owned())
+            })
+            .unwrap_or_default();
+
+        let created_at = post.published_at.unwrap_or_else(TimeHelper::now);
+        let updated_at = post
+            .modified_at
+            .filter(|modified_at| modified_at > &created_at);
+
+        let id = Uuid::new_v4();
+        let article = Article::new_from_existing(
+            id,
+            author_id,
+            cover_url,
+            title.clone(),
+            into_paragraphs(&post.content),
+            approved,
+            created_at,
+            updated_at,
+            Some(tag_id),
+            Some(tag_value),
+            Slug::new(id, title),
+            false,
+            false,
+            0,
+            None,
+            CommentMode::Open,
+        );
+
+        if !run.dry_run {
+            self.article_repository
+                .create(article)
+                .await
+                .map_err(|err| {
+                    generate_service_internal_error(
+                        "Error occurred on Import Wordpress Service, while creating an article",
+                        err,
+                    )
+                })?;
+        }
+
+        self.remember(run, ImportedContentKind::Article, &post.id, id.to_string())
+            .await?;
+        run.report.articles.created += 1;
+
+        Ok(Some(id))
+    }
+
+    async fn import_comment(
+        &self,
+        run: &mut ImportRun,
+        author_logins: &HashMap<&str, &str>,
+        article_id: Uuid,
+        comment: &WordpressComment,
+    ) -> Result<(), DomainError> {
+        if run
+            .previously_imported(ImportedContentKind::Comment, &comment.id)
+            .is_some()
+        {
+            run.report.comments.existing += 1;
+            return Ok(());
+        }
+
+        let is_active = match comment.approved.as_str() {
+            "1" => true,
+            "0" => false,
+            _ => {
+                run.report.comments.skipped += 1;
+                return Ok(());
+            }
+        };
+
+        if comment.content.trim().is_empty() {
+            run.report.comments.skipped += 1;
+            return Ok(());
+        }
+
+        let author_id = match author_logins.get(comment.user_id.as_str()) {
+            Some(login) if comment.user_id != "0" => {
+                self.resolve_user(run, &author_external_id(login), login)
+                    .await?
+            }
+            _ => {
+                let guest_key = match comment.author_email.trim() {
+                    "" => comment.author_name.trim().to_lowercase(),
+                    email => email.to_lowercase(),
+                };
+
+                self.resolve_user(run, &format!("guest:{guest_key}"), &comment.author_name)
+                    .await?
+            }
+        };
+
+        let id = Uuid::new_v4();
+        let new_comment = Comment::new_from_existing(
+            id,
+            Some(article_id),
+            None,
+            author_id,
+            comment.content.trim().to_owned(),
+            is_active,
+            comment.created_at.unwrap_or_else(TimeHelper::now),
+            None,
+        );
+
+        if !run.dry_run {
+            self.comment_repository
+                .create(new_comment)
+                .await
+                .map_err(|err| {
+                    generate_service_internal_error(
+                        "Error occurred on Import Wordpress Service, while creating a comment",
+                        err,
+                    )
+                })?;
+        }
+
+        self.remember(
+            run,
+            ImportedContentKind::Comment,
+            &comment.id,
+            id.to_string(),
+        )
+        .await?;
+        run.report.comments.created += 1;
+
+        Ok(())
+    }
+
+    /// Finds the user a WordPress author or guest has been imported as, creating it if needed.
+    /// Users are never merged into pre-existing accounts, even if their nicknames match.
+    async fn resolve_user(
+        &self,
+        run: &mut ImportRun,
+        external_id: &str,
+        name: &str,
+    ) -> Result<Uuid, DomainError> {
+        if let Some(user_id) = run.users.get(external_id) {
+            return Ok(*user_id);
+        }
+
+        if let Some(user_id) = run
+            .previously_imported(ImportedContentKind::User, external_id)
+            .and_then(|user_id| Uuid::parse_str(user_id).ok())
+        {
+            run.users.insert(external_id.to_owned(), user_id);
+            run.report.users.existing += 1;
+
+            return Ok(user_id);
+        }
+
+        let nickname = self.available_nickname(run, name).await?;
+
+        if nickname != name {
+            run.report
+                .renamed_users
+                .push((name.to_owned(), nickname.clone()));
+        }
+
+        // Imported users must have their password reset by the staff before signing in.
+        let user = User::new(nickname, User::UNUSABLE_PASSWORD.into(), Some(Role::User));
+        let user_id = user.id();
+
+        if !run.dry_run {
+            self.user_repository.create(user).await.map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Import Wordpress Service, while creating an user",
+                    err,
+                )
+            })?;
+        }
+
+        self.remember(
+            run,
+            ImportedContentKind::User,
+            external_id,
+            user_id.to_string(),
+        )
+        .await?;
+        run.users.insert(external_id.to_owned(), user_id);
+        run.report.users.created += 1;
+
+        Ok(user_id)
+    }
+
+    /// Finds the tag a WordPress category has been imported as, reusing existing tags of the same name.
+    async fn resolve_tag(
+        &self,
+        run: &mut ImportRun,
+        slug: &str,
+        name: &str,
+    ) -> Result<(i32, String), DomainError> {
+        if let Some(tag) = run.tags.get(slug) {
+            return Ok(tag.clone());
+        }
+
+        let name = match name.trim() {
+            "" => slug.trim(),
+            name => name,
+        };
+
+        let imported_tag_id = run
+            .previously_imported(ImportedContentKind::Tag, slug)
+            .and_then(|tag_id| tag_id.parse::<i32>().ok());
+
+        let tag = match imported_tag_id {
+            Some(tag_id) => self
+                .article_tag_repository
+                .find_by_id(tag_id)
+                .await
+                .map_err(|err| {
+                    generate_service_internal_error(
+                        "Error occurred on Import Wordpress Service, while finding a tag by id",
+                        err,
+                    )
+                })?,
+            None => None,
+        };
+
+        let tag = match tag {
+            Some(tag) => Some(tag),
+            None => self
+                .article_tag_repository
+                .find_by_value(name.to_owned())
+                .await
+                .map_err(|err| {
+                    generate_service_internal_error(
+                        "Error occurred on Import Wordpress Service, while finding a tag by value",
+                        err,
+                    )
+                })?,
+        };
+
+        let tag = match tag {
+            Some(tag) => {
+                run.report.tags.existing += 1;
+                (tag.id(), tag.value().to_owned())
+            }
+            None if run.dry_run => {
+                run.report.tags.created += 1;
+                (0, name.to_owned())
+            }
+            None => {
+                let tag = self
+                    .article_tag_repository
+                    .create(DraftArticleTag::new(name.to_owned()))
+                    .await
+                    .map_err(|err| {
+                        generate_service_internal_error(
+                            "Error occurred on Import Wordpress Service, while creating a tag",
+                            err,
+                        )
+                    })?;
+
+                run.report.tags.created += 1;
+                (tag.id(), tag.value().to_owned())
+            }
+        };
+
+        if imported_tag_id != Some(tag.0) {
+            self.remember(run, ImportedContentKind::Tag, slug, tag.0.to_string())
+                .await?;
+        }
+
+        run.tags.insert(slug.to_owned(), tag.clone());
+
+        Ok(tag)
+    }
+
+    /// Turns a WordPress name into a valid nickname that no one else has.
+    async fn available_nickname(
+        &self,
+        run: &mut ImportRun,
+        name: &str,
+    ) -> Result<String, DomainError> {
+        let base = sanitize_nickname(name);
+        let mut nickname = base.clone();
+        let mut suffix = 1;
+
+        loop {
+            let taken = run.taken_nicknames.contains(&nickname.to_lowercase())
+                || self
+                    .user_repository
+                    .find_by_nickname(&nickname)
+                    .await
+                    .map_err(|err| {
+                        generate_service_internal_error(
+                            "Error occurred on Import Wordpress Service, while finding an user by nickname",
+                            err,
+                        )
+                    })?
+                    .is_some();
+
+            if !taken {
+                break;
+            }
+
+            suffix += 1;
+            let suffix = suffix.to_string();
+            let base: String = base
+                .chars()
+                .take(MAX_NICKNAME_LENGTH - suffix.len())
+                .collect();
+
+            nickname = format!("{base}{suffix}");
+        }
+
+        run.taken_nicknames.insert(nickname.to_lowercase());
+
+        Ok(nickname)
+    }
+
+    async fn remember(
+        &self,
+        run: &mut ImportRun,
+        kind: ImportedContentKind,
+        external_id: &str,
+        internal_id: String,
+    ) -> Result<(), DomainError> {
+        if run.dry_run {
+            return Ok(());
+        }
+
+        self.imported_content_repository
+            .create(ImportedContent::new(
+                run.source.clone(),
+                kind,
+                external_id.to_owned(),
+                internal_id,
+            ))
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Import Wordpress Service, while recording an imported content",
+                    err,
+                )
+            })?;
+
+        Ok(())
+    }
+}
+
+fn author_external_id(login: &str) -> String {
+    format!("author:{}", login.trim().to_lowercase())
+}
+
+/// Keeps the characters nicknames accept (stripping accents first), replacing spaces with underscores.
+fn sanitize_nickname(name: &str) -> String {
+    let mut nickname: String = name
+        .trim()
+        .nfd()
+        .filter_map(|char| match char {
+            ' ' => Some('_'),
+            'a'..='z' | 'A'..='Z' | '0'..='9' => Some(char),
+            '_' | '-' | '=' | '?' | '!' | '@' | ':' | ';' | '.' | ',' => Some(char),
+            _ => None,
+        })
+        .take(MAX_NICKNAME_LENGTH)
+        .collect();
+
+    if nickname.is_empty() {
+        nickname = GUEST_NICKNAME.to_owned();
+    }
+
+    while nickname.len() < MIN_NICKNAME_LENGTH {
+        nickname.push('_');
+    }
+
+    nickname
+}
+
+/// WordPress stores posts written on its classic editor with plain line breaks instead of paragraphs.
+fn into_paragraphs(content: &str) -> String {
+    let content = content.trim().replace("\r\n", "\n");
+
+    if content.contains("<p") || content.contains("<!-- wp:") {
+        return content;
+    }
+
+    content
+        .split("\n\n")
+        .map(str::trim)
+        .filter(|paragraph| !paragraph.is_empty())
+        .map(|paragraph| format!("<p>{}</p>", paragraph.replace('\n', "<br />")))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::domain::domain_entities::article_tag::ArticleTag;
+    use crate::domain::domain_entities::wordpress_export::{WordpressAuthor, WordpressCategory};
+    use crate::infra::cache::in_memory_related_articles_cache::InMemoryRelatedArticlesCache;
+    use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::article_tag_repository::get_article_tag_repository;
+    use crate::tests::repositories::comment_repository::get_comment_repository;
+    use crate::tests::repositories::imported_content_repository::get_imported_content_repository;
+    use crate::tests::repositories::users_repository::get_user_repository;
+
+    fn export() -> WordpressExport {
+        WordpressExport {
+            site_url: "https://fansite.example/".into(),
+            authors: vec![WordpressAuthor {
+                id: "2".into(),
+                login: "João Silva".into(),
+                display_name: "João".into(),
+            }],
+            categories: vec![
+                WordpressCategory {
+                    slug: "noticias".into(),
+                    name: "Notícias".into(),
+                },
+                WordpressCategory {
+                    slug: "eventos".into(),
+                    name: "Eventos".into(),
+                },
+            ],
+            posts: vec![
+                WordpressPost {
+                    id: "10".into(),
+                    post_type: "post".into(),
+                    status: "publish".into(),
+                    title: "Novo mobi".into(),
+                    content: "Primeiro <img src=\"https://fansite.example/mobi.png\" />\n\nSegundo"
+                        .into(),
+                    author_login: "João Silva".into(),
+                    category_slugs: vec!["noticias".into()],
+                    comments: vec![
+                        WordpressComment {
+                            id: "100".into(),
+                            author_name: "João".into(),
+                            user_id: "2".into(),
+                            content: "Obrigado!".into(),
+                            approved: "1".into(),
+                            ..Default::default()
+                        },
+                        WordpressComment {
+                            id: "101".into(),
+                            author_name: "Visitante".into(),
+                            author_email: "visitante@example.com".into(),
+                            user_id: "0".into(),
+                            content: "Legal".into(),
+                            approved: "0".into(),
+                            ..Default::default()
+                        },
+                        WordpressComment {
+                            id: "102".into(),
+                            author_name: "Bot".into(),
+                            user_id: "0".into(),
+                            content: "Compre agora".into(),
+                            approved: "spam".into(),
+                            ..Default::default()
+                        },
+                    ],
+                    ..Default::default()
+                },
+                WordpressPost {
+                    id: "11".into(),
+                    post_type: "post".into(),
+                    status: "draft".into(),
+                    title: "Rascunho".into(),
+                    author_login: "João Silva".into(),
+                    ..Default::default()
+                },
+                WordpressPost {
+                    id: "12".into(),
+                    post_type: "post".into(),
+                    status: "trash".into(),
+                    title: "Lixo".into(),
+                    ..Default::default()
+                },
+                WordpressPost {
+                    id: "13".into(),
+                    post_type: "page".into(),
+                    status: "publish".into(),
+                    title: "Sobre".into(),
+                    ..Default::default()
+                },
+            ],
+        }
+    }
+
+    #[tokio::test]
+    async fn test() {
+        let (users_db, user_repository) = get_user_repository();
+        let (tags_db, article_tag_repository) = get_article_tag_repository();
+        let (articles_db, article_repository) = get_article_repository();
+        let (comments_db, comment_repository) = get_comment_repository();
+        let (imported_db, imported_content_repository) = get_imported_content_repository();
+
+        users_db.lock().unwrap().push(User::new(
+            "Joao_Silva".into(),
+            "password".into(),
+            Some(Role::User),
+        ));
+        tags_db
+            .lock()
+            .unwrap()
+            .push(ArticleTag::new_from_existing(1, "Eventos".into()));
+
+        let sut = ImportWordpressService::new(
+            user_repository,
+            article_tag_repository,
+            article_repository,
+            comment_repository,
+            imported_content_repository,
+            InMemoryRelatedArticlesCache::new(),
+        );
+
+        let dry_run_report = sut
+            .exec(ImportWordpressParams {
+                export: export(),
+                dry_run: true,
+            })
+            .await
+            .unwrap();
+
+        assert!(dry_run_report.dry_run);
+        assert_eq!(dry_run_report.users.created, 2);
+        assert_eq!(dry_run_report.articles.created, 2);
+        assert_eq!(dry_run_report.articles.skipped, 1);
+        assert_eq!(dry_run_report.comments.created, 2);
+        assert_eq!(dry_run_report.comments.skipped, 1);
+        assert_eq!(users_db.lock().unwrap().len(), 1);
+        assert_eq!(tags_db.lock().unwrap().len(), 1);
+        assert!(articles_db.lock().unwrap().is_empty());
+        assert!(imported_db.lock().unwrap().is_empty());
+
+        let report = sut
+            .exec(ImportWordpressParams {
+                export: export(),
+                dry_run: false,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            report,
+            ImportReport {
+                dry_run: false,
+                ..dry_run_report
+            }
+        );
+        assert_eq!(
+            report.tags,
+            ImportTally {
+                created: 2,
+                existing: 1,
+                skipped: 0
+            }
+        );
+        assert_eq!(
+            report.renamed_users,
+            vec![("João Silva".to_owned(), "Joao_Silva2".to_owned())]
+        );
+
+        let users = users_db.lock().unwrap().clone();
+        assert_eq!(users.len(), 3);
+        assert!(users[1..]
+            .iter()
+            .all(|user| user.password() == User::UNUSABLE_PASSWORD));
+
+        let articles = articles_db.lock().unwrap().clone();
+        assert_eq!(articles.len(), 2);
+        assert!(articles[0].approved());
+        assert!(!articles[1].approved());
+        assert_eq!(articles[0].tag_value().as_deref(), Some("Notícias"));
+        assert_eq!(articles[0].cover_url(), "https://fansite.example/mobi.png");
+        assert_eq!(articles[1].tag_value().as_deref(), Some(UNCATEGORIZED_TAG));
+        assert!(articles[0].content().starts_with("<p>Primeiro"));
+
+        let comments = comments_db.lock().unwrap().clone();
+        assert_eq!(comments.len(), 2);
+        assert_eq!(comments[0].author_id(), users[1].id());
+        assert!(!comments[1].is_active());
+
+        let rerun_report = sut
+            .exec(ImportWordpressParams {
+                export: export(),
+                dry_run: false,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(rerun_report.users.created, 0);
+        assert_eq!(rerun_report.tags.created, 0);
+        assert_eq!(rerun_report.articles.created, 0);
+        assert_eq!(rerun_report.articles.existing, 2);
+        assert_eq!(rerun_report.comments.created, 0);
+        assert_eq!(users_db.lock().unwrap().len(), 3);
+        assert_eq!(articles_db.lock().unwrap().len(), 2);
+        assert_eq!(comments_db.lock().unwrap().len(), 2);
+
+        let missing_source = sut
+            .exec(ImportWordpressParams {
+                export: WordpressExport::default(),
+                dry_run: true,
+            })
+            .await;
+
+        assert!(matches!(missing_source, Err(DomainError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_sanitize_nickname() {
+        assert_eq!(sanitize_nickname("Ágata Müller"), "Agata_Muller");
+        assert_eq!(sanitize_nickname("x"), "x__");
+        assert_eq!(sanitize_nickname("★★★"), GUEST_NICKNAME);
+        assert_eq!(
+            sanitize_nickname(&"a".repeat(30)).len(),
+            MAX_NICKNAME_LENGTH
+        );
+    }
+}
@@ -1,7 +1,9 @@
 use crate::core::pagination::{PaginationParameters, PaginationResponse, DEFAULT_PER_PAGE};
+use crate::core::query::Sort;
 use crate::domain::domain_entities::article_tag::ArticleTag;
 use crate::domain::repositories::article_tag_repository::{
-    ArticleTagQueryType, ArticleTagRepositoryTrait, FindManyArticleTagsResponse,
+    ArticleTagQuery, ArticleTagQueryType, ArticleTagRepositoryTrait, ArticleTagSortField,
+    FindManyArticleTagsResponse,
 };
 use crate::error::DomainError;
 use crate::util::generate_service_internal_error;
@@ -10,6 +12,7 @@ pub struct FetchManyArticleTagsParams {
     pub page: Option<u32>,
     pub per_page: Option<u32>,
     pub query: Option<String>,
+    pub sort: Vec<Sort<ArticleTagSortField>>,
 }
 
 #[derive(Debug)]
@@ -47,14 +50,16 @@ impl<ArticleTagRepository: ArticleTagRepositoryTrait>
             }
         };
 
-        let query = params.query.map(ArticleTagQueryType::Value);
+        let mut query =
+            ArticleTagQuery::new().filter_if(params.query.map(ArticleTagQueryType::Value));
+        query.sort = params.sort;
 
         let response = self
             .article_tag_repository
             .find_many(PaginationParameters {
                 page,
                 items_per_page: per_page,
-                query,
+                query: Some(query),
             })
             .await;
 
@@ -97,6 +102,7 @@ mod test {
         let result = sut
             .exec(super::FetchManyArticleTagsParams {
                 query: Some("foo".into()),
+                sort: vec![],
                 page: None,
                 per_page: None,
             })
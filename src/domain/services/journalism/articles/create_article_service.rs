@@ -1,5 +1,6 @@
 use uuid::Uuid;
 
+use crate::domain::cache::related_articles_cache::RelatedArticlesCacheTrait;
 use crate::domain::domain_entities::article::Article;
 use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
 use crate::domain::repositories::article_tag_repository::ArticleTagRepositoryTrait;
@@ -20,27 +21,38 @@ pub struct CreateArticleService<
     ArticleRepository: ArticleRepositoryTrait,
     ArticleTagRepository: ArticleTagRepositoryTrait,
     UserRepository: UserRepositoryTrait,
+    RelatedArticlesCache: RelatedArticlesCacheTrait,
 > {
     article_repository: ArticleRepository,
     article_tag_repository: ArticleTagRepository,
     user_repository: UserRepository,
+    related_articles_cache: RelatedArticlesCache,
 }
 
 impl<
         ArticleRepository: ArticleRepositoryTrait,
         ArticleTagRepository: ArticleTagRepositoryTrait,
         UserRepository: UserRepositoryTrait,
-    > CreateArticleService<ArticleRepository, ArticleTagRepository, UserRepository>
+        RelatedArticlesCache: RelatedArticlesCacheTrait,
+    >
+    CreateArticleService<
+        ArticleRepository,
+        ArticleTagRepository,
+        UserRepository,
+        RelatedArticlesCache,
+    >
 {
     pub fn new(
         article_repository: ArticleRepository,
         article_tag_repository: ArticleTagRepository,
         user_repository: UserRepository,
+        related_articles_cache: RelatedArticlesCache,
     ) -> Self {
         CreateArticleService {
             article_repository,
             article_tag_repository,
             user_repository,
+            related_articles_cache,
         }
     }
 
@@ -108,6 +120,8 @@ impl<
             ));
         }
 
+        self.related_articles_cache.invalidate();
+
         Ok(response.unwrap())
     }
 }
@@ -115,12 +129,14 @@ impl<
 #[cfg(test)]
 mod test {
     use super::CreateArticleParams;
+    use crate::domain::cache::related_articles_cache::RelatedArticlesCacheTrait;
     use crate::domain::domain_entities::article_tag::ArticleTag;
     use crate::domain::repositories::article_tag_repository::MockArticleTagRepositoryTrait;
     use crate::domain::{
         domain_entities::{role::Role, user::User},
         repositories::user_repository::MockUserRepositoryTrait,
     };
+    use crate::infra::cache::in_memory_related_articles_cache::InMemoryRelatedArticlesCache;
     use crate::tests::repositories::article_repository::get_article_repository;
     use std::sync::{Arc, Mutex};
 
@@ -164,10 +180,15 @@ mod test {
             Ok(None)
         });
 
+        let cached_article_id = uuid::Uuid::new_v4();
+        let related_articles_cache = InMemoryRelatedArticlesCache::new();
+        related_articles_cache.set(cached_article_id, vec![]);
+
         let service = super::CreateArticleService {
             article_repository: mocked_article_repo,
             article_tag_repository: mocked_tag_repo,
             user_repository: mocked_user_repo,
+            related_articles_cache: related_articles_cache.clone(),
         };
 
         let result = service
@@ -182,5 +203,7 @@ mod test {
             .await;
 
         assert_eq!("Conteúdo do artigo aqui", result.unwrap().content());
+        // a new article might be related to any other
+        assert!(related_articles_cache.get(cached_article_id).is_none());
     }
 }
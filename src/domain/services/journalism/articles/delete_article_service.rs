@@ -1,5 +1,6 @@
 use uuid::Uuid;
 
+use crate::domain::cache::related_articles_cache::RelatedArticlesCacheTrait;
 use crate::domain::repositories::article_comment_repository::ArticleCommentRepositoryTrait;
 use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
 use crate::domain::repositories::user_repository::UserRepositoryTrait;
@@ -14,24 +15,32 @@ pub struct DeleteArticleService<
     AR: ArticleRepositoryTrait,
     ACR: ArticleCommentRepositoryTrait,
     UR: UserRepositoryTrait,
+    RAC: RelatedArticlesCacheTrait,
 > {
     article_repository: AR,
     article_comment_repository: ACR,
     user_repository: UR,
+    related_articles_cache: RAC,
 }
 
-impl<AR: ArticleRepositoryTrait, ACR: ArticleCommentRepositoryTrait, UR: UserRepositoryTrait>
-    DeleteArticleService<AR, ACR, UR>
+impl<
+        AR: ArticleRepositoryTrait,
+        ACR: ArticleCommentRepositoryTrait,
+        UR: UserRepositoryTrait,
+        RAC: RelatedArticlesCacheTrait,
+    > DeleteArticleService<AR, ACR, UR, RAC>
 {
     pub fn new(
         article_repository: AR,
         article_comment_repository: ACR,
         user_repository: UR,
+        related_articles_cache: RAC,
     ) -> Self {
         DeleteArticleService {
             article_repository,
             article_comment_repository,
             user_repository,
+            related_articles_cache,
         }
     }
 
@@ -94,7 +103,11 @@ impl<AR: ArticleRepositoryTrait, ACR: ArticleCommentRepositoryTrait, UR: UserRep
                     "Error occurred on Delete Article Service, while deleting the article",
                     err,
                 )
-            })
+            })?;
+
+        self.related_articles_cache.invalidate();
+
+        Ok(())
     }
 }
 
@@ -106,11 +119,14 @@ mod test {
 
     use super::{DeleteArticleParams, DeleteArticleService};
 
+    use crate::domain::cache::related_articles_cache::RelatedArticlesCacheTrait;
     use crate::domain::domain_entities::article::Article;
+    use crate::domain::domain_entities::habbo_avatar::HabboAvatar;
     use crate::domain::domain_entities::role::Role;
     use crate::domain::domain_entities::user::User;
     use crate::domain::repositories::article_comment_repository::MockArticleCommentRepositoryTrait;
     use crate::domain::repositories::user_repository::MockUserRepositoryTrait;
+    use crate::infra::cache::in_memory_related_articles_cache::InMemoryRelatedArticlesCache;
     use crate::libs::time::TimeHelper;
     use crate::tests::repositories::article_repository::get_article_repository;
 
@@ -155,15 +171,22 @@ mod test {
                 TimeHelper::now(),
                 None,
                 Some(Role::Principal),
+                None,
+                HabboAvatar::default(),
             );
 
             Ok(Some(fake_user))
         });
 
+        let related_article_id = Uuid::new_v4();
+        let related_articles_cache = InMemoryRelatedArticlesCache::new();
+        related_articles_cache.set(related_article_id, vec![article.clone()]);
+
         let service = DeleteArticleService {
             user_repository: mocked_user_repo,
             article_comment_repository: mocked_article_comment_repo,
             article_repository: mocked_article_repo,
+            related_articles_cache: related_articles_cache.clone(),
         };
 
         let result = service
@@ -175,5 +198,7 @@ mod test {
 
         assert!(result.is_ok());
         assert_eq!(0, article_db.lock().unwrap().len());
+        // rankings listing the deleted article are dropped
+        assert!(related_articles_cache.get(related_article_id).is_none());
     }
 }
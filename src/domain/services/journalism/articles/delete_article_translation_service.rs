@@ -0,0 +1,148 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::locale::Locale;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::article_coauthor_repository::ArticleCoauthorRepositoryTrait;
+use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::domain::repositories::article_translation_repository::ArticleTranslationRepositoryTrait;
+use crate::domain::services::journalism::articles::upsert_article_translation_service::find_translatable_article;
+use crate::error::DomainError;
+use crate::util::generate_service_internal_error;
+
+pub struct DeleteArticleTranslationParams {
+    pub user_id: Uuid,
+    pub user_role: Role,
+    pub article_id: Uuid,
+    pub locale: Locale,
+}
+
+pub struct DeleteArticleTranslationService<
+    ArticleRepository: ArticleRepositoryTrait,
+    ArticleTranslationRepository: ArticleTranslationRepositoryTrait,
+    ArticleCoauthorRepository: ArticleCoauthorRepositoryTrait,
+> {
+    article_repository: ArticleRepository,
+    article_translation_repository: ArticleTranslationRepository,
+    article_coauthor_repository: ArticleCoauthorRepository,
+}
+
+impl<
+        ArticleRepository: ArticleRepositoryTrait,
+        ArticleTranslationRepository: ArticleTranslationRepositoryTrait,
+        ArticleCoauthorRepository: ArticleCoauthorRepositoryTrait,
+    >
+    DeleteArticleTranslationService<
+        ArticleRepository,
+        ArticleTranslationRepository,
+        ArticleCoauthorRepository,
+    >
+{
+    pub fn new(
+        article_repository: ArticleRepository,
+        article_translation_repository: ArticleTranslationRepository,
+        article_coauthor_repository: ArticleCoauthorRepository,
+    ) -> Self {
+        DeleteArticleTranslationService {
+            article_repository,
+            article_translation_repository,
+            article_coauthor_repository,
+        }
+    }
+
+    pub async fn exec(&self, params: DeleteArticleTranslationParams) -> Result<(), DomainError> {
+        let article = find_translatable_article(
+            &self.article_repository,
+            &self.article_coauthor_repository,
+            params.article_id,
+            params.locale,
+            (params.user_id, &params.user_role),
+        )
+        .await?;
+
+        let translation = self
+            .article_translation_repository
+            .find_by_article_id_and_locale(article.id(), params.locale)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Delete Article Translation Service, while finding the article's translation",
+                    err,
+                )
+            })?
+            .ok_or_else(DomainError::resource_not_found_err)?;
+
+        self.article_translation_repository
+            .delete(translation)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Delete Article Translation Service, while deleting the translation",
+                    err,
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::domain::domain_entities::article::Article;
+    use crate::domain::domain_entities::article_translation::ArticleTranslation;
+    use crate::tests::repositories::article_coauthor_repository::get_article_coauthor_repository;
+    use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::article_translation_repository::get_article_translation_repository;
+    use std::sync::{Arc, Mutex};
+
+    #[tokio::test]
+    async fn test() {
+        let (article_db, article_repository) = get_article_repository();
+        let (translation_db, article_translation_repository) = get_article_translation_repository();
+        let (_, article_coauthor_repository) =
+            get_article_coauthor_repository(Arc::new(Mutex::new(vec![])), article_db.clone());
+
+        let article = Article::new(
+            Uuid::new_v4(),
+            "Título".into(),
+            "Conteúdo".into(),
+            "url".into(),
+            1,
+            "Foo".into(),
+        );
+
+        article_db.lock().unwrap().push(article.clone());
+        translation_db.lock().unwrap().push(ArticleTranslation::new(
+            article.id(),
+            Locale::En,
+            "Title".into(),
+            "Content".into(),
+        ));
+
+        let service = DeleteArticleTranslationService::new(
+            article_repository,
+            article_translation_repository,
+            article_coauthor_repository,
+        );
+
+        let params = |user_role: Role, locale: Locale| DeleteArticleTranslationParams {
+            user_id: Uuid::new_v4(),
+            user_role,
+            article_id: article.id(),
+            locale,
+        };
+
+        let writer_result = service.exec(params(Role::Writer, Locale::En)).await;
+        assert!(matches!(writer_result, Err(DomainError::Unauthorized(_))));
+
+        let missing_result = service.exec(params(Role::Editor, Locale::Es)).await;
+        assert!(matches!(
+            missing_result,
+            Err(DomainError::ResourceNotFound(_))
+        ));
+
+        service
+            .exec(params(Role::Editor, Locale::En))
+            .await
+            .unwrap();
+        assert!(translation_db.lock().unwrap().is_empty());
+    }
+}
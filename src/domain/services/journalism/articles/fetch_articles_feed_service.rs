@@ -0,0 +1,294 @@
+use chrono::NaiveDateTime as DateTime;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::core::pagination::PaginationParameters;
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_tag::ArticleTag;
+use crate::domain::domain_entities::user::User;
+use crate::domain::repositories::article_repository::{
+    ArticleQuery, ArticleQueryType, ArticleRepositoryTrait, FindManyArticlesResponse,
+};
+use crate::domain::repositories::article_tag_repository::ArticleTagRepositoryTrait;
+use crate::domain::repositories::user_repository::UserRepositoryTrait;
+use crate::error::DomainError;
+use crate::util::generate_service_internal_error;
+
+pub const FEED_ENTRIES_AMOUNT: u32 = 20;
+
+pub enum ArticlesFeedFilter {
+    All,
+    Tag(i32),
+    AuthorNickname(String),
+}
+
+pub struct FetchArticlesFeedParams {
+    pub filter: ArticlesFeedFilter,
+}
+
+#[derive(Debug)]
+pub struct ArticlesFeedEntry {
+    pub article: Article,
+    pub author: User,
+}
+
+#[derive(Debug)]
+pub struct ArticlesFeed {
+    /// Only present on tag feeds.
+    pub tag: Option<ArticleTag>,
+    /// Only present on author feeds.
+    pub author: Option<User>,
+    pub entries: Vec<ArticlesFeedEntry>,
+    /// The most recent creation or update datetime among the feed's articles.
+    pub last_modified: Option<DateTime>,
+}
+
+pub struct FetchArticlesFeedService<AR, ATR, UR>
+where
+    AR: ArticleRepositoryTrait,
+    ATR: ArticleTagRepositoryTrait,
+    UR: UserRepositoryTrait,
+{
+    article_repository: AR,
+    article_tag_repository: ATR,
+    user_repository: UR,
+}
+
+impl<AR: ArticleRepositoryTrait, ATR: ArticleTagRepositoryTrait, UR: UserRepositoryTrait>
+    FetchArticlesFeedService<AR, ATR, UR>
+{
+    pub fn new(article_repository: AR, article_tag_repository: ATR, user_repository: UR) -> Self {
+        FetchArticlesFeedService {
+            article_repository,
+            article_tag_repository,
+            user_repository,
+        }
+    }
+
+    pub async fn exec(&self, params: FetchArticlesFeedParams) -> Result<ArticlesFeed, DomainError> {
+        let mut tag = None;
+        let mut author = None;
+
+        let query = match params.filter {
+            ArticlesFeedFilter::All => None,
+            ArticlesFeedFilter::Tag(tag_id) => {
+                let found_tag = self
+                    .article_tag_repository
+                    .find_by_id(tag_id)
+                    .await
+                    .map_err(|err| {
+                        generate_service_internal_error(
+                            "Error occurred on Fetch Articles Feed Service, while finding tag by id",
+                            err,
+                        )
+                    })?
+                    .ok_or(DomainError::resource_not_found_err())?;
+
+                tag = Some(found_tag);
+                Some(ArticleQueryType::Tag(tag_id))
+            }
+            ArticlesFeedFilter::AuthorNickname(nickname) => {
+                let found_author = self
+                    .user_repository
+                    .find_by_nickname(&nickname)
+                    .await
+                    .map_err(|err| {
+                        generate_service_internal_error(
+                            "Error occurred on Fetch Articles Feed Service, while finding user by nickname",
+                            err,
+                        )
+                    })?
+                    .ok_or(DomainError::resource_not_found_err())?;
+
+                let query = ArticleQueryType::Author(found_author.id());
+                author = Some(found_author);
+                Some(query)
+            }
+        };
+
+        let FindManyArticlesResponse(articles, _) = self
+            .article_repository
+            .find_many(
+                PaginationParameters {
+                    page: 1,
+                    items_per_page: FEED_ENTRIES_AMOUNT,
+                    query: Some(ArticleQuery::new().filter_if(query)),
+                },
+                Some(true),
+            )
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Fetch Articles Feed Service, while fetching the articles",
+                    err,
+                )
+            })?;
+
+        let last_modified = articles
+            .iter()
+            .map(|article| article.updated_at().unwrap_or(article.created_at()))
+            .max();
+
+        let entries = self.attach_authors(articles).await?;
+
+        Ok(ArticlesFeed {
+            tag,
+            author,
+            entries,
+            last_modified,
+        })
+    }
+
+    async fn attach_authors(
+        &self,
+        articles: Vec<Article>,
+    ) -> Result<Vec<ArticlesFeedEntry>, DomainError> {
+        // most feed articles share a handful of authors, so each of them is fetched only once
+        let mut authors: HashMap<Uuid, User> = HashMap::new();
+        let mut entries = Vec::with_capacity(articles.len());
+
+        for article in articles {
+            if !authors.contains_key(&article.author_id()) {
+                let author = self
+                    .user_repository
+                    .find_by_id(&article.author_id())
+                    .await
+                    .map_err(|err| {
+                        generate_service_internal_error(
+                            "Error occurred on Fetch Articles Feed Service, while finding article's author",
+                            err,
+                        )
+                    })?;
+
+                match author {
+                    None => {
+                        log::error!(
+                            "Author from article of id '{}' returned None on Fetch Articles Feed Service.",
+                            article.id()
+                        );
+                        continue;
+                    }
+                    Some(author) => authors.insert(author.id(), author),
+                };
+            }
+
+            entries.push(ArticlesFeedEntry {
+                author: authors[&article.author_id()].clone(),
+                article,
+            });
+        }
+
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use http::StatusCode;
+
+    use crate::domain::domain_entities::role::Role;
+    use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::article_tag_repository::get_article_tag_repository;
+    use crate::tests::repositories::users_repository::get_user_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (articles_db, article_repository) = get_article_repository();
+        let (tags_db, article_tag_repository) = get_article_tag_repository();
+        let (users_db, user_repository) = get_user_repository();
+
+        let writer = User::new("Floricultor".into(), "123".into(), Some(Role::Writer));
+        let other_writer = User::new("Salem".into(), "123".into(), Some(Role::Writer));
+        users_db
+            .lock()
+            .unwrap()
+            .extend([writer.clone(), other_writer.clone()]);
+
+        tags_db
+            .lock()
+            .unwrap()
+            .push(ArticleTag::new_from_existing(1, "Guias".into()));
+
+        let mut guide = Article::new(
+            writer.id(),
+            "Guia".into(),
+            "Conteúdo".into(),
+            "https://images.com/cover.png".into(),
+            1,
+            "Guias".into(),
+        );
+        guide.set_approved(true);
+
+        let mut news = Article::new(
+            other_writer.id(),
+            "Notícia".into(),
+            "Conteúdo".into(),
+            "https://images.com/cover.png".into(),
+            2,
+            "Notícias".into(),
+        );
+        news.set_approved(true);
+
+        let draft = Article::new(
+            writer.id(),
+            "Rascunho".into(),
+            "Conteúdo".into(),
+            "https://images.com/cover.png".into(),
+            1,
+            "Guias".into(),
+        );
+
+        articles_db
+            .lock()
+            .unwrap()
+            .extend([guide.clone(), news.clone(), draft]);
+
+        let sut = FetchArticlesFeedService::new(
+            article_repository,
+            article_tag_repository,
+            user_repository,
+        );
+
+        let feed = sut
+            .exec(FetchArticlesFeedParams {
+                filter: ArticlesFeedFilter::All,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(2, feed.entries.len());
+        assert!(feed.last_modified.is_some());
+
+        let feed = sut
+            .exec(FetchArticlesFeedParams {
+                filter: ArticlesFeedFilter::Tag(1),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!("Guias", feed.tag.unwrap().value());
+        assert_eq!(1, feed.entries.len());
+        assert_eq!(guide.id(), feed.entries[0].article.id());
+        assert_eq!(writer.id(), feed.entries[0].author.id());
+
+        let feed = sut
+            .exec(FetchArticlesFeedParams {
+                filter: ArticlesFeedFilter::AuthorNickname("Salem".into()),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(1, feed.entries.len());
+        assert_eq!(news.id(), feed.entries[0].article.id());
+
+        let result = sut
+            .exec(FetchArticlesFeedParams {
+                filter: ArticlesFeedFilter::Tag(3),
+            })
+            .await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.unwrap_err().get_code());
+    }
+}
@@ -1,8 +1,15 @@
 use crate::domain::domain_entities::article::Article;
 use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
 use crate::error::DomainError;
+use crate::libs::time::TimeHelper;
 use crate::util::generate_service_internal_error;
 
+pub const DEFAULT_HOME_PAGE_SLOTS: u32 = 3;
+
+pub struct FetchHomePageArticlesParams {
+    pub slots: Option<u32>,
+}
+
 pub struct FetchHomePageArticlesService<ArticleRepository: ArticleRepositoryTrait> {
     article_repository: ArticleRepository,
 }
@@ -12,59 +19,199 @@ impl<ArticleRepository: ArticleRepositoryTrait> FetchHomePageArticlesService<Art
         FetchHomePageArticlesService { article_repository }
     }
 
-    pub async fn exec(&self) -> Result<Vec<Article>, DomainError> {
-        self.article_repository
-            .get_home_articles()
+    pub async fn exec(
+        &self,
+        params: FetchHomePageArticlesParams,
+    ) -> Result<Vec<Article>, DomainError> {
+        let slots = params.slots.unwrap_or(DEFAULT_HOME_PAGE_SLOTS) as u64;
+
+        let mut articles = self
+            .article_repository
+            .find_pinned_articles(TimeHelper::now(), slots)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Fetch Home Page Articles Service, while getting the pinned articles from database",
+                    err,
+                )
+            })?;
+
+        if articles.len() as u64 >= slots {
+            return Ok(articles);
+        }
+
+        // fetches a whole page of featured and recent articles, since some of them might already be pinned
+        let recent_articles = self
+            .article_repository
+            .get_home_articles(slots)
             .await
             .map_err(|err| {
                 generate_service_internal_error(
                     "Error occurred on Fetch Home Page Articles Service, while getting the articles from database",
                    err,
                 )
-            })
+            })?;
+
+        let remaining_slots = slots as usize - articles.len();
+
+        let recent_articles = recent_articles
+            .into_iter()
+            .filter(|recent| !articles.iter().any(|pinned| pinned.id() == recent.id()))
+            .take(remaining_slots)
+            .collect::<Vec<Article>>();
+
+        articles.extend(recent_articles);
+
+        Ok(articles)
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::libs::time::TimeHelper;
     use crate::tests::repositories::article_repository::get_article_repository;
+    use chrono::TimeDelta;
     use tokio;
     use uuid::Uuid;
 
-    #[tokio::test]
-    async fn test() {
-        let (article_db, article_repository) = get_article_repository();
-
-        article_db.lock().unwrap().push(Article::new(
-            Uuid::new_v4(),
-            "Título da notícia 1".to_string(),
-            "Conteúdo da primeira notícia".to_string(),
-            "url".to_string(),
-            1,
-            "Foo".into(),
-        ));
-        article_db.lock().unwrap().push(Article::new(
-            Uuid::new_v4(),
-            "Título da notícia 2".to_string(),
-            "Conteúdo da segunda notícia".to_string(),
-            "url".to_string(),
-            1,
-            "Foo".into(),
-        ));
-        article_db.lock().unwrap().push(Article::new(
+    fn create_article(title: &str, approved: bool) -> Article {
+        let mut article = Article::new(
             Uuid::new_v4(),
-            "Título da notícia 3".to_string(),
-            "Conteúdo da terceira notícia".to_string(),
+            title.to_string(),
+            format!("Conteúdo da {title}"),
             "url".to_string(),
             1,
             "Foo".into(),
-        ));
+        );
+
+        article.set_approved(approved);
+
+        article
+    }
+
+    #[tokio::test]
+    async fn test() {
+        let (article_db, article_repository) = get_article_repository();
+
+        article_db
+            .lock()
+            .unwrap()
+            .push(create_article("Título da notícia 1", true));
+        article_db
+            .lock()
+            .unwrap()
+            .push(create_article("Título da notícia 2", true));
+        article_db
+            .lock()
+            .unwrap()
+            .push(create_article("Título da notícia 3", true));
+        article_db
+            .lock()
+            .unwrap()
+            .push(create_article("Rascunho", false));
 
         let service = FetchHomePageArticlesService::new(article_repository);
 
-        let result = service.exec().await.unwrap();
+        let result = service
+            .exec(FetchHomePageArticlesParams { slots: None })
+            .await
+            .unwrap();
 
         assert_eq!(3, result.len());
+        assert!(
+            result.iter().all(|article| article.approved()),
+            "unapproved articles should never be shown on the home page"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pinned_articles_come_first() {
+        let (article_db, article_repository) = get_article_repository();
+
+        let mut pinned_second = create_article("Fixada em segundo", true);
+        pinned_second.pin(2, None);
+
+        let mut pinned_first = create_article("Fixada em primeiro", true);
+        pinned_first.pin(1, Some(TimeHelper::now() + TimeDelta::days(1)));
+
+        let mut expired_pin = create_article("Fixação expirada", true);
+        expired_pin.pin(0, Some(TimeHelper::now() - TimeDelta::days(1)));
+
+        let mut unapproved_pin = create_article("Rascunho fixado", false);
+        unapproved_pin.pin(0, None);
+
+        let recent = create_article("Recente", true);
+
+        {
+            let mut db = article_db.lock().unwrap();
+            db.push(pinned_second.clone());
+            db.push(pinned_first.clone());
+            db.push(expired_pin.clone());
+            db.push(unapproved_pin);
+            db.push(recent.clone());
+        }
+
+        let service = FetchHomePageArticlesService::new(article_repository);
+
+        let result = service
+            .exec(FetchHomePageArticlesParams { slots: Some(4) })
+            .await
+            .unwrap();
+
+        let result_ids = result.iter().map(Article::id).collect::<Vec<Uuid>>();
+
+        assert_eq!(
+            vec![
+                pinned_first.id(),
+                pinned_second.id(),
+                recent.id(),
+                expired_pin.id()
+            ],
+            result_ids,
+            "pinned articles should come first, followed by the most recent approved ones"
+        );
+
+        let result = service
+            .exec(FetchHomePageArticlesParams { slots: Some(1) })
+            .await
+            .unwrap();
+
+        assert_eq!(1, result.len());
+        assert_eq!(pinned_first.id(), result[0].id());
+    }
+
+    #[tokio::test]
+    async fn test_featured_articles_come_before_recent_ones() {
+        let (article_db, article_repository) = get_article_repository();
+
+        let mut pinned = create_article("Fixada", true);
+        pinned.pin(0, None);
+
+        let mut featured = create_article("Destaque antigo", true);
+        featured.set_featured(true);
+
+        let mut unapproved_featured = create_article("Rascunho em destaque", false);
+        unapproved_featured.set_featured(true);
+
+        let recent = create_article("Recente", true);
+
+        article_db.lock().unwrap().push(pinned.clone());
+        article_db.lock().unwrap().push(featured.clone());
+        article_db.lock().unwrap().push(unapproved_featured);
+        article_db.lock().unwrap().push(recent.clone());
+
+        let service = FetchHomePageArticlesService::new(article_repository);
+
+        let result = service
+            .exec(FetchHomePageArticlesParams { slots: Some(2) })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec![pinned.id(), featured.id()],
+            result.iter().map(Article::id).collect::<Vec<Uuid>>(),
+            "featured articles should fill the slots left by pinned ones before recent articles"
+        );
     }
 }
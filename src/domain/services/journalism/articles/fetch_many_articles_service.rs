@@ -1,11 +1,16 @@
-use crate::core::pagination::{PaginationParameters, PaginationResponse};
+use crate::core::pagination::{
+    Cursor, CursorPage, CursorParameters, PaginationParameters, PaginationResponse,
+};
+use crate::core::query::{DateRange, Sort};
 use crate::domain::domain_entities::article::Article;
 use crate::domain::repositories::article_repository::{
-    ArticleQueryType, ArticleRepositoryTrait, FindManyArticlesResponse,
+    ArticleQuery, ArticleQueryType, ArticleRepositoryTrait, ArticleSortField,
+    FindManyArticlesResponse,
 };
 use crate::domain::repositories::user_repository::UserRepositoryTrait;
 use crate::error::DomainError;
 use crate::util::generate_service_internal_error;
+use uuid::Uuid;
 
 type Error = DomainError;
 
@@ -13,12 +18,24 @@ type Error = DomainError;
 pub enum ServiceArticleQueryType {
     Title(String),
     Author(String),
+    Tag(i32),
+    CreatedAt(DateRange),
 }
 
 pub struct FetchManyArticlesParams {
     pub page: Option<u32>,
     pub per_page: Option<u32>,
-    pub query: Option<ServiceArticleQueryType>,
+    /// Articles must match all of them.
+    pub filters: Vec<ServiceArticleQueryType>,
+    pub sort: Vec<Sort<ArticleSortField>>,
+    pub approved_state: Option<bool>,
+}
+
+pub struct FetchArticlesByCursorParams {
+    pub cursor: Option<Cursor<Uuid>>,
+    pub per_page: Option<u32>,
+    /// Articles must match all of them.
+    pub filters: Vec<ServiceArticleQueryType>,
     pub approved_state: Option<bool>,
 }
 
@@ -72,7 +89,13 @@ impl<ArticleRepository: ArticleRepositoryTrait, UserRepository: UserRepositoryTr
             default_page
         };
 
-        let query = self.parse_query(params.query).await?;
+        let mut query = ArticleQuery::new();
+
+        for filter in params.filters {
+            query = query.filter(self.parse_filter(filter).await?);
+        }
+
+        query.sort = params.sort;
 
         let FindManyArticlesResponse(articles, total_items) = self
             .article_repository
@@ -80,7 +103,7 @@ impl<ArticleRepository: ArticleRepositoryTrait, UserRepository: UserRepositoryTr
                 PaginationParameters {
                     items_per_page,
                     page,
-                    query,
+                    query: Some(query),
                 },
                 params.approved_state,
             )
@@ -98,15 +121,50 @@ impl<ArticleRepository: ArticleRepositoryTrait, UserRepository: UserRepositoryTr
         })
     }
 
-    async fn parse_query(
+    /// Lists the articles from the most recent to the oldest, a page after (or before) the cursor.
+    pub async fn exec_by_cursor(
         &self,
-        query: Option<ServiceArticleQueryType>,
-    ) -> Result<Option<ArticleQueryType>, Error> {
-        if query.is_none() {
-            return Ok(None);
+        params: FetchArticlesByCursorParams,
+    ) -> Result<CursorPage<Article, Uuid>, Error> {
+        let items_per_page = params.per_page.unwrap_or(9);
+
+        let mut filters = vec![];
+
+        for filter in params.filters {
+            filters.push(self.parse_filter(filter).await?);
         }
 
-        match query.unwrap() {
+        let articles = self
+            .article_repository
+            .find_many_by_cursor(
+                CursorParameters {
+                    cursor: params.cursor.clone(),
+                    items_per_page,
+                    filters,
+                },
+                params.approved_state,
+            )
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Fetch Many Articles Service, while finding articles by cursor",
+                    err,
+                )
+            })?;
+
+        Ok(CursorPage::from_window(
+            articles,
+            params.cursor.as_ref(),
+            items_per_page,
+            |article| (article.created_at(), article.id()),
+        ))
+    }
+
+    async fn parse_filter(
+        &self,
+        filter: ServiceArticleQueryType,
+    ) -> Result<ArticleQueryType, Error> {
+        match filter {
             ServiceArticleQueryType::Author(content) => {
                 let user = self.user_repository.find_by_nickname(&content).await;
 
@@ -120,9 +178,11 @@ impl<ArticleRepository: ArticleRepositoryTrait, UserRepository: UserRepositoryTr
                     return Err(DomainError::resource_not_found_err());
                 }
 
-                Ok(Some(ArticleQueryType::Author(user.unwrap().id())))
+                Ok(ArticleQueryType::Author(user.unwrap().id()))
             }
-            ServiceArticleQueryType::Title(content) => Ok(Some(ArticleQueryType::Title(content))),
+            ServiceArticleQueryType::Title(content) => Ok(ArticleQueryType::Title(content)),
+            ServiceArticleQueryType::Tag(tag_id) => Ok(ArticleQueryType::Tag(tag_id)),
+            ServiceArticleQueryType::CreatedAt(range) => Ok(ArticleQueryType::CreatedAt(range)),
         }
     }
 }
@@ -130,6 +190,7 @@ impl<ArticleRepository: ArticleRepositoryTrait, UserRepository: UserRepositoryTr
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::core::query::SortDirection;
     use http::StatusCode;
     use tokio;
 
@@ -188,7 +249,8 @@ mod test {
             .exec(FetchManyArticlesParams {
                 page: Some(2),
                 per_page: Some(1),
-                query: Some(ServiceArticleQueryType::Title("article".to_string())),
+                filters: vec![ServiceArticleQueryType::Title("article".to_string())],
+                sort: vec![],
                 approved_state: None,
             })
             .await
@@ -217,7 +279,8 @@ mod test {
             .exec(FetchManyArticlesParams {
                 page: None,
                 per_page: None,
-                query: None,
+                filters: vec![],
+                sort: vec![],
                 approved_state: None,
             })
             .await
@@ -244,7 +307,8 @@ mod test {
             .exec(FetchManyArticlesParams {
                 page: None,
                 per_page: None,
-                query: Some(ServiceArticleQueryType::Author("Vamp".to_string())),
+                filters: vec![ServiceArticleQueryType::Author("Vamp".to_string())],
+                sort: vec![],
                 approved_state: None,
             })
             .await
@@ -260,7 +324,8 @@ mod test {
             .exec(FetchManyArticlesParams {
                 page: None,
                 per_page: None,
-                query: Some(ServiceArticleQueryType::Author("Floricultor".to_string())),
+                filters: vec![ServiceArticleQueryType::Author("Floricultor".to_string())],
+                sort: vec![],
                 approved_state: None,
             })
             .await
@@ -280,7 +345,8 @@ mod test {
             .exec(FetchManyArticlesParams {
                 page: None,
                 per_page: None,
-                query: None,
+                filters: vec![],
+                sort: vec![],
                 approved_state: Some(true),
             })
             .await
@@ -294,6 +360,83 @@ mod test {
         assert_eq!(
             1, query_approved_only_articles_request.pagination.total_items,
             "Expected only-approved-articles request pagination total_items to be 1."
-        )
+        );
+
+        let combined_query_request = fetch_many_articles_service
+            .exec(FetchManyArticlesParams {
+                page: None,
+                per_page: None,
+                filters: vec![
+                    ServiceArticleQueryType::Author("Floricultor".to_string()),
+                    ServiceArticleQueryType::Title("article 2".to_string()),
+                    ServiceArticleQueryType::CreatedAt(
+                        DateRange::new(Some(approved_article.created_at()), None).unwrap(),
+                    ),
+                ],
+                sort: vec![],
+                approved_state: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(1, combined_query_request.data.len());
+        assert_eq!(combined_query_request.data[0].title(), "Article 2 title");
+
+        let sorted_request = fetch_many_articles_service
+            .exec(FetchManyArticlesParams {
+                page: None,
+                per_page: None,
+                filters: vec![ServiceArticleQueryType::Tag(1)],
+                sort: vec![Sort {
+                    field: ArticleSortField::Title,
+                    direction: SortDirection::Desc,
+                }],
+                approved_state: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(sorted_request.data[0].title(), "Article 2 title");
+        assert_eq!(sorted_request.data[1].title(), "Article 1 title");
+
+        let first_page = fetch_many_articles_service
+            .exec_by_cursor(FetchArticlesByCursorParams {
+                cursor: None,
+                per_page: Some(1),
+                filters: vec![ServiceArticleQueryType::Title("article".to_string())],
+                approved_state: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(1, first_page.data.len());
+        assert!(first_page.pagination.prev_cursor.is_none());
+
+        let last_page = fetch_many_articles_service
+            .exec_by_cursor(FetchArticlesByCursorParams {
+                cursor: first_page.pagination.next_cursor,
+                per_page: Some(1),
+                filters: vec![ServiceArticleQueryType::Title("article".to_string())],
+                approved_state: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(1, last_page.data.len());
+        assert_ne!(first_page.data[0].id(), last_page.data[0].id());
+        assert!(last_page.pagination.next_cursor.is_none());
+
+        let back_to_first_page = fetch_many_articles_service
+            .exec_by_cursor(FetchArticlesByCursorParams {
+                cursor: last_page.pagination.prev_cursor,
+                per_page: Some(1),
+                filters: vec![ServiceArticleQueryType::Title("article".to_string())],
+                approved_state: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(first_page.data[0].id(), back_to_first_page.data[0].id());
+        assert!(back_to_first_page.pagination.prev_cursor.is_none());
     }
 }
@@ -0,0 +1,252 @@
+use chrono::TimeDelta;
+
+use crate::domain::cache::related_articles_cache::RelatedArticlesCacheTrait;
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::slug::Slug;
+use crate::domain::politics::related_articles_politics::RelatedArticlesPolitics;
+use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::error::DomainError;
+use crate::libs::time::TimeHelper;
+use crate::util::generate_service_internal_error;
+
+pub const DEFAULT_RELATED_ARTICLES_AMOUNT: u32 = 4;
+pub const MAX_RELATED_ARTICLES_AMOUNT: u32 = 12;
+
+/// Articles neither sharing the tag nor the author are only considered if they're at most this old.
+const RECENT_CANDIDATES_WINDOW_IN_DAYS: i64 = 90;
+const CANDIDATES_LIMIT: u64 = 200;
+
+pub struct FetchRelatedArticlesParams {
+    pub article_slug: Slug,
+    pub amount: Option<u32>,
+}
+
+pub struct FetchRelatedArticlesService<ArticleRepository, RelatedArticlesCache>
+where
+    ArticleRepository: ArticleRepositoryTrait,
+    RelatedArticlesCache: RelatedArticlesCacheTrait,
+{
+    article_repository: ArticleRepository,
+    related_articles_cache: RelatedArticlesCache,
+}
+
+impl<ArticleRepository, RelatedArticlesCache>
+    FetchRelatedArticlesService<ArticleRepository, RelatedArticlesCache>
+where
+    ArticleRepository: ArticleRepositoryTrait,
+    RelatedArticlesCache: RelatedArticlesCacheTrait,
+{
+    pub fn new(
+        article_repository: ArticleRepository,
+        related_articles_cache: RelatedArticlesCache,
+    ) -> Self {
+        FetchRelatedArticlesService {
+            article_repository,
+            related_articles_cache,
+        }
+    }
+
+    pub async fn exec(
+        &self,
+        params: FetchRelatedArticlesParams,
+    ) -> Result<Vec<Article>, DomainError> {
+        let article = self
+            .article_repository
+            .find_by_slug(&params.article_slug)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Fetch Related Articles Service, while finding article by slug",
+                    err,
+                )
+            })?;
+
+        let article = match article {
+            Some(article) if article.approved() => article,
+            _ => return Err(DomainError::resource_not_found_err()),
+        };
+
+        find_related_articles(
+            &self.article_repository,
+            &self.related_articles_cache,
+            &article,
+            params.amount.unwrap_or(DEFAULT_RELATED_ARTICLES_AMOUNT),
+        )
+        .await
+    }
+}
+
+/// Gets the `amount` articles most related to `article`, ranking them only if the cache doesn't have them yet.
+///
+/// Shared with the Get Expanded Article Service, which embeds them in the article's page.
+pub async fn find_related_articles<ArticleRepository, RelatedArticlesCache>(
+    article_repository: &ArticleRepository,
+    related_articles_cache: &RelatedArticlesCache,
+    article: &Article,
+    amount: u32,
+) -> Result<Vec<Article>, DomainError>
+where
+    ArticleRepository: ArticleRepositoryTrait,
+    RelatedArticlesCache: RelatedArticlesCacheTrait,
+{
+    let amount = amount.min(MAX_RELATED_ARTICLES_AMOUNT) as usize;
+
+    if let Some(related_articles) = related_articles_cache.get(article.id()) {
+        return Ok(related_articles.into_iter().take(amount).collect());
+    }
+
+    let now = TimeHelper::now();
+
+    let candidates = article_repository
+        .find_related_candidates(
+            article,
+            now - TimeDelta::days(RECENT_CANDIDATES_WINDOW_IN_DAYS),
+            CANDIDATES_LIMIT,
+        )
+        .await
+        .map_err(|err| {
+            generate_service_internal_error(
+                "Error occurred while finding the candidates to be related to an article",
+                err,
+            )
+        })?;
+
+    // the whole ranking is cached so that requests for any amount can be served from it
+    let related_articles = RelatedArticlesPolitics::rank(
+        article,
+        candidates,
+        now,
+        MAX_RELATED_ARTICLES_AMOUNT as usize,
+    );
+
+    related_articles_cache.set(article.id(), related_articles.clone());
+
+    Ok(related_articles.into_iter().take(amount).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use uuid::Uuid;
+
+    use crate::domain::domain_entities::article::CommentMode;
+    use crate::infra::cache::in_memory_related_articles_cache::InMemoryRelatedArticlesCache;
+    use crate::tests::repositories::article_repository::get_article_repository;
+
+    fn approved_article(author_id: Uuid, title: &str, content: &str, tag_id: i32) -> Article {
+        let mut article = Article::new(
+            author_id,
+            title.into(),
+            content.into(),
+            "url".into(),
+            tag_id,
+            "Tag".into(),
+        );
+        article.set_approved(true);
+
+        article
+    }
+
+    #[tokio::test]
+    async fn test() {
+        let (articles_db, article_repository) = get_article_repository();
+        let cache = InMemoryRelatedArticlesCache::new();
+
+        let author_id = Uuid::new_v4();
+
+        let article = approved_article(
+            author_id,
+            "Guia de wired para iniciantes",
+            "Aprenda a montar seus primeiros mobis wired no quarto.",
+            1,
+        );
+        let closely_related = approved_article(
+            Uuid::new_v4(),
+            "Guia de wired avançado",
+            "Depois de montar seus primeiros mobis wired, aprenda truques avançados.",
+            1,
+        );
+        let loosely_related = approved_article(
+            Uuid::new_v4(),
+            "Resultado do concurso de quartos",
+            "Confira os vencedores do concurso.",
+            1,
+        );
+        let unrelated = approved_article(
+            Uuid::new_v4(),
+            "Nova coleção de raros",
+            "A loja recebeu novos raros.",
+            2,
+        );
+
+        articles_db.lock().unwrap().extend([
+            article.clone(),
+            unrelated.clone(),
+            loosely_related.clone(),
+            closely_related.clone(),
+            Article::new_from_existing(
+                Uuid::new_v4(),
+                author_id,
+                "url".into(),
+                "Guia de wired para iniciantes".into(),
+                "Rascunho".into(),
+                false,
+                TimeHelper::now(),
+                None,
+                Some(1),
+                Some("Tag".into()),
+                Slug::new(Uuid::new_v4(), "rascunho".into()),
+                false,
+                false,
+                0,
+                None,
+                CommentMode::Open,
+            ),
+        ]);
+
+        let sut = FetchRelatedArticlesService::new(article_repository, cache.clone());
+
+        let result = sut
+            .exec(FetchRelatedArticlesParams {
+                article_slug: article.slug(),
+                amount: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec![closely_related.id(), loosely_related.id(), unrelated.id()],
+            result
+                .iter()
+                .map(|article| article.id())
+                .collect::<Vec<Uuid>>()
+        );
+
+        // results are served from the cache until it gets invalidated
+        articles_db.lock().unwrap().clear();
+        articles_db.lock().unwrap().push(article.clone());
+
+        let result = sut
+            .exec(FetchRelatedArticlesParams {
+                article_slug: article.slug(),
+                amount: Some(1),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(vec![closely_related.id()], vec![result[0].id()]);
+
+        cache.invalidate();
+
+        let result = sut
+            .exec(FetchRelatedArticlesParams {
+                article_slug: article.slug(),
+                amount: None,
+            })
+            .await
+            .unwrap();
+
+        assert!(result.is_empty());
+    }
+}
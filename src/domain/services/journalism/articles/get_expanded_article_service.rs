@@ -1,16 +1,27 @@
 use crate::core::pagination::PaginationParameters;
 use crate::core::pagination::PaginationResponse;
 use crate::core::pagination::DEFAULT_PER_PAGE;
-use crate::domain::domain_entities::article::Article;
+use crate::domain::cache::related_articles_cache::RelatedArticlesCacheTrait;
+use crate::domain::domain_entities::article::{Article, CommentMode};
+use crate::domain::domain_entities::article_coauthor::ArticleCoauthor;
 use crate::domain::domain_entities::comment_with_author::CommentWithAuthor;
+use crate::domain::domain_entities::locale::Locale;
 use crate::domain::domain_entities::role::Role;
+use crate::domain::domain_entities::series::Series;
 use crate::domain::domain_entities::slug::Slug;
 use crate::domain::domain_entities::user::User;
+use crate::domain::politics::article_politics::ArticlePolitics;
+use crate::domain::repositories::article_coauthor_repository::ArticleCoauthorRepositoryTrait;
 use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::domain::repositories::article_translation_repository::ArticleTranslationRepositoryTrait;
 use crate::domain::repositories::comment_user_article_repository::CommentUserArticleRepositoryTrait;
 use crate::domain::repositories::comment_user_article_repository::FindManyCommentsWithAuthorResponse;
+use crate::domain::repositories::series_repository::SeriesRepositoryTrait;
 use crate::domain::repositories::user_repository::UserRepositoryTrait;
+use crate::domain::services::journalism::articles::fetch_related_articles_service::find_related_articles;
+use crate::domain::services::journalism::articles::translate_articles_service::translate_article;
 use crate::error::DomainError;
+use crate::libs::time::TimeHelper;
 use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
 use uuid::Uuid;
 
@@ -19,6 +30,12 @@ pub struct GetExpandedArticleParams<'exec> {
     pub comments_per_page: Option<u32>,
     pub user_role: Option<&'exec Role>,
     pub user_id: Option<&'exec Uuid>,
+    /// How many related articles should be included, if any.
+    pub related_articles_amount: Option<u32>,
+    /// Defaults to the locale of the slug, which might be a translation's.
+    pub locale: Option<Locale>,
+    /// Articles older than this many days no longer take comments, see `ArticlePolitics::comment_mode`.
+    pub comment_auto_close_days: Option<u32>,
 }
 
 #[derive(Debug)]
@@ -27,39 +44,83 @@ pub struct FetchManyCommentsWithAuthorResponse {
     pub data: Vec<CommentWithAuthor>,
 }
 
+#[derive(Debug)]
+pub struct ArticleSeriesNavigation {
+    pub series: Series,
+    /// The article's 1-based position within the series.
+    pub position: usize,
+    pub total: usize,
+    pub previous: Option<Article>,
+    pub next: Option<Article>,
+}
+
 #[derive(Debug)]
 pub struct GetExpandedArticleResponse {
     pub article: Article,
     pub article_author: User,
+    /// The article's co-authors along with their users, in the order they're credited.
+    pub coauthors: Vec<(ArticleCoauthor, User)>,
     pub comments: FetchManyCommentsWithAuthorResponse,
+    pub series: Option<ArticleSeriesNavigation>,
+    /// Only present if requested and the article is approved.
+    pub related_articles: Option<Vec<Article>>,
+    /// The locale the article is actually in, which is the fallback one if it hasn't been translated to the requested
+    /// locale.
+    pub locale: Locale,
+    /// The article's slug in each locale it can be read in, the fallback one included.
+    pub alternates: Vec<(Locale, Slug)>,
+    /// The mode the article's comments are actually under, after the auto-close.
+    pub comment_mode: CommentMode,
+    /// Whether the user can comment on the article, or could once logged in if there's no user.
+    pub can_comment: bool,
 }
 
-pub struct GetExpandedArticleService<UR, AR, CUAR>
+pub struct GetExpandedArticleService<UR, AR, CUAR, SR, ACR, ATR, RAC>
 where
     UR: UserRepositoryTrait,
     AR: ArticleRepositoryTrait,
     CUAR: CommentUserArticleRepositoryTrait,
+    SR: SeriesRepositoryTrait,
+    ACR: ArticleCoauthorRepositoryTrait,
+    ATR: ArticleTranslationRepositoryTrait,
+    RAC: RelatedArticlesCacheTrait,
 {
     user_repository: UR,
     article_repository: AR,
     comment_user_article_repository: CUAR,
+    series_repository: SR,
+    article_coauthor_repository: ACR,
+    article_translation_repository: ATR,
+    related_articles_cache: RAC,
 }
 
 impl<
         UR: UserRepositoryTrait,
         AR: ArticleRepositoryTrait,
         CUAR: CommentUserArticleRepositoryTrait,
-    > GetExpandedArticleService<UR, AR, CUAR>
+        SR: SeriesRepositoryTrait,
+        ACR: ArticleCoauthorRepositoryTrait,
+        ATR: ArticleTranslationRepositoryTrait,
+        RAC: RelatedArticlesCacheTrait,
+    > GetExpandedArticleService<UR, AR, CUAR, SR, ACR, ATR, RAC>
 {
     pub fn new(
         user_repository: UR,
         article_repository: AR,
         comment_user_article_repository: CUAR,
+        series_repository: SR,
+        article_coauthor_repository: ACR,
+        article_translation_repository: ATR,
+        related_articles_cache: RAC,
     ) -> Self {
         GetExpandedArticleService {
             user_repository,
             article_repository,
             comment_user_article_repository,
+            series_repository,
+            article_coauthor_repository,
+            article_translation_repository,
+            related_articles_cache,
         }
     }
 
@@ -69,19 +130,7 @@ impl<
     ) -> Result<GetExpandedArticleResponse, DomainError> {
         let items_per_page = params.comments_per_page.unwrap_or(DEFAULT_PER_PAGE as u32);
 
-        let article = match self
-            .article_repository
-            .find_by_slug(&params.article_slug)
-            .await
-            .map_err(|err| {
-                generate_service_internal_error(
-                    "Error occurred on Get Expanded Article Service, while finding article by Id",
-                    err,
-                )
-            })? {
-            None => return Err(DomainError::resource_not_found_err()),
-            Some(article) => article,
-        };
+        let (article, slug_locale) = self.find_article_by_slug(&params.article_slug).await?;
 
         let user_can_see_article = {
             if params.user_id.is_none() || params.user_role.is_none() {
@@ -96,7 +145,10 @@ impl<
             }
         };
 
-        if !article.approved() && !user_can_see_article {
+        if !article.approved()
+            && !user_can_see_article
+            && !self.user_is_coauthor(&article, params.user_id).await?
+        {
             return Err(DomainError::resource_not_found_err());
         }
 
@@ -120,7 +172,7 @@ impl<
             })?;
 
         let comments = FetchManyCommentsWithAuthorResponse {
-            data,
+            data: data.into_iter().map(CommentWithAuthor::redacted).collect(),
             pagination: PaginationResponse::new(1, total_items, items_per_page),
         };
 
@@ -145,12 +197,194 @@ impl<
 
         let author = author.unwrap();
 
+        let coauthors = self
+            .article_coauthor_repository
+            .find_with_users_by_article_id(article.id())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Get Expanded Article Service, while finding the article's co-authors",
+                    err,
+                )
+            })?;
+
+        let series = self.get_series_navigation(&article).await?;
+
+        let related_articles = match params.related_articles_amount {
+            Some(amount) if article.approved() => Some(
+                find_related_articles(
+                    &self.article_repository,
+                    &self.related_articles_cache,
+                    &article,
+                    amount,
+                )
+                .await?,
+            ),
+            _ => None,
+        };
+
+        let translations = self
+            .article_translation_repository
+            .find_many_by_article_ids(vec![article.id()])
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Get Expanded Article Service, while finding the article's translations",
+                    err,
+                )
+            })?;
+
+        let alternates = [(Locale::FALLBACK, article.slug())]
+            .into_iter()
+            .chain(
+                translations
+                    .iter()
+                    .map(|translation| (translation.locale(), translation.slug())),
+            )
+            .collect();
+
+        let comment_mode = ArticlePolitics::comment_mode(
+            &article,
+            params.comment_auto_close_days,
+            TimeHelper::now(),
+        );
+
+        let translated_article =
+            translate_article(article, &translations, params.locale.or(slug_locale));
+
         Ok(GetExpandedArticleResponse {
-            article,
+            article: translated_article.article,
             article_author: author,
+            coauthors,
             comments,
+            series,
+            related_articles,
+            locale: translated_article.locale,
+            alternates,
+            comment_mode,
+            can_comment: ArticlePolitics::can_comment(comment_mode, params.user_role),
         })
     }
+
+    /// Finds the article by its own slug or by one of its translations', in which case the translation's locale is
+    /// returned too.
+    async fn find_article_by_slug(
+        &self,
+        slug: &Slug,
+    ) -> Result<(Article, Option<Locale>), DomainError> {
+        let article = self
+            .article_repository
+            .find_by_slug(slug)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Get Expanded Article Service, while finding article by slug",
+                    err,
+                )
+            })?;
+
+        if let Some(article) = article {
+            return Ok((article, None));
+        }
+
+        let translation = self
+            .article_translation_repository
+            .find_by_slug(slug)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Get Expanded Article Service, while finding a translation by slug",
+                    err,
+                )
+            })?
+            .ok_or_else(DomainError::resource_not_found_err)?;
+
+        let article = self
+            .article_repository
+            .find_by_id(translation.article_id())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Get Expanded Article Service, while finding the translated article by id",
+                    err,
+                )
+            })?
+            .ok_or_else(DomainError::resource_not_found_err)?;
+
+        Ok((article, Some(translation.locale())))
+    }
+
+    async fn user_is_coauthor(
+        &self,
+        article: &Article,
+        user_id: Option<&Uuid>,
+    ) -> Result<bool, DomainError> {
+        let user_id = match user_id {
+            None => return Ok(false),
+            Some(user_id) => *user_id,
+        };
+
+        self.article_coauthor_repository
+            .is_coauthor(article.id(), user_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Get Expanded Article Service, while checking if user is a co-author",
+                    err,
+                )
+            })
+    }
+
+    async fn get_series_navigation(
+        &self,
+        article: &Article,
+    ) -> Result<Option<ArticleSeriesNavigation>, DomainError> {
+        let series = self
+            .series_repository
+            .find_by_article_id(article.id())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Get Expanded Article Service, while finding the article's series",
+                    err,
+                )
+            })?;
+
+        let series = match series {
+            None => return Ok(None),
+            Some(series) => series,
+        };
+
+        // unapproved articles are only seen by staff, who can navigate through the whole series
+        let series_articles = self
+            .series_repository
+            .find_articles(series.id(), article.approved())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Get Expanded Article Service, while fetching the series' articles",
+                    err,
+                )
+            })?;
+
+        let index = match series_articles
+            .iter()
+            .position(|series_article| series_article.id() == article.id())
+        {
+            None => return Ok(None),
+            Some(index) => index,
+        };
+
+        Ok(Some(ArticleSeriesNavigation {
+            position: index + 1,
+            total: series_articles.len(),
+            previous: index
+                .checked_sub(1)
+                .and_then(|previous| series_articles.get(previous).cloned()),
+            next: series_articles.get(index + 1).cloned(),
+            series,
+        }))
+    }
 }
 
 #[cfg(test)]
@@ -161,19 +395,31 @@ mod test {
     use tokio;
     use uuid::Uuid;
 
+    use crate::domain::domain_entities::article_translation::ArticleTranslation;
+    use crate::domain::domain_entities::habbo_avatar::HabboAvatar;
     use crate::domain::domain_entities::{comment_with_author::CommentWithAuthor, role::Role};
+    use crate::domain::repositories::article_comment_repository::CommentSortField;
     use crate::domain::repositories::comment_user_article_repository::{
         CommentWithAuthorQueryType, MockCommentUserArticleRepositoryTrait,
     };
     use crate::domain::repositories::user_repository::MockUserRepositoryTrait;
+    use crate::infra::cache::in_memory_related_articles_cache::InMemoryRelatedArticlesCache;
     use crate::libs::time::TimeHelper;
+    use crate::tests::query_spec::apply_query_spec;
+    use crate::tests::repositories::article_coauthor_repository::get_article_coauthor_repository;
     use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::article_translation_repository::get_article_translation_repository;
+    use crate::tests::repositories::series_repository::get_series_repository;
 
     #[tokio::test]
     async fn test() {
         let mut mocked_user_repo = MockUserRepositoryTrait::new();
         let mut mock_comm_user_art_repo = MockCommentUserArticleRepositoryTrait::new();
         let (articles_db, mocked_article_repository) = get_article_repository();
+        let (_, _, series_repository) = get_series_repository(articles_db.clone());
+        let (_, article_coauthor_repository) =
+            get_article_coauthor_repository(Arc::new(Mutex::new(vec![])), articles_db.clone());
+        let (_, article_translation_repository) = get_article_translation_repository();
 
         let comments_db: Arc<Mutex<Vec<CommentWithAuthor>>> = Arc::new(Mutex::new(vec![]));
 
@@ -213,6 +459,8 @@ mod test {
             TimeHelper::now(),
             None,
             Some(Role::Ceo),
+            None,
+            HabboAvatar::default(),
         );
 
         let user_id = user.id();
@@ -232,37 +480,31 @@ mod test {
                     query,
                 } = params;
 
-                let mut comments: Vec<CommentWithAuthor> = Vec::new();
-
-                if query.is_some() {
-                    match query.unwrap() {
-                        CommentWithAuthorQueryType::Content(content) => {
-                            for item in comments_db_to_move.lock().unwrap().iter() {
-                                if item
-                                    .content()
-                                    .to_lowercase()
-                                    .contains(&content.to_lowercase()[..])
-                                    || include_inactive
-                                    || item.is_active()
-                                {
-                                    comments.push(item.clone());
-                                }
-                            }
-                        }
+                let comments: Vec<CommentWithAuthor> = apply_query_spec(
+                    comments_db_to_move
+                        .lock()
+                        .unwrap()
+                        .iter()
+                        .filter(|item| include_inactive || item.is_active())
+                        .cloned()
+                        .collect(),
+                    query.as_ref(),
+                    |item, query| match query {
+                        CommentWithAuthorQueryType::Content(content) => item
+                            .content()
+                            .to_lowercase()
+                            .contains(&content.to_lowercase()[..]),
                         CommentWithAuthorQueryType::Author(content) => {
-                            for item in comments_db_to_move.lock().unwrap().iter() {
-                                if item.author().id().eq(&content)
-                                    || include_inactive
-                                    || item.is_active()
-                                {
-                                    comments.push(item.clone());
-                                }
-                            }
+                            item.author().id().eq(content)
                         }
-                    }
-                } else {
-                    comments = comments_db_to_move.lock().unwrap().clone();
-                }
+                        CommentWithAuthorQueryType::CreatedAt(range) => {
+                            range.contains(&item.created_at())
+                        }
+                    },
+                    |a, b, field| match field {
+                        CommentSortField::CreatedAt => a.created_at().cmp(&b.created_at()),
+                    },
+                );
 
                 let total_of_items_before_paginating = comments.len();
 
@@ -286,6 +528,10 @@ mod test {
             user_repository: mocked_user_repo,
             comment_user_article_repository: mock_comm_user_art_repo,
             article_repository: mocked_article_repository,
+            series_repository,
+            article_coauthor_repository,
+            article_translation_repository,
+            related_articles_cache: InMemoryRelatedArticlesCache::new(),
         };
 
         let allowed_result = sut
@@ -294,6 +540,9 @@ mod test {
                 comments_per_page: None,
                 user_id: Some(&user_id),
                 user_role: Some(&Role::Editor),
+                related_articles_amount: None,
+                locale: None,
+                comment_auto_close_days: None,
             })
             .await
             .unwrap();
@@ -301,9 +550,17 @@ mod test {
         let GetExpandedArticleResponse {
             article,
             article_author,
+            coauthors,
             comments,
+            series,
+            comment_mode,
+            can_comment,
+            ..
         } = allowed_result;
 
+        assert_eq!(CommentMode::Open, comment_mode);
+        assert!(can_comment);
+
         let FetchManyCommentsWithAuthorResponse { data, pagination } = comments;
 
         assert_eq!(mocked_comm_1, data[0].clone());
@@ -311,6 +568,8 @@ mod test {
         assert_eq!(2, pagination.total_items);
         assert_eq!(mocked_article_id, article.id());
         assert_eq!(user_id, article_author.id());
+        assert!(series.is_none());
+        assert!(coauthors.is_empty());
 
         let unauthorized_result = sut
             .exec(GetExpandedArticleParams {
@@ -318,6 +577,9 @@ mod test {
                 comments_per_page: None,
                 user_id: None,
                 user_role: None,
+                related_articles_amount: None,
+                locale: None,
+                comment_auto_close_days: None,
             })
             .await;
 
@@ -326,4 +588,287 @@ mod test {
             "Expected a user not to be able to see an unapproved article if it's not the author and nor has the permission to see unapproved articles."
         );
     }
+
+    #[tokio::test]
+    async fn test_series_navigation() {
+        let mut mocked_user_repo = MockUserRepositoryTrait::new();
+        let mut mock_comm_user_art_repo = MockCommentUserArticleRepositoryTrait::new();
+        let (articles_db, mocked_article_repository) = get_article_repository();
+        let (series_db, series_articles_db, series_repository) =
+            get_series_repository(articles_db.clone());
+        let (_, article_coauthor_repository) =
+            get_article_coauthor_repository(Arc::new(Mutex::new(vec![])), articles_db.clone());
+        let (_, article_translation_repository) = get_article_translation_repository();
+
+        let author = User::new("Floricultor".into(), "123".into(), Some(Role::Writer));
+
+        let mut articles = vec![];
+
+        for title in ["Parte 1", "Parte 2", "Parte 3"] {
+            let mut article = Article::new(
+                author.id(),
+                title.into(),
+                "Conteúdo".into(),
+                "url".into(),
+                1,
+                "Guias".into(),
+            );
+            article.set_approved(true);
+            articles.push(article);
+        }
+
+        articles_db.lock().unwrap().extend(articles.clone());
+
+        let series = Series::new(author.id(), "Guia de Wired".into(), "Descrição".into());
+        series_db.lock().unwrap().push(series.clone());
+        series_articles_db.lock().unwrap().insert(
+            series.id(),
+            articles.iter().map(|article| article.id()).collect(),
+        );
+
+        mocked_user_repo
+            .expect_find_by_id()
+            .returning(move |_id| Ok(Some(author.clone())));
+
+        mock_comm_user_art_repo
+            .expect_find_many_comments()
+            .returning(|_, _, _| Ok(FindManyCommentsWithAuthorResponse(vec![], 0)));
+
+        let sut = GetExpandedArticleService::new(
+            mocked_user_repo,
+            mocked_article_repository,
+            mock_comm_user_art_repo,
+            series_repository,
+            article_coauthor_repository,
+            article_translation_repository,
+            InMemoryRelatedArticlesCache::new(),
+        );
+
+        let navigation = sut
+            .exec(GetExpandedArticleParams {
+                article_slug: articles[1].slug(),
+                comments_per_page: None,
+                user_id: None,
+                user_role: None,
+                related_articles_amount: None,
+                locale: None,
+                comment_auto_close_days: None,
+            })
+            .await
+            .unwrap()
+            .series
+            .expect("article should be part of a series");
+
+        assert_eq!(series.id(), navigation.series.id());
+        assert_eq!((2, 3), (navigation.position, navigation.total));
+        assert_eq!(
+            Some(articles[0].id()),
+            navigation.previous.map(|article| article.id())
+        );
+        assert_eq!(
+            Some(articles[2].id()),
+            navigation.next.map(|article| article.id())
+        );
+
+        let navigation = sut
+            .exec(GetExpandedArticleParams {
+                article_slug: articles[0].slug(),
+                comments_per_page: None,
+                user_id: None,
+                user_role: None,
+                related_articles_amount: None,
+                locale: None,
+                comment_auto_close_days: None,
+            })
+            .await
+            .unwrap()
+            .series
+            .unwrap();
+
+        assert!(navigation.previous.is_none());
+        assert_eq!(1, navigation.position);
+    }
+
+    #[tokio::test]
+    async fn test_coauthors() {
+        let mut mocked_user_repo = MockUserRepositoryTrait::new();
+        let mut mock_comm_user_art_repo = MockCommentUserArticleRepositoryTrait::new();
+        let (articles_db, mocked_article_repository) = get_article_repository();
+        let (_, _, series_repository) = get_series_repository(articles_db.clone());
+        let users_db: Arc<Mutex<Vec<User>>> = Arc::new(Mutex::new(vec![]));
+        let (coauthors_db, article_coauthor_repository) =
+            get_article_coauthor_repository(users_db.clone(), articles_db.clone());
+        let (_, article_translation_repository) = get_article_translation_repository();
+
+        let author = User::new("Floricultor".into(), "123".into(), Some(Role::Writer));
+        let reviewer = User::new("Salem".into(), "123".into(), Some(Role::Writer));
+        let illustrator = User::new("Elffi".into(), "123".into(), Some(Role::Writer));
+
+        let article = Article::new(
+            author.id(),
+            "Notícia".into(),
+            "Conteúdo".into(),
+            "url".into(),
+            1,
+            "Foo".into(),
+        );
+
+        articles_db.lock().unwrap().push(article.clone());
+        users_db
+            .lock()
+            .unwrap()
+            .extend([reviewer.clone(), illustrator.clone()]);
+        coauthors_db.lock().unwrap().extend([
+            ArticleCoauthor::new(article.id(), illustrator.id(), "arte".into()),
+            ArticleCoauthor::new(article.id(), reviewer.id(), "revisão".into()),
+        ]);
+
+        mocked_user_repo
+            .expect_find_by_id()
+            .returning(move |_id| Ok(Some(author.clone())));
+
+        mock_comm_user_art_repo
+            .expect_find_many_comments()
+            .returning(|_, _, _| Ok(FindManyCommentsWithAuthorResponse(vec![], 0)));
+
+        let sut = GetExpandedArticleService::new(
+            mocked_user_repo,
+            mocked_article_repository,
+            mock_comm_user_art_repo,
+            series_repository,
+            article_coauthor_repository,
+            article_translation_repository,
+            InMemoryRelatedArticlesCache::new(),
+        );
+
+        // co-authors can see the unapproved article just like its author
+        let coauthors = sut
+            .exec(GetExpandedArticleParams {
+                article_slug: article.slug(),
+                comments_per_page: None,
+                user_id: Some(&reviewer.id()),
+                user_role: Some(&Role::Writer),
+                related_articles_amount: None,
+                locale: None,
+                comment_auto_close_days: None,
+            })
+            .await
+            .unwrap()
+            .coauthors;
+
+        assert_eq!(
+            vec![(illustrator.id(), "arte"), (reviewer.id(), "revisão")],
+            coauthors
+                .iter()
+                .map(|(coauthor, user)| (user.id(), coauthor.contribution()))
+                .collect::<Vec<_>>()
+        );
+
+        let outsider_id = Uuid::new_v4();
+        let result = sut
+            .exec(GetExpandedArticleParams {
+                article_slug: article.slug(),
+                comments_per_page: None,
+                user_id: Some(&outsider_id),
+                user_role: Some(&Role::Writer),
+                related_articles_amount: None,
+                locale: None,
+                comment_auto_close_days: None,
+            })
+            .await;
+
+        assert!(result.is_err());
+    }
+    #[tokio::test]
+    async fn test_translations() {
+        let mut mocked_user_repo = MockUserRepositoryTrait::new();
+        let mut mock_comm_user_art_repo = MockCommentUserArticleRepositoryTrait::new();
+        let (articles_db, mocked_article_repository) = get_article_repository();
+        let (_, _, series_repository) = get_series_repository(articles_db.clone());
+        let (_, article_coauthor_repository) =
+            get_article_coauthor_repository(Arc::new(Mutex::new(vec![])), articles_db.clone());
+        let (translations_db, article_translation_repository) =
+            get_article_translation_repository();
+
+        let author = User::new("Floricultor".into(), "123".into(), Some(Role::Writer));
+
+        let mut article = Article::new(
+            author.id(),
+            "Raros da semana".into(),
+            "Conteúdo".into(),
+            "url".into(),
+            1,
+            "Foo".into(),
+        );
+        article.set_approved(true);
+
+        let translation = ArticleTranslation::new(
+            article.id(),
+            Locale::Es,
+            "Raros de la semana".into(),
+            "Contenido".into(),
+        );
+
+        articles_db.lock().unwrap().push(article.clone());
+        translations_db.lock().unwrap().push(translation.clone());
+
+        mocked_user_repo
+            .expect_find_by_id()
+            .returning(move |_id| Ok(Some(author.clone())));
+
+        mock_comm_user_art_repo
+            .expect_find_many_comments()
+            .returning(|_, _, _| Ok(FindManyCommentsWithAuthorResponse(vec![], 0)));
+
+        let sut = GetExpandedArticleService::new(
+            mocked_user_repo,
+            mocked_article_repository,
+            mock_comm_user_art_repo,
+            series_repository,
+            article_coauthor_repository,
+            article_translation_repository,
+            InMemoryRelatedArticlesCache::new(),
+        );
+
+        let params = |article_slug: Slug, locale: Option<Locale>| GetExpandedArticleParams {
+            article_slug,
+            comments_per_page: None,
+            user_id: None,
+            user_role: None,
+            related_articles_amount: None,
+            locale,
+            comment_auto_close_days: None,
+        };
+
+        let response = sut
+            .exec(params(article.slug(), Some(Locale::Es)))
+            .await
+            .unwrap();
+
+        assert_eq!(Locale::Es, response.locale);
+        assert_eq!("Raros de la semana", response.article.title());
+        assert_eq!(translation.slug(), response.article.slug());
+        assert_eq!(
+            vec![
+                (Locale::PtBr, article.slug()),
+                (Locale::Es, translation.slug())
+            ],
+            response.alternates
+        );
+
+        // there's no English translation
+        let response = sut
+            .exec(params(article.slug(), Some(Locale::En)))
+            .await
+            .unwrap();
+
+        assert_eq!(Locale::PtBr, response.locale);
+        assert_eq!("Raros da semana", response.article.title());
+
+        // a translation's slug leads to the article in the translation's locale
+        let response = sut.exec(params(translation.slug(), None)).await.unwrap();
+
+        assert_eq!(article.id(), response.article.id());
+        assert_eq!(Locale::Es, response.locale);
+    }
 }
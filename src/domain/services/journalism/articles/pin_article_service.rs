@@ -0,0 +1,167 @@
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::error::DomainError;
+use crate::libs::time::TimeHelper;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub struct PinArticleParams {
+    pub user_role: Role,
+    pub article_id: Uuid,
+    pub pinned: bool,
+    pub pin_order: Option<i32>,
+    pub pinned_until: Option<DateTime>,
+    pub featured: Option<bool>,
+}
+
+pub struct PinArticleService<ArticleRepository: ArticleRepositoryTrait> {
+    article_repository: ArticleRepository,
+}
+
+impl<ArticleRepository: ArticleRepositoryTrait> PinArticleService<ArticleRepository> {
+    pub fn new(article_repository: ArticleRepository) -> Self {
+        PinArticleService { article_repository }
+    }
+
+    pub async fn exec(&self, params: PinArticleParams) -> Result<Article, DomainError> {
+        if !verify_role_has_permission(&params.user_role, RolePermissions::PinArticle) {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        let mut article = match self
+            .article_repository
+            .find_by_id(params.article_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Pin Article Service, while finding article by id",
+                    err,
+                )
+            })? {
+            None => return Err(DomainError::resource_not_found_err()),
+            Some(article) => article,
+        };
+
+        if params.pinned {
+            if !article.approved() {
+                return Err(DomainError::bad_request_err()
+                    .with_message("Only approved articles can be pinned."));
+            }
+
+            if params
+                .pinned_until
+                .is_some_and(|pinned_until| pinned_until <= TimeHelper::now())
+            {
+                return Err(DomainError::bad_request_err()
+                    .with_message("Pin expiration date must be in the future."));
+            }
+
+            article.pin(params.pin_order.unwrap_or(0), params.pinned_until);
+        } else {
+            article.unpin();
+        }
+
+        if let Some(featured) = params.featured {
+            article.set_featured(featured);
+        }
+
+        self.article_repository.save(article).await.map_err(|err| {
+            generate_service_internal_error(
+                "Error occurred on Pin Article Service, while saving the article on the database",
+                err,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tests::repositories::article_repository::get_article_repository;
+    use chrono::TimeDelta;
+
+    #[tokio::test]
+    async fn test() {
+        let (article_db, article_repository) = get_article_repository();
+
+        let mut article = Article::new(
+            Uuid::new_v4(),
+            "Título".into(),
+            "Conteúdo".into(),
+            "url".into(),
+            1,
+            "Foo".into(),
+        );
+
+        article_db.lock().unwrap().push(article.clone());
+
+        let service = PinArticleService::new(article_repository);
+
+        let writer_result = service
+            .exec(PinArticleParams {
+                user_role: Role::Writer,
+                article_id: article.id(),
+                pinned: true,
+                pin_order: None,
+                pinned_until: None,
+                featured: None,
+            })
+            .await;
+
+        assert!(matches!(writer_result, Err(DomainError::Unauthorized(_))));
+
+        let unapproved_result = service
+            .exec(PinArticleParams {
+                user_role: Role::Editor,
+                article_id: article.id(),
+                pinned: true,
+                pin_order: None,
+                pinned_until: None,
+                featured: None,
+            })
+            .await;
+
+        assert!(matches!(unapproved_result, Err(DomainError::BadRequest(_))));
+
+        article.set_approved(true);
+        article_db.lock().unwrap()[0] = article.clone();
+
+        let pinned_until = TimeHelper::now() + TimeDelta::days(2);
+
+        let result = service
+            .exec(PinArticleParams {
+                user_role: Role::Editor,
+                article_id: article.id(),
+                pinned: true,
+                pin_order: Some(1),
+                pinned_until: Some(pinned_until),
+                featured: Some(true),
+            })
+            .await
+            .unwrap();
+
+        assert!(result.pinned());
+        assert!(result.featured());
+        assert_eq!(1, result.pin_order());
+        assert_eq!(Some(pinned_until), result.pinned_until());
+
+        let result = service
+            .exec(PinArticleParams {
+                user_role: Role::Editor,
+                article_id: article.id(),
+                pinned: false,
+                pin_order: None,
+                pinned_until: None,
+                featured: None,
+            })
+            .await
+            .unwrap();
+
+        assert!(!result.pinned());
+        assert!(result.featured());
+        assert_eq!(None, result.pinned_until());
+    }
+}
@@ -0,0 +1,68 @@
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_cover::ArticleCoverUrls;
+use crate::domain::storage::file_storage::FileStorageTrait;
+
+/// Tells the URLs of every variant of the articles' covers, which only the storage they were uploaded to knows.
+pub struct ResolveArticleCoversService<FS: FileStorageTrait> {
+    file_storage: FS,
+}
+
+impl<FS: FileStorageTrait> ResolveArticleCoversService<FS> {
+    pub fn new(file_storage: FS) -> Self {
+        ResolveArticleCoversService { file_storage }
+    }
+
+    pub fn exec(&self, cover_url: &str) -> ArticleCoverUrls {
+        ArticleCoverUrls::from_cover_url(cover_url, &self.file_storage)
+    }
+
+    /// Pairs each of the articles with its cover's URLs.
+    pub fn exec_on_articles(&self, articles: Vec<Article>) -> Vec<(Article, ArticleCoverUrls)> {
+        articles
+            .into_iter()
+            .map(|article| {
+                let cover = self.exec(article.cover_url());
+                (article, cover)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::domain::domain_entities::article_cover::{ArticleCoverKeys, ArticleCoverThumbnail};
+    use crate::tests::file_storage::{get_file_storage, FAKE_STORAGE_URL};
+
+    #[test]
+    fn test() {
+        let (_, file_storage) = get_file_storage();
+        let service = ResolveArticleCoversService::new(file_storage);
+
+        let keys = ArticleCoverKeys::new("png");
+        let cover = service.exec(&format!("{FAKE_STORAGE_URL}/{}", keys.original()));
+
+        assert_eq!(
+            format!(
+                "{FAKE_STORAGE_URL}/{}",
+                keys.thumbnail(ArticleCoverThumbnail::Card)
+            ),
+            cover.card
+        );
+        assert_eq!(
+            format!(
+                "{FAKE_STORAGE_URL}/{}",
+                keys.thumbnail(ArticleCoverThumbnail::Hero)
+            ),
+            cover.hero
+        );
+
+        // covers hosted elsewhere have no thumbnails
+        let cover = service.exec("https://example.com/cover.png");
+
+        assert_eq!("https://example.com/cover.png", cover.original);
+        assert_eq!("https://example.com/cover.png", cover.card);
+        assert_eq!("https://example.com/cover.png", cover.hero);
+    }
+}
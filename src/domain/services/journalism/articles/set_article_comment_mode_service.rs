@@ -0,0 +1,100 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article::{Article, CommentMode};
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::error::DomainError;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub struct SetArticleCommentModeParams {
+    pub user_role: Role,
+    pub article_id: Uuid,
+    pub comment_mode: CommentMode,
+}
+
+pub struct SetArticleCommentModeService<ArticleRepository: ArticleRepositoryTrait> {
+    article_repository: ArticleRepository,
+}
+
+impl<ArticleRepository: ArticleRepositoryTrait> SetArticleCommentModeService<ArticleRepository> {
+    pub fn new(article_repository: ArticleRepository) -> Self {
+        SetArticleCommentModeService { article_repository }
+    }
+
+    pub async fn exec(&self, params: SetArticleCommentModeParams) -> Result<Article, DomainError> {
+        if !verify_role_has_permission(&params.user_role, RolePermissions::ChangeArticleCommentMode)
+        {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        let mut article = self
+            .article_repository
+            .find_by_id(params.article_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Set Article Comment Mode Service, while finding article by id",
+                    err,
+                )
+            })?
+            .ok_or_else(DomainError::resource_not_found_err)?;
+
+        article.set_comment_mode(params.comment_mode);
+
+        self.article_repository.save(article).await.map_err(|err| {
+            generate_service_internal_error(
+                "Error occurred on Set Article Comment Mode Service, while saving the article on the database",
+                err,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tests::repositories::article_repository::get_article_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (article_db, article_repository) = get_article_repository();
+
+        let article = Article::new(
+            Uuid::new_v4(),
+            "Título".into(),
+            "Conteúdo".into(),
+            "url".into(),
+            1,
+            "Foo".into(),
+        );
+
+        article_db.lock().unwrap().push(article.clone());
+
+        let service = SetArticleCommentModeService::new(article_repository);
+
+        let writer_result = service
+            .exec(SetArticleCommentModeParams {
+                user_role: Role::Writer,
+                article_id: article.id(),
+                comment_mode: CommentMode::Closed,
+            })
+            .await;
+
+        assert!(matches!(writer_result, Err(DomainError::Unauthorized(_))));
+
+        let result = service
+            .exec(SetArticleCommentModeParams {
+                user_role: Role::Editor,
+                article_id: article.id(),
+                comment_mode: CommentMode::StaffOnly,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(CommentMode::StaffOnly, result.comment_mode());
+        assert_eq!(
+            CommentMode::StaffOnly,
+            article_db.lock().unwrap()[0].comment_mode()
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_translation::ArticleTranslation;
+use crate::domain::domain_entities::locale::Locale;
+use crate::domain::repositories::article_translation_repository::ArticleTranslationRepositoryTrait;
+use crate::error::DomainError;
+use crate::util::generate_service_internal_error;
+
+pub struct TranslateArticlesParams {
+    pub articles: Vec<Article>,
+    /// The fallback locale is used if none is given.
+    pub locale: Option<Locale>,
+}
+
+#[derive(Debug)]
+pub struct TranslatedArticle {
+    /// Has the translated title, content and slug, if there's any translation to the requested locale.
+    pub article: Article,
+    /// The locale the article is actually in.
+    pub locale: Locale,
+    pub missing_locales: Vec<Locale>,
+}
+
+pub struct TranslateArticlesService<ArticleTranslationRepository: ArticleTranslationRepositoryTrait>
+{
+    article_translation_repository: ArticleTranslationRepository,
+}
+
+impl<ArticleTranslationRepository: ArticleTranslationRepositoryTrait>
+    TranslateArticlesService<ArticleTranslationRepository>
+{
+    pub fn new(article_translation_repository: ArticleTranslationRepository) -> Self {
+        TranslateArticlesService {
+            article_translation_repository,
+        }
+    }
+
+    pub async fn exec(
+        &self,
+        params: TranslateArticlesParams,
+    ) -> Result<Vec<TranslatedArticle>, DomainError> {
+        let mut translations: HashMap<Uuid, Vec<ArticleTranslation>> = HashMap::new();
+
+        for translation in self
+            .article_translation_repository
+            .find_many_by_article_ids(params.articles.iter().map(Article::id).collect())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Translate Articles Service, while finding the articles' translations",
+                    err,
+                )
+            })?
+        {
+            translations
+                .entry(translation.article_id())
+                .or_default()
+                .push(translation);
+        }
+
+        let articles = params
+            .articles
+            .into_iter()
+            .map(|article| {
+                let translations = translations.remove(&article.id()).unwrap_or_default();
+                translate_article(article, &translations, params.locale)
+            })
+            .collect();
+
+        Ok(articles)
+    }
+}
+
+/// Translates the article with one of its translations, if any of them is to the requested locale.
+pub fn translate_article(
+    mut article: Article,
+    translations: &[ArticleTranslation],
+    locale: Option<Locale>,
+) -> TranslatedArticle {
+    let translation = locale.and_then(|locale| {
+        translations
+            .iter()
+            .find(|translation| translation.locale() == locale)
+    });
+
+    if let Some(translation) = translation {
+        article.translate(translation);
+    }
+
+    let missing_locales = Locale::ALL
+        .into_iter()
+        .filter(|locale| *locale != Locale::FALLBACK)
+        .filter(|locale| {
+            !translations
+                .iter()
+                .any(|translation| translation.locale() == *locale)
+        })
+        .collect();
+
+    TranslatedArticle {
+        article,
+        locale: translation.map_or(Locale::FALLBACK, ArticleTranslation::locale),
+        missing_locales,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tests::repositories::article_translation_repository::get_article_translation_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (translation_db, article_translation_repository) = get_article_translation_repository();
+
+        let article = |title: &str| {
+            Article::new(
+                Uuid::new_v4(),
+                title.into(),
+                "Conteúdo".into(),
+                "url".into(),
+                1,
+                "Foo".into(),
+            )
+        };
+
+        let translated_article = article("Evento de verão");
+        let untranslated_article = article("Evento de inverno");
+
+        translation_db.lock().unwrap().push(ArticleTranslation::new(
+            translated_article.id(),
+            Locale::Es,
+            "Evento de verano".into(),
+            "Contenido".into(),
+        ));
+
+        let service = TranslateArticlesService::new(article_translation_repository);
+
+        let articles = service
+            .exec(TranslateArticlesParams {
+                articles: vec![translated_article.clone(), untranslated_article.clone()],
+                locale: Some(Locale::Es),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(Locale::Es, articles[0].locale);
+        assert_eq!("Evento de verano", articles[0].article.title());
+        assert_eq!("Contenido", articles[0].article.content());
+        assert_eq!(vec![Locale::En], articles[0].missing_locales);
+
+        assert_eq!(Locale::FALLBACK, articles[1].locale);
+        assert_eq!("Evento de inverno", articles[1].article.title());
+        assert_eq!(vec![Locale::Es, Locale::En], articles[1].missing_locales);
+
+        let articles = service
+            .exec(TranslateArticlesParams {
+                articles: vec![translated_article.clone()],
+                locale: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(translated_article, articles[0].article);
+    }
+}
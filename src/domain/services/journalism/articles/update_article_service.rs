@@ -1,10 +1,16 @@
+use std::collections::HashSet;
+
 use uuid::Uuid;
 
+use crate::domain::cache::related_articles_cache::RelatedArticlesCacheTrait;
 use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_coauthor::ArticleCoauthor;
 use crate::domain::domain_entities::article_tag::ArticleTag;
 use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::article_coauthor_repository::ArticleCoauthorRepositoryTrait;
 use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
 use crate::domain::repositories::article_tag_repository::ArticleTagRepositoryTrait;
+use crate::domain::repositories::user_repository::UserRepositoryTrait;
 use crate::error::DomainError;
 use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
 
@@ -18,27 +24,57 @@ pub struct UpdateArticleParams {
     pub approved: Option<bool>,
     pub author_id: Option<Uuid>,
     pub tag_id: Option<i32>,
+    /// Replaces the article's co-authors, which are credited in the given order.
+    pub coauthors: Option<Vec<UpdateArticleCoauthorParams>>,
 }
+
+pub struct UpdateArticleCoauthorParams {
+    pub user_id: Uuid,
+    pub contribution: String,
+}
+
 pub struct UpdateArticleService<
     ArticleRepository: ArticleRepositoryTrait,
     ArticleTagRepository: ArticleTagRepositoryTrait,
+    ArticleCoauthorRepository: ArticleCoauthorRepositoryTrait,
+    UserRepository: UserRepositoryTrait,
+    RelatedArticlesCache: RelatedArticlesCacheTrait,
 > {
     article_repository: ArticleRepository,
     article_tag_repository: ArticleTagRepository,
+    article_coauthor_repository: ArticleCoauthorRepository,
+    user_repository: UserRepository,
+    related_articles_cache: RelatedArticlesCache,
 }
 
 impl<
         ArticleRepository: ArticleRepositoryTrait,
         ArticleTagRepository: ArticleTagRepositoryTrait,
-    > UpdateArticleService<ArticleRepository, ArticleTagRepository>
+        ArticleCoauthorRepository: ArticleCoauthorRepositoryTrait,
+        UserRepository: UserRepositoryTrait,
+        RelatedArticlesCache: RelatedArticlesCacheTrait,
+    >
+    UpdateArticleService<
+        ArticleRepository,
+        ArticleTagRepository,
+        ArticleCoauthorRepository,
+        UserRepository,
+        RelatedArticlesCache,
+    >
 {
     pub fn new(
         article_repository: ArticleRepository,
         article_tag_repository: ArticleTagRepository,
+        article_coauthor_repository: ArticleCoauthorRepository,
+        user_repository: UserRepository,
+        related_articles_cache: RelatedArticlesCache,
     ) -> Self {
         UpdateArticleService {
             article_repository,
             article_tag_repository,
+            article_coauthor_repository,
+            user_repository,
+            related_articles_cache,
         }
     }
 
@@ -48,6 +84,7 @@ impl<
             && params.title.is_none()
             && params.cover_url.is_none()
             && params.approved.is_none()
+            && params.coauthors.is_none()
         {
             return Err(DomainError::bad_request_err());
         }
@@ -89,12 +126,27 @@ impl<
 
         let user_is_author = article.author_id() == params.user_id;
 
-        if !user_can_update && !user_is_author {
+        // co-authors share the author's edit rights
+        let user_is_coauthor = !user_is_author
+            && self
+                .article_coauthor_repository
+                .is_coauthor(article.id(), params.user_id)
+                .await
+                .map_err(|err| {
+                    generate_service_internal_error(
+                        "Error occurred on Update Article Service, while checking if user is a co-author",
+                        err,
+                    )
+                })?;
+
+        let user_is_credited = user_is_author || user_is_coauthor;
+
+        if !user_can_update && !user_is_credited {
             return Err(DomainError::unauthorized_err());
         }
 
         // if user is author but does no longer belong to the team, he can't delete his own article either.
-        if user_is_author && params.user_role == Role::User {
+        if user_is_credited && params.user_role == Role::User {
             return Err(DomainError::unauthorized_err());
         }
 
@@ -105,6 +157,11 @@ impl<
             return Err(DomainError::unauthorized_err());
         }
 
+        // co-authors can edit the article, but only its author chooses who is credited on it
+        if !user_can_change_article_author && !user_is_author && params.coauthors.is_some() {
+            return Err(DomainError::unauthorized_err());
+        }
+
         // modifies the article where requested
         if params.author_id.is_some() {
             article.set_author_id(params.author_id.unwrap())
@@ -137,7 +194,23 @@ impl<
             article.set_tag_value(tag.value().to_owned());
         }
 
-        let response = self.article_repository.save(article).await;
+        let coauthors = match params.coauthors {
+            Some(coauthors) => Some(self.validate_coauthors(&article, coauthors).await?),
+            // a co-author promoted to author is no longer credited as co-author
+            None if params.author_id.is_some() => {
+                self.get_coauthors_without_author(&article).await?
+            }
+            None => None,
+        };
+
+        let response = match coauthors {
+            Some(coauthors) => {
+                self.article_coauthor_repository
+                    .save_article_with_coauthors(article, coauthors)
+                    .await
+            }
+            None => self.article_repository.save(article).await,
+        };
 
         if let Err(err) = response {
             return Err(generate_service_internal_error(
@@ -147,9 +220,95 @@ impl<
         }
         let article = response.unwrap();
 
+        self.related_articles_cache.invalidate();
+
         Ok(article)
     }
 
+    async fn validate_coauthors(
+        &self,
+        article: &Article,
+        coauthors: Vec<UpdateArticleCoauthorParams>,
+    ) -> Result<Vec<ArticleCoauthor>, DomainError> {
+        let mut seen_users = HashSet::new();
+
+        for coauthor in coauthors.iter() {
+            if coauthor.user_id == article.author_id() {
+                return Err(DomainError::bad_request_err()
+                    .with_message("The article's author cannot be one of its co-authors."));
+            }
+
+            if !seen_users.insert(coauthor.user_id) {
+                return Err(DomainError::bad_request_err().with_message(format!(
+                    "User with id '{}' was credited more than once.",
+                    coauthor.user_id
+                )));
+            }
+
+            if coauthor.contribution.trim().is_empty() {
+                return Err(DomainError::bad_request_err()
+                    .with_message("Every co-author must have a contribution label."));
+            }
+
+            let user = self
+                .user_repository
+                .find_by_id(&coauthor.user_id)
+                .await
+                .map_err(|err| {
+                    generate_service_internal_error(
+                        "Error occurred in Update Article Service, while finding co-author by id",
+                        err,
+                    )
+                })?;
+
+            if user.is_none() {
+                return Err(DomainError::bad_request_err()
+                    .with_message(format!("User with id '{}' not found.", coauthor.user_id)));
+            }
+        }
+
+        Ok(coauthors
+            .into_iter()
+            .map(|coauthor| {
+                ArticleCoauthor::new(
+                    article.id(),
+                    coauthor.user_id,
+                    coauthor.contribution.trim().to_owned(),
+                )
+            })
+            .collect())
+    }
+
+    async fn get_coauthors_without_author(
+        &self,
+        article: &Article,
+    ) -> Result<Option<Vec<ArticleCoauthor>>, DomainError> {
+        let coauthors = self
+            .article_coauthor_repository
+            .find_by_article_id(article.id())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred in Update Article Service, while finding the article's co-authors",
+                    err,
+                )
+            })?;
+
+        if !coauthors
+            .iter()
+            .any(|coauthor| coauthor.user_id() == article.author_id())
+        {
+            return Ok(None);
+        }
+
+        Ok(Some(
+            coauthors
+                .into_iter()
+                .filter(|coauthor| coauthor.user_id() != article.author_id())
+                .collect(),
+        ))
+    }
+
     async fn get_tag_by_id(&self, tag_id: i32) -> Result<ArticleTag, DomainError> {
         let tag = self
             .article_tag_repository
@@ -173,11 +332,15 @@ impl<
 
 #[cfg(test)]
 mod test {
-    use super::{Article, UpdateArticleParams};
+    use super::{Article, UpdateArticleCoauthorParams, UpdateArticleParams};
     use crate::domain::domain_entities::article_tag::ArticleTag;
     use crate::domain::domain_entities::role::Role;
+    use crate::domain::domain_entities::user::User;
+    use crate::infra::cache::in_memory_related_articles_cache::InMemoryRelatedArticlesCache;
+    use crate::tests::repositories::article_coauthor_repository::get_article_coauthor_repository;
     use crate::tests::repositories::article_repository::get_article_repository;
     use crate::tests::repositories::article_tag_repository::get_article_tag_repository;
+    use crate::tests::repositories::users_repository::get_user_repository;
     use http::StatusCode;
     use uuid::Uuid;
 
@@ -185,6 +348,9 @@ mod test {
     async fn test() {
         let (article_db, article_repository) = get_article_repository();
         let (tag_db, article_tag_repository) = get_article_tag_repository();
+        let (user_db, user_repository) = get_user_repository();
+        let (_, article_coauthor_repository) =
+            get_article_coauthor_repository(user_db, article_db.clone());
 
         let article = Article::new(
             Uuid::new_v4(),
@@ -203,6 +369,9 @@ mod test {
         let service = super::UpdateArticleService {
             article_repository,
             article_tag_repository,
+            article_coauthor_repository,
+            user_repository,
+            related_articles_cache: InMemoryRelatedArticlesCache::new(),
         };
 
         let result = service
@@ -216,6 +385,7 @@ mod test {
                 cover_url: None,
                 author_id: None,
                 tag_id: None,
+                coauthors: None,
             })
             .await;
 
@@ -232,6 +402,7 @@ mod test {
                 cover_url: None,
                 author_id: None,
                 tag_id: Some(2),
+                coauthors: None,
             })
             .await;
 
@@ -240,4 +411,98 @@ mod test {
         assert_eq!("Título atualizado", result.title());
         assert_eq!("Bar".to_string(), result.tag_value().unwrap());
     }
+
+    #[tokio::test]
+    async fn test_coauthors() {
+        let (article_db, article_repository) = get_article_repository();
+        let (_, article_tag_repository) = get_article_tag_repository();
+        let (user_db, user_repository) = get_user_repository();
+        let (coauthor_db, article_coauthor_repository) =
+            get_article_coauthor_repository(user_db.clone(), article_db.clone());
+
+        let author = User::new("Author".into(), "password".into(), Some(Role::Writer));
+        let illustrator = User::new("Illustrator".into(), "password".into(), Some(Role::Writer));
+        let outsider = User::new("Outsider".into(), "password".into(), Some(Role::Writer));
+
+        let article = Article::new(
+            author.id(),
+            "Título inicial".to_string(),
+            "Conteúdo inicial".to_string(),
+            "coverurl.inicial".to_string(),
+            1,
+            "Foo".to_string(),
+        );
+
+        article_db.lock().unwrap().push(article.clone());
+        user_db
+            .lock()
+            .unwrap()
+            .extend([author.clone(), illustrator.clone(), outsider.clone()]);
+
+        let service = super::UpdateArticleService {
+            article_repository,
+            article_tag_repository,
+            article_coauthor_repository,
+            user_repository,
+            related_articles_cache: InMemoryRelatedArticlesCache::new(),
+        };
+
+        let title_update = |user_id| UpdateArticleParams {
+            user_id,
+            user_role: Role::Writer,
+            article_id: article.id(),
+            approved: None,
+            title: Some("Título atualizado".to_string()),
+            content: None,
+            cover_url: None,
+            author_id: None,
+            tag_id: None,
+            coauthors: None,
+        };
+
+        let result = service.exec(title_update(illustrator.id())).await;
+        assert_eq!(result.unwrap_err().get_code(), StatusCode::UNAUTHORIZED);
+
+        // the author can't credit himself as co-author
+        let result = service
+            .exec(UpdateArticleParams {
+                coauthors: Some(vec![UpdateArticleCoauthorParams {
+                    user_id: author.id(),
+                    contribution: "texto".into(),
+                }]),
+                ..title_update(author.id())
+            })
+            .await;
+        assert_eq!(result.unwrap_err().get_code(), StatusCode::BAD_REQUEST);
+
+        let result = service
+            .exec(UpdateArticleParams {
+                coauthors: Some(vec![UpdateArticleCoauthorParams {
+                    user_id: illustrator.id(),
+                    contribution: "arte".into(),
+                }]),
+                ..title_update(author.id())
+            })
+            .await;
+        assert!(result.is_ok());
+        assert_eq!(1, coauthor_db.lock().unwrap().len());
+        assert_eq!("arte", coauthor_db.lock().unwrap()[0].contribution());
+        assert_eq!("Título atualizado", article_db.lock().unwrap()[0].title());
+
+        // now the illustrator shares the author's edit rights...
+        let result = service.exec(title_update(illustrator.id())).await;
+        assert!(result.is_ok());
+
+        // ...but can't choose who is credited on the article
+        let result = service
+            .exec(UpdateArticleParams {
+                coauthors: Some(vec![]),
+                ..title_update(illustrator.id())
+            })
+            .await;
+        assert_eq!(result.unwrap_err().get_code(), StatusCode::UNAUTHORIZED);
+
+        let result = service.exec(title_update(outsider.id())).await;
+        assert_eq!(result.unwrap_err().get_code(), StatusCode::UNAUTHORIZED);
+    }
 }
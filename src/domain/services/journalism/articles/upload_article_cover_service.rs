@@ -0,0 +1,195 @@
+use std::error::Error;
+
+use crate::domain::domain_entities::article_cover::{
+    ArticleCoverKeys, ArticleCoverThumbnail, ArticleCoverUrls,
+};
+use crate::domain::domain_entities::role::Role;
+use crate::domain::politics::image_upload_politics::ImageUploadPolitics;
+use crate::domain::storage::file_storage::FileStorageTrait;
+use crate::error::DomainError;
+use crate::libs::image::ImageHelper;
+use crate::util::generate_service_internal_error;
+use crate::util::{verify_role_has_permission, RolePermissions};
+
+pub struct UploadArticleCoverParams {
+    pub user_role: Role,
+    pub content: Vec<u8>,
+    /// The MIME type declared by the client, if any.
+    pub content_type: Option<String>,
+}
+
+pub struct UploadArticleCoverService<FS: FileStorageTrait> {
+    file_storage: FS,
+}
+
+impl<FS: FileStorageTrait> UploadArticleCoverService<FS> {
+    pub fn new(file_storage: FS) -> Self {
+        UploadArticleCoverService { file_storage }
+    }
+
+    pub async fn exec(
+        &self,
+        params: UploadArticleCoverParams,
+    ) -> Result<ArticleCoverUrls, DomainError> {
+        if !verify_role_has_permission(&params.user_role, RolePermissions::CreateArticle) {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        let (mime_type, extension) = ImageUploadPolitics::validate(
+            &params.content,
+            params.content_type.as_deref(),
+            "Cover",
+        )?;
+
+        // decoding and resizing are CPU-bound, so they must not block the async runtime
+        let content = params.content;
+        let (content, thumbnails) = tokio::task::spawn_blocking(move || {
+            let dimensions = ArticleCoverThumbnail::ALL.map(|thumbnail| thumbnail.dimensions());
+            let thumbnails = ImageHelper::thumbnails(&content, &dimensions);
+
+            (content, thumbnails)
+        })
+        .await
+        .map_err(|err| {
+            generate_service_internal_error(
+                "Error occurred on Upload Article Cover Service, while generating the thumbnails",
+                Box::new(err),
+            )
+        })?;
+
+        let thumbnails = thumbnails.map_err(|_| {
+            DomainError::bad_request_err().with_message("Cover could not be read as an image.")
+        })?;
+
+        let keys = ArticleCoverKeys::new(extension);
+
+        let mut files = vec![(keys.original(), content, mime_type)];
+        files.extend(
+            ArticleCoverThumbnail::ALL
+                .into_iter()
+                .zip(thumbnails)
+                .map(|(thumbnail, content)| (keys.thumbnail(thumbnail), content, "image/jpeg")),
+        );
+
+        if let Err(err) = self.store_all(files).await {
+            return Err(generate_service_internal_error(
+                "Error occurred on Upload Article Cover Service, while storing the cover",
+                err,
+            ));
+        }
+
+        Ok(ArticleCoverUrls::from_keys(&keys, &self.file_storage))
+    }
+
+    /// Either every file gets stored or none of them.
+    async fn store_all(
+        &self,
+        files: Vec<(String, Vec<u8>, &'static str)>,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut stored_keys: Vec<String> = vec![];
+
+        for (key, content, content_type) in files {
+            if let Err(err) = self.file_storage.put(&key, content, content_type).await {
+                for stored_key in stored_keys.iter() {
+                    let _ = self.file_storage.delete(stored_key).await;
+                }
+
+                return Err(err);
+            }
+
+            stored_keys.push(key);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::io::Cursor;
+
+    use image::{ImageFormat, RgbImage};
+
+    use crate::tests::file_storage::{get_file_storage, FAKE_STORAGE_URL};
+
+    #[tokio::test]
+    async fn test() {
+        let (files, file_storage) = get_file_storage();
+
+        let mut png = Cursor::new(Vec::new());
+        RgbImage::new(640, 360)
+            .write_to(&mut png, ImageFormat::Png)
+            .unwrap();
+        let png = png.into_inner();
+
+        let sut = UploadArticleCoverService::new(file_storage);
+
+        let unauthorized_result = sut
+            .exec(UploadArticleCoverParams {
+                user_role: Role::User,
+                content: png.clone(),
+                content_type: Some("image/png".into()),
+            })
+            .await;
+
+        assert!(matches!(
+            unauthorized_result,
+            Err(DomainError::Unauthorized(_))
+        ));
+
+        let mismatching_type_result = sut
+            .exec(UploadArticleCoverParams {
+                user_role: Role::Writer,
+                content: png.clone(),
+                content_type: Some("image/jpeg".into()),
+            })
+            .await;
+
+        assert!(matches!(
+            mismatching_type_result,
+            Err(DomainError::BadRequest(_))
+        ));
+
+        let not_an_image_result = sut
+            .exec(UploadArticleCoverParams {
+                user_role: Role::Writer,
+                content: b"<svg></svg>".to_vec(),
+                content_type: None,
+            })
+            .await;
+
+        assert!(matches!(
+            not_an_image_result,
+            Err(DomainError::BadRequest(_))
+        ));
+
+        assert!(files.lock().unwrap().is_empty());
+
+        let urls = sut
+            .exec(UploadArticleCoverParams {
+                user_role: Role::Writer,
+                content: png.clone(),
+                content_type: Some("image/png".into()),
+            })
+            .await
+            .unwrap();
+
+        assert!(urls.original.ends_with(".png"));
+        assert!(urls.card.ends_with("-card.jpg"));
+        assert!(urls.hero.ends_with("-hero.jpg"));
+
+        let files = files.lock().unwrap();
+        assert_eq!(3, files.len());
+
+        let card_key = urls
+            .card
+            .trim_start_matches(&format!("{FAKE_STORAGE_URL}/"));
+        let card = image::load_from_memory(&files[card_key]).unwrap();
+        assert_eq!(
+            ArticleCoverThumbnail::Card.dimensions(),
+            (card.width(), card.height())
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_translation::ArticleTranslation;
+use crate::domain::domain_entities::locale::Locale;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::article_coauthor_repository::ArticleCoauthorRepositoryTrait;
+use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::domain::repositories::article_translation_repository::ArticleTranslationRepositoryTrait;
+use crate::error::DomainError;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub struct UpsertArticleTranslationParams {
+    pub user_id: Uuid,
+    pub user_role: Role,
+    pub article_id: Uuid,
+    pub locale: Locale,
+    pub title: String,
+    pub content: String,
+}
+
+pub struct UpsertArticleTranslationService<
+    ArticleRepository: ArticleRepositoryTrait,
+    ArticleTranslationRepository: ArticleTranslationRepositoryTrait,
+    ArticleCoauthorRepository: ArticleCoauthorRepositoryTrait,
+> {
+    article_repository: ArticleRepository,
+    article_translation_repository: ArticleTranslationRepository,
+    article_coauthor_repository: ArticleCoauthorRepository,
+}
+
+impl<
+        ArticleRepository: ArticleRepositoryTrait,
+        ArticleTranslationRepository: ArticleTranslationRepositoryTrait,
+        ArticleCoauthorRepository: ArticleCoauthorRepositoryTrait,
+    >
+    UpsertArticleTranslationService<
+        ArticleRepository,
+        ArticleTranslationRepository,
+        ArticleCoauthorRepository,
+    >
+{
+    pub fn new(
+        article_repository: ArticleRepository,
+        article_translation_repository: ArticleTranslationRepository,
+        article_coauthor_repository: ArticleCoauthorRepository,
+    ) -> Self {
+        UpsertArticleTranslationService {
+            article_repository,
+            article_translation_repository,
+            article_coauthor_repository,
+        }
+    }
+
+    pub async fn exec(
+        &self,
+        params: UpsertArticleTranslationParams,
+    ) -> Result<ArticleTranslation, DomainError> {
+        let article = find_translatable_article(
+            &self.article_repository,
+            &self.article_coauthor_repository,
+            params.article_id,
+            params.locale,
+            (params.user_id, &params.user_role),
+        )
+        .await?;
+
+        let translation = self
+            .article_translation_repository
+            .find_by_article_id_and_locale(article.id(), params.locale)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Upsert Article Translation Service, while finding the article's translation",
+                    err,
+                )
+            })?;
+
+        let translation = match translation {
+            None => {
+                ArticleTranslation::new(article.id(), params.locale, params.title, params.content)
+            }
+            Some(mut translation) => {
+                if translation.title() != params.title {
+                    translation.set_title(params.title);
+                }
+
+                if translation.content() != params.content {
+                    translation.set_content(params.content);
+                }
+
+                translation
+            }
+        };
+
+        self.article_translation_repository
+            .save(translation)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Upsert Article Translation Service, while saving the translation",
+                    err,
+                )
+            })
+    }
+}
+
+/// Finds the article whose translation to the locale is about to change, making sure the user is allowed to do so.
+/// Those who can edit the article can translate it.
+pub async fn find_translatable_article(
+    article_repository: &impl ArticleRepositoryTrait,
+    article_coauthor_repository: &impl ArticleCoauthorRepositoryTrait,
+    article_id: Uuid,
+    locale: Locale,
+    (user_id, user_role): (Uuid, &Role),
+) -> Result<Article, DomainError> {
+    if locale == Locale::FALLBACK {
+        return Err(DomainError::bad_request_err().with_message(format!(
+            "Articles are written in {}, that's not a translation.",
+            Locale::FALLBACK
+        )));
+    }
+
+    let article = article_repository
+        .find_by_id(article_id)
+        .await
+        .map_err(|err| {
+            generate_service_internal_error(
+                "Error occurred while finding the article to be translated",
+                err,
+            )
+        })?
+        .ok_or_else(DomainError::resource_not_found_err)?;
+
+    if verify_role_has_permission(user_role, RolePermissions::UpdateArticle) {
+        return Ok(article);
+    }
+
+    if *user_role == Role::User {
+        return Err(DomainError::unauthorized_err());
+    }
+
+    let user_is_credited = article.author_id() == user_id
+        || article_coauthor_repository
+            .is_coauthor(article.id(), user_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred while checking if user is a co-author of the article to be translated",
+                    err,
+                )
+            })?;
+
+    match user_is_credited {
+        true => Ok(article),
+        false => Err(DomainError::unauthorized_err()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::domain::domain_entities::article_coauthor::ArticleCoauthor;
+    use crate::tests::repositories::article_coauthor_repository::get_article_coauthor_repository;
+    use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::article_translation_repository::get_article_translation_repository;
+    use std::sync::{Arc, Mutex};
+
+    #[tokio::test]
+    async fn test() {
+        let (article_db, article_repository) = get_article_repository();
+        let (translation_db, article_translation_repository) = get_article_translation_repository();
+        let (coauthor_db, article_coauthor_repository) =
+            get_article_coauthor_repository(Arc::new(Mutex::new(vec![])), article_db.clone());
+
+        let author_id = Uuid::new_v4();
+        let coauthor_id = Uuid::new_v4();
+
+        let article = Article::new(
+            author_id,
+            "Novo mobi raro".into(),
+            "Conteúdo".into(),
+            "url".into(),
+            1,
+            "Foo".into(),
+        );
+
+        article_db.lock().unwrap().push(article.clone());
+        coauthor_db.lock().unwrap().push(ArticleCoauthor::new(
+            article.id(),
+            coauthor_id,
+            "tradução".into(),
+        ));
+
+        let service = UpsertArticleTranslationService::new(
+            article_repository,
+            article_translation_repository,
+            article_coauthor_repository,
+        );
+
+        let params = |user_id: Uuid, user_role: Role, locale: Locale, title: &str| {
+            UpsertArticleTranslationParams {
+                user_id,
+                user_role,
+                article_id: article.id(),
+                locale,
+                title: title.into(),
+                content: "Contenido".into(),
+            }
+        };
+
+        let fallback_locale_result = service
+            .exec(params(author_id, Role::Writer, Locale::PtBr, "Novo"))
+            .await;
+
+        assert!(matches!(
+            fallback_locale_result,
+            Err(DomainError::BadRequest(_))
+        ));
+
+        let stranger_result = service
+            .exec(params(Uuid::new_v4(), Role::Writer, Locale::Es, "Nuevo"))
+            .await;
+
+        assert!(matches!(stranger_result, Err(DomainError::Unauthorized(_))));
+
+        let translation = service
+            .exec(params(
+                coauthor_id,
+                Role::Writer,
+                Locale::Es,
+                "Nuevo mueble raro",
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(Locale::Es, translation.locale());
+        assert!(translation
+            .slug()
+            .to_string()
+            .ends_with("nuevo-mueble-raro"));
+
+        let translation = service
+            .exec(params(
+                Uuid::new_v4(),
+                Role::Editor,
+                Locale::Es,
+                "Mueble raro",
+            ))
+            .await
+            .unwrap();
+
+        assert!(translation.updated_at().is_some());
+        assert_eq!(1, translation_db.lock().unwrap().len());
+        assert_eq!("Mueble raro", translation_db.lock().unwrap()[0].title());
+    }
+}
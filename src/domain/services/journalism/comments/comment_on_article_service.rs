@@ -1,59 +1,449 @@
-use log::error;
+use std::collections::HashMap;
+
 use uuid::Uuid;
 
-use crate::domain::domain_entities::comment::Comment;
+use crate::domain::domain_entities::article::CommentMode;
+use crate::domain::domain_entities::blocked_word::BlockedWord;
+use crate::domain::domain_entities::comment::{Comment, MAX_REPLY_DEPTH};
+use crate::domain::domain_entities::comment_mention::{CommentMention, MentionCandidate};
+use crate::domain::domain_entities::comment_report::{DraftCommentReport, ReportReason};
+use crate::domain::domain_entities::comment_review::CommentReview;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::politics::article_politics::ArticlePolitics;
+use crate::domain::politics::comment_flood_politics::{CommentFloodPolicy, CommentFloodPolitics};
+use crate::domain::politics::comment_review_politics::{
+    CommentReviewPolitics, PreModerationPolicy,
+};
+use crate::domain::politics::word_filter_politics::WordFilterPolitics;
 use crate::domain::repositories::{
-    article_repository::ArticleRepositoryTrait, comment_repository::CommentRepositoryTrait,
+    article_repository::ArticleRepositoryTrait,
+    blocked_word_repository::BlockedWordRepositoryTrait,
+    comment_mention_repository::CommentMentionRepositoryTrait,
+    comment_report_repository::CommentReportRepositoryTrait,
+    comment_repository::CommentRepositoryTrait,
+    comment_review_repository::CommentReviewRepositoryTrait, user_repository::UserRepositoryTrait,
 };
 use crate::error::DomainError;
-use crate::{LOG_SEP, R_EOL};
+use crate::libs::time::TimeHelper;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
 
 pub struct CommentOnArticleParams {
     pub author_id: Uuid,
+    /// Decides which flood limits apply and whether unapproved articles can be commented on.
+    pub author_role: Role,
     pub article_id: Uuid,
     pub content: String,
+    /// Turns the comment into a reply to this one.
+    pub parent_id: Option<Uuid>,
+    /// Which authors have their comment held for review instead of published right away.
+    pub pre_moderation: PreModerationPolicy,
+    /// How fast the author can comment and how soon they can repeat a comment, by their role.
+    pub flood: CommentFloodPolicy,
+    /// Articles older than this many days no longer take comments, see `ArticlePolitics::comment_mode`.
+    pub comment_auto_close_days: Option<u32>,
 }
 
-pub struct CommentOnArticleService<CR, AR>
+/// How many mentions of a comment are resolved, so that a comment can't make us look for lots of users.
+pub const MAX_MENTIONS_PER_COMMENT: usize = 10;
+
+pub struct CommentOnArticleService<CR, AR, UR, CMR, BWR, CRR, CRVR>
 where
     CR: CommentRepositoryTrait,
     AR: ArticleRepositoryTrait,
+    UR: UserRepositoryTrait,
+    CMR: CommentMentionRepositoryTrait,
+    BWR: BlockedWordRepositoryTrait,
+    CRR: CommentReportRepositoryTrait,
+    CRVR: CommentReviewRepositoryTrait,
 {
     comment_repository: CR,
     article_repository: AR,
+    user_repository: UR,
+    comment_mention_repository: CMR,
+    blocked_word_repository: BWR,
+    comment_report_repository: CRR,
+    comment_review_repository: CRVR,
 }
 
-impl<CR: CommentRepositoryTrait, AR: ArticleRepositoryTrait> CommentOnArticleService<CR, AR> {
-    pub fn new(comment_repository: CR, article_repository: AR) -> Self {
+impl<CR, AR, UR, CMR, BWR, CRR, CRVR> CommentOnArticleService<CR, AR, UR, CMR, BWR, CRR, CRVR>
+where
+    CR: CommentRepositoryTrait,
+    AR: ArticleRepositoryTrait,
+    UR: UserRepositoryTrait,
+    CMR: CommentMentionRepositoryTrait,
+    BWR: BlockedWordRepositoryTrait,
+    CRR: CommentReportRepositoryTrait,
+    CRVR: CommentReviewRepositoryTrait,
+{
+    pub fn new(
+        comment_repository: CR,
+        article_repository: AR,
+        user_repository: UR,
+        comment_mention_repository: CMR,
+        blocked_word_repository: BWR,
+        comment_report_repository: CRR,
+        comment_review_repository: CRVR,
+    ) -> Self {
         CommentOnArticleService {
             comment_repository,
             article_repository,
+            user_repository,
+            comment_mention_repository,
+            blocked_word_repository,
+            comment_report_repository,
+            comment_review_repository,
         }
     }
 
     pub async fn exec(&self, params: CommentOnArticleParams) -> Result<Comment, DomainError> {
-        let article_on_db = self.article_repository.find_by_id(params.article_id).await;
+        let article = self
+            .article_repository
+            .find_by_id(params.article_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Comment On Article Service, while fetching the article from db",
+                    err,
+                )
+            })?
+            .ok_or(DomainError::bad_request_err())?;
 
-        if article_on_db.is_err() {
-            error!("{R_EOL}{LOG_SEP}{R_EOL}Error occurred on comment_on_article_service.rs, while fetching article from db:{R_EOL}{:#?}{R_EOL}{LOG_SEP}{R_EOL}", article_on_db.unwrap_err());
-            return Err(DomainError::internal_err());
+        if !article.approved()
+            && !verify_role_has_permission(
+                &params.author_role,
+                RolePermissions::SeeUnapprovedArticle,
+            )
+        {
+            return Err(DomainError::bad_request_err());
         }
 
-        if article_on_db.unwrap().is_none() {
-            return Err(DomainError::bad_request_err());
+        let comment_mode = ArticlePolitics::comment_mode(
+            &article,
+            params.comment_auto_close_days,
+            TimeHelper::now(),
+        );
+
+        if !ArticlePolitics::can_comment(comment_mode, Some(&params.author_role)) {
+            return Err(
+                DomainError::bad_request_err().with_message(match comment_mode {
+                    CommentMode::StaffOnly => "Only the team can comment on this article.",
+                    _ => "Comments are closed on this article.",
+                }),
+            );
+        }
+
+        self.check_flood(
+            params.author_id,
+            &params.author_role,
+            &params.content,
+            &params.flood,
+        )
+        .await?;
+
+        let (content, flagged_by) =
+            filter_comment_content(&self.blocked_word_repository, params.content).await?;
+
+        let mut comment = match params.parent_id {
+            None => Comment::new(params.author_id, Some(params.article_id), content),
+            Some(parent_id) => {
+                let parent = self.find_parent(parent_id, params.article_id).await?;
+                Comment::new_reply(params.author_id, &parent, content)
+            }
+        };
+
+        let needs_review = flagged_by.is_none()
+            && self
+                .needs_review(
+                    params.author_id,
+                    &params.author_role,
+                    &params.pre_moderation,
+                )
+                .await?;
+
+        // hidden until a moderator reviews it
+        if flagged_by.is_some() || needs_review {
+            comment.set_is_active(false);
+        }
+
+        let comment = self
+            .comment_repository
+            .create(comment)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Comment On Article Service, while creating the comment",
+                    err,
+                )
+            })?;
+
+        if let Some(blocked_word) = flagged_by {
+            report_flagged_comment(&self.comment_report_repository, &comment, &blocked_word)
+                .await?;
+        }
+
+        if needs_review {
+            self.comment_review_repository
+                .create(CommentReview::new(comment.id(), comment.author_id()))
+                .await
+                .map_err(|err| {
+                    generate_service_internal_error(
+                        "Error occurred on Comment On Article Service, while queueing the comment for review",
+                        err,
+                    )
+                })?;
+        }
+
+        let mentions = resolve_mentions(&self.user_repository, &comment).await?;
+
+        if !mentions.is_empty() {
+            self.comment_mention_repository
+                .set_mentions(comment.id(), mentions)
+                .await
+                .map_err(|err| {
+                    generate_service_internal_error(
+                        "Error occurred on Comment On Article Service, while saving the comment's mentions",
+                        err,
+                    )
+                })?;
+        }
+
+        Ok(comment)
+    }
+
+    /// Rejects the comment if the author, unless exempt by their role, is commenting too fast or repeating themselves.
+    async fn check_flood(
+        &self,
+        author_id: Uuid,
+        author_role: &Role,
+        content: &str,
+        policy: &CommentFloodPolicy,
+    ) -> Result<(), DomainError> {
+        let limits = match CommentFloodPolitics::limits(policy, author_role) {
+            Some(limits) => limits,
+            None => return Ok(()),
+        };
+
+        let now = TimeHelper::now();
+
+        let recent_comments = self
+            .comment_repository
+            .find_many_by_author_since(author_id, now - CommentFloodPolitics::lookback(&limits))
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Comment On Article Service, while fetching the author's recent comments",
+                    err,
+                )
+            })?;
+
+        CommentFloodPolitics::check(&limits, content, &recent_comments, now)
+    }
+
+    /// Whether the comment must wait for a moderator's approval to be published, which is the case for the comments of
+    /// regular users who are still new by the policy and never had a comment approved.
+    async fn needs_review(
+        &self,
+        author_id: Uuid,
+        author_role: &Role,
+        policy: &PreModerationPolicy,
+    ) -> Result<bool, DomainError> {
+        if !CommentReviewPolitics::applies_to(author_role)
+            || *policy == PreModerationPolicy::DISABLED
+        {
+            return Ok(false);
         }
 
-        let comment = Comment::new(params.author_id, Some(params.article_id), params.content);
+        let has_graduated = self
+            .comment_review_repository
+            .author_has_approved_review(author_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Comment On Article Service, while checking the author's approved reviews",
+                    err,
+                )
+            })?;
+
+        if has_graduated {
+            return Ok(false);
+        }
+
+        let author = self
+            .user_repository
+            .find_by_id(&author_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Comment On Article Service, while fetching the author from db",
+                    err,
+                )
+            })?;
+
+        let author = match author {
+            Some(author) => author,
+            None => return Ok(true),
+        };
+
+        let published_comments = self
+            .comment_repository
+            .count_active_by_author(author_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Comment On Article Service, while counting the author's comments",
+                    err,
+                )
+            })?;
 
-        let response = self.comment_repository.create(comment).await;
+        Ok(CommentReviewPolitics::is_newcomer(
+            policy,
+            author.created_at(),
+            published_comments,
+            TimeHelper::now(),
+        ))
+    }
+
+    /// Finds the comment being replied to, which must be active, on the same article and not nested deeper than
+    /// `MAX_REPLY_DEPTH` allows replies to be.
+    async fn find_parent(&self, parent_id: Uuid, article_id: Uuid) -> Result<Comment, DomainError> {
+        let parent = self
+            .find_comment(parent_id)
+            .await?
+            .filter(|parent| parent.is_active() && parent.article_id() == Some(article_id))
+            .ok_or(
+                DomainError::bad_request_err()
+                    .with_message("The comment being replied to is not available."),
+            )?;
+
+        let mut depth = 1;
+        let mut ancestor_id = parent.parent_id();
+
+        while let Some(id) = ancestor_id {
+            depth += 1;
 
-        if response.is_err() {
-            error!("{R_EOL}{LOG_SEP}{R_EOL}Error occurred on comment_on_article_service.rs, while creating comment transaction:{R_EOL}{:#?}{R_EOL}{LOG_SEP}{R_EOL}", response.unwrap_err());
-            return Err(DomainError::internal_err());
+            if depth > MAX_REPLY_DEPTH {
+                return Err(DomainError::bad_request_err()
+                    .with_message("Replies can't be nested any deeper."));
+            }
+
+            ancestor_id = self
+                .find_comment(id)
+                .await?
+                .and_then(|ancestor| ancestor.parent_id());
         }
 
-        Ok(response.unwrap())
+        Ok(parent)
+    }
+
+    async fn find_comment(&self, id: Uuid) -> Result<Option<Comment>, DomainError> {
+        self.comment_repository.find_by_id(id).await.map_err(|err| {
+            generate_service_internal_error(
+                "Error occurred on Comment On Article Service, while fetching a comment from db",
+                err,
+            )
+        })
+    }
+}
+
+/// Runs a comment's content through the word filter, masking its blocked words or rejecting it as they say.
+/// Returns the filtered content along with the blocked word that flagged it, if any.
+pub async fn filter_comment_content<BWR: BlockedWordRepositoryTrait>(
+    blocked_word_repository: &BWR,
+    content: String,
+) -> Result<(String, Option<BlockedWord>), DomainError> {
+    let blocked_words = blocked_word_repository.find_all().await.map_err(|err| {
+        generate_service_internal_error(
+            "Error occurred while fetching the blocked words to filter a comment",
+            err,
+        )
+    })?;
+
+    let outcome = WordFilterPolitics::filter(&blocked_words).apply(&content);
+
+    if outcome.rejected_by.is_some() {
+        return Err(DomainError::bad_request_err()
+            .with_message("The comment contains words that aren't allowed."));
+    }
+
+    Ok((outcome.text, outcome.flagged_by))
+}
+
+/// Reports a comment flagged by the word filter for moderators to review it, on behalf of whoever added the blocked
+/// word that flagged it.
+pub async fn report_flagged_comment<CRR: CommentReportRepositoryTrait>(
+    comment_report_repository: &CRR,
+    comment: &Comment,
+    blocked_word: &BlockedWord,
+) -> Result<(), DomainError> {
+    let report = DraftCommentReport::new(
+        comment.id(),
+        blocked_word.author_id(),
+        format!(
+            "Flagged by the word filter (\"{}\").",
+            blocked_word.pattern()
+        ),
+        ReportReason::Offensive,
+    );
+
+    comment_report_repository
+        .create(report)
+        .await
+        .map_err(|err| {
+            generate_service_internal_error(
+                "Error occurred while reporting a comment flagged by the word filter",
+                err,
+            )
+        })?;
+
+    Ok(())
+}
+
+/// Finds the "@nickname"s of the comment that belong to some user. A nickname followed by punctuation is looked for
+/// with and without it, e.g. "@Floricultor!" is a mention of "Floricultor!" if there's such user, or else of
+/// "Floricultor".
+pub async fn resolve_mentions<UR: UserRepositoryTrait>(
+    user_repository: &UR,
+    comment: &Comment,
+) -> Result<Vec<CommentMention>, DomainError> {
+    let mut users: HashMap<String, Option<Uuid>> = HashMap::new();
+    let mut mentions = vec![];
+
+    for candidate in MentionCandidate::find_all(comment.content())
+        .into_iter()
+        .take(MAX_MENTIONS_PER_COMMENT)
+    {
+        for nickname in candidate.nicknames() {
+            let key = nickname.to_lowercase();
+
+            if !users.contains_key(&key) {
+                let user = user_repository
+                    .find_by_nickname(nickname)
+                    .await
+                    .map_err(|err| {
+                        generate_service_internal_error(
+                            "Error occurred on Comment On Article Service, while finding a mentioned user by nickname",
+                            err,
+                        )
+                    })?
+                    .filter(|user| user.nickname().to_lowercase() == key);
+
+                users.insert(key.clone(), user.map(|user| user.id()));
+            }
+
+            if let Some(user_id) = users[&key] {
+                let end = candidate.start + 1 + nickname.chars().count() as u32;
+                mentions.push(CommentMention::new(
+                    comment.id(),
+                    user_id,
+                    candidate.start,
+                    end,
+                ));
+                break;
+            }
+        }
     }
+
+    Ok(mentions)
 }
 
 #[cfg(test)]
@@ -63,10 +453,21 @@ mod test {
     use std::sync::Mutex;
 
     use crate::domain::domain_entities::article::Article;
+    use crate::domain::domain_entities::blocked_word::BlockedWordAction;
+    use crate::domain::domain_entities::comment_report::CommentReportTrait;
+    use crate::domain::domain_entities::habbo_avatar::HabboAvatar;
     use crate::domain::domain_entities::slug::Slug;
+    use crate::domain::domain_entities::user::User;
     use crate::domain::repositories::comment_repository::MockCommentRepositoryTrait;
     use crate::libs::time::TimeHelper;
     use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::blocked_word_repository::get_blocked_word_repository;
+    use crate::tests::repositories::comment_mention_repository::get_comment_mention_repository;
+    use crate::tests::repositories::comment_report_repository::get_comment_report_repository;
+    use crate::tests::repositories::comment_repository::get_comment_repository;
+    use crate::tests::repositories::comment_review_repository::get_comment_review_repository;
+    use crate::tests::repositories::users_repository::get_user_repository;
+    use chrono::TimeDelta;
 
     #[allow(dead_code)]
     #[derive(Clone, Copy)]
@@ -90,12 +491,17 @@ mod test {
             "cover_url".into(),
             "title".into(),
             "content".into(),
-            false,
+            true,
             TimeHelper::now(),
             None,
             Some(1),
             Some("Foo".to_string()),
             Slug::new(article_id, "title".into()),
+            false,
+            false,
+            0,
+            None,
+            CommentMode::Open,
         ));
 
         let comment_article_db: Arc<Mutex<Vec<CommentArticle>>> = Arc::new(Mutex::new(vec![]));
@@ -121,13 +527,42 @@ mod test {
                 Ok(comment)
             });
 
-        let sut = CommentOnArticleService::new(mocked_comment_repo, mocked_article_repo);
+        mocked_comment_repo
+            .expect_find_many_by_author_since()
+            .returning(|_, _| Ok(vec![]));
+
+        let (_, user_repository) = get_user_repository();
+        let (_, comment_mention_repository) = get_comment_mention_repository();
+
+        let (_, blocked_word_repository) = get_blocked_word_repository();
+        let (_, comment_report_repository) =
+            get_comment_report_repository(Arc::new(Mutex::new(vec![])));
+
+        let (_, comment_review_repository) = get_comment_review_repository(
+            Arc::new(Mutex::new(vec![])),
+            Arc::new(Mutex::new(vec![])),
+        );
+
+        let sut = CommentOnArticleService::new(
+            mocked_comment_repo,
+            mocked_article_repo,
+            user_repository,
+            comment_mention_repository,
+            blocked_word_repository,
+            comment_report_repository,
+            comment_review_repository,
+        );
 
         let res = sut
             .exec(CommentOnArticleParams {
                 article_id,
                 author_id: user_id,
+                author_role: Role::User,
                 content: "This article is awesome!".into(),
+                parent_id: None,
+                pre_moderation: PreModerationPolicy::DISABLED,
+                flood: CommentFloodPolicy::default(),
+                comment_auto_close_days: None,
             })
             .await;
 
@@ -149,4 +584,648 @@ mod test {
             (article_id, res.id())
         );
     }
+
+    #[tokio::test]
+    async fn test_replies() {
+        let (article_db, mocked_article_repo) = get_article_repository();
+        let (comment_db, mocked_comment_repo) = get_comment_repository();
+
+        let user_id = Uuid::new_v4();
+        let mut article = Article::new(
+            user_id,
+            "title".into(),
+            "content".into(),
+            "cover_url".into(),
+            1,
+            "Foo".into(),
+        );
+        article.set_approved(true);
+        let article_id = article.id();
+        let mut other_article = Article::new(
+            user_id,
+            "other title".into(),
+            "content".into(),
+            "cover_url".into(),
+            1,
+            "Foo".into(),
+        );
+        other_article.set_approved(true);
+        let other_article_id = other_article.id();
+
+        article_db.lock().unwrap().push(article);
+        article_db.lock().unwrap().push(other_article);
+
+        let (_, user_repository) = get_user_repository();
+        let (_, comment_mention_repository) = get_comment_mention_repository();
+
+        let (_, blocked_word_repository) = get_blocked_word_repository();
+        let (_, comment_report_repository) = get_comment_report_repository(comment_db.clone());
+
+        let (_, comment_review_repository) = get_comment_review_repository(
+            Arc::new(Mutex::new(vec![])),
+            Arc::new(Mutex::new(vec![])),
+        );
+
+        let sut = CommentOnArticleService::new(
+            mocked_comment_repo,
+            mocked_article_repo,
+            user_repository,
+            comment_mention_repository,
+            blocked_word_repository,
+            comment_report_repository,
+            comment_review_repository,
+        );
+
+        let mut parent_id = None;
+
+        for _ in 0..=MAX_REPLY_DEPTH {
+            let comment = sut
+                .exec(CommentOnArticleParams {
+                    article_id,
+                    author_id: user_id,
+                    // staff aren't flood limited
+                    author_role: Role::Editor,
+                    content: "Concordo!".into(),
+                    parent_id,
+                    pre_moderation: PreModerationPolicy::DISABLED,
+                    flood: CommentFloodPolicy::default(),
+                    comment_auto_close_days: None,
+                })
+                .await
+                .unwrap();
+
+            assert_eq!(comment.parent_id(), parent_id);
+            parent_id = Some(comment.id());
+        }
+
+        let res = sut
+            .exec(CommentOnArticleParams {
+                article_id,
+                author_id: user_id,
+                author_role: Role::Editor,
+                content: "Too deep".into(),
+                parent_id,
+                pre_moderation: PreModerationPolicy::DISABLED,
+                flood: CommentFloodPolicy::default(),
+                comment_auto_close_days: None,
+            })
+            .await;
+
+        assert!(res.is_err());
+        assert_eq!(MAX_REPLY_DEPTH + 1, comment_db.lock().unwrap().len());
+
+        let top_level_id = comment_db.lock().unwrap()[0].id();
+
+        let res = sut
+            .exec(CommentOnArticleParams {
+                article_id: other_article_id,
+                author_id: user_id,
+                author_role: Role::Editor,
+                content: "Wrong article".into(),
+                parent_id: Some(top_level_id),
+                pre_moderation: PreModerationPolicy::DISABLED,
+                flood: CommentFloodPolicy::default(),
+                comment_auto_close_days: None,
+            })
+            .await;
+
+        assert!(res.is_err());
+
+        comment_db.lock().unwrap()[0].set_is_active(false);
+
+        let res = sut
+            .exec(CommentOnArticleParams {
+                article_id,
+                author_id: user_id,
+                author_role: Role::Editor,
+                content: "Replying to a removed comment".into(),
+                parent_id: Some(top_level_id),
+                pre_moderation: PreModerationPolicy::DISABLED,
+                flood: CommentFloodPolicy::default(),
+                comment_auto_close_days: None,
+            })
+            .await;
+
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_mentions() {
+        let (article_db, article_repository) = get_article_repository();
+        let (_, comment_repository) = get_comment_repository();
+        let (user_db, user_repository) = get_user_repository();
+        let (mention_db, comment_mention_repository) = get_comment_mention_repository();
+
+        let floricultor = User::new("Floricultor".into(), "password".into(), None);
+        let exclamation = User::new("Uau!".into(), "password".into(), None);
+
+        user_db
+            .lock()
+            .unwrap()
+            .extend([floricultor.clone(), exclamation.clone()]);
+
+        let mut article = Article::new(
+            floricultor.id(),
+            "title".into(),
+            "content".into(),
+            "cover_url".into(),
+            1,
+            "Foo".into(),
+        );
+        article.set_approved(true);
+        let article_id = article.id();
+
+        article_db.lock().unwrap().push(article);
+
+        let (_, blocked_word_repository) = get_blocked_word_repository();
+        let (_, comment_report_repository) =
+            get_comment_report_repository(Arc::new(Mutex::new(vec![])));
+
+        let (_, comment_review_repository) =
+            get_comment_review_repository(Arc::new(Mutex::new(vec![])), user_db.clone());
+
+        let sut = CommentOnArticleService::new(
+            comment_repository,
+            article_repository,
+            user_repository,
+            comment_mention_repository,
+            blocked_word_repository,
+            comment_report_repository,
+            comment_review_repository,
+        );
+
+        let comment = sut
+            .exec(CommentOnArticleParams {
+                article_id,
+                author_id: Uuid::new_v4(),
+                author_role: Role::User,
+                content: "@Floricultor, @Uau! @Ninguem e-mail@Floricultor".into(),
+                parent_id: None,
+                pre_moderation: PreModerationPolicy::DISABLED,
+                flood: CommentFloodPolicy::default(),
+                comment_auto_close_days: None,
+            })
+            .await
+            .unwrap();
+
+        let mentions: Vec<(Uuid, u32, u32)> = mention_db
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|mention| {
+                assert_eq!(comment.id(), mention.comment_id());
+                (mention.user_id(), mention.start(), mention.end())
+            })
+            .collect();
+
+        assert_eq!(
+            vec![(floricultor.id(), 0, 12), (exclamation.id(), 14, 19)],
+            mentions
+        );
+    }
+
+    #[tokio::test]
+    async fn test_flood() {
+        let (article_db, article_repository) = get_article_repository();
+        let (comment_db, comment_repository) = get_comment_repository();
+        let (_, user_repository) = get_user_repository();
+        let (_, comment_mention_repository) = get_comment_mention_repository();
+
+        let user_id = Uuid::new_v4();
+        let mut article = Article::new(
+            user_id,
+            "title".into(),
+            "content".into(),
+            "cover_url".into(),
+            1,
+            "Foo".into(),
+        );
+        article.set_approved(true);
+        let article_id = article.id();
+
+        article_db.lock().unwrap().push(article);
+
+        let (_, blocked_word_repository) = get_blocked_word_repository();
+        let (_, comment_report_repository) = get_comment_report_repository(comment_db.clone());
+
+        let (_, comment_review_repository) = get_comment_review_repository(
+            Arc::new(Mutex::new(vec![])),
+            Arc::new(Mutex::new(vec![])),
+        );
+
+        let sut = CommentOnArticleService::new(
+            comment_repository,
+            article_repository,
+            user_repository,
+            comment_mention_repository,
+            blocked_word_repository,
+            comment_report_repository,
+            comment_review_repository,
+        );
+
+        let params = |content: &str| CommentOnArticleParams {
+            article_id,
+            author_id: user_id,
+            author_role: Role::User,
+            content: content.into(),
+            parent_id: None,
+            pre_moderation: PreModerationPolicy::DISABLED,
+            flood: CommentFloodPolicy::default(),
+            comment_auto_close_days: None,
+        };
+
+        sut.exec(params("Primeiro!")).await.unwrap();
+
+        let err = sut.exec(params("Segundo!")).await.unwrap_err();
+        let retry_after = err.get_retry_after().unwrap();
+        assert!(retry_after > 0 && retry_after <= 30);
+
+        // past the cooldown, but the same comment all over again
+        let earlier = TimeHelper::now() - TimeDelta::minutes(1);
+        comment_db.lock().unwrap()[0] = Comment::new_from_existing(
+            Uuid::new_v4(),
+            Some(article_id),
+            None,
+            user_id,
+            "Primeiro!".into(),
+            true,
+            earlier,
+            None,
+        );
+
+        let err = sut.exec(params("primeiro")).await.unwrap_err();
+        assert!(err.get_retry_after().is_none());
+
+        // filling the sliding window up
+        let limits =
+            CommentFloodPolitics::limits(&CommentFloodPolicy::default(), &Role::User).unwrap();
+        comment_db.lock().unwrap().clear();
+
+        for minutes in 1..=limits.max_comments_per_window as i64 {
+            comment_db.lock().unwrap().push(Comment::new_from_existing(
+                Uuid::new_v4(),
+                Some(article_id),
+                None,
+                user_id,
+                format!("Comentário número {minutes}"),
+                true,
+                TimeHelper::now() - TimeDelta::minutes(minutes),
+                None,
+            ));
+        }
+
+        let err = sut.exec(params("Mais um")).await.unwrap_err();
+        let retry_after = err.get_retry_after().unwrap();
+        assert!(retry_after > 0 && retry_after <= 5 * 60);
+
+        let res = sut
+            .exec(CommentOnArticleParams {
+                author_role: Role::Coord,
+                ..params("Mais um")
+            })
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_unapproved_article() {
+        let (article_db, article_repository) = get_article_repository();
+        let (comment_db, comment_repository) = get_comment_repository();
+        let (_, user_repository) = get_user_repository();
+        let (_, comment_mention_repository) = get_comment_mention_repository();
+
+        let user_id = Uuid::new_v4();
+        let article = Article::new(
+            user_id,
+            "title".into(),
+            "content".into(),
+            "cover_url".into(),
+            1,
+            "Foo".into(),
+        );
+        let article_id = article.id();
+
+        article_db.lock().unwrap().push(article);
+
+        let (_, blocked_word_repository) = get_blocked_word_repository();
+        let (_, comment_report_repository) = get_comment_report_repository(comment_db.clone());
+
+        let (_, comment_review_repository) = get_comment_review_repository(
+            Arc::new(Mutex::new(vec![])),
+            Arc::new(Mutex::new(vec![])),
+        );
+
+        let sut = CommentOnArticleService::new(
+            comment_repository,
+            article_repository,
+            user_repository,
+            comment_mention_repository,
+            blocked_word_repository,
+            comment_report_repository,
+            comment_review_repository,
+        );
+
+        let res = sut
+            .exec(CommentOnArticleParams {
+                article_id,
+                author_id: user_id,
+                author_role: Role::User,
+                content: "Ainda não saiu!".into(),
+                parent_id: None,
+                pre_moderation: PreModerationPolicy::DISABLED,
+                flood: CommentFloodPolicy::default(),
+                comment_auto_close_days: None,
+            })
+            .await;
+
+        assert!(res.is_err());
+        assert!(comment_db.lock().unwrap().is_empty());
+
+        let res = sut
+            .exec(CommentOnArticleParams {
+                article_id,
+                author_id: user_id,
+                author_role: Role::Editor,
+                content: "Revisando".into(),
+                parent_id: None,
+                pre_moderation: PreModerationPolicy::DISABLED,
+                flood: CommentFloodPolicy::default(),
+                comment_auto_close_days: None,
+            })
+            .await;
+
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_comment_mode() {
+        let (article_db, article_repository) = get_article_repository();
+        let (comment_db, comment_repository) = get_comment_repository();
+        let (_, user_repository) = get_user_repository();
+        let (_, comment_mention_repository) = get_comment_mention_repository();
+
+        let user_id = Uuid::new_v4();
+        let mut article = Article::new(
+            user_id,
+            "title".into(),
+            "content".into(),
+            "cover_url".into(),
+            1,
+            "Foo".into(),
+        );
+        article.set_approved(true);
+        article.set_comment_mode(CommentMode::StaffOnly);
+        let article_id = article.id();
+
+        let old_article_id = Uuid::new_v4();
+        let old_article = Article::new_from_existing(
+            old_article_id,
+            user_id,
+            "cover_url".into(),
+            "old title".into(),
+            "content".into(),
+            true,
+            TimeHelper::now() - TimeDelta::days(40),
+            None,
+            Some(1),
+            Some("Foo".to_string()),
+            Slug::new(old_article_id, "old title".into()),
+            false,
+            false,
+            0,
+            None,
+            CommentMode::Open,
+        );
+
+        article_db.lock().unwrap().extend([article, old_article]);
+
+        let (_, blocked_word_repository) = get_blocked_word_repository();
+        let (_, comment_report_repository) = get_comment_report_repository(comment_db.clone());
+
+        let (_, comment_review_repository) = get_comment_review_repository(
+            Arc::new(Mutex::new(vec![])),
+            Arc::new(Mutex::new(vec![])),
+        );
+
+        let sut = CommentOnArticleService::new(
+            comment_repository,
+            article_repository,
+            user_repository,
+            comment_mention_repository,
+            blocked_word_repository,
+            comment_report_repository,
+            comment_review_repository,
+        );
+
+        let params = |article_id: Uuid, author_role: Role| CommentOnArticleParams {
+            article_id,
+            author_id: user_id,
+            author_role,
+            content: "Comentário".into(),
+            parent_id: None,
+            pre_moderation: PreModerationPolicy::DISABLED,
+            flood: CommentFloodPolicy::default(),
+            comment_auto_close_days: Some(30),
+        };
+
+        assert!(sut.exec(params(article_id, Role::User)).await.is_err());
+        assert!(sut.exec(params(article_id, Role::Writer)).await.is_ok());
+
+        // closed for everyone once older than the auto-close
+        assert!(sut.exec(params(old_article_id, Role::Ceo)).await.is_err());
+
+        assert_eq!(1, comment_db.lock().unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn test_word_filter() {
+        let (article_db, article_repository) = get_article_repository();
+        let (comment_db, comment_repository) = get_comment_repository();
+        let (_, user_repository) = get_user_repository();
+        let (_, comment_mention_repository) = get_comment_mention_repository();
+        let (blocked_word_db, blocked_word_repository) = get_blocked_word_repository();
+        let (report_db, comment_report_repository) =
+            get_comment_report_repository(comment_db.clone());
+
+        let moderator_id = Uuid::new_v4();
+
+        blocked_word_db.lock().unwrap().extend([
+            BlockedWord::new("bobo".into(), false, BlockedWordAction::Mask, moderator_id),
+            BlockedWord::new(
+                r"compre\s+moedas".into(),
+                true,
+                BlockedWordAction::Reject,
+                moderator_id,
+            ),
+            BlockedWord::new("golpe".into(), false, BlockedWordAction::Flag, moderator_id),
+        ]);
+
+        let mut article = Article::new(
+            moderator_id,
+            "title".into(),
+            "content".into(),
+            "cover_url".into(),
+            1,
+            "Foo".into(),
+        );
+        article.set_approved(true);
+        let article_id = article.id();
+
+        article_db.lock().unwrap().push(article);
+
+        let (_, comment_review_repository) = get_comment_review_repository(
+            Arc::new(Mutex::new(vec![])),
+            Arc::new(Mutex::new(vec![])),
+        );
+
+        let sut = CommentOnArticleService::new(
+            comment_repository,
+            article_repository,
+            user_repository,
+            comment_mention_repository,
+            blocked_word_repository,
+            comment_report_repository,
+            comment_review_repository,
+        );
+
+        let params = |content: &str| CommentOnArticleParams {
+            article_id,
+            author_id: Uuid::new_v4(),
+            author_role: Role::User,
+            content: content.into(),
+            parent_id: None,
+            pre_moderation: PreModerationPolicy::DISABLED,
+            flood: CommentFloodPolicy::default(),
+            comment_auto_close_days: None,
+        };
+
+        let comment = sut.exec(params("Que notícia B0B0")).await.unwrap();
+        assert_eq!("Que notícia ****", comment.content());
+        assert!(comment.is_active());
+
+        let res = sut.exec(params("Compre moedas no meu site")).await;
+        assert!(matches!(res, Err(DomainError::BadRequest(_))));
+        assert_eq!(1, comment_db.lock().unwrap().len());
+
+        let comment = sut.exec(params("Esse site é golpe")).await.unwrap();
+        assert!(!comment.is_active());
+
+        let reports = report_db.lock().unwrap();
+        assert_eq!(1, reports.len());
+        assert_eq!(
+            (Some(comment.id()), moderator_id),
+            (reports[0].comment_id(), reports[0].user_id())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pre_moderation() {
+        let (article_db, article_repository) = get_article_repository();
+        let (comment_db, comment_repository) = get_comment_repository();
+        let (user_db, user_repository) = get_user_repository();
+        let (_, comment_mention_repository) = get_comment_mention_repository();
+        let (_, blocked_word_repository) = get_blocked_word_repository();
+        let (_, comment_report_repository) = get_comment_report_repository(comment_db.clone());
+        let (review_db, comment_review_repository) =
+            get_comment_review_repository(comment_db.clone(), user_db.clone());
+
+        let newcomer = User::new("Novato".into(), "password".into(), None);
+        let veteran = User::new_from_existing(
+            Uuid::new_v4(),
+            "Veterano".into(),
+            "password".into(),
+            TimeHelper::now() - TimeDelta::days(30),
+            None,
+            None,
+            None,
+            HabboAvatar::default(),
+        );
+
+        user_db
+            .lock()
+            .unwrap()
+            .extend([newcomer.clone(), veteran.clone()]);
+
+        let mut article = Article::new(
+            veteran.id(),
+            "title".into(),
+            "content".into(),
+            "cover_url".into(),
+            1,
+            "Foo".into(),
+        );
+        article.set_approved(true);
+        let article_id = article.id();
+
+        article_db.lock().unwrap().push(article);
+
+        for hours in 2..5 {
+            comment_db.lock().unwrap().push(Comment::new_from_existing(
+                Uuid::new_v4(),
+                Some(article_id),
+                None,
+                veteran.id(),
+                format!("Comentário de {hours} horas atrás"),
+                true,
+                TimeHelper::now() - TimeDelta::hours(hours),
+                None,
+            ));
+        }
+
+        let sut = CommentOnArticleService::new(
+            comment_repository,
+            article_repository,
+            user_repository,
+            comment_mention_repository,
+            blocked_word_repository,
+            comment_report_repository,
+            comment_review_repository,
+        );
+
+        let params = |author_id: Uuid, content: &str| CommentOnArticleParams {
+            article_id,
+            author_id,
+            author_role: Role::User,
+            content: content.into(),
+            parent_id: None,
+            pre_moderation: PreModerationPolicy::new(Some(7), Some(3)),
+            flood: CommentFloodPolicy::default(),
+            comment_auto_close_days: None,
+        };
+
+        let comment = sut.exec(params(veteran.id(), "Sem revisão")).await.unwrap();
+        assert!(comment.is_active());
+
+        let comment = sut
+            .exec(params(newcomer.id(), "Meu primeiro comentário"))
+            .await
+            .unwrap();
+        assert!(!comment.is_active());
+        assert_eq!(1, review_db.lock().unwrap().len());
+        assert!(review_db.lock().unwrap()[0].is_pending());
+
+        let comment = sut
+            .exec(CommentOnArticleParams {
+                author_role: Role::Writer,
+                ..params(Uuid::new_v4(), "Escritores não passam por revisão")
+            })
+            .await
+            .unwrap();
+        assert!(comment.is_active());
+
+        // once a moderator approves one of their comments, the newcomer publishes right away
+        review_db.lock().unwrap()[0].review(veteran.id(), true);
+        comment_db
+            .lock()
+            .unwrap()
+            .retain(|comment| comment.author_id() != newcomer.id());
+
+        let comment = sut
+            .exec(params(newcomer.id(), "Agora sem revisão"))
+            .await
+            .unwrap();
+        assert!(comment.is_active());
+        assert_eq!(1, review_db.lock().unwrap().len());
+    }
 }
@@ -68,6 +68,7 @@ mod test {
     use crate::domain::domain_entities::comment::Comment;
     use crate::domain::domain_entities::role::Role;
     use crate::domain::repositories::comment_repository::MockCommentRepositoryTrait;
+    use crate::tests::repositories::comment_repository::get_comment_repository;
 
     #[tokio::test]
     async fn test() {
@@ -122,4 +123,33 @@ mod test {
         let db = comment_db.lock().unwrap();
         assert_eq!(0, db.len());
     }
+
+    #[tokio::test]
+    async fn test_replies_outlive_their_parent() {
+        let (comment_db, comment_repository) = get_comment_repository();
+
+        let parent = Comment::new(Uuid::new_v4(), Some(Uuid::new_v4()), "Pai".into());
+        let reply = Comment::new_reply(Uuid::new_v4(), &parent, "Resposta".into());
+
+        comment_db
+            .lock()
+            .unwrap()
+            .extend([parent.clone(), reply.clone()]);
+
+        let service = DeleteCommentService::new(comment_repository);
+
+        service
+            .exec(DeleteCommentParams {
+                user_id: parent.author_id(),
+                staff_role: Role::User,
+                comment_id: parent.id(),
+            })
+            .await
+            .unwrap();
+
+        let db = comment_db.lock().unwrap();
+        assert_eq!(1, db.len());
+        assert_eq!(reply.id(), db[0].id());
+        assert!(db[0].parent_id().is_none());
+    }
 }
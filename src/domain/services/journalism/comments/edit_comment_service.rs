@@ -0,0 +1,281 @@
+use chrono::TimeDelta;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::comment::Comment;
+use crate::domain::domain_entities::comment_edit::CommentEdit;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::blocked_word_repository::BlockedWordRepositoryTrait;
+use crate::domain::repositories::comment_edit_repository::CommentEditRepositoryTrait;
+use crate::domain::repositories::comment_mention_repository::CommentMentionRepositoryTrait;
+use crate::domain::repositories::comment_report_repository::CommentReportRepositoryTrait;
+use crate::domain::repositories::comment_repository::CommentRepositoryTrait;
+use crate::domain::repositories::user_repository::UserRepositoryTrait;
+use crate::domain::services::journalism::comments::comment_on_article_service::{
+    filter_comment_content, report_flagged_comment, resolve_mentions,
+};
+use crate::error::DomainError;
+use crate::libs::time::TimeHelper;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub const DEFAULT_COMMENT_EDIT_WINDOW_MINUTES: u32 = 15;
+
+pub struct EditCommentParams {
+    pub user_id: Uuid,
+    pub user_role: Role,
+    pub comment_id: Uuid,
+    pub content: String,
+    /// For how long after commenting its author can still edit it.
+    pub edit_window_minutes: Option<u32>,
+}
+
+pub struct EditCommentService<CR, CER, UR, CMR, BWR, CRR>
+where
+    CR: CommentRepositoryTrait,
+    CER: CommentEditRepositoryTrait,
+    UR: UserRepositoryTrait,
+    CMR: CommentMentionRepositoryTrait,
+    BWR: BlockedWordRepositoryTrait,
+    CRR: CommentReportRepositoryTrait,
+{
+    comment_repository: CR,
+    comment_edit_repository: CER,
+    user_repository: UR,
+    comment_mention_repository: CMR,
+    blocked_word_repository: BWR,
+    comment_report_repository: CRR,
+}
+
+impl<CR, CER, UR, CMR, BWR, CRR> EditCommentService<CR, CER, UR, CMR, BWR, CRR>
+where
+    CR: CommentRepositoryTrait,
+    CER: CommentEditRepositoryTrait,
+    UR: UserRepositoryTrait,
+    CMR: CommentMentionRepositoryTrait,
+    BWR: BlockedWordRepositoryTrait,
+    CRR: CommentReportRepositoryTrait,
+{
+    pub fn new(
+        comment_repository: CR,
+        comment_edit_repository: CER,
+        user_repository: UR,
+        comment_mention_repository: CMR,
+        blocked_word_repository: BWR,
+        comment_report_repository: CRR,
+    ) -> Self {
+        EditCommentService {
+            comment_repository,
+            comment_edit_repository,
+            user_repository,
+            comment_mention_repository,
+            blocked_word_repository,
+            comment_report_repository,
+        }
+    }
+
+    /// Authors can edit their active comments within the edit window, while users allowed to inactivate comments can
+    /// edit any of them at any time. The replaced content is kept as a `CommentEdit`.
+    pub async fn exec(&self, params: EditCommentParams) -> Result<Comment, DomainError> {
+        let mut comment = self
+            .comment_repository
+            .find_by_id(params.comment_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Edit Comment Service, while finding comment by id",
+                    err,
+                )
+            })?
+            .ok_or(DomainError::resource_not_found_err())?;
+
+        let user_is_moderator =
+            verify_role_has_permission(&params.user_role, RolePermissions::InactivateComment);
+
+        if !user_is_moderator {
+            if comment.author_id() != params.user_id || !comment.is_active() {
+                return Err(DomainError::unauthorized_err());
+            }
+
+            let edit_window = TimeDelta::minutes(
+                params
+                    .edit_window_minutes
+                    .unwrap_or(DEFAULT_COMMENT_EDIT_WINDOW_MINUTES) as i64,
+            );
+
+            if TimeHelper::now() - comment.created_at() > edit_window {
+                return Err(DomainError::bad_request_err()
+                    .with_message("This comment can no longer be edited."));
+            }
+        }
+
+        let (content, flagged_by) =
+            filter_comment_content(&self.blocked_word_repository, params.content).await?;
+
+        if comment.content() == content {
+            return Ok(comment);
+        }
+
+        self.comment_edit_repository
+            .create(CommentEdit::new(
+                comment.id(),
+                params.user_id,
+                comment.content().to_owned(),
+            ))
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Edit Comment Service, while saving the comment's edit history",
+                    err,
+                )
+            })?;
+
+        comment.set_content(content);
+
+        // hidden until a moderator reviews it
+        if flagged_by.is_some() {
+            comment.set_is_active(false);
+        }
+
+        let comment = self.comment_repository.save(comment).await.map_err(|err| {
+            generate_service_internal_error(
+                "Error occurred on Edit Comment Service, while saving the comment",
+                err,
+            )
+        })?;
+
+        if let Some(blocked_word) = flagged_by {
+            report_flagged_comment(&self.comment_report_repository, &comment, &blocked_word)
+                .await?;
+        }
+
+        // the previous mentions' spans no longer match the content
+        let mentions = resolve_mentions(&self.user_repository, &comment).await?;
+
+        self.comment_mention_repository
+            .set_mentions(comment.id(), mentions)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Edit Comment Service, while saving the comment's mentions",
+                    err,
+                )
+            })?;
+
+        Ok(comment)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::domain::domain_entities::user::User;
+    use crate::tests::repositories::blocked_word_repository::get_blocked_word_repository;
+    use crate::tests::repositories::comment_edit_repository::get_comment_edit_repository;
+    use crate::tests::repositories::comment_mention_repository::get_comment_mention_repository;
+    use crate::tests::repositories::comment_report_repository::get_comment_report_repository;
+    use crate::tests::repositories::comment_repository::get_comment_repository;
+    use crate::tests::repositories::users_repository::get_user_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (comment_db, comment_repository) = get_comment_repository();
+        let (comment_edit_db, comment_edit_repository) = get_comment_edit_repository();
+        let (user_db, user_repository) = get_user_repository();
+        let (mention_db, comment_mention_repository) = get_comment_mention_repository();
+
+        let mentioned_user = User::new("Floricultor".into(), "password".into(), None);
+        user_db.lock().unwrap().push(mentioned_user.clone());
+
+        let author_id = Uuid::new_v4();
+        let comment = Comment::new(author_id, Some(Uuid::new_v4()), "Bom artgo".into());
+        let comment_id = comment.id();
+
+        comment_db.lock().unwrap().push(comment);
+
+        let (_, blocked_word_repository) = get_blocked_word_repository();
+        let (_, comment_report_repository) = get_comment_report_repository(comment_db.clone());
+
+        let sut = EditCommentService::new(
+            comment_repository,
+            comment_edit_repository,
+            user_repository,
+            comment_mention_repository,
+            blocked_word_repository,
+            comment_report_repository,
+        );
+
+        let res = sut
+            .exec(EditCommentParams {
+                user_id: Uuid::new_v4(),
+                user_role: Role::User,
+                comment_id,
+                content: "Não é meu comentário".into(),
+                edit_window_minutes: None,
+            })
+            .await;
+
+        assert!(res.is_err());
+
+        let comment = sut
+            .exec(EditCommentParams {
+                user_id: author_id,
+                user_role: Role::User,
+                comment_id,
+                content: "Bom artigo, @Floricultor".into(),
+                edit_window_minutes: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!("Bom artigo, @Floricultor", comment.content());
+        assert!(comment.updated_at().is_some());
+        assert_eq!(
+            "Bom artigo, @Floricultor",
+            comment_db.lock().unwrap()[0].content()
+        );
+        assert_eq!(
+            vec![(mentioned_user.id(), 12, 24)],
+            mention_db
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|mention| (mention.user_id(), mention.start(), mention.end()))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(1, comment_edit_db.lock().unwrap().len());
+        assert_eq!(
+            "Bom artgo",
+            comment_edit_db.lock().unwrap()[0].previous_content()
+        );
+
+        // the edit window is over for the author, but not for moderators
+        let res = sut
+            .exec(EditCommentParams {
+                user_id: author_id,
+                user_role: Role::User,
+                comment_id,
+                content: "Ótimo artigo".into(),
+                edit_window_minutes: Some(0),
+            })
+            .await;
+
+        assert!(res.is_err());
+
+        let moderator_id = Uuid::new_v4();
+        let comment = sut
+            .exec(EditCommentParams {
+                user_id: moderator_id,
+                user_role: Role::Coord,
+                comment_id,
+                content: "[conteúdo editado pela moderação]".into(),
+                edit_window_minutes: Some(0),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!("[conteúdo editado pela moderação]", comment.content());
+        assert_eq!(2, comment_edit_db.lock().unwrap().len());
+        assert!(mention_db.lock().unwrap().is_empty());
+        assert_eq!(moderator_id, comment_edit_db.lock().unwrap()[1].editor_id());
+    }
+}
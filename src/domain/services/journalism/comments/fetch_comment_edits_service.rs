@@ -0,0 +1,87 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::comment_edit::CommentEdit;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::comment_edit_repository::CommentEditRepositoryTrait;
+use crate::error::DomainError;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub struct FetchCommentEditsParams {
+    pub user_role: Role,
+    pub comment_id: Uuid,
+}
+
+pub struct FetchCommentEditsService<CER: CommentEditRepositoryTrait> {
+    comment_edit_repository: CER,
+}
+
+impl<CER: CommentEditRepositoryTrait> FetchCommentEditsService<CER> {
+    pub fn new(comment_edit_repository: CER) -> Self {
+        FetchCommentEditsService {
+            comment_edit_repository,
+        }
+    }
+
+    /// The edit history is only visible to users allowed to inactivate comments.
+    pub async fn exec(
+        &self,
+        params: FetchCommentEditsParams,
+    ) -> Result<Vec<CommentEdit>, DomainError> {
+        if !verify_role_has_permission(&params.user_role, RolePermissions::InactivateComment) {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        self.comment_edit_repository
+            .find_many_by_comment_id(params.comment_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Fetch Comment Edits Service, while fetching the comment's edits",
+                    err,
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::tests::repositories::comment_edit_repository::get_comment_edit_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (comment_edit_db, comment_edit_repository) = get_comment_edit_repository();
+
+        let comment_id = Uuid::new_v4();
+        let editor_id = Uuid::new_v4();
+
+        comment_edit_db.lock().unwrap().extend([
+            CommentEdit::new(comment_id, editor_id, "Primeira versão".into()),
+            CommentEdit::new(comment_id, editor_id, "Segunda versão".into()),
+            CommentEdit::new(Uuid::new_v4(), editor_id, "Outro comentário".into()),
+        ]);
+
+        let sut = FetchCommentEditsService::new(comment_edit_repository);
+
+        let res = sut
+            .exec(FetchCommentEditsParams {
+                user_role: Role::Writer,
+                comment_id,
+            })
+            .await;
+
+        assert!(res.is_err());
+
+        let edits = sut
+            .exec(FetchCommentEditsParams {
+                user_role: Role::Coord,
+                comment_id,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(2, edits.len());
+        assert_eq!("Segunda versão", edits[0].previous_content());
+    }
+}
@@ -1,9 +1,11 @@
 use uuid::Uuid;
 
 use crate::core::pagination::{PaginationParameters, PaginationResponse};
+use crate::core::query::{DateRange, Sort};
 use crate::domain::domain_entities::comment::Comment;
 use crate::domain::repositories::article_comment_repository::{
-    ArticleCommentRepositoryTrait, CommentQueryType, FindManyCommentsResponse,
+    ArticleCommentRepositoryTrait, CommentQuery, CommentQueryType, CommentSortField,
+    FindManyCommentsResponse,
 };
 use crate::domain::repositories::user_repository::UserRepositoryTrait;
 use crate::error::DomainError;
@@ -13,12 +15,15 @@ use crate::util::generate_service_internal_error;
 pub enum ServiceCommentQueryType {
     Author(String),
     Content(String),
+    CreatedAt(DateRange),
 }
 
 pub struct FetchManyCommentsParams {
     pub page: Option<u32>,
     pub per_page: Option<u32>,
-    pub query: Option<ServiceCommentQueryType>,
+    /// Comments must match all of them.
+    pub filters: Vec<ServiceCommentQueryType>,
+    pub sort: Vec<Sort<CommentSortField>>,
 }
 
 pub struct FetchManyCommentsService<ArticleCommentRepository, UserRepository>
@@ -97,7 +102,13 @@ impl<
             default_page
         };
 
-        let query = self.parse_query(params.query).await?;
+        let mut query = CommentQuery::new();
+
+        for filter in params.filters {
+            query = query.filter(self.parse_filter(filter).await?);
+        }
+
+        query.sort = params.sort;
 
         let response = self
             .article_comment_repository
@@ -107,7 +118,7 @@ impl<
                 PaginationParameters {
                     items_per_page,
                     page,
-                    query,
+                    query: Some(query),
                 },
             )
             .await
@@ -124,17 +135,11 @@ impl<
         })
     }
 
-    async fn parse_query(
+    async fn parse_filter(
         &self,
-        query: Option<ServiceCommentQueryType>,
-    ) -> Result<Option<CommentQueryType>, DomainError> {
-        if query.is_none() {
-            return Ok(None);
-        }
-
-        let query = query.unwrap();
-
-        match query {
+        filter: ServiceCommentQueryType,
+    ) -> Result<CommentQueryType, DomainError> {
+        match filter {
             ServiceCommentQueryType::Author(content) => {
                 let user = self.user_repository.find_by_nickname(&content).await;
 
@@ -150,11 +155,10 @@ impl<
 
                 let content = user.unwrap().id();
 
-                Ok(Some(CommentQueryType::Author(content)))
-            }
-            ServiceCommentQueryType::Content(content) => {
-                Ok(Some(CommentQueryType::Content(content)))
+                Ok(CommentQueryType::Author(content))
             }
+            ServiceCommentQueryType::Content(content) => Ok(CommentQueryType::Content(content)),
+            ServiceCommentQueryType::CreatedAt(range) => Ok(CommentQueryType::CreatedAt(range)),
         }
     }
 }
@@ -171,6 +175,7 @@ mod test {
     use crate::domain::repositories::article_comment_repository::MockArticleCommentRepositoryTrait;
     use crate::domain::repositories::user_repository::MockUserRepositoryTrait;
     use crate::libs::time::TimeHelper;
+    use crate::tests::query_spec::apply_query_spec;
 
     #[tokio::test]
     async fn test() {
@@ -203,10 +208,12 @@ mod test {
         db.push(Comment::new_from_existing(
             Uuid::new_v4(),
             Some(article.id()),
+            None,
             user.id(),
             "Coment 2 content here".into(),
             false,
             TimeHelper::now(),
+            None,
         ));
 
         let mut mocked_comment_repo: MockArticleCommentRepositoryTrait =
@@ -235,39 +242,24 @@ mod test {
                     query,
                 } = params;
 
-                let mut comments: Vec<Comment> = Vec::new();
-
-                if query.is_some() {
-                    match query.unwrap() {
-                        CommentQueryType::Content(content) => {
-                            for item in db.iter() {
-                                if item
-                                    .content()
-                                    .to_lowercase()
-                                    .contains(&content.to_lowercase()[..])
-                                    && (include_inactive || item.is_active())
-                                {
-                                    comments.push(item.clone());
-                                }
-                            }
-                        }
-                        CommentQueryType::Author(content) => {
-                            for item in db.iter() {
-                                if item.author_id().eq(&content)
-                                    && (include_inactive || item.is_active())
-                                {
-                                    comments.push(item.clone());
-                                }
-                            }
-                        }
-                    }
-                } else {
-                    for item in db.iter() {
-                        if include_inactive || item.is_active() {
-                            comments.push(item.clone());
-                        }
-                    }
-                }
+                let comments = apply_query_spec(
+                    db.iter()
+                        .filter(|item| include_inactive || item.is_active())
+                        .cloned()
+                        .collect(),
+                    query.as_ref(),
+                    |item, query| match query {
+                        CommentQueryType::Content(content) => item
+                            .content()
+                            .to_lowercase()
+                            .contains(&content.to_lowercase()[..]),
+                        CommentQueryType::Author(content) => item.author_id().eq(content),
+                        CommentQueryType::CreatedAt(range) => range.contains(&item.created_at()),
+                    },
+                    |a: &Comment, b: &Comment, field| match field {
+                        CommentSortField::CreatedAt => a.created_at().cmp(&b.created_at()),
+                    },
+                );
 
                 let total_of_items_before_paginating = comments.len();
 
@@ -298,7 +290,8 @@ mod test {
                 FetchManyCommentsParams {
                     page: Some(2),
                     per_page: Some(1),
-                    query: Some(ServiceCommentQueryType::Content("comment".to_string())),
+                    filters: vec![ServiceCommentQueryType::Content("comment".to_string())],
+                    sort: vec![],
                 },
             )
             .await
@@ -322,7 +315,8 @@ mod test {
                 FetchManyCommentsParams {
                     page: None,
                     per_page: None,
-                    query: None,
+                    filters: vec![],
+                    sort: vec![],
                 },
             )
             .await
@@ -347,7 +341,8 @@ mod test {
                 FetchManyCommentsParams {
                     page: None,
                     per_page: None,
-                    query: Some(ServiceCommentQueryType::Author("Vamp".to_string())),
+                    filters: vec![ServiceCommentQueryType::Author("Vamp".to_string())],
+                    sort: vec![],
                 },
             )
             .await
@@ -362,7 +357,8 @@ mod test {
                 FetchManyCommentsParams {
                     page: None,
                     per_page: None,
-                    query: Some(ServiceCommentQueryType::Author("Floricultor".to_string())),
+                    filters: vec![ServiceCommentQueryType::Author("Floricultor".to_string())],
+                    sort: vec![],
                 },
             )
             .await
@@ -377,5 +373,25 @@ mod test {
                 total_items: 3
             }
         );
+
+        // make a request combining filters
+        let res_5 = fetch_many_comments_service
+            .exec(
+                true,
+                FetchManyCommentsParams {
+                    page: None,
+                    per_page: None,
+                    filters: vec![
+                        ServiceCommentQueryType::Author("Floricultor".to_string()),
+                        ServiceCommentQueryType::Content("coment 2".to_string()),
+                    ],
+                    sort: vec![],
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(1, res_5.data.len());
+        assert!(!res_5.data[0].is_active());
     }
 }
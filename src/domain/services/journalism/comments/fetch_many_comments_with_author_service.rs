@@ -1,11 +1,17 @@
 use log::error;
 use uuid::Uuid;
 
-use crate::core::pagination::{PaginationParameters, PaginationResponse, DEFAULT_PER_PAGE};
+use crate::core::pagination::{
+    Cursor, CursorPage, CursorParameters, PaginationParameters, PaginationResponse,
+    DEFAULT_PER_PAGE,
+};
+use crate::core::query::SortDirection;
+use crate::domain::repositories::article_comment_repository::CommentSortField;
 use crate::domain::repositories::comment_user_article_repository::{
-    CommentUserArticleRepositoryTrait, FindManyCommentsWithAuthorResponse,
+    CommentUserArticleRepositoryTrait, CommentWithAuthorQuery, FindManyCommentsWithAuthorResponse,
 };
 use crate::error::DomainError;
+use crate::util::generate_service_internal_error;
 
 use crate::domain::domain_entities::comment_with_author::CommentWithAuthor;
 use crate::{LOG_SEP, R_EOL};
@@ -15,6 +21,11 @@ pub struct FetchManyArticleCommentsWithAuthorParams {
     pub per_page: Option<u32>,
 }
 
+pub struct FetchArticleCommentsByCursorParams {
+    pub cursor: Option<Cursor<Uuid>>,
+    pub per_page: Option<u32>,
+}
+
 pub struct FetchManyArticleCommentsWithAuthorService<CommentUserArticleRepository>
 where
     CommentUserArticleRepository: CommentUserArticleRepositoryTrait,
@@ -90,10 +101,93 @@ impl<CommentUserArticleRepository: CommentUserArticleRepositoryTrait>
         let FindManyCommentsWithAuthorResponse(comments, total_items) = response;
 
         Ok(FetchManyCommentsWithAuthorResponse {
-            data: comments,
+            data: comments
+                .into_iter()
+                .map(CommentWithAuthor::redacted)
+                .collect(),
             pagination: PaginationResponse::new(page, total_items, items_per_page),
         })
     }
+
+    /// Lists a comment's direct replies, from the oldest to the most recent so that they read as a conversation.
+    pub async fn exec_replies(
+        &self,
+        parent_id: Uuid,
+        params: FetchManyArticleCommentsWithAuthorParams,
+    ) -> ExecFuncReturn {
+        let items_per_page = params.per_page.unwrap_or(DEFAULT_PER_PAGE as u32);
+        let page = params.page.filter(|page| *page > 0).unwrap_or(1);
+
+        let FindManyCommentsWithAuthorResponse(replies, total_items) = self
+            .article_comment_repository
+            .find_many_replies(
+                parent_id,
+                false,
+                PaginationParameters {
+                    items_per_page,
+                    page,
+                    query: Some(
+                        CommentWithAuthorQuery::new()
+                            .sort_by(CommentSortField::CreatedAt, SortDirection::Asc),
+                    ),
+                },
+            )
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Fetch Many Comments With Author Service, while fetching the replies of a comment",
+                    err,
+                )
+            })?;
+
+        Ok(FetchManyCommentsWithAuthorResponse {
+            data: replies
+                .into_iter()
+                .map(CommentWithAuthor::redacted)
+                .collect(),
+            pagination: PaginationResponse::new(page, total_items, items_per_page),
+        })
+    }
+
+    /// Lists the comments from the most recent to the oldest, a page after (or before) the cursor.
+    pub async fn exec_by_cursor(
+        &self,
+        article_id: Uuid,
+        params: FetchArticleCommentsByCursorParams,
+    ) -> Result<CursorPage<CommentWithAuthor, Uuid>, DomainError> {
+        let items_per_page = params.per_page.unwrap_or(DEFAULT_PER_PAGE as u32);
+
+        let comments = self
+            .article_comment_repository
+            .find_many_comments_by_cursor(
+                article_id,
+                false,
+                CursorParameters {
+                    cursor: params.cursor.clone(),
+                    items_per_page,
+                    filters: vec![],
+                },
+            )
+            .await
+            .map_err(|err| {
+                error!(
+                    "{R_EOL}{LOG_SEP}{R_EOL}Error occurred on Fetch Many Comments With Author Service, while fetching comments by cursor from database: {R_EOL}{}{R_EOL}{LOG_SEP}{R_EOL}",
+                    err
+                );
+
+                DomainError::internal_err()
+            })?;
+
+        Ok(CursorPage::from_window(
+            comments
+                .into_iter()
+                .map(CommentWithAuthor::redacted)
+                .collect(),
+            params.cursor.as_ref(),
+            items_per_page,
+            |comment| (comment.created_at(), comment.id()),
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -102,10 +196,12 @@ mod test {
     use tokio;
 
     use crate::domain::domain_entities::article::Article;
+    use crate::domain::domain_entities::comment_with_author::REMOVED_COMMENT_PLACEHOLDER;
     use crate::domain::domain_entities::role::Role;
     use crate::domain::domain_entities::user::User;
     use crate::domain::repositories::comment_user_article_repository::MockCommentUserArticleRepositoryTrait;
     use crate::libs::time::TimeHelper;
+    use crate::tests::query_spec::select_by_cursor;
     use crate::tests::repositories::article_repository::get_article_repository;
 
     #[tokio::test]
@@ -146,12 +242,34 @@ mod test {
         db.push(CommentWithAuthor::new_from_existing(
             Uuid::new_v4(),
             Some(article.id()),
+            None,
             "Comment 2 content here".into(),
             false,
             TimeHelper::now(),
+            None,
             user.clone(),
         ));
 
+        let cursor_db = db.clone();
+        mocked_comment_repo
+            .expect_find_many_comments_by_cursor()
+            .returning(move |_article_id, include_inactive, params| {
+                let comments = cursor_db
+                    .iter()
+                    .filter(|comment| include_inactive || comment.is_active())
+                    .cloned()
+                    .collect();
+
+                Ok(select_by_cursor(
+                    comments,
+                    &params.filters,
+                    params.cursor.as_ref(),
+                    params.items_per_page,
+                    |_, _| true,
+                    |comment| (comment.created_at(), comment.id()),
+                ))
+            });
+
         mocked_comment_repo.expect_find_many_comments().returning(
             move |_article_id, include_inactive, params| {
                 let PaginationParameters {
@@ -212,5 +330,127 @@ mod test {
         );
         assert_eq!(response.data[0].content(), "Comment 1 content here");
         assert_eq!(response.data[1].content(), "Comment 2 content here");
+
+        let first_page = fetch_many_comments_service
+            .exec_by_cursor(
+                article_id,
+                FetchArticleCommentsByCursorParams {
+                    cursor: None,
+                    per_page: Some(1),
+                },
+            )
+            .await
+            .unwrap();
+
+        let last_page = fetch_many_comments_service
+            .exec_by_cursor(
+                article_id,
+                FetchArticleCommentsByCursorParams {
+                    cursor: first_page.pagination.next_cursor,
+                    per_page: Some(1),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(1, last_page.data.len());
+        assert_ne!(first_page.data[0].id(), last_page.data[0].id());
+        assert!(last_page.data[0].is_active());
+        assert!(last_page.pagination.next_cursor.is_none());
+        assert!(last_page.pagination.prev_cursor.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_replies() {
+        let mut mocked_comment_repo = MockCommentUserArticleRepositoryTrait::new();
+
+        let user = User::new(
+            "Floricultor".to_string(),
+            "password".to_string(),
+            Some(Role::User),
+        );
+        let article_id = Uuid::new_v4();
+
+        let mut parent = CommentWithAuthor::new_from_existing(
+            Uuid::new_v4(),
+            Some(article_id),
+            None,
+            "Comentário ofensivo".into(),
+            false,
+            TimeHelper::now(),
+            None,
+            user.clone(),
+        );
+        parent.set_replies_count(2);
+
+        let replies: Vec<CommentWithAuthor> = ["Primeira resposta", "Segunda resposta"]
+            .into_iter()
+            .map(|content| {
+                CommentWithAuthor::new_from_existing(
+                    Uuid::new_v4(),
+                    Some(article_id),
+                    Some(parent.id()),
+                    content.into(),
+                    true,
+                    TimeHelper::now(),
+                    None,
+                    user.clone(),
+                )
+            })
+            .collect();
+
+        let top_level = vec![parent.clone()];
+        mocked_comment_repo.expect_find_many_comments().returning(
+            move |_article_id, _include_inactive, _params| {
+                Ok(FindManyCommentsWithAuthorResponse(top_level.clone(), 1))
+            },
+        );
+
+        let replies_db = replies.clone();
+        mocked_comment_repo.expect_find_many_replies().returning(
+            move |parent_id, _include_inactive, params| {
+                let sort = &params.query.as_ref().unwrap().sort;
+                assert_eq!(SortDirection::Asc, sort[0].direction);
+
+                let replies: Vec<CommentWithAuthor> = replies_db
+                    .iter()
+                    .filter(|reply| reply.parent_id() == Some(parent_id))
+                    .cloned()
+                    .collect();
+                let total_items = replies.len() as u64;
+
+                Ok(FindManyCommentsWithAuthorResponse(replies, total_items))
+            },
+        );
+
+        let sut = FetchManyArticleCommentsWithAuthorService::new(mocked_comment_repo);
+
+        let comments = sut
+            .exec(
+                article_id,
+                FetchManyArticleCommentsWithAuthorParams {
+                    page: None,
+                    per_page: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(REMOVED_COMMENT_PLACEHOLDER, comments.data[0].content());
+        assert_eq!(2, comments.data[0].replies_count());
+
+        let res = sut
+            .exec_replies(
+                parent.id(),
+                FetchManyArticleCommentsWithAuthorParams {
+                    page: None,
+                    per_page: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(2, res.pagination.total_items);
+        assert_eq!(replies, res.data);
     }
 }
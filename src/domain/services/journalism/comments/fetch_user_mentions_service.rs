@@ -0,0 +1,106 @@
+use uuid::Uuid;
+
+use crate::core::pagination::{PaginationParameters, PaginationResponse, DEFAULT_PER_PAGE};
+use crate::domain::domain_entities::comment_mention::CommentMention;
+use crate::domain::repositories::comment_mention_repository::{
+    CommentMentionRepositoryTrait, FindManyCommentMentionsResponse,
+};
+use crate::error::DomainError;
+use crate::util::generate_service_internal_error;
+
+pub struct FetchUserMentionsParams {
+    pub user_id: Uuid,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
+
+#[derive(Debug)]
+pub struct FetchUserMentionsResponse {
+    pub pagination: PaginationResponse,
+    pub data: Vec<CommentMention>,
+}
+
+/// Lists where a user has been mentioned, so that they can be notified about it.
+pub struct FetchUserMentionsService<CMR: CommentMentionRepositoryTrait> {
+    comment_mention_repository: CMR,
+}
+
+impl<CMR: CommentMentionRepositoryTrait> FetchUserMentionsService<CMR> {
+    pub fn new(comment_mention_repository: CMR) -> Self {
+        FetchUserMentionsService {
+            comment_mention_repository,
+        }
+    }
+
+    pub async fn exec(
+        &self,
+        params: FetchUserMentionsParams,
+    ) -> Result<FetchUserMentionsResponse, DomainError> {
+        let items_per_page = params.per_page.unwrap_or(DEFAULT_PER_PAGE as u32);
+        let page = params.page.filter(|page| *page > 0).unwrap_or(1);
+
+        let FindManyCommentMentionsResponse(mentions, total_items) = self
+            .comment_mention_repository
+            .find_many_by_user_id(
+                params.user_id,
+                PaginationParameters {
+                    page,
+                    items_per_page,
+                    query: None,
+                },
+            )
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Fetch User Mentions Service, while fetching the user's mentions",
+                    err,
+                )
+            })?;
+
+        Ok(FetchUserMentionsResponse {
+            data: mentions,
+            pagination: PaginationResponse::new(page, total_items, items_per_page),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::tests::repositories::comment_mention_repository::get_comment_mention_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (mention_db, comment_mention_repository) = get_comment_mention_repository();
+
+        let user_id = Uuid::new_v4();
+
+        mention_db.lock().unwrap().extend([
+            CommentMention::new(Uuid::new_v4(), user_id, 0, 12),
+            CommentMention::new(Uuid::new_v4(), Uuid::new_v4(), 0, 5),
+            CommentMention::new(Uuid::new_v4(), user_id, 4, 16),
+        ]);
+
+        let sut = FetchUserMentionsService::new(comment_mention_repository);
+
+        let res = sut
+            .exec(FetchUserMentionsParams {
+                user_id,
+                page: None,
+                per_page: Some(1),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            res.pagination,
+            PaginationResponse {
+                current_page: 1,
+                total_pages: 2,
+                total_items: 2
+            }
+        );
+        assert_eq!(4, res.data[0].start());
+    }
+}
@@ -1,4 +1,7 @@
 pub mod comment_on_article_service;
 pub mod delete_comment_service;
+pub mod edit_comment_service;
+pub mod fetch_comment_edits_service;
 pub mod fetch_many_comments_service;
 pub mod fetch_many_comments_with_author_service;
+pub mod fetch_user_mentions_service;
@@ -2,3 +2,5 @@ pub mod article_tags;
 pub mod articles;
 pub mod comments;
 pub mod free_badges;
+pub mod reactions;
+pub mod series;
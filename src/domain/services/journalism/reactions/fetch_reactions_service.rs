@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::domain::domain_entities::comment_with_author::CommentWithAuthor;
+use crate::domain::domain_entities::reaction::{ReactionSummary, ReactionTarget};
+use crate::domain::repositories::reaction_repository::ReactionRepositoryTrait;
+use crate::error::DomainError;
+use crate::util::generate_service_internal_error;
+
+pub struct FetchReactionsParams {
+    /// The user whose own reactions are told apart, if authenticated.
+    pub viewer_id: Option<Uuid>,
+    pub target: ReactionTarget,
+    pub target_ids: Vec<Uuid>,
+}
+
+pub struct FetchReactionsService<RR: ReactionRepositoryTrait> {
+    reaction_repository: RR,
+}
+
+impl<RR: ReactionRepositoryTrait> FetchReactionsService<RR> {
+    pub fn new(reaction_repository: RR) -> Self {
+        FetchReactionsService {
+            reaction_repository,
+        }
+    }
+
+    pub async fn exec(
+        &self,
+        params: FetchReactionsParams,
+    ) -> Result<HashMap<Uuid, ReactionSummary>, DomainError> {
+        summarize_reactions(
+            &self.reaction_repository,
+            params.viewer_id,
+            params.target,
+            params.target_ids,
+        )
+        .await
+    }
+
+    /// Sets the reactions of each of the comments.
+    pub async fn exec_on_comments(
+        &self,
+        viewer_id: Option<Uuid>,
+        mut comments: Vec<CommentWithAuthor>,
+    ) -> Result<Vec<CommentWithAuthor>, DomainError> {
+        let mut summaries = summarize_reactions(
+            &self.reaction_repository,
+            viewer_id,
+            ReactionTarget::Comment,
+            comments.iter().map(|comment| comment.id()).collect(),
+        )
+        .await?;
+
+        for comment in comments.iter_mut() {
+            comment.set_reactions(summaries.remove(&comment.id()).unwrap_or_default());
+        }
+
+        Ok(comments)
+    }
+}
+
+/// Gathers the stored reaction counts of each target, along with the viewer's own reaction to it. Every target gets
+/// a summary, even if it hasn't been reacted to.
+pub async fn summarize_reactions<RR: ReactionRepositoryTrait>(
+    reaction_repository: &RR,
+    viewer_id: Option<Uuid>,
+    target: ReactionTarget,
+    target_ids: Vec<Uuid>,
+) -> Result<HashMap<Uuid, ReactionSummary>, DomainError> {
+    let mut counts = reaction_repository
+        .find_counts(target, target_ids.clone())
+        .await
+        .map_err(|err| {
+            generate_service_internal_error(
+                "Error occurred while fetching the reaction counts",
+                err,
+            )
+        })?;
+
+    let own_reactions = match viewer_id {
+        None => vec![],
+        Some(viewer_id) => reaction_repository
+            .find_many_by_user_id(viewer_id, target, target_ids.clone())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred while fetching the viewer's reactions",
+                    err,
+                )
+            })?,
+    };
+
+    let mut summaries = target_ids
+        .into_iter()
+        .map(|target_id| {
+            let summary = ReactionSummary {
+                counts: counts.remove(&target_id).unwrap_or_default(),
+                own: None,
+            };
+
+            (target_id, summary)
+        })
+        .collect::<HashMap<Uuid, ReactionSummary>>();
+
+    for reaction in own_reactions {
+        if let Some(summary) = summaries.get_mut(&reaction.target_id()) {
+            summary.own = Some(reaction.kind().to_owned());
+        }
+    }
+
+    Ok(summaries)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::domain::domain_entities::reaction::Reaction;
+    use crate::tests::repositories::reaction_repository::get_reaction_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (reaction_db, reaction_repository) = get_reaction_repository();
+
+        let viewer_id = Uuid::new_v4();
+        let comment_id = Uuid::new_v4();
+        let other_comment_id = Uuid::new_v4();
+
+        reaction_db.lock().unwrap().extend([
+            Reaction::new(
+                viewer_id,
+                ReactionTarget::Comment,
+                comment_id,
+                "heart".into(),
+            ),
+            Reaction::new(
+                Uuid::new_v4(),
+                ReactionTarget::Comment,
+                comment_id,
+                "heart".into(),
+            ),
+            Reaction::new(
+                Uuid::new_v4(),
+                ReactionTarget::Comment,
+                comment_id,
+                "wow".into(),
+            ),
+            Reaction::new(viewer_id, ReactionTarget::Article, comment_id, "sad".into()),
+        ]);
+
+        let sut = FetchReactionsService::new(reaction_repository);
+
+        let summaries = sut
+            .exec(FetchReactionsParams {
+                viewer_id: Some(viewer_id),
+                target: ReactionTarget::Comment,
+                target_ids: vec![comment_id, other_comment_id],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(2, summaries.len());
+        assert_eq!(Some(&2), summaries[&comment_id].counts.get("heart"));
+        assert_eq!(Some(&1), summaries[&comment_id].counts.get("wow"));
+        assert_eq!(None, summaries[&comment_id].counts.get("sad"));
+        assert_eq!(Some("heart"), summaries[&comment_id].own.as_deref());
+        assert_eq!(ReactionSummary::default(), summaries[&other_comment_id]);
+
+        let summaries = sut
+            .exec(FetchReactionsParams {
+                viewer_id: None,
+                target: ReactionTarget::Comment,
+                target_ids: vec![comment_id],
+            })
+            .await
+            .unwrap();
+
+        assert!(summaries[&comment_id].own.is_none());
+    }
+}
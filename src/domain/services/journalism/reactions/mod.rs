@@ -0,0 +1,2 @@
+pub mod fetch_reactions_service;
+pub mod react_service;
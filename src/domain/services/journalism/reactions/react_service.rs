@@ -0,0 +1,252 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::reaction::{Reaction, ReactionSummary, ReactionTarget};
+use crate::domain::domain_entities::role::Role;
+use crate::domain::politics::reaction_politics::ReactionPolitics;
+use crate::domain::repositories::{
+    article_repository::ArticleRepositoryTrait, comment_repository::CommentRepositoryTrait,
+    reaction_repository::ReactionRepositoryTrait,
+};
+use crate::error::DomainError;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+use super::fetch_reactions_service::summarize_reactions;
+
+pub struct ReactParams {
+    pub user_id: Uuid,
+    /// Decides whether unapproved articles can be reacted to.
+    pub user_role: Role,
+    pub target: ReactionTarget,
+    pub target_id: Uuid,
+    /// The user's new reaction to the target, or none to remove it.
+    pub reaction: Option<String>,
+    /// The configured reactions, see `ReactionPolitics::allowed`.
+    pub allowed_reactions: Vec<String>,
+}
+
+pub struct ReactService<RR, AR, CR>
+where
+    RR: ReactionRepositoryTrait,
+    AR: ArticleRepositoryTrait,
+    CR: CommentRepositoryTrait,
+{
+    reaction_repository: RR,
+    article_repository: AR,
+    comment_repository: CR,
+}
+
+impl<RR, AR, CR> ReactService<RR, AR, CR>
+where
+    RR: ReactionRepositoryTrait,
+    AR: ArticleRepositoryTrait,
+    CR: CommentRepositoryTrait,
+{
+    pub fn new(reaction_repository: RR, article_repository: AR, comment_repository: CR) -> Self {
+        ReactService {
+            reaction_repository,
+            article_repository,
+            comment_repository,
+        }
+    }
+
+    /// Sets, replaces or removes the user's reaction to the target, returning the target's updated reactions.
+    pub async fn exec(&self, params: ReactParams) -> Result<ReactionSummary, DomainError> {
+        self.ensure_target_is_visible(&params).await?;
+
+        match params.reaction {
+            Some(reaction) => {
+                let reaction = ReactionPolitics::validate(&params.allowed_reactions, &reaction)?;
+
+                self.reaction_repository
+                    .set(Reaction::new(
+                        params.user_id,
+                        params.target,
+                        params.target_id,
+                        reaction,
+                    ))
+                    .await
+                    .map_err(|err| {
+                        generate_service_internal_error(
+                            "Error occurred on React Service, while saving the reaction",
+                            err,
+                        )
+                    })?;
+            }
+            None => {
+                self.reaction_repository
+                    .remove(params.user_id, params.target, params.target_id)
+                    .await
+                    .map_err(|err| {
+                        generate_service_internal_error(
+                            "Error occurred on React Service, while removing the reaction",
+                            err,
+                        )
+                    })?;
+            }
+        }
+
+        let mut summaries = summarize_reactions(
+            &self.reaction_repository,
+            Some(params.user_id),
+            params.target,
+            vec![params.target_id],
+        )
+        .await?;
+
+        Ok(summaries.remove(&params.target_id).unwrap_or_default())
+    }
+
+    /// Only approved articles and active comments can be reacted to, but for team members allowed to see unapproved
+    /// articles.
+    async fn ensure_target_is_visible(&self, params: &ReactParams) -> Result<(), DomainError> {
+        let is_visible = match params.target {
+            ReactionTarget::Article => self
+                .article_repository
+                .find_by_id(params.target_id)
+                .await
+                .map_err(|err| {
+                    generate_service_internal_error(
+                        "Error occurred on React Service, while fetching the article",
+                        err,
+                    )
+                })?
+                .is_some_and(|article| {
+                    article.approved()
+                        || verify_role_has_permission(
+                            &params.user_role,
+                            RolePermissions::SeeUnapprovedArticle,
+                        )
+                }),
+            ReactionTarget::Comment => self
+                .comment_repository
+                .find_by_id(params.target_id)
+                .await
+                .map_err(|err| {
+                    generate_service_internal_error(
+                        "Error occurred on React Service, while fetching the comment",
+                        err,
+                    )
+                })?
+                .is_some_and(|comment| comment.is_active()),
+        };
+
+        if !is_visible {
+            return Err(DomainError::resource_not_found_err());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::domain::domain_entities::article::Article;
+    use crate::domain::domain_entities::comment::Comment;
+    use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::comment_repository::get_comment_repository;
+    use crate::tests::repositories::reaction_repository::get_reaction_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (reaction_db, reaction_repository) = get_reaction_repository();
+        let (article_db, article_repository) = get_article_repository();
+        let (comment_db, comment_repository) = get_comment_repository();
+
+        let user_id = Uuid::new_v4();
+
+        let mut article = Article::new(
+            user_id,
+            "title".into(),
+            "content".into(),
+            "cover_url".into(),
+            1,
+            "Foo".into(),
+        );
+        article.set_approved(true);
+        let article_id = article.id();
+
+        let unapproved_article = Article::new(
+            user_id,
+            "other title".into(),
+            "content".into(),
+            "cover_url".into(),
+            1,
+            "Foo".into(),
+        );
+        let unapproved_article_id = unapproved_article.id();
+
+        article_db
+            .lock()
+            .unwrap()
+            .extend([article, unapproved_article]);
+
+        let mut comment = Comment::new(Uuid::new_v4(), Some(article_id), "Comentário".into());
+        comment.set_is_active(false);
+        let inactive_comment_id = comment.id();
+        comment_db.lock().unwrap().push(comment);
+
+        let sut = ReactService::new(reaction_repository, article_repository, comment_repository);
+
+        let params =
+            |target: ReactionTarget, target_id: Uuid, reaction: Option<&str>| ReactParams {
+                user_id,
+                user_role: Role::User,
+                target,
+                target_id,
+                reaction: reaction.map(Into::into),
+                allowed_reactions: ReactionPolitics::allowed(None),
+            };
+
+        let summary = sut
+            .exec(params(ReactionTarget::Article, article_id, Some("Heart")))
+            .await
+            .unwrap();
+
+        assert_eq!(Some(&1), summary.counts.get("heart"));
+        assert_eq!(Some("heart"), summary.own.as_deref());
+
+        // reacting again replaces the previous reaction
+        let summary = sut
+            .exec(params(ReactionTarget::Article, article_id, Some("wow")))
+            .await
+            .unwrap();
+
+        assert_eq!(None, summary.counts.get("heart"));
+        assert_eq!(Some(&1), summary.counts.get("wow"));
+        assert_eq!(1, reaction_db.lock().unwrap().len());
+
+        let res = sut
+            .exec(params(ReactionTarget::Article, article_id, Some("angry")))
+            .await;
+        assert!(res.is_err());
+
+        let res = sut
+            .exec(params(
+                ReactionTarget::Article,
+                unapproved_article_id,
+                Some("heart"),
+            ))
+            .await;
+        assert!(res.is_err());
+
+        let res = sut
+            .exec(params(
+                ReactionTarget::Comment,
+                inactive_comment_id,
+                Some("heart"),
+            ))
+            .await;
+        assert!(res.is_err());
+
+        let summary = sut
+            .exec(params(ReactionTarget::Article, article_id, None))
+            .await
+            .unwrap();
+
+        assert!(summary.counts.is_empty());
+        assert!(summary.own.is_none());
+        assert!(reaction_db.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,165 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::role::Role;
+use crate::domain::domain_entities::series::Series;
+use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::domain::repositories::series_repository::SeriesRepositoryTrait;
+use crate::domain::services::journalism::series::validate_series_articles;
+use crate::error::DomainError;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub struct CreateSeriesParams {
+    pub staff_id: Uuid,
+    pub staff_role: Role,
+    pub title: String,
+    pub description: String,
+    pub articles_ids: Vec<Uuid>,
+}
+
+pub struct CreateSeriesService<SR, AR>
+where
+    SR: SeriesRepositoryTrait,
+    AR: ArticleRepositoryTrait,
+{
+    series_repository: SR,
+    article_repository: AR,
+}
+
+impl<SR, AR> CreateSeriesService<SR, AR>
+where
+    SR: SeriesRepositoryTrait,
+    AR: ArticleRepositoryTrait,
+{
+    pub fn new(series_repository: SR, article_repository: AR) -> Self {
+        CreateSeriesService {
+            series_repository,
+            article_repository,
+        }
+    }
+
+    pub async fn exec(&self, params: CreateSeriesParams) -> Result<Series, DomainError> {
+        if !verify_role_has_permission(&params.staff_role, RolePermissions::CreateSeries) {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        validate_series_articles(
+            &self.article_repository,
+            &self.series_repository,
+            None,
+            &params.articles_ids,
+        )
+        .await?;
+
+        let series = Series::new(params.staff_id, params.title, params.description);
+
+        let series = self
+            .series_repository
+            .create(series)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Create Series Service, while creating the series on the database",
+                    err,
+                )
+            })?;
+
+        self.series_repository
+            .set_articles(series.id(), params.articles_ids)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Create Series Service, while setting the series' articles",
+                    err,
+                )
+            })?;
+
+        Ok(series)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::domain::domain_entities::article::Article;
+    use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::series_repository::get_series_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (articles_db, article_repository) = get_article_repository();
+        let (series_db, series_articles_db, series_repository) =
+            get_series_repository(articles_db.clone());
+
+        let staff_id = Uuid::new_v4();
+
+        let first_article = Article::new(
+            staff_id,
+            "Guia de Wired parte 1".into(),
+            "Conteúdo".into(),
+            "url".into(),
+            1,
+            "Guias".into(),
+        );
+
+        let second_article = Article::new(
+            staff_id,
+            "Guia de Wired parte 2".into(),
+            "Conteúdo".into(),
+            "url".into(),
+            1,
+            "Guias".into(),
+        );
+
+        articles_db.lock().unwrap().push(first_article.clone());
+        articles_db.lock().unwrap().push(second_article.clone());
+
+        let service = CreateSeriesService::new(series_repository, article_repository);
+
+        let unauthorized_result = service
+            .exec(CreateSeriesParams {
+                staff_id,
+                staff_role: Role::Writer,
+                title: "Guia de Wired".into(),
+                description: "Tudo sobre wired.".into(),
+                articles_ids: vec![],
+            })
+            .await;
+
+        assert!(matches!(
+            unauthorized_result,
+            Err(DomainError::Unauthorized(_))
+        ));
+
+        let series = service
+            .exec(CreateSeriesParams {
+                staff_id,
+                staff_role: Role::Editor,
+                title: "Guia de Wired".into(),
+                description: "Tudo sobre wired.".into(),
+                articles_ids: vec![second_article.id(), first_article.id()],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(1, series_db.lock().unwrap().len());
+        assert_eq!(
+            Some(&vec![second_article.id(), first_article.id()]),
+            series_articles_db.lock().unwrap().get(&series.id())
+        );
+
+        let duplicated_membership_result = service
+            .exec(CreateSeriesParams {
+                staff_id,
+                staff_role: Role::Editor,
+                title: "Outro guia".into(),
+                description: "Outro guia.".into(),
+                articles_ids: vec![first_article.id()],
+            })
+            .await;
+
+        assert!(matches!(
+            duplicated_membership_result,
+            Err(DomainError::BadRequest(_))
+        ));
+    }
+}
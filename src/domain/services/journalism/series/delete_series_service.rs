@@ -0,0 +1,90 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::series_repository::SeriesRepositoryTrait;
+use crate::error::DomainError;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub struct DeleteSeriesParams {
+    pub staff_role: Role,
+    pub series_id: Uuid,
+}
+
+pub struct DeleteSeriesService<SR: SeriesRepositoryTrait> {
+    series_repository: SR,
+}
+
+impl<SR: SeriesRepositoryTrait> DeleteSeriesService<SR> {
+    pub fn new(series_repository: SR) -> Self {
+        DeleteSeriesService { series_repository }
+    }
+
+    pub async fn exec(&self, params: DeleteSeriesParams) -> Result<(), DomainError> {
+        if !verify_role_has_permission(&params.staff_role, RolePermissions::DeleteSeries) {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        let series = match self
+            .series_repository
+            .find_by_id(params.series_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Delete Series Service, while finding series by id",
+                    err,
+                )
+            })? {
+            None => return Err(DomainError::resource_not_found_err()),
+            Some(series) => series,
+        };
+
+        self.series_repository.delete(series).await.map_err(|err| {
+            generate_service_internal_error(
+                "Error occurred on Delete Series Service, while deleting the series from the database",
+                err,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::domain::domain_entities::series::Series;
+    use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::series_repository::get_series_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (articles_db, _) = get_article_repository();
+        let (series_db, _, series_repository) = get_series_repository(articles_db);
+
+        let series = Series::new(Uuid::new_v4(), "Guia".into(), "Descrição".into());
+        series_db.lock().unwrap().push(series.clone());
+
+        let service = DeleteSeriesService::new(series_repository);
+
+        let unauthorized_result = service
+            .exec(DeleteSeriesParams {
+                staff_role: Role::Editor,
+                series_id: series.id(),
+            })
+            .await;
+
+        assert!(matches!(
+            unauthorized_result,
+            Err(DomainError::Unauthorized(_))
+        ));
+        assert_eq!(1, series_db.lock().unwrap().len());
+
+        service
+            .exec(DeleteSeriesParams {
+                staff_role: Role::Principal,
+                series_id: series.id(),
+            })
+            .await
+            .unwrap();
+
+        assert!(series_db.lock().unwrap().is_empty());
+    }
+}
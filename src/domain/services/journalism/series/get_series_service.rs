@@ -0,0 +1,138 @@
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::domain_entities::series::Series;
+use crate::domain::domain_entities::slug::Slug;
+use crate::domain::repositories::series_repository::SeriesRepositoryTrait;
+use crate::error::DomainError;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub struct GetSeriesParams<'exec> {
+    pub series_slug: Slug,
+    pub user_role: Option<&'exec Role>,
+}
+
+#[derive(Debug)]
+pub struct GetSeriesResponse {
+    pub series: Series,
+    pub articles: Vec<Article>,
+}
+
+pub struct GetSeriesService<SR: SeriesRepositoryTrait> {
+    series_repository: SR,
+}
+
+impl<SR: SeriesRepositoryTrait> GetSeriesService<SR> {
+    pub fn new(series_repository: SR) -> Self {
+        GetSeriesService { series_repository }
+    }
+
+    pub async fn exec(
+        &self,
+        params: GetSeriesParams<'_>,
+    ) -> Result<GetSeriesResponse, DomainError> {
+        let series = match self
+            .series_repository
+            .find_by_slug(&params.series_slug)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Get Series Service, while finding series by slug",
+                    err,
+                )
+            })? {
+            None => return Err(DomainError::resource_not_found_err()),
+            Some(series) => series,
+        };
+
+        let user_can_see_unapproved = params.user_role.is_some_and(|role| {
+            verify_role_has_permission(role, RolePermissions::SeeUnapprovedArticle)
+        });
+
+        let articles = self
+            .series_repository
+            .find_articles(series.id(), !user_can_see_unapproved)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Get Series Service, while fetching the series' articles",
+                    err,
+                )
+            })?;
+
+        Ok(GetSeriesResponse { series, articles })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use uuid::Uuid;
+
+    use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::series_repository::get_series_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (articles_db, _) = get_article_repository();
+        let (series_db, series_articles_db, series_repository) =
+            get_series_repository(articles_db.clone());
+
+        let author_id = Uuid::new_v4();
+
+        let mut first_article = Article::new(
+            author_id,
+            "Parte 1".into(),
+            "Conteúdo".into(),
+            "url".into(),
+            1,
+            "Guias".into(),
+        );
+        first_article.set_approved(true);
+
+        let draft = Article::new(
+            author_id,
+            "Parte 2".into(),
+            "Conteúdo".into(),
+            "url".into(),
+            1,
+            "Guias".into(),
+        );
+
+        articles_db.lock().unwrap().push(first_article.clone());
+        articles_db.lock().unwrap().push(draft.clone());
+
+        let series = Series::new(author_id, "Guia".into(), "Descrição".into());
+        series_db.lock().unwrap().push(series.clone());
+        series_articles_db
+            .lock()
+            .unwrap()
+            .insert(series.id(), vec![first_article.id(), draft.id()]);
+
+        let service = GetSeriesService::new(series_repository);
+
+        let GetSeriesResponse {
+            series: found_series,
+            articles,
+        } = service
+            .exec(GetSeriesParams {
+                series_slug: series.slug(),
+                user_role: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(series.id(), found_series.id());
+        assert_eq!(1, articles.len());
+        assert_eq!(first_article.id(), articles[0].id());
+
+        let GetSeriesResponse { articles, .. } = service
+            .exec(GetSeriesParams {
+                series_slug: series.slug(),
+                user_role: Some(&Role::Editor),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(2, articles.len());
+    }
+}
@@ -0,0 +1,63 @@
+pub mod create_series_service;
+pub mod delete_series_service;
+pub mod get_series_service;
+pub mod update_series_service;
+
+use uuid::Uuid;
+
+use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::domain::repositories::series_repository::SeriesRepositoryTrait;
+use crate::error::DomainError;
+use crate::util::generate_service_internal_error;
+
+/// Checks the articles a series is set to have: each of them must exist, be listed once and not belong to another
+/// series. `series_id` is the one being updated, if any, whose own articles are fine.
+pub async fn validate_series_articles<AR: ArticleRepositoryTrait, SR: SeriesRepositoryTrait>(
+    article_repository: &AR,
+    series_repository: &SR,
+    series_id: Option<Uuid>,
+    articles_ids: &[Uuid],
+) -> Result<(), DomainError> {
+    for (index, article_id) in articles_ids.iter().enumerate() {
+        if articles_ids[..index].contains(article_id) {
+            return Err(DomainError::bad_request_err().with_message(format!(
+                "Article with id '{}' is listed more than once.",
+                article_id
+            )));
+        }
+
+        let article = article_repository
+            .find_by_id(*article_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred while finding a series' article by id",
+                    err,
+                )
+            })?;
+
+        if article.is_none() {
+            return Err(DomainError::bad_request_err()
+                .with_message(format!("Article with id '{}' not found.", article_id)));
+        }
+
+        let current_series = series_repository
+            .find_by_article_id(*article_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred while finding series by article id",
+                    err,
+                )
+            })?;
+
+        if current_series.is_some_and(|current_series| Some(current_series.id()) != series_id) {
+            return Err(DomainError::bad_request_err().with_message(format!(
+                "Article with id '{}' already belongs to another series.",
+                article_id
+            )));
+        }
+    }
+
+    Ok(())
+}
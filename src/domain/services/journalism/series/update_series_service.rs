@@ -0,0 +1,174 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::role::Role;
+use crate::domain::domain_entities::series::Series;
+use crate::domain::repositories::article_repository::ArticleRepositoryTrait;
+use crate::domain::repositories::series_repository::SeriesRepositoryTrait;
+use crate::domain::services::journalism::series::validate_series_articles;
+use crate::error::DomainError;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub struct UpdateSeriesParams {
+    pub staff_role: Role,
+    pub series_id: Uuid,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub articles_ids: Option<Vec<Uuid>>,
+}
+
+pub struct UpdateSeriesService<SR, AR>
+where
+    SR: SeriesRepositoryTrait,
+    AR: ArticleRepositoryTrait,
+{
+    series_repository: SR,
+    article_repository: AR,
+}
+
+impl<SR, AR> UpdateSeriesService<SR, AR>
+where
+    SR: SeriesRepositoryTrait,
+    AR: ArticleRepositoryTrait,
+{
+    pub fn new(series_repository: SR, article_repository: AR) -> Self {
+        UpdateSeriesService {
+            series_repository,
+            article_repository,
+        }
+    }
+
+    pub async fn exec(&self, params: UpdateSeriesParams) -> Result<Series, DomainError> {
+        if !verify_role_has_permission(&params.staff_role, RolePermissions::UpdateSeries) {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        let mut series = match self
+            .series_repository
+            .find_by_id(params.series_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Update Series Service, while finding series by id",
+                    err,
+                )
+            })? {
+            None => return Err(DomainError::resource_not_found_err()),
+            Some(series) => series,
+        };
+
+        if let Some(articles_ids) = params.articles_ids {
+            validate_series_articles(
+                &self.article_repository,
+                &self.series_repository,
+                Some(series.id()),
+                &articles_ids,
+            )
+            .await?;
+
+            self.series_repository
+                .set_articles(series.id(), articles_ids)
+                .await
+                .map_err(|err| {
+                    generate_service_internal_error(
+                        "Error occurred on Update Series Service, while setting the series' articles",
+                        err,
+                    )
+                })?;
+        }
+
+        if let Some(title) = params.title {
+            series.set_title(title);
+        }
+
+        if let Some(description) = params.description {
+            series.set_description(description);
+        }
+
+        self.series_repository.save(series).await.map_err(|err| {
+            generate_service_internal_error(
+                "Error occurred on Update Series Service, while saving the series on the database",
+                err,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::domain::domain_entities::article::Article;
+    use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::series_repository::get_series_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (articles_db, article_repository) = get_article_repository();
+        let (series_db, series_articles_db, series_repository) =
+            get_series_repository(articles_db.clone());
+
+        let staff_id = Uuid::new_v4();
+
+        let first_article = Article::new(
+            staff_id,
+            "Parte 1".into(),
+            "Conteúdo".into(),
+            "url".into(),
+            1,
+            "Guias".into(),
+        );
+
+        let second_article = Article::new(
+            staff_id,
+            "Parte 2".into(),
+            "Conteúdo".into(),
+            "url".into(),
+            1,
+            "Guias".into(),
+        );
+
+        articles_db.lock().unwrap().push(first_article.clone());
+        articles_db.lock().unwrap().push(second_article.clone());
+
+        let series = Series::new(staff_id, "Guia".into(), "Descrição".into());
+        series_db.lock().unwrap().push(series.clone());
+        series_articles_db
+            .lock()
+            .unwrap()
+            .insert(series.id(), vec![first_article.id()]);
+
+        let service = UpdateSeriesService::new(series_repository, article_repository);
+
+        let result = service
+            .exec(UpdateSeriesParams {
+                staff_role: Role::Editor,
+                series_id: series.id(),
+                title: Some("Guia de Wired".into()),
+                description: None,
+                articles_ids: Some(vec![first_article.id(), second_article.id()]),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!("Guia de Wired", result.title());
+        assert_ne!(series.slug(), result.slug());
+        assert_eq!(
+            Some(&vec![first_article.id(), second_article.id()]),
+            series_articles_db.lock().unwrap().get(&series.id())
+        );
+
+        let unauthorized_result = service
+            .exec(UpdateSeriesParams {
+                staff_role: Role::User,
+                series_id: series.id(),
+                title: Some("Guia".into()),
+                description: None,
+                articles_ids: None,
+            })
+            .await;
+
+        assert!(matches!(
+            unauthorized_result,
+            Err(DomainError::Unauthorized(_))
+        ));
+    }
+}
@@ -0,0 +1,172 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::media::MediaUsage;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::media_repository::MediaRepositoryTrait;
+use crate::domain::storage::file_storage::FileStorageTrait;
+use crate::error::DomainError;
+use crate::util::generate_service_internal_error;
+use crate::util::{verify_role_has_permission, RolePermissions};
+
+pub struct DeleteMediaParams {
+    pub user_id: Uuid,
+    pub user_role: Role,
+    pub media_id: Uuid,
+}
+
+pub struct DeleteMediaService<MR: MediaRepositoryTrait, FS: FileStorageTrait> {
+    media_repository: MR,
+    file_storage: FS,
+}
+
+impl<MR: MediaRepositoryTrait, FS: FileStorageTrait> DeleteMediaService<MR, FS> {
+    pub fn new(media_repository: MR, file_storage: FS) -> Self {
+        DeleteMediaService {
+            media_repository,
+            file_storage,
+        }
+    }
+
+    pub async fn exec(&self, params: DeleteMediaParams) -> Result<(), DomainError> {
+        let media = self
+            .media_repository
+            .find_by_id(params.media_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Delete Media Service, while fetching the media",
+                    err,
+                )
+            })?
+            .ok_or(DomainError::resource_not_found_err())?;
+
+        if media.uploader_id() != params.user_id
+            && !verify_role_has_permission(&params.user_role, RolePermissions::DeleteMedia)
+        {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        let usages = self
+            .media_repository
+            .find_usages(&self.file_storage.url(media.storage_key()))
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Delete Media Service, while fetching the media's usages",
+                    err,
+                )
+            })?;
+
+        if !usages.is_empty() {
+            let usages = usages
+                .iter()
+                .map(|usage| match usage {
+                    MediaUsage::Article { title, .. } => format!("article '{title}'"),
+                    MediaUsage::Announcement { id } => format!("announcement '{id}'"),
+                    MediaUsage::FreeBadge { code, .. } => format!("free badge '{code}'"),
+                })
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            return Err(DomainError::bad_request_err().with_message(format!(
+                "Media can't be deleted while it's still in use by: {usages}."
+            )));
+        }
+
+        let storage_key = media.storage_key().to_owned();
+
+        self.media_repository.delete(media).await.map_err(|err| {
+            generate_service_internal_error(
+                "Error occurred on Delete Media Service, while deleting the media",
+                err,
+            )
+        })?;
+
+        self.file_storage.delete(&storage_key).await.map_err(|err| {
+            generate_service_internal_error(
+                "Error occurred on Delete Media Service, while deleting the file",
+                err,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::domain::domain_entities::media::{Media, MediaFileInfo};
+    use crate::tests::file_storage::{get_file_storage, FAKE_STORAGE_URL};
+    use crate::tests::repositories::media_repository::get_media_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (media_db, usages_db, media_repository) = get_media_repository();
+        let (files, file_storage) = get_file_storage();
+
+        let uploader_id = Uuid::new_v4();
+        let media = Media::new(
+            uploader_id,
+            "banner.png".into(),
+            MediaFileInfo {
+                mime_type: "image/png".into(),
+                extension: "png".into(),
+                size: 1024,
+                width: 100,
+                height: 100,
+            },
+            "".into(),
+            vec![],
+        );
+        media_db.lock().unwrap().push(media.clone());
+        files
+            .lock()
+            .unwrap()
+            .insert(media.storage_key().to_owned(), vec![0]);
+
+        let media_url = format!("{FAKE_STORAGE_URL}/{}", media.storage_key());
+        usages_db.lock().unwrap().insert(
+            media_url.clone(),
+            vec![MediaUsage::Announcement { id: Uuid::new_v4() }],
+        );
+
+        let sut = DeleteMediaService::new(media_repository, file_storage);
+
+        let other_writer_result = sut
+            .exec(DeleteMediaParams {
+                user_id: Uuid::new_v4(),
+                user_role: Role::Writer,
+                media_id: media.id(),
+            })
+            .await;
+
+        assert!(matches!(
+            other_writer_result,
+            Err(DomainError::Unauthorized(_))
+        ));
+
+        let in_use_result = sut
+            .exec(DeleteMediaParams {
+                user_id: uploader_id,
+                user_role: Role::Writer,
+                media_id: media.id(),
+            })
+            .await;
+
+        assert!(matches!(in_use_result, Err(DomainError::BadRequest(_))));
+        assert_eq!(1, media_db.lock().unwrap().len());
+
+        usages_db.lock().unwrap().remove(&media_url);
+
+        sut.exec(DeleteMediaParams {
+            user_id: Uuid::new_v4(),
+            user_role: Role::Editor,
+            media_id: media.id(),
+        })
+        .await
+        .unwrap();
+
+        assert!(media_db.lock().unwrap().is_empty());
+        assert!(files.lock().unwrap().is_empty());
+    }
+}
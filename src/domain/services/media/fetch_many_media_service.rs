@@ -0,0 +1,176 @@
+use crate::core::pagination::{PaginationParameters, PaginationResponse, DEFAULT_PER_PAGE};
+use crate::core::query::Sort;
+use crate::domain::domain_entities::media::Media;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::media_repository::{
+    FindManyMediaResponse, MediaQuery, MediaQueryType, MediaRepositoryTrait, MediaSortField,
+};
+use crate::error::DomainError;
+use crate::util::generate_service_internal_error;
+use crate::util::{verify_role_has_permission, RolePermissions};
+
+pub struct FetchManyMediaParams {
+    pub user_role: Role,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+    /// Media must match all of them.
+    pub filters: Vec<MediaQueryType>,
+    pub sort: Vec<Sort<MediaSortField>>,
+}
+
+#[derive(Debug)]
+pub struct FetchManyMediaResponse {
+    pub pagination: PaginationResponse,
+    pub data: Vec<Media>,
+}
+
+pub struct FetchManyMediaService<MR: MediaRepositoryTrait> {
+    media_repository: MR,
+}
+
+impl<MR: MediaRepositoryTrait> FetchManyMediaService<MR> {
+    pub fn new(media_repository: MR) -> Self {
+        FetchManyMediaService { media_repository }
+    }
+
+    pub async fn exec(
+        &self,
+        params: FetchManyMediaParams,
+    ) -> Result<FetchManyMediaResponse, DomainError> {
+        // the library is shared by the whole staff, which is who can upload to it
+        if !verify_role_has_permission(&params.user_role, RolePermissions::UploadMedia) {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        let page = params.page.unwrap_or(1);
+        let items_per_page = params.per_page.unwrap_or(DEFAULT_PER_PAGE as u32);
+
+        let FindManyMediaResponse(media, total_items) = self
+            .media_repository
+            .find_many(PaginationParameters {
+                page,
+                items_per_page,
+                query: Some(MediaQuery {
+                    filters: params.filters,
+                    sort: params.sort,
+                }),
+            })
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Fetch Many Media Service, while fetching the media",
+                    err,
+                )
+            })?;
+
+        Ok(FetchManyMediaResponse {
+            data: media,
+            pagination: PaginationResponse::new(page, total_items, items_per_page),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use uuid::Uuid;
+
+    use crate::core::query::SortDirection;
+    use crate::domain::domain_entities::media::MediaFileInfo;
+    use crate::tests::repositories::media_repository::get_media_repository;
+
+    fn media(file_name: &str, tags: Vec<String>) -> Media {
+        Media::new(
+            Uuid::new_v4(),
+            file_name.into(),
+            MediaFileInfo {
+                mime_type: "image/png".into(),
+                extension: "png".into(),
+                size: 1024,
+                width: 100,
+                height: 100,
+            },
+            "".into(),
+            tags,
+        )
+    }
+
+    #[tokio::test]
+    async fn test() {
+        let (media_db, _, media_repository) = get_media_repository();
+
+        media_db.lock().unwrap().extend([
+            media("banner-carnaval.png", vec!["eventos".into()]),
+            media("emblema.gif", vec!["emblemas".into()]),
+            media("banner-natal.png", vec!["eventos".into(), "natal".into()]),
+        ]);
+
+        let sut = FetchManyMediaService::new(media_repository);
+
+        let unauthorized_result = sut
+            .exec(FetchManyMediaParams {
+                user_role: Role::User,
+                page: None,
+                per_page: None,
+                filters: vec![],
+                sort: vec![],
+            })
+            .await;
+
+        assert!(matches!(
+            unauthorized_result,
+            Err(DomainError::Unauthorized(_))
+        ));
+
+        let result = sut
+            .exec(FetchManyMediaParams {
+                user_role: Role::Writer,
+                page: Some(1),
+                per_page: Some(1),
+                filters: vec![MediaQueryType::Tag("Eventos".into())],
+                sort: vec![],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(1, result.data.len());
+        assert_eq!(2, result.pagination.total_items);
+        assert_eq!(2, result.pagination.total_pages);
+
+        let result = sut
+            .exec(FetchManyMediaParams {
+                user_role: Role::Writer,
+                page: None,
+                per_page: None,
+                filters: vec![MediaQueryType::Text("EMBLEMA".into())],
+                sort: vec![],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!("emblema.gif", result.data[0].file_name());
+        assert_eq!(1, result.pagination.total_items);
+
+        let result = sut
+            .exec(FetchManyMediaParams {
+                user_role: Role::Writer,
+                page: None,
+                per_page: None,
+                filters: vec![
+                    MediaQueryType::Tag("eventos".into()),
+                    MediaQueryType::Text("banner".into()),
+                ],
+                sort: vec![Sort {
+                    field: MediaSortField::FileName,
+                    direction: SortDirection::Asc,
+                }],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(2, result.pagination.total_items);
+        assert_eq!("banner-carnaval.png", result.data[0].file_name());
+        assert_eq!("banner-natal.png", result.data[1].file_name());
+    }
+}
@@ -0,0 +1,129 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::media::{Media, MediaUsage};
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::media_repository::MediaRepositoryTrait;
+use crate::domain::storage::file_storage::FileStorageTrait;
+use crate::error::DomainError;
+use crate::util::generate_service_internal_error;
+use crate::util::{verify_role_has_permission, RolePermissions};
+
+pub struct GetMediaParams {
+    pub user_role: Role,
+    pub media_id: Uuid,
+}
+
+#[derive(Debug)]
+pub struct GetMediaResponse {
+    pub media: Media,
+    pub usages: Vec<MediaUsage>,
+}
+
+pub struct GetMediaService<MR: MediaRepositoryTrait, FS: FileStorageTrait> {
+    media_repository: MR,
+    file_storage: FS,
+}
+
+impl<MR: MediaRepositoryTrait, FS: FileStorageTrait> GetMediaService<MR, FS> {
+    pub fn new(media_repository: MR, file_storage: FS) -> Self {
+        GetMediaService {
+            media_repository,
+            file_storage,
+        }
+    }
+
+    pub async fn exec(&self, params: GetMediaParams) -> Result<GetMediaResponse, DomainError> {
+        if !verify_role_has_permission(&params.user_role, RolePermissions::UploadMedia) {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        let media = self
+            .media_repository
+            .find_by_id(params.media_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Get Media Service, while fetching the media",
+                    err,
+                )
+            })?
+            .ok_or(DomainError::resource_not_found_err())?;
+
+        let usages = self
+            .media_repository
+            .find_usages(&self.file_storage.url(media.storage_key()))
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Get Media Service, while fetching the media's usages",
+                    err,
+                )
+            })?;
+
+        Ok(GetMediaResponse { media, usages })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::domain::domain_entities::media::MediaFileInfo;
+    use crate::tests::file_storage::{get_file_storage, FAKE_STORAGE_URL};
+    use crate::tests::repositories::media_repository::get_media_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (media_db, usages_db, media_repository) = get_media_repository();
+        let (_, file_storage) = get_file_storage();
+
+        let media = Media::new(
+            Uuid::new_v4(),
+            "banner.png".into(),
+            MediaFileInfo {
+                mime_type: "image/png".into(),
+                extension: "png".into(),
+                size: 1024,
+                width: 100,
+                height: 100,
+            },
+            "".into(),
+            vec![],
+        );
+        media_db.lock().unwrap().push(media.clone());
+
+        let usage = MediaUsage::FreeBadge {
+            id: Uuid::new_v4(),
+            code: "PT001".into(),
+        };
+        usages_db.lock().unwrap().insert(
+            format!("{FAKE_STORAGE_URL}/{}", media.storage_key()),
+            vec![usage.clone()],
+        );
+
+        let sut = GetMediaService::new(media_repository, file_storage);
+
+        let result = sut
+            .exec(GetMediaParams {
+                user_role: Role::Writer,
+                media_id: media.id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(media, result.media);
+        assert_eq!(vec![usage], result.usages);
+
+        let not_found_result = sut
+            .exec(GetMediaParams {
+                user_role: Role::Writer,
+                media_id: Uuid::new_v4(),
+            })
+            .await;
+
+        assert!(matches!(
+            not_found_result,
+            Err(DomainError::ResourceNotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,41 @@
+pub mod delete_media_service;
+pub mod fetch_many_media_service;
+pub mod get_media_service;
+pub mod update_media_service;
+pub mod upload_media_service;
+
+use crate::error::DomainError;
+
+pub const MAX_MEDIA_ALT_TEXT_LENGTH: usize = 255;
+pub const MAX_MEDIA_TAGS: usize = 10;
+pub const MAX_MEDIA_TAG_LENGTH: usize = 32;
+
+/// Checks the fields staff members can edit, regardless of whether the media is being uploaded or updated.
+pub fn validate_media_fields(
+    alt_text: Option<&str>,
+    tags: Option<&[String]>,
+) -> Result<(), DomainError> {
+    if alt_text.is_some_and(|alt_text| alt_text.chars().count() > MAX_MEDIA_ALT_TEXT_LENGTH) {
+        return Err(DomainError::bad_request_err().with_message(format!(
+            "Alternative text must have at most {MAX_MEDIA_ALT_TEXT_LENGTH} characters."
+        )));
+    }
+
+    if let Some(tags) = tags {
+        if tags.len() > MAX_MEDIA_TAGS {
+            return Err(DomainError::bad_request_err()
+                .with_message(format!("Media can have at most {MAX_MEDIA_TAGS} tags.")));
+        }
+
+        if tags
+            .iter()
+            .any(|tag| tag.trim().chars().count() > MAX_MEDIA_TAG_LENGTH)
+        {
+            return Err(DomainError::bad_request_err().with_message(format!(
+                "Tags must have at most {MAX_MEDIA_TAG_LENGTH} characters."
+            )));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::media::Media;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::media_repository::MediaRepositoryTrait;
+use crate::error::DomainError;
+use crate::util::generate_service_internal_error;
+use crate::util::{verify_role_has_permission, RolePermissions};
+
+use super::validate_media_fields;
+
+pub struct UpdateMediaParams {
+    pub user_id: Uuid,
+    pub user_role: Role,
+    pub media_id: Uuid,
+    pub alt_text: Option<String>,
+    pub tags: Option<Vec<String>>,
+}
+
+pub struct UpdateMediaService<MR: MediaRepositoryTrait> {
+    media_repository: MR,
+}
+
+impl<MR: MediaRepositoryTrait> UpdateMediaService<MR> {
+    pub fn new(media_repository: MR) -> Self {
+        UpdateMediaService { media_repository }
+    }
+
+    pub async fn exec(&self, params: UpdateMediaParams) -> Result<Media, DomainError> {
+        let mut media = self
+            .media_repository
+            .find_by_id(params.media_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Update Media Service, while fetching the media",
+                    err,
+                )
+            })?
+            .ok_or(DomainError::resource_not_found_err())?;
+
+        // the uploader can always edit their own media
+        if media.uploader_id() != params.user_id
+            && !verify_role_has_permission(&params.user_role, RolePermissions::UpdateMedia)
+        {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        validate_media_fields(params.alt_text.as_deref(), params.tags.as_deref())?;
+
+        if let Some(alt_text) = params.alt_text {
+            media.set_alt_text(alt_text.trim().to_owned());
+        }
+
+        if let Some(tags) = params.tags {
+            media.set_tags(tags);
+        }
+
+        self.media_repository.save(media).await.map_err(|err| {
+            generate_service_internal_error(
+                "Error occurred on Update Media Service, while saving the media",
+                err,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::domain::domain_entities::media::MediaFileInfo;
+    use crate::tests::repositories::media_repository::get_media_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (media_db, _, media_repository) = get_media_repository();
+
+        let uploader_id = Uuid::new_v4();
+        let media = Media::new(
+            uploader_id,
+            "banner.png".into(),
+            MediaFileInfo {
+                mime_type: "image/png".into(),
+                extension: "png".into(),
+                size: 1024,
+                width: 100,
+                height: 100,
+            },
+            "".into(),
+            vec![],
+        );
+        media_db.lock().unwrap().push(media.clone());
+
+        let sut = UpdateMediaService::new(media_repository);
+
+        let other_writer_result = sut
+            .exec(UpdateMediaParams {
+                user_id: Uuid::new_v4(),
+                user_role: Role::Writer,
+                media_id: media.id(),
+                alt_text: Some("Banner".into()),
+                tags: None,
+            })
+            .await;
+
+        assert!(matches!(
+            other_writer_result,
+            Err(DomainError::Unauthorized(_))
+        ));
+
+        let updated_media = sut
+            .exec(UpdateMediaParams {
+                user_id: uploader_id,
+                user_role: Role::Writer,
+                media_id: media.id(),
+                alt_text: Some("Banner do evento".into()),
+                tags: Some(vec!["Eventos".into()]),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!("Banner do evento", updated_media.alt_text());
+        assert_eq!(&["eventos".to_string()], updated_media.tags());
+        assert!(updated_media.updated_at().is_some());
+        assert_eq!(updated_media, media_db.lock().unwrap()[0]);
+
+        let editor_result = sut
+            .exec(UpdateMediaParams {
+                user_id: Uuid::new_v4(),
+                user_role: Role::Editor,
+                media_id: media.id(),
+                alt_text: None,
+                tags: Some(vec![]),
+            })
+            .await
+            .unwrap();
+
+        assert!(editor_result.tags().is_empty());
+    }
+}
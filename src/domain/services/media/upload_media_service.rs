@@ -0,0 +1,169 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::media::{Media, MediaFileInfo};
+use crate::domain::domain_entities::role::Role;
+use crate::domain::politics::image_upload_politics::ImageUploadPolitics;
+use crate::domain::repositories::media_repository::MediaRepositoryTrait;
+use crate::domain::storage::file_storage::FileStorageTrait;
+use crate::error::DomainError;
+use crate::libs::image::ImageHelper;
+use crate::util::generate_service_internal_error;
+use crate::util::{verify_role_has_permission, RolePermissions};
+
+use super::validate_media_fields;
+
+pub struct UploadMediaParams {
+    pub user_id: Uuid,
+    pub user_role: Role,
+    pub content: Vec<u8>,
+    /// The MIME type declared by the client, if any.
+    pub content_type: Option<String>,
+    pub file_name: Option<String>,
+    pub alt_text: String,
+    pub tags: Vec<String>,
+}
+
+pub struct UploadMediaService<MR: MediaRepositoryTrait, FS: FileStorageTrait> {
+    media_repository: MR,
+    file_storage: FS,
+}
+
+impl<MR: MediaRepositoryTrait, FS: FileStorageTrait> UploadMediaService<MR, FS> {
+    pub fn new(media_repository: MR, file_storage: FS) -> Self {
+        UploadMediaService {
+            media_repository,
+            file_storage,
+        }
+    }
+
+    pub async fn exec(&self, params: UploadMediaParams) -> Result<Media, DomainError> {
+        if !verify_role_has_permission(&params.user_role, RolePermissions::UploadMedia) {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        validate_media_fields(Some(&params.alt_text), Some(&params.tags))?;
+
+        let (mime_type, extension) = ImageUploadPolitics::validate(
+            &params.content,
+            params.content_type.as_deref(),
+            "Media",
+        )?;
+
+        let (width, height) = ImageHelper::dimensions(&params.content).map_err(|_| {
+            DomainError::bad_request_err().with_message("Media could not be read as an image.")
+        })?;
+
+        // only the name is kept, in case the client sent the file's whole path
+        let file_name = params
+            .file_name
+            .as_deref()
+            .and_then(|file_name| file_name.rsplit(['/', '\\']).next())
+            .map(str::trim)
+            .filter(|file_name| !file_name.is_empty())
+            .map(|file_name| file_name.chars().take(255).collect())
+            .unwrap_or_else(|| format!("untitled.{extension}"));
+
+        let media = Media::new(
+            params.user_id,
+            file_name,
+            MediaFileInfo {
+                mime_type: mime_type.to_owned(),
+                extension: extension.to_owned(),
+                size: params.content.len() as u64,
+                width,
+                height,
+            },
+            params.alt_text.trim().to_owned(),
+            params.tags,
+        );
+
+        self.file_storage
+            .put(media.storage_key(), params.content, mime_type)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Upload Media Service, while storing the file",
+                    err,
+                )
+            })?;
+
+        let storage_key = media.storage_key().to_owned();
+
+        match self.media_repository.create(media).await {
+            Ok(media) => Ok(media),
+            Err(err) => {
+                // the file would be left orphaned otherwise
+                let _ = self.file_storage.delete(&storage_key).await;
+
+                Err(generate_service_internal_error(
+                    "Error occurred on Upload Media Service, while persisting the media",
+                    err,
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::io::Cursor;
+
+    use image::{ImageFormat, RgbImage};
+
+    use crate::tests::file_storage::get_file_storage;
+    use crate::tests::repositories::media_repository::get_media_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (media_db, _, media_repository) = get_media_repository();
+        let (files, file_storage) = get_file_storage();
+
+        let mut png = Cursor::new(Vec::new());
+        RgbImage::new(64, 32)
+            .write_to(&mut png, ImageFormat::Png)
+            .unwrap();
+        let png = png.into_inner();
+
+        let sut = UploadMediaService::new(media_repository, file_storage);
+
+        let unauthorized_result = sut
+            .exec(UploadMediaParams {
+                user_id: Uuid::new_v4(),
+                user_role: Role::User,
+                content: png.clone(),
+                content_type: Some("image/png".into()),
+                file_name: Some("banner.png".into()),
+                alt_text: "".into(),
+                tags: vec![],
+            })
+            .await;
+
+        assert!(matches!(
+            unauthorized_result,
+            Err(DomainError::Unauthorized(_))
+        ));
+
+        let media = sut
+            .exec(UploadMediaParams {
+                user_id: Uuid::new_v4(),
+                user_role: Role::Writer,
+                content: png.clone(),
+                content_type: Some("image/png".into()),
+                file_name: Some("C:\\Users\\staff\\banner.png".into()),
+                alt_text: " Banner do evento ".into(),
+                tags: vec!["Eventos".into(), "banner".into(), "eventos ".into()],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!("banner.png", media.file_name());
+        assert_eq!("image/png", media.mime_type());
+        assert_eq!((64, 32), (media.width(), media.height()));
+        assert_eq!("Banner do evento", media.alt_text());
+        assert_eq!(&["banner".to_string(), "eventos".to_string()], media.tags());
+        assert_eq!(1, media_db.lock().unwrap().len());
+        assert!(files.lock().unwrap().contains_key(media.storage_key()));
+    }
+}
@@ -1,5 +1,8 @@
 pub mod announcements;
 pub mod identity;
+pub mod imports;
 pub mod journalism;
+pub mod media;
 pub mod security;
+pub mod seo;
 pub mod teams;
@@ -0,0 +1,176 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::comment_report::{CommentReport, CommentReportTrait};
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::comment_report_repository::CommentReportRepositoryTrait;
+use crate::domain::repositories::user_repository::UserRepositoryTrait;
+use crate::error::DomainError;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub struct AssignCommentReportParams {
+    pub staff_role: Role,
+    pub com_report_id: i32,
+    /// `None` leaves the report up for any moderator again.
+    pub assignee_id: Option<Uuid>,
+}
+
+/// Hands a report over to a moderator, who must be able to solve it.
+pub struct AssignCommentReportService<CRR, UR>
+where
+    CRR: CommentReportRepositoryTrait,
+    UR: UserRepositoryTrait,
+{
+    comment_report_repository: CRR,
+    user_repository: UR,
+}
+
+impl<CRR, UR> AssignCommentReportService<CRR, UR>
+where
+    CRR: CommentReportRepositoryTrait,
+    UR: UserRepositoryTrait,
+{
+    pub fn new(comment_report_repository: CRR, user_repository: UR) -> Self {
+        AssignCommentReportService {
+            comment_report_repository,
+            user_repository,
+        }
+    }
+
+    pub async fn exec(
+        &self,
+        params: AssignCommentReportParams,
+    ) -> Result<CommentReport, DomainError> {
+        if !verify_role_has_permission(&params.staff_role, RolePermissions::SolveReport) {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        let mut comm_report = self
+            .comment_report_repository
+            .find_by_id(params.com_report_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Assign Comment Report Service, while fetching the comment report",
+                    err,
+                )
+            })?
+            .ok_or(DomainError::resource_not_found_err())?;
+
+        if comm_report.solved_by().is_some() {
+            return Err(
+                DomainError::bad_request_err().with_message("The report has already been solved.")
+            );
+        }
+
+        if let Some(assignee_id) = params.assignee_id {
+            let assignee = self
+                .user_repository
+                .find_by_id(&assignee_id)
+                .await
+                .map_err(|err| {
+                    generate_service_internal_error(
+                        "Error occurred on Assign Comment Report Service, while fetching the assignee",
+                        err,
+                    )
+                })?
+                .ok_or(DomainError::resource_not_found_err())?;
+
+            let assignee_can_solve = assignee.role().is_some_and(|role| {
+                verify_role_has_permission(&role, RolePermissions::SolveReport)
+            });
+
+            if !assignee_can_solve {
+                return Err(DomainError::bad_request_err()
+                    .with_message("The assignee isn't allowed to solve reports."));
+            }
+        }
+
+        comm_report.set_assigned_to(params.assignee_id);
+
+        self.comment_report_repository
+            .save(comm_report)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Assign Comment Report Service, while saving the comment report",
+                    err,
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::domain::domain_entities::comment_report::{DraftCommentReport, ReportReason};
+    use crate::domain::domain_entities::user::User;
+    use crate::tests::repositories::comment_report_repository::get_comment_report_repository;
+    use crate::tests::repositories::users_repository::get_user_repository;
+    use std::sync::{Arc, Mutex};
+
+    #[tokio::test]
+    async fn test_assign_comment_report() {
+        let (comm_report_db, comment_report_repository) =
+            get_comment_report_repository(Arc::new(Mutex::new(vec![])));
+        let (user_db, user_repository) = get_user_repository();
+
+        let moderator = User::new("Moderador".into(), "password".into(), Some(Role::Coord));
+        let writer = User::new("Jornalista".into(), "password".into(), Some(Role::Writer));
+        user_db.lock().unwrap().push(moderator.clone());
+        user_db.lock().unwrap().push(writer.clone());
+
+        let report = DraftCommentReport::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "Spam".into(),
+            ReportReason::Spam,
+        )
+        .to_comment_report(1);
+        comm_report_db.lock().unwrap().push(report);
+
+        let sut = AssignCommentReportService::new(comment_report_repository, user_repository);
+
+        let report = sut
+            .exec(AssignCommentReportParams {
+                staff_role: Role::Admin,
+                com_report_id: 1,
+                assignee_id: Some(moderator.id()),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(report.assigned_to(), Some(moderator.id()));
+        assert_eq!(comm_report_db.lock().unwrap()[0], report);
+
+        let not_a_moderator = sut
+            .exec(AssignCommentReportParams {
+                staff_role: Role::Admin,
+                com_report_id: 1,
+                assignee_id: Some(writer.id()),
+            })
+            .await;
+
+        assert!(not_a_moderator.is_err());
+
+        let unauthorized = sut
+            .exec(AssignCommentReportParams {
+                staff_role: Role::Writer,
+                com_report_id: 1,
+                assignee_id: None,
+            })
+            .await;
+
+        assert!(unauthorized.is_err());
+
+        let report = sut
+            .exec(AssignCommentReportParams {
+                staff_role: Role::Coord,
+                com_report_id: 1,
+                assignee_id: None,
+            })
+            .await
+            .unwrap();
+
+        assert!(report.assigned_to().is_none());
+    }
+}
@@ -0,0 +1,88 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::blocked_word::{BlockedWord, BlockedWordAction};
+use crate::domain::domain_entities::role::Role;
+use crate::domain::politics::word_filter_politics::WordFilterPolitics;
+use crate::domain::repositories::blocked_word_repository::BlockedWordRepositoryTrait;
+use crate::error::DomainError;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub struct CreateBlockedWordParams {
+    pub staff_id: Uuid,
+    pub staff_role: Role,
+    pub pattern: String,
+    pub is_regex: bool,
+    pub action: BlockedWordAction,
+}
+
+pub struct CreateBlockedWordService<BWR: BlockedWordRepositoryTrait> {
+    blocked_word_repository: BWR,
+}
+
+impl<BWR: BlockedWordRepositoryTrait> CreateBlockedWordService<BWR> {
+    pub fn new(blocked_word_repository: BWR) -> Self {
+        CreateBlockedWordService {
+            blocked_word_repository,
+        }
+    }
+
+    pub async fn exec(&self, params: CreateBlockedWordParams) -> Result<BlockedWord, DomainError> {
+        if !verify_role_has_permission(&params.staff_role, RolePermissions::CreateBlockedWord) {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        let pattern = params.pattern.trim().to_owned();
+
+        WordFilterPolitics::validate(&pattern, params.is_regex)?;
+
+        let blocked_word =
+            BlockedWord::new(pattern, params.is_regex, params.action, params.staff_id);
+
+        self.blocked_word_repository
+            .create(blocked_word)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Create Blocked Word Service, while creating the blocked word",
+                    err,
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tests::repositories::blocked_word_repository::get_blocked_word_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (db, blocked_word_repository) = get_blocked_word_repository();
+        let sut = CreateBlockedWordService::new(blocked_word_repository);
+
+        let params = |staff_role: Role, pattern: &str, is_regex: bool| CreateBlockedWordParams {
+            staff_id: Uuid::new_v4(),
+            staff_role,
+            pattern: pattern.into(),
+            is_regex,
+            action: BlockedWordAction::Reject,
+        };
+
+        let res = sut.exec(params(Role::Editor, "golpe", false)).await;
+        assert!(matches!(res, Err(DomainError::Unauthorized(_))));
+
+        let res = sut.exec(params(Role::Coord, "(golpe", true)).await;
+        assert!(matches!(res, Err(DomainError::BadRequest(_))));
+
+        let res = sut.exec(params(Role::Coord, "!!!", false)).await;
+        assert!(matches!(res, Err(DomainError::BadRequest(_))));
+
+        let blocked_word = sut
+            .exec(params(Role::Coord, " golpe ", false))
+            .await
+            .unwrap();
+
+        assert_eq!("golpe", blocked_word.pattern());
+        assert_eq!(vec![blocked_word], *db.lock().unwrap());
+    }
+}
@@ -1,11 +1,24 @@
+use std::collections::HashSet;
+
 use log::error;
 use uuid::Uuid;
 
+use crate::domain::domain_entities::comment::Comment;
 use crate::domain::domain_entities::comment_report::CommentReport;
+use crate::domain::domain_entities::comment_report::CommentReportIdTrait;
+use crate::domain::domain_entities::comment_report::CommentReportTrait;
 use crate::domain::domain_entities::comment_report::DraftCommentReport;
+use crate::domain::domain_entities::comment_report::ReportReason;
+use crate::domain::politics::comment_report_politics::{
+    CommentReportPolitics, DEFAULT_COMMENT_REPORT_HIDE_THRESHOLD,
+};
+use crate::domain::politics::word_filter_politics::WordFilterPolitics;
+use crate::domain::repositories::blocked_word_repository::BlockedWordRepositoryTrait;
 use crate::domain::repositories::comment_report_repository::CommentReportRepositoryTrait;
 use crate::domain::repositories::comment_repository::CommentRepositoryTrait;
+use crate::domain::repositories::comment_review_repository::CommentReviewRepositoryTrait;
 use crate::error::DomainError;
+use crate::util::generate_service_internal_error;
 
 use crate::{LOG_SEP, R_EOL};
 
@@ -13,20 +26,40 @@ pub struct CreateCommentReportParams {
     pub user_id: Uuid,
     pub comment_id: Uuid,
     pub content: String,
+    pub reason: ReportReason,
+    /// How much weight of distinct reporters hides the comment, see `CommentReportPolitics`.
+    pub auto_hide_threshold: Option<u32>,
 }
-pub struct CreateCommentReportService<CR: CommentRepositoryTrait, CRR: CommentReportRepositoryTrait>
-{
+pub struct CreateCommentReportService<
+    CR: CommentRepositoryTrait,
+    CRR: CommentReportRepositoryTrait,
+    BWR: BlockedWordRepositoryTrait,
+    CRVR: CommentReviewRepositoryTrait,
+> {
     comment_repository: CR,
     comment_report_repository: CRR,
+    blocked_word_repository: BWR,
+    comment_review_repository: CRVR,
 }
 
-impl<CR: CommentRepositoryTrait, CRR: CommentReportRepositoryTrait>
-    CreateCommentReportService<CR, CRR>
+impl<
+        CR: CommentRepositoryTrait,
+        CRR: CommentReportRepositoryTrait,
+        BWR: BlockedWordRepositoryTrait,
+        CRVR: CommentReviewRepositoryTrait,
+    > CreateCommentReportService<CR, CRR, BWR, CRVR>
 {
-    pub fn new(comment_repository: CR, comment_report_repository: CRR) -> Self {
+    pub fn new(
+        comment_repository: CR,
+        comment_report_repository: CRR,
+        blocked_word_repository: BWR,
+        comment_review_repository: CRVR,
+    ) -> Self {
         CreateCommentReportService {
             comment_repository,
             comment_report_repository,
+            blocked_word_repository,
+            comment_review_repository,
         }
     }
 
@@ -54,7 +87,10 @@ impl<CR: CommentRepositoryTrait, CRR: CommentReportRepositoryTrait>
         let comment_on_db = comment_on_db.unwrap();
         let comment_id = comment_on_db.id();
 
-        let comment_report = DraftCommentReport::new(comment_id, params.user_id, params.content);
+        let content = self.filter_message(params.content).await?;
+
+        let comment_report =
+            DraftCommentReport::new(comment_id, params.user_id, content, params.reason);
 
         let response = self.comment_report_repository.create(comment_report).await;
 
@@ -66,7 +102,127 @@ impl<CR: CommentRepositoryTrait, CRR: CommentReportRepositoryTrait>
             return Err(DomainError::internal_err());
         }
 
-        Ok(response.unwrap())
+        self.auto_hide(
+            comment_on_db,
+            response.unwrap(),
+            params
+                .auto_hide_threshold
+                .unwrap_or(DEFAULT_COMMENT_REPORT_HIDE_THRESHOLD),
+        )
+        .await
+    }
+
+    /// Hides the comment once its unsolved reports come from enough (weighted) distinct users, flagging all of them.
+    /// Reports on a comment that's already been hidden that way are flagged too. Returns the new report as it ended up.
+    async fn auto_hide(
+        &self,
+        comment: Comment,
+        mut report: CommentReport,
+        threshold: u32,
+    ) -> Result<CommentReport, DomainError> {
+        if threshold == 0 {
+            return Ok(report);
+        }
+
+        let mut reports = self
+            .comment_report_repository
+            .find_many_unsolved_by_comment_id(comment.id())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Create Comment Report Service, while fetching the comment's reports",
+                    err,
+                )
+            })?;
+
+        if !comment.is_active() {
+            if !reports.iter().any(|report| report.comment_auto_hidden()) {
+                return Ok(report);
+            }
+
+            report.set_comment_auto_hidden(true);
+
+            return self
+                .comment_report_repository
+                .save(report)
+                .await
+                .map_err(|err| {
+                    generate_service_internal_error(
+                        "Error occurred on Create Comment Report Service, while flagging the report",
+                        err,
+                    )
+                });
+        }
+
+        let reporters: HashSet<Uuid> = reports
+            .iter()
+            .map(|report| report.user_id())
+            .filter(|user_id| *user_id != comment.author_id())
+            .collect();
+
+        let total_weight: u32 = self
+            .comment_review_repository
+            .find_many_authors_trust(reporters.into_iter().collect())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Create Comment Report Service, while checking how much the reporters are trusted",
+                    err,
+                )
+            })?
+            .iter()
+            .map(|reporter| {
+                CommentReportPolitics::reporter_weight(&reporter.role, reporter.has_approved_review)
+            })
+            .sum();
+
+        if !CommentReportPolitics::should_hide(total_weight, threshold) {
+            return Ok(report);
+        }
+
+        for flagged in reports.iter_mut() {
+            flagged.set_comment_auto_hidden(true);
+        }
+
+        let reports = self
+            .comment_report_repository
+            .save_many_hiding_comment(comment.id(), reports)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Create Comment Report Service, while hiding the reported comment",
+                    err,
+                )
+            })?;
+
+        Ok(reports
+            .into_iter()
+            .find(|flagged| flagged.id() == report.id())
+            .unwrap_or(report))
+    }
+
+    /// Reports' messages go through the word filter too, although flagging them makes no difference: they are already
+    /// up for moderators to review.
+    async fn filter_message(&self, message: String) -> Result<String, DomainError> {
+        let blocked_words = self
+            .blocked_word_repository
+            .find_all()
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                "Error occurred on Create Comment Report Service, while fetching the blocked words",
+                err,
+            )
+            })?;
+
+        let outcome = WordFilterPolitics::filter(&blocked_words).apply(&message);
+
+        if outcome.rejected_by.is_some() {
+            return Err(DomainError::bad_request_err()
+                .with_message("The report contains words that aren't allowed."));
+        }
+
+        Ok(outcome.text)
     }
 }
 
@@ -81,9 +237,17 @@ mod test {
     use crate::domain::domain_entities::comment::Comment;
     use crate::domain::domain_entities::comment_report::CommentReportTrait;
     use crate::domain::domain_entities::comment_report::DraftCommentReport;
+    use crate::domain::domain_entities::comment_report::{ReportPriority, ReportReason};
+    use crate::domain::domain_entities::comment_review::CommentReview;
+    use crate::domain::domain_entities::role::Role;
+    use crate::domain::domain_entities::user::User;
     use crate::domain::repositories::comment_report_repository::MockCommentReportRepositoryTrait;
     use crate::domain::repositories::comment_repository::MockCommentRepositoryTrait;
     use crate::libs::time::TimeHelper;
+    use crate::tests::repositories::blocked_word_repository::get_blocked_word_repository;
+    use crate::tests::repositories::comment_report_repository::get_comment_report_repository;
+    use crate::tests::repositories::comment_repository::get_comment_repository;
+    use crate::tests::repositories::comment_review_repository::get_comment_review_repository;
 
     #[tokio::test]
     async fn test() {
@@ -99,10 +263,12 @@ mod test {
             let fake_comm = Comment::new_from_existing(
                 id,
                 Some(Uuid::new_v4()),
+                None,
                 Uuid::new_v4(),
                 "notíca de um autor de merda fodido".into(),
                 true,
                 TimeHelper::now(),
+                None,
             );
 
             Ok(Some(fake_comm))
@@ -119,9 +285,17 @@ mod test {
             })
             .times(1);
 
+        let (_, blocked_word_repository) = get_blocked_word_repository();
+        let (_, comment_review_repository) = get_comment_review_repository(
+            Arc::new(Mutex::new(vec![])),
+            Arc::new(Mutex::new(vec![])),
+        );
+
         let service = super::CreateCommentReportService {
             comment_repository: mocked_comment_repo,
             comment_report_repository: mocked_comment_report_repo,
+            blocked_word_repository,
+            comment_review_repository,
         };
 
         let result = service
@@ -129,6 +303,8 @@ mod test {
                 comment_id: Uuid::new_v4(),
                 user_id: Uuid::new_v4(),
                 content: "Esse comentário é tóxico e ofensivo.".into(),
+                reason: ReportReason::Offensive,
+                auto_hide_threshold: Some(0),
             })
             .await;
 
@@ -138,4 +314,73 @@ mod test {
 
         assert_eq!(result, db.lock().unwrap()[0]);
     }
+
+    #[tokio::test]
+    async fn test_auto_hide() {
+        let (comment_db, comment_repository) = get_comment_repository();
+        let (report_db, comment_report_repository) =
+            get_comment_report_repository(comment_db.clone());
+        let (_, blocked_word_repository) = get_blocked_word_repository();
+        let user_db = Arc::new(Mutex::new(vec![]));
+        let (review_db, comment_review_repository) =
+            get_comment_review_repository(comment_db.clone(), user_db.clone());
+
+        let comment = Comment::new(Uuid::new_v4(), Some(Uuid::new_v4()), "Spam".into());
+        let comment_id = comment.id();
+        comment_db.lock().unwrap().push(comment);
+
+        let newcomer = User::new("Novato".into(), "password".into(), None);
+        let trusted = User::new("Confiavel".into(), "password".into(), Some(Role::User));
+        let writer = User::new("Escritor".into(), "password".into(), Some(Role::Writer));
+        let latecomer = User::new("Atrasado".into(), "password".into(), None);
+
+        let mut review = CommentReview::new(Uuid::new_v4(), trusted.id());
+        review.review(writer.id(), true);
+        review_db.lock().unwrap().push(review);
+
+        user_db.lock().unwrap().extend([
+            newcomer.clone(),
+            trusted.clone(),
+            writer.clone(),
+            latecomer.clone(),
+        ]);
+
+        let service = super::CreateCommentReportService::new(
+            comment_repository,
+            comment_report_repository,
+            blocked_word_repository,
+            comment_review_repository,
+        );
+
+        let report = |user_id: Uuid| CreateCommentReportParams {
+            user_id,
+            comment_id,
+            content: "Spam!".into(),
+            reason: ReportReason::Spam,
+            auto_hide_threshold: Some(6),
+        };
+
+        // reporting twice doesn't count twice
+        for user_id in [newcomer.id(), newcomer.id(), trusted.id()] {
+            let res = service.exec(report(user_id)).await.unwrap();
+            assert!(!res.comment_auto_hidden());
+            assert_eq!(res.priority(), ReportPriority::Normal);
+        }
+
+        assert!(comment_db.lock().unwrap()[0].is_active());
+
+        let res = service.exec(report(writer.id())).await.unwrap();
+        assert!(res.comment_auto_hidden());
+        assert_eq!(res.priority(), ReportPriority::High);
+        assert!(!comment_db.lock().unwrap()[0].is_active());
+
+        let res = service.exec(report(latecomer.id())).await.unwrap();
+        assert!(res.comment_auto_hidden());
+
+        assert!(report_db
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|report| report.comment_auto_hidden()));
+    }
 }
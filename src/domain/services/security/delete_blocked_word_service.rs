@@ -0,0 +1,51 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::blocked_word_repository::BlockedWordRepositoryTrait;
+use crate::error::DomainError;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub struct DeleteBlockedWordParams {
+    pub staff_role: Role,
+    pub blocked_word_id: Uuid,
+}
+
+pub struct DeleteBlockedWordService<BWR: BlockedWordRepositoryTrait> {
+    blocked_word_repository: BWR,
+}
+
+impl<BWR: BlockedWordRepositoryTrait> DeleteBlockedWordService<BWR> {
+    pub fn new(blocked_word_repository: BWR) -> Self {
+        DeleteBlockedWordService {
+            blocked_word_repository,
+        }
+    }
+
+    pub async fn exec(&self, params: DeleteBlockedWordParams) -> Result<(), DomainError> {
+        if !verify_role_has_permission(&params.staff_role, RolePermissions::DeleteBlockedWord) {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        let blocked_word = self
+            .blocked_word_repository
+            .find_by_id(params.blocked_word_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Delete Blocked Word Service, while fetching the blocked word",
+                    err,
+                )
+            })?
+            .ok_or(DomainError::resource_not_found_err())?;
+
+        self.blocked_word_repository
+            .delete(blocked_word)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Delete Blocked Word Service, while deleting the blocked word",
+                    err,
+                )
+            })
+    }
+}
@@ -61,7 +61,9 @@ mod test {
     use super::*;
     use std::sync::{Arc, Mutex};
 
-    use crate::domain::domain_entities::comment_report::{CommentReport, CommentReportIdTrait};
+    use crate::domain::domain_entities::comment_report::{
+        CommentReport, CommentReportIdTrait, ReportReason,
+    };
     use crate::domain::repositories::comment_report_repository::MockCommentReportRepositoryTrait;
     use crate::libs::time::TimeHelper;
 
@@ -78,22 +80,32 @@ mod test {
 
         let comment_report_1 = CommentReport::new_from_existing(
             1,
-            Uuid::new_v4(),
+            Some(Uuid::new_v4()),
             Uuid::new_v4(),
             "Esse comentário é tóxico.".into(),
             None,
             TimeHelper::now(),
+            false,
+            ReportReason::Offensive,
+            None,
+            None,
+            None,
         );
 
         let comment_report_id_1 = comment_report_1.id();
 
         let comment_report_2 = CommentReport::new_from_existing(
             2,
-            Uuid::new_v4(),
+            Some(Uuid::new_v4()),
             Uuid::new_v4(),
             "Estão me ofendendo neste comentário!".into(),
             None,
             TimeHelper::now(),
+            false,
+            ReportReason::Offensive,
+            None,
+            None,
+            None,
         );
 
         let comment_report_id_2 = comment_report_2.id();
@@ -0,0 +1,126 @@
+use crate::core::pagination::{PaginationParameters, PaginationResponse, DEFAULT_PER_PAGE};
+use crate::domain::domain_entities::comment::Comment;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::comment_review_repository::{
+    CommentReviewRepositoryTrait, FindManyPendingCommentsResponse,
+};
+use crate::error::DomainError;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub struct FetchCommentReviewQueueParams {
+    pub user_role: Role,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
+
+#[derive(Debug)]
+pub struct FetchCommentReviewQueueResponse {
+    pub pagination: PaginationResponse,
+    pub data: Vec<Comment>,
+}
+
+/// Lists the comments held for review, the ones waiting the longest first.
+pub struct FetchCommentReviewQueueService<CRVR: CommentReviewRepositoryTrait> {
+    comment_review_repository: CRVR,
+}
+
+impl<CRVR: CommentReviewRepositoryTrait> FetchCommentReviewQueueService<CRVR> {
+    pub fn new(comment_review_repository: CRVR) -> Self {
+        FetchCommentReviewQueueService {
+            comment_review_repository,
+        }
+    }
+
+    pub async fn exec(
+        &self,
+        params: FetchCommentReviewQueueParams,
+    ) -> Result<FetchCommentReviewQueueResponse, DomainError> {
+        if !verify_role_has_permission(&params.user_role, RolePermissions::InactivateComment) {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        let items_per_page = params.per_page.unwrap_or(DEFAULT_PER_PAGE as u32);
+        let page = params.page.filter(|page| *page > 0).unwrap_or(1);
+
+        let FindManyPendingCommentsResponse(comments, total_items) = self
+            .comment_review_repository
+            .find_many_pending_comments(PaginationParameters {
+                page,
+                items_per_page,
+                query: None,
+            })
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Fetch Comment Review Queue Service, while fetching the pending comments",
+                    err,
+                )
+            })?;
+
+        Ok(FetchCommentReviewQueueResponse {
+            data: comments,
+            pagination: PaginationResponse::new(page, total_items, items_per_page),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use uuid::Uuid;
+
+    use crate::domain::domain_entities::comment_review::CommentReview;
+    use crate::tests::repositories::comment_review_repository::get_comment_review_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let comment_db = Arc::new(Mutex::new(vec![]));
+        let (review_db, comment_review_repository) =
+            get_comment_review_repository(comment_db.clone(), Arc::new(Mutex::new(vec![])));
+
+        for content in ["Primeiro", "Segundo", "Revisado"] {
+            let mut comment = Comment::new(Uuid::new_v4(), Some(Uuid::new_v4()), content.into());
+            comment.set_is_active(false);
+
+            let mut review = CommentReview::new(comment.id(), comment.author_id());
+
+            if content == "Revisado" {
+                review.review(Uuid::new_v4(), false);
+            }
+
+            comment_db.lock().unwrap().push(comment);
+            review_db.lock().unwrap().push(review);
+        }
+
+        let sut = FetchCommentReviewQueueService::new(comment_review_repository);
+
+        let res = sut
+            .exec(FetchCommentReviewQueueParams {
+                user_role: Role::User,
+                page: None,
+                per_page: None,
+            })
+            .await;
+
+        assert!(matches!(res, Err(DomainError::Unauthorized(_))));
+
+        let res = sut
+            .exec(FetchCommentReviewQueueParams {
+                user_role: Role::Coord,
+                page: None,
+                per_page: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(2, res.pagination.total_items);
+        assert_eq!(
+            vec!["Primeiro", "Segundo"],
+            res.data
+                .iter()
+                .map(|comment| comment.content())
+                .collect::<Vec<&str>>()
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use crate::domain::domain_entities::blocked_word::BlockedWord;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::blocked_word_repository::BlockedWordRepositoryTrait;
+use crate::error::DomainError;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub struct FetchManyBlockedWordsParams {
+    pub staff_role: Role,
+}
+
+/// Lists the whole word filter, which is small enough not to be paginated. Only those who can change it can see it.
+pub struct FetchManyBlockedWordsService<BWR: BlockedWordRepositoryTrait> {
+    blocked_word_repository: BWR,
+}
+
+impl<BWR: BlockedWordRepositoryTrait> FetchManyBlockedWordsService<BWR> {
+    pub fn new(blocked_word_repository: BWR) -> Self {
+        FetchManyBlockedWordsService {
+            blocked_word_repository,
+        }
+    }
+
+    pub async fn exec(
+        &self,
+        params: FetchManyBlockedWordsParams,
+    ) -> Result<Vec<BlockedWord>, DomainError> {
+        if !verify_role_has_permission(&params.staff_role, RolePermissions::UpdateBlockedWord) {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        self.blocked_word_repository
+            .find_all()
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Fetch Many Blocked Words Service, while fetching the blocked words",
+                    err,
+                )
+            })
+    }
+}
@@ -1,12 +1,18 @@
-use crate::domain::domain_entities::comment_report::CommentReport;
+use crate::domain::domain_entities::comment_report::{
+    CommentReport, CommentReportIdTrait, CommentReportTrait, ReportReason,
+};
 use crate::domain::repositories::user_repository::UserRepositoryTrait;
 use crate::error::DomainError;
 use crate::util::generate_service_internal_error;
 use uuid::Uuid;
 
-use crate::core::pagination::{PaginationParameters, PaginationResponse};
+use crate::core::pagination::{
+    Cursor, CursorPage, CursorParameters, PaginationParameters, PaginationResponse,
+};
+use crate::core::query::{DateRange, Sort};
 use crate::domain::repositories::comment_report_repository::{
-    CommentReportQueryType, CommentReportRepositoryTrait, FindManyCommentReportsResponse,
+    CommentReportQuery, CommentReportQueryType, CommentReportRepositoryTrait,
+    CommentReportSortField, FindManyCommentReportsResponse,
 };
 
 type Error = DomainError;
@@ -19,12 +25,25 @@ pub enum CommentReportServiceQuery {
     SolvedBy(String),
     Solved(bool),
     Content(String),
+    CreatedAt(DateRange),
+    Reason(ReportReason),
+    /// The NICKNAME of the moderator the reports have been assigned to, just like `SolvedBy`.
+    AssignedTo(String),
 }
 
 pub struct FetchManyCommentReportsParams {
     pub page: Option<u32>,
     pub per_page: Option<u32>,
-    pub query: Option<CommentReportServiceQuery>,
+    /// Reports must match all of them.
+    pub filters: Vec<CommentReportServiceQuery>,
+    pub sort: Vec<Sort<CommentReportSortField>>,
+}
+
+pub struct FetchCommentReportsByCursorParams {
+    pub cursor: Option<Cursor<i32>>,
+    pub per_page: Option<u32>,
+    /// Reports must match all of them.
+    pub filters: Vec<CommentReportServiceQuery>,
 }
 
 #[derive(Debug)]
@@ -80,14 +99,20 @@ impl<
             default_page
         };
 
-        let parsed_query = self.parse_query(params.query).await?;
+        let mut query = CommentReportQuery::new();
+
+        for filter in params.filters {
+            query = query.filter(self.parse_filter(filter).await?);
+        }
+
+        query.sort = params.sort;
 
         let FindManyCommentReportsResponse(data, total_items)  = self
             .comment_report_repository
             .find_many(PaginationParameters {
                 items_per_page,
                 page,
-                query: parsed_query,
+                query: Some(query),
             })
             .await.map_err(|err|
                 generate_service_internal_error(
@@ -101,36 +126,75 @@ impl<
         })
     }
 
-    async fn parse_query(
+    /// Lists the reports from the most recent to the oldest, a page after (or before) the cursor.
+    pub async fn exec_by_cursor(
         &self,
-        service_query: Option<CommentReportServiceQuery>,
-    ) -> Result<Option<CommentReportQueryType>, Error> {
-        match service_query {
-            None => Ok(None),
-            Some(service_query) => match service_query {
-                CommentReportServiceQuery::Content(content) => {
-                    Ok(Some(CommentReportQueryType::Content(content)))
-                }
-                CommentReportServiceQuery::Solved(value) => {
-                    Ok(Some(CommentReportQueryType::Solved(value)))
-                }
-                CommentReportServiceQuery::SolvedBy(nickname) => {
-                    let user_id = self.get_id_from_nickname(nickname).await.map_err(|err| {
-                        generate_service_internal_error(
-                        "Error occurred on Fetch Many Articles Service, while parsing the query",
-                        err,
-                    )
-                    })?;
-
-                    match user_id {
-                        None => Err(DomainError::resource_not_found_err()),
-                        Some(id) => Ok(Some(CommentReportQueryType::SolvedBy(id))),
-                    }
-                }
-            },
+        params: FetchCommentReportsByCursorParams,
+    ) -> Result<CursorPage<CommentReport, i32>, Error> {
+        let items_per_page = params.per_page.unwrap_or(9);
+
+        let mut filters = vec![];
+
+        for filter in params.filters {
+            filters.push(self.parse_filter(filter).await?);
+        }
+
+        let comment_reports = self
+            .comment_report_repository
+            .find_many_by_cursor(CursorParameters {
+                cursor: params.cursor.clone(),
+                items_per_page,
+                filters,
+            })
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Fetch Many Comment Reports Service, while finding comment reports by cursor",
+                    err,
+                )
+            })?;
+
+        Ok(CursorPage::from_window(
+            comment_reports,
+            params.cursor.as_ref(),
+            items_per_page,
+            |comment_report| (comment_report.created_at(), comment_report.id()),
+        ))
+    }
+
+    async fn parse_filter(
+        &self,
+        filter: CommentReportServiceQuery,
+    ) -> Result<CommentReportQueryType, Error> {
+        match filter {
+            CommentReportServiceQuery::Content(content) => {
+                Ok(CommentReportQueryType::Content(content))
+            }
+            CommentReportServiceQuery::Solved(value) => Ok(CommentReportQueryType::Solved(value)),
+            CommentReportServiceQuery::CreatedAt(range) => {
+                Ok(CommentReportQueryType::CreatedAt(range))
+            }
+            CommentReportServiceQuery::Reason(reason) => Ok(CommentReportQueryType::Reason(reason)),
+            CommentReportServiceQuery::SolvedBy(nickname) => Ok(CommentReportQueryType::SolvedBy(
+                self.parse_nickname(nickname).await?,
+            )),
+            CommentReportServiceQuery::AssignedTo(nickname) => Ok(
+                CommentReportQueryType::AssignedTo(self.parse_nickname(nickname).await?),
+            ),
         }
     }
 
+    async fn parse_nickname(&self, nickname: String) -> Result<Uuid, Error> {
+        let user_id = self.get_id_from_nickname(nickname).await.map_err(|err| {
+            generate_service_internal_error(
+                "Error occurred on Fetch Many Articles Service, while parsing the query",
+                err,
+            )
+        })?;
+
+        user_id.ok_or(DomainError::resource_not_found_err())
+    }
+
     async fn get_id_from_nickname(
         &self,
         nickname: String,
@@ -146,6 +210,7 @@ impl<
 
 #[cfg(test)]
 mod test {
+    use crate::core::query::SortDirection;
     use crate::{
         domain::{
             domain_entities::{comment_report::CommentReportTrait, user::User},
@@ -155,6 +220,7 @@ mod test {
             },
         },
         libs::time::TimeHelper,
+        tests::query_spec::{apply_query_spec, select_by_cursor},
     };
 
     use super::*;
@@ -164,6 +230,21 @@ mod test {
     use tokio;
     use uuid::Uuid;
 
+    fn matches_query(item: &CommentReport, query: &CommentReportQueryType) -> bool {
+        match query {
+            CommentReportQueryType::Content(content) => item.message().contains(&content[..]),
+            CommentReportQueryType::SolvedBy(solved_by) => {
+                item.solved_by().is_some_and(|id| id.eq(solved_by))
+            }
+            CommentReportQueryType::Solved(solved) => item.solved_by().is_some().eq(solved),
+            CommentReportQueryType::CreatedAt(range) => range.contains(&item.created_at()),
+            CommentReportQueryType::Reason(reason) => item.reason().eq(reason),
+            CommentReportQueryType::AssignedTo(assigned_to) => {
+                item.assigned_to().is_some_and(|id| id.eq(assigned_to))
+            }
+        }
+    }
+
     #[tokio::test]
     async fn test() {
         let comment_report_db: Arc<Mutex<Vec<CommentReport>>> = Arc::new(Mutex::new(Vec::new()));
@@ -177,20 +258,30 @@ mod test {
 
         let comm_rep_1 = CommentReport::new_from_existing(
             1,
-            Uuid::new_v4(),
+            Some(Uuid::new_v4()),
             Uuid::new_v4(),
             "report numero 1".into(),
             None,
             TimeHelper::now(),
+            false,
+            ReportReason::ScamLink,
+            Some(user.id()),
+            None,
+            None,
         );
 
         let comm_rep_2 = CommentReport::new_from_existing(
             2,
-            Uuid::new_v4(),
+            Some(Uuid::new_v4()),
             Uuid::new_v4(),
             "report numero 2".into(),
             Some(user.id()),
             TimeHelper::now(),
+            false,
+            ReportReason::OffTopic,
+            None,
+            None,
+            None,
         );
 
         comment_report_db.lock().unwrap().push(comm_rep_1);
@@ -210,39 +301,15 @@ mod test {
                     query,
                 } = params;
 
-                let mut comment_reports: Vec<CommentReport> = vec![];
-
-                if query.is_some() {
-                    let query = query.unwrap();
-
-                    match query {
-                        CommentReportQueryType::Content(content) => {
-                            for item in comm_repo_db_clone.lock().unwrap().iter() {
-                                if item.message().contains(&content[..]) {
-                                    comment_reports.push(item.clone());
-                                }
-                            }
-                        }
-                        CommentReportQueryType::SolvedBy(solved_by) => {
-                            for item in comm_repo_db_clone.lock().unwrap().iter() {
-                                if item.solved_by().is_some()
-                                    && item.solved_by().unwrap().eq(&solved_by)
-                                {
-                                    comment_reports.push(item.clone());
-                                }
-                            }
-                        }
-                        CommentReportQueryType::Solved(solved) => {
-                            for item in comm_repo_db_clone.lock().unwrap().iter() {
-                                if item.solved_by().is_some().eq(&solved) {
-                                    comment_reports.push(item.clone());
-                                }
-                            }
-                        }
-                    };
-                } else {
-                    comment_reports = comm_repo_db_clone.lock().unwrap().to_vec();
-                }
+                let comment_reports = apply_query_spec(
+                    comm_repo_db_clone.lock().unwrap().to_vec(),
+                    query.as_ref(),
+                    matches_query,
+                    |a, b, field| match field {
+                        CommentReportSortField::CreatedAt => a.created_at().cmp(&b.created_at()),
+                        CommentReportSortField::Priority => a.priority().cmp(&b.priority()),
+                    },
+                );
 
                 let total_of_items_before_paginating = comment_reports.len();
 
@@ -262,6 +329,20 @@ mod test {
                 ))
             });
 
+        let comm_repo_db_clone = Arc::clone(&comment_report_db);
+        mocked_comm_report_repo
+            .expect_find_many_by_cursor()
+            .returning(move |params| {
+                Ok(select_by_cursor(
+                    comm_repo_db_clone.lock().unwrap().to_vec(),
+                    &params.filters,
+                    params.cursor.as_ref(),
+                    params.items_per_page,
+                    matches_query,
+                    |item| (item.created_at(), item.id()),
+                ))
+            });
+
         let user_repo_db_clone = Arc::clone(&user_db);
         mocked_user_repo
             .expect_find_by_nickname()
@@ -284,7 +365,8 @@ mod test {
             .exec(FetchManyCommentReportsParams {
                 page: Some(1),
                 per_page: Some(1),
-                query: Some(CommentReportServiceQuery::SolvedBy("Floricultor".into())),
+                filters: vec![CommentReportServiceQuery::SolvedBy("Floricultor".into())],
+                sort: vec![],
             })
             .await;
 
@@ -293,5 +375,103 @@ mod test {
         assert_eq!("report numero 2".to_string(), res.data[0].message());
         assert_eq!(1, res.pagination.total_pages);
         assert_eq!(1, res.pagination.total_items);
+
+        let res = sut
+            .exec(FetchManyCommentReportsParams {
+                page: Some(1),
+                per_page: Some(1),
+                filters: vec![
+                    CommentReportServiceQuery::Solved(false),
+                    CommentReportServiceQuery::Content("numero".into()),
+                ],
+                sort: vec![],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!("report numero 1".to_string(), res.data[0].message());
+        assert_eq!(1, res.pagination.total_items);
+
+        let res = sut
+            .exec(FetchManyCommentReportsParams {
+                page: Some(1),
+                per_page: Some(2),
+                filters: vec![],
+                sort: vec![Sort {
+                    field: CommentReportSortField::Priority,
+                    direction: SortDirection::Desc,
+                }],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(1, res.data[0].id());
+
+        let res = sut
+            .exec(FetchManyCommentReportsParams {
+                page: Some(1),
+                per_page: Some(2),
+                filters: vec![
+                    CommentReportServiceQuery::AssignedTo("floricultor".into()),
+                    CommentReportServiceQuery::Reason(ReportReason::ScamLink),
+                ],
+                sort: vec![],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(1, res.pagination.total_items);
+        assert_eq!(1, res.data[0].id());
+
+        let page = sut
+            .exec_by_cursor(FetchCommentReportsByCursorParams {
+                cursor: None,
+                per_page: Some(1),
+                filters: vec![],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(2, page.data[0].id());
+        assert!(page.pagination.prev_cursor.is_none());
+
+        let page = sut
+            .exec_by_cursor(FetchCommentReportsByCursorParams {
+                cursor: page.pagination.next_cursor,
+                per_page: Some(1),
+                filters: vec![],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(1, page.data[0].id());
+        assert!(page.pagination.next_cursor.is_none());
+
+        let page = sut
+            .exec_by_cursor(FetchCommentReportsByCursorParams {
+                cursor: page.pagination.prev_cursor,
+                per_page: Some(1),
+                filters: vec![CommentReportServiceQuery::Solved(true)],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(2, page.data[0].id());
+        assert!(page.pagination.prev_cursor.is_none());
+
+        let page = sut
+            .exec_by_cursor(FetchCommentReportsByCursorParams {
+                cursor: None,
+                per_page: Some(2),
+                filters: vec![
+                    CommentReportServiceQuery::Reason(ReportReason::ScamLink),
+                    CommentReportServiceQuery::AssignedTo("Floricultor".into()),
+                ],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(1, page.data.len());
+        assert_eq!(1, page.data[0].id());
     }
 }
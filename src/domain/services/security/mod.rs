@@ -1,5 +1,13 @@
+pub mod assign_comment_report_service;
+pub mod create_blocked_word_service;
 pub mod create_comment_report_service;
+pub mod delete_blocked_word_service;
 pub mod delete_comment_report_service;
+pub mod fetch_comment_review_queue_service;
+pub mod fetch_many_blocked_words_service;
 pub mod fetch_many_comment_reports_service;
+pub mod restore_comment_service;
+pub mod review_comments_service;
 pub mod solve_comment_report_service;
 pub mod toggle_comment_visibility_service;
+pub mod update_blocked_word_service;
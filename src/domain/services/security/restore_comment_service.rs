@@ -0,0 +1,157 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::comment::Comment;
+use crate::domain::domain_entities::comment_report::{CommentReportTrait, ReportResolution};
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::comment_report_repository::CommentReportRepositoryTrait;
+use crate::domain::repositories::comment_repository::CommentRepositoryTrait;
+use crate::error::DomainError;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub struct RestoreCommentParams {
+    pub moderator_id: Uuid,
+    pub moderator_role: Role,
+    pub comment_id: Uuid,
+}
+
+/// Shows again a comment hidden for having been reported by too many users, rejecting its pending reports on behalf
+/// of the moderator.
+pub struct RestoreCommentService<CR, CRR>
+where
+    CR: CommentRepositoryTrait,
+    CRR: CommentReportRepositoryTrait,
+{
+    comment_repository: CR,
+    comment_report_repository: CRR,
+}
+
+impl<CR, CRR> RestoreCommentService<CR, CRR>
+where
+    CR: CommentRepositoryTrait,
+    CRR: CommentReportRepositoryTrait,
+{
+    pub fn new(comment_repository: CR, comment_report_repository: CRR) -> Self {
+        RestoreCommentService {
+            comment_repository,
+            comment_report_repository,
+        }
+    }
+
+    pub async fn exec(&self, params: RestoreCommentParams) -> Result<Comment, DomainError> {
+        if !verify_role_has_permission(&params.moderator_role, RolePermissions::InactivateComment) {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        let mut comment = self
+            .comment_repository
+            .find_by_id(params.comment_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Restore Comment Service, while finding the comment by id",
+                    err,
+                )
+            })?
+            .ok_or(DomainError::resource_not_found_err())?;
+
+        let reports = self
+            .comment_report_repository
+            .find_many_unsolved_by_comment_id(comment.id())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Restore Comment Service, while fetching the comment's reports",
+                    err,
+                )
+            })?;
+
+        if comment.is_active() || !reports.iter().any(|report| report.comment_auto_hidden()) {
+            return Err(DomainError::bad_request_err()
+                .with_message("The comment hasn't been hidden by reports."));
+        }
+
+        comment.set_is_active(true);
+
+        let comment = self.comment_repository.save(comment).await.map_err(|err| {
+            generate_service_internal_error(
+                "Error occurred on Restore Comment Service, while saving the comment",
+                err,
+            )
+        })?;
+
+        for mut report in reports {
+            report.resolve(params.moderator_id, ReportResolution::Rejected, None);
+
+            self.comment_report_repository
+                .save(report)
+                .await
+                .map_err(|err| {
+                    generate_service_internal_error(
+                        "Error occurred on Restore Comment Service, while solving a report",
+                        err,
+                    )
+                })?;
+        }
+
+        Ok(comment)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::domain::domain_entities::comment_report::{DraftCommentReport, ReportReason};
+    use crate::tests::repositories::comment_report_repository::get_comment_report_repository;
+    use crate::tests::repositories::comment_repository::get_comment_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (comment_db, comment_repository) = get_comment_repository();
+        let (report_db, comment_report_repository) =
+            get_comment_report_repository(comment_db.clone());
+
+        let moderator_id = Uuid::new_v4();
+
+        let mut comment = Comment::new(Uuid::new_v4(), Some(Uuid::new_v4()), "Polêmico".into());
+        comment.set_is_active(false);
+        let comment_id = comment.id();
+        comment_db.lock().unwrap().push(comment);
+
+        for id in 1..=2 {
+            let mut report = DraftCommentReport::new(
+                comment_id,
+                Uuid::new_v4(),
+                "Spam".into(),
+                ReportReason::Spam,
+            )
+            .to_comment_report(id);
+            report.set_comment_auto_hidden(true);
+            report_db.lock().unwrap().push(report);
+        }
+
+        let sut = RestoreCommentService::new(comment_repository, comment_report_repository);
+
+        let params = |moderator_role: Role| RestoreCommentParams {
+            moderator_id,
+            moderator_role,
+            comment_id,
+        };
+
+        let res = sut.exec(params(Role::Editor)).await;
+        assert!(matches!(res, Err(DomainError::Unauthorized(_))));
+
+        let comment = sut.exec(params(Role::Coord)).await.unwrap();
+        assert!(comment.is_active());
+        assert!(report_db
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|report| report.solved_by() == Some(moderator_id)
+                && report.resolution() == Some(ReportResolution::Rejected)));
+
+        // there's nothing left to restore
+        let res = sut.exec(params(Role::Coord)).await;
+        assert!(matches!(res, Err(DomainError::BadRequest(_))));
+    }
+}
@@ -0,0 +1,175 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::comment_review::CommentReview;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::comment_review_repository::CommentReviewRepositoryTrait;
+use crate::error::DomainError;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+/// How many comments can be reviewed at once.
+pub const MAX_COMMENTS_PER_REVIEW: usize = 100;
+
+pub struct ReviewCommentsParams {
+    pub moderator_id: Uuid,
+    pub moderator_role: Role,
+    pub comment_ids: Vec<Uuid>,
+    /// Publishes the comments if set, or else keeps them hidden for good.
+    pub approve: bool,
+}
+
+/// Approves or rejects comments held for review in bulk. Comments that aren't pending review are left untouched.
+pub struct ReviewCommentsService<CRVR>
+where
+    CRVR: CommentReviewRepositoryTrait,
+{
+    comment_review_repository: CRVR,
+}
+
+impl<CRVR> ReviewCommentsService<CRVR>
+where
+    CRVR: CommentReviewRepositoryTrait,
+{
+    pub fn new(comment_review_repository: CRVR) -> Self {
+        ReviewCommentsService {
+            comment_review_repository,
+        }
+    }
+
+    pub async fn exec(
+        &self,
+        params: ReviewCommentsParams,
+    ) -> Result<Vec<CommentReview>, DomainError> {
+        if !verify_role_has_permission(&params.moderator_role, RolePermissions::InactivateComment) {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        if params.comment_ids.is_empty() || params.comment_ids.len() > MAX_COMMENTS_PER_REVIEW {
+            return Err(DomainError::bad_request_err().with_message(format!(
+                "Between 1 and {MAX_COMMENTS_PER_REVIEW} comments must be reviewed at once."
+            )));
+        }
+
+        let reviews = self
+            .comment_review_repository
+            .find_many_by_comment_ids(params.comment_ids)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Review Comments Service, while fetching the comments' reviews",
+                    err,
+                )
+            })?
+            .into_iter()
+            .filter(|review| review.is_pending())
+            .map(|mut review| {
+                review.review(params.moderator_id, params.approve);
+                review
+            })
+            .collect::<Vec<CommentReview>>();
+
+        if reviews.is_empty() {
+            return Ok(reviews);
+        }
+
+        self.comment_review_repository
+            .save_many_publishing_approved(reviews)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Review Comments Service, while saving the comments' reviews",
+                    err,
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::sync::{Arc, Mutex};
+
+    use crate::domain::domain_entities::comment::Comment;
+    use crate::tests::repositories::comment_review_repository::get_comment_review_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let comment_db: Arc<Mutex<Vec<Comment>>> = Arc::new(Mutex::new(vec![]));
+        let (review_db, comment_review_repository) =
+            get_comment_review_repository(comment_db.clone(), Arc::new(Mutex::new(vec![])));
+
+        let moderator_id = Uuid::new_v4();
+        let mut comment_ids = vec![];
+
+        for content in ["Primeiro", "Segundo", "Terceiro"] {
+            let mut comment = Comment::new(Uuid::new_v4(), Some(Uuid::new_v4()), content.into());
+            comment.set_is_active(false);
+
+            comment_ids.push(comment.id());
+            review_db
+                .lock()
+                .unwrap()
+                .push(CommentReview::new(comment.id(), comment.author_id()));
+            comment_db.lock().unwrap().push(comment);
+        }
+
+        let sut = ReviewCommentsService::new(comment_review_repository);
+
+        let res = sut
+            .exec(ReviewCommentsParams {
+                moderator_id,
+                moderator_role: Role::Editor,
+                comment_ids: comment_ids.clone(),
+                approve: true,
+            })
+            .await;
+
+        assert!(matches!(res, Err(DomainError::Unauthorized(_))));
+
+        let reviewed = sut
+            .exec(ReviewCommentsParams {
+                moderator_id,
+                moderator_role: Role::Coord,
+                comment_ids: comment_ids[..2].to_vec(),
+                approve: true,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(2, reviewed.len());
+
+        let reviewed = sut
+            .exec(ReviewCommentsParams {
+                moderator_id,
+                moderator_role: Role::Coord,
+                comment_ids: comment_ids.clone(),
+                approve: false,
+            })
+            .await
+            .unwrap();
+
+        // the approved ones aren't pending anymore
+        assert_eq!(1, reviewed.len());
+
+        let comments = comment_db.lock().unwrap();
+        assert_eq!(
+            vec![true, true, false],
+            comments
+                .iter()
+                .map(|comment| comment.is_active())
+                .collect::<Vec<bool>>()
+        );
+
+        let reviews = review_db.lock().unwrap();
+        assert_eq!(
+            vec![Some(true), Some(true), Some(false)],
+            reviews
+                .iter()
+                .map(|review| review.approved())
+                .collect::<Vec<Option<bool>>>()
+        );
+        assert!(reviews
+            .iter()
+            .all(|review| review.reviewed_by() == Some(moderator_id)));
+    }
+}
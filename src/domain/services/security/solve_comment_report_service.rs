@@ -1,5 +1,7 @@
 use uuid::Uuid;
 
+use crate::domain::domain_entities::comment_report::CommentReportTrait;
+use crate::domain::domain_entities::comment_report::{CommentReport, ReportResolution};
 use crate::domain::domain_entities::role::Role;
 use crate::domain::repositories::comment_report_repository::CommentReportRepositoryTrait;
 use crate::error::DomainError;
@@ -11,8 +13,11 @@ pub struct SolveCommentReportParams {
     pub staff_id: Uuid,
     pub staff_role: Role,
     pub com_report_id: i32,
+    pub resolution: ReportResolution,
+    pub resolution_notes: Option<String>,
 }
 
+/// Solves a report, hiding or deleting the reported comment according to the resolution.
 pub struct SolveCommentReportService<CommentReportRepository: CommentReportRepositoryTrait> {
     comment_report_repository: CommentReportRepository,
 }
@@ -26,151 +31,182 @@ impl<CommentReportRepository: CommentReportRepositoryTrait>
         }
     }
 
-    pub async fn exec(&self, params: SolveCommentReportParams) -> Result<(), DomainError> {
+    pub async fn exec(
+        &self,
+        params: SolveCommentReportParams,
+    ) -> Result<CommentReport, DomainError> {
         let staff_can_solve =
             verify_role_has_permission(&params.staff_role, RolePermissions::SolveReport);
 
-        if !staff_can_solve {
+        let staff_can_apply_resolution = match params.resolution {
+            ReportResolution::CommentHidden => {
+                verify_role_has_permission(&params.staff_role, RolePermissions::InactivateComment)
+            }
+            ReportResolution::CommentDeleted => {
+                verify_role_has_permission(&params.staff_role, RolePermissions::DeleteComment)
+            }
+            ReportResolution::Rejected => true,
+        };
+
+        if !staff_can_solve || !staff_can_apply_resolution {
             return Err(DomainError::unauthorized_err());
         }
 
-        let comm_report = self
+        let mut comm_report = self
             .comment_report_repository
             .find_by_id(params.com_report_id)
             .await
             .map_err(|err| generate_service_internal_error(
                 "Error occurred on Solve Comment Report Service, while fetching the comment report from database",
                 err,
-            ))?;
+            ))?
+            .ok_or(DomainError::resource_not_found_err())?;
 
-        if comm_report.is_none() {
-            return Err(DomainError::resource_not_found_err());
+        if comm_report.solved_by().is_some() {
+            return Err(
+                DomainError::bad_request_err().with_message("The report has already been solved.")
+            );
         }
 
-        let mut comm_report = comm_report.unwrap();
+        if params.resolution.acts_on_comment() && comm_report.comment_id().is_none() {
+            return Err(DomainError::bad_request_err()
+                .with_message("The reported comment no longer exists."));
+        }
 
-        comm_report.set_solved_by(Some(params.staff_id));
+        let resolution_notes = params
+            .resolution_notes
+            .map(|notes| notes.trim().to_owned())
+            .filter(|notes| !notes.is_empty());
 
-        let _comment_report = self.comment_report_repository.save(comm_report).await.map_err(|err| generate_service_internal_error(
-            "Error occurred on Solve Comment Report Service, while updating the comment report at database",
-            err,
-        ))?;
+        comm_report.resolve(params.staff_id, params.resolution, resolution_notes);
 
-        Ok(())
+        self.comment_report_repository.solve(comm_report).await.map_err(|err| generate_service_internal_error(
+            "Error occurred on Solve Comment Report Service, while solving the comment report at database",
+            err,
+        ))
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use std::sync::{Arc, Mutex};
 
-    use crate::domain::domain_entities::comment_report::{
-        CommentReport, CommentReportIdTrait, CommentReportTrait,
-    };
+    use crate::domain::domain_entities::comment_report::DraftCommentReport;
+    use crate::domain::domain_entities::comment_report::ReportReason;
     use crate::domain::domain_entities::role::Role;
-    use crate::domain::repositories::comment_report_repository::MockCommentReportRepositoryTrait;
-    use crate::libs::time::TimeHelper;
+    use crate::tests::repositories::comment_report_repository::get_comment_report_repository;
 
+    use std::sync::{Arc, Mutex};
     use tokio;
 
     #[tokio::test]
     async fn test() {
-        let mut mocked_comment_report_repo = MockCommentReportRepositoryTrait::new();
-
-        type CommentReportDB = Arc<Mutex<Vec<CommentReport>>>;
-
-        let comm_report_db: CommentReportDB = Arc::new(Mutex::new(vec![]));
-
-        let comment_report_1 = CommentReport::new_from_existing(
-            1,
-            Uuid::new_v4(),
-            Uuid::new_v4(),
-            "Esse comentário é tóxico.".into(),
-            None,
-            TimeHelper::now(),
-        );
-
-        let comment_report_id_1 = comment_report_1.id();
-
-        let comment_report_2 = CommentReport::new_from_existing(
-            2,
-            Uuid::new_v4(),
-            Uuid::new_v4(),
-            "Estão me ofendendo neste comentário!".into(),
-            None,
-            TimeHelper::now(),
-        );
-
-        let comment_report_id_2 = comment_report_2.id();
-
-        comm_report_db.lock().unwrap().push(comment_report_1);
-        comm_report_db.lock().unwrap().push(comment_report_2);
-
-        let comm_report_db_clone = Arc::clone(&comm_report_db);
-        mocked_comment_report_repo
-            .expect_find_by_id()
-            .returning(move |id| {
-                let mut _comm_report: Option<CommentReport> = None;
-
-                for comm_rep in comm_report_db_clone.lock().unwrap().iter() {
-                    if comm_rep.id().eq(&id) {
-                        _comm_report = Some(comm_rep.clone());
-                        break;
-                    }
-                }
-
-                Ok(_comm_report)
-            });
-
-        let comm_report_db_clone = Arc::clone(&comm_report_db);
-        mocked_comment_report_repo
-            .expect_save()
-            .returning(move |comm_report| {
-                let mut index = None;
-
-                for (i, comm_rep) in comm_report_db_clone.lock().unwrap().iter().enumerate() {
-                    if comm_rep.id().eq(&comm_report.id()) {
-                        index = Some(i);
-                    }
-                }
-
-                if index.is_some() {
-                    comm_report_db_clone.lock().unwrap()[index.unwrap()] = comm_report.clone();
-                }
-
-                Ok(comm_report)
-            });
-
-        let sut = SolveCommentReportService {
-            comment_report_repository: mocked_comment_report_repo,
-        };
+        let (comm_report_db, comment_report_repository) =
+            get_comment_report_repository(Arc::new(Mutex::new(vec![])));
+
+        let comment_id = Uuid::new_v4();
+        let other_comment_id = Uuid::new_v4();
+
+        for (id, (comment_id, message)) in [
+            (comment_id, "Esse comentário é tóxico."),
+            (comment_id, "Estão me ofendendo neste comentário!"),
+            (other_comment_id, "Golpe, não cliquem!"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let report = DraftCommentReport::new(
+                comment_id,
+                Uuid::new_v4(),
+                message.into(),
+                ReportReason::Offensive,
+            );
+
+            comm_report_db
+                .lock()
+                .unwrap()
+                .push(report.to_comment_report(id as i32 + 1));
+        }
+
+        let sut = SolveCommentReportService::new(comment_report_repository);
 
         let fake_staff_id = Uuid::new_v4();
 
-        let result = sut
+        let unauthorized = sut
+            .exec(SolveCommentReportParams {
+                staff_id: fake_staff_id,
+                staff_role: Role::User,
+                com_report_id: 1,
+                resolution: ReportResolution::Rejected,
+                resolution_notes: None,
+            })
+            .await;
+
+        assert!(unauthorized.is_err());
+        assert_eq!(None, comm_report_db.lock().unwrap()[0].solved_by());
+
+        // coordinators can hide comments, but not delete them
+        let cannot_delete = sut
             .exec(SolveCommentReportParams {
                 staff_id: fake_staff_id,
                 staff_role: Role::Coord,
-                com_report_id: comment_report_id_1,
+                com_report_id: 1,
+                resolution: ReportResolution::CommentDeleted,
+                resolution_notes: None,
             })
             .await;
 
-        assert!(result.is_ok());
-        assert_eq!(
-            Some(fake_staff_id),
-            comm_report_db.lock().unwrap()[0].solved_by()
-        );
+        assert!(cannot_delete.is_err());
 
-        let result_2 = sut
+        let report = sut
             .exec(SolveCommentReportParams {
                 staff_id: fake_staff_id,
-                staff_role: Role::User,
-                com_report_id: comment_report_id_2,
+                staff_role: Role::Coord,
+                com_report_id: 1,
+                resolution: ReportResolution::CommentHidden,
+                resolution_notes: Some("  Ofensa a outro usuário.  ".into()),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(Some(fake_staff_id), report.solved_by());
+        assert_eq!(Some(ReportResolution::CommentHidden), report.resolution());
+        assert_eq!(Some("Ofensa a outro usuário."), report.resolution_notes());
+
+        {
+            let db = comm_report_db.lock().unwrap();
+
+            assert_eq!(db[0], report);
+            assert_eq!(Some(fake_staff_id), db[1].solved_by());
+            assert_eq!(Some(ReportResolution::CommentHidden), db[1].resolution());
+            assert_eq!(None, db[2].solved_by());
+        }
+
+        let already_solved = sut
+            .exec(SolveCommentReportParams {
+                staff_id: fake_staff_id,
+                staff_role: Role::Coord,
+                com_report_id: 2,
+                resolution: ReportResolution::Rejected,
+                resolution_notes: None,
             })
             .await;
 
-        assert!(result_2.is_err());
-        assert_eq!(None, comm_report_db.lock().unwrap()[1].solved_by());
+        assert!(already_solved.is_err());
+
+        let report = sut
+            .exec(SolveCommentReportParams {
+                staff_id: fake_staff_id,
+                staff_role: Role::Admin,
+                com_report_id: 3,
+                resolution: ReportResolution::CommentDeleted,
+                resolution_notes: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(Some(ReportResolution::CommentDeleted), report.resolution());
+        assert!(report.comment_id().is_none());
+        assert!(report.resolution_notes().is_none());
     }
 }
@@ -70,6 +70,7 @@ mod test {
 
     use crate::domain::domain_entities::role::Role;
     use crate::domain::repositories::comment_repository::MockCommentRepositoryTrait;
+    use crate::tests::repositories::comment_repository::get_comment_repository;
 
     use std::sync::{Arc, Mutex};
     use tokio;
@@ -137,4 +138,28 @@ mod test {
 
         assert!(!res.unwrap().is_active());
     }
+
+    #[tokio::test]
+    async fn test_keeps_replies() {
+        let (comment_db, mocked_comment_repo) = get_comment_repository();
+
+        let parent = Comment::new(Uuid::new_v4(), Some(Uuid::new_v4()), "Parent".into());
+        let reply = Comment::new_reply(Uuid::new_v4(), &parent, "Reply".into());
+
+        comment_db.lock().unwrap().push(parent.clone());
+        comment_db.lock().unwrap().push(reply.clone());
+
+        let sut = ToggleCommentVisibilityService::new(mocked_comment_repo);
+
+        let res = sut
+            .exec(ToggleCommentVisibilityParams {
+                user_role: &Role::Coord,
+                comment_id: parent.id(),
+            })
+            .await
+            .unwrap();
+
+        assert!(!res.is_active());
+        assert_eq!(vec![res, reply], *comment_db.lock().unwrap());
+    }
 }
@@ -0,0 +1,129 @@
+use uuid::Uuid;
+
+use crate::domain::domain_entities::blocked_word::{BlockedWord, BlockedWordAction};
+use crate::domain::domain_entities::role::Role;
+use crate::domain::politics::word_filter_politics::WordFilterPolitics;
+use crate::domain::repositories::blocked_word_repository::BlockedWordRepositoryTrait;
+use crate::error::DomainError;
+use crate::util::{generate_service_internal_error, verify_role_has_permission, RolePermissions};
+
+pub struct UpdateBlockedWordParams {
+    pub staff_role: Role,
+    pub blocked_word_id: Uuid,
+    pub pattern: Option<String>,
+    /// Only taken into account along with a new `pattern`.
+    pub is_regex: Option<bool>,
+    pub action: Option<BlockedWordAction>,
+}
+
+pub struct UpdateBlockedWordService<BWR: BlockedWordRepositoryTrait> {
+    blocked_word_repository: BWR,
+}
+
+impl<BWR: BlockedWordRepositoryTrait> UpdateBlockedWordService<BWR> {
+    pub fn new(blocked_word_repository: BWR) -> Self {
+        UpdateBlockedWordService {
+            blocked_word_repository,
+        }
+    }
+
+    pub async fn exec(&self, params: UpdateBlockedWordParams) -> Result<BlockedWord, DomainError> {
+        if !verify_role_has_permission(&params.staff_role, RolePermissions::UpdateBlockedWord) {
+            return Err(DomainError::unauthorized_err());
+        }
+
+        let mut blocked_word = self
+            .blocked_word_repository
+            .find_by_id(params.blocked_word_id)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Update Blocked Word Service, while fetching the blocked word",
+                    err,
+                )
+            })?
+            .ok_or(DomainError::resource_not_found_err())?;
+
+        if let Some(pattern) = params.pattern {
+            let pattern = pattern.trim().to_owned();
+            let is_regex = params.is_regex.unwrap_or(blocked_word.is_regex());
+
+            WordFilterPolitics::validate(&pattern, is_regex)?;
+            blocked_word.set_pattern(pattern, is_regex);
+        }
+
+        if let Some(action) = params.action {
+            blocked_word.set_action(action);
+        }
+
+        self.blocked_word_repository
+            .save(blocked_word)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Update Blocked Word Service, while saving the blocked word",
+                    err,
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tests::repositories::blocked_word_repository::get_blocked_word_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (db, blocked_word_repository) = get_blocked_word_repository();
+        let sut = UpdateBlockedWordService::new(blocked_word_repository);
+
+        let blocked_word = BlockedWord::new(
+            "golpe".into(),
+            false,
+            BlockedWordAction::Reject,
+            Uuid::new_v4(),
+        );
+        db.lock().unwrap().push(blocked_word.clone());
+
+        let res = sut
+            .exec(UpdateBlockedWordParams {
+                staff_role: Role::Coord,
+                blocked_word_id: blocked_word.id(),
+                pattern: Some("[".into()),
+                is_regex: Some(true),
+                action: None,
+            })
+            .await;
+
+        assert!(matches!(res, Err(DomainError::BadRequest(_))));
+
+        let updated = sut
+            .exec(UpdateBlockedWordParams {
+                staff_role: Role::Coord,
+                blocked_word_id: blocked_word.id(),
+                pattern: Some(r"g[o0]lpe\s+do\s+pix".into()),
+                is_regex: Some(true),
+                action: Some(BlockedWordAction::Flag),
+            })
+            .await
+            .unwrap();
+
+        assert!(updated.is_regex());
+        assert_eq!(BlockedWordAction::Flag, updated.action());
+        assert!(updated.updated_at().is_some());
+        assert_eq!(updated, db.lock().unwrap()[0]);
+
+        let res = sut
+            .exec(UpdateBlockedWordParams {
+                staff_role: Role::User,
+                blocked_word_id: blocked_word.id(),
+                pattern: None,
+                is_regex: None,
+                action: Some(BlockedWordAction::Mask),
+            })
+            .await;
+
+        assert!(matches!(res, Err(DomainError::Unauthorized(_))));
+    }
+}
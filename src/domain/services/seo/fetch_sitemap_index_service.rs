@@ -0,0 +1,178 @@
+use crate::core::pagination::PaginationParameters;
+use crate::domain::repositories::article_repository::{
+    ArticleRepositoryTrait, FindManyArticlesResponse,
+};
+use crate::domain::repositories::article_tag_repository::{
+    ArticleTagRepositoryTrait, FindManyArticleTagsResponse,
+};
+use crate::domain::repositories::free_badge_repository::{
+    FindManyFreeBadgesResponse, FreeBadgeQuery, FreeBadgeRepositoryTrait,
+};
+use crate::error::DomainError;
+use crate::libs::time::TimeHelper;
+use crate::util::generate_service_internal_error;
+
+use super::MAX_URLS_PER_SITEMAP;
+
+/// How many child sitemaps of each kind there are. The static pages always fit in a single one.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SitemapIndex {
+    pub articles_sitemaps: u32,
+    pub tags_sitemaps: u32,
+    pub free_badges_sitemaps: u32,
+}
+
+pub struct FetchSitemapIndexService<AR, ATR, FBR>
+where
+    AR: ArticleRepositoryTrait,
+    ATR: ArticleTagRepositoryTrait,
+    FBR: FreeBadgeRepositoryTrait,
+{
+    article_repository: AR,
+    article_tag_repository: ATR,
+    free_badge_repository: FBR,
+}
+
+impl<AR: ArticleRepositoryTrait, ATR: ArticleTagRepositoryTrait, FBR: FreeBadgeRepositoryTrait>
+    FetchSitemapIndexService<AR, ATR, FBR>
+{
+    pub fn new(
+        article_repository: AR,
+        article_tag_repository: ATR,
+        free_badge_repository: FBR,
+    ) -> Self {
+        FetchSitemapIndexService {
+            article_repository,
+            article_tag_repository,
+            free_badge_repository,
+        }
+    }
+
+    pub async fn exec(&self) -> Result<SitemapIndex, DomainError> {
+        let FindManyArticlesResponse(_, articles_count) = self
+            .article_repository
+            .find_many(counting_params(), Some(true))
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Fetch Sitemap Index Service, while counting the approved articles",
+                    err,
+                )
+            })?;
+
+        let FindManyArticleTagsResponse(_, tags_count) = self
+            .article_tag_repository
+            .find_many(counting_params())
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Fetch Sitemap Index Service, while counting the article tags",
+                    err,
+                )
+            })?;
+
+        let FindManyFreeBadgesResponse(_, free_badges_count) = self
+            .free_badge_repository
+            .find_many(PaginationParameters {
+                query: Some(FreeBadgeQuery::AvailableAt(TimeHelper::now())),
+                ..counting_params()
+            })
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Fetch Sitemap Index Service, while counting the free badges",
+                    err,
+                )
+            })?;
+
+        Ok(SitemapIndex {
+            articles_sitemaps: sitemaps_needed_for(articles_count),
+            tags_sitemaps: sitemaps_needed_for(tags_count),
+            free_badges_sitemaps: sitemaps_needed_for(free_badges_count),
+        })
+    }
+}
+
+/// Only the total of items matters, so a single item is fetched.
+fn counting_params<QT: Clone>() -> PaginationParameters<QT> {
+    PaginationParameters {
+        page: 1,
+        items_per_page: 1,
+        query: None,
+    }
+}
+
+fn sitemaps_needed_for(urls_count: u64) -> u32 {
+    urls_count.div_ceil(MAX_URLS_PER_SITEMAP as u64) as u32
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use uuid::Uuid;
+
+    use crate::domain::domain_entities::article::Article;
+    use crate::domain::domain_entities::free_badge::FreeBadge;
+    use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::article_tag_repository::get_article_tag_repository;
+    use crate::tests::repositories::free_badge_repository::get_free_badge_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (articles_db, article_repository) = get_article_repository();
+        let (_, article_tag_repository) = get_article_tag_repository();
+        let (badges_db, free_badge_repository) = get_free_badge_repository();
+
+        let mut approved_article = Article::new(
+            Uuid::new_v4(),
+            "Aprovado".into(),
+            "Conteúdo".into(),
+            "url".into(),
+            1,
+            "Tag".into(),
+        );
+        approved_article.set_approved(true);
+
+        let unapproved_article = Article::new(
+            Uuid::new_v4(),
+            "Não aprovado".into(),
+            "Conteúdo".into(),
+            "url".into(),
+            1,
+            "Tag".into(),
+        );
+
+        articles_db
+            .lock()
+            .unwrap()
+            .extend([approved_article, unapproved_article]);
+
+        badges_db.lock().unwrap().push(FreeBadge::new(
+            "PT001".into(),
+            "image".into(),
+            "link".into(),
+            false,
+            Some(TimeHelper::now() - chrono::TimeDelta::days(1)),
+        ));
+
+        let sut = FetchSitemapIndexService::new(
+            article_repository,
+            article_tag_repository,
+            free_badge_repository,
+        );
+
+        assert_eq!(
+            SitemapIndex {
+                articles_sitemaps: 1,
+                tags_sitemaps: 0,
+                free_badges_sitemaps: 0,
+            },
+            sut.exec().await.unwrap()
+        );
+
+        assert_eq!(0, sitemaps_needed_for(0));
+        assert_eq!(1, sitemaps_needed_for(MAX_URLS_PER_SITEMAP as u64));
+        assert_eq!(2, sitemaps_needed_for(MAX_URLS_PER_SITEMAP as u64 + 1));
+    }
+}
@@ -0,0 +1,284 @@
+use chrono::NaiveDateTime as DateTime;
+use uuid::Uuid;
+
+use crate::core::pagination::PaginationParameters;
+use crate::domain::domain_entities::slug::Slug;
+use crate::domain::repositories::article_repository::{
+    ArticleRepositoryTrait, FindManyArticlesResponse,
+};
+use crate::domain::repositories::article_tag_repository::{
+    ArticleTagRepositoryTrait, FindManyArticleTagsResponse,
+};
+use crate::domain::repositories::free_badge_repository::{
+    FindManyFreeBadgesResponse, FreeBadgeQuery, FreeBadgeRepositoryTrait,
+};
+use crate::error::DomainError;
+use crate::libs::time::TimeHelper;
+use crate::util::generate_service_internal_error;
+
+use super::MAX_URLS_PER_SITEMAP;
+
+/// Paths, relative to the site's root, of the pages that aren't backed by any entity.
+pub const STATIC_PAGES: [&str; 4] = ["", "articles", "free-badges", "team"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sitemap {
+    StaticPages,
+    /// Holds the 1-based number of the sitemap.
+    Articles(u32),
+    Tags(u32),
+    FreeBadges(u32),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SitemapEntry {
+    StaticPage(&'static str),
+    Article { slug: Slug, last_modified: DateTime },
+    Tag { id: i32 },
+    FreeBadge { id: Uuid, last_modified: DateTime },
+}
+
+impl SitemapEntry {
+    pub fn last_modified(&self) -> Option<DateTime> {
+        match self {
+            SitemapEntry::Article { last_modified, .. }
+            | SitemapEntry::FreeBadge { last_modified, .. } => Some(*last_modified),
+            _ => None,
+        }
+    }
+}
+
+pub struct FetchSitemapParams {
+    pub sitemap: Sitemap,
+}
+
+pub struct FetchSitemapService<AR, ATR, FBR>
+where
+    AR: ArticleRepositoryTrait,
+    ATR: ArticleTagRepositoryTrait,
+    FBR: FreeBadgeRepositoryTrait,
+{
+    article_repository: AR,
+    article_tag_repository: ATR,
+    free_badge_repository: FBR,
+}
+
+impl<AR: ArticleRepositoryTrait, ATR: ArticleTagRepositoryTrait, FBR: FreeBadgeRepositoryTrait>
+    FetchSitemapService<AR, ATR, FBR>
+{
+    pub fn new(
+        article_repository: AR,
+        article_tag_repository: ATR,
+        free_badge_repository: FBR,
+    ) -> Self {
+        FetchSitemapService {
+            article_repository,
+            article_tag_repository,
+            free_badge_repository,
+        }
+    }
+
+    pub async fn exec(&self, params: FetchSitemapParams) -> Result<Vec<SitemapEntry>, DomainError> {
+        let entries = match params.sitemap {
+            Sitemap::StaticPages => STATIC_PAGES
+                .into_iter()
+                .map(SitemapEntry::StaticPage)
+                .collect(),
+            Sitemap::Articles(page) => self.get_articles_entries(page).await?,
+            Sitemap::Tags(page) => self.get_tags_entries(page).await?,
+            Sitemap::FreeBadges(page) => self.get_free_badges_entries(page).await?,
+        };
+
+        // sitemaps past the last one don't exist, but the first one does even if there's nothing in it
+        let is_first_sitemap = matches!(
+            params.sitemap,
+            Sitemap::StaticPages | Sitemap::Articles(1) | Sitemap::Tags(1) | Sitemap::FreeBadges(1)
+        );
+
+        if entries.is_empty() && !is_first_sitemap {
+            return Err(DomainError::resource_not_found_err());
+        }
+
+        Ok(entries)
+    }
+
+    async fn get_articles_entries(&self, page: u32) -> Result<Vec<SitemapEntry>, DomainError> {
+        let FindManyArticlesResponse(articles, _) = self
+            .article_repository
+            .find_many(sitemap_params(page)?, Some(true))
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Fetch Sitemap Service, while fetching the approved articles",
+                    err,
+                )
+            })?;
+
+        Ok(articles
+            .into_iter()
+            .filter(|article| article.approved())
+            .map(|article| SitemapEntry::Article {
+                last_modified: article.updated_at().unwrap_or(article.created_at()),
+                slug: article.slug(),
+            })
+            .collect())
+    }
+
+    async fn get_tags_entries(&self, page: u32) -> Result<Vec<SitemapEntry>, DomainError> {
+        let FindManyArticleTagsResponse(tags, _) = self
+            .article_tag_repository
+            .find_many(sitemap_params(page)?)
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Fetch Sitemap Service, while fetching the article tags",
+                    err,
+                )
+            })?;
+
+        Ok(tags
+            .into_iter()
+            .map(|tag| SitemapEntry::Tag { id: tag.id() })
+            .collect())
+    }
+
+    async fn get_free_badges_entries(&self, page: u32) -> Result<Vec<SitemapEntry>, DomainError> {
+        let FindManyFreeBadgesResponse(free_badges, _) = self
+            .free_badge_repository
+            .find_many(PaginationParameters {
+                query: Some(FreeBadgeQuery::AvailableAt(TimeHelper::now())),
+                ..sitemap_params(page)?
+            })
+            .await
+            .map_err(|err| {
+                generate_service_internal_error(
+                    "Error occurred on Fetch Sitemap Service, while fetching the free badges",
+                    err,
+                )
+            })?;
+
+        Ok(free_badges
+            .into_iter()
+            .map(|badge| SitemapEntry::FreeBadge {
+                id: badge.id(),
+                last_modified: badge.created_at(),
+            })
+            .collect())
+    }
+}
+
+fn sitemap_params<QT: Clone>(page: u32) -> Result<PaginationParameters<QT>, DomainError> {
+    if page == 0 {
+        return Err(DomainError::resource_not_found_err());
+    }
+
+    Ok(PaginationParameters {
+        page,
+        items_per_page: MAX_URLS_PER_SITEMAP,
+        query: None,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use http::StatusCode;
+
+    use crate::domain::domain_entities::article::Article;
+    use crate::domain::domain_entities::free_badge::FreeBadge;
+    use crate::tests::repositories::article_repository::get_article_repository;
+    use crate::tests::repositories::article_tag_repository::get_article_tag_repository;
+    use crate::tests::repositories::free_badge_repository::get_free_badge_repository;
+
+    #[tokio::test]
+    async fn test() {
+        let (articles_db, article_repository) = get_article_repository();
+        let (_, article_tag_repository) = get_article_tag_repository();
+        let (badges_db, free_badge_repository) = get_free_badge_repository();
+
+        let mut approved_article = Article::new(
+            Uuid::new_v4(),
+            "Aprovado".into(),
+            "Conteúdo".into(),
+            "url".into(),
+            1,
+            "Tag".into(),
+        );
+        approved_article.set_approved(true);
+        approved_article.set_title("Aprovado e editado".into());
+
+        let unapproved_article = Article::new(
+            Uuid::new_v4(),
+            "Não aprovado".into(),
+            "Conteúdo".into(),
+            "url".into(),
+            1,
+            "Tag".into(),
+        );
+
+        articles_db
+            .lock()
+            .unwrap()
+            .extend([approved_article.clone(), unapproved_article]);
+
+        let available_badge =
+            FreeBadge::new("PT001".into(), "image".into(), "link".into(), false, None);
+        let expired_badge = FreeBadge::new(
+            "PT002".into(),
+            "image".into(),
+            "link".into(),
+            false,
+            Some(TimeHelper::now() - chrono::TimeDelta::days(1)),
+        );
+
+        badges_db
+            .lock()
+            .unwrap()
+            .extend([available_badge.clone(), expired_badge]);
+
+        let sut = FetchSitemapService::new(
+            article_repository,
+            article_tag_repository,
+            free_badge_repository,
+        );
+
+        let entries = sut
+            .exec(FetchSitemapParams {
+                sitemap: Sitemap::Articles(1),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec![SitemapEntry::Article {
+                slug: approved_article.slug(),
+                last_modified: approved_article.updated_at().unwrap(),
+            }],
+            entries
+        );
+
+        let entries = sut
+            .exec(FetchSitemapParams {
+                sitemap: Sitemap::FreeBadges(1),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec![SitemapEntry::FreeBadge {
+                id: available_badge.id(),
+                last_modified: available_badge.created_at(),
+            }],
+            entries
+        );
+
+        let result = sut
+            .exec(FetchSitemapParams {
+                sitemap: Sitemap::Articles(2),
+            })
+            .await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.unwrap_err().get_code());
+    }
+}
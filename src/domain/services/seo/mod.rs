@@ -0,0 +1,5 @@
+pub mod fetch_sitemap_index_service;
+pub mod fetch_sitemap_service;
+
+/// The maximum amount of URLs a single sitemap may contain, as defined by the sitemaps protocol.
+pub const MAX_URLS_PER_SITEMAP: u32 = 50_000;
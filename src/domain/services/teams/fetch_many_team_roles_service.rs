@@ -1,9 +1,11 @@
 use log::error;
 
 use crate::core::pagination::{PaginationParameters, PaginationResponse, DEFAULT_PER_PAGE};
+use crate::core::query::Sort;
 use crate::domain::domain_entities::team_role::TeamRole;
 use crate::domain::repositories::team_role_repository::{
-    FindManyTeamRolesResponse, TeamRoleQueryType, TeamRoleRepositoryTrait,
+    FindManyTeamRolesResponse, TeamRoleQuery, TeamRoleQueryType, TeamRoleRepositoryTrait,
+    TeamRoleSortField,
 };
 use crate::error::DomainError;
 
@@ -12,7 +14,9 @@ use crate::{LOG_SEP, R_EOL};
 pub struct FetchManyTeamRolesParams {
     pub page: Option<u32>,
     pub per_page: Option<u32>,
-    pub query: Option<TeamRoleQueryType>,
+    /// Team roles must match all of them.
+    pub filters: Vec<TeamRoleQueryType>,
+    pub sort: Vec<Sort<TeamRoleSortField>>,
 }
 
 #[derive(Debug)]
@@ -58,7 +62,10 @@ impl<TeamRoleRepository: TeamRoleRepositoryTrait> FetchManyTeamRolesService<Team
             .find_many(PaginationParameters {
                 items_per_page,
                 page,
-                query: params.query,
+                query: Some(TeamRoleQuery {
+                    filters: params.filters,
+                    sort: params.sort,
+                }),
             })
             .await;
 
@@ -86,6 +93,7 @@ mod test {
     use crate::domain::repositories::team_role_repository::MockTeamRoleRepositoryTrait;
 
     use super::*;
+    use crate::tests::query_spec::apply_query_spec;
     use std::sync::{Arc, Mutex};
     use tokio;
 
@@ -112,21 +120,17 @@ mod test {
                     query,
                 } = params;
 
-                let mut roles = Vec::new();
-
-                if query.is_none() {
-                    roles = to_move_tr_db.lock().unwrap().clone();
-                } else {
-                    match query.unwrap() {
-                        TeamRoleQueryType::Title(title) => {
-                            for tr in to_move_tr_db.lock().unwrap().iter() {
-                                if tr.title().contains(&title) {
-                                    roles.push(tr.clone());
-                                }
-                            }
-                        }
-                    }
-                }
+                let roles = apply_query_spec(
+                    to_move_tr_db.lock().unwrap().clone(),
+                    query.as_ref(),
+                    |tr, query| match query {
+                        TeamRoleQueryType::Title(title) => tr.title().contains(title.as_str()),
+                    },
+                    |a, b, field| match field {
+                        TeamRoleSortField::CreatedAt => a.created_at().cmp(&b.created_at()),
+                        TeamRoleSortField::Title => a.title().cmp(b.title()),
+                    },
+                );
 
                 let total_before_paginating = roles.len();
 
@@ -154,7 +158,8 @@ mod test {
             .exec(FetchManyTeamRolesParams {
                 page: None,
                 per_page: None,
-                query: Some(TeamRoleQueryType::Title("1".to_string())),
+                filters: vec![TeamRoleQueryType::Title("1".to_string())],
+                sort: vec![],
             })
             .await;
 
@@ -169,7 +174,8 @@ mod test {
             .exec(FetchManyTeamRolesParams {
                 page: None,
                 per_page: None,
-                query: None,
+                filters: vec![],
+                sort: vec![],
             })
             .await;
 
@@ -1,7 +1,9 @@
 use crate::core::pagination::{PaginationParameters, PaginationResponse, DEFAULT_PER_PAGE};
+use crate::core::query::Sort;
 use crate::domain::domain_entities::team_user::TeamUser;
 use crate::domain::repositories::team_user_repository::{
-    FindManyTeamUsersResponse, TeamUserQueryType, TeamUserRepositoryTrait,
+    FindManyTeamUsersResponse, TeamUserQuery, TeamUserQueryType, TeamUserRepositoryTrait,
+    TeamUserSortField,
 };
 use crate::error::DomainError;
 use crate::util::generate_service_internal_error;
@@ -15,7 +17,9 @@ pub struct FetchManyTeamUsersResponse {
 pub struct FetchManyTeamUsersParams {
     pub page: Option<u32>,
     pub per_page: Option<u32>,
-    pub query: Option<TeamUserQueryType>,
+    /// Team users must match all of them.
+    pub filters: Vec<TeamUserQueryType>,
+    pub sort: Vec<Sort<TeamUserSortField>>,
 }
 
 pub struct FetchManyTeamUsersService<TeamUserRepository: TeamUserRepositoryTrait> {
@@ -55,7 +59,10 @@ impl<TeamUserRepository: TeamUserRepositoryTrait> FetchManyTeamUsersService<Team
             .find_many(PaginationParameters {
                 items_per_page,
                 page,
-                query: params.query,
+                query: Some(TeamUserQuery {
+                    filters: params.filters,
+                    sort: params.sort,
+                }),
             })
             .await
             .map_err(|err| generate_service_internal_error(
@@ -76,6 +83,8 @@ mod test {
     use crate::domain::repositories::team_user_repository::MockTeamUserRepositoryTrait;
 
     use super::*;
+    use crate::core::query::SortDirection;
+    use crate::tests::query_spec::apply_query_spec;
     use std::sync::{Arc, Mutex};
     use tokio;
 
@@ -116,32 +125,21 @@ mod test {
                     query,
                 } = params;
 
-                let mut users = Vec::new();
-
-                if query.is_none() {
-                    users = to_move_tr_db.lock().unwrap().clone();
-                } else {
-                    match query.unwrap() {
-                        TeamUserQueryType::Nickname(nickname) => {
-                            for tr in to_move_tr_db.lock().unwrap().iter() {
-                                if tr
-                                    .nickname()
-                                    .to_lowercase()
-                                    .contains(&nickname.to_lowercase())
-                                {
-                                    users.push(tr.clone());
-                                }
-                            }
-                        }
-                        TeamUserQueryType::TeamRole(id) => {
-                            for tr in to_move_tr_db.lock().unwrap().iter() {
-                                if tr.team_role_id().eq(&id) {
-                                    users.push(tr.clone());
-                                }
-                            }
-                        }
-                    }
-                }
+                let users = apply_query_spec(
+                    to_move_tr_db.lock().unwrap().clone(),
+                    query.as_ref(),
+                    |tr, query| match query {
+                        TeamUserQueryType::Nickname(nickname) => tr
+                            .nickname()
+                            .to_lowercase()
+                            .contains(&nickname.to_lowercase()),
+                        TeamUserQueryType::TeamRole(id) => tr.team_role_id().eq(id),
+                    },
+                    |a, b, field| match field {
+                        TeamUserSortField::CreatedAt => a.created_at().cmp(&b.created_at()),
+                        TeamUserSortField::Nickname => a.nickname().cmp(b.nickname()),
+                    },
+                );
 
                 let total_before_paginating = users.len();
 
@@ -169,7 +167,8 @@ mod test {
             .exec(FetchManyTeamUsersParams {
                 page: None,
                 per_page: None,
-                query: Some(TeamUserQueryType::Nickname("parme".to_string())),
+                filters: vec![TeamUserQueryType::Nickname("parme".to_string())],
+                sort: vec![],
             })
             .await;
 
@@ -184,7 +183,8 @@ mod test {
             .exec(FetchManyTeamUsersParams {
                 page: None,
                 per_page: None,
-                query: None,
+                filters: vec![],
+                sort: vec![],
             })
             .await;
 
@@ -195,5 +195,21 @@ mod test {
         assert_eq!(result_2.pagination.total_items, 2);
         assert_eq!(result_2.data[0].nickname(), "Flori".to_string());
         assert_eq!(result_2.data[1].nickname(), "Parme".to_string());
+
+        let result_3 = sut
+            .exec(FetchManyTeamUsersParams {
+                page: None,
+                per_page: None,
+                filters: vec![TeamUserQueryType::TeamRole(team_role.id())],
+                sort: vec![Sort {
+                    field: TeamUserSortField::Nickname,
+                    direction: SortDirection::Desc,
+                }],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result_3.data[0].nickname(), "Parme".to_string());
+        assert_eq!(result_3.data[1].nickname(), "Flori".to_string());
     }
 }
@@ -0,0 +1,22 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+#[cfg(test)]
+use mockall::automock;
+
+/// Stores files under slash-separated keys (e.g. `covers/<id>.png`) and tells the public URL each of them is served at.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait FileStorageTrait {
+    async fn put(
+        &self,
+        key: &str,
+        content: Vec<u8>,
+        content_type: &str,
+    ) -> Result<(), Box<dyn Error>>;
+    async fn delete(&self, key: &str) -> Result<(), Box<dyn Error>>;
+    fn url(&self, key: &str) -> String;
+    /// The inverse of `url`. Returns `None` if the URL doesn't point to this storage.
+    fn key_from_url(&self, url: &str) -> Option<String>;
+}
@@ -12,6 +12,21 @@ pub struct EnvConfig {
     pub host: String,
     pub port: u16,
     pub workers: usize,
+    pub home_articles_slots: Option<u32>,
+    pub comment_edit_window_minutes: Option<u32>,
+    pub comment_auto_close_days: Option<u32>,
+    pub comment_review_account_age_days: Option<u32>,
+    pub comment_review_min_approved_comments: Option<u32>,
+    pub comment_report_hide_threshold: Option<u32>,
+    pub comment_flood_user_cooldown_seconds: Option<u32>,
+    pub comment_flood_writer_cooldown_seconds: Option<u32>,
+    pub comment_flood_user_max_per_window: Option<u32>,
+    pub comment_flood_writer_max_per_window: Option<u32>,
+    pub comment_flood_window_minutes: Option<u32>,
+    pub comment_flood_duplicate_window_minutes: Option<u32>,
+    pub reactions: Option<String>,
+    pub uploads_dir: Option<String>,
+    pub uploads_url: Option<String>,
 }
 
 impl EnvConfig {
@@ -21,6 +21,8 @@ pub enum DomainError {
     Internal(String),
     InvalidCredentials(String),
     ResourceNotFound(String),
+    /// Carries after how many seconds the request may be retried.
+    TooManyRequests(String, u64),
     Unauthorized(String),
     UserAlreadyExists(String),
     Validation(String, ErrorsMap),
@@ -34,6 +36,7 @@ impl DomainError {
             DomainError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
             DomainError::InvalidCredentials(_) => StatusCode::UNAUTHORIZED,
             DomainError::ResourceNotFound(_) => StatusCode::NOT_FOUND,
+            DomainError::TooManyRequests(_, _) => StatusCode::TOO_MANY_REQUESTS,
             DomainError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
             DomainError::UserAlreadyExists(_) => StatusCode::CONFLICT,
             DomainError::Validation(_, _) => StatusCode::BAD_REQUEST,
@@ -47,6 +50,7 @@ impl DomainError {
             | DomainError::Internal(msg)
             | DomainError::InvalidCredentials(msg)
             | DomainError::ResourceNotFound(msg)
+            | DomainError::TooManyRequests(msg, _)
             | DomainError::Unauthorized(msg)
             | DomainError::UserAlreadyExists(msg)
             | DomainError::Validation(msg, _) => msg,
@@ -65,12 +69,23 @@ impl DomainError {
             DomainError::Internal(_) => DomainError::Internal(msg),
             DomainError::InvalidCredentials(_) => DomainError::InvalidCredentials(msg),
             DomainError::ResourceNotFound(_) => DomainError::ResourceNotFound(msg),
+            DomainError::TooManyRequests(_, retry_after) => {
+                DomainError::TooManyRequests(msg, retry_after)
+            }
             DomainError::Unauthorized(_) => DomainError::Unauthorized(msg),
             DomainError::UserAlreadyExists(_) => DomainError::UserAlreadyExists(msg),
             DomainError::Validation(_, errors_map) => DomainError::Validation(msg, errors_map),
         }
     }
 
+    /// After how many seconds the failed request may be retried, if that's known.
+    pub fn get_retry_after(&self) -> Option<u64> {
+        match self {
+            DomainError::TooManyRequests(_, retry_after) => Some(*retry_after),
+            _ => None,
+        }
+    }
+
     pub fn unauthorized_err() -> Self {
         Self::Unauthorized("Unauthorized.".into())
     }
@@ -91,6 +106,13 @@ impl DomainError {
         DomainError::ResourceNotFound("Resource not found.".into())
     }
 
+    pub fn too_many_requests_err(retry_after: u64) -> Self {
+        Self::TooManyRequests(
+            format!("Too many requests, try again in {retry_after} seconds."),
+            retry_after,
+        )
+    }
+
     pub fn user_already_exists_err(nickname: &str) -> Self {
         Self::UserAlreadyExists(format!("User with nickname {} already exist.", nickname))
     }
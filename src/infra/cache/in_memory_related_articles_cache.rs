@@ -0,0 +1,54 @@
+use chrono::{NaiveDateTime as DateTime, TimeDelta};
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, RwLock};
+use uuid::Uuid;
+
+use crate::domain::cache::related_articles_cache::RelatedArticlesCacheTrait;
+use crate::domain::domain_entities::article::Article;
+use crate::libs::time::TimeHelper;
+
+/// Even if no article changes, rankings get stale as their recency score decays.
+const ENTRIES_TTL_IN_MINUTES: i64 = 60;
+
+/// The cache shared by the whole application. The services that create, edit or delete articles invalidate it.
+pub static RELATED_ARTICLES_CACHE: LazyLock<InMemoryRelatedArticlesCache> =
+    LazyLock::new(InMemoryRelatedArticlesCache::new);
+
+type Entries = HashMap<Uuid, (DateTime, Vec<Article>)>;
+
+#[derive(Clone, Default)]
+pub struct InMemoryRelatedArticlesCache {
+    entries: Arc<RwLock<Entries>>,
+}
+
+impl InMemoryRelatedArticlesCache {
+    pub fn new() -> Self {
+        InMemoryRelatedArticlesCache::default()
+    }
+}
+
+impl RelatedArticlesCacheTrait for InMemoryRelatedArticlesCache {
+    fn get(&self, article_id: Uuid) -> Option<Vec<Article>> {
+        let entries = self.entries.read().unwrap();
+        let (cached_at, related_articles) = entries.get(&article_id)?;
+
+        if TimeHelper::now().signed_duration_since(*cached_at)
+            > TimeDelta::minutes(ENTRIES_TTL_IN_MINUTES)
+        {
+            return None;
+        }
+
+        Some(related_articles.clone())
+    }
+
+    fn set(&self, article_id: Uuid, related_articles: Vec<Article>) {
+        self.entries
+            .write()
+            .unwrap()
+            .insert(article_id, (TimeHelper::now(), related_articles));
+    }
+
+    fn invalidate(&self) {
+        self.entries.write().unwrap().clear();
+    }
+}
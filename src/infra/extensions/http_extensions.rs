@@ -1,5 +1,8 @@
 use crate::error::DomainError;
-use actix_web::{http::StatusCode, HttpResponse, HttpResponseBuilder, ResponseError};
+use actix_web::{
+    http::{header, StatusCode},
+    HttpResponse, HttpResponseBuilder, ResponseError,
+};
 use serde_json::json;
 
 impl ResponseError for DomainError {
@@ -14,6 +17,14 @@ impl ResponseError for DomainError {
                 "message": msg,
                 "errors": errors_map
             }))
+        } else if let Some(retry_after) = self.get_retry_after() {
+            HttpResponseBuilder::new(self.status_code())
+                .insert_header((header::RETRY_AFTER, retry_after))
+                .json(json!({
+                    "code": self.get_code().as_u16(),
+                    "message": self.get_message(),
+                    "retryAfter": retry_after
+                }))
         } else {
             HttpResponseBuilder::new(self.status_code()).json(json!({
                 "code": self.get_code().as_u16(),
@@ -0,0 +1,65 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::SystemTime;
+
+use actix_web::http::header::{
+    EntityTag, Header, HttpDate, IfModifiedSince, IfNoneMatch, CONTENT_TYPE, ETAG, IF_NONE_MATCH,
+    LAST_MODIFIED,
+};
+use actix_web::{HttpRequest, HttpResponse};
+use chrono::NaiveDateTime as DateTime;
+
+use crate::libs::time::TimeHelper;
+
+/// Answers with `body` along with its `ETag` and `Last-Modified` headers, or with a `304 Not Modified`
+/// if the client's cached copy, told by the `If-None-Match` or `If-Modified-Since` headers, is still fresh.
+pub fn respond_with_cache_validators(
+    request: &HttpRequest,
+    body: String,
+    content_type: &str,
+    last_modified: Option<DateTime>,
+) -> HttpResponse {
+    let mut hasher = DefaultHasher::new();
+    body.hash(&mut hasher);
+    let etag = EntityTag::new_strong(format!("{:x}", hasher.finish()));
+
+    let last_modified = last_modified
+        .map(|datetime| HttpDate::from(SystemTime::from(TimeHelper::with_offset(datetime))));
+
+    if is_not_modified(request, &etag, last_modified) {
+        return HttpResponse::NotModified()
+            .insert_header((ETAG, etag.to_string()))
+            .finish();
+    }
+
+    let mut response = HttpResponse::Ok();
+    response
+        .insert_header((CONTENT_TYPE, content_type))
+        .insert_header((ETAG, etag.to_string()));
+
+    if let Some(last_modified) = last_modified {
+        response.insert_header((LAST_MODIFIED, last_modified.to_string()));
+    }
+
+    response.body(body)
+}
+
+fn is_not_modified(
+    request: &HttpRequest,
+    etag: &EntityTag,
+    last_modified: Option<HttpDate>,
+) -> bool {
+    // as of RFC 9110, `If-Modified-Since` must be ignored whenever `If-None-Match` is present
+    if request.headers().contains_key(IF_NONE_MATCH) {
+        return match IfNoneMatch::parse(request) {
+            Ok(IfNoneMatch::Any) => true,
+            Ok(IfNoneMatch::Items(etags)) => etags.iter().any(|item| item.weak_eq(etag)),
+            Err(_) => false,
+        };
+    }
+
+    match (IfModifiedSince::parse(request), last_modified) {
+        (Ok(IfModifiedSince(since)), Some(last_modified)) => last_modified <= since,
+        _ => false,
+    }
+}
@@ -1,8 +1,12 @@
 use crate::core::pagination::DEFAULT_PER_PAGE;
+use crate::core::query::{DateRange, SortField};
 use crate::domain::factories::announcements::{
     create_announcement_service_factory, delete_announcement_service_factory,
     fetch_many_announcements_service_factory, update_announcement_service_factory,
 };
+use crate::domain::repositories::announcements_repository::{
+    AnnouncementQueryType, AnnouncementSortField,
+};
 use crate::domain::services::announcements::create_announcement_service::CreateAnnouncementParams;
 use crate::domain::services::announcements::delete_announcement_service::DeleteAnnouncementParams;
 use crate::domain::services::announcements::fetch_many_announcements_service::{
@@ -88,11 +92,38 @@ impl AnnouncementsController {
         query: web::Query<ListAnnouncementsDto>,
     ) -> AppResponse {
         let service = fetch_many_announcements_service_factory::exec(&db_conn);
+
+        let ListAnnouncementsDto {
+            page,
+            per_page,
+            description,
+            created_from,
+            created_to,
+            sort,
+        } = query.into_inner();
+
+        let created_at = DateRange::new(created_from, created_to)?;
+
+        let filters = [
+            description.map(AnnouncementQueryType::Description),
+            (!created_at.is_unbounded()).then_some(AnnouncementQueryType::CreatedAt(created_at)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        let sort = sort
+            .as_deref()
+            .map(AnnouncementSortField::parse_expression)
+            .transpose()?
+            .unwrap_or_default();
+
         service
             .exec(FetchManyAnnouncementsParams {
-                page: query.page,
-                per_page: query.per_page.map(|p| p as u32),
-                query: query.description.to_owned(),
+                page,
+                per_page: per_page.map(|p| p as u32),
+                filters,
+                sort,
             })
             .await
             .map(|FetchManyAnnouncementsResponse { data, pagination }| {
@@ -105,7 +136,7 @@ impl AnnouncementsController {
                     data: mapped_announcements,
                     pagination: PaginationPresenter::to_http(
                         pagination,
-                        query.per_page.unwrap_or(DEFAULT_PER_PAGE),
+                        per_page.unwrap_or(DEFAULT_PER_PAGE),
                     ),
                 })
             })
@@ -1,10 +1,12 @@
 use super::controller::ControllerTrait;
 use super::AppResponse;
 use crate::core::pagination::DEFAULT_PER_PAGE;
+use crate::core::query::SortField;
 use crate::domain::factories::journalism::article_tags::{
     create_article_tag_service_factory, delete_article_tag_service_factory,
     fetch_many_article_tags_service_factory, update_article_tag_service_factory,
 };
+use crate::domain::repositories::article_tag_repository::ArticleTagSortField;
 use crate::domain::services::journalism::article_tags::{
     create_article_tag_service::CreateArticleTagParams,
     delete_article_tag_service::DeleteArticleTagParams,
@@ -92,17 +94,25 @@ impl ArticleTagsController {
             page,
             per_page,
             value,
+            sort,
         } = query
             .validate()
             .map(|_| query.into_inner())
             .map_err(IntoDomainError::into_domain_err)?;
 
+        let sort = sort
+            .as_deref()
+            .map(ArticleTagSortField::parse_expression)
+            .transpose()?
+            .unwrap_or_default();
+
         let service = fetch_many_article_tags_service_factory::exec(&db_conn);
 
         let service_response = service
             .exec(FetchManyArticleTagsParams {
                 per_page: per_page.map(|pp| pp as u32),
                 query: value,
+                sort,
                 page,
             })
             .await?;
@@ -1,38 +1,72 @@
-use actix_web::{middleware::from_fn, web, HttpResponse};
+use actix_multipart::form::{MultipartForm, MultipartFormConfig};
+use actix_web::{http::header, middleware::from_fn, web, HttpResponse};
 use serde_json::json;
 use uuid::Uuid;
 use validator::Validate;
 
-use crate::core::pagination::DEFAULT_PER_PAGE;
+use crate::core::pagination::{Cursor, DEFAULT_PER_PAGE};
+use crate::core::query::{DateRange, SortField};
+use crate::domain::domain_entities::article::{Article, CommentMode};
+use crate::domain::domain_entities::locale::Locale;
+use crate::domain::domain_entities::reaction::ReactionTarget;
 use crate::domain::domain_entities::slug::Slug;
 use crate::domain::factories::journalism::articles::{
     create_article_service_factory, delete_article_service_factory,
-    fetch_many_articles_service_factory, get_expanded_article_service_factory,
-    update_article_service_factory,
+    delete_article_translation_service_factory, fetch_home_page_articles_service_factory,
+    fetch_many_articles_service_factory, fetch_related_articles_service_factory,
+    get_expanded_article_service_factory, pin_article_service_factory,
+    resolve_article_covers_service_factory, set_article_comment_mode_service_factory,
+    translate_articles_service_factory, update_article_service_factory,
+    upload_article_cover_service_factory, upsert_article_translation_service_factory,
 };
+use crate::domain::factories::journalism::reactions::fetch_reactions_service_factory;
+use crate::domain::politics::image_upload_politics::MAX_UPLOADED_IMAGE_SIZE;
+use crate::domain::repositories::article_repository::ArticleSortField;
 use crate::domain::services::journalism::articles::{
     create_article_service::CreateArticleParams,
     delete_article_service::DeleteArticleParams,
-    fetch_many_articles_service::{FetchManyArticlesParams, ServiceArticleQueryType},
+    delete_article_translation_service::DeleteArticleTranslationParams,
+    fetch_home_page_articles_service::FetchHomePageArticlesParams,
+    fetch_many_articles_service::{
+        FetchArticlesByCursorParams, FetchManyArticlesParams, ServiceArticleQueryType,
+    },
+    fetch_related_articles_service::{FetchRelatedArticlesParams, DEFAULT_RELATED_ARTICLES_AMOUNT},
     get_expanded_article_service::{
         FetchManyCommentsWithAuthorResponse, GetExpandedArticleParams, GetExpandedArticleResponse,
     },
-    update_article_service::UpdateArticleParams,
+    pin_article_service::PinArticleParams,
+    set_article_comment_mode_service::SetArticleCommentModeParams,
+    translate_articles_service::TranslateArticlesParams,
+    update_article_service::{UpdateArticleCoauthorParams, UpdateArticleParams},
+    upload_article_cover_service::UploadArticleCoverParams,
+    upsert_article_translation_service::UpsertArticleTranslationParams,
 };
+use crate::domain::services::journalism::reactions::fetch_reactions_service::FetchReactionsParams;
+use crate::error::DomainError;
 use crate::infra::extensions::validator::IntoDomainError;
+use crate::infra::http::dtos::article_comment_mode::ArticleCommentModeDto;
+use crate::infra::http::dtos::article_translation::UpsertArticleTranslationDto;
 use crate::infra::http::dtos::create_article::CreateArticleDto;
 use crate::infra::http::dtos::list_article_admin::AdminListArticlesDto;
 use crate::infra::http::dtos::list_articles::ListArticlesDto;
+use crate::infra::http::dtos::pin_article::PinArticleDto;
+use crate::infra::http::dtos::related_articles_query::RelatedArticlesQueryDto;
 use crate::infra::http::dtos::update_article::UpdateArticleDto;
+use crate::infra::http::dtos::upload_article_cover::UploadArticleCoverDto;
+use crate::infra::http::extractors::req_locale::ReqLocale;
 use crate::infra::http::extractors::req_user::ReqUser;
 use crate::infra::http::middlewares::authentication_middleware;
-use crate::infra::http::presenters::article::MappedArticle;
+use crate::infra::http::presenters::article::MappedTranslatedArticle;
+use crate::infra::http::presenters::article_cover::ArticleCoverPresenter;
+use crate::infra::http::presenters::article_translation::ArticleTranslationPresenter;
 use crate::infra::http::presenters::pagination::PaginationPresenter;
 use crate::infra::http::presenters::presenter::PresenterTrait;
 use crate::infra::http::presenters::{
-    article::ArticlePresenter, expanded_article::ExpandedArticlePresenter,
+    article::ArticlePresenter,
+    expanded_article::{ExpandedArticlePresenter, ExpandedArticleView},
 };
 use crate::infra::sea::sea_service::SeaService;
+use crate::ENV_VARS;
 
 use super::controller::ControllerTrait;
 use super::AppResponse;
@@ -43,6 +77,12 @@ impl ControllerTrait for ArticlesController {
     fn register(cfg: &mut web::ServiceConfig) {
         cfg.service(
             web::scope("/articles")
+                // oversized covers still get through, so that the service can tell why they're rejected
+                .app_data(
+                    MultipartFormConfig::default()
+                        .total_limit(MAX_UPLOADED_IMAGE_SIZE * 2)
+                        .memory_limit(MAX_UPLOADED_IMAGE_SIZE * 2),
+                )
                 // CREATE
                 .route(
                     "/new",
@@ -50,9 +90,17 @@ impl ControllerTrait for ArticlesController {
                         .to(Self::create)
                         .wrap(from_fn(authentication_middleware)),
                 )
+                .route(
+                    "/covers/upload",
+                    web::post()
+                        .to(Self::upload_cover)
+                        .wrap(from_fn(authentication_middleware)),
+                )
                 // READ
                 .route("/{slug}/get", web::get().to(Self::get))
+                .route("/{slug}/related", web::get().to(Self::related))
                 .route("/list", web::get().to(Self::list))
+                .route("/home", web::get().to(Self::home))
                 .route(
                     "/list/admin",
                     web::get()
@@ -66,12 +114,36 @@ impl ControllerTrait for ArticlesController {
                         .to(Self::update)
                         .wrap(from_fn(authentication_middleware)),
                 )
+                .route(
+                    "/{id}/pin",
+                    web::put()
+                        .to(Self::pin)
+                        .wrap(from_fn(authentication_middleware)),
+                )
+                .route(
+                    "/{id}/comment_mode",
+                    web::put()
+                        .to(Self::set_comment_mode)
+                        .wrap(from_fn(authentication_middleware)),
+                )
+                .route(
+                    "/{id}/translations/{locale}",
+                    web::put()
+                        .to(Self::upsert_translation)
+                        .wrap(from_fn(authentication_middleware)),
+                )
                 // DELETE
                 .route(
                     "/{id}/delete",
                     web::delete()
                         .to(Self::delete)
                         .wrap(from_fn(authentication_middleware)),
+                )
+                .route(
+                    "/{id}/translations/{locale}",
+                    web::delete()
+                        .to(Self::delete_translation)
+                        .wrap(from_fn(authentication_middleware)),
                 ),
         );
     }
@@ -109,16 +181,40 @@ impl ArticlesController {
             })
             .await?;
 
-        let mapped_article = ArticlePresenter::to_http(article);
+        let cover = resolve_article_covers_service_factory::exec().exec(article.cover_url());
+        let mapped_article = ArticlePresenter::to_http(article, cover);
 
         Ok(HttpResponse::Created().json(json!({"data": mapped_article})))
     }
 
+    async fn upload_cover(
+        user: web::ReqData<ReqUser>,
+        MultipartForm(form): MultipartForm<UploadArticleCoverDto>,
+    ) -> AppResponse {
+        let service = upload_article_cover_service_factory::exec();
+
+        let urls = service
+            .exec(UploadArticleCoverParams {
+                user_role: user.into_inner().user_role.unwrap(),
+                content_type: form
+                    .cover
+                    .content_type
+                    .map(|mime| mime.essence_str().to_owned()),
+                content: form.cover.data.to_vec(),
+            })
+            .await?;
+
+        let mapped_urls = ArticleCoverPresenter::to_http(urls);
+
+        Ok(HttpResponse::Created().json(json!({"data": mapped_urls})))
+    }
+
     async fn get(
         db_conn: web::Data<SeaService>,
 
         article_slug: web::Path<String>,
         user: Option<web::ReqData<ReqUser>>,
+        ReqLocale(locale): ReqLocale,
     ) -> AppResponse {
         let service = get_expanded_article_service_factory::exec(&db_conn);
 
@@ -130,13 +226,23 @@ impl ArticlesController {
         let GetExpandedArticleResponse {
             article,
             article_author,
+            coauthors,
             comments: comment_response,
+            series,
+            related_articles,
+            locale,
+            alternates,
+            comment_mode,
+            can_comment,
         } = service
             .exec(GetExpandedArticleParams {
                 article_slug: Slug::new_from_existing(article_slug.into_inner()),
                 comments_per_page: Some(DEFAULT_PER_PAGE as u32),
                 user_id,
                 user_role,
+                related_articles_amount: Some(DEFAULT_RELATED_ARTICLES_AMOUNT),
+                locale,
+                comment_auto_close_days: ENV_VARS.comment_auto_close_days,
             })
             .await?;
 
@@ -145,21 +251,83 @@ impl ArticlesController {
             pagination: comments_pagination,
         } = comment_response;
 
-        let mapped_article = ExpandedArticlePresenter::to_http(
+        let reactions_service = fetch_reactions_service_factory::exec(&db_conn);
+        let viewer_id = user.map(|user| user.user_id);
+
+        let reactions = reactions_service
+            .exec(FetchReactionsParams {
+                viewer_id,
+                target: ReactionTarget::Article,
+                target_ids: vec![article.id()],
+            })
+            .await?
+            .remove(&article.id())
+            .unwrap_or_default();
+
+        let comments = reactions_service
+            .exec_on_comments(viewer_id, comments)
+            .await?;
+
+        let covers_service = resolve_article_covers_service_factory::exec();
+
+        let mapped_article = ExpandedArticlePresenter::to_http(ExpandedArticleView {
+            cover: covers_service.exec(article.cover_url()),
             article,
-            article_author,
+            reactions,
+            author: article_author,
+            coauthors,
             comments,
-            (comments_pagination, DEFAULT_PER_PAGE),
-        );
+            comments_pagination,
+            comments_per_page: DEFAULT_PER_PAGE,
+            series,
+            related_articles: related_articles
+                .map(|articles| covers_service.exec_on_articles(articles)),
+            locale,
+            alternates,
+            comment_mode,
+            can_comment,
+        });
+
+        Ok(HttpResponse::Ok()
+            .insert_header((header::CONTENT_LANGUAGE, locale.code()))
+            .json(json!({
+                "data": mapped_article,
+            })))
+    }
 
-        Ok(HttpResponse::Ok().json(json!({
-            "data": mapped_article,
-        })))
+    async fn related(
+        db_conn: web::Data<SeaService>,
+        article_slug: web::Path<String>,
+        query: web::Query<RelatedArticlesQueryDto>,
+        ReqLocale(locale): ReqLocale,
+        user: Option<web::ReqData<ReqUser>>,
+    ) -> AppResponse {
+        let RelatedArticlesQueryDto { amount } = query
+            .validate()
+            .map_err(|err| err.into_domain_err())
+            .map(|_| query.into_inner())?;
+
+        let service = fetch_related_articles_service_factory::exec(&db_conn);
+
+        let articles = service
+            .exec(FetchRelatedArticlesParams {
+                article_slug: Slug::new_from_existing(article_slug.into_inner()),
+                amount,
+            })
+            .await?;
+
+        let viewer_id = user.map(|user| user.user_id);
+        let articles =
+            Self::translate_articles(&db_conn, articles, locale, false, viewer_id).await?;
+
+        Ok(HttpResponse::Ok().json(json!({"data": articles})))
     }
 
     async fn list(
         db_conn: web::Data<SeaService>,
         query: web::Query<ListArticlesDto>,
+        ReqLocale(locale): ReqLocale,
+        user: Option<web::ReqData<ReqUser>>,
     ) -> AppResponse {
         let query_body = query
             .validate()
@@ -168,31 +336,76 @@ impl ArticlesController {
 
         Self::get_list_of_articles(
             &db_conn,
-            query_body.title,
-            query_body.author,
-            query_body.page,
-            query_body.per_page,
+            query_body,
             Some(true),
+            locale,
+            false,
+            user.map(|user| user.user_id),
         )
         .await
     }
 
+    async fn home(
+        db_conn: web::Data<SeaService>,
+        ReqLocale(locale): ReqLocale,
+        user: Option<web::ReqData<ReqUser>>,
+    ) -> AppResponse {
+        let service = fetch_home_page_articles_service_factory::exec(&db_conn);
+
+        let articles = service
+            .exec(FetchHomePageArticlesParams {
+                slots: ENV_VARS.home_articles_slots,
+            })
+            .await?;
+
+        let viewer_id = user.map(|user| user.user_id);
+        let articles =
+            Self::translate_articles(&db_conn, articles, locale, false, viewer_id).await?;
+
+        Ok(HttpResponse::Ok().json(json!({"data": articles})))
+    }
+
     async fn admin_list(
         db_conn: web::Data<SeaService>,
         query: web::Query<AdminListArticlesDto>,
+        ReqLocale(locale): ReqLocale,
+        user: web::ReqData<ReqUser>,
     ) -> AppResponse {
         let query_body = query
             .validate()
             .map(|_| query.into_inner())
             .map_err(|err| err.into_domain_err())?;
 
+        let AdminListArticlesDto {
+            page,
+            per_page,
+            title,
+            author,
+            tag,
+            created_from,
+            created_to,
+            sort,
+            cursor,
+            approved_state,
+        } = query_body;
+
         Self::get_list_of_articles(
             &db_conn,
-            query_body.title,
-            query_body.author,
-            query_body.page,
-            query_body.per_page,
-            query_body.approved_state,
+            ListArticlesDto {
+                page,
+                per_page,
+                title,
+                author,
+                tag,
+                created_from,
+                created_to,
+                sort,
+                cursor,
+            },
+            approved_state,
+            locale,
+            true,
+            Some(user.user_id),
         )
         .await
     }
@@ -210,6 +423,7 @@ impl ArticlesController {
             content,
             author_id,
             tag_id,
+            coauthors,
         } = body
             .validate()
             .map(|_| body.into_inner())
@@ -232,10 +446,79 @@ impl ArticlesController {
                 title,
                 author_id,
                 tag_id,
+                coauthors: coauthors.map(|coauthors| {
+                    coauthors
+                        .into_iter()
+                        .map(|coauthor| UpdateArticleCoauthorParams {
+                            user_id: coauthor.user_id,
+                            contribution: coauthor.contribution,
+                        })
+                        .collect()
+                }),
+            })
+            .await?;
+
+        let cover = resolve_article_covers_service_factory::exec().exec(article.cover_url());
+        let mapped_article = ArticlePresenter::to_http(article, cover);
+
+        Ok(HttpResponse::Ok().json(json!({"data": mapped_article})))
+    }
+
+    async fn pin(
+        db_conn: web::Data<SeaService>,
+        user: web::ReqData<ReqUser>,
+        body: web::Json<PinArticleDto>,
+        article_id: web::Path<Uuid>,
+    ) -> AppResponse {
+        let PinArticleDto {
+            pinned,
+            pin_order,
+            pinned_until,
+            featured,
+        } = body
+            .validate()
+            .map(|_| body.into_inner())
+            .map_err(|err| err.into_domain_err())?;
+
+        let service = pin_article_service_factory::exec(&db_conn);
+
+        let article = service
+            .exec(PinArticleParams {
+                user_role: user.into_inner().user_role.unwrap(),
+                article_id: article_id.into_inner(),
+                pinned,
+                pin_order,
+                pinned_until,
+                featured,
             })
             .await?;
 
-        let mapped_article = ArticlePresenter::to_http(article);
+        let cover = resolve_article_covers_service_factory::exec().exec(article.cover_url());
+        let mapped_article = ArticlePresenter::to_http(article, cover);
+
+        Ok(HttpResponse::Ok().json(json!({"data": mapped_article})))
+    }
+
+    async fn set_comment_mode(
+        db_conn: web::Data<SeaService>,
+        user: web::ReqData<ReqUser>,
+        body: web::Json<ArticleCommentModeDto>,
+        article_id: web::Path<Uuid>,
+    ) -> AppResponse {
+        let comment_mode = body.into_inner().comment_mode.parse::<CommentMode>()?;
+
+        let service = set_article_comment_mode_service_factory::exec(&db_conn);
+
+        let article = service
+            .exec(SetArticleCommentModeParams {
+                user_role: user.into_inner().user_role.unwrap(),
+                article_id: article_id.into_inner(),
+                comment_mode,
+            })
+            .await?;
+
+        let cover = resolve_article_covers_service_factory::exec().exec(article.cover_url());
+        let mapped_article = ArticlePresenter::to_http(article, cover);
 
         Ok(HttpResponse::Ok().json(json!({"data": mapped_article})))
     }
@@ -256,43 +539,196 @@ impl ArticlesController {
             .map(|_| HttpResponse::NoContent().finish())
     }
 
+    async fn upsert_translation(
+        db_conn: web::Data<SeaService>,
+        user: web::ReqData<ReqUser>,
+        path: web::Path<(Uuid, String)>,
+        body: web::Json<UpsertArticleTranslationDto>,
+    ) -> AppResponse {
+        let UpsertArticleTranslationDto { title, content } = body
+            .validate()
+            .map(|_| body.into_inner())
+            .map_err(IntoDomainError::into_domain_err)?;
+
+        let (article_id, locale) = path.into_inner();
+        let user = user.into_inner();
+
+        let service = upsert_article_translation_service_factory::exec(&db_conn);
+
+        let translation = service
+            .exec(UpsertArticleTranslationParams {
+                user_id: user.user_id,
+                user_role: user.user_role.unwrap(),
+                article_id,
+                locale: locale.parse()?,
+                title,
+                content,
+            })
+            .await?;
+
+        let mapped_translation = ArticleTranslationPresenter::to_http(translation);
+
+        Ok(HttpResponse::Ok().json(json!({"data": mapped_translation})))
+    }
+
+    async fn delete_translation(
+        db_conn: web::Data<SeaService>,
+        user: web::ReqData<ReqUser>,
+        path: web::Path<(Uuid, String)>,
+    ) -> AppResponse {
+        let (article_id, locale) = path.into_inner();
+        let user = user.into_inner();
+
+        let service = delete_article_translation_service_factory::exec(&db_conn);
+
+        service
+            .exec(DeleteArticleTranslationParams {
+                user_id: user.user_id,
+                user_role: user.user_role.unwrap(),
+                article_id,
+                locale: locale.parse()?,
+            })
+            .await?;
+
+        Ok(HttpResponse::NoContent().finish())
+    }
+
     async fn get_list_of_articles(
         db_conn: &SeaService,
-        title: Option<String>,
-        author: Option<String>,
-        page: Option<u32>,
-        per_page: Option<u8>,
+        query: ListArticlesDto,
         approved_state: Option<bool>,
+        locale: Option<Locale>,
+        with_missing_locales: bool,
+        viewer_id: Option<Uuid>,
     ) -> AppResponse {
         let service = fetch_many_articles_service_factory::exec(db_conn);
 
-        let query = {
-            if let Some(title) = title {
-                Some(ServiceArticleQueryType::Title(title))
-            } else {
-                author.map(ServiceArticleQueryType::Author)
+        let ListArticlesDto {
+            page,
+            per_page,
+            title,
+            author,
+            tag,
+            created_from,
+            created_to,
+            sort,
+            cursor,
+        } = query;
+
+        let created_at = DateRange::new(created_from, created_to)?;
+
+        let filters = [
+            title.map(ServiceArticleQueryType::Title),
+            author.map(ServiceArticleQueryType::Author),
+            tag.map(ServiceArticleQueryType::Tag),
+            (!created_at.is_unbounded()).then_some(ServiceArticleQueryType::CreatedAt(created_at)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        if let Some(cursor) = cursor {
+            if sort.is_some() {
+                return Err(DomainError::bad_request_err()
+                    .with_message("Cursor pagination can't be sorted."));
             }
-        };
 
-        service
+            let cursor = (!cursor.is_empty())
+                .then(|| Cursor::decode(&cursor))
+                .transpose()?;
+
+            let page = service
+                .exec_by_cursor(FetchArticlesByCursorParams {
+                    cursor,
+                    per_page: per_page.map(|pp| pp as u32),
+                    filters,
+                    approved_state,
+                })
+                .await?;
+
+            let mapped_articles = Self::translate_articles(
+                db_conn,
+                page.data,
+                locale,
+                with_missing_locales,
+                viewer_id,
+            )
+            .await?;
+
+            return Ok(HttpResponse::Ok().json(json!({
+                "pagination": PaginationPresenter::cursor_to_http(page.pagination, per_page.unwrap_or(DEFAULT_PER_PAGE)),
+                "data": mapped_articles
+            })));
+        }
+
+        let sort = sort
+            .as_deref()
+            .map(ArticleSortField::parse_expression)
+            .transpose()?
+            .unwrap_or_default();
+
+        let articles = service
             .exec(FetchManyArticlesParams {
                 page,
                 per_page: per_page.map(|pp| pp as u32),
-                query,
+                filters,
+                sort,
                 approved_state,
             })
-            .await
-            .map(|articles| {
-                let mapped_articles = articles
-                .data
-                .into_iter()
-                .map(ArticlePresenter::to_http)
-                .collect::<Vec<MappedArticle>>();
-
-                HttpResponse::Ok().json(json!({
-                    "pagination": PaginationPresenter::to_http(articles.pagination, per_page.unwrap_or(DEFAULT_PER_PAGE)),
-                    "data": mapped_articles
-                }))
+            .await?;
+
+        let mapped_articles = Self::translate_articles(
+            db_conn,
+            articles.data,
+            locale,
+            with_missing_locales,
+            viewer_id,
+        )
+        .await?;
+
+        Ok(HttpResponse::Ok().json(json!({
+            "pagination": PaginationPresenter::to_http(articles.pagination, per_page.unwrap_or(DEFAULT_PER_PAGE)),
+            "data": mapped_articles
+        })))
+    }
+
+    async fn translate_articles(
+        db_conn: &SeaService,
+        articles: Vec<Article>,
+        locale: Option<Locale>,
+        with_missing_locales: bool,
+        viewer_id: Option<Uuid>,
+    ) -> Result<Vec<MappedTranslatedArticle>, DomainError> {
+        let service = translate_articles_service_factory::exec(db_conn);
+        let reactions_service = fetch_reactions_service_factory::exec(db_conn);
+        let covers_service = resolve_article_covers_service_factory::exec();
+
+        let mut reactions = reactions_service
+            .exec(FetchReactionsParams {
+                viewer_id,
+                target: ReactionTarget::Article,
+                target_ids: articles.iter().map(|article| article.id()).collect(),
             })
+            .await?;
+
+        let articles = service
+            .exec(TranslateArticlesParams { articles, locale })
+            .await?
+            .into_iter()
+            .map(|article| {
+                let article_reactions = reactions.remove(&article.article.id()).unwrap_or_default();
+
+                let cover = covers_service.exec(article.article.cover_url());
+
+                ArticlePresenter::translated_to_http(
+                    article,
+                    cover,
+                    with_missing_locales,
+                    article_reactions,
+                )
+            })
+            .collect();
+
+        Ok(articles)
     }
 }
@@ -0,0 +1,173 @@
+use super::controller::ControllerTrait;
+use super::AppResponse;
+use crate::domain::domain_entities::blocked_word::BlockedWordAction;
+use crate::domain::factories::security::{
+    create_blocked_word_service_factory, delete_blocked_word_service_factory,
+    fetch_many_blocked_words_service_factory, update_blocked_word_service_factory,
+};
+use crate::domain::services::security::{
+    create_blocked_word_service::CreateBlockedWordParams,
+    delete_blocked_word_service::DeleteBlockedWordParams,
+    fetch_many_blocked_words_service::FetchManyBlockedWordsParams,
+    update_blocked_word_service::UpdateBlockedWordParams,
+};
+use crate::infra::extensions::validator::IntoDomainError;
+use crate::infra::http::dtos::blocked_word::{CreateBlockedWordDto, UpdateBlockedWordDto};
+use crate::infra::http::extractors::req_user::ReqUser;
+use crate::infra::http::middlewares::authentication_middleware;
+use crate::infra::http::presenters::blocked_word::{BlockedWordPresenter, MappedBlockedWord};
+use crate::infra::http::presenters::presenter::{JsonWrappedEntity, PresenterTrait};
+use crate::infra::sea::sea_service::SeaService;
+use actix_web::{middleware::from_fn, web, HttpResponse};
+use serde_json::json;
+use uuid::Uuid;
+use validator::Validate;
+
+pub struct BlockedWordsController;
+
+impl ControllerTrait for BlockedWordsController {
+    fn register(cfg: &mut web::ServiceConfig) {
+        cfg.service(
+            web::scope("/blocked_words")
+                // CREATE
+                .route(
+                    "/new",
+                    web::post()
+                        .to(Self::create)
+                        .wrap(from_fn(authentication_middleware)),
+                )
+                // READ
+                .route(
+                    "/list",
+                    web::get()
+                        .to(Self::list)
+                        .wrap(from_fn(authentication_middleware)),
+                )
+                // UPDATE
+                .route(
+                    "/{id}/update",
+                    web::put()
+                        .to(Self::update)
+                        .wrap(from_fn(authentication_middleware)),
+                )
+                // DELETE
+                .route(
+                    "/{id}/delete",
+                    web::delete()
+                        .to(Self::delete)
+                        .wrap(from_fn(authentication_middleware)),
+                ),
+        );
+    }
+}
+
+impl BlockedWordsController {
+    async fn create(
+        db_conn: web::Data<SeaService>,
+        body: web::Json<CreateBlockedWordDto>,
+        user: web::ReqData<ReqUser>,
+    ) -> AppResponse {
+        let CreateBlockedWordDto {
+            pattern,
+            is_regex,
+            action,
+        } = body
+            .validate()
+            .map(|_| body.into_inner())
+            .map_err(IntoDomainError::into_domain_err)?;
+
+        let action = action.parse::<BlockedWordAction>()?;
+
+        let ReqUser {
+            user_role,
+            user_id,
+            exp: _,
+        } = user.into_inner();
+
+        let service = create_blocked_word_service_factory::exec(&db_conn);
+
+        let blocked_word = service
+            .exec(CreateBlockedWordParams {
+                staff_id: user_id,
+                staff_role: user_role.unwrap(),
+                pattern,
+                is_regex,
+                action,
+            })
+            .await?;
+
+        Ok(HttpResponse::Created().json(JsonWrappedEntity {
+            data: BlockedWordPresenter::to_http(blocked_word),
+        }))
+    }
+
+    async fn list(db_conn: web::Data<SeaService>, user: web::ReqData<ReqUser>) -> AppResponse {
+        let service = fetch_many_blocked_words_service_factory::exec(&db_conn);
+
+        let blocked_words = service
+            .exec(FetchManyBlockedWordsParams {
+                staff_role: user.into_inner().user_role.unwrap(),
+            })
+            .await?;
+
+        let mapped_blocked_words = blocked_words
+            .into_iter()
+            .map(BlockedWordPresenter::to_http)
+            .collect::<Vec<MappedBlockedWord>>();
+
+        Ok(HttpResponse::Ok().json(json!({"data": mapped_blocked_words})))
+    }
+
+    async fn update(
+        db_conn: web::Data<SeaService>,
+        body: web::Json<UpdateBlockedWordDto>,
+        user: web::ReqData<ReqUser>,
+        blocked_word_id: web::Path<Uuid>,
+    ) -> AppResponse {
+        let UpdateBlockedWordDto {
+            pattern,
+            is_regex,
+            action,
+        } = body
+            .validate()
+            .map(|_| body.into_inner())
+            .map_err(IntoDomainError::into_domain_err)?;
+
+        let action = action
+            .map(|action| action.parse::<BlockedWordAction>())
+            .transpose()?;
+
+        let service = update_blocked_word_service_factory::exec(&db_conn);
+
+        let blocked_word = service
+            .exec(UpdateBlockedWordParams {
+                staff_role: user.into_inner().user_role.unwrap(),
+                blocked_word_id: blocked_word_id.into_inner(),
+                pattern,
+                is_regex,
+                action,
+            })
+            .await?;
+
+        Ok(HttpResponse::Ok().json(JsonWrappedEntity {
+            data: BlockedWordPresenter::to_http(blocked_word),
+        }))
+    }
+
+    async fn delete(
+        db_conn: web::Data<SeaService>,
+        user: web::ReqData<ReqUser>,
+        blocked_word_id: web::Path<Uuid>,
+    ) -> AppResponse {
+        let service = delete_blocked_word_service_factory::exec(&db_conn);
+
+        service
+            .exec(DeleteBlockedWordParams {
+                staff_role: user.into_inner().user_role.unwrap(),
+                blocked_word_id: blocked_word_id.into_inner(),
+            })
+            .await?;
+
+        Ok(HttpResponse::NoContent().finish())
+    }
+}
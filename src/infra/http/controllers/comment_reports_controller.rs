@@ -1,27 +1,36 @@
 use super::controller::ControllerTrait;
 use super::AppResponse;
-use crate::core::pagination::DEFAULT_PER_PAGE;
+use crate::core::pagination::{Cursor, DEFAULT_PER_PAGE};
+use crate::core::query::{DateRange, SortField};
+use crate::domain::domain_entities::comment_report::{ReportReason, ReportResolution};
 use crate::domain::factories::security::{
-    create_comment_report_service_factory, delete_comment_report_service_factory,
-    fetch_many_comment_reports_service_factory, solve_comment_report_service_factory,
+    assign_comment_report_service_factory, create_comment_report_service_factory,
+    delete_comment_report_service_factory, fetch_many_comment_reports_service_factory,
+    solve_comment_report_service_factory,
 };
+use crate::domain::repositories::comment_report_repository::CommentReportSortField;
 use crate::domain::services::security::{
+    assign_comment_report_service::AssignCommentReportParams,
     create_comment_report_service::CreateCommentReportParams,
     delete_comment_report_service::DeleteCommentReportParams,
     fetch_many_comment_reports_service::{
-        CommentReportServiceQuery, FetchManyCommentReportsParams,
+        CommentReportServiceQuery, FetchCommentReportsByCursorParams, FetchManyCommentReportsParams,
     },
     solve_comment_report_service::SolveCommentReportParams,
 };
+use crate::error::DomainError;
 use crate::infra::extensions::validator::IntoDomainError;
+use crate::infra::http::dtos::assign_comment_report::AssignCommentReportDto;
 use crate::infra::http::dtos::create_comment_report::CreateCommentReportDto;
 use crate::infra::http::dtos::list_comment_reports::ListCommentReportsDto;
+use crate::infra::http::dtos::solve_comment_report::SolveCommentReportDto;
 use crate::infra::http::extractors::req_user::ReqUser;
 use crate::infra::http::middlewares::authentication_middleware;
 use crate::infra::http::presenters::comment_report::{CommentReportPresenter, MappedCommentReport};
 use crate::infra::http::presenters::pagination::PaginationPresenter;
 use crate::infra::http::presenters::presenter::PresenterTrait;
 use crate::infra::sea::sea_service::SeaService;
+use crate::ENV_VARS;
 use actix_web::{middleware::from_fn, web, HttpResponse};
 use serde_json::json;
 use uuid::Uuid;
@@ -54,6 +63,13 @@ impl ControllerTrait for CommentReportsController {
                         .to(Self::update)
                         .wrap(from_fn(authentication_middleware)),
                 )
+                // ASSIGN A COMMENT REPORT TO A MODERATOR
+                .route(
+                    "/{id}/assign",
+                    web::patch()
+                        .to(Self::assign)
+                        .wrap(from_fn(authentication_middleware)),
+                )
                 // DESTROY A COMMENT REPORT
                 .route(
                     "/{id}/delete",
@@ -77,13 +93,22 @@ impl CommentReportsController {
             .map(|_| body.into_inner())
             .map_err(IntoDomainError::into_domain_err)?;
 
+        let reason = body
+            .reason
+            .as_deref()
+            .map(str::parse::<ReportReason>)
+            .transpose()?
+            .unwrap_or_default();
+
         let service = create_comment_report_service_factory::exec(&db_conn);
 
         let comment_report = service
             .exec(CreateCommentReportParams {
                 user_id: user.user_id,
                 content: body.content,
+                reason,
                 comment_id: comment_id.into_inner(),
+                auto_hide_threshold: ENV_VARS.comment_report_hide_threshold,
             })
             .await?;
 
@@ -104,21 +129,76 @@ impl CommentReportsController {
             solved,
             solved_by,
             content,
+            reason,
+            assigned_to,
+            created_from,
+            created_to,
+            sort,
+            cursor,
         } = query.into_inner();
 
-        let query = {
-            if let Some(solved_by) = solved_by {
-                Some(CommentReportServiceQuery::SolvedBy(solved_by))
-            } else if let Some(solved) = solved {
-                Some(CommentReportServiceQuery::Solved(solved))
-            } else {
-                content.map(CommentReportServiceQuery::Content)
+        let created_at = DateRange::new(created_from, created_to)?;
+
+        let filters = [
+            solved_by.map(CommentReportServiceQuery::SolvedBy),
+            solved.map(CommentReportServiceQuery::Solved),
+            content.map(CommentReportServiceQuery::Content),
+            reason
+                .as_deref()
+                .map(str::parse::<ReportReason>)
+                .transpose()?
+                .map(CommentReportServiceQuery::Reason),
+            assigned_to.map(CommentReportServiceQuery::AssignedTo),
+            (!created_at.is_unbounded())
+                .then_some(CommentReportServiceQuery::CreatedAt(created_at)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        if let Some(cursor) = cursor {
+            if sort.is_some() {
+                return Err(DomainError::bad_request_err()
+                    .with_message("Cursor pagination can't be sorted."));
             }
-        };
+
+            let cursor = (!cursor.is_empty())
+                .then(|| Cursor::decode(&cursor))
+                .transpose()?;
+
+            let page = service
+                .exec_by_cursor(FetchCommentReportsByCursorParams {
+                    cursor,
+                    filters,
+                    per_page: per_page.map(|pp| pp as u32),
+                })
+                .await?;
+
+            let mapped_reports = page
+                .data
+                .into_iter()
+                .map(CommentReportPresenter::to_http)
+                .collect::<Vec<MappedCommentReport>>();
+
+            return Ok(HttpResponse::Ok().json(json!({
+                "data": mapped_reports,
+                "pagination": PaginationPresenter::cursor_to_http(
+                    page.pagination,
+                    per_page.unwrap_or(DEFAULT_PER_PAGE),
+                ),
+            })));
+        }
+
+        let sort = sort
+            .as_deref()
+            .map(CommentReportSortField::parse_expression)
+            .transpose()?
+            .unwrap_or_default();
 
         let comment_reports_paginated_data = service
             .exec(FetchManyCommentReportsParams {
-                query,
+                filters,
+                sort,
                 per_page: per_page.map(|pp| pp as u32),
                 page,
             })
@@ -145,20 +225,53 @@ impl CommentReportsController {
         db_conn: web::Data<SeaService>,
         user: web::ReqData<ReqUser>,
         report_id: web::Path<i32>,
+        body: web::Json<SolveCommentReportDto>,
     ) -> AppResponse {
+        let body = body
+            .validate()
+            .map(|_| body.into_inner())
+            .map_err(IntoDomainError::into_domain_err)?;
+
+        let resolution = body.resolution.parse::<ReportResolution>()?;
+
         let service = solve_comment_report_service_factory::exec(&db_conn);
 
         let user = user.into_inner();
 
-        service
+        let comment_report = service
             .exec(SolveCommentReportParams {
                 staff_role: user.user_role.unwrap(),
                 com_report_id: report_id.into_inner(),
                 staff_id: user.user_id,
+                resolution,
+                resolution_notes: body.resolution_notes,
             })
             .await?;
 
-        Ok(HttpResponse::NoContent().finish())
+        let mapped_comment_report = CommentReportPresenter::to_http(comment_report);
+
+        Ok(HttpResponse::Ok().json(json!({"data": mapped_comment_report})))
+    }
+
+    async fn assign(
+        db_conn: web::Data<SeaService>,
+        user: web::ReqData<ReqUser>,
+        report_id: web::Path<i32>,
+        body: web::Json<AssignCommentReportDto>,
+    ) -> AppResponse {
+        let service = assign_comment_report_service_factory::exec(&db_conn);
+
+        let comment_report = service
+            .exec(AssignCommentReportParams {
+                staff_role: user.into_inner().user_role.unwrap(),
+                com_report_id: report_id.into_inner(),
+                assignee_id: body.into_inner().assignee_id,
+            })
+            .await?;
+
+        let mapped_comment_report = CommentReportPresenter::to_http(comment_report);
+
+        Ok(HttpResponse::Ok().json(json!({"data": mapped_comment_report})))
     }
 
     async fn delete(
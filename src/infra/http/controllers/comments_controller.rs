@@ -1,30 +1,60 @@
 use super::controller::ControllerTrait;
 use super::AppResponse;
-use crate::core::pagination::DEFAULT_PER_PAGE;
+use crate::core::pagination::{Cursor, DEFAULT_PER_PAGE};
+use crate::core::query::{DateRange, SortField};
 use crate::domain::factories::journalism::comments::{
     comment_on_article_service_factory, delete_comment_service_factory,
+    edit_comment_service_factory, fetch_comment_edits_service_factory,
     fetch_many_comments_service_factory, fetch_many_comments_with_author_service_factory,
+    fetch_user_mentions_service_factory,
 };
-use crate::domain::factories::security::toggle_comment_visibility_service_factory;
+use crate::domain::factories::journalism::reactions::fetch_reactions_service_factory;
+use crate::domain::factories::security::{
+    fetch_comment_review_queue_service_factory, restore_comment_service_factory,
+    review_comments_service_factory, toggle_comment_visibility_service_factory,
+};
+use crate::domain::politics::comment_flood_politics::{
+    CommentFloodPolicy, CommentFloodPolicySettings,
+};
+use crate::domain::politics::comment_review_politics::PreModerationPolicy;
+use crate::domain::repositories::article_comment_repository::CommentSortField;
 use crate::domain::services::journalism::comments::{
     comment_on_article_service::CommentOnArticleParams,
     delete_comment_service::DeleteCommentParams,
+    edit_comment_service::EditCommentParams,
+    fetch_comment_edits_service::FetchCommentEditsParams,
     fetch_many_comments_service::{FetchManyCommentsParams, ServiceCommentQueryType},
-    fetch_many_comments_with_author_service::FetchManyArticleCommentsWithAuthorParams,
+    fetch_many_comments_with_author_service::{
+        FetchArticleCommentsByCursorParams, FetchManyArticleCommentsWithAuthorParams,
+    },
+    fetch_user_mentions_service::FetchUserMentionsParams,
 };
+use crate::domain::services::security::fetch_comment_review_queue_service::FetchCommentReviewQueueParams;
+use crate::domain::services::security::restore_comment_service::RestoreCommentParams;
+use crate::domain::services::security::review_comments_service::ReviewCommentsParams;
 use crate::domain::services::security::toggle_comment_visibility_service::ToggleCommentVisibilityParams;
+use crate::infra::extensions::validator::IntoDomainError;
 use crate::infra::http::dtos::comment_on_article::CommentOnArticleDto;
+use crate::infra::http::dtos::edit_comment::EditCommentDto;
+use crate::infra::http::dtos::list_article_comments::ListArticleCommentsDto;
 use crate::infra::http::dtos::list_comments::ListCommentsDto;
+use crate::infra::http::dtos::review_comments::ReviewCommentsDto;
 use crate::infra::http::dtos::simple_pagination_query::SimplePaginationQueryDto;
 use crate::infra::http::extractors::req_user::ReqUser;
 use crate::infra::http::middlewares::authentication_middleware;
 use crate::infra::http::presenters::comment::{CommentPresenter, MappedComment, MappedRawComment};
+use crate::infra::http::presenters::comment_edit::{CommentEditPresenter, MappedCommentEdit};
+use crate::infra::http::presenters::comment_mention::{
+    CommentMentionPresenter, MappedCommentMention,
+};
 use crate::infra::http::presenters::pagination::PaginationPresenter;
 use crate::infra::http::presenters::presenter::PresenterTrait;
 use crate::infra::sea::sea_service::SeaService;
+use crate::ENV_VARS;
 use actix_web::{middleware::from_fn, web, HttpResponse};
 use serde_json::json;
 use uuid::Uuid;
+use validator::Validate;
 
 pub struct CommentsController;
 
@@ -41,6 +71,29 @@ impl ControllerTrait for CommentsController {
                 )
                 // Get an article's comments with author list
                 .route("/{article_id}/list", web::get().to(Self::list))
+                // Get a comment's replies with author list
+                .route("/{id}/replies", web::get().to(Self::replies))
+                // Get where the user has been mentioned
+                .route(
+                    "/mentions",
+                    web::get()
+                        .to(Self::mentions)
+                        .wrap(from_fn(authentication_middleware)),
+                )
+                // Get the comments held for review
+                .route(
+                    "/queue",
+                    web::get()
+                        .to(Self::review_queue)
+                        .wrap(from_fn(authentication_middleware)),
+                )
+                // Approve or reject comments held for review
+                .route(
+                    "/queue/review",
+                    web::patch()
+                        .to(Self::review)
+                        .wrap(from_fn(authentication_middleware)),
+                )
                 // Get a comments list
                 .route(
                     "/list/admin",
@@ -48,6 +101,20 @@ impl ControllerTrait for CommentsController {
                         .to(Self::admin_list)
                         .wrap(from_fn(authentication_middleware)),
                 )
+                // Edit a comment
+                .route(
+                    "/{id}/edit",
+                    web::put()
+                        .to(Self::edit)
+                        .wrap(from_fn(authentication_middleware)),
+                )
+                // Get a comment's edit history
+                .route(
+                    "/{id}/edits",
+                    web::get()
+                        .to(Self::edits)
+                        .wrap(from_fn(authentication_middleware)),
+                )
                 // Deactivate comment visibility
                 .route(
                     "/{id}/deactivate",
@@ -55,6 +122,13 @@ impl ControllerTrait for CommentsController {
                         .to(Self::disable_visibility)
                         .wrap(from_fn(authentication_middleware)),
                 )
+                // Show again a comment hidden by reports
+                .route(
+                    "/{id}/restore",
+                    web::patch()
+                        .to(Self::restore)
+                        .wrap(from_fn(authentication_middleware)),
+                )
                 // Definitely delete a comment
                 .route(
                     "/{id}/delete",
@@ -75,25 +149,88 @@ impl CommentsController {
     ) -> AppResponse {
         let service = comment_on_article_service_factory::exec(&db_conn);
 
-        let _comment = service
+        let CommentOnArticleDto { content, parent_id } = body.into_inner();
+
+        let ReqUser {
+            user_role,
+            user_id,
+            exp: _,
+        } = user.into_inner();
+
+        let comment = service
             .exec(CommentOnArticleParams {
-                author_id: user.user_id,
-                content: body.into_inner().content,
+                author_id: user_id,
+                author_role: user_role.unwrap(),
+                content,
                 article_id: article_id.into_inner(),
+                parent_id,
+                pre_moderation: PreModerationPolicy::new(
+                    ENV_VARS.comment_review_account_age_days,
+                    ENV_VARS.comment_review_min_approved_comments,
+                ),
+                flood: CommentFloodPolicy::new(CommentFloodPolicySettings {
+                    user_cooldown_seconds: ENV_VARS.comment_flood_user_cooldown_seconds,
+                    writer_cooldown_seconds: ENV_VARS.comment_flood_writer_cooldown_seconds,
+                    user_max_per_window: ENV_VARS.comment_flood_user_max_per_window,
+                    writer_max_per_window: ENV_VARS.comment_flood_writer_max_per_window,
+                    window_minutes: ENV_VARS.comment_flood_window_minutes,
+                    duplicate_window_minutes: ENV_VARS.comment_flood_duplicate_window_minutes,
+                }),
+                comment_auto_close_days: ENV_VARS.comment_auto_close_days,
             })
             .await?;
 
+        // hidden comments are waiting for a moderator's review
+        if !comment.is_active() {
+            return Ok(HttpResponse::Accepted().finish());
+        }
+
         Ok(HttpResponse::Created().finish())
     }
 
     async fn list(
         db_conn: web::Data<SeaService>,
         article_id: web::Path<Uuid>,
-        query: web::Query<SimplePaginationQueryDto>,
+        query: web::Query<ListArticleCommentsDto>,
+        user: Option<web::ReqData<ReqUser>>,
     ) -> AppResponse {
         let service = fetch_many_comments_with_author_service_factory::exec(&db_conn);
+        let reactions_service = fetch_reactions_service_factory::exec(&db_conn);
+        let viewer_id = user.map(|user| user.user_id);
+
+        let ListArticleCommentsDto {
+            per_page,
+            page,
+            cursor,
+        } = query.into_inner();
+
+        if let Some(cursor) = cursor {
+            let cursor = (!cursor.is_empty())
+                .then(|| Cursor::decode(&cursor))
+                .transpose()?;
+
+            let comments = service
+                .exec_by_cursor(
+                    article_id.into_inner(),
+                    FetchArticleCommentsByCursorParams {
+                        cursor,
+                        per_page: per_page.map(|pp| pp as u32),
+                    },
+                )
+                .await?;
+
+            let mapped_comments: Vec<MappedComment> = reactions_service
+                .exec_on_comments(viewer_id, comments.data)
+                .await?
+                .into_iter()
+                .map(CommentPresenter::to_http)
+                .collect();
 
-        let SimplePaginationQueryDto { per_page, page } = query.into_inner();
+            return Ok(HttpResponse::Ok().json(json!({
+                "pagination": PaginationPresenter::cursor_to_http(comments.pagination, per_page.unwrap_or(DEFAULT_PER_PAGE)),
+                "data": mapped_comments
+            })));
+        }
 
         let comments = service
             .exec(
@@ -105,8 +242,9 @@ impl CommentsController {
             )
             .await?;
 
-        let mapped_comments: Vec<MappedComment> = comments
-            .data
+        let mapped_comments: Vec<MappedComment> = reactions_service
+            .exec_on_comments(viewer_id, comments.data)
+            .await?
             .into_iter()
             .map(CommentPresenter::to_http)
             .collect();
@@ -117,6 +255,131 @@ impl CommentsController {
                 })))
     }
 
+    async fn replies(
+        db_conn: web::Data<SeaService>,
+        comment_id: web::Path<Uuid>,
+        query: web::Query<SimplePaginationQueryDto>,
+        user: Option<web::ReqData<ReqUser>>,
+    ) -> AppResponse {
+        let service = fetch_many_comments_with_author_service_factory::exec(&db_conn);
+        let reactions_service = fetch_reactions_service_factory::exec(&db_conn);
+
+        let SimplePaginationQueryDto { page, per_page } = query.into_inner();
+
+        let replies = service
+            .exec_replies(
+                comment_id.into_inner(),
+                FetchManyArticleCommentsWithAuthorParams {
+                    page,
+                    per_page: per_page.map(|pp| pp as u32),
+                },
+            )
+            .await?;
+
+        let mapped_replies: Vec<MappedComment> = reactions_service
+            .exec_on_comments(user.map(|user| user.user_id), replies.data)
+            .await?
+            .into_iter()
+            .map(CommentPresenter::to_http)
+            .collect();
+
+        Ok(HttpResponse::Ok().json(json!({
+            "pagination": PaginationPresenter::to_http(replies.pagination, per_page.unwrap_or(DEFAULT_PER_PAGE)),
+            "data": mapped_replies
+        })))
+    }
+
+    async fn mentions(
+        db_conn: web::Data<SeaService>,
+        user: web::ReqData<ReqUser>,
+        query: web::Query<SimplePaginationQueryDto>,
+    ) -> AppResponse {
+        let service = fetch_user_mentions_service_factory::exec(&db_conn);
+
+        let SimplePaginationQueryDto { page, per_page } = query.into_inner();
+
+        let mentions = service
+            .exec(FetchUserMentionsParams {
+                user_id: user.user_id,
+                page,
+                per_page: per_page.map(|pp| pp as u32),
+            })
+            .await?;
+
+        let mapped_mentions: Vec<MappedCommentMention> = mentions
+            .data
+            .into_iter()
+            .map(CommentMentionPresenter::to_http)
+            .collect();
+
+        Ok(HttpResponse::Ok().json(json!({
+            "pagination": PaginationPresenter::to_http(mentions.pagination, per_page.unwrap_or(DEFAULT_PER_PAGE)),
+            "data": mapped_mentions
+        })))
+    }
+
+    async fn review_queue(
+        db_conn: web::Data<SeaService>,
+        user: web::ReqData<ReqUser>,
+        query: web::Query<SimplePaginationQueryDto>,
+    ) -> AppResponse {
+        let service = fetch_comment_review_queue_service_factory::exec(&db_conn);
+
+        let SimplePaginationQueryDto { page, per_page } = query.into_inner();
+
+        let comments = service
+            .exec(FetchCommentReviewQueueParams {
+                user_role: user.into_inner().user_role.unwrap(),
+                page,
+                per_page: per_page.map(|pp| pp as u32),
+            })
+            .await?;
+
+        let mapped_comments: Vec<MappedRawComment> = comments
+            .data
+            .into_iter()
+            .map(CommentPresenter::to_http_raw)
+            .collect();
+
+        Ok(HttpResponse::Ok().json(json!({
+            "pagination": PaginationPresenter::to_http(comments.pagination, per_page.unwrap_or(DEFAULT_PER_PAGE)),
+            "data": mapped_comments
+        })))
+    }
+
+    async fn review(
+        db_conn: web::Data<SeaService>,
+        user: web::ReqData<ReqUser>,
+        body: web::Json<ReviewCommentsDto>,
+    ) -> AppResponse {
+        let ReviewCommentsDto {
+            comment_ids,
+            approve,
+        } = body
+            .validate()
+            .map(|_| body.into_inner())
+            .map_err(IntoDomainError::into_domain_err)?;
+
+        let service = review_comments_service_factory::exec(&db_conn);
+
+        let ReqUser {
+            user_role,
+            user_id,
+            exp: _,
+        } = user.into_inner();
+
+        service
+            .exec(ReviewCommentsParams {
+                moderator_id: user_id,
+                moderator_role: user_role.unwrap(),
+                comment_ids,
+                approve,
+            })
+            .await?;
+
+        Ok(HttpResponse::NoContent().finish())
+    }
+
     async fn admin_list(
         db_conn: web::Data<SeaService>,
         query: web::Query<ListCommentsDto>,
@@ -129,23 +392,36 @@ impl CommentsController {
             author,
             content,
             include_inactive,
+            created_from,
+            created_to,
+            sort,
         } = query.into_inner();
 
         let include_inactive = include_inactive.unwrap_or(false);
 
-        let query = {
-            if let Some(author) = author {
-                Some(ServiceCommentQueryType::Author(author))
-            } else {
-                content.map(ServiceCommentQueryType::Content)
-            }
-        };
+        let created_at = DateRange::new(created_from, created_to)?;
+
+        let filters = [
+            author.map(ServiceCommentQueryType::Author),
+            content.map(ServiceCommentQueryType::Content),
+            (!created_at.is_unbounded()).then_some(ServiceCommentQueryType::CreatedAt(created_at)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        let sort = sort
+            .as_deref()
+            .map(CommentSortField::parse_expression)
+            .transpose()?
+            .unwrap_or_default();
 
         let comments = service
             .exec(
                 include_inactive,
                 FetchManyCommentsParams {
-                    query,
+                    filters,
+                    sort,
                     per_page: per_page.map(|pp| pp as u32),
                     page,
                 },
@@ -164,6 +440,60 @@ impl CommentsController {
                 })))
     }
 
+    async fn edit(
+        db_conn: web::Data<SeaService>,
+        user: web::ReqData<ReqUser>,
+        comment_id: web::Path<Uuid>,
+        body: web::Json<EditCommentDto>,
+    ) -> AppResponse {
+        let EditCommentDto { content } = body
+            .validate()
+            .map(|_| body.into_inner())
+            .map_err(IntoDomainError::into_domain_err)?;
+
+        let service = edit_comment_service_factory::exec(&db_conn);
+
+        let ReqUser {
+            user_role,
+            user_id,
+            exp: _,
+        } = user.into_inner();
+
+        let comment = service
+            .exec(EditCommentParams {
+                user_id,
+                user_role: user_role.unwrap(),
+                comment_id: comment_id.into_inner(),
+                content,
+                edit_window_minutes: ENV_VARS.comment_edit_window_minutes,
+            })
+            .await?;
+
+        Ok(HttpResponse::Ok().json(json!({"data": CommentPresenter::to_http_raw(comment)})))
+    }
+
+    async fn edits(
+        db_conn: web::Data<SeaService>,
+        user: web::ReqData<ReqUser>,
+        comment_id: web::Path<Uuid>,
+    ) -> AppResponse {
+        let service = fetch_comment_edits_service_factory::exec(&db_conn);
+
+        let edits = service
+            .exec(FetchCommentEditsParams {
+                user_role: user.into_inner().user_role.unwrap(),
+                comment_id: comment_id.into_inner(),
+            })
+            .await?;
+
+        let mapped_edits: Vec<MappedCommentEdit> = edits
+            .into_iter()
+            .map(CommentEditPresenter::to_http)
+            .collect();
+
+        Ok(HttpResponse::Ok().json(json!({"data": mapped_edits})))
+    }
+
     async fn disable_visibility(
         db_conn: web::Data<SeaService>,
         user: web::ReqData<ReqUser>,
@@ -182,6 +512,30 @@ impl CommentsController {
         Ok(HttpResponse::NoContent().finish())
     }
 
+    async fn restore(
+        db_conn: web::Data<SeaService>,
+        user: web::ReqData<ReqUser>,
+        comment_id: web::Path<Uuid>,
+    ) -> AppResponse {
+        let service = restore_comment_service_factory::exec(&db_conn);
+
+        let ReqUser {
+            user_role,
+            user_id,
+            exp: _,
+        } = user.into_inner();
+
+        service
+            .exec(RestoreCommentParams {
+                moderator_id: user_id,
+                moderator_role: user_role.unwrap(),
+                comment_id: comment_id.into_inner(),
+            })
+            .await?;
+
+        Ok(HttpResponse::NoContent().finish())
+    }
+
     async fn delete(
         db_conn: web::Data<SeaService>,
         comment_id: web::Path<Uuid>,
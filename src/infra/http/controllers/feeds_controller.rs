@@ -0,0 +1,125 @@
+use actix_web::{web, HttpRequest};
+
+use crate::domain::factories::journalism::articles::fetch_articles_feed_service_factory;
+use crate::domain::services::journalism::articles::fetch_articles_feed_service::{
+    ArticlesFeedFilter, FetchArticlesFeedParams,
+};
+use crate::infra::http::cache_validators::respond_with_cache_validators;
+use crate::infra::http::presenters::articles_feed::ArticlesFeedPresenter;
+use crate::infra::sea::sea_service::SeaService;
+use crate::util::SiteUrl;
+
+use super::controller::ControllerTrait;
+use super::AppResponse;
+
+#[derive(Clone, Copy)]
+enum FeedFormat {
+    Rss,
+    Atom,
+}
+
+pub struct FeedsController;
+
+impl ControllerTrait for FeedsController {
+    fn register(cfg: &mut web::ServiceConfig) {
+        cfg.service(
+            web::scope("/feeds")
+                // READ
+                .route("/articles.rss", web::get().to(Self::articles_rss))
+                .route("/articles.atom", web::get().to(Self::articles_atom))
+                .route(
+                    "/tags/{tag_id}/articles.rss",
+                    web::get().to(Self::tag_articles_rss),
+                )
+                .route(
+                    "/tags/{tag_id}/articles.atom",
+                    web::get().to(Self::tag_articles_atom),
+                )
+                .route(
+                    "/authors/{nickname}/articles.rss",
+                    web::get().to(Self::author_articles_rss),
+                )
+                .route(
+                    "/authors/{nickname}/articles.atom",
+                    web::get().to(Self::author_articles_atom),
+                ),
+        );
+    }
+}
+
+impl FeedsController {
+    async fn articles_rss(request: HttpRequest, db_conn: web::Data<SeaService>) -> AppResponse {
+        Self::feed(request, &db_conn, ArticlesFeedFilter::All, FeedFormat::Rss).await
+    }
+
+    async fn articles_atom(request: HttpRequest, db_conn: web::Data<SeaService>) -> AppResponse {
+        Self::feed(request, &db_conn, ArticlesFeedFilter::All, FeedFormat::Atom).await
+    }
+
+    async fn tag_articles_rss(
+        request: HttpRequest,
+        db_conn: web::Data<SeaService>,
+        tag_id: web::Path<i32>,
+    ) -> AppResponse {
+        let filter = ArticlesFeedFilter::Tag(tag_id.into_inner());
+        Self::feed(request, &db_conn, filter, FeedFormat::Rss).await
+    }
+
+    async fn tag_articles_atom(
+        request: HttpRequest,
+        db_conn: web::Data<SeaService>,
+        tag_id: web::Path<i32>,
+    ) -> AppResponse {
+        let filter = ArticlesFeedFilter::Tag(tag_id.into_inner());
+        Self::feed(request, &db_conn, filter, FeedFormat::Atom).await
+    }
+
+    async fn author_articles_rss(
+        request: HttpRequest,
+        db_conn: web::Data<SeaService>,
+        nickname: web::Path<String>,
+    ) -> AppResponse {
+        let filter = ArticlesFeedFilter::AuthorNickname(nickname.into_inner());
+        Self::feed(request, &db_conn, filter, FeedFormat::Rss).await
+    }
+
+    async fn author_articles_atom(
+        request: HttpRequest,
+        db_conn: web::Data<SeaService>,
+        nickname: web::Path<String>,
+    ) -> AppResponse {
+        let filter = ArticlesFeedFilter::AuthorNickname(nickname.into_inner());
+        Self::feed(request, &db_conn, filter, FeedFormat::Atom).await
+    }
+
+    async fn feed(
+        request: HttpRequest,
+        db_conn: &SeaService,
+        filter: ArticlesFeedFilter,
+        format: FeedFormat,
+    ) -> AppResponse {
+        let service = fetch_articles_feed_service_factory::exec(db_conn);
+
+        let feed = service.exec(FetchArticlesFeedParams { filter }).await?;
+
+        let self_url = SiteUrl::absolute(request.path());
+
+        let (body, content_type) = match format {
+            FeedFormat::Rss => (
+                ArticlesFeedPresenter::to_rss(&feed, &self_url),
+                "application/rss+xml; charset=utf-8",
+            ),
+            FeedFormat::Atom => (
+                ArticlesFeedPresenter::to_atom(&feed, &self_url),
+                "application/atom+xml; charset=utf-8",
+            ),
+        };
+
+        Ok(respond_with_cache_validators(
+            &request,
+            body,
+            content_type,
+            feed.last_modified,
+        ))
+    }
+}
@@ -0,0 +1,247 @@
+use actix_multipart::form::{MultipartForm, MultipartFormConfig};
+use actix_web::{middleware::from_fn, web, HttpResponse};
+use uuid::Uuid;
+use validator::Validate;
+
+use super::controller::ControllerTrait;
+use super::AppResponse;
+use crate::core::pagination::DEFAULT_PER_PAGE;
+use crate::core::query::{DateRange, SortField};
+use crate::domain::factories::media::{
+    delete_media_service_factory, fetch_many_media_service_factory, get_media_service_factory,
+    update_media_service_factory, upload_media_service_factory,
+};
+use crate::domain::politics::image_upload_politics::MAX_UPLOADED_IMAGE_SIZE;
+use crate::domain::repositories::media_repository::{MediaQueryType, MediaSortField};
+use crate::domain::services::media::{
+    delete_media_service::DeleteMediaParams, fetch_many_media_service::FetchManyMediaParams,
+    get_media_service::GetMediaParams, update_media_service::UpdateMediaParams,
+    upload_media_service::UploadMediaParams,
+};
+use crate::infra::extensions::validator::IntoDomainError;
+use crate::infra::http::dtos::media::{ListMediaDto, UpdateMediaDto, UploadMediaDto};
+use crate::infra::http::extractors::req_user::ReqUser;
+use crate::infra::http::middlewares::authentication_middleware;
+use crate::infra::http::presenters::media::{MappedMedia, MediaPresenter};
+use crate::infra::http::presenters::pagination::PaginationPresenter;
+use crate::infra::http::presenters::presenter::{JsonWrappedEntity, PresenterTrait};
+use crate::infra::sea::sea_service::SeaService;
+
+pub struct MediaController;
+
+impl ControllerTrait for MediaController {
+    fn register(cfg: &mut web::ServiceConfig) {
+        cfg.service(
+            web::scope("/media")
+                // oversized files still get through, so that the service can tell why they're rejected
+                .app_data(
+                    MultipartFormConfig::default()
+                        .total_limit(MAX_UPLOADED_IMAGE_SIZE * 2)
+                        .memory_limit(MAX_UPLOADED_IMAGE_SIZE * 2),
+                )
+                // CREATE
+                .route(
+                    "/upload",
+                    web::post()
+                        .to(Self::upload)
+                        .wrap(from_fn(authentication_middleware)),
+                )
+                // READ
+                .route(
+                    "/list",
+                    web::get()
+                        .to(Self::list)
+                        .wrap(from_fn(authentication_middleware)),
+                )
+                .route(
+                    "/{id}/get",
+                    web::get()
+                        .to(Self::get)
+                        .wrap(from_fn(authentication_middleware)),
+                )
+                // UPDATE
+                .route(
+                    "/{id}/update",
+                    web::put()
+                        .to(Self::update)
+                        .wrap(from_fn(authentication_middleware)),
+                )
+                // DELETE
+                .route(
+                    "/{id}/delete",
+                    web::delete()
+                        .to(Self::delete)
+                        .wrap(from_fn(authentication_middleware)),
+                ),
+        );
+    }
+}
+
+impl MediaController {
+    async fn upload(
+        db_conn: web::Data<SeaService>,
+        user: web::ReqData<ReqUser>,
+        MultipartForm(form): MultipartForm<UploadMediaDto>,
+    ) -> AppResponse {
+        let user = user.into_inner();
+
+        let service = upload_media_service_factory::exec(&db_conn);
+
+        let media = service
+            .exec(UploadMediaParams {
+                user_id: user.user_id,
+                user_role: user.user_role.unwrap(),
+                content_type: form
+                    .file
+                    .content_type
+                    .map(|mime| mime.essence_str().to_owned()),
+                file_name: form.file.file_name,
+                content: form.file.data.to_vec(),
+                alt_text: form
+                    .alt_text
+                    .map(|alt_text| alt_text.into_inner())
+                    .unwrap_or_default(),
+                tags: form.tags.into_iter().map(|tag| tag.into_inner()).collect(),
+            })
+            .await?;
+
+        Ok(HttpResponse::Created().json(JsonWrappedEntity {
+            data: MediaPresenter::to_http(media),
+        }))
+    }
+
+    async fn list(
+        db_conn: web::Data<SeaService>,
+        user: web::ReqData<ReqUser>,
+        query: web::Query<ListMediaDto>,
+    ) -> AppResponse {
+        let ListMediaDto {
+            page,
+            per_page,
+            text,
+            tag,
+            uploader_id,
+            created_from,
+            created_to,
+            sort,
+        } = query
+            .validate()
+            .map(|_| query.into_inner())
+            .map_err(IntoDomainError::into_domain_err)?;
+
+        let created_at = DateRange::new(created_from, created_to)?;
+
+        let filters = [
+            text.map(MediaQueryType::Text),
+            tag.map(MediaQueryType::Tag),
+            uploader_id.map(MediaQueryType::Uploader),
+            (!created_at.is_unbounded()).then_some(MediaQueryType::CreatedAt(created_at)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        let sort = sort
+            .as_deref()
+            .map(MediaSortField::parse_expression)
+            .transpose()?
+            .unwrap_or_default();
+
+        let service = fetch_many_media_service_factory::exec(&db_conn);
+
+        let response = service
+            .exec(FetchManyMediaParams {
+                user_role: user.into_inner().user_role.unwrap(),
+                page,
+                per_page: per_page.map(|per_page| per_page as u32),
+                filters,
+                sort,
+            })
+            .await?;
+
+        let mapped_media = response
+            .data
+            .into_iter()
+            .map(MediaPresenter::to_http)
+            .collect::<Vec<MappedMedia>>();
+
+        let mapped_pagination =
+            PaginationPresenter::to_http(response.pagination, per_page.unwrap_or(DEFAULT_PER_PAGE));
+
+        Ok(
+            HttpResponse::Ok().json(MediaPresenter::to_json_paginated_wrapper(
+                mapped_media,
+                mapped_pagination,
+            )),
+        )
+    }
+
+    async fn get(
+        db_conn: web::Data<SeaService>,
+        user: web::ReqData<ReqUser>,
+        media_id: web::Path<Uuid>,
+    ) -> AppResponse {
+        let service = get_media_service_factory::exec(&db_conn);
+
+        let response = service
+            .exec(GetMediaParams {
+                user_role: user.into_inner().user_role.unwrap(),
+                media_id: media_id.into_inner(),
+            })
+            .await?;
+
+        Ok(HttpResponse::Ok().json(JsonWrappedEntity {
+            data: MediaPresenter::to_http_with_usages(response.media, response.usages),
+        }))
+    }
+
+    async fn update(
+        db_conn: web::Data<SeaService>,
+        user: web::ReqData<ReqUser>,
+        body: web::Json<UpdateMediaDto>,
+        media_id: web::Path<Uuid>,
+    ) -> AppResponse {
+        let body = body
+            .validate()
+            .map(|_| body.into_inner())
+            .map_err(IntoDomainError::into_domain_err)?;
+
+        let user = user.into_inner();
+
+        let service = update_media_service_factory::exec(&db_conn);
+
+        let media = service
+            .exec(UpdateMediaParams {
+                user_id: user.user_id,
+                user_role: user.user_role.unwrap(),
+                media_id: media_id.into_inner(),
+                alt_text: body.alt_text,
+                tags: body.tags,
+            })
+            .await?;
+
+        Ok(HttpResponse::Ok().json(JsonWrappedEntity {
+            data: MediaPresenter::to_http(media),
+        }))
+    }
+
+    async fn delete(
+        db_conn: web::Data<SeaService>,
+        user: web::ReqData<ReqUser>,
+        media_id: web::Path<Uuid>,
+    ) -> AppResponse {
+        let user = user.into_inner();
+
+        let service = delete_media_service_factory::exec(&db_conn);
+
+        service
+            .exec(DeleteMediaParams {
+                user_id: user.user_id,
+                user_role: user.user_role.unwrap(),
+                media_id: media_id.into_inner(),
+            })
+            .await?;
+
+        Ok(HttpResponse::NoContent().finish())
+    }
+}
@@ -4,11 +4,17 @@ use crate::error::DomainError;
 
 pub mod article_tags_controller;
 pub mod articles_controller;
+pub mod blocked_words_controller;
 pub mod comment_reports_controller;
 pub mod comments_controller;
 pub mod controller;
+pub mod feeds_controller;
 pub mod free_badges_controller;
+pub mod media_controller;
+pub mod reactions_controller;
+pub mod series_controller;
 pub mod sessions_controller;
+pub mod sitemaps_controller;
 pub mod team_roles_controller;
 pub mod team_users_controller;
 pub mod users_controller;
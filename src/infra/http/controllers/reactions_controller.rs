@@ -0,0 +1,83 @@
+use super::controller::ControllerTrait;
+use super::AppResponse;
+use crate::domain::domain_entities::reaction::ReactionTarget;
+use crate::domain::factories::journalism::reactions::react_service_factory;
+use crate::domain::politics::reaction_politics::ReactionPolitics;
+use crate::domain::services::journalism::reactions::react_service::ReactParams;
+use crate::infra::extensions::validator::IntoDomainError;
+use crate::infra::http::dtos::react::ReactDto;
+use crate::infra::http::extractors::req_user::ReqUser;
+use crate::infra::http::middlewares::authentication_middleware;
+use crate::infra::http::presenters::presenter::{JsonWrappedEntity, PresenterTrait};
+use crate::infra::http::presenters::reaction::ReactionPresenter;
+use crate::infra::sea::sea_service::SeaService;
+use crate::ENV_VARS;
+use actix_web::{middleware::from_fn, web, HttpResponse};
+use serde_json::json;
+use uuid::Uuid;
+use validator::Validate;
+
+pub struct ReactionsController;
+
+impl ControllerTrait for ReactionsController {
+    fn register(cfg: &mut web::ServiceConfig) {
+        cfg.service(
+            web::scope("/reactions")
+                // READ
+                .route("/list", web::get().to(Self::list))
+                // UPDATE
+                .route(
+                    "/{target}/{id}",
+                    web::put()
+                        .to(Self::react)
+                        .wrap(from_fn(authentication_middleware)),
+                ),
+        );
+    }
+}
+
+impl ReactionsController {
+    async fn list() -> AppResponse {
+        let reactions = ReactionPolitics::allowed(ENV_VARS.reactions.as_deref());
+
+        Ok(HttpResponse::Ok().json(json!({"data": reactions})))
+    }
+
+    async fn react(
+        db_conn: web::Data<SeaService>,
+        path: web::Path<(String, Uuid)>,
+        body: web::Json<ReactDto>,
+        user: web::ReqData<ReqUser>,
+    ) -> AppResponse {
+        let ReactDto { reaction } = body
+            .validate()
+            .map(|_| body.into_inner())
+            .map_err(IntoDomainError::into_domain_err)?;
+
+        let (target, target_id) = path.into_inner();
+        let target = target.parse::<ReactionTarget>()?;
+
+        let ReqUser {
+            user_role,
+            user_id,
+            exp: _,
+        } = user.into_inner();
+
+        let service = react_service_factory::exec(&db_conn);
+
+        let reactions = service
+            .exec(ReactParams {
+                user_id,
+                user_role: user_role.unwrap(),
+                target,
+                target_id,
+                reaction,
+                allowed_reactions: ReactionPolitics::allowed(ENV_VARS.reactions.as_deref()),
+            })
+            .await?;
+
+        Ok(HttpResponse::Ok().json(JsonWrappedEntity {
+            data: ReactionPresenter::to_http(reactions),
+        }))
+    }
+}
@@ -0,0 +1,149 @@
+use actix_web::{web, HttpResponse};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::domain_entities::slug::Slug;
+use crate::domain::factories::journalism::articles::resolve_article_covers_service_factory;
+use crate::domain::factories::journalism::series::{
+    create_series_service_factory, delete_series_service_factory, get_series_service_factory,
+    update_series_service_factory,
+};
+use crate::domain::services::journalism::series::{
+    create_series_service::CreateSeriesParams,
+    delete_series_service::DeleteSeriesParams,
+    get_series_service::{GetSeriesParams, GetSeriesResponse},
+    update_series_service::UpdateSeriesParams,
+};
+use crate::infra::extensions::validator::IntoDomainError;
+use crate::infra::http::dtos::series::{CreateSeriesDto, UpdateSeriesDto};
+use crate::infra::http::extractors::req_user::ReqUser;
+use crate::infra::http::middlewares::AuthenticationMiddleware;
+use crate::infra::http::presenters::presenter::{JsonWrappedEntity, PresenterTrait};
+use crate::infra::http::presenters::series::SeriesPresenter;
+use crate::infra::sea::sea_service::SeaService;
+
+use super::controller::ControllerTrait;
+use super::AppResponse;
+
+pub struct SeriesController;
+
+impl ControllerTrait for SeriesController {
+    fn register(cfg: &mut web::ServiceConfig) {
+        cfg.service(
+            web::scope("/series")
+                // CREATE
+                .route(
+                    "/new",
+                    web::post().to(Self::create).wrap(AuthenticationMiddleware),
+                )
+                // READ
+                .route("/{slug}/get", web::get().to(Self::get))
+                // UPDATE
+                .route(
+                    "/{id}/update",
+                    web::put().to(Self::update).wrap(AuthenticationMiddleware),
+                )
+                // DELETE
+                .route(
+                    "/{id}/delete",
+                    web::delete()
+                        .to(Self::delete)
+                        .wrap(AuthenticationMiddleware),
+                ),
+        );
+    }
+}
+
+impl SeriesController {
+    async fn create(
+        db_conn: web::Data<SeaService>,
+        body: web::Json<CreateSeriesDto>,
+        staff: web::ReqData<ReqUser>,
+    ) -> AppResponse {
+        body.validate().map_err(IntoDomainError::into_domain_err)?;
+        let body = body.into_inner();
+        let staff = staff.into_inner();
+
+        let service = create_series_service_factory::exec(&db_conn);
+
+        service
+            .exec(CreateSeriesParams {
+                staff_id: staff.user_id,
+                staff_role: staff.user_role.unwrap(),
+                title: body.title,
+                description: body.description,
+                articles_ids: body.articles_ids,
+            })
+            .await
+            .map(|series| {
+                HttpResponse::Created().json(JsonWrappedEntity {
+                    data: SeriesPresenter::to_http(series),
+                })
+            })
+    }
+
+    async fn get(
+        db_conn: web::Data<SeaService>,
+        series_slug: web::Path<String>,
+        user: Option<web::ReqData<ReqUser>>,
+    ) -> AppResponse {
+        let service = get_series_service_factory::exec(&db_conn);
+
+        let GetSeriesResponse { series, articles } = service
+            .exec(GetSeriesParams {
+                series_slug: Slug::new_from_existing(series_slug.into_inner()),
+                user_role: user.as_ref().and_then(|user| user.user_role.as_ref()),
+            })
+            .await?;
+
+        let articles = resolve_article_covers_service_factory::exec().exec_on_articles(articles);
+
+        Ok(HttpResponse::Ok().json(JsonWrappedEntity {
+            data: SeriesPresenter::to_http_with_articles(series, articles),
+        }))
+    }
+
+    async fn update(
+        db_conn: web::Data<SeaService>,
+        body: web::Json<UpdateSeriesDto>,
+        staff: web::ReqData<ReqUser>,
+        series_id: web::Path<Uuid>,
+    ) -> AppResponse {
+        body.validate().map_err(IntoDomainError::into_domain_err)?;
+        let body = body.into_inner();
+
+        let service = update_series_service_factory::exec(&db_conn);
+
+        service
+            .exec(UpdateSeriesParams {
+                staff_role: staff.into_inner().user_role.unwrap(),
+                series_id: series_id.into_inner(),
+                title: body.title,
+                description: body.description,
+                articles_ids: body.articles_ids,
+            })
+            .await
+            .map(|series| {
+                HttpResponse::Ok().json(JsonWrappedEntity {
+                    data: SeriesPresenter::to_http(series),
+                })
+            })
+    }
+
+    async fn delete(
+        db_conn: web::Data<SeaService>,
+        staff: web::ReqData<ReqUser>,
+        series_id: web::Path<Uuid>,
+    ) -> AppResponse {
+        let service = delete_series_service_factory::exec(&db_conn);
+
+        service
+            .exec(DeleteSeriesParams {
+                staff_role: staff.into_inner().user_role.unwrap(),
+                series_id: series_id.into_inner(),
+            })
+            .await?;
+
+        Ok(HttpResponse::NoContent().finish())
+    }
+}
@@ -0,0 +1,97 @@
+use actix_web::{web, HttpRequest};
+
+use crate::domain::factories::seo::{
+    fetch_sitemap_index_service_factory, fetch_sitemap_service_factory,
+};
+use crate::domain::services::seo::fetch_sitemap_service::{FetchSitemapParams, Sitemap};
+use crate::infra::http::cache_validators::respond_with_cache_validators;
+use crate::infra::http::presenters::sitemap::SitemapPresenter;
+use crate::infra::sea::sea_service::SeaService;
+use crate::util::SiteUrl;
+
+use super::controller::ControllerTrait;
+use super::AppResponse;
+
+const XML_CONTENT_TYPE: &str = "application/xml; charset=utf-8";
+
+pub struct SitemapsController;
+
+impl ControllerTrait for SitemapsController {
+    fn register(cfg: &mut web::ServiceConfig) {
+        cfg.route("/sitemap.xml", web::get().to(Self::index))
+            .service(
+                web::scope("/sitemaps")
+                    // READ
+                    .route("/static.xml", web::get().to(Self::static_pages))
+                    .route("/articles-{page:\\d+}.xml", web::get().to(Self::articles))
+                    .route("/tags-{page:\\d+}.xml", web::get().to(Self::tags))
+                    .route(
+                        "/free-badges-{page:\\d+}.xml",
+                        web::get().to(Self::free_badges),
+                    ),
+            );
+    }
+}
+
+impl SitemapsController {
+    async fn index(request: HttpRequest, db_conn: web::Data<SeaService>) -> AppResponse {
+        let service = fetch_sitemap_index_service_factory::exec(&db_conn);
+
+        let index = service.exec().await?;
+
+        let body = SitemapPresenter::to_index(&index, &SiteUrl::absolute("sitemaps"));
+
+        Ok(respond_with_cache_validators(
+            &request,
+            body,
+            XML_CONTENT_TYPE,
+            None,
+        ))
+    }
+
+    async fn static_pages(request: HttpRequest, db_conn: web::Data<SeaService>) -> AppResponse {
+        Self::sitemap(request, &db_conn, Sitemap::StaticPages).await
+    }
+
+    async fn articles(
+        request: HttpRequest,
+        db_conn: web::Data<SeaService>,
+        page: web::Path<u32>,
+    ) -> AppResponse {
+        Self::sitemap(request, &db_conn, Sitemap::Articles(page.into_inner())).await
+    }
+
+    async fn tags(
+        request: HttpRequest,
+        db_conn: web::Data<SeaService>,
+        page: web::Path<u32>,
+    ) -> AppResponse {
+        Self::sitemap(request, &db_conn, Sitemap::Tags(page.into_inner())).await
+    }
+
+    async fn free_badges(
+        request: HttpRequest,
+        db_conn: web::Data<SeaService>,
+        page: web::Path<u32>,
+    ) -> AppResponse {
+        Self::sitemap(request, &db_conn, Sitemap::FreeBadges(page.into_inner())).await
+    }
+
+    async fn sitemap(request: HttpRequest, db_conn: &SeaService, sitemap: Sitemap) -> AppResponse {
+        let service = fetch_sitemap_service_factory::exec(db_conn);
+
+        let entries = service.exec(FetchSitemapParams { sitemap }).await?;
+
+        let last_modified = entries
+            .iter()
+            .filter_map(|entry| entry.last_modified())
+            .max();
+
+        Ok(respond_with_cache_validators(
+            &request,
+            SitemapPresenter::to_urlset(&entries),
+            XML_CONTENT_TYPE,
+            last_modified,
+        ))
+    }
+}
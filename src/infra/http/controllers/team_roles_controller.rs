@@ -1,11 +1,12 @@
 use super::controller::ControllerTrait;
 use super::AppResponse;
 use crate::core::pagination::DEFAULT_PER_PAGE;
+use crate::core::query::SortField;
 use crate::domain::factories::teams::{
     create_team_role_service_factory, delete_team_role_service_factory,
     fetch_many_team_roles_service_factory, update_team_role_service_factory,
 };
-use crate::domain::repositories::team_role_repository::TeamRoleQueryType;
+use crate::domain::repositories::team_role_repository::{TeamRoleQueryType, TeamRoleSortField};
 use crate::domain::services::teams::{
     create_team_role_service::CreateTeamRoleParams, delete_team_role_service::DeleteTeamRoleParams,
     fetch_many_team_roles_service::FetchManyTeamRolesParams,
@@ -97,6 +98,13 @@ impl TeamRolesController {
             .map(|_| query.into_inner())
             .map_err(IntoDomainError::into_domain_err)?;
 
+        let sort = query
+            .sort
+            .as_deref()
+            .map(TeamRoleSortField::parse_expression)
+            .transpose()?
+            .unwrap_or_default();
+
         let team_roles = service
             .exec(FetchManyTeamRolesParams {
                 per_page: if query.per_page.is_some() {
@@ -105,7 +113,13 @@ impl TeamRolesController {
                     None
                 },
                 page: query.page,
-                query: query.title.map(TeamRoleQueryType::Title),
+                filters: query
+                    .title
+                    .clone()
+                    .map(TeamRoleQueryType::Title)
+                    .into_iter()
+                    .collect(),
+                sort,
             })
             .await?;
 
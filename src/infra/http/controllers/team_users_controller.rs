@@ -1,11 +1,12 @@
 use super::controller::ControllerTrait;
 use super::AppResponse;
 use crate::core::pagination::DEFAULT_PER_PAGE;
+use crate::core::query::SortField;
 use crate::domain::factories::teams::{
     create_team_user_service_factory, delete_team_user_service_factory,
     fetch_many_team_users_service_factory, update_team_user_service_factory,
 };
-use crate::domain::repositories::team_user_repository::TeamUserQueryType;
+use crate::domain::repositories::team_user_repository::{TeamUserQueryType, TeamUserSortField};
 use crate::domain::services::teams::{
     create_team_user_service::CreateTeamUserParams, delete_team_user_service::DeleteTeamUserParams,
     fetch_many_team_users_service::FetchManyTeamUsersParams,
@@ -100,20 +101,28 @@ impl TeamUsersController {
             per_page,
             team_role_id,
             nickname,
+            sort,
         } = query.into_inner();
 
-        let query = {
-            if let Some(team_role_id) = team_role_id {
-                Some(TeamUserQueryType::TeamRole(team_role_id))
-            } else {
-                nickname.map(TeamUserQueryType::Nickname)
-            }
-        };
+        let filters = [
+            team_role_id.map(TeamUserQueryType::TeamRole),
+            nickname.map(TeamUserQueryType::Nickname),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        let sort = sort
+            .as_deref()
+            .map(TeamUserSortField::parse_expression)
+            .transpose()?
+            .unwrap_or_default();
 
         let team_users = service
             .exec(FetchManyTeamUsersParams {
                 per_page: per_page.map(|pp| pp as u32),
-                query,
+                filters,
+                sort,
                 page,
             })
             .await?;
@@ -1,3 +1,4 @@
+use actix_web::http::header::ContentDisposition;
 use actix_web::{web, HttpResponse};
 use serde_json::json;
 use std::str::FromStr;
@@ -5,27 +6,39 @@ use uuid::Uuid;
 use validator::Validate;
 
 use crate::core::pagination::DEFAULT_PER_PAGE;
+use crate::core::query::{DateRange, SortField};
+use crate::domain::domain_entities::habbo_avatar::{HabboAvatar, HabboHotel};
 use crate::domain::domain_entities::role::Role;
 use crate::domain::factories::identity::{
-    change_password_service_factory, create_user_service_factory, fetch_many_users_service_factory,
-    get_user_service_factory, update_user_service_factory,
+    change_password_service_factory, create_user_service_factory, delete_account_service_factory,
+    export_personal_data_service_factory, fetch_many_users_service_factory,
+    get_user_profile_service_factory, get_user_service_factory, update_profile_service_factory,
+    update_user_service_factory,
 };
-use crate::domain::repositories::user_repository::UserQueryType;
+use crate::domain::factories::journalism::articles::resolve_article_covers_service_factory;
+use crate::domain::repositories::user_repository::{UserQueryType, UserSortField};
 use crate::domain::services::identity::{
     change_password_service::ChangePasswordParams, create_user_service::CreateUserParams,
-    fetch_many_users_service::FetchManyUsersParams, get_user_service::GetUserServiceParams,
+    delete_account_service::DeleteAccountParams,
+    export_personal_data_service::ExportPersonalDataParams,
+    fetch_many_users_service::FetchManyUsersParams, get_user_profile_service::GetUserProfileParams,
+    get_user_service::GetUserServiceParams, update_profile_service::UpdateProfileParams,
     update_user_service::UpdateUserParams,
 };
 use crate::infra::extensions::validator::IntoDomainError;
 use crate::infra::http::dtos::change_password::ChangePasswordDto;
 use crate::infra::http::dtos::create_user::CreateUserDto;
+use crate::infra::http::dtos::delete_account::DeleteAccountDto;
 use crate::infra::http::dtos::list_users::ListUsersDto;
+use crate::infra::http::dtos::update_profile::UpdateProfileDto;
 use crate::infra::http::dtos::update_user::UpdateUserDto;
 use crate::infra::http::extractors::req_user::ReqUser;
 use crate::infra::http::middlewares::AuthenticationMiddleware;
 use crate::infra::http::presenters::pagination::PaginationPresenter;
+use crate::infra::http::presenters::personal_data_export::PersonalDataExportPresenter;
 use crate::infra::http::presenters::presenter::PresenterTrait;
 use crate::infra::http::presenters::user::{MappedUser, UserPresenter};
+use crate::infra::http::presenters::user_profile::UserProfilePresenter;
 use crate::infra::sea::sea_service::SeaService;
 
 use super::controller::ControllerTrait;
@@ -51,11 +64,34 @@ impl ControllerTrait for UsersController {
                         .to(Self::edit_password)
                         .wrap(AuthenticationMiddleware),
                 )
+                // UPDATE OWN PROFILE
+                .route(
+                    "/profile",
+                    web::put()
+                        .to(Self::update_profile)
+                        .wrap(AuthenticationMiddleware),
+                )
+                // EXPORT OWN PERSONAL DATA
+                .route(
+                    "/me/export",
+                    web::get()
+                        .to(Self::export_personal_data)
+                        .wrap(AuthenticationMiddleware),
+                )
+                // DELETE OWN ACCOUNT
+                .route(
+                    "/me/delete",
+                    web::delete()
+                        .to(Self::delete_account)
+                        .wrap(AuthenticationMiddleware),
+                )
                 // LIST USERS WITH PAGINATION
                 .route(
                     "/list",
                     web::get().to(Self::list).wrap(AuthenticationMiddleware),
                 )
+                // GET USER'S PUBLIC PROFILE
+                .route("/{nickname}/profile", web::get().to(Self::profile))
                 // GET SINGLE USER BY ID
                 .route(
                     "/{id}",
@@ -155,6 +191,91 @@ impl UsersController {
         Ok(HttpResponse::Ok().finish())
     }
 
+    async fn update_profile(
+        db_conn: web::Data<SeaService>,
+        body: web::Json<UpdateProfileDto>,
+        user: web::ReqData<ReqUser>,
+    ) -> AppResponse {
+        let UpdateProfileDto {
+            bio,
+            habbo_hotel,
+            avatar_direction,
+            avatar_head_only,
+        } = body
+            .validate()
+            .map(|_| body.into_inner())
+            .map_err(IntoDomainError::into_domain_err)?;
+
+        let habbo_hotel = habbo_hotel
+            .map(|hotel| HabboHotel::from_str(&hotel))
+            .transpose()?;
+
+        let update_profile_service = update_profile_service_factory::exec(&db_conn);
+
+        let user = update_profile_service
+            .exec(UpdateProfileParams {
+                user_id: user.user_id,
+                bio,
+                habbo_hotel,
+                avatar_direction: avatar_direction.unwrap_or(HabboAvatar::DEFAULT_DIRECTION),
+                avatar_head_only,
+            })
+            .await?;
+
+        let mapped_user = UserPresenter::to_http(user);
+
+        Ok(HttpResponse::Ok().json(json!({"user": mapped_user})))
+    }
+
+    async fn export_personal_data(
+        db_conn: web::Data<SeaService>,
+        user: web::ReqData<ReqUser>,
+    ) -> AppResponse {
+        let export_personal_data_service = export_personal_data_service_factory::exec(&db_conn);
+
+        let export = export_personal_data_service
+            .exec(ExportPersonalDataParams {
+                user_id: user.user_id,
+            })
+            .await?;
+
+        let covers_service = resolve_article_covers_service_factory::exec();
+        let article_covers = export
+            .articles
+            .iter()
+            .map(|article| covers_service.exec(article.cover_url()))
+            .collect();
+
+        Ok(HttpResponse::Ok()
+            .insert_header(ContentDisposition::attachment(format!(
+                "dados-{}.json",
+                user.user_id
+            )))
+            .json(PersonalDataExportPresenter::to_http(export, article_covers)))
+    }
+
+    async fn delete_account(
+        db_conn: web::Data<SeaService>,
+        body: web::Json<DeleteAccountDto>,
+        user: web::ReqData<ReqUser>,
+    ) -> AppResponse {
+        let DeleteAccountDto { password } = body
+            .validate()
+            .map(|_| body.into_inner())
+            .map_err(IntoDomainError::into_domain_err)?;
+
+        let delete_account_service = delete_account_service_factory::exec(&db_conn);
+
+        delete_account_service
+            .exec(DeleteAccountParams {
+                user_id: user.user_id,
+                password,
+            })
+            .await?;
+
+        Ok(HttpResponse::NoContent().finish())
+    }
+
     async fn get(db_conn: web::Data<SeaService>, user_id: web::Path<Uuid>) -> AppResponse {
         let get_user_service = get_user_service_factory::exec(&db_conn);
 
@@ -168,12 +289,27 @@ impl UsersController {
         Ok(HttpResponse::Ok().json(json!({"user": user})))
     }
 
+    async fn profile(db_conn: web::Data<SeaService>, nickname: web::Path<String>) -> AppResponse {
+        let get_user_profile_service = get_user_profile_service_factory::exec(&db_conn);
+
+        let profile = get_user_profile_service
+            .exec(GetUserProfileParams {
+                nickname: nickname.into_inner(),
+            })
+            .await?;
+
+        Ok(HttpResponse::Ok().json(json!({"profile": UserProfilePresenter::to_http(profile)})))
+    }
+
     async fn list(db_conn: web::Data<SeaService>, query: web::Query<ListUsersDto>) -> AppResponse {
         let ListUsersDto {
             nickname,
             page,
             per_page,
             role,
+            created_from,
+            created_to,
+            sort,
         } = query
             .validate()
             .map(|_| query.into_inner())
@@ -181,23 +317,31 @@ impl UsersController {
 
         let fetch_many_users_service = fetch_many_users_service_factory::exec(&db_conn);
 
-        let query: Option<UserQueryType> = if let Some(nickname) = nickname {
-            Some(UserQueryType::Nickname(nickname))
-        } else {
-            match role.map(|role| Role::from_str(&role)) {
-                Some(role) => match role {
-                    Err(err) => return Err(err),
-                    Ok(role) => Some(UserQueryType::Role(role)),
-                },
-                None => None,
-            }
-        };
+        let created_at = DateRange::new(created_from, created_to)?;
+
+        let filters = [
+            nickname.map(UserQueryType::Nickname),
+            role.map(|role| Role::from_str(&role))
+                .transpose()?
+                .map(UserQueryType::Role),
+            (!created_at.is_unbounded()).then_some(UserQueryType::CreatedAt(created_at)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        let sort = sort
+            .as_deref()
+            .map(UserSortField::parse_expression)
+            .transpose()?
+            .unwrap_or_default();
 
         let users = fetch_many_users_service
             .exec(FetchManyUsersParams {
                 page,
                 per_page: per_page.map(|v| v as u32),
-                query,
+                filters,
+                sort,
             })
             .await?;
 
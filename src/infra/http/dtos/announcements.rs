@@ -1,3 +1,4 @@
+use chrono::NaiveDateTime as DateTime;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
@@ -35,4 +36,13 @@ pub struct ListAnnouncementsDto {
     pub per_page: Option<u8>,
 
     pub description: Option<String>,
+
+    #[serde(rename = "createdFrom")]
+    pub created_from: Option<DateTime>,
+
+    #[serde(rename = "createdTo")]
+    pub created_to: Option<DateTime>,
+
+    /// e.g. `-createdAt`
+    pub sort: Option<String>,
 }
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct ArticleCommentModeDto {
+    /// One of "open", "staff_only" or "closed".
+    #[serde(rename = "commentMode")]
+    pub comment_mode: String,
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct UpsertArticleTranslationDto {
+    #[validate(length(min = 1, message = "Title must not be empty."))]
+    pub title: String,
+
+    pub content: String,
+}
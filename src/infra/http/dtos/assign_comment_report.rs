@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct AssignCommentReportDto {
+    /// Left out to unassign the report.
+    #[serde(rename = "assigneeId")]
+    pub assignee_id: Option<Uuid>,
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct CreateBlockedWordDto {
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "Pattern must have between 1 and 255 characters."
+    ))]
+    pub pattern: String,
+
+    #[serde(rename = "isRegex", default)]
+    pub is_regex: bool,
+
+    /// One of "mask", "reject" or "flag".
+    pub action: String,
+}
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct UpdateBlockedWordDto {
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "Pattern must have between 1 and 255 characters."
+    ))]
+    pub pattern: Option<String>,
+
+    #[serde(rename = "isRegex")]
+    pub is_regex: Option<bool>,
+
+    pub action: Option<String>,
+}
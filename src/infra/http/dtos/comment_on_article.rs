@@ -1,8 +1,13 @@
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 use validator::Validate;
 
 #[derive(Serialize, Deserialize, Validate)]
 pub struct CommentOnArticleDto {
     #[validate(length(min = 1, message = "Comment can't be empty."))]
     pub content: String,
+
+    /// The comment being replied to, if any.
+    #[serde(rename = "parentId")]
+    pub parent_id: Option<Uuid>,
 }
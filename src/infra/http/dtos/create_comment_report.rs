@@ -5,4 +5,7 @@ use validator::Validate;
 pub struct CreateCommentReportDto {
     #[validate(length(min = 1, message = "Comment report can't be empty."))]
     pub content: String,
+
+    /// One of "spam", "offensive", "scam_link", "off_topic" or "other", the latter if left out.
+    pub reason: Option<String>,
 }
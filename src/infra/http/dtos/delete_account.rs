@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct DeleteAccountDto {
+    #[validate(length(min = 1))]
+    pub password: String,
+}
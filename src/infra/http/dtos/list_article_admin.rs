@@ -1,3 +1,4 @@
+use chrono::NaiveDateTime as DateTime;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
@@ -12,6 +13,20 @@ pub struct AdminListArticlesDto {
 
     pub author: Option<String>,
 
+    pub tag: Option<i32>,
+
+    #[serde(rename = "createdFrom")]
+    pub created_from: Option<DateTime>,
+
+    #[serde(rename = "createdTo")]
+    pub created_to: Option<DateTime>,
+
+    /// e.g. `-createdAt,title`
+    pub sort: Option<String>,
+
+    /// Switches to cursor pagination, in which `page` and `sort` don't apply. Empty for the first page.
+    pub cursor: Option<String>,
+
     #[serde(rename = "approvedState")]
     pub approved_state: Option<bool>,
 }
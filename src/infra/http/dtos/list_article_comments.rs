@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct ListArticleCommentsDto {
+    pub page: Option<u32>,
+
+    #[serde(rename = "perPage")]
+    pub per_page: Option<u8>,
+
+    /// Switches to cursor pagination, in which `page` doesn't apply. Empty for the first page.
+    pub cursor: Option<String>,
+}
@@ -7,4 +7,6 @@ pub struct ListArticleTagsDto {
     #[serde(rename = "perPage")]
     pub per_page: Option<u8>,
     pub value: Option<String>,
+    /// e.g. `value`
+    pub sort: Option<String>,
 }
@@ -1,3 +1,4 @@
+use chrono::NaiveDateTime as DateTime;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
@@ -14,4 +15,23 @@ pub struct ListCommentReportsDto {
     pub solved: Option<bool>,
 
     pub content: Option<String>,
+
+    /// One of the reasons reports can be made for, e.g. `scam_link`.
+    pub reason: Option<String>,
+
+    /// The nickname of the moderator the reports have been assigned to.
+    #[serde(rename = "assignedTo")]
+    pub assigned_to: Option<String>,
+
+    #[serde(rename = "createdFrom")]
+    pub created_from: Option<DateTime>,
+
+    #[serde(rename = "createdTo")]
+    pub created_to: Option<DateTime>,
+
+    /// e.g. `-createdAt` or `-priority`
+    pub sort: Option<String>,
+
+    /// Switches to cursor pagination, in which `page` and `sort` don't apply. Empty for the first page.
+    pub cursor: Option<String>,
 }
@@ -1,3 +1,4 @@
+use chrono::NaiveDateTime as DateTime;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
@@ -20,4 +21,11 @@ pub struct ListCommentsDto {
     pub content: Option<String>,
 
     pub author: Option<String>,
+
+    pub created_from: Option<DateTime>,
+
+    pub created_to: Option<DateTime>,
+
+    /// e.g. `-createdAt`
+    pub sort: Option<String>,
 }
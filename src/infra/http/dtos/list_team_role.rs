@@ -9,4 +9,7 @@ pub struct ListTeamRoleDto {
     pub per_page: Option<u8>,
 
     pub title: Option<String>,
+
+    /// e.g. `title`
+    pub sort: Option<String>,
 }
@@ -12,4 +12,7 @@ pub struct ListTeamUsersDto {
 
     #[validate(length(min = 1))]
     pub nickname: Option<String>,
+
+    /// e.g. `nickname`
+    pub sort: Option<String>,
 }
@@ -1,4 +1,5 @@
 use crate::libs::custom_validators::validate_user_role;
+use chrono::NaiveDateTime as DateTime;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
@@ -13,4 +14,11 @@ pub struct ListUsersDto {
 
     #[validate(length(min = 1))]
     pub nickname: Option<String>,
+
+    pub created_from: Option<DateTime>,
+
+    pub created_to: Option<DateTime>,
+
+    /// e.g. `-lastLogin,nickname`
+    pub sort: Option<String>,
 }
@@ -0,0 +1,43 @@
+use actix_multipart::form::bytes::Bytes;
+use actix_multipart::form::text::Text;
+use actix_multipart::form::MultipartForm;
+use chrono::NaiveDateTime as DateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(MultipartForm)]
+pub struct UploadMediaDto {
+    pub file: Bytes,
+    #[multipart(rename = "altText")]
+    pub alt_text: Option<Text<String>>,
+    /// Sent as repeated `tags` fields.
+    pub tags: Vec<Text<String>>,
+}
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct UpdateMediaDto {
+    #[serde(rename = "altText")]
+    pub alt_text: Option<String>,
+    pub tags: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct ListMediaDto {
+    #[validate(range(min = 1, message = "Min page is 1."))]
+    pub page: Option<u32>,
+    #[serde(rename = "perPage")]
+    #[validate(range(min = 1, message = "Per page amount must be at least 1."))]
+    pub per_page: Option<u8>,
+    /// Searches the file names and alternative texts.
+    pub text: Option<String>,
+    pub tag: Option<String>,
+    #[serde(rename = "uploaderId")]
+    pub uploader_id: Option<Uuid>,
+    #[serde(rename = "createdFrom")]
+    pub created_from: Option<DateTime>,
+    #[serde(rename = "createdTo")]
+    pub created_to: Option<DateTime>,
+    /// e.g. `fileName,-size`
+    pub sort: Option<String>,
+}
@@ -1,4 +1,8 @@
 pub mod announcements;
+pub mod article_comment_mode;
+pub mod article_translation;
+pub mod assign_comment_report;
+pub mod blocked_word;
 pub mod change_password;
 pub mod comment_on_article;
 pub mod create_article;
@@ -8,7 +12,10 @@ pub mod create_free_badge;
 pub mod create_team_role;
 pub mod create_team_user;
 pub mod create_user;
+pub mod delete_account;
+pub mod edit_comment;
 pub mod list_article_admin;
+pub mod list_article_comments;
 pub mod list_article_tags;
 pub mod list_articles;
 pub mod list_comment_reports;
@@ -17,10 +24,19 @@ pub mod list_team_role;
 pub mod list_team_user;
 pub mod list_users;
 pub mod login;
+pub mod media;
+pub mod pin_article;
+pub mod react;
+pub mod related_articles_query;
+pub mod review_comments;
+pub mod series;
 pub mod simple_pagination_query;
+pub mod solve_comment_report;
 pub mod update_article;
 pub mod update_article_tag;
 pub mod update_free_badge;
+pub mod update_profile;
 pub mod update_team_role;
 pub mod update_team_user;
 pub mod update_user;
+pub mod upload_article_cover;
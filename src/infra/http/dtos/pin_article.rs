@@ -0,0 +1,17 @@
+use chrono::NaiveDateTime as DateTime;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct PinArticleDto {
+    pub pinned: bool,
+
+    #[validate(range(min = 0, message = "Pin order must be a non-negative number."))]
+    #[serde(rename = "pinOrder")]
+    pub pin_order: Option<i32>,
+
+    #[serde(rename = "pinnedUntil")]
+    pub pinned_until: Option<DateTime>,
+
+    pub featured: Option<bool>,
+}
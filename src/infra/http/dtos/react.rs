@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct ReactDto {
+    /// One of the configured reactions, or none to remove the current one.
+    #[validate(length(max = 32, message = "Reaction must have at most 32 characters."))]
+    pub reaction: Option<String>,
+}
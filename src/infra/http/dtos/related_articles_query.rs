@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct RelatedArticlesQueryDto {
+    #[validate(range(min = 1, max = 12, message = "Amount must be between 1 and 12."))]
+    pub amount: Option<u32>,
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct ReviewCommentsDto {
+    #[serde(rename = "commentIds")]
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Between 1 and 100 comments must be reviewed at once."
+    ))]
+    pub comment_ids: Vec<Uuid>,
+
+    /// Publishes the comments if set, or else keeps them hidden for good.
+    pub approve: bool,
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct CreateSeriesDto {
+    #[validate(length(min = 1, message = "Title must not be empty."))]
+    pub title: String,
+
+    pub description: String,
+
+    #[serde(rename = "articlesIds", default)]
+    pub articles_ids: Vec<Uuid>,
+}
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct UpdateSeriesDto {
+    #[validate(length(min = 1, message = "Title must not be empty."))]
+    pub title: Option<String>,
+
+    pub description: Option<String>,
+
+    #[serde(rename = "articlesIds")]
+    pub articles_ids: Option<Vec<Uuid>>,
+}
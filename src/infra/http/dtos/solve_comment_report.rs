@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct SolveCommentReportDto {
+    /// One of "comment_hidden", "comment_deleted" or "rejected".
+    pub resolution: String,
+
+    #[serde(rename = "resolutionNotes")]
+    #[validate(length(
+        max = 1000,
+        message = "Resolution notes must have at most 1000 characters."
+    ))]
+    pub resolution_notes: Option<String>,
+}
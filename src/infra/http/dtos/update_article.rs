@@ -19,4 +19,20 @@ pub struct UpdateArticleDto {
 
     #[serde(rename = "tagId")]
     pub tag_id: Option<i32>,
+
+    #[validate(nested)]
+    pub coauthors: Option<Vec<ArticleCoauthorDto>>,
+}
+
+#[derive(Serialize, Deserialize, Validate)]
+pub struct ArticleCoauthorDto {
+    #[serde(rename = "userId")]
+    pub user_id: Uuid,
+
+    #[validate(length(
+        min = 1,
+        max = 32,
+        message = "Contribution must be between 1 and 32 characters long."
+    ))]
+    pub contribution: String,
 }
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// Replaces the whole profile, so fields left out are cleared or set to their defaults.
+#[derive(Serialize, Deserialize, Validate)]
+pub struct UpdateProfileDto {
+    #[validate(length(max = 500, message = "Your bio must have at most 500 characters."))]
+    pub bio: Option<String>,
+
+    /// One of the supported hotels' domains (e.g. `com.br`), or none to hide the avatar.
+    #[serde(rename = "habboHotel")]
+    pub habbo_hotel: Option<String>,
+
+    #[serde(rename = "avatarDirection")]
+    #[validate(range(max = 7, message = "The avatar's direction must be between 0 and 7."))]
+    pub avatar_direction: Option<u8>,
+
+    #[serde(rename = "avatarHeadOnly", default)]
+    pub avatar_head_only: bool,
+}
@@ -0,0 +1,7 @@
+use actix_multipart::form::bytes::Bytes;
+use actix_multipart::form::MultipartForm;
+
+#[derive(MultipartForm)]
+pub struct UploadArticleCoverDto {
+    pub cover: Bytes,
+}
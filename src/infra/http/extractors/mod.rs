@@ -1 +1,2 @@
+pub mod req_locale;
 pub mod req_user;
@@ -0,0 +1,86 @@
+use std::future::{ready, Ready};
+use std::str::FromStr;
+
+use actix_web::dev::Payload;
+use actix_web::http::header::{AcceptLanguage, Header, Preference};
+use actix_web::{web, FromRequest, HttpRequest};
+use serde::Deserialize;
+
+use crate::domain::domain_entities::locale::Locale;
+use crate::error::DomainError;
+
+#[derive(Deserialize)]
+struct LangQuery {
+    lang: Option<String>,
+}
+
+/// The locale the client wants to read in: the `lang` query parameter's, otherwise the most preferred supported one of
+/// the `Accept-Language` header. `None` if there's neither, in which case the fallback locale is served.
+pub struct ReqLocale(pub Option<Locale>);
+
+impl FromRequest for ReqLocale {
+    type Error = DomainError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(Self::extract_locale(req).map(ReqLocale))
+    }
+}
+
+impl ReqLocale {
+    fn extract_locale(req: &HttpRequest) -> Result<Option<Locale>, DomainError> {
+        let lang = web::Query::<LangQuery>::from_query(req.query_string())
+            .ok()
+            .and_then(|query| query.into_inner().lang);
+
+        if let Some(lang) = lang {
+            return Locale::from_str(&lang).map(Some);
+        }
+
+        // unsupported and malformed languages are just ignored
+        let locale = AcceptLanguage::parse(req).ok().and_then(|accept_language| {
+            accept_language
+                .ranked()
+                .into_iter()
+                .find_map(|preference| match preference {
+                    Preference::Specific(tag) => Locale::from_language_tag(tag.as_str()),
+                    Preference::Any => None,
+                })
+        });
+
+        Ok(locale)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use actix_web::test::TestRequest;
+
+    #[test]
+    fn test() {
+        let req = TestRequest::get()
+            .uri("/articles/list?lang=es")
+            .insert_header(("Accept-Language", "en-US,en;q=0.9"))
+            .to_http_request();
+
+        assert_eq!(Some(Locale::Es), ReqLocale::extract_locale(&req).unwrap());
+
+        let req = TestRequest::get()
+            .uri("/articles/list")
+            .insert_header(("Accept-Language", "fr-FR;q=0.9, en;q=0.5, es;q=0.7"))
+            .to_http_request();
+
+        assert_eq!(Some(Locale::Es), ReqLocale::extract_locale(&req).unwrap());
+
+        let req = TestRequest::get().uri("/articles/list").to_http_request();
+
+        assert_eq!(None, ReqLocale::extract_locale(&req).unwrap());
+
+        let req = TestRequest::get()
+            .uri("/articles/list?lang=fr")
+            .to_http_request();
+
+        assert!(ReqLocale::extract_locale(&req).is_err());
+    }
+}
@@ -1,3 +1,4 @@
+pub mod cache_validators;
 pub mod controllers;
 pub mod dtos;
 pub mod extractors;
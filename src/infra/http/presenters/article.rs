@@ -3,7 +3,14 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_cover::ArticleCoverUrls;
+use crate::domain::domain_entities::reaction::ReactionSummary;
+use crate::domain::services::journalism::articles::translate_articles_service::TranslatedArticle;
+use crate::infra::http::presenters::article_cover::{
+    ArticleCoverPresenter, MappedArticleCoverUrls,
+};
 use crate::infra::http::presenters::presenter::PresenterTrait;
+use crate::infra::http::presenters::reaction::{MappedReactions, ReactionPresenter};
 
 #[derive(Serialize, Deserialize)]
 pub struct MappedArticle {
@@ -12,6 +19,8 @@ pub struct MappedArticle {
     author_id: Uuid,
     #[serde(rename = "coverUrl")]
     cover_url: String,
+    #[serde(rename = "coverVariants")]
+    cover_variants: MappedArticleCoverUrls,
     title: String,
     content: String,
     approved: bool,
@@ -20,22 +29,69 @@ pub struct MappedArticle {
     #[serde(rename = "updatedAt")]
     updated_at: Option<DateTime>,
     slug: String,
+    featured: bool,
+    pinned: bool,
+    #[serde(rename = "pinOrder")]
+    pin_order: i32,
+    #[serde(rename = "pinnedUntil")]
+    pinned_until: Option<DateTime>,
+    #[serde(rename = "commentMode")]
+    comment_mode: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedTranslatedArticle {
+    #[serde(flatten)]
+    article: MappedArticle,
+    locale: String,
+    #[serde(rename = "missingLocales", skip_serializing_if = "Option::is_none")]
+    missing_locales: Option<Vec<String>>,
+    reactions: MappedReactions,
 }
 
 pub struct ArticlePresenter;
 
-impl PresenterTrait<Article, MappedArticle> for ArticlePresenter {
-    fn to_http(article: Article) -> MappedArticle {
+impl ArticlePresenter {
+    /// The locales an article hasn't been translated to yet are only meant for the staff.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn translated_to_http(
+        translated_article: TranslatedArticle,
+        cover: ArticleCoverUrls,
+        with_missing_locales: bool,
+        reactions: ReactionSummary,
+    ) -> MappedTranslatedArticle {
+        MappedTranslatedArticle {
+            article: Self::to_http(translated_article.article, cover),
+            locale: translated_article.locale.to_string(),
+            missing_locales: with_missing_locales.then(|| {
+                translated_article
+                    .missing_locales
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect()
+            }),
+            reactions: ReactionPresenter::to_http(reactions),
+        }
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_http(article: Article, cover: ArticleCoverUrls) -> MappedArticle {
         MappedArticle {
             id: article.id(),
             author_id: article.author_id(),
             title: article.title().into(),
             cover_url: article.cover_url().into(),
+            cover_variants: ArticleCoverPresenter::to_http(cover),
             content: article.content().into(),
             slug: article.slug().to_string(),
             approved: article.approved(),
             created_at: article.created_at(),
             updated_at: article.updated_at(),
+            featured: article.featured(),
+            pinned: article.pinned(),
+            pin_order: article.pin_order(),
+            pinned_until: article.pinned_until(),
+            comment_mode: article.comment_mode().to_string(),
         }
     }
 }
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::domain_entities::article_cover::ArticleCoverUrls;
+use crate::infra::http::presenters::presenter::PresenterTrait;
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedArticleCoverUrls {
+    original: String,
+    card: String,
+    hero: String,
+}
+
+pub struct ArticleCoverPresenter;
+
+impl PresenterTrait<ArticleCoverUrls, MappedArticleCoverUrls> for ArticleCoverPresenter {
+    fn to_http(urls: ArticleCoverUrls) -> MappedArticleCoverUrls {
+        MappedArticleCoverUrls {
+            original: urls.original,
+            card: urls.card,
+            hero: urls.hero,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use chrono::NaiveDateTime as DateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article_translation::ArticleTranslation;
+use crate::infra::http::presenters::presenter::PresenterTrait;
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedArticleTranslation {
+    #[serde(rename = "articleId")]
+    article_id: Uuid,
+    locale: String,
+    title: String,
+    content: String,
+    slug: String,
+    #[serde(rename = "createdAt")]
+    created_at: DateTime,
+    #[serde(rename = "updatedAt")]
+    updated_at: Option<DateTime>,
+}
+
+pub struct ArticleTranslationPresenter;
+
+impl PresenterTrait<ArticleTranslation, MappedArticleTranslation> for ArticleTranslationPresenter {
+    fn to_http(translation: ArticleTranslation) -> MappedArticleTranslation {
+        MappedArticleTranslation {
+            article_id: translation.article_id(),
+            locale: translation.locale().to_string(),
+            title: translation.title().into(),
+            content: translation.content().into(),
+            slug: translation.slug().to_string(),
+            created_at: translation.created_at(),
+            updated_at: translation.updated_at(),
+        }
+    }
+}
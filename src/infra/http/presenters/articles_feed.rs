@@ -0,0 +1,203 @@
+use std::fmt::Write;
+
+use super::xml::{escape, push_element};
+use crate::domain::services::journalism::articles::fetch_articles_feed_service::{
+    ArticlesFeed, ArticlesFeedEntry,
+};
+use crate::libs::time::TimeHelper;
+use crate::util::SiteUrl;
+
+const FEED_LANGUAGE: &str = "pt-BR";
+
+/// Renders articles feeds as RSS 2.0 and Atom documents. `self_url` must be the feed's own absolute URL.
+pub struct ArticlesFeedPresenter;
+
+impl ArticlesFeedPresenter {
+    pub fn to_rss(feed: &ArticlesFeed, self_url: &str) -> String {
+        let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+
+        xml.push_str(r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom" xmlns:dc="http://purl.org/dc/elements/1.1/">"#);
+        xml.push_str("<channel>");
+        push_element(&mut xml, "title", &Self::title(feed));
+        push_element(&mut xml, "link", &Self::alternate_url(feed));
+        push_element(&mut xml, "description", &Self::description(feed));
+        push_element(&mut xml, "language", FEED_LANGUAGE);
+        let _ = write!(
+            xml,
+            r#"<atom:link href="{}" rel="self" type="application/rss+xml"/>"#,
+            escape(self_url)
+        );
+
+        if let Some(last_modified) = feed.last_modified {
+            push_element(
+                &mut xml,
+                "lastBuildDate",
+                &TimeHelper::with_offset(last_modified).to_rfc2822(),
+            );
+        }
+
+        for ArticlesFeedEntry { article, author } in feed.entries.iter() {
+            let article_url = SiteUrl::article(&article.slug().to_string());
+
+            xml.push_str("<item>");
+            push_element(&mut xml, "title", article.title());
+            push_element(&mut xml, "link", &article_url);
+            let _ = write!(
+                xml,
+                r#"<guid isPermaLink="true">{}</guid>"#,
+                escape(&article_url)
+            );
+            push_element(&mut xml, "dc:creator", author.nickname());
+
+            if let Some(tag) = article.tag_value() {
+                push_element(&mut xml, "category", &tag);
+            }
+
+            push_element(
+                &mut xml,
+                "pubDate",
+                &TimeHelper::with_offset(article.created_at()).to_rfc2822(),
+            );
+            push_element(&mut xml, "description", article.content());
+            // the enclosure's length is required, but we can't know it without downloading the image
+            let _ = write!(
+                xml,
+                r#"<enclosure url="{}" type="{}" length="0"/>"#,
+                escape(article.cover_url()),
+                image_mime_type(article.cover_url())
+            );
+            xml.push_str("</item>");
+        }
+
+        xml.push_str("</channel></rss>");
+
+        xml
+    }
+
+    pub fn to_atom(feed: &ArticlesFeed, self_url: &str) -> String {
+        let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+
+        let _ = write!(
+            xml,
+            r#"<feed xmlns="http://www.w3.org/2005/Atom" xml:lang="{FEED_LANGUAGE}">"#
+        );
+        push_element(&mut xml, "title", &Self::title(feed));
+        push_element(&mut xml, "subtitle", &Self::description(feed));
+        push_element(&mut xml, "id", self_url);
+        let _ = write!(
+            xml,
+            r#"<link rel="self" type="application/atom+xml" href="{}"/><link rel="alternate" type="text/html" href="{}"/>"#,
+            escape(self_url),
+            escape(&Self::alternate_url(feed))
+        );
+        push_element(
+            &mut xml,
+            "updated",
+            &TimeHelper::with_offset(feed.last_modified.unwrap_or(TimeHelper::now())).to_rfc3339(),
+        );
+
+        for ArticlesFeedEntry { article, author } in feed.entries.iter() {
+            xml.push_str("<entry>");
+            push_element(&mut xml, "title", article.title());
+            push_element(&mut xml, "id", &format!("urn:uuid:{}", article.id()));
+            let _ = write!(
+                xml,
+                r#"<link rel="alternate" type="text/html" href="{}"/><link rel="enclosure" type="{}" href="{}"/>"#,
+                escape(&SiteUrl::article(&article.slug().to_string())),
+                image_mime_type(article.cover_url()),
+                escape(article.cover_url())
+            );
+            xml.push_str("<author>");
+            push_element(&mut xml, "name", author.nickname());
+            push_element(&mut xml, "uri", &SiteUrl::user(author.nickname()));
+            xml.push_str("</author>");
+
+            if let Some(tag) = article.tag_value() {
+                let _ = write!(xml, r#"<category term="{}"/>"#, escape(&tag));
+            }
+
+            push_element(
+                &mut xml,
+                "published",
+                &TimeHelper::with_offset(article.created_at()).to_rfc3339(),
+            );
+            push_element(
+                &mut xml,
+                "updated",
+                &TimeHelper::with_offset(article.updated_at().unwrap_or(article.created_at()))
+                    .to_rfc3339(),
+            );
+            let _ = write!(
+                xml,
+                r#"<content type="html">{}</content>"#,
+                escape(article.content())
+            );
+            xml.push_str("</entry>");
+        }
+
+        xml.push_str("</feed>");
+
+        xml
+    }
+
+    fn title(feed: &ArticlesFeed) -> String {
+        let domain = SiteUrl::base();
+        let domain = domain
+            .trim_start_matches("https://")
+            .trim_start_matches("http://");
+
+        if let Some(tag) = &feed.tag {
+            return format!("{} - Notícias de {domain}", tag.value());
+        }
+
+        if let Some(author) = &feed.author {
+            return format!("{} - Notícias de {domain}", author.nickname());
+        }
+
+        format!("Notícias de {domain}")
+    }
+
+    fn description(feed: &ArticlesFeed) -> String {
+        if let Some(tag) = &feed.tag {
+            return format!("As notícias mais recentes com a tag {}.", tag.value());
+        }
+
+        if let Some(author) = &feed.author {
+            return format!(
+                "As notícias mais recentes escritas por {}.",
+                author.nickname()
+            );
+        }
+
+        "As notícias mais recentes.".into()
+    }
+
+    fn alternate_url(feed: &ArticlesFeed) -> String {
+        if let Some(tag) = &feed.tag {
+            return SiteUrl::tag(tag.id());
+        }
+
+        if let Some(author) = &feed.author {
+            return SiteUrl::user(author.nickname());
+        }
+
+        SiteUrl::base()
+    }
+}
+
+fn image_mime_type(url: &str) -> &'static str {
+    let path = url
+        .split(['?', '#'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+
+    match path.rsplit('.').next() {
+        Some("png") => "image/png",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("avif") => "image/avif",
+        Some("svg") => "image/svg+xml",
+        _ => "image/jpeg",
+    }
+}
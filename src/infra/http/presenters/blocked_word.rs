@@ -0,0 +1,37 @@
+use chrono::NaiveDateTime as DateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::domain_entities::blocked_word::BlockedWord;
+use crate::infra::http::presenters::presenter::PresenterTrait;
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedBlockedWord {
+    id: Uuid,
+    pattern: String,
+    #[serde(rename = "isRegex")]
+    is_regex: bool,
+    action: String,
+    #[serde(rename = "authorId")]
+    author_id: Uuid,
+    #[serde(rename = "createdAt")]
+    created_at: DateTime,
+    #[serde(rename = "updatedAt")]
+    updated_at: Option<DateTime>,
+}
+
+pub struct BlockedWordPresenter;
+
+impl PresenterTrait<BlockedWord, MappedBlockedWord> for BlockedWordPresenter {
+    fn to_http(blocked_word: BlockedWord) -> MappedBlockedWord {
+        MappedBlockedWord {
+            id: blocked_word.id(),
+            pattern: blocked_word.pattern().into(),
+            is_regex: blocked_word.is_regex(),
+            action: blocked_word.action().code().into(),
+            author_id: blocked_word.author_id(),
+            created_at: blocked_word.created_at(),
+            updated_at: blocked_word.updated_at(),
+        }
+    }
+}
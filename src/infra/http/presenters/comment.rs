@@ -1,6 +1,10 @@
 use crate::domain::domain_entities::comment::Comment;
 use crate::domain::domain_entities::{comment_with_author::CommentWithAuthor, role::Role};
+use crate::infra::http::presenters::comment_mention::{
+    CommentMentionPresenter, MappedCommentMention,
+};
 use crate::infra::http::presenters::presenter::PresenterTrait;
+use crate::infra::http::presenters::reaction::{MappedReactions, ReactionPresenter};
 use chrono::NaiveDateTime as DateTime;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -18,6 +22,15 @@ pub struct MappedComment {
     #[serde(rename = "createdAt")]
     created_at: DateTime,
     author: MappedCommentAuthor,
+    #[serde(rename = "parentId")]
+    parent_id: Option<Uuid>,
+    edited: bool,
+    #[serde(rename = "editedAt")]
+    edited_at: Option<DateTime>,
+    #[serde(rename = "repliesCount")]
+    replies_count: u64,
+    mentions: Vec<MappedCommentMention>,
+    reactions: MappedReactions,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -29,6 +42,10 @@ pub struct MappedRawComment {
     #[serde(rename = "isActive")]
     is_active: bool,
     author_id: Uuid,
+    #[serde(rename = "parentId")]
+    parent_id: Option<Uuid>,
+    #[serde(rename = "updatedAt")]
+    updated_at: Option<DateTime>,
 }
 
 pub struct CommentPresenter;
@@ -44,6 +61,17 @@ impl PresenterTrait<CommentWithAuthor, MappedComment> for CommentPresenter {
                 nickname: author.nickname().to_owned(),
                 role: author.role().unwrap(),
             },
+            parent_id: comment.parent_id(),
+            edited: comment.updated_at().is_some(),
+            edited_at: comment.updated_at(),
+            replies_count: comment.replies_count(),
+            mentions: comment
+                .mentions()
+                .iter()
+                .cloned()
+                .map(CommentMentionPresenter::to_http)
+                .collect(),
+            reactions: ReactionPresenter::to_http(comment.reactions().clone()),
         }
     }
 }
@@ -57,6 +85,8 @@ impl CommentPresenter {
             content: comment.content().to_owned(),
             is_active: comment.is_active(),
             created_at: comment.created_at(),
+            parent_id: comment.parent_id(),
+            updated_at: comment.updated_at(),
         }
     }
 }
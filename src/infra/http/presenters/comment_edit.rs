@@ -0,0 +1,33 @@
+use chrono::NaiveDateTime as DateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::domain_entities::comment_edit::CommentEdit;
+use crate::infra::http::presenters::presenter::PresenterTrait;
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedCommentEdit {
+    id: Uuid,
+    #[serde(rename = "commentId")]
+    comment_id: Uuid,
+    #[serde(rename = "editorId")]
+    editor_id: Uuid,
+    #[serde(rename = "previousContent")]
+    previous_content: String,
+    #[serde(rename = "createdAt")]
+    created_at: DateTime,
+}
+
+pub struct CommentEditPresenter;
+
+impl PresenterTrait<CommentEdit, MappedCommentEdit> for CommentEditPresenter {
+    fn to_http(comment_edit: CommentEdit) -> MappedCommentEdit {
+        MappedCommentEdit {
+            id: comment_edit.id(),
+            comment_id: comment_edit.comment_id(),
+            editor_id: comment_edit.editor_id(),
+            previous_content: comment_edit.previous_content().into(),
+            created_at: comment_edit.created_at(),
+        }
+    }
+}
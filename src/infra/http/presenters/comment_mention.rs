@@ -0,0 +1,34 @@
+use chrono::NaiveDateTime as DateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::domain_entities::comment_mention::CommentMention;
+use crate::infra::http::presenters::presenter::PresenterTrait;
+
+/// `start` and `end` delimit the "@nickname" in the comment's content, in characters, so that it can be linked to the
+/// mentioned user's profile.
+#[derive(Serialize, Deserialize)]
+pub struct MappedCommentMention {
+    #[serde(rename = "commentId")]
+    comment_id: Uuid,
+    #[serde(rename = "userId")]
+    user_id: Uuid,
+    start: u32,
+    end: u32,
+    #[serde(rename = "createdAt")]
+    created_at: DateTime,
+}
+
+pub struct CommentMentionPresenter;
+
+impl PresenterTrait<CommentMention, MappedCommentMention> for CommentMentionPresenter {
+    fn to_http(mention: CommentMention) -> MappedCommentMention {
+        MappedCommentMention {
+            comment_id: mention.comment_id(),
+            user_id: mention.user_id(),
+            start: mention.start(),
+            end: mention.end(),
+            created_at: mention.created_at(),
+        }
+    }
+}
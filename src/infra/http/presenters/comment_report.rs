@@ -16,8 +16,9 @@ struct MappedCommentAuthor {
 #[derive(Serialize, Deserialize)]
 pub struct MappedCommentReport {
     id: i32,
+    /// Null once the comment has been deleted.
     #[serde(rename = "commentId")]
-    comment_id: Uuid,
+    comment_id: Option<Uuid>,
     #[serde(rename = "userId")]
     user_id: Uuid,
     message: String,
@@ -25,6 +26,15 @@ pub struct MappedCommentReport {
     solved_by: Option<Uuid>,
     #[serde(rename = "createdAt")]
     created_at: DateTime,
+    #[serde(rename = "commentAutoHidden")]
+    comment_auto_hidden: bool,
+    reason: String,
+    priority: String,
+    #[serde(rename = "assignedTo")]
+    assigned_to: Option<Uuid>,
+    resolution: Option<String>,
+    #[serde(rename = "resolutionNotes")]
+    resolution_notes: Option<String>,
 }
 
 pub struct CommentReportPresenter;
@@ -38,6 +48,12 @@ impl PresenterTrait<CommentReport, MappedCommentReport> for CommentReportPresent
             comment_id: report.comment_id(),
             message: report.message(),
             created_at: report.created_at(),
+            comment_auto_hidden: report.comment_auto_hidden(),
+            reason: report.reason().to_string(),
+            priority: report.priority().to_string(),
+            assigned_to: report.assigned_to(),
+            resolution: report.resolution().map(|resolution| resolution.to_string()),
+            resolution_notes: report.resolution_notes().map(str::to_owned),
         }
     }
 }
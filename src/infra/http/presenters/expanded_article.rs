@@ -1,13 +1,24 @@
 use super::{
+    article::{ArticlePresenter, MappedArticle},
+    article_cover::{ArticleCoverPresenter, MappedArticleCoverUrls},
     comment::{CommentPresenter, MappedComment},
     pagination::{MappedPagination, PaginationPresenter},
+    reaction::{MappedReactions, ReactionPresenter},
     user::{MappedUser, UserPresenter},
 };
+use crate::domain::services::journalism::articles::get_expanded_article_service::ArticleSeriesNavigation;
 use crate::infra::http::presenters::presenter::PresenterTrait;
 use crate::{
     core::pagination::PaginationResponse,
     domain::domain_entities::{
-        article::Article, comment_with_author::CommentWithAuthor, user::User,
+        article::{Article, CommentMode},
+        article_coauthor::ArticleCoauthor,
+        article_cover::ArticleCoverUrls,
+        comment_with_author::CommentWithAuthor,
+        locale::Locale,
+        reaction::ReactionSummary,
+        slug::Slug,
+        user::User,
     },
 };
 use chrono::NaiveDateTime as DateTime;
@@ -19,6 +30,8 @@ pub struct MappedExpandedArticle {
     id: Uuid,
     #[serde(rename = "coverUrl")]
     cover_url: String,
+    #[serde(rename = "coverVariants")]
+    cover_variants: MappedArticleCoverUrls,
     title: String,
     content: String,
     approved: bool,
@@ -27,10 +40,70 @@ pub struct MappedExpandedArticle {
     #[serde(rename = "updatedAt")]
     updated_at: Option<DateTime>,
     slug: String,
+    featured: bool,
+
+    reactions: MappedReactions,
 
     author: MappedUser,
 
+    coauthors: Vec<MappedArticleCoauthor>,
+
     comments: MappedExpandedArticleComments,
+
+    series: Option<MappedArticleSeriesNavigation>,
+
+    #[serde(rename = "relatedArticles")]
+    related_articles: Option<Vec<MappedArticle>>,
+
+    locale: String,
+
+    alternates: Vec<MappedArticleAlternate>,
+
+    #[serde(rename = "commentMode")]
+    comment_mode: String,
+
+    /// Whether the comment box should be shown to the user.
+    #[serde(rename = "canComment")]
+    can_comment: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MappedArticleAlternate {
+    locale: String,
+    slug: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MappedArticleCoauthor {
+    contribution: String,
+    #[serde(flatten)]
+    user: MappedUser,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MappedArticleSeriesNavigation {
+    id: Uuid,
+    title: String,
+    slug: String,
+    position: usize,
+    total: usize,
+    previous: Option<MappedSeriesArticleLink>,
+    next: Option<MappedSeriesArticleLink>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MappedSeriesArticleLink {
+    title: String,
+    slug: String,
+}
+
+impl From<Article> for MappedSeriesArticleLink {
+    fn from(article: Article) -> Self {
+        MappedSeriesArticleLink {
+            title: article.title().into(),
+            slug: article.slug().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -39,35 +112,108 @@ struct MappedExpandedArticleComments {
     pagination: MappedPagination,
 }
 
+/// Everything an expanded article is shown with.
+pub struct ExpandedArticleView {
+    pub article: Article,
+    pub cover: ArticleCoverUrls,
+    pub reactions: ReactionSummary,
+    pub author: User,
+    pub coauthors: Vec<(ArticleCoauthor, User)>,
+    pub comments: Vec<CommentWithAuthor>,
+    pub comments_pagination: PaginationResponse,
+    pub comments_per_page: u8,
+    pub series: Option<ArticleSeriesNavigation>,
+    pub related_articles: Option<Vec<(Article, ArticleCoverUrls)>>,
+    pub locale: Locale,
+    pub alternates: Vec<(Locale, Slug)>,
+    pub comment_mode: CommentMode,
+    pub can_comment: bool,
+}
+
 pub struct ExpandedArticlePresenter;
 
 impl ExpandedArticlePresenter {
     #[allow(clippy::wrong_self_convention)]
-    pub fn to_http(
-        article: Article,
-        author: User,
-        comments: Vec<CommentWithAuthor>,
-        pagination: (PaginationResponse, u8),
-    ) -> MappedExpandedArticle {
+    pub fn to_http(view: ExpandedArticleView) -> MappedExpandedArticle {
+        let ExpandedArticleView {
+            article,
+            cover,
+            reactions,
+            author,
+            coauthors,
+            comments,
+            comments_pagination,
+            comments_per_page,
+            series,
+            related_articles,
+            locale,
+            alternates,
+            comment_mode,
+            can_comment,
+        } = view;
+
         MappedExpandedArticle {
             id: article.id(),
             title: article.title().into(),
             cover_url: article.cover_url().into(),
+            cover_variants: ArticleCoverPresenter::to_http(cover),
             content: article.content().into(),
             slug: article.slug().to_string(),
             approved: article.approved(),
             created_at: article.created_at(),
             updated_at: article.updated_at(),
+            featured: article.featured(),
+
+            reactions: ReactionPresenter::to_http(reactions),
 
             author: UserPresenter::to_http(author),
 
+            coauthors: coauthors
+                .into_iter()
+                .map(|(coauthor, user)| MappedArticleCoauthor {
+                    contribution: coauthor.contribution().into(),
+                    user: UserPresenter::to_http(user),
+                })
+                .collect(),
+
             comments: MappedExpandedArticleComments {
                 data: comments
                     .into_iter()
                     .map(CommentPresenter::to_http)
                     .collect(),
-                pagination: PaginationPresenter::to_http(pagination.0, pagination.1),
+                pagination: PaginationPresenter::to_http(comments_pagination, comments_per_page),
             },
+
+            series: series.map(|navigation| MappedArticleSeriesNavigation {
+                id: navigation.series.id(),
+                title: navigation.series.title().into(),
+                slug: navigation.series.slug().to_string(),
+                position: navigation.position,
+                total: navigation.total,
+                previous: navigation.previous.map(MappedSeriesArticleLink::from),
+                next: navigation.next.map(MappedSeriesArticleLink::from),
+            }),
+
+            related_articles: related_articles.map(|articles| {
+                articles
+                    .into_iter()
+                    .map(|(article, cover)| ArticlePresenter::to_http(article, cover))
+                    .collect()
+            }),
+
+            locale: locale.to_string(),
+
+            alternates: alternates
+                .into_iter()
+                .map(|(locale, slug)| MappedArticleAlternate {
+                    locale: locale.to_string(),
+                    slug: slug.to_string(),
+                })
+                .collect(),
+
+            comment_mode: comment_mode.to_string(),
+
+            can_comment,
         }
     }
 }
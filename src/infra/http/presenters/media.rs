@@ -0,0 +1,82 @@
+use chrono::NaiveDateTime as DateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::domain_entities::media::{Media, MediaUsage};
+use crate::domain::storage::file_storage::FileStorageTrait;
+use crate::infra::http::presenters::presenter::PresenterTrait;
+use crate::infra::storage::local_file_storage::LocalFileStorage;
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedMedia {
+    id: Uuid,
+    #[serde(rename = "uploaderId")]
+    uploader_id: Uuid,
+    url: String,
+    #[serde(rename = "fileName")]
+    file_name: String,
+    #[serde(rename = "mimeType")]
+    mime_type: String,
+    size: u64,
+    width: u32,
+    height: u32,
+    #[serde(rename = "altText")]
+    alt_text: String,
+    tags: Vec<String>,
+    #[serde(rename = "createdAt")]
+    created_at: DateTime,
+    #[serde(rename = "updatedAt")]
+    updated_at: Option<DateTime>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedMediaWithUsages {
+    #[serde(flatten)]
+    media: MappedMedia,
+    usages: Vec<MappedMediaUsage>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum MappedMediaUsage {
+    Article { id: Uuid, title: String },
+    Announcement { id: Uuid },
+    FreeBadge { id: Uuid, code: String },
+}
+
+pub struct MediaPresenter;
+
+impl PresenterTrait<Media, MappedMedia> for MediaPresenter {
+    fn to_http(media: Media) -> MappedMedia {
+        MappedMedia {
+            id: media.id(),
+            uploader_id: media.uploader_id(),
+            url: LocalFileStorage::new().url(media.storage_key()),
+            file_name: media.file_name().into(),
+            mime_type: media.mime_type().into(),
+            size: media.size(),
+            width: media.width(),
+            height: media.height(),
+            alt_text: media.alt_text().into(),
+            tags: media.tags().to_vec(),
+            created_at: media.created_at(),
+            updated_at: media.updated_at(),
+        }
+    }
+}
+
+impl MediaPresenter {
+    pub fn to_http_with_usages(media: Media, usages: Vec<MediaUsage>) -> MappedMediaWithUsages {
+        MappedMediaWithUsages {
+            media: Self::to_http(media),
+            usages: usages
+                .into_iter()
+                .map(|usage| match usage {
+                    MediaUsage::Article { id, title } => MappedMediaUsage::Article { id, title },
+                    MediaUsage::Announcement { id } => MappedMediaUsage::Announcement { id },
+                    MediaUsage::FreeBadge { id, code } => MappedMediaUsage::FreeBadge { id, code },
+                })
+                .collect(),
+        }
+    }
+}
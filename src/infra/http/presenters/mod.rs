@@ -1,12 +1,25 @@
 pub mod announcement;
 pub mod article;
+pub mod article_cover;
 pub mod article_tag;
+pub mod article_translation;
+pub mod articles_feed;
+pub mod blocked_word;
 pub mod comment;
+pub mod comment_edit;
+pub mod comment_mention;
 pub mod comment_report;
 pub mod expanded_article;
 pub mod free_badge;
+pub mod media;
 pub mod pagination;
+pub mod personal_data_export;
 pub mod presenter;
+pub mod reaction;
+pub mod series;
+pub mod sitemap;
 pub mod team_role;
 pub mod team_user;
 pub mod user;
+pub mod user_profile;
+pub mod xml;
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::core::pagination::PaginationResponse;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::core::pagination::{CursorPaginationResponse, PaginationResponse};
 
 #[derive(Serialize, Deserialize)]
 pub struct MappedPagination {
@@ -14,6 +17,16 @@ pub struct MappedPagination {
     items_per_page: u8,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct MappedCursorPagination {
+    #[serde(rename = "nextCursor")]
+    next_cursor: Option<String>,
+    #[serde(rename = "prevCursor")]
+    prev_cursor: Option<String>,
+    #[serde(rename = "itemsPerPage")]
+    items_per_page: u8,
+}
+
 pub struct PaginationPresenter;
 
 impl PaginationPresenter {
@@ -26,4 +39,16 @@ impl PaginationPresenter {
             items_per_page: per_page,
         }
     }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn cursor_to_http<ID: Display + FromStr>(
+        pagination_details: CursorPaginationResponse<ID>,
+        per_page: u8,
+    ) -> MappedCursorPagination {
+        MappedCursorPagination {
+            next_cursor: pagination_details.next_cursor.map(|cursor| cursor.encode()),
+            prev_cursor: pagination_details.prev_cursor.map(|cursor| cursor.encode()),
+            items_per_page: per_page,
+        }
+    }
 }
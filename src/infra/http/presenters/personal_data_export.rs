@@ -0,0 +1,58 @@
+use chrono::NaiveDateTime as DateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::domain_entities::article_cover::ArticleCoverUrls;
+use crate::domain::services::identity::export_personal_data_service::PersonalDataExport;
+use crate::infra::http::presenters::article::{ArticlePresenter, MappedArticle};
+use crate::infra::http::presenters::comment::{CommentPresenter, MappedRawComment};
+use crate::infra::http::presenters::comment_report::{CommentReportPresenter, MappedCommentReport};
+use crate::infra::http::presenters::presenter::PresenterTrait;
+use crate::infra::http::presenters::user::{MappedUser, UserPresenter};
+use crate::libs::time::TimeHelper;
+
+/// Unlike the other presenters, this one carries the user's private data (e.g. their last login), as it's meant
+/// for the user alone.
+#[derive(Serialize, Deserialize)]
+pub struct MappedPersonalDataExport {
+    #[serde(rename = "exportedAt")]
+    exported_at: DateTime,
+    user: MappedUser,
+    #[serde(rename = "lastLogin")]
+    last_login: Option<DateTime>,
+    comments: Vec<MappedRawComment>,
+    reports: Vec<MappedCommentReport>,
+    articles: Vec<MappedArticle>,
+}
+
+pub struct PersonalDataExportPresenter;
+
+impl PersonalDataExportPresenter {
+    /// The covers are the ones of each of the exported articles, in the same order.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_http(
+        export: PersonalDataExport,
+        article_covers: Vec<ArticleCoverUrls>,
+    ) -> MappedPersonalDataExport {
+        MappedPersonalDataExport {
+            exported_at: TimeHelper::now(),
+            last_login: export.user.last_login(),
+            user: UserPresenter::to_http(export.user),
+            comments: export
+                .comments
+                .into_iter()
+                .map(CommentPresenter::to_http_raw)
+                .collect(),
+            reports: export
+                .reports
+                .into_iter()
+                .map(CommentReportPresenter::to_http)
+                .collect(),
+            articles: export
+                .articles
+                .into_iter()
+                .zip(article_covers)
+                .map(|(article, cover)| ArticlePresenter::to_http(article, cover))
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::domain_entities::reaction::ReactionSummary;
+use crate::infra::http::presenters::presenter::PresenterTrait;
+
+/// `mine` is the reaction of the authenticated user, if any.
+#[derive(Serialize, Deserialize)]
+pub struct MappedReactions {
+    counts: BTreeMap<String, u64>,
+    mine: Option<String>,
+}
+
+pub struct ReactionPresenter;
+
+impl PresenterTrait<ReactionSummary, MappedReactions> for ReactionPresenter {
+    fn to_http(summary: ReactionSummary) -> MappedReactions {
+        MappedReactions {
+            counts: summary.counts,
+            mine: summary.own,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use chrono::NaiveDateTime as DateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_cover::ArticleCoverUrls;
+use crate::domain::domain_entities::series::Series;
+use crate::infra::http::presenters::article::{ArticlePresenter, MappedArticle};
+use crate::infra::http::presenters::presenter::PresenterTrait;
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedSeries {
+    id: Uuid,
+    #[serde(rename = "authorId")]
+    author_id: Uuid,
+    title: String,
+    description: String,
+    slug: String,
+    #[serde(rename = "createdAt")]
+    created_at: DateTime,
+    #[serde(rename = "updatedAt")]
+    updated_at: Option<DateTime>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedSeriesWithArticles {
+    #[serde(flatten)]
+    series: MappedSeries,
+    articles: Vec<MappedArticle>,
+}
+
+pub struct SeriesPresenter;
+
+impl PresenterTrait<Series, MappedSeries> for SeriesPresenter {
+    fn to_http(series: Series) -> MappedSeries {
+        MappedSeries {
+            id: series.id(),
+            author_id: series.author_id(),
+            title: series.title().into(),
+            description: series.description().into(),
+            slug: series.slug().to_string(),
+            created_at: series.created_at(),
+            updated_at: series.updated_at(),
+        }
+    }
+}
+
+impl SeriesPresenter {
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_http_with_articles(
+        series: Series,
+        articles: Vec<(Article, ArticleCoverUrls)>,
+    ) -> MappedSeriesWithArticles {
+        MappedSeriesWithArticles {
+            series: Self::to_http(series),
+            articles: articles
+                .into_iter()
+                .map(|(article, cover)| ArticlePresenter::to_http(article, cover))
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use std::fmt::Write;
+
+use super::xml::{escape, push_element};
+use crate::domain::services::seo::fetch_sitemap_index_service::SitemapIndex;
+use crate::domain::services::seo::fetch_sitemap_service::SitemapEntry;
+use crate::libs::time::TimeHelper;
+use crate::util::SiteUrl;
+
+const SITEMAP_NAMESPACE: &str = "http://www.sitemaps.org/schemas/sitemap/0.9";
+
+/// Renders sitemaps and the sitemap index as described by the sitemaps.org protocol.
+pub struct SitemapPresenter;
+
+impl SitemapPresenter {
+    /// `sitemaps_url` must be the absolute URL under which the sitemaps are served, without a trailing slash.
+    pub fn to_index(index: &SitemapIndex, sitemaps_url: &str) -> String {
+        let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        let _ = write!(xml, r#"<sitemapindex xmlns="{SITEMAP_NAMESPACE}">"#);
+
+        let mut push_sitemap = |name: &str| {
+            xml.push_str("<sitemap>");
+            push_element(&mut xml, "loc", &format!("{sitemaps_url}/{name}.xml"));
+            xml.push_str("</sitemap>");
+        };
+
+        push_sitemap("static");
+
+        for page in 1..=index.articles_sitemaps {
+            push_sitemap(&format!("articles-{page}"));
+        }
+
+        for page in 1..=index.tags_sitemaps {
+            push_sitemap(&format!("tags-{page}"));
+        }
+
+        for page in 1..=index.free_badges_sitemaps {
+            push_sitemap(&format!("free-badges-{page}"));
+        }
+
+        xml.push_str("</sitemapindex>");
+
+        xml
+    }
+
+    pub fn to_urlset(entries: &[SitemapEntry]) -> String {
+        let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        let _ = write!(xml, r#"<urlset xmlns="{SITEMAP_NAMESPACE}">"#);
+
+        for entry in entries {
+            let loc = match entry {
+                SitemapEntry::StaticPage(path) => SiteUrl::absolute(path),
+                SitemapEntry::Article { slug, .. } => SiteUrl::article(&slug.to_string()),
+                SitemapEntry::Tag { id } => SiteUrl::tag(*id),
+                SitemapEntry::FreeBadge { id, .. } => SiteUrl::free_badge(&id.to_string()),
+            };
+
+            xml.push_str("<url>");
+            let _ = write!(xml, "<loc>{}</loc>", escape(&loc));
+
+            if let Some(last_modified) = entry.last_modified() {
+                push_element(
+                    &mut xml,
+                    "lastmod",
+                    &TimeHelper::with_offset(last_modified).to_rfc3339(),
+                );
+            }
+
+            xml.push_str("</url>");
+        }
+
+        xml.push_str("</urlset>");
+
+        xml
+    }
+}
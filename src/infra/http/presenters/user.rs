@@ -4,6 +4,17 @@ use uuid::Uuid;
 
 use crate::domain::domain_entities::{role::Role, user::User};
 use crate::infra::http::presenters::presenter::PresenterTrait;
+
+/// `url` points to the avatar on the Habbo imager, and is missing until the user picks a hotel.
+#[derive(Serialize, Deserialize)]
+pub struct MappedHabboAvatar {
+    hotel: Option<String>,
+    direction: u8,
+    #[serde(rename = "headOnly")]
+    head_only: bool,
+    url: Option<String>,
+}
+
 #[derive(Serialize, Deserialize)]
 #[allow(non_snake_case)]
 pub struct MappedUser {
@@ -11,13 +22,30 @@ pub struct MappedUser {
     nickname: String,
     createdAt: DateTime,
     role: Role,
+    bio: Option<String>,
+    avatar: MappedHabboAvatar,
 }
 
 pub struct UserPresenter;
 
+impl UserPresenter {
+    pub fn avatar_to_http(user: &User) -> MappedHabboAvatar {
+        let avatar = user.avatar();
+
+        MappedHabboAvatar {
+            hotel: avatar.hotel().map(|hotel| hotel.to_string()),
+            direction: avatar.direction(),
+            head_only: avatar.head_only(),
+            url: avatar.imager_url(user.nickname()),
+        }
+    }
+}
+
 impl PresenterTrait<User, MappedUser> for UserPresenter {
     fn to_http(user: User) -> MappedUser {
         MappedUser {
+            avatar: Self::avatar_to_http(&user),
+            bio: user.bio().map(ToOwned::to_owned),
             nickname: user.nickname().into(),
             id: user.id(),
             role: user.role().unwrap(),
@@ -0,0 +1,122 @@
+use chrono::NaiveDateTime as DateTime;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::{article::Article, comment::Comment, role::Role};
+use crate::domain::services::identity::get_user_profile_service::UserProfile;
+use crate::infra::http::presenters::presenter::PresenterTrait;
+use crate::infra::http::presenters::team_user::{MappedTeamUser, TeamUserPresenter};
+use crate::infra::http::presenters::user::{MappedHabboAvatar, UserPresenter};
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedProfileArticle {
+    id: Uuid,
+    title: String,
+    slug: String,
+    #[serde(rename = "coverUrl")]
+    cover_url: String,
+    #[serde(rename = "createdAt")]
+    created_at: DateTime,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedProfileArticles {
+    total: u64,
+    latest: Vec<MappedProfileArticle>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedProfileComment {
+    id: Uuid,
+    content: String,
+    #[serde(rename = "createdAt")]
+    created_at: DateTime,
+    article: MappedProfileArticle,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedProfileComments {
+    total: u64,
+    latest: Vec<MappedProfileComment>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MappedProfileReactions {
+    given: BTreeMap<String, u64>,
+    received: BTreeMap<String, u64>,
+}
+
+/// Only public data goes here, the user's last login for instance must never do.
+#[derive(Serialize, Deserialize)]
+pub struct MappedUserProfile {
+    id: Uuid,
+    nickname: String,
+    role: Role,
+    #[serde(rename = "joinedAt")]
+    created_at: DateTime,
+    bio: Option<String>,
+    avatar: MappedHabboAvatar,
+    /// The user's entry on the team page, if they are on the team.
+    team: Option<MappedTeamUser>,
+    articles: MappedProfileArticles,
+    comments: MappedProfileComments,
+    reactions: MappedProfileReactions,
+}
+
+pub struct UserProfilePresenter;
+
+impl UserProfilePresenter {
+    fn article_to_http(article: Article) -> MappedProfileArticle {
+        MappedProfileArticle {
+            id: article.id(),
+            title: article.title().to_owned(),
+            slug: article.slug().to_string(),
+            cover_url: article.cover_url().to_owned(),
+            created_at: article.created_at(),
+        }
+    }
+
+    fn comment_to_http((comment, article): (Comment, Article)) -> MappedProfileComment {
+        MappedProfileComment {
+            id: comment.id(),
+            content: comment.content().to_owned(),
+            created_at: comment.created_at(),
+            article: Self::article_to_http(article),
+        }
+    }
+}
+
+impl PresenterTrait<UserProfile, MappedUserProfile> for UserProfilePresenter {
+    fn to_http(profile: UserProfile) -> MappedUserProfile {
+        MappedUserProfile {
+            id: profile.user.id(),
+            nickname: profile.user.nickname().to_owned(),
+            role: profile.user.role().unwrap(),
+            created_at: profile.user.created_at(),
+            bio: profile.user.bio().map(ToOwned::to_owned),
+            avatar: UserPresenter::avatar_to_http(&profile.user),
+            team: profile.team_user.map(TeamUserPresenter::to_http),
+            articles: MappedProfileArticles {
+                total: profile.articles_count,
+                latest: profile
+                    .articles
+                    .into_iter()
+                    .map(Self::article_to_http)
+                    .collect(),
+            },
+            comments: MappedProfileComments {
+                total: profile.comments_count,
+                latest: profile
+                    .comments
+                    .into_iter()
+                    .map(Self::comment_to_http)
+                    .collect(),
+            },
+            reactions: MappedProfileReactions {
+                given: profile.reactions_given,
+                received: profile.reactions_received,
+            },
+        }
+    }
+}
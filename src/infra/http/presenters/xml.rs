@@ -0,0 +1,23 @@
+use std::fmt::Write;
+
+/// Writes `<name>text</name>`, escaping the text.
+pub fn push_element(xml: &mut String, name: &str, text: &str) {
+    let _ = write!(xml, "<{name}>{}</{name}>", escape(text));
+}
+
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for char in text.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(char),
+        }
+    }
+
+    escaped
+}
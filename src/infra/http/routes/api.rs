@@ -1,10 +1,14 @@
 use crate::infra::http::controllers::announcements_controller::AnnouncementsController;
 use crate::infra::http::controllers::article_tags_controller::ArticleTagsController;
 use crate::infra::http::controllers::articles_controller::ArticlesController;
+use crate::infra::http::controllers::blocked_words_controller::BlockedWordsController;
 use crate::infra::http::controllers::comment_reports_controller::CommentReportsController;
 use crate::infra::http::controllers::comments_controller::CommentsController;
 use crate::infra::http::controllers::controller::ControllerTrait;
 use crate::infra::http::controllers::free_badges_controller::FreeBadgesController;
+use crate::infra::http::controllers::media_controller::MediaController;
+use crate::infra::http::controllers::reactions_controller::ReactionsController;
+use crate::infra::http::controllers::series_controller::SeriesController;
 use crate::infra::http::controllers::sessions_controller::SessionsController;
 use crate::infra::http::controllers::team_roles_controller::TeamRolesController;
 use crate::infra::http::controllers::team_users_controller::TeamUsersController;
@@ -25,11 +29,15 @@ impl RouteTrait for ApiRoutes {
                 .configure(ArticlesController::register)
                 .configure(CommentsController::register)
                 .configure(CommentReportsController::register)
+                .configure(ReactionsController::register)
+                .configure(BlockedWordsController::register)
                 .configure(TeamRolesController::register)
                 .configure(TeamUsersController::register)
                 .configure(ArticleTagsController::register)
                 .configure(FreeBadgesController::register)
-                .configure(AnnouncementsController::register),
+                .configure(AnnouncementsController::register)
+                .configure(SeriesController::register)
+                .configure(MediaController::register),
         );
     }
 }
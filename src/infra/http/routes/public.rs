@@ -0,0 +1,21 @@
+use crate::infra::http::controllers::controller::ControllerTrait;
+use crate::infra::http::controllers::feeds_controller::FeedsController;
+use crate::infra::http::controllers::sitemaps_controller::SitemapsController;
+use crate::infra::http::routes::route::RouteTrait;
+use crate::infra::storage::local_file_storage::{LocalFileStorage, UPLOADS_ROUTE};
+use actix_web::web;
+
+/// Routes served outside of the `api` scope, meant to be consumed by feed readers and crawlers rather than by the
+/// fan-site's frontend, along with the uploaded files.
+pub struct PublicRoutes;
+
+impl RouteTrait for PublicRoutes {
+    fn register(cfg: &mut web::ServiceConfig) {
+        cfg.configure(FeedsController::register)
+            .configure(SitemapsController::register)
+            .service(actix_files::Files::new(
+                UPLOADS_ROUTE,
+                LocalFileStorage::root(),
+            ));
+    }
+}
@@ -0,0 +1 @@
+pub mod wxr_parser;
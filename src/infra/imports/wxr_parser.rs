@@ -0,0 +1,279 @@
+use chrono::NaiveDateTime;
+use roxmltree::{Document, Node};
+use std::collections::HashMap;
+use std::error::Error;
+
+use crate::domain::domain_entities::wordpress_export::{
+    WordpressAuthor, WordpressCategory, WordpressComment, WordpressExport, WordpressPost,
+};
+use crate::libs::time::TimeHelper;
+
+/// WXR 1.0, 1.1 and 1.2 only differ by the version at the end of this namespace.
+const WP_NAMESPACE_PREFIX: &str = "http://wordpress.org/export/";
+const CONTENT_NAMESPACE: &str = "http://purl.org/rss/1.0/modules/content/";
+const DC_NAMESPACE: &str = "http://purl.org/dc/elements/1.1/";
+
+const WP_DATE_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Reads WordPress eXtended RSS (WXR) files, the format of WordPress' "Tools > Export".
+pub struct WxrParser;
+
+impl WxrParser {
+    pub fn parse(xml: &str) -> Result<WordpressExport, Box<dyn Error>> {
+        let document = Document::parse(xml)?;
+
+        let channel = document
+            .root_element()
+            .children()
+            .find(|node| node.has_tag_name("channel"))
+            .ok_or("The file is not a WordPress export: its channel is missing.")?;
+
+        let mut site_url = wp_text(channel, "base_site_url");
+
+        if site_url.is_empty() {
+            site_url = text(channel, None, "link");
+        }
+
+        let authors = wp_children(channel, "author")
+            .map(|author| WordpressAuthor {
+                id: wp_text(author, "author_id"),
+                login: wp_text(author, "author_login"),
+                display_name: wp_text(author, "author_display_name"),
+            })
+            .collect();
+
+        let categories = wp_children(channel, "category")
+            .map(|category| WordpressCategory {
+                slug: wp_text(category, "category_nicename"),
+                name: wp_text(category, "cat_name"),
+            })
+            .collect();
+
+        let items: Vec<Node> = channel
+            .children()
+            .filter(|node| node.has_tag_name("item"))
+            .collect();
+
+        // featured images are attachments, which posts point to by their ids
+        let attachment_urls: HashMap<String, String> = items
+            .iter()
+            .filter(|item| wp_text(**item, "post_type") == "attachment")
+            .map(|item| (wp_text(*item, "post_id"), wp_text(*item, "attachment_url")))
+            .collect();
+
+        let posts = items
+            .iter()
+            .map(|item| parse_post(*item, &attachment_urls))
+            .collect();
+
+        Ok(WordpressExport {
+            site_url,
+            authors,
+            categories,
+            posts,
+        })
+    }
+}
+
+fn parse_post(item: Node, attachment_urls: &HashMap<String, String>) -> WordpressPost {
+    let cover_url = wp_children(item, "postmeta")
+        .find(|meta| wp_text(*meta, "meta_key") == "_thumbnail_id")
+        .and_then(|meta| attachment_urls.get(&wp_text(meta, "meta_value")))
+        .filter(|url| !url.is_empty())
+        .cloned();
+
+    let category_slugs = item
+        .children()
+        .filter(|node| {
+            node.has_tag_name("category") && node.attribute("domain") == Some("category")
+        })
+        .filter_map(|node| node.attribute("nicename"))
+        .map(str::to_owned)
+        .collect();
+
+    let comments = wp_children(item, "comment")
+        .filter(|comment| {
+            // pingbacks and trackbacks are notifications from other sites, not comments
+            matches!(wp_text(*comment, "comment_type").as_str(), "" | "comment")
+        })
+        .map(|comment| WordpressComment {
+            id: wp_text(comment, "comment_id"),
+            author_name: wp_text(comment, "comment_author"),
+            author_email: wp_text(comment, "comment_author_email"),
+            user_id: wp_text(comment, "comment_user_id"),
+            content: wp_text(comment, "comment_content"),
+            approved: wp_text(comment, "comment_approved"),
+            created_at: wp_date(comment, "comment_date"),
+        })
+        .collect();
+
+    WordpressPost {
+        id: wp_text(item, "post_id"),
+        post_type: wp_text(item, "post_type"),
+        status: wp_text(item, "status"),
+        title: text(item, None, "title"),
+        content: text(item, Some(CONTENT_NAMESPACE), "encoded"),
+        author_login: text(item, Some(DC_NAMESPACE), "creator"),
+        published_at: wp_date(item, "post_date"),
+        modified_at: wp_date(item, "post_modified"),
+        category_slugs,
+        cover_url,
+        comments,
+    }
+}
+
+fn is_wp_element(node: &Node, name: &str) -> bool {
+    node.is_element()
+        && node.tag_name().name() == name
+        && node
+            .tag_name()
+            .namespace()
+            .is_some_and(|namespace| namespace.starts_with(WP_NAMESPACE_PREFIX))
+}
+
+fn wp_children<'a, 'input>(
+    node: Node<'a, 'input>,
+    name: &'a str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children()
+        .filter(move |child| is_wp_element(child, name))
+}
+
+fn wp_text(node: Node, name: &str) -> String {
+    wp_children(node, name)
+        .next()
+        .map(inner_text)
+        .unwrap_or_default()
+}
+
+/// Reads the text of a child element from the given namespace, or without any namespace.
+fn text(node: Node, namespace: Option<&str>, name: &str) -> String {
+    node.children()
+        .find(|child| {
+            child.is_element()
+                && child.tag_name().name() == name
+                && child.tag_name().namespace() == namespace
+        })
+        .map(inner_text)
+        .unwrap_or_default()
+}
+
+fn inner_text(node: Node) -> String {
+    node.children()
+        .filter_map(|child| child.text())
+        .collect::<String>()
+        .trim()
+        .to_owned()
+}
+
+/// Prefers the UTC date, since the site's timezone isn't exported. Drafts have `0000-00-00 00:00:00` dates.
+fn wp_date(node: Node, name: &str) -> Option<NaiveDateTime> {
+    let parse = |value: String| NaiveDateTime::parse_from_str(&value, WP_DATE_FORMAT).ok();
+
+    parse(wp_text(node, &format!("{name}_gmt")))
+        .map(TimeHelper::from_utc)
+        .or_else(|| parse(wp_text(node, name)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXPORT: &str = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<rss version="2.0"
+    xmlns:excerpt="http://wordpress.org/export/1.2/excerpt/"
+    xmlns:content="http://purl.org/rss/1.0/modules/content/"
+    xmlns:dc="http://purl.org/dc/elements/1.1/"
+    xmlns:wp="http://wordpress.org/export/1.2/">
+<channel>
+    <title>Fã-site</title>
+    <link>https://fansite.example</link>
+    <wp:wxr_version>1.2</wp:wxr_version>
+    <wp:base_site_url>https://fansite.example</wp:base_site_url>
+    <wp:author>
+        <wp:author_id>2</wp:author_id>
+        <wp:author_login><![CDATA[joao]]></wp:author_login>
+        <wp:author_display_name><![CDATA[João]]></wp:author_display_name>
+    </wp:author>
+    <wp:category>
+        <wp:term_id>3</wp:term_id>
+        <wp:category_nicename><![CDATA[noticias]]></wp:category_nicename>
+        <wp:cat_name><![CDATA[Notícias]]></wp:cat_name>
+    </wp:category>
+    <item>
+        <title>Capa</title>
+        <wp:post_id>55</wp:post_id>
+        <wp:post_type><![CDATA[attachment]]></wp:post_type>
+        <wp:attachment_url><![CDATA[https://fansite.example/capa.png]]></wp:attachment_url>
+    </item>
+    <item>
+        <title><![CDATA[Novo mobi]]></title>
+        <dc:creator><![CDATA[joao]]></dc:creator>
+        <content:encoded><![CDATA[<p>Olá & bem-vindos</p>]]></content:encoded>
+        <excerpt:encoded><![CDATA[]]></excerpt:encoded>
+        <wp:post_id>10</wp:post_id>
+        <wp:post_date><![CDATA[2021-03-04 07:00:00]]></wp:post_date>
+        <wp:post_date_gmt><![CDATA[2021-03-04 10:00:00]]></wp:post_date_gmt>
+        <wp:post_modified><![CDATA[2021-03-05 07:00:00]]></wp:post_modified>
+        <wp:post_modified_gmt><![CDATA[0000-00-00 00:00:00]]></wp:post_modified_gmt>
+        <wp:status><![CDATA[publish]]></wp:status>
+        <wp:post_type><![CDATA[post]]></wp:post_type>
+        <category domain="post_tag" nicename="mobis"><![CDATA[Mobis]]></category>
+        <category domain="category" nicename="noticias"><![CDATA[Notícias]]></category>
+        <wp:postmeta>
+            <wp:meta_key><![CDATA[_thumbnail_id]]></wp:meta_key>
+            <wp:meta_value><![CDATA[55]]></wp:meta_value>
+        </wp:postmeta>
+        <wp:comment>
+            <wp:comment_id>7</wp:comment_id>
+            <wp:comment_author><![CDATA[Visitante]]></wp:comment_author>
+            <wp:comment_author_email><![CDATA[visitante@example.com]]></wp:comment_author_email>
+            <wp:comment_date><![CDATA[2021-03-04 08:00:00]]></wp:comment_date>
+            <wp:comment_content><![CDATA[Legal!]]></wp:comment_content>
+            <wp:comment_approved><![CDATA[1]]></wp:comment_approved>
+            <wp:comment_type><![CDATA[comment]]></wp:comment_type>
+            <wp:comment_user_id>0</wp:comment_user_id>
+        </wp:comment>
+        <wp:comment>
+            <wp:comment_id>8</wp:comment_id>
+            <wp:comment_type><![CDATA[pingback]]></wp:comment_type>
+        </wp:comment>
+    </item>
+</channel>
+</rss>"#;
+
+    #[test]
+    fn test() {
+        let export = WxrParser::parse(EXPORT).unwrap();
+
+        assert_eq!(export.site_url, "https://fansite.example");
+        assert_eq!(export.authors[0].login, "joao");
+        assert_eq!(export.authors[0].display_name, "João");
+        assert_eq!(export.categories[0].slug, "noticias");
+        assert_eq!(export.categories[0].name, "Notícias");
+        assert_eq!(export.posts.len(), 2);
+
+        let post = &export.posts[1];
+        assert_eq!(post.id, "10");
+        assert_eq!(post.title, "Novo mobi");
+        assert_eq!(post.content, "<p>Olá & bem-vindos</p>");
+        assert_eq!(post.author_login, "joao");
+        assert_eq!(post.status, "publish");
+        assert_eq!(post.category_slugs, vec!["noticias"]);
+        assert_eq!(
+            post.cover_url.as_deref(),
+            Some("https://fansite.example/capa.png")
+        );
+        assert_eq!(
+            post.published_at.unwrap().to_string(),
+            "2021-03-04 07:00:00"
+        );
+        assert_eq!(post.modified_at.unwrap().to_string(), "2021-03-05 07:00:00");
+        assert_eq!(post.comments.len(), 1);
+        assert_eq!(post.comments[0].author_email, "visitante@example.com");
+        assert_eq!(post.comments[0].user_id, "0");
+
+        assert!(WxrParser::parse("<rss></rss>").is_err());
+        assert!(WxrParser::parse("not xml").is_err());
+    }
+}
@@ -1,5 +1,8 @@
+pub mod cache;
 pub mod cryptography;
 mod extensions;
 pub mod http;
+pub mod imports;
 pub mod jwt;
 pub mod sea;
+pub mod storage;
@@ -1,11 +1,21 @@
 pub mod sea_announcement_mapper;
+pub mod sea_article_coauthor_mapper;
 pub mod sea_article_mapper;
 pub mod sea_article_tag_mapper;
+pub mod sea_article_translation_mapper;
+pub mod sea_blocked_word_mapper;
+pub mod sea_comment_edit_mapper;
 pub mod sea_comment_mapper;
+pub mod sea_comment_mention_mapper;
 pub mod sea_comment_report_mapper;
+pub mod sea_comment_review_mapper;
 pub mod sea_comment_with_author_mapper;
 pub mod sea_free_badge_mapper;
+pub mod sea_imported_content_mapper;
+pub mod sea_media_mapper;
+pub mod sea_reaction_mapper;
 pub mod sea_role_mapper;
+pub mod sea_series_mapper;
 pub mod sea_team_role_mapper;
 pub mod sea_team_user_mapper;
 pub mod sea_user_mapper;
@@ -0,0 +1,26 @@
+use entities::article_coauthor::ActiveModel as ArticleCoauthorActiveModel;
+use entities::article_coauthor::Model as ArticleCoauthorModel;
+use sea_orm::IntoActiveValue;
+
+use crate::domain::domain_entities::article_coauthor::ArticleCoauthor;
+
+pub struct SeaArticleCoauthorMapper;
+
+impl SeaArticleCoauthorMapper {
+    pub fn model_into_entity(model: ArticleCoauthorModel) -> ArticleCoauthor {
+        ArticleCoauthor::new(model.article_id, model.user_id, model.contribution)
+    }
+
+    /// Co-authors are listed in the order they were set, which the entity itself doesn't keep.
+    pub fn entity_into_active_model(
+        entity: ArticleCoauthor,
+        position: i32,
+    ) -> ArticleCoauthorActiveModel {
+        ArticleCoauthorActiveModel {
+            article_id: entity.article_id().into_active_value(),
+            user_id: entity.user_id().into_active_value(),
+            contribution: entity.contribution().to_owned().into_active_value(),
+            position: position.into_active_value(),
+        }
+    }
+}
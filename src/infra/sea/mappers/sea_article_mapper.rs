@@ -1,8 +1,9 @@
 use entities::article::ActiveModel as ArticleActiveModel;
 use entities::article::Model as ArticleModel;
 use sea_orm::IntoActiveValue;
+use std::str::FromStr;
 
-use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article::{Article, CommentMode};
 use crate::domain::domain_entities::slug::Slug;
 
 use super::SeaMapper;
@@ -23,6 +24,11 @@ impl SeaMapper<Article, ArticleModel, ArticleActiveModel> for SeaArticleMapper {
             slug: entity.slug().to_string(),
             tag_id: entity.tag_id(),
             tag_value: entity.tag_value(),
+            featured: entity.featured(),
+            pinned: entity.pinned(),
+            pin_order: entity.pin_order(),
+            pinned_until: entity.pinned_until(),
+            comment_mode: entity.comment_mode().code().to_owned(),
         }
     }
 
@@ -39,6 +45,11 @@ impl SeaMapper<Article, ArticleModel, ArticleActiveModel> for SeaArticleMapper {
             slug: entity.slug().to_string().into_active_value(),
             tag_value: entity.tag_value().into_active_value(),
             tag_id: entity.tag_id().into_active_value(),
+            featured: entity.featured().into_active_value(),
+            pinned: entity.pinned().into_active_value(),
+            pin_order: entity.pin_order().into_active_value(),
+            pinned_until: entity.pinned_until().into_active_value(),
+            comment_mode: entity.comment_mode().code().to_owned().into_active_value(),
         }
     }
 
@@ -55,6 +66,11 @@ impl SeaMapper<Article, ArticleModel, ArticleActiveModel> for SeaArticleMapper {
             active_model.tag_id.unwrap(),
             active_model.tag_value.unwrap(),
             Slug::new_from_existing(active_model.slug.unwrap()),
+            active_model.featured.unwrap(),
+            active_model.pinned.unwrap(),
+            active_model.pin_order.unwrap(),
+            active_model.pinned_until.unwrap(),
+            CommentMode::from_str(&active_model.comment_mode.unwrap()).unwrap_or_default(),
         )
     }
 
@@ -71,6 +87,11 @@ impl SeaMapper<Article, ArticleModel, ArticleActiveModel> for SeaArticleMapper {
             model.tag_id,
             model.tag_value,
             Slug::new_from_existing(model.slug),
+            model.featured,
+            model.pinned,
+            model.pin_order,
+            model.pinned_until,
+            CommentMode::from_str(&model.comment_mode).unwrap_or_default(),
         )
     }
 }
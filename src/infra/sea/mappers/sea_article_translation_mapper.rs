@@ -0,0 +1,42 @@
+use std::str::FromStr;
+
+use entities::article_translation::ActiveModel as ArticleTranslationActiveModel;
+use entities::article_translation::Model as ArticleTranslationModel;
+use sea_orm::IntoActiveValue;
+
+use crate::domain::domain_entities::article_translation::ArticleTranslation;
+use crate::domain::domain_entities::locale::Locale;
+use crate::domain::domain_entities::slug::Slug;
+
+pub struct SeaArticleTranslationMapper;
+
+impl SeaArticleTranslationMapper {
+    /// Translations to locales that are no longer supported are left out.
+    pub fn model_into_entity(model: ArticleTranslationModel) -> Option<ArticleTranslation> {
+        let locale = Locale::from_str(&model.locale).ok()?;
+
+        Some(ArticleTranslation::new_from_existing(
+            model.article_id,
+            locale,
+            model.title,
+            model.content,
+            Slug::new_from_existing(model.slug),
+            model.created_at,
+            model.updated_at,
+        ))
+    }
+
+    pub fn entity_into_active_model(
+        translation: ArticleTranslation,
+    ) -> ArticleTranslationActiveModel {
+        ArticleTranslationActiveModel {
+            article_id: translation.article_id().into_active_value(),
+            locale: translation.locale().code().to_owned().into_active_value(),
+            title: translation.title().to_owned().into_active_value(),
+            content: translation.content().to_owned().into_active_value(),
+            slug: translation.slug().to_string().into_active_value(),
+            created_at: translation.created_at().into_active_value(),
+            updated_at: translation.updated_at().into_active_value(),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use std::str::FromStr;
+
+use entities::blocked_word::ActiveModel as BlockedWordActiveModel;
+use entities::blocked_word::Model as BlockedWordModel;
+use sea_orm::IntoActiveValue;
+
+use crate::domain::domain_entities::blocked_word::{BlockedWord, BlockedWordAction};
+
+pub struct SeaBlockedWordMapper;
+
+impl SeaBlockedWordMapper {
+    /// Blocked words with an action that is no longer supported are left out.
+    pub fn model_into_entity(model: BlockedWordModel) -> Option<BlockedWord> {
+        let action = BlockedWordAction::from_str(&model.action).ok()?;
+
+        Some(BlockedWord::new_from_existing(
+            model.id,
+            model.pattern,
+            model.is_regex,
+            action,
+            model.author_id,
+            model.created_at,
+            model.updated_at,
+        ))
+    }
+
+    pub fn entity_into_active_model(blocked_word: BlockedWord) -> BlockedWordActiveModel {
+        BlockedWordActiveModel {
+            id: blocked_word.id().into_active_value(),
+            pattern: blocked_word.pattern().to_owned().into_active_value(),
+            is_regex: blocked_word.is_regex().into_active_value(),
+            action: blocked_word.action().code().to_owned().into_active_value(),
+            author_id: blocked_word.author_id().into_active_value(),
+            created_at: blocked_word.created_at().into_active_value(),
+            updated_at: blocked_word.updated_at().into_active_value(),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use entities::comment_edit::ActiveModel as CommentEditActiveModel;
+use entities::comment_edit::Model as CommentEditModel;
+use sea_orm::IntoActiveValue;
+
+use crate::domain::domain_entities::comment_edit::CommentEdit;
+
+use super::SeaMapper;
+
+pub struct SeaCommentEditMapper;
+
+impl SeaMapper<CommentEdit, CommentEditModel, CommentEditActiveModel> for SeaCommentEditMapper {
+    fn entity_into_model(entity: CommentEdit) -> CommentEditModel {
+        CommentEditModel {
+            id: entity.id(),
+            comment_id: entity.comment_id(),
+            editor_id: entity.editor_id(),
+            previous_content: entity.previous_content().to_owned(),
+            created_at: entity.created_at(),
+        }
+    }
+
+    fn entity_into_active_model(entity: CommentEdit) -> CommentEditActiveModel {
+        CommentEditActiveModel {
+            id: entity.id().into_active_value(),
+            comment_id: entity.comment_id().into_active_value(),
+            editor_id: entity.editor_id().into_active_value(),
+            previous_content: entity.previous_content().to_owned().into_active_value(),
+            created_at: entity.created_at().into_active_value(),
+        }
+    }
+
+    fn active_model_into_entity(active_model: CommentEditActiveModel) -> CommentEdit {
+        CommentEdit::new_from_existing(
+            active_model.id.unwrap(),
+            active_model.comment_id.unwrap(),
+            active_model.editor_id.unwrap(),
+            active_model.previous_content.unwrap(),
+            active_model.created_at.unwrap(),
+        )
+    }
+
+    fn model_into_entity(model: CommentEditModel) -> CommentEdit {
+        CommentEdit::new_from_existing(
+            model.id,
+            model.comment_id,
+            model.editor_id,
+            model.previous_content,
+            model.created_at,
+        )
+    }
+}
@@ -13,10 +13,12 @@ impl SeaMapper<Comment, CommentModel, CommentActiveModel> for SeaCommentMapper {
         CommentModel {
             id: entity.id(),
             article_id: entity.article_id(),
+            parent_id: entity.parent_id(),
             author_id: entity.author_id(),
             content: entity.content().into(),
             is_active: entity.is_active(),
             created_at: entity.created_at(),
+            updated_at: entity.updated_at(),
         }
     }
 
@@ -24,10 +26,12 @@ impl SeaMapper<Comment, CommentModel, CommentActiveModel> for SeaCommentMapper {
         CommentActiveModel {
             id: entity.id().into_active_value(),
             article_id: entity.article_id().into_active_value(),
+            parent_id: entity.parent_id().into_active_value(),
             author_id: entity.author_id().into_active_value(),
             content: entity.content().to_string().into_active_value(),
             is_active: entity.is_active().into_active_value(),
             created_at: entity.created_at().into_active_value(),
+            updated_at: entity.updated_at().into_active_value(),
         }
     }
 
@@ -35,10 +39,12 @@ impl SeaMapper<Comment, CommentModel, CommentActiveModel> for SeaCommentMapper {
         Comment::new_from_existing(
             active_model.id.unwrap(),
             active_model.article_id.unwrap(),
+            active_model.parent_id.unwrap(),
             active_model.author_id.unwrap(),
             active_model.content.unwrap(),
             active_model.is_active.unwrap(),
             active_model.created_at.unwrap(),
+            active_model.updated_at.unwrap(),
         )
     }
 
@@ -46,10 +52,12 @@ impl SeaMapper<Comment, CommentModel, CommentActiveModel> for SeaCommentMapper {
         Comment::new_from_existing(
             model.id,
             model.article_id,
+            model.parent_id,
             model.author_id,
             model.content,
             model.is_active,
             model.created_at,
+            model.updated_at,
         )
     }
 }
@@ -0,0 +1,53 @@
+use entities::comment_mention::ActiveModel as CommentMentionActiveModel;
+use entities::comment_mention::Model as CommentMentionModel;
+use sea_orm::IntoActiveValue;
+
+use crate::domain::domain_entities::comment_mention::CommentMention;
+
+use super::SeaMapper;
+
+pub struct SeaCommentMentionMapper;
+
+impl SeaMapper<CommentMention, CommentMentionModel, CommentMentionActiveModel>
+    for SeaCommentMentionMapper
+{
+    fn entity_into_model(entity: CommentMention) -> CommentMentionModel {
+        CommentMentionModel {
+            comment_id: entity.comment_id(),
+            user_id: entity.user_id(),
+            span_start: entity.start() as i32,
+            span_end: entity.end() as i32,
+            created_at: entity.created_at(),
+        }
+    }
+
+    fn entity_into_active_model(entity: CommentMention) -> CommentMentionActiveModel {
+        CommentMentionActiveModel {
+            comment_id: entity.comment_id().into_active_value(),
+            user_id: entity.user_id().into_active_value(),
+            span_start: (entity.start() as i32).into_active_value(),
+            span_end: (entity.end() as i32).into_active_value(),
+            created_at: entity.created_at().into_active_value(),
+        }
+    }
+
+    fn active_model_into_entity(active_model: CommentMentionActiveModel) -> CommentMention {
+        CommentMention::new_from_existing(
+            active_model.comment_id.unwrap(),
+            active_model.user_id.unwrap(),
+            active_model.span_start.unwrap() as u32,
+            active_model.span_end.unwrap() as u32,
+            active_model.created_at.unwrap(),
+        )
+    }
+
+    fn model_into_entity(model: CommentMentionModel) -> CommentMention {
+        CommentMention::new_from_existing(
+            model.comment_id,
+            model.user_id,
+            model.span_start as u32,
+            model.span_end as u32,
+            model.created_at,
+        )
+    }
+}
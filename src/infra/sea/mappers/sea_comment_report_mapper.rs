@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use entities::comment_report::ActiveModel as CommentReportActiveModel;
 use entities::comment_report::Model as CommentReportModel;
 use sea_orm::IntoActiveValue;
@@ -6,11 +8,21 @@ use crate::domain::domain_entities::comment_report::CommentReport;
 use crate::domain::domain_entities::comment_report::CommentReportIdTrait;
 use crate::domain::domain_entities::comment_report::CommentReportTrait;
 use crate::domain::domain_entities::comment_report::DraftCommentReport;
+use crate::domain::domain_entities::comment_report::{ReportReason, ReportResolution};
 
 use super::SeaMapper;
 
 pub struct SeaCommentReportMapper;
 
+/// Falls back to `ReportReason::Other` if the stored reason is no longer known.
+fn reason_from_model(reason: &str) -> ReportReason {
+    ReportReason::from_str(reason).unwrap_or_default()
+}
+
+fn resolution_from_model(resolution: Option<String>) -> Option<ReportResolution> {
+    resolution.and_then(|resolution| ReportResolution::from_str(&resolution).ok())
+}
+
 impl SeaMapper<CommentReport, CommentReportModel, CommentReportActiveModel>
     for SeaCommentReportMapper
 {
@@ -22,6 +34,14 @@ impl SeaMapper<CommentReport, CommentReportModel, CommentReportActiveModel>
             solved_by: entity.solved_by(),
             user_id: entity.user_id(),
             id: entity.id(),
+            comment_auto_hidden: entity.comment_auto_hidden(),
+            reason: entity.reason().code().to_owned(),
+            priority: entity.priority().level(),
+            assigned_to: entity.assigned_to(),
+            resolution: entity
+                .resolution()
+                .map(|resolution| resolution.code().to_owned()),
+            resolution_notes: entity.resolution_notes().map(str::to_owned),
         }
     }
 
@@ -33,6 +53,18 @@ impl SeaMapper<CommentReport, CommentReportModel, CommentReportActiveModel>
             solved_by: entity.solved_by().into_active_value(),
             created_at: entity.created_at().into_active_value(),
             id: entity.id().into_active_value(),
+            comment_auto_hidden: entity.comment_auto_hidden().into_active_value(),
+            reason: entity.reason().code().to_owned().into_active_value(),
+            priority: entity.priority().level().into_active_value(),
+            assigned_to: entity.assigned_to().into_active_value(),
+            resolution: entity
+                .resolution()
+                .map(|resolution| resolution.code().to_owned())
+                .into_active_value(),
+            resolution_notes: entity
+                .resolution_notes()
+                .map(str::to_owned)
+                .into_active_value(),
         }
     }
 
@@ -44,6 +76,11 @@ impl SeaMapper<CommentReport, CommentReportModel, CommentReportActiveModel>
             active_model.message.unwrap(),
             active_model.solved_by.unwrap(),
             active_model.created_at.unwrap(),
+            active_model.comment_auto_hidden.unwrap(),
+            reason_from_model(&active_model.reason.unwrap()),
+            active_model.assigned_to.unwrap(),
+            resolution_from_model(active_model.resolution.unwrap()),
+            active_model.resolution_notes.unwrap(),
         )
     }
 
@@ -55,6 +92,11 @@ impl SeaMapper<CommentReport, CommentReportModel, CommentReportActiveModel>
             model.message,
             model.solved_by,
             model.created_at,
+            model.comment_auto_hidden,
+            reason_from_model(&model.reason),
+            model.assigned_to,
+            resolution_from_model(model.resolution),
+            model.resolution_notes,
         )
     }
 }
@@ -65,10 +107,13 @@ impl SeaCommentReportMapper {
     ) -> CommentReportActiveModel {
         CommentReportActiveModel {
             user_id: draft_entity.user_id().into_active_value(),
-            comment_id: draft_entity.comment_id().into_active_value(),
+            comment_id: Some(draft_entity.comment_id()).into_active_value(),
             message: draft_entity.message().into_active_value(),
             solved_by: draft_entity.solved_by().into_active_value(),
             created_at: draft_entity.created_at().into_active_value(),
+            comment_auto_hidden: draft_entity.comment_auto_hidden().into_active_value(),
+            reason: draft_entity.reason().code().to_owned().into_active_value(),
+            priority: draft_entity.priority().level().into_active_value(),
             ..Default::default()
         }
     }
@@ -0,0 +1,57 @@
+use entities::comment_review::ActiveModel as CommentReviewActiveModel;
+use entities::comment_review::Model as CommentReviewModel;
+use sea_orm::IntoActiveValue;
+
+use crate::domain::domain_entities::comment_review::CommentReview;
+
+use super::SeaMapper;
+
+pub struct SeaCommentReviewMapper;
+
+impl SeaMapper<CommentReview, CommentReviewModel, CommentReviewActiveModel>
+    for SeaCommentReviewMapper
+{
+    fn entity_into_model(entity: CommentReview) -> CommentReviewModel {
+        CommentReviewModel {
+            comment_id: entity.comment_id(),
+            author_id: entity.author_id(),
+            created_at: entity.created_at(),
+            reviewed_by: entity.reviewed_by(),
+            reviewed_at: entity.reviewed_at(),
+            approved: entity.approved(),
+        }
+    }
+
+    fn entity_into_active_model(entity: CommentReview) -> CommentReviewActiveModel {
+        CommentReviewActiveModel {
+            comment_id: entity.comment_id().into_active_value(),
+            author_id: entity.author_id().into_active_value(),
+            created_at: entity.created_at().into_active_value(),
+            reviewed_by: entity.reviewed_by().into_active_value(),
+            reviewed_at: entity.reviewed_at().into_active_value(),
+            approved: entity.approved().into_active_value(),
+        }
+    }
+
+    fn active_model_into_entity(active_model: CommentReviewActiveModel) -> CommentReview {
+        CommentReview::new_from_existing(
+            active_model.comment_id.unwrap(),
+            active_model.author_id.unwrap(),
+            active_model.created_at.unwrap(),
+            active_model.reviewed_by.unwrap(),
+            active_model.reviewed_at.unwrap(),
+            active_model.approved.unwrap(),
+        )
+    }
+
+    fn model_into_entity(model: CommentReviewModel) -> CommentReview {
+        CommentReview::new_from_existing(
+            model.comment_id,
+            model.author_id,
+            model.created_at,
+            model.reviewed_by,
+            model.reviewed_at,
+            model.approved,
+        )
+    }
+}
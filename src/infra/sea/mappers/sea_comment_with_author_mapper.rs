@@ -15,9 +15,11 @@ impl SeaCommentWithAuthorMapper {
         CommentWithAuthor::new_from_existing(
             comment_model.id,
             comment_model.article_id,
+            comment_model.parent_id,
             comment_model.content,
             comment_model.is_active,
             comment_model.created_at,
+            comment_model.updated_at,
             SeaUserMapper::model_into_entity(user_model),
         )
     }
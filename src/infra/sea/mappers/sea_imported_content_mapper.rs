@@ -0,0 +1,34 @@
+use std::str::FromStr;
+
+use entities::imported_content::ActiveModel as ImportedContentActiveModel;
+use entities::imported_content::Model as ImportedContentModel;
+use sea_orm::IntoActiveValue;
+
+use crate::domain::domain_entities::imported_content::{ImportedContent, ImportedContentKind};
+
+pub struct SeaImportedContentMapper;
+
+impl SeaImportedContentMapper {
+    pub fn entity_into_active_model(entity: &ImportedContent) -> ImportedContentActiveModel {
+        ImportedContentActiveModel {
+            source: entity.source().to_string().into_active_value(),
+            kind: entity.kind().as_str().to_string().into_active_value(),
+            external_id: entity.external_id().to_string().into_active_value(),
+            internal_id: entity.internal_id().to_string().into_active_value(),
+            imported_at: entity.imported_at().into_active_value(),
+        }
+    }
+
+    /// Records of a kind this version doesn't know about are ignored.
+    pub fn model_into_entity(model: ImportedContentModel) -> Option<ImportedContent> {
+        let kind = ImportedContentKind::from_str(&model.kind).ok()?;
+
+        Some(ImportedContent::new_from_existing(
+            model.source,
+            kind,
+            model.external_id,
+            model.internal_id,
+            model.imported_at,
+        ))
+    }
+}
@@ -0,0 +1,55 @@
+use entities::media::ActiveModel as MediaActiveModel;
+use entities::media::Model as MediaModel;
+use entities::media_tag::ActiveModel as MediaTagActiveModel;
+use sea_orm::IntoActiveValue;
+
+use crate::domain::domain_entities::media::Media;
+
+/// Media tags are stored in a table of their own, so they are mapped apart from the media.
+pub struct SeaMediaMapper;
+
+impl SeaMediaMapper {
+    pub fn entity_into_active_model(media: &Media) -> MediaActiveModel {
+        MediaActiveModel {
+            id: media.id().into_active_value(),
+            uploader_id: media.uploader_id().into_active_value(),
+            storage_key: media.storage_key().to_owned().into_active_value(),
+            file_name: media.file_name().to_owned().into_active_value(),
+            mime_type: media.mime_type().to_owned().into_active_value(),
+            size: (media.size() as i64).into_active_value(),
+            width: (media.width() as i32).into_active_value(),
+            height: (media.height() as i32).into_active_value(),
+            alt_text: media.alt_text().to_owned().into_active_value(),
+            created_at: media.created_at().into_active_value(),
+            updated_at: media.updated_at().into_active_value(),
+        }
+    }
+
+    pub fn tags_into_active_models(media: &Media) -> Vec<MediaTagActiveModel> {
+        media
+            .tags()
+            .iter()
+            .map(|tag| MediaTagActiveModel {
+                media_id: media.id().into_active_value(),
+                tag: tag.clone().into_active_value(),
+            })
+            .collect()
+    }
+
+    pub fn model_into_entity(model: MediaModel, tags: Vec<String>) -> Media {
+        Media::new_from_existing(
+            model.id,
+            model.uploader_id,
+            model.storage_key,
+            model.file_name,
+            model.mime_type,
+            model.size as u64,
+            model.width as u32,
+            model.height as u32,
+            model.alt_text,
+            tags,
+            model.created_at,
+            model.updated_at,
+        )
+    }
+}
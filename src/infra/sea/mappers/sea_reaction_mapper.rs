@@ -0,0 +1,34 @@
+use std::str::FromStr;
+
+use entities::reaction::ActiveModel as ReactionActiveModel;
+use entities::reaction::Model as ReactionModel;
+use sea_orm::IntoActiveValue;
+
+use crate::domain::domain_entities::reaction::{Reaction, ReactionTarget};
+
+pub struct SeaReactionMapper;
+
+impl SeaReactionMapper {
+    /// Reactions to targets that are no longer supported are left out.
+    pub fn model_into_entity(model: ReactionModel) -> Option<Reaction> {
+        let target = ReactionTarget::from_str(&model.target).ok()?;
+
+        Some(Reaction::new_from_existing(
+            model.user_id,
+            target,
+            model.target_id,
+            model.kind,
+            model.created_at,
+        ))
+    }
+
+    pub fn entity_into_active_model(reaction: Reaction) -> ReactionActiveModel {
+        ReactionActiveModel {
+            user_id: reaction.user_id().into_active_value(),
+            target: reaction.target().code().to_owned().into_active_value(),
+            target_id: reaction.target_id().into_active_value(),
+            kind: reaction.kind().to_owned().into_active_value(),
+            created_at: reaction.created_at().into_active_value(),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use entities::series::ActiveModel as SeriesActiveModel;
+use entities::series::Model as SeriesModel;
+use sea_orm::IntoActiveValue;
+
+use crate::domain::domain_entities::series::Series;
+use crate::domain::domain_entities::slug::Slug;
+
+use super::SeaMapper;
+
+pub struct SeaSeriesMapper;
+
+impl SeaMapper<Series, SeriesModel, SeriesActiveModel> for SeaSeriesMapper {
+    fn entity_into_model(entity: Series) -> SeriesModel {
+        SeriesModel {
+            id: entity.id(),
+            author_id: entity.author_id(),
+            title: entity.title().to_owned(),
+            description: entity.description().to_owned(),
+            slug: entity.slug().to_string(),
+            created_at: entity.created_at(),
+            updated_at: entity.updated_at(),
+        }
+    }
+
+    fn entity_into_active_model(entity: Series) -> SeriesActiveModel {
+        SeriesActiveModel {
+            id: entity.id().into_active_value(),
+            author_id: entity.author_id().into_active_value(),
+            title: entity.title().to_owned().into_active_value(),
+            description: entity.description().to_owned().into_active_value(),
+            slug: entity.slug().to_string().into_active_value(),
+            created_at: entity.created_at().into_active_value(),
+            updated_at: entity.updated_at().into_active_value(),
+        }
+    }
+
+    fn active_model_into_entity(active_model: SeriesActiveModel) -> Series {
+        Series::new_from_existing(
+            active_model.id.unwrap(),
+            active_model.author_id.unwrap(),
+            active_model.title.unwrap(),
+            active_model.description.unwrap(),
+            Slug::new_from_existing(active_model.slug.unwrap()),
+            active_model.created_at.unwrap(),
+            active_model.updated_at.unwrap(),
+        )
+    }
+
+    fn model_into_entity(model: SeriesModel) -> Series {
+        Series::new_from_existing(
+            model.id,
+            model.author_id,
+            model.title,
+            model.description,
+            Slug::new_from_existing(model.slug),
+            model.created_at,
+            model.updated_at,
+        )
+    }
+}
@@ -1,7 +1,9 @@
 use entities::user::ActiveModel as UserActiveModel;
 use entities::user::Model as UserModel;
 use sea_orm::IntoActiveValue;
+use std::str::FromStr;
 
+use crate::domain::domain_entities::habbo_avatar::{HabboAvatar, HabboHotel};
 use crate::domain::domain_entities::user::User;
 
 use super::sea_role_mapper::SeaRoleMapper;
@@ -9,6 +11,16 @@ use super::SeaMapper;
 
 pub struct SeaUserMapper;
 
+/// Falls back to the default avatar if the stored one is no longer valid (e.g. its hotel got unsupported).
+fn avatar_from_model(habbo_hotel: Option<String>, direction: i16, head_only: bool) -> HabboAvatar {
+    let hotel = habbo_hotel.and_then(|hotel| HabboHotel::from_str(&hotel).ok());
+
+    u8::try_from(direction)
+        .ok()
+        .and_then(|direction| HabboAvatar::new(hotel, direction, head_only).ok())
+        .unwrap_or_default()
+}
+
 impl SeaMapper<User, UserModel, UserActiveModel> for SeaUserMapper {
     fn entity_into_model(entity: User) -> UserModel {
         UserModel {
@@ -18,6 +30,10 @@ impl SeaMapper<User, UserModel, UserActiveModel> for SeaUserMapper {
             role: entity.role().map(SeaRoleMapper::into_model),
             created_at: entity.created_at(),
             last_login: entity.last_login(),
+            bio: entity.bio().map(ToOwned::to_owned),
+            habbo_hotel: entity.avatar().hotel().map(|hotel| hotel.to_string()),
+            avatar_direction: entity.avatar().direction().into(),
+            avatar_head_only: entity.avatar().head_only(),
         }
     }
 
@@ -29,6 +45,14 @@ impl SeaMapper<User, UserModel, UserActiveModel> for SeaUserMapper {
             role: sea_orm::ActiveValue::Set(entity.role().map(SeaRoleMapper::into_model)),
             created_at: entity.created_at().into_active_value(),
             last_login: entity.last_login().into_active_value(),
+            bio: entity.bio().map(ToOwned::to_owned).into_active_value(),
+            habbo_hotel: entity
+                .avatar()
+                .hotel()
+                .map(|hotel| hotel.to_string())
+                .into_active_value(),
+            avatar_direction: i16::from(entity.avatar().direction()).into_active_value(),
+            avatar_head_only: entity.avatar().head_only().into_active_value(),
         }
     }
 
@@ -40,6 +64,12 @@ impl SeaMapper<User, UserModel, UserActiveModel> for SeaUserMapper {
             active_model.created_at.unwrap(),
             active_model.last_login.unwrap(),
             active_model.role.unwrap().map(SeaRoleMapper::into_entity),
+            active_model.bio.unwrap(),
+            avatar_from_model(
+                active_model.habbo_hotel.unwrap(),
+                active_model.avatar_direction.unwrap(),
+                active_model.avatar_head_only.unwrap(),
+            ),
         )
     }
 
@@ -51,6 +81,12 @@ impl SeaMapper<User, UserModel, UserActiveModel> for SeaUserMapper {
             model.created_at.to_owned(),
             model.last_login.to_owned(),
             model.role.map(SeaRoleMapper::into_entity),
+            model.bio,
+            avatar_from_model(
+                model.habbo_hotel,
+                model.avatar_direction,
+                model.avatar_head_only,
+            ),
         )
     }
 }
@@ -1,3 +1,4 @@
 pub mod mappers;
+pub mod query_spec;
 pub mod repositories;
 pub mod sea_service;
@@ -0,0 +1,106 @@
+use sea_orm::{
+    ColumnTrait, Condition, EntityTrait, Order, QueryFilter, QueryOrder, QuerySelect, Select, Value,
+};
+
+use crate::core::pagination::{Cursor, CursorDirection};
+use crate::core::query::{DateRange, QuerySpec, SortDirection};
+
+/// Maps a field a listing can be sorted by into a column of the entity's table.
+pub trait SeaSortField<E: EntityTrait> {
+    fn column(&self) -> E::Column;
+}
+
+/// Narrows the select down with every filter of the query, combining them with AND.
+pub fn apply_filters<E, F, SF>(
+    select: Select<E>,
+    query: Option<&QuerySpec<F, SF>>,
+    apply_filter: impl Fn(Select<E>, F) -> Select<E>,
+) -> Select<E>
+where
+    E: EntityTrait,
+    F: Clone,
+{
+    match query {
+        None => select,
+        Some(query) => query.filters.iter().cloned().fold(select, apply_filter),
+    }
+}
+
+/// Orders the select by each sort of the query, then by the default column (most recent first), which keeps the
+/// order of ties stable across pages.
+pub fn apply_sort<E, F, SF>(
+    select: Select<E>,
+    query: Option<&QuerySpec<F, SF>>,
+    default_column: E::Column,
+) -> Select<E>
+where
+    E: EntityTrait,
+    SF: SeaSortField<E>,
+{
+    query
+        .map(|query| query.sort.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .fold(select, |select, sort| {
+            select.order_by(sort.field.column(), into_order(sort.direction))
+        })
+        .order_by_desc(default_column)
+}
+
+/// Matches the rows whose column falls within the range, ends included.
+pub fn date_range_condition<C: ColumnTrait>(column: C, range: DateRange) -> Condition {
+    Condition::all()
+        .add_option(range.from.map(|from| column.gte(from)))
+        .add_option(range.to.map(|to| column.lte(to)))
+}
+
+/// Keeps the rows following the cursor in its direction, ordered by creation date then id so that rows created at the
+/// same time aren't skipped. One row more than a page is fetched, see `CursorPage::from_window`.
+pub fn apply_cursor<E, ID>(
+    select: Select<E>,
+    cursor: Option<&Cursor<ID>>,
+    created_at_column: E::Column,
+    id_column: E::Column,
+    items_per_page: u32,
+) -> Select<E>
+where
+    E: EntityTrait,
+    ID: Clone + Into<Value>,
+{
+    let direction = cursor.map_or(CursorDirection::Next, |cursor| cursor.direction);
+
+    let select = match cursor {
+        None => select,
+        Some(cursor) => {
+            let (created_at, id) = (cursor.created_at, cursor.id.clone());
+
+            let condition = match direction {
+                CursorDirection::Next => Condition::any()
+                    .add(created_at_column.lt(created_at))
+                    .add(created_at_column.eq(created_at).and(id_column.lt(id))),
+                CursorDirection::Prev => Condition::any()
+                    .add(created_at_column.gt(created_at))
+                    .add(created_at_column.eq(created_at).and(id_column.gt(id))),
+            };
+
+            select.filter(condition)
+        }
+    };
+
+    let order = match direction {
+        CursorDirection::Next => Order::Desc,
+        CursorDirection::Prev => Order::Asc,
+    };
+
+    select
+        .order_by(created_at_column, order.clone())
+        .order_by(id_column, order)
+        .limit(items_per_page as u64 + 1)
+}
+
+fn into_order(direction: SortDirection) -> Order {
+    match direction {
+        SortDirection::Asc => Order::Asc,
+        SortDirection::Desc => Order::Desc,
+    }
+}
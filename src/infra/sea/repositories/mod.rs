@@ -1,11 +1,21 @@
 pub mod sea_announcement_repository;
+pub mod sea_article_coauthor_repository;
 pub mod sea_article_comment_repository;
 pub mod sea_article_repository;
 pub mod sea_article_tag_repository;
+pub mod sea_article_translation_repository;
+pub mod sea_blocked_word_repository;
+pub mod sea_comment_edit_repository;
+pub mod sea_comment_mention_repository;
 pub mod sea_comment_report_repository;
 pub mod sea_comment_repository;
+pub mod sea_comment_review_repository;
 pub mod sea_comment_user_article_repository;
 pub mod sea_free_badge_repository;
+pub mod sea_imported_content_repository;
+pub mod sea_media_repository;
+pub mod sea_reaction_repository;
+pub mod sea_series_repository;
 pub mod sea_team_role_repository;
 pub mod sea_team_user_repository;
 pub mod sea_user_repository;
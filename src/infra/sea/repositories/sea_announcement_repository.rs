@@ -3,8 +3,7 @@ use entities::announcement::Column as AnnouncementColumn;
 use entities::announcement::Entity as AnnouncementEntity;
 use migration::extension::postgres::PgExpr;
 use sea_orm::{
-    ActiveModelTrait, EntityTrait, IntoSimpleExpr, PaginatorTrait, QueryFilter, QueryOrder,
-    QuerySelect, QueryTrait, Select,
+    ActiveModelTrait, EntityTrait, IntoSimpleExpr, PaginatorTrait, QueryFilter, QuerySelect, Select,
 };
 use std::error::Error;
 use uuid::Uuid;
@@ -12,9 +11,13 @@ use uuid::Uuid;
 use crate::core::pagination::PaginationParameters;
 use crate::domain::domain_entities::announcement::Announcement;
 use crate::domain::repositories::announcements_repository::{
-    AnnouncementQueryType, AnnouncementRepositoryTrait, FindManyAnnouncementsResponse,
+    AnnouncementQuery, AnnouncementQueryType, AnnouncementRepositoryTrait, AnnouncementSortField,
+    FindManyAnnouncementsResponse,
 };
 use crate::infra::sea::mappers::{sea_announcement_mapper::SeaAnnouncementMapper, SeaMapper};
+use crate::infra::sea::query_spec::{
+    apply_filters, apply_sort, date_range_condition, SeaSortField,
+};
 use crate::infra::sea::sea_service::SeaService;
 
 pub struct SeaAnnouncementRepository<'a> {
@@ -56,28 +59,34 @@ impl AnnouncementRepositoryTrait for SeaAnnouncementRepository<'_> {
 
     async fn find_many(
         &self,
-        params: PaginationParameters<AnnouncementQueryType>,
+        params: PaginationParameters<AnnouncementQuery>,
     ) -> Result<FindManyAnnouncementsResponse, Box<dyn Error>> {
         let offset = ((params.page - 1) * params.items_per_page) as u64;
 
-        let announcements = AnnouncementEntity::find()
-            .order_by_desc(AnnouncementColumn::CreatedAt)
-            .apply_if(
-                params.query.clone(),
+        let announcements = apply_sort(
+            apply_filters(
+                AnnouncementEntity::find(),
+                params.query.as_ref(),
                 SeaAnnouncementRepository::apply_query_filters,
-            )
-            .limit(params.items_per_page as u64)
-            .offset(offset)
-            .all(&self.sea_service.db)
-            .await?
-            .into_iter()
-            .map(SeaAnnouncementMapper::model_into_entity)
-            .collect::<Vec<Announcement>>();
+            ),
+            params.query.as_ref(),
+            AnnouncementColumn::CreatedAt,
+        )
+        .limit(params.items_per_page as u64)
+        .offset(offset)
+        .all(&self.sea_service.db)
+        .await?
+        .into_iter()
+        .map(SeaAnnouncementMapper::model_into_entity)
+        .collect::<Vec<Announcement>>();
 
-        let count = AnnouncementEntity::find()
-            .apply_if(params.query, SeaAnnouncementRepository::apply_query_filters)
-            .count(&self.sea_service.db)
-            .await?;
+        let count = apply_filters(
+            AnnouncementEntity::find(),
+            params.query.as_ref(),
+            SeaAnnouncementRepository::apply_query_filters,
+        )
+        .count(&self.sea_service.db)
+        .await?;
 
         Ok(FindManyAnnouncementsResponse(announcements, count))
     }
@@ -102,6 +111,17 @@ impl SeaAnnouncementRepository<'_> {
                     .into_simple_expr()
                     .ilike(format!("%{}%", description)),
             ),
+            AnnouncementQueryType::CreatedAt(range) => {
+                builder.filter(date_range_condition(AnnouncementColumn::CreatedAt, range))
+            }
+        }
+    }
+}
+
+impl SeaSortField<AnnouncementEntity> for AnnouncementSortField {
+    fn column(&self) -> AnnouncementColumn {
+        match self {
+            AnnouncementSortField::CreatedAt => AnnouncementColumn::CreatedAt,
         }
     }
 }
@@ -0,0 +1,121 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, TransactionTrait,
+};
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_coauthor::ArticleCoauthor;
+use crate::domain::domain_entities::user::User;
+use crate::domain::repositories::article_coauthor_repository::ArticleCoauthorRepositoryTrait;
+use crate::infra::sea::mappers::sea_article_coauthor_mapper::SeaArticleCoauthorMapper;
+use crate::infra::sea::mappers::sea_article_mapper::SeaArticleMapper;
+use crate::infra::sea::mappers::sea_user_mapper::SeaUserMapper;
+use crate::infra::sea::mappers::SeaMapper;
+use crate::infra::sea::sea_service::SeaService;
+
+use entities::article::Column as ArticleColumn;
+use entities::article::Entity as ArticleEntity;
+use entities::article_coauthor::Column as ArticleCoauthorColumn;
+use entities::article_coauthor::Entity as ArticleCoauthorEntity;
+use entities::user::Entity as UserEntity;
+
+pub struct SeaArticleCoauthorRepository<'a> {
+    sea_service: &'a SeaService,
+}
+
+impl<'a> SeaArticleCoauthorRepository<'a> {
+    pub fn new(sea_service: &'a SeaService) -> Self {
+        SeaArticleCoauthorRepository { sea_service }
+    }
+}
+
+#[async_trait]
+impl ArticleCoauthorRepositoryTrait for SeaArticleCoauthorRepository<'_> {
+    async fn find_by_article_id(
+        &self,
+        article_id: Uuid,
+    ) -> Result<Vec<ArticleCoauthor>, Box<dyn Error>> {
+        let coauthors = ArticleCoauthorEntity::find()
+            .filter(ArticleCoauthorColumn::ArticleId.eq(article_id))
+            .order_by_asc(ArticleCoauthorColumn::Position)
+            .all(&self.sea_service.db)
+            .await?
+            .into_iter()
+            .map(SeaArticleCoauthorMapper::model_into_entity)
+            .collect();
+
+        Ok(coauthors)
+    }
+
+    async fn find_with_users_by_article_id(
+        &self,
+        article_id: Uuid,
+    ) -> Result<Vec<(ArticleCoauthor, User)>, Box<dyn Error>> {
+        let coauthors = ArticleCoauthorEntity::find()
+            .filter(ArticleCoauthorColumn::ArticleId.eq(article_id))
+            .order_by_asc(ArticleCoauthorColumn::Position)
+            .find_also_related(UserEntity)
+            .all(&self.sea_service.db)
+            .await?
+            .into_iter()
+            .filter_map(|(coauthor, user)| {
+                user.map(|user| {
+                    (
+                        SeaArticleCoauthorMapper::model_into_entity(coauthor),
+                        SeaUserMapper::model_into_entity(user),
+                    )
+                })
+            })
+            .collect();
+
+        Ok(coauthors)
+    }
+
+    async fn is_coauthor(&self, article_id: Uuid, user_id: Uuid) -> Result<bool, Box<dyn Error>> {
+        let count = ArticleCoauthorEntity::find()
+            .filter(ArticleCoauthorColumn::ArticleId.eq(article_id))
+            .filter(ArticleCoauthorColumn::UserId.eq(user_id))
+            .count(&self.sea_service.db)
+            .await?;
+
+        Ok(count > 0)
+    }
+
+    async fn save_article_with_coauthors(
+        &self,
+        article: Article,
+        coauthors: Vec<ArticleCoauthor>,
+    ) -> Result<Article, Box<dyn Error>> {
+        let article_id = article.id();
+        let transaction = self.sea_service.db.begin().await?;
+
+        let article = ArticleEntity::update(SeaArticleMapper::entity_into_active_model(article))
+            .filter(ArticleColumn::Id.eq(article_id))
+            .exec(&transaction)
+            .await?;
+
+        ArticleCoauthorEntity::delete_many()
+            .filter(ArticleCoauthorColumn::ArticleId.eq(article_id))
+            .exec(&transaction)
+            .await?;
+
+        if !coauthors.is_empty() {
+            let coauthors = coauthors
+                .into_iter()
+                .enumerate()
+                .map(|(position, coauthor)| {
+                    SeaArticleCoauthorMapper::entity_into_active_model(coauthor, position as i32)
+                });
+
+            ArticleCoauthorEntity::insert_many(coauthors)
+                .exec(&transaction)
+                .await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(SeaArticleMapper::model_into_entity(article))
+    }
+}
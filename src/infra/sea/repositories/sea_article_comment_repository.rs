@@ -1,8 +1,8 @@
 use async_trait::async_trait;
 use migration::{Expr, Func};
 use sea_orm::{
-    ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
-    QuerySelect, QueryTrait, TransactionTrait, Value,
+    ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter, QuerySelect,
+    QueryTrait, TransactionTrait, Value,
 };
 use std::error::Error;
 use std::future::Future;
@@ -12,11 +12,15 @@ use crate::core::pagination::PaginationParameters;
 use crate::domain::domain_entities::article::Article;
 use crate::domain::domain_entities::comment::Comment;
 use crate::domain::repositories::article_comment_repository::{
-    ArticleCommentRepositoryTrait, CommentQueryType, FindManyCommentsResponse,
+    ArticleCommentRepositoryTrait, CommentQuery, CommentQueryType, CommentSortField,
+    FindManyCommentsResponse,
 };
 use crate::infra::sea::mappers::sea_article_mapper::SeaArticleMapper;
 use crate::infra::sea::mappers::sea_comment_mapper::SeaCommentMapper;
 use crate::infra::sea::mappers::SeaMapper;
+use crate::infra::sea::query_spec::{
+    apply_filters, apply_sort, date_range_condition, SeaSortField,
+};
 use crate::infra::sea::sea_service::SeaService;
 
 use entities::comment::Column as CommentColumn;
@@ -42,7 +46,7 @@ impl ArticleCommentRepositoryTrait for SeaArticleCommentRepository<'_> {
         &self,
         article_id: Option<Uuid>,
         include_inactive: bool,
-        params: PaginationParameters<CommentQueryType>,
+        params: PaginationParameters<CommentQuery>,
     ) -> Result<FindManyCommentsResponse, Box<dyn Error>> {
         let current_page = params.page as u64;
         let items_per_page = params.items_per_page as u64;
@@ -51,43 +55,33 @@ impl ArticleCommentRepositoryTrait for SeaArticleCommentRepository<'_> {
 
         let include_inactive = Some(include_inactive);
 
-        let comments = CommentEntity::find()
-            .apply_if(include_inactive, |query_builder, val| {
-                if !val {
-                    query_builder.filter(CommentColumn::IsActive.eq(true))
-                } else {
-                    query_builder
-                }
-            })
-            .apply_if(article_id, |query_builder, id| {
-                query_builder.filter(CommentColumn::ArticleId.eq(id))
-            })
-            .apply_if(params.clone().query, |query_builder, query| {
-                self.find_many_get_filters(query_builder, query)
-            })
-            .order_by_desc(CommentColumn::CreatedAt)
-            .limit(items_per_page)
-            .offset(leap)
-            .all(&self.sea_service.db)
-            .await?;
-
-        let comments_count = CommentEntity::find()
-            .apply_if(include_inactive, |query_builder, val| {
-                if !val {
-                    query_builder.filter(CommentColumn::IsActive.eq(true))
-                } else {
-                    query_builder
-                }
-            })
-            .apply_if(article_id, |query_builder, id| {
-                query_builder.filter(CommentColumn::ArticleId.eq(id))
-            })
-            .apply_if(params.clone().query, |query_builder, query| {
-                self.find_many_get_filters(query_builder, query)
-            })
-            .offset(leap)
-            .count(&self.sea_service.db)
-            .await?;
+        let comments_query = apply_filters(
+            CommentEntity::find()
+                .apply_if(include_inactive, |query_builder, val| {
+                    if !val {
+                        query_builder.filter(CommentColumn::IsActive.eq(true))
+                    } else {
+                        query_builder
+                    }
+                })
+                .apply_if(article_id, |query_builder, id| {
+                    query_builder.filter(CommentColumn::ArticleId.eq(id))
+                }),
+            params.query.as_ref(),
+            |query_builder, query| self.find_many_get_filters(query_builder, query),
+        );
+
+        let comments = apply_sort(
+            comments_query.clone(),
+            params.query.as_ref(),
+            CommentColumn::CreatedAt,
+        )
+        .limit(items_per_page)
+        .offset(leap)
+        .all(&self.sea_service.db)
+        .await?;
+
+        let comments_count = comments_query.count(&self.sea_service.db).await?;
 
         let mut mapped_comments: Vec<Comment> = vec![];
 
@@ -198,6 +192,17 @@ impl SeaArticleCommentRepository<'_> {
                     .like(format!("%{}%", content.to_lowercase()));
                 query_builder.filter(filter)
             }
+            CommentQueryType::CreatedAt(range) => {
+                query_builder.filter(date_range_condition(CommentColumn::CreatedAt, range))
+            }
+        }
+    }
+}
+
+impl SeaSortField<CommentEntity> for CommentSortField {
+    fn column(&self) -> CommentColumn {
+        match self {
+            CommentSortField::CreatedAt => CommentColumn::CreatedAt,
         }
     }
 }
@@ -1,18 +1,23 @@
 use async_trait::async_trait;
+use chrono::NaiveDateTime as DateTime;
 use migration::{Expr, Func};
-use sea_orm::{ActiveModelTrait, EntityTrait, QueryFilter};
+use sea_orm::{ActiveModelTrait, Condition, EntityTrait, QueryFilter};
 use sea_orm::{ColumnTrait, PaginatorTrait, QueryOrder, QuerySelect, QueryTrait};
 use std::error::Error;
 use uuid::Uuid;
 
-use crate::core::pagination::PaginationParameters;
+use crate::core::pagination::{CursorParameters, PaginationParameters};
 use crate::domain::domain_entities::article::Article;
 use crate::domain::domain_entities::slug::Slug;
 use crate::domain::repositories::article_repository::{
-    ArticleQueryType, ArticleRepositoryTrait, FindManyArticlesResponse,
+    ArticleQuery, ArticleQueryType, ArticleRepositoryTrait, ArticleSortField,
+    FindManyArticlesResponse,
 };
 use crate::infra::sea::mappers::sea_article_mapper::SeaArticleMapper;
 use crate::infra::sea::mappers::SeaMapper;
+use crate::infra::sea::query_spec::{
+    apply_cursor, apply_filters, apply_sort, date_range_condition, SeaSortField,
+};
 use crate::infra::sea::sea_service::SeaService;
 
 use entities::article::Column as ArticleColumn;
@@ -71,48 +76,56 @@ impl ArticleRepositoryTrait for SeaArticleRepository<'_> {
         Ok(Some(mapped_article))
     }
 
+    async fn find_many_by_author(&self, author_id: Uuid) -> Result<Vec<Article>, Box<dyn Error>> {
+        let articles = ArticleEntity::find()
+            .filter(ArticleColumn::AuthorId.eq(author_id))
+            .order_by_desc(ArticleColumn::CreatedAt)
+            .all(&self.sea_service.db)
+            .await?
+            .into_iter()
+            .map(SeaArticleMapper::model_into_entity)
+            .collect();
+
+        Ok(articles)
+    }
+
     async fn find_many(
         &self,
-        params: PaginationParameters<ArticleQueryType>,
+        params: PaginationParameters<ArticleQuery>,
         show_only_approved_state: Option<bool>,
     ) -> Result<FindManyArticlesResponse, Box<dyn Error>> {
-        #[allow(unused_mut)]
-        let mut articles_response;
-
         let current_page = params.page as u64;
         let items_per_page = params.items_per_page as u64;
 
         let leap = (&current_page - 1) * items_per_page;
 
-        articles_response = ArticleEntity::find()
-            .order_by_desc(ArticleColumn::CreatedAt)
-            .apply_if(
-                params.clone().query,
-                |#[allow(unused_mut)] mut query_builder, query| {
-                    self.find_many_get_filters(query_builder, query)
-                },
-            )
-            .apply_if(show_only_approved_state, |query_builder, approved| {
-                query_builder.filter(ArticleColumn::Approved.eq(approved))
-            })
-            .limit(items_per_page)
-            .offset(leap)
-            .all(&self.sea_service.db)
-            .await?;
-
-        let articles_count = ArticleEntity::find()
-            .apply_if(
-                params.query,
-                |#[allow(unused_mut)] mut query_builder, query| {
-                    self.find_many_get_filters(query_builder, query)
-                },
-            )
-            .apply_if(show_only_approved_state, |query_builder, approved| {
-                query_builder.filter(ArticleColumn::Approved.eq(approved))
-            })
-            .offset(leap)
-            .count(&self.sea_service.db)
-            .await?;
+        let articles_response = apply_sort(
+            apply_filters(
+                ArticleEntity::find(),
+                params.query.as_ref(),
+                |query_builder, query| self.find_many_get_filters(query_builder, query),
+            ),
+            params.query.as_ref(),
+            ArticleColumn::CreatedAt,
+        )
+        .apply_if(show_only_approved_state, |query_builder, approved| {
+            query_builder.filter(ArticleColumn::Approved.eq(approved))
+        })
+        .limit(items_per_page)
+        .offset(leap)
+        .all(&self.sea_service.db)
+        .await?;
+
+        let articles_count = apply_filters(
+            ArticleEntity::find(),
+            params.query.as_ref(),
+            |query_builder, query| self.find_many_get_filters(query_builder, query),
+        )
+        .apply_if(show_only_approved_state, |query_builder, approved| {
+            query_builder.filter(ArticleColumn::Approved.eq(approved))
+        })
+        .count(&self.sea_service.db)
+        .await?;
 
         let mut articles: Vec<Article> = vec![];
 
@@ -123,9 +136,65 @@ impl ArticleRepositoryTrait for SeaArticleRepository<'_> {
         Ok(FindManyArticlesResponse(articles, articles_count))
     }
 
-    async fn get_home_articles(&self) -> Result<Vec<Article>, Box<dyn Error>> {
+    async fn find_many_by_cursor(
+        &self,
+        params: CursorParameters<ArticleQueryType, Uuid>,
+        show_only_approved_state: Option<bool>,
+    ) -> Result<Vec<Article>, Box<dyn Error>> {
+        let articles = apply_cursor(
+            params
+                .filters
+                .into_iter()
+                .fold(ArticleEntity::find(), |query_builder, query| {
+                    self.find_many_get_filters(query_builder, query)
+                }),
+            params.cursor.as_ref(),
+            ArticleColumn::CreatedAt,
+            ArticleColumn::Id,
+            params.items_per_page,
+        )
+        .apply_if(show_only_approved_state, |query_builder, approved| {
+            query_builder.filter(ArticleColumn::Approved.eq(approved))
+        })
+        .all(&self.sea_service.db)
+        .await?
+        .into_iter()
+        .map(SeaArticleMapper::model_into_entity)
+        .collect();
+
+        Ok(articles)
+    }
+
+    async fn find_pinned_articles(
+        &self,
+        now: DateTime,
+        limit: u64,
+    ) -> Result<Vec<Article>, Box<dyn Error>> {
         let articles = ArticleEntity::find()
-            .limit(3)
+            .filter(ArticleColumn::Approved.eq(true))
+            .filter(ArticleColumn::Pinned.eq(true))
+            .filter(
+                Condition::any()
+                    .add(ArticleColumn::PinnedUntil.is_null())
+                    .add(ArticleColumn::PinnedUntil.gt(now)),
+            )
+            .order_by_asc(ArticleColumn::PinOrder)
+            .order_by_desc(ArticleColumn::CreatedAt)
+            .limit(limit)
+            .all(&self.sea_service.db)
+            .await?
+            .into_iter()
+            .map(SeaArticleMapper::model_into_entity)
+            .collect();
+
+        Ok(articles)
+    }
+
+    async fn get_home_articles(&self, limit: u64) -> Result<Vec<Article>, Box<dyn Error>> {
+        let articles = ArticleEntity::find()
+            .filter(ArticleColumn::Approved.eq(true))
+            .limit(limit)
+            .order_by_desc(ArticleColumn::Featured)
             .order_by_desc(ArticleColumn::CreatedAt)
             .all(&self.sea_service.db)
             .await?;
@@ -139,6 +208,36 @@ impl ArticleRepositoryTrait for SeaArticleRepository<'_> {
         Ok(mapped_articles)
     }
 
+    async fn find_related_candidates(
+        &self,
+        article: &Article,
+        since: DateTime,
+        limit: u64,
+    ) -> Result<Vec<Article>, Box<dyn Error>> {
+        let articles = ArticleEntity::find()
+            .filter(ArticleColumn::Approved.eq(true))
+            .filter(ArticleColumn::Id.ne(article.id()))
+            .filter(
+                Condition::any()
+                    .add_option(
+                        article
+                            .tag_id()
+                            .map(|tag_id| ArticleColumn::TagId.eq(tag_id)),
+                    )
+                    .add(ArticleColumn::AuthorId.eq(article.author_id()))
+                    .add(ArticleColumn::CreatedAt.gte(since)),
+            )
+            .order_by_desc(ArticleColumn::CreatedAt)
+            .limit(limit)
+            .all(&self.sea_service.db)
+            .await?
+            .into_iter()
+            .map(SeaArticleMapper::model_into_entity)
+            .collect();
+
+        Ok(articles)
+    }
+
     async fn save(&self, article: Article) -> Result<Article, Box<dyn Error>> {
         let article_id = &article.id().clone();
 
@@ -170,6 +269,18 @@ impl SeaArticleRepository<'_> {
                 query_builder.filter(filter)
             }
             ArticleQueryType::Tag(tag_id) => query_builder.filter(ArticleColumn::TagId.eq(tag_id)),
+            ArticleQueryType::CreatedAt(range) => {
+                query_builder.filter(date_range_condition(ArticleColumn::CreatedAt, range))
+            }
+        }
+    }
+}
+
+impl SeaSortField<ArticleEntity> for ArticleSortField {
+    fn column(&self) -> ArticleColumn {
+        match self {
+            ArticleSortField::CreatedAt => ArticleColumn::CreatedAt,
+            ArticleSortField::Title => ArticleColumn::Title,
         }
     }
 }
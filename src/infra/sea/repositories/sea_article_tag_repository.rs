@@ -1,9 +1,7 @@
 use async_trait::async_trait;
 use migration::{Expr, Func};
 use sea_orm::{ActiveModelTrait, EntityTrait};
-use sea_orm::{
-    ColumnTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait, Select,
-};
+use sea_orm::{ColumnTrait, PaginatorTrait, QueryFilter, QuerySelect, Select};
 use std::error::Error;
 
 use crate::core::pagination::PaginationParameters;
@@ -11,10 +9,12 @@ use crate::domain::domain_entities::article_tag::ArticleTag;
 use crate::domain::domain_entities::article_tag::DraftArticleTag;
 use crate::infra::sea::mappers::sea_article_tag_mapper::SeaArticleTagMapper;
 use crate::infra::sea::mappers::SeaMapper;
+use crate::infra::sea::query_spec::{apply_filters, apply_sort, SeaSortField};
 use crate::infra::sea::sea_service::SeaService;
 
 use crate::domain::repositories::article_tag_repository::{
-    ArticleTagQueryType, ArticleTagRepositoryTrait, FindManyArticleTagsResponse,
+    ArticleTagQuery, ArticleTagQueryType, ArticleTagRepositoryTrait, ArticleTagSortField,
+    FindManyArticleTagsResponse,
 };
 use entities::article_tag::Column as ArticleTagColumn;
 use entities::article_tag::Entity as ArticleTagEntity;
@@ -73,7 +73,7 @@ impl ArticleTagRepositoryTrait for SeaArticleTagRepository<'_> {
 
     async fn find_many(
         &self,
-        params: PaginationParameters<ArticleTagQueryType>,
+        params: PaginationParameters<ArticleTagQuery>,
     ) -> Result<FindManyArticleTagsResponse, Box<dyn Error>> {
         let current_page = params.page as u64;
         let items_per_page = params.items_per_page as u64;
@@ -87,19 +87,20 @@ impl ArticleTagRepositoryTrait for SeaArticleTagRepository<'_> {
             query_builder.filter(filter)
         };
 
-        let article_tags_response = ArticleTagEntity::find()
-            .order_by_desc(ArticleTagColumn::Id)
-            .apply_if(params.clone().query, filter)
-            .limit(items_per_page)
-            .offset(leap)
-            .all(&self.sea_service.db)
-            .await?;
-
-        let article_tags_count = ArticleTagEntity::find()
-            .apply_if(params.query, filter)
-            .offset(leap)
-            .count(&self.sea_service.db)
-            .await?;
+        let article_tags_response = apply_sort(
+            apply_filters(ArticleTagEntity::find(), params.query.as_ref(), filter),
+            params.query.as_ref(),
+            ArticleTagColumn::Id,
+        )
+        .limit(items_per_page)
+        .offset(leap)
+        .all(&self.sea_service.db)
+        .await?;
+
+        let article_tags_count =
+            apply_filters(ArticleTagEntity::find(), params.query.as_ref(), filter)
+                .count(&self.sea_service.db)
+                .await?;
 
         let mut article_tags: Vec<ArticleTag> = vec![];
 
@@ -138,3 +139,12 @@ impl ArticleTagRepositoryTrait for SeaArticleTagRepository<'_> {
         Ok(())
     }
 }
+
+impl SeaSortField<ArticleTagEntity> for ArticleTagSortField {
+    fn column(&self) -> ArticleTagColumn {
+        match self {
+            ArticleTagSortField::Id => ArticleTagColumn::Id,
+            ArticleTagSortField::Value => ArticleTagColumn::Value,
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article_translation::ArticleTranslation;
+use crate::domain::domain_entities::locale::Locale;
+use crate::domain::domain_entities::slug::Slug;
+use crate::domain::repositories::article_translation_repository::ArticleTranslationRepositoryTrait;
+use crate::infra::sea::mappers::sea_article_translation_mapper::SeaArticleTranslationMapper;
+use crate::infra::sea::sea_service::SeaService;
+
+use entities::article_translation::Column as ArticleTranslationColumn;
+use entities::article_translation::Entity as ArticleTranslationEntity;
+
+pub struct SeaArticleTranslationRepository<'a> {
+    sea_service: &'a SeaService,
+}
+
+impl<'a> SeaArticleTranslationRepository<'a> {
+    pub fn new(sea_service: &'a SeaService) -> Self {
+        SeaArticleTranslationRepository { sea_service }
+    }
+}
+
+#[async_trait]
+impl ArticleTranslationRepositoryTrait for SeaArticleTranslationRepository<'_> {
+    async fn find_by_article_id_and_locale(
+        &self,
+        article_id: Uuid,
+        locale: Locale,
+    ) -> Result<Option<ArticleTranslation>, Box<dyn Error>> {
+        let translation = ArticleTranslationEntity::find_by_id((article_id, locale.code().into()))
+            .one(&self.sea_service.db)
+            .await?;
+
+        Ok(translation.and_then(SeaArticleTranslationMapper::model_into_entity))
+    }
+
+    async fn find_many_by_article_ids(
+        &self,
+        article_ids: Vec<Uuid>,
+    ) -> Result<Vec<ArticleTranslation>, Box<dyn Error>> {
+        if article_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let translations = ArticleTranslationEntity::find()
+            .filter(ArticleTranslationColumn::ArticleId.is_in(article_ids))
+            .all(&self.sea_service.db)
+            .await?
+            .into_iter()
+            .filter_map(SeaArticleTranslationMapper::model_into_entity)
+            .collect();
+
+        Ok(translations)
+    }
+
+    async fn find_by_slug(
+        &self,
+        slug: &Slug,
+    ) -> Result<Option<ArticleTranslation>, Box<dyn Error>> {
+        let translation = ArticleTranslationEntity::find()
+            .filter(ArticleTranslationColumn::Slug.eq(slug.to_string()))
+            .one(&self.sea_service.db)
+            .await?;
+
+        Ok(translation.and_then(SeaArticleTranslationMapper::model_into_entity))
+    }
+
+    async fn save(
+        &self,
+        translation: ArticleTranslation,
+    ) -> Result<ArticleTranslation, Box<dyn Error>> {
+        ArticleTranslationEntity::insert(SeaArticleTranslationMapper::entity_into_active_model(
+            translation.clone(),
+        ))
+        .on_conflict(
+            OnConflict::columns([
+                ArticleTranslationColumn::ArticleId,
+                ArticleTranslationColumn::Locale,
+            ])
+            .update_columns([
+                ArticleTranslationColumn::Title,
+                ArticleTranslationColumn::Content,
+                ArticleTranslationColumn::Slug,
+                ArticleTranslationColumn::UpdatedAt,
+            ])
+            .to_owned(),
+        )
+        .exec_without_returning(&self.sea_service.db)
+        .await?;
+
+        Ok(translation)
+    }
+
+    async fn delete(&self, translation: ArticleTranslation) -> Result<(), Box<dyn Error>> {
+        ArticleTranslationEntity::delete_by_id((
+            translation.article_id(),
+            translation.locale().code().to_owned(),
+        ))
+        .exec(&self.sea_service.db)
+        .await?;
+
+        Ok(())
+    }
+}
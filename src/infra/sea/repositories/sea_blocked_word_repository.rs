@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, EntityTrait, QueryOrder};
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::blocked_word::BlockedWord;
+use crate::domain::repositories::blocked_word_repository::BlockedWordRepositoryTrait;
+use crate::infra::sea::mappers::sea_blocked_word_mapper::SeaBlockedWordMapper;
+use crate::infra::sea::sea_service::SeaService;
+
+use entities::blocked_word::Column as BlockedWordColumn;
+use entities::blocked_word::Entity as BlockedWordEntity;
+
+pub struct SeaBlockedWordRepository<'a> {
+    sea_service: &'a SeaService,
+}
+
+impl<'a> SeaBlockedWordRepository<'a> {
+    pub fn new(sea_service: &'a SeaService) -> Self {
+        SeaBlockedWordRepository { sea_service }
+    }
+}
+
+#[async_trait]
+impl BlockedWordRepositoryTrait for SeaBlockedWordRepository<'_> {
+    async fn create(&self, blocked_word: BlockedWord) -> Result<BlockedWord, Box<dyn Error>> {
+        let model = SeaBlockedWordMapper::entity_into_active_model(blocked_word)
+            .insert(&self.sea_service.db)
+            .await?;
+
+        Ok(SeaBlockedWordMapper::model_into_entity(model)
+            .ok_or("Blocked word with an unsupported action.")?)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<BlockedWord>, Box<dyn Error>> {
+        let blocked_word = BlockedWordEntity::find_by_id(id)
+            .one(&self.sea_service.db)
+            .await?;
+
+        Ok(blocked_word.and_then(SeaBlockedWordMapper::model_into_entity))
+    }
+
+    async fn find_all(&self) -> Result<Vec<BlockedWord>, Box<dyn Error>> {
+        let blocked_words = BlockedWordEntity::find()
+            .order_by_asc(BlockedWordColumn::CreatedAt)
+            .all(&self.sea_service.db)
+            .await?;
+
+        Ok(blocked_words
+            .into_iter()
+            .filter_map(SeaBlockedWordMapper::model_into_entity)
+            .collect())
+    }
+
+    async fn save(&self, blocked_word: BlockedWord) -> Result<BlockedWord, Box<dyn Error>> {
+        let model = SeaBlockedWordMapper::entity_into_active_model(blocked_word)
+            .update(&self.sea_service.db)
+            .await?;
+
+        Ok(SeaBlockedWordMapper::model_into_entity(model)
+            .ok_or("Blocked word with an unsupported action.")?)
+    }
+
+    async fn delete(&self, blocked_word: BlockedWord) -> Result<(), Box<dyn Error>> {
+        BlockedWordEntity::delete_by_id(blocked_word.id())
+            .exec(&self.sea_service.db)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::comment_edit::CommentEdit;
+use crate::domain::repositories::comment_edit_repository::CommentEditRepositoryTrait;
+use crate::infra::sea::mappers::sea_comment_edit_mapper::SeaCommentEditMapper;
+use crate::infra::sea::mappers::SeaMapper;
+use crate::infra::sea::sea_service::SeaService;
+
+use entities::comment_edit::Column as CommentEditColumn;
+use entities::comment_edit::Entity as CommentEditEntity;
+
+pub struct SeaCommentEditRepository<'a> {
+    sea_service: &'a SeaService,
+}
+
+impl<'a> SeaCommentEditRepository<'a> {
+    pub fn new(sea_service: &'a SeaService) -> Self {
+        SeaCommentEditRepository { sea_service }
+    }
+}
+
+#[async_trait]
+impl CommentEditRepositoryTrait for SeaCommentEditRepository<'_> {
+    async fn create(&self, comment_edit: CommentEdit) -> Result<CommentEdit, Box<dyn Error>> {
+        let comment_edit = SeaCommentEditMapper::entity_into_active_model(comment_edit)
+            .insert(&self.sea_service.db)
+            .await?;
+
+        Ok(SeaCommentEditMapper::model_into_entity(comment_edit))
+    }
+
+    async fn find_many_by_comment_id(
+        &self,
+        comment_id: Uuid,
+    ) -> Result<Vec<CommentEdit>, Box<dyn Error>> {
+        let comment_edits = CommentEditEntity::find()
+            .filter(CommentEditColumn::CommentId.eq(comment_id))
+            .order_by_desc(CommentEditColumn::CreatedAt)
+            .all(&self.sea_service.db)
+            .await?;
+
+        Ok(comment_edits
+            .into_iter()
+            .map(SeaCommentEditMapper::model_into_entity)
+            .collect())
+    }
+}
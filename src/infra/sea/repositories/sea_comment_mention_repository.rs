@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ColumnTrait, EntityTrait, JoinType, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+    RelationTrait, TransactionTrait,
+};
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::core::pagination::PaginationParameters;
+use crate::domain::domain_entities::comment_mention::CommentMention;
+use crate::domain::repositories::comment_mention_repository::{
+    CommentMentionRepositoryTrait, FindManyCommentMentionsResponse,
+};
+use crate::infra::sea::mappers::sea_comment_mention_mapper::SeaCommentMentionMapper;
+use crate::infra::sea::mappers::SeaMapper;
+use crate::infra::sea::sea_service::SeaService;
+
+use entities::comment::Column as CommentColumn;
+use entities::comment_mention::Column as CommentMentionColumn;
+use entities::comment_mention::Entity as CommentMentionEntity;
+use entities::comment_mention::Relation as CommentMentionRelation;
+
+pub struct SeaCommentMentionRepository<'a> {
+    sea_service: &'a SeaService,
+}
+
+impl<'a> SeaCommentMentionRepository<'a> {
+    pub fn new(sea_service: &'a SeaService) -> Self {
+        SeaCommentMentionRepository { sea_service }
+    }
+}
+
+#[async_trait]
+impl CommentMentionRepositoryTrait for SeaCommentMentionRepository<'_> {
+    async fn set_mentions(
+        &self,
+        comment_id: Uuid,
+        mentions: Vec<CommentMention>,
+    ) -> Result<(), Box<dyn Error>> {
+        let transaction = self.sea_service.db.begin().await?;
+
+        CommentMentionEntity::delete_many()
+            .filter(CommentMentionColumn::CommentId.eq(comment_id))
+            .exec(&transaction)
+            .await?;
+
+        if !mentions.is_empty() {
+            let mentions = mentions
+                .into_iter()
+                .map(SeaCommentMentionMapper::entity_into_active_model);
+
+            CommentMentionEntity::insert_many(mentions)
+                .exec(&transaction)
+                .await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    async fn find_many_by_comment_ids(
+        &self,
+        comment_ids: Vec<Uuid>,
+    ) -> Result<Vec<CommentMention>, Box<dyn Error>> {
+        if comment_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mentions = CommentMentionEntity::find()
+            .filter(CommentMentionColumn::CommentId.is_in(comment_ids))
+            .order_by_asc(CommentMentionColumn::SpanStart)
+            .all(&self.sea_service.db)
+            .await?;
+
+        Ok(mentions
+            .into_iter()
+            .map(SeaCommentMentionMapper::model_into_entity)
+            .collect())
+    }
+
+    async fn find_many_by_user_id(
+        &self,
+        user_id: Uuid,
+        params: PaginationParameters<()>,
+    ) -> Result<FindManyCommentMentionsResponse, Box<dyn Error>> {
+        let items_per_page = params.items_per_page as u64;
+        let leap = (params.page as u64 - 1) * items_per_page;
+
+        let user_mentions = || {
+            CommentMentionEntity::find()
+                .join(JoinType::InnerJoin, CommentMentionRelation::Comment.def())
+                .filter(CommentMentionColumn::UserId.eq(user_id))
+                .filter(CommentColumn::IsActive.eq(true))
+        };
+
+        let mentions = user_mentions()
+            .order_by_desc(CommentMentionColumn::CreatedAt)
+            .limit(items_per_page)
+            .offset(leap)
+            .all(&self.sea_service.db)
+            .await?;
+
+        let mentions_count = user_mentions().count(&self.sea_service.db).await?;
+
+        Ok(FindManyCommentMentionsResponse(
+            mentions
+                .into_iter()
+                .map(SeaCommentMentionMapper::model_into_entity)
+                .collect(),
+            mentions_count,
+        ))
+    }
+}
@@ -1,20 +1,29 @@
 use async_trait::async_trait;
 use migration::{Expr, Func};
-use sea_orm::{ActiveModelTrait, EntityTrait};
-use sea_orm::{ColumnTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait};
+use sea_orm::{ActiveModelTrait, EntityTrait, TransactionTrait};
+use sea_orm::{ColumnTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect};
 use std::error::Error;
+use uuid::Uuid;
 
-use crate::core::pagination::PaginationParameters;
+use crate::core::pagination::{CursorParameters, PaginationParameters};
 use crate::domain::domain_entities::comment_report::CommentReport;
 use crate::domain::domain_entities::comment_report::CommentReportIdTrait;
+use crate::domain::domain_entities::comment_report::CommentReportTrait;
 use crate::domain::domain_entities::comment_report::DraftCommentReport;
+use crate::domain::domain_entities::comment_report::ReportResolution;
 use crate::domain::repositories::comment_report_repository::{
-    CommentReportQueryType, CommentReportRepositoryTrait, FindManyCommentReportsResponse,
+    CommentReportQuery, CommentReportQueryType, CommentReportRepositoryTrait,
+    CommentReportSortField, FindManyCommentReportsResponse,
 };
 use crate::infra::sea::mappers::sea_comment_report_mapper::SeaCommentReportMapper;
 use crate::infra::sea::mappers::SeaMapper;
+use crate::infra::sea::query_spec::{
+    apply_cursor, apply_filters, apply_sort, date_range_condition, SeaSortField,
+};
 use crate::infra::sea::sea_service::SeaService;
 
+use entities::comment::Column as CommentColumn;
+use entities::comment::Entity as CommentEntity;
 use entities::comment_report::Column as CommentReportColumn;
 use entities::comment_report::Entity as CommentReportEntity;
 
@@ -64,36 +73,34 @@ impl CommentReportRepositoryTrait for SeaCommentReportRepository<'_> {
 
     async fn find_many(
         &self,
-        params: PaginationParameters<CommentReportQueryType>,
+        params: PaginationParameters<CommentReportQuery>,
     ) -> Result<FindManyCommentReportsResponse, Box<dyn Error>> {
         let current_page = params.page as u64;
         let items_per_page = params.items_per_page as u64;
 
         let leap = (&current_page - 1) * items_per_page;
 
-        let comment_reports_response = CommentReportEntity::find()
-            .order_by_desc(CommentReportColumn::CreatedAt)
-            .apply_if(
-                params.clone().query,
-                |#[allow(unused_mut)] mut query_builder, query| {
-                    self.find_many_get_filters(query_builder, query)
-                },
-            )
-            .limit(items_per_page)
-            .offset(leap)
-            .all(&self.sea_service.db)
-            .await?;
+        let comment_reports_response = apply_sort(
+            apply_filters(
+                CommentReportEntity::find(),
+                params.query.as_ref(),
+                |query_builder, query| self.find_many_get_filters(query_builder, query),
+            ),
+            params.query.as_ref(),
+            CommentReportColumn::CreatedAt,
+        )
+        .limit(items_per_page)
+        .offset(leap)
+        .all(&self.sea_service.db)
+        .await?;
 
-        let comment_reports_count = CommentReportEntity::find()
-            .apply_if(
-                params.query,
-                |#[allow(unused_mut)] mut query_builder, query| {
-                    self.find_many_get_filters(query_builder, query)
-                },
-            )
-            .offset(leap)
-            .count(&self.sea_service.db)
-            .await?;
+        let comment_reports_count = apply_filters(
+            CommentReportEntity::find(),
+            params.query.as_ref(),
+            |query_builder, query| self.find_many_get_filters(query_builder, query),
+        )
+        .count(&self.sea_service.db)
+        .await?;
 
         let mut comment_reports: Vec<CommentReport> = vec![];
 
@@ -107,6 +114,63 @@ impl CommentReportRepositoryTrait for SeaCommentReportRepository<'_> {
         ))
     }
 
+    async fn find_many_by_cursor(
+        &self,
+        params: CursorParameters<CommentReportQueryType, i32>,
+    ) -> Result<Vec<CommentReport>, Box<dyn Error>> {
+        let comment_reports = apply_cursor(
+            params
+                .filters
+                .into_iter()
+                .fold(CommentReportEntity::find(), |query_builder, query| {
+                    self.find_many_get_filters(query_builder, query)
+                }),
+            params.cursor.as_ref(),
+            CommentReportColumn::CreatedAt,
+            CommentReportColumn::Id,
+            params.items_per_page,
+        )
+        .all(&self.sea_service.db)
+        .await?
+        .into_iter()
+        .map(SeaCommentReportMapper::model_into_entity)
+        .collect();
+
+        Ok(comment_reports)
+    }
+
+    async fn find_many_unsolved_by_comment_id(
+        &self,
+        comment_id: Uuid,
+    ) -> Result<Vec<CommentReport>, Box<dyn Error>> {
+        let comment_reports = CommentReportEntity::find()
+            .filter(CommentReportColumn::CommentId.eq(comment_id))
+            .filter(CommentReportColumn::SolvedBy.is_null())
+            .all(&self.sea_service.db)
+            .await?
+            .into_iter()
+            .map(SeaCommentReportMapper::model_into_entity)
+            .collect();
+
+        Ok(comment_reports)
+    }
+
+    async fn find_many_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<CommentReport>, Box<dyn Error>> {
+        let comment_reports = CommentReportEntity::find()
+            .filter(CommentReportColumn::UserId.eq(user_id))
+            .order_by_desc(CommentReportColumn::CreatedAt)
+            .all(&self.sea_service.db)
+            .await?
+            .into_iter()
+            .map(SeaCommentReportMapper::model_into_entity)
+            .collect();
+
+        Ok(comment_reports)
+    }
+
     async fn save(&self, comment_report: CommentReport) -> Result<CommentReport, Box<dyn Error>> {
         let comm_rep_id = comment_report.id();
 
@@ -122,6 +186,98 @@ impl CommentReportRepositoryTrait for SeaCommentReportRepository<'_> {
         Ok(comment_report)
     }
 
+    async fn save_many_hiding_comment(
+        &self,
+        comment_id: Uuid,
+        comment_reports: Vec<CommentReport>,
+    ) -> Result<Vec<CommentReport>, Box<dyn Error>> {
+        let transaction = self.sea_service.db.begin().await?;
+
+        CommentEntity::update_many()
+            .col_expr(CommentColumn::IsActive, Expr::value(false))
+            .filter(CommentColumn::Id.eq(comment_id))
+            .exec(&transaction)
+            .await?;
+
+        let mut saved_reports = Vec::with_capacity(comment_reports.len());
+
+        for comment_report in comment_reports {
+            let comm_rep_id = comment_report.id();
+
+            let comment_report = CommentReportEntity::update(
+                SeaCommentReportMapper::entity_into_active_model(comment_report),
+            )
+            .filter(CommentReportColumn::Id.eq(comm_rep_id))
+            .exec(&transaction)
+            .await?;
+
+            saved_reports.push(SeaCommentReportMapper::model_into_entity(comment_report));
+        }
+
+        transaction.commit().await?;
+
+        Ok(saved_reports)
+    }
+
+    async fn solve(
+        &self,
+        mut comment_report: CommentReport,
+    ) -> Result<CommentReport, Box<dyn Error>> {
+        let comm_rep_id = comment_report.id();
+        let comment_id = comment_report.comment_id();
+        let resolution = comment_report.resolution();
+
+        let transaction = self.sea_service.db.begin().await?;
+
+        CommentReportEntity::update(SeaCommentReportMapper::entity_into_active_model(
+            comment_report.clone(),
+        ))
+        .filter(CommentReportColumn::Id.eq(comm_rep_id))
+        .exec(&transaction)
+        .await?;
+
+        if let (Some(comment_id), Some(resolution)) = (comment_id, resolution) {
+            if resolution.acts_on_comment() {
+                CommentReportEntity::update_many()
+                    .col_expr(
+                        CommentReportColumn::SolvedBy,
+                        Expr::value(comment_report.solved_by()),
+                    )
+                    .col_expr(
+                        CommentReportColumn::Resolution,
+                        Expr::value(resolution.code()),
+                    )
+                    .filter(CommentReportColumn::CommentId.eq(comment_id))
+                    .filter(CommentReportColumn::SolvedBy.is_null())
+                    .exec(&transaction)
+                    .await?;
+            }
+
+            match resolution {
+                ReportResolution::CommentHidden => {
+                    CommentEntity::update_many()
+                        .col_expr(CommentColumn::IsActive, Expr::value(false))
+                        .filter(CommentColumn::Id.eq(comment_id))
+                        .exec(&transaction)
+                        .await?;
+                }
+                ReportResolution::CommentDeleted => {
+                    // the reports are kept, losing their comment
+                    CommentEntity::delete_by_id(comment_id)
+                        .exec(&transaction)
+                        .await?;
+
+                    comment_report.set_comment_id(None);
+                }
+                ReportResolution::Rejected => {}
+            }
+        }
+
+        transaction.commit().await?;
+
+        Ok(comment_report)
+    }
+
     async fn delete(&self, comment_report: CommentReport) -> Result<(), Box<dyn Error>> {
         let comment_report = SeaCommentReportMapper::entity_into_active_model(comment_report);
 
@@ -153,6 +309,24 @@ impl SeaCommentReportRepository<'_> {
                 let filter = CommentReportColumn::SolvedBy.is_null().eq(!solved);
                 query_builder.filter(filter)
             }
+            CommentReportQueryType::CreatedAt(range) => {
+                query_builder.filter(date_range_condition(CommentReportColumn::CreatedAt, range))
+            }
+            CommentReportQueryType::Reason(reason) => {
+                query_builder.filter(CommentReportColumn::Reason.eq(reason.code()))
+            }
+            CommentReportQueryType::AssignedTo(id) => {
+                query_builder.filter(CommentReportColumn::AssignedTo.eq(id))
+            }
+        }
+    }
+}
+
+impl SeaSortField<CommentReportEntity> for CommentReportSortField {
+    fn column(&self) -> CommentReportColumn {
+        match self {
+            CommentReportSortField::CreatedAt => CommentReportColumn::CreatedAt,
+            CommentReportSortField::Priority => CommentReportColumn::Priority,
         }
     }
 }
@@ -1,8 +1,11 @@
 use std::error::Error;
 
 use async_trait::async_trait;
-use sea_orm::EntityTrait;
-use sea_orm::{ActiveModelTrait, ModelTrait};
+use chrono::NaiveDateTime as DateTime;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, JoinType, ModelTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, RelationTrait,
+};
 use uuid::Uuid;
 
 use crate::domain::domain_entities::comment::Comment;
@@ -11,7 +14,11 @@ use crate::infra::sea::mappers::sea_comment_mapper::SeaCommentMapper;
 use crate::infra::sea::mappers::SeaMapper;
 use crate::infra::sea::sea_service::SeaService;
 
+use entities::comment::Column as CommentColumn;
 use entities::comment::Entity as CommentEntity;
+use entities::comment::Relation as CommentRelation;
+
+use entities::article::Column as ArticleColumn;
 
 pub struct SeaCommentRepository<'a> {
     sea_service: &'a SeaService,
@@ -44,6 +51,67 @@ impl CommentRepositoryTrait for SeaCommentRepository<'_> {
             .map(SeaCommentMapper::model_into_entity))
     }
 
+    async fn find_many_by_author_since(
+        &self,
+        author_id: Uuid,
+        since: DateTime,
+    ) -> Result<Vec<Comment>, Box<dyn Error>> {
+        let comments = CommentEntity::find()
+            .filter(CommentColumn::AuthorId.eq(author_id))
+            .filter(CommentColumn::CreatedAt.gte(since))
+            .all(&self.sea_service.db)
+            .await?;
+
+        Ok(comments
+            .into_iter()
+            .map(SeaCommentMapper::model_into_entity)
+            .collect())
+    }
+
+    async fn find_many_by_author(&self, author_id: Uuid) -> Result<Vec<Comment>, Box<dyn Error>> {
+        let comments = CommentEntity::find()
+            .filter(CommentColumn::AuthorId.eq(author_id))
+            .order_by_desc(CommentColumn::CreatedAt)
+            .all(&self.sea_service.db)
+            .await?;
+
+        Ok(comments
+            .into_iter()
+            .map(SeaCommentMapper::model_into_entity)
+            .collect())
+    }
+
+    async fn count_active_by_author(&self, author_id: Uuid) -> Result<u64, Box<dyn Error>> {
+        let count = CommentEntity::find()
+            .filter(CommentColumn::AuthorId.eq(author_id))
+            .filter(CommentColumn::IsActive.eq(true))
+            .count(&self.sea_service.db)
+            .await?;
+
+        Ok(count)
+    }
+
+    async fn find_recent_active_by_author(
+        &self,
+        author_id: Uuid,
+        limit: u64,
+    ) -> Result<Vec<Comment>, Box<dyn Error>> {
+        let comments = CommentEntity::find()
+            .join(JoinType::InnerJoin, CommentRelation::Article.def())
+            .filter(CommentColumn::AuthorId.eq(author_id))
+            .filter(CommentColumn::IsActive.eq(true))
+            .filter(ArticleColumn::Approved.eq(true))
+            .order_by_desc(CommentColumn::CreatedAt)
+            .limit(limit)
+            .all(&self.sea_service.db)
+            .await?;
+
+        Ok(comments
+            .into_iter()
+            .map(SeaCommentMapper::model_into_entity)
+            .collect())
+    }
+
     async fn delete(&self, comment: Comment) -> Result<(), Box<dyn Error>> {
         SeaCommentMapper::entity_into_model(comment)
             .delete(&self.sea_service.db)
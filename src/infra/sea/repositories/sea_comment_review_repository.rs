@@ -0,0 +1,191 @@
+use async_trait::async_trait;
+use migration::{Expr, Query};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, JoinType, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, RelationTrait, TransactionTrait,
+};
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::core::pagination::PaginationParameters;
+use crate::domain::domain_entities::comment_review::CommentReview;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::repositories::comment_review_repository::{
+    AuthorTrust, CommentReviewRepositoryTrait, FindManyPendingCommentsResponse,
+};
+use crate::infra::sea::mappers::sea_comment_mapper::SeaCommentMapper;
+use crate::infra::sea::mappers::sea_comment_review_mapper::SeaCommentReviewMapper;
+use crate::infra::sea::mappers::sea_role_mapper::SeaRoleMapper;
+use crate::infra::sea::mappers::SeaMapper;
+use crate::infra::sea::sea_service::SeaService;
+
+use entities::comment::Column as CommentColumn;
+use entities::comment::Entity as CommentEntity;
+use entities::comment::Relation as CommentRelation;
+use entities::comment_review::Column as CommentReviewColumn;
+use entities::comment_review::Entity as CommentReviewEntity;
+use entities::sea_orm_active_enums::Role as RoleModel;
+use entities::user::Column as UserColumn;
+use entities::user::Entity as UserEntity;
+
+pub struct SeaCommentReviewRepository<'a> {
+    sea_service: &'a SeaService,
+}
+
+impl<'a> SeaCommentReviewRepository<'a> {
+    pub fn new(sea_service: &'a SeaService) -> Self {
+        SeaCommentReviewRepository { sea_service }
+    }
+}
+
+#[async_trait]
+impl CommentReviewRepositoryTrait for SeaCommentReviewRepository<'_> {
+    async fn create(&self, comment_review: CommentReview) -> Result<CommentReview, Box<dyn Error>> {
+        let model = SeaCommentReviewMapper::entity_into_active_model(comment_review)
+            .insert(&self.sea_service.db)
+            .await?;
+
+        Ok(SeaCommentReviewMapper::model_into_entity(model))
+    }
+
+    async fn find_many_by_comment_ids(
+        &self,
+        comment_ids: Vec<Uuid>,
+    ) -> Result<Vec<CommentReview>, Box<dyn Error>> {
+        if comment_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let comment_reviews = CommentReviewEntity::find()
+            .filter(CommentReviewColumn::CommentId.is_in(comment_ids))
+            .all(&self.sea_service.db)
+            .await?;
+
+        Ok(comment_reviews
+            .into_iter()
+            .map(SeaCommentReviewMapper::model_into_entity)
+            .collect())
+    }
+
+    async fn find_many_pending_comments(
+        &self,
+        params: PaginationParameters<()>,
+    ) -> Result<FindManyPendingCommentsResponse, Box<dyn Error>> {
+        let items_per_page = params.items_per_page as u64;
+        let leap = (params.page as u64 - 1) * items_per_page;
+
+        let pending_comments = || {
+            CommentEntity::find()
+                .join(JoinType::InnerJoin, CommentRelation::CommentReview.def())
+                .filter(CommentReviewColumn::Approved.is_null())
+        };
+
+        let comments = pending_comments()
+            .order_by_asc(CommentReviewColumn::CreatedAt)
+            .limit(items_per_page)
+            .offset(leap)
+            .all(&self.sea_service.db)
+            .await?;
+
+        let comments_count = pending_comments().count(&self.sea_service.db).await?;
+
+        Ok(FindManyPendingCommentsResponse(
+            comments
+                .into_iter()
+                .map(SeaCommentMapper::model_into_entity)
+                .collect(),
+            comments_count,
+        ))
+    }
+
+    async fn author_has_approved_review(&self, author_id: Uuid) -> Result<bool, Box<dyn Error>> {
+        let approved_count = CommentReviewEntity::find()
+            .filter(CommentReviewColumn::AuthorId.eq(author_id))
+            .filter(CommentReviewColumn::Approved.eq(true))
+            .count(&self.sea_service.db)
+            .await?;
+
+        Ok(approved_count > 0)
+    }
+
+    async fn find_many_authors_trust(
+        &self,
+        author_ids: Vec<Uuid>,
+    ) -> Result<Vec<AuthorTrust>, Box<dyn Error>> {
+        if author_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let approved_review = Query::select()
+            .expr(Expr::val(1))
+            .from(CommentReviewEntity)
+            .and_where(
+                Expr::col((CommentReviewEntity, CommentReviewColumn::AuthorId))
+                    .equals((UserEntity, UserColumn::Id)),
+            )
+            .and_where(Expr::col((CommentReviewEntity, CommentReviewColumn::Approved)).eq(true))
+            .to_owned();
+
+        let authors = UserEntity::find()
+            .select_only()
+            .column(UserColumn::Id)
+            .column(UserColumn::Role)
+            .column_as(Expr::exists(approved_review), "has_approved_review")
+            .filter(UserColumn::Id.is_in(author_ids))
+            .into_tuple::<(Uuid, Option<RoleModel>, bool)>()
+            .all(&self.sea_service.db)
+            .await?;
+
+        Ok(authors
+            .into_iter()
+            .map(|(author_id, role, has_approved_review)| AuthorTrust {
+                author_id,
+                role: role.map(SeaRoleMapper::into_entity).unwrap_or(Role::User),
+                has_approved_review,
+            })
+            .collect())
+    }
+
+    async fn save(&self, comment_review: CommentReview) -> Result<CommentReview, Box<dyn Error>> {
+        let model = SeaCommentReviewMapper::entity_into_active_model(comment_review)
+            .update(&self.sea_service.db)
+            .await?;
+
+        Ok(SeaCommentReviewMapper::model_into_entity(model))
+    }
+
+    async fn save_many_publishing_approved(
+        &self,
+        comment_reviews: Vec<CommentReview>,
+    ) -> Result<Vec<CommentReview>, Box<dyn Error>> {
+        let approved_comment_ids = comment_reviews
+            .iter()
+            .filter(|review| review.approved() == Some(true))
+            .map(|review| review.comment_id())
+            .collect::<Vec<Uuid>>();
+
+        let transaction = self.sea_service.db.begin().await?;
+
+        let mut saved_reviews = Vec::with_capacity(comment_reviews.len());
+
+        for comment_review in comment_reviews {
+            let model = SeaCommentReviewMapper::entity_into_active_model(comment_review)
+                .update(&transaction)
+                .await?;
+
+            saved_reviews.push(SeaCommentReviewMapper::model_into_entity(model));
+        }
+
+        if !approved_comment_ids.is_empty() {
+            CommentEntity::update_many()
+                .col_expr(CommentColumn::IsActive, Expr::value(true))
+                .filter(CommentColumn::Id.is_in(approved_comment_ids))
+                .exec(&transaction)
+                .await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(saved_reviews)
+    }
+}
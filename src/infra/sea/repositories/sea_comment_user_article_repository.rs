@@ -1,23 +1,31 @@
+use std::collections::HashMap;
 use std::error::Error;
 
 use async_trait::async_trait;
 use migration::{Expr, Func};
+use sea_orm::sea_query::Query;
 use sea_orm::{
-    ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
+    ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
 };
 use uuid::Uuid;
 
-use crate::core::pagination::PaginationParameters;
+use crate::core::pagination::{CursorParameters, PaginationParameters};
+use crate::domain::domain_entities::comment_mention::CommentMention;
 use crate::domain::domain_entities::comment_with_author::CommentWithAuthor;
 use crate::domain::repositories::comment_user_article_repository::{
-    CommentUserArticleRepositoryTrait, CommentWithAuthorQueryType,
+    CommentUserArticleRepositoryTrait, CommentWithAuthorQuery, CommentWithAuthorQueryType,
     FindManyCommentsWithAuthorResponse,
 };
 use crate::infra::sea::mappers::sea_comment_with_author_mapper::SeaCommentWithAuthorMapper;
+use crate::infra::sea::query_spec::{
+    apply_cursor, apply_filters, apply_sort, date_range_condition,
+};
 use crate::infra::sea::sea_service::SeaService;
 
 use entities::comment::Column as CommentColumn;
 use entities::comment::Entity as CommentEntity;
+use entities::comment_mention::Column as CommentMentionColumn;
+use entities::comment_mention::Entity as CommentMentionEntity;
 
 use entities::user::Entity as UserEntity;
 
@@ -40,60 +48,198 @@ impl CommentUserArticleRepositoryTrait for SeaCommentUserArticleRepository<'_> {
         &self,
         article_id: Uuid,
         include_inactive: bool,
-        params: PaginationParameters<CommentWithAuthorQueryType>,
+        params: PaginationParameters<CommentWithAuthorQuery>,
+    ) -> Result<FindManyCommentsWithAuthorResponse, Box<dyn Error>> {
+        self.find_page(
+            Condition::all()
+                .add(CommentColumn::ArticleId.eq(article_id))
+                .add(CommentColumn::ParentId.is_null()),
+            include_inactive,
+            params,
+        )
+        .await
+    }
+
+    async fn find_many_comments_by_cursor(
+        &self,
+        article_id: Uuid,
+        include_inactive: bool,
+        params: CursorParameters<CommentWithAuthorQueryType, Uuid>,
+    ) -> Result<Vec<CommentWithAuthor>, Box<dyn Error>> {
+        let comments = apply_cursor(
+            params.filters.into_iter().fold(
+                CommentEntity::find()
+                    .filter(Self::visibility_condition(include_inactive))
+                    .filter(CommentColumn::ArticleId.eq(article_id))
+                    .filter(CommentColumn::ParentId.is_null()),
+                |query_builder, query| self.find_many_get_filters(query_builder, query),
+            ),
+            params.cursor.as_ref(),
+            CommentColumn::CreatedAt,
+            CommentColumn::Id,
+            params.items_per_page,
+        )
+        .find_also_related(UserEntity)
+        .all(&self.sea_service.db)
+        .await?
+        .into_iter()
+        .map(|(comment, author)| {
+            SeaCommentWithAuthorMapper::models_into_entity((comment, author.unwrap()))
+        })
+        .collect();
+
+        let comments = self.with_replies_count(comments, include_inactive).await?;
+
+        self.with_mentions(comments).await
+    }
+
+    async fn find_many_replies(
+        &self,
+        parent_id: Uuid,
+        include_inactive: bool,
+        params: PaginationParameters<CommentWithAuthorQuery>,
+    ) -> Result<FindManyCommentsWithAuthorResponse, Box<dyn Error>> {
+        self.find_page(
+            Condition::all().add(CommentColumn::ParentId.eq(parent_id)),
+            include_inactive,
+            params,
+        )
+        .await
+    }
+}
+
+impl SeaCommentUserArticleRepository<'_> {
+    async fn find_page(
+        &self,
+        scope: Condition,
+        include_inactive: bool,
+        params: PaginationParameters<CommentWithAuthorQuery>,
     ) -> Result<FindManyCommentsWithAuthorResponse, Box<dyn Error>> {
         let current_page = params.page as u64;
         let items_per_page = params.items_per_page as u64;
 
         let leap = (&current_page - 1) * items_per_page;
 
-        let include_inactive = Some(include_inactive);
-
-        let comments = CommentEntity::find()
-            .apply_if(include_inactive, |query_builder, val| {
-                if !val {
-                    query_builder.filter(CommentColumn::IsActive.eq(true))
-                } else {
-                    query_builder
-                }
-            })
-            .filter(CommentColumn::ArticleId.eq(article_id))
-            .apply_if(params.clone().query, |query_builder, query| {
-                self.find_many_get_filters(query_builder, query)
-            })
-            .order_by_desc(CommentColumn::CreatedAt)
-            .find_also_related(UserEntity)
-            .limit(items_per_page)
-            .offset(leap)
-            .all(&self.sea_service.db)
-            .await?;
-
-        let comments_count = CommentEntity::find()
-            .filter(CommentColumn::ArticleId.eq(article_id))
-            .apply_if(params.clone().query, |query_builder, query| {
-                self.find_many_get_filters(query_builder, query)
-            })
-            .offset(leap)
-            .count(&self.sea_service.db)
-            .await?;
-
-        let mut mapped_comments: Vec<CommentWithAuthor> = vec![];
-
-        for models in comments {
-            mapped_comments.push(SeaCommentWithAuthorMapper::models_into_entity((
-                models.0,
-                models.1.unwrap(),
-            )))
-        }
+        let scoped_comments = || {
+            apply_filters(
+                CommentEntity::find()
+                    .filter(Self::visibility_condition(include_inactive))
+                    .filter(scope.clone()),
+                params.query.as_ref(),
+                |query_builder, query| self.find_many_get_filters(query_builder, query),
+            )
+        };
+
+        let comments = apply_sort(
+            scoped_comments(),
+            params.query.as_ref(),
+            CommentColumn::CreatedAt,
+        )
+        .find_also_related(UserEntity)
+        .limit(items_per_page)
+        .offset(leap)
+        .all(&self.sea_service.db)
+        .await?
+        .into_iter()
+        .map(|(comment, author)| {
+            SeaCommentWithAuthorMapper::models_into_entity((comment, author.unwrap()))
+        })
+        .collect();
+
+        let comments_count = scoped_comments().count(&self.sea_service.db).await?;
+
+        let comments = self.with_replies_count(comments, include_inactive).await?;
 
         Ok(FindManyCommentsWithAuthorResponse(
-            mapped_comments,
+            self.with_mentions(comments).await?,
             comments_count,
         ))
     }
-}
 
-impl SeaCommentUserArticleRepository<'_> {
+    /// Inactive comments are hidden, unless some active reply hangs on them.
+    fn visibility_condition(include_inactive: bool) -> Condition {
+        if include_inactive {
+            return Condition::all();
+        }
+
+        Condition::any().add(CommentColumn::IsActive.eq(true)).add(
+            CommentColumn::Id.in_subquery(
+                Query::select()
+                    .column(CommentColumn::ParentId)
+                    .from(CommentEntity)
+                    .and_where(CommentColumn::ParentId.is_not_null())
+                    .and_where(CommentColumn::IsActive.eq(true))
+                    .to_owned(),
+            ),
+        )
+    }
+
+    async fn with_mentions(
+        &self,
+        mut comments: Vec<CommentWithAuthor>,
+    ) -> Result<Vec<CommentWithAuthor>, Box<dyn Error>> {
+        if comments.is_empty() {
+            return Ok(comments);
+        }
+
+        let mut mentions: HashMap<Uuid, Vec<CommentMention>> = HashMap::new();
+
+        for mention in CommentMentionEntity::find()
+            .filter(
+                CommentMentionColumn::CommentId.is_in(comments.iter().map(|comment| comment.id())),
+            )
+            .order_by_asc(CommentMentionColumn::SpanStart)
+            .all(&self.sea_service.db)
+            .await?
+        {
+            mentions.entry(mention.comment_id).or_default().push(
+                CommentMention::new_from_existing(
+                    mention.comment_id,
+                    mention.user_id,
+                    mention.span_start as u32,
+                    mention.span_end as u32,
+                    mention.created_at,
+                ),
+            );
+        }
+
+        for comment in comments.iter_mut() {
+            comment.set_mentions(mentions.remove(&comment.id()).unwrap_or_default());
+        }
+
+        Ok(comments)
+    }
+
+    async fn with_replies_count(
+        &self,
+        mut comments: Vec<CommentWithAuthor>,
+        include_inactive: bool,
+    ) -> Result<Vec<CommentWithAuthor>, Box<dyn Error>> {
+        if comments.is_empty() {
+            return Ok(comments);
+        }
+
+        let replies_count: HashMap<Uuid, i64> = CommentEntity::find()
+            .select_only()
+            .column(CommentColumn::ParentId)
+            .column_as(CommentColumn::Id.count(), "replies_count")
+            .filter(Self::visibility_condition(include_inactive))
+            .filter(CommentColumn::ParentId.is_in(comments.iter().map(|comment| comment.id())))
+            .group_by(CommentColumn::ParentId)
+            .into_tuple::<(Uuid, i64)>()
+            .all(&self.sea_service.db)
+            .await?
+            .into_iter()
+            .collect();
+
+        for comment in comments.iter_mut() {
+            let count = replies_count.get(&comment.id()).copied().unwrap_or(0);
+            comment.set_replies_count(count as u64);
+        }
+
+        Ok(comments)
+    }
+
     fn find_many_get_filters(
         &self,
         query_builder: sea_orm::Select<CommentEntity>,
@@ -109,6 +255,9 @@ impl SeaCommentUserArticleRepository<'_> {
                     .like(format!("%{}%", content.to_lowercase()));
                 query_builder.filter(filter)
             }
+            CommentWithAuthorQueryType::CreatedAt(range) => {
+                query_builder.filter(date_range_condition(CommentColumn::CreatedAt, range))
+            }
         }
     }
 }
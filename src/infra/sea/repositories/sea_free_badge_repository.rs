@@ -1,7 +1,7 @@
 use crate::core::pagination::PaginationParameters;
 use crate::domain::domain_entities::free_badge::FreeBadge;
 use crate::domain::repositories::free_badge_repository::{
-    FindManyFreeBadgesResponse, FreeBadgeRepositoryTrait,
+    FindManyFreeBadgesResponse, FreeBadgeQuery, FreeBadgeRepositoryTrait,
 };
 use crate::infra::sea::mappers::sea_free_badge_mapper::SeaFreeBadgeMapper;
 use crate::infra::sea::mappers::SeaMapper;
@@ -9,7 +9,10 @@ use crate::infra::sea::sea_service::SeaService;
 use async_trait::async_trait;
 use entities::free_badge::Column as FreeBadgeColumn;
 use entities::free_badge::Entity as FreeBadgeEntity;
-use sea_orm::{ActiveModelTrait, EntityTrait, PaginatorTrait, QueryOrder, QuerySelect};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect,
+};
 use std::error::Error;
 use uuid::Uuid;
 
@@ -52,24 +55,32 @@ impl FreeBadgeRepositoryTrait for SeaFreeBadgeRepository<'_> {
 
     async fn find_many(
         &self,
-        params: PaginationParameters<()>,
+        params: PaginationParameters<FreeBadgeQuery>,
     ) -> Result<FindManyFreeBadgesResponse, Box<dyn Error>> {
         let current_page = params.page as u64;
         let items_per_page = params.items_per_page as u64;
 
         let leap = (&current_page - 1) * items_per_page;
 
-        let badges = FreeBadgeEntity::find()
+        let mut query = FreeBadgeEntity::find();
+
+        if let Some(FreeBadgeQuery::AvailableAt(moment)) = params.query {
+            query = query.filter(
+                Condition::any()
+                    .add(FreeBadgeColumn::AvailableUntil.is_null())
+                    .add(FreeBadgeColumn::AvailableUntil.gt(moment)),
+            );
+        }
+
+        let badges = query
+            .clone()
             .order_by_desc(FreeBadgeColumn::CreatedAt)
             .limit(items_per_page)
             .offset(leap)
             .all(&self.sea_service.db)
             .await?;
 
-        let badges_count = FreeBadgeEntity::find()
-            .offset(leap)
-            .count(&self.sea_service.db)
-            .await?;
+        let badges_count = query.count(&self.sea_service.db).await?;
 
         let mapped_badges: Vec<FreeBadge> = badges
             .into_iter()
@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter};
+use std::error::Error;
+
+use crate::domain::domain_entities::imported_content::ImportedContent;
+use crate::domain::repositories::imported_content_repository::ImportedContentRepositoryTrait;
+use crate::infra::sea::mappers::sea_imported_content_mapper::SeaImportedContentMapper;
+use crate::infra::sea::sea_service::SeaService;
+
+use entities::imported_content::Column as ImportedContentColumn;
+use entities::imported_content::Entity as ImportedContentEntity;
+
+pub struct SeaImportedContentRepository<'a> {
+    sea_service: &'a SeaService,
+}
+
+impl<'a> SeaImportedContentRepository<'a> {
+    pub fn new(sea_service: &'a SeaService) -> Self {
+        SeaImportedContentRepository { sea_service }
+    }
+}
+
+#[async_trait]
+impl ImportedContentRepositoryTrait for SeaImportedContentRepository<'_> {
+    async fn create(
+        &self,
+        imported_content: ImportedContent,
+    ) -> Result<ImportedContent, Box<dyn Error>> {
+        let active_model = SeaImportedContentMapper::entity_into_active_model(&imported_content);
+
+        active_model.insert(&self.sea_service.db).await?;
+
+        Ok(imported_content)
+    }
+
+    async fn find_many_by_source(
+        &self,
+        source: String,
+    ) -> Result<Vec<ImportedContent>, Box<dyn Error>> {
+        let models = ImportedContentEntity::find()
+            .filter(ImportedContentColumn::Source.eq(source))
+            .all(&self.sea_service.db)
+            .await?;
+
+        Ok(models
+            .into_iter()
+            .filter_map(SeaImportedContentMapper::model_into_entity)
+            .collect())
+    }
+}
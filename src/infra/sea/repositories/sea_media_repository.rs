@@ -0,0 +1,244 @@
+use async_trait::async_trait;
+use migration::{Expr, Func, Query};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Select, TransactionTrait,
+};
+use std::collections::HashMap;
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::core::pagination::PaginationParameters;
+use crate::domain::domain_entities::media::{Media, MediaUsage};
+use crate::domain::repositories::media_repository::{
+    FindManyMediaResponse, MediaQuery, MediaQueryType, MediaRepositoryTrait, MediaSortField,
+};
+use crate::infra::sea::mappers::sea_media_mapper::SeaMediaMapper;
+use crate::infra::sea::query_spec::{
+    apply_filters, apply_sort, date_range_condition, SeaSortField,
+};
+use crate::infra::sea::sea_service::SeaService;
+
+use entities::announcement::Column as AnnouncementColumn;
+use entities::announcement::Entity as AnnouncementEntity;
+use entities::article::Column as ArticleColumn;
+use entities::article::Entity as ArticleEntity;
+use entities::free_badge::Column as FreeBadgeColumn;
+use entities::free_badge::Entity as FreeBadgeEntity;
+use entities::media::Column as MediaColumn;
+use entities::media::Entity as MediaEntity;
+use entities::media_tag::Column as MediaTagColumn;
+use entities::media_tag::Entity as MediaTagEntity;
+
+pub struct SeaMediaRepository<'a> {
+    sea_service: &'a SeaService,
+}
+
+impl<'a> SeaMediaRepository<'a> {
+    pub fn new(sea_service: &'a SeaService) -> Self {
+        SeaMediaRepository { sea_service }
+    }
+
+    async fn find_tags(
+        &self,
+        media_ids: Vec<Uuid>,
+    ) -> Result<HashMap<Uuid, Vec<String>>, Box<dyn Error>> {
+        let mut tags: HashMap<Uuid, Vec<String>> = HashMap::new();
+
+        if media_ids.is_empty() {
+            return Ok(tags);
+        }
+
+        let tag_models = MediaTagEntity::find()
+            .filter(MediaTagColumn::MediaId.is_in(media_ids))
+            .order_by_asc(MediaTagColumn::Tag)
+            .all(&self.sea_service.db)
+            .await?;
+
+        for tag in tag_models {
+            tags.entry(tag.media_id).or_default().push(tag.tag);
+        }
+
+        Ok(tags)
+    }
+
+    /// Inserts or updates the media, replacing its tags.
+    async fn persist(&self, media: Media, is_new: bool) -> Result<Media, Box<dyn Error>> {
+        let transaction = self.sea_service.db.begin().await?;
+
+        let active_model = SeaMediaMapper::entity_into_active_model(&media);
+
+        if is_new {
+            active_model.insert(&transaction).await?;
+        } else {
+            active_model.update(&transaction).await?;
+        }
+
+        MediaTagEntity::delete_many()
+            .filter(MediaTagColumn::MediaId.eq(media.id()))
+            .exec(&transaction)
+            .await?;
+
+        if !media.tags().is_empty() {
+            MediaTagEntity::insert_many(SeaMediaMapper::tags_into_active_models(&media))
+                .exec(&transaction)
+                .await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(media)
+    }
+}
+
+#[async_trait]
+impl MediaRepositoryTrait for SeaMediaRepository<'_> {
+    async fn create(&self, media: Media) -> Result<Media, Box<dyn Error>> {
+        self.persist(media, true).await
+    }
+
+    async fn save(&self, media: Media) -> Result<Media, Box<dyn Error>> {
+        self.persist(media, false).await
+    }
+
+    async fn delete(&self, media: Media) -> Result<(), Box<dyn Error>> {
+        MediaEntity::delete_by_id(media.id())
+            .exec(&self.sea_service.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Media>, Box<dyn Error>> {
+        let model = MediaEntity::find_by_id(id)
+            .one(&self.sea_service.db)
+            .await?;
+
+        match model {
+            None => Ok(None),
+            Some(model) => {
+                let mut tags = self.find_tags(vec![model.id]).await?;
+                let tags = tags.remove(&model.id).unwrap_or_default();
+
+                Ok(Some(SeaMediaMapper::model_into_entity(model, tags)))
+            }
+        }
+    }
+
+    async fn find_many(
+        &self,
+        params: PaginationParameters<MediaQuery>,
+    ) -> Result<FindManyMediaResponse, Box<dyn Error>> {
+        let current_page = params.page as u64;
+        let items_per_page = params.items_per_page as u64;
+
+        let leap = (current_page - 1) * items_per_page;
+
+        let filter = |query_builder: Select<MediaEntity>, query: MediaQueryType| match query {
+            MediaQueryType::Text(text) => {
+                let pattern = format!("%{}%", text.to_lowercase());
+
+                query_builder.filter(
+                    Condition::any()
+                        .add(
+                            Expr::expr(Func::lower(Expr::col(MediaColumn::FileName)))
+                                .like(pattern.clone()),
+                        )
+                        .add(
+                            Expr::expr(Func::lower(Expr::col(MediaColumn::AltText))).like(pattern),
+                        ),
+                )
+            }
+            MediaQueryType::Tag(tag) => query_builder.filter(
+                MediaColumn::Id.in_subquery(
+                    Query::select()
+                        .column(MediaTagColumn::MediaId)
+                        .from(MediaTagEntity)
+                        .and_where(MediaTagColumn::Tag.eq(tag.trim().to_lowercase()))
+                        .to_owned(),
+                ),
+            ),
+            MediaQueryType::Uploader(uploader_id) => {
+                query_builder.filter(MediaColumn::UploaderId.eq(uploader_id))
+            }
+            MediaQueryType::CreatedAt(range) => {
+                query_builder.filter(date_range_condition(MediaColumn::CreatedAt, range))
+            }
+        };
+
+        let models = apply_sort(
+            apply_filters(MediaEntity::find(), params.query.as_ref(), filter),
+            params.query.as_ref(),
+            MediaColumn::CreatedAt,
+        )
+        .limit(items_per_page)
+        .offset(leap)
+        .all(&self.sea_service.db)
+        .await?;
+
+        let media_count = apply_filters(MediaEntity::find(), params.query.as_ref(), filter)
+            .count(&self.sea_service.db)
+            .await?;
+
+        let mut tags = self
+            .find_tags(models.iter().map(|model| model.id).collect())
+            .await?;
+
+        let media = models
+            .into_iter()
+            .map(|model| {
+                let media_tags = tags.remove(&model.id).unwrap_or_default();
+                SeaMediaMapper::model_into_entity(model, media_tags)
+            })
+            .collect();
+
+        Ok(FindManyMediaResponse(media, media_count))
+    }
+
+    async fn find_usages(&self, url: &str) -> Result<Vec<MediaUsage>, Box<dyn Error>> {
+        let articles = ArticleEntity::find()
+            .filter(
+                Condition::any()
+                    .add(ArticleColumn::CoverUrl.eq(url))
+                    .add(ArticleColumn::Content.contains(url)),
+            )
+            .all(&self.sea_service.db)
+            .await?
+            .into_iter()
+            .map(|article| MediaUsage::Article {
+                id: article.id,
+                title: article.title,
+            });
+
+        let announcements = AnnouncementEntity::find()
+            .filter(AnnouncementColumn::Image.eq(url))
+            .all(&self.sea_service.db)
+            .await?
+            .into_iter()
+            .map(|announcement| MediaUsage::Announcement {
+                id: announcement.id,
+            });
+
+        let free_badges = FreeBadgeEntity::find()
+            .filter(FreeBadgeColumn::Image.eq(url))
+            .all(&self.sea_service.db)
+            .await?
+            .into_iter()
+            .map(|free_badge| MediaUsage::FreeBadge {
+                id: free_badge.id,
+                code: free_badge.code,
+            });
+
+        Ok(articles.chain(announcements).chain(free_badges).collect())
+    }
+}
+
+impl SeaSortField<MediaEntity> for MediaSortField {
+    fn column(&self) -> MediaColumn {
+        match self {
+            MediaSortField::CreatedAt => MediaColumn::CreatedAt,
+            MediaSortField::FileName => MediaColumn::FileName,
+            MediaSortField::Size => MediaColumn::Size,
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use async_trait::async_trait;
+use migration::{Expr, Query};
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{
+    ColumnTrait, Condition, DatabaseTransaction, DbErr, EntityTrait, IntoActiveValue, QueryFilter,
+    QuerySelect, TransactionTrait,
+};
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::reaction::{Reaction, ReactionTarget};
+use crate::domain::repositories::reaction_repository::ReactionRepositoryTrait;
+use crate::infra::sea::mappers::sea_reaction_mapper::SeaReactionMapper;
+use crate::infra::sea::sea_service::SeaService;
+
+use entities::reaction::Column as ReactionColumn;
+use entities::reaction::Entity as ReactionEntity;
+use entities::reaction_count::ActiveModel as ReactionCountActiveModel;
+use entities::reaction_count::Column as ReactionCountColumn;
+use entities::reaction_count::Entity as ReactionCountEntity;
+
+use entities::article::Column as ArticleColumn;
+use entities::article::Entity as ArticleEntity;
+use entities::comment::Column as CommentColumn;
+use entities::comment::Entity as CommentEntity;
+
+pub struct SeaReactionRepository<'a> {
+    sea_service: &'a SeaService,
+}
+
+impl<'a> SeaReactionRepository<'a> {
+    pub fn new(sea_service: &'a SeaService) -> Self {
+        SeaReactionRepository { sea_service }
+    }
+}
+
+async fn increment_count(
+    transaction: &DatabaseTransaction,
+    target: ReactionTarget,
+    target_id: Uuid,
+    reaction: &str,
+) -> Result<(), DbErr> {
+    let count = ReactionCountActiveModel {
+        target: target.code().to_owned().into_active_value(),
+        target_id: target_id.into_active_value(),
+        kind: reaction.to_owned().into_active_value(),
+        amount: 1.into_active_value(),
+    };
+
+    ReactionCountEntity::insert(count)
+        .on_conflict(
+            OnConflict::columns([
+                ReactionCountColumn::Target,
+                ReactionCountColumn::TargetId,
+                ReactionCountColumn::Kind,
+            ])
+            .value(
+                ReactionCountColumn::Amount,
+                Expr::col((ReactionCountEntity, ReactionCountColumn::Amount)).add(1),
+            )
+            .to_owned(),
+        )
+        .exec(transaction)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn decrement_count(
+    transaction: &DatabaseTransaction,
+    target: ReactionTarget,
+    target_id: Uuid,
+    reaction: &str,
+) -> Result<(), DbErr> {
+    ReactionCountEntity::update_many()
+        .col_expr(
+            ReactionCountColumn::Amount,
+            Expr::col(ReactionCountColumn::Amount).sub(1),
+        )
+        .filter(ReactionCountColumn::Target.eq(target.code()))
+        .filter(ReactionCountColumn::TargetId.eq(target_id))
+        .filter(ReactionCountColumn::Kind.eq(reaction))
+        .filter(ReactionCountColumn::Amount.gt(0))
+        .exec(transaction)
+        .await?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl ReactionRepositoryTrait for SeaReactionRepository<'_> {
+    async fn find(
+        &self,
+        user_id: Uuid,
+        target: ReactionTarget,
+        target_id: Uuid,
+    ) -> Result<Option<Reaction>, Box<dyn Error>> {
+        let reaction = ReactionEntity::find_by_id((user_id, target.code().to_owned(), target_id))
+            .one(&self.sea_service.db)
+            .await?;
+
+        Ok(reaction.and_then(SeaReactionMapper::model_into_entity))
+    }
+
+    async fn set(&self, reaction: Reaction) -> Result<(), Box<dyn Error>> {
+        let transaction = self.sea_service.db.begin().await?;
+
+        let previous = ReactionEntity::find_by_id((
+            reaction.user_id(),
+            reaction.target().code().to_owned(),
+            reaction.target_id(),
+        ))
+        .one(&transaction)
+        .await?;
+
+        if let Some(previous) = previous {
+            if previous.kind == reaction.kind() {
+                transaction.commit().await?;
+
+                return Ok(());
+            }
+
+            decrement_count(
+                &transaction,
+                reaction.target(),
+                reaction.target_id(),
+                &previous.kind,
+            )
+            .await?;
+
+            ReactionEntity::delete_by_id((previous.user_id, previous.target, previous.target_id))
+                .exec(&transaction)
+                .await?;
+        }
+
+        increment_count(
+            &transaction,
+            reaction.target(),
+            reaction.target_id(),
+            reaction.kind(),
+        )
+        .await?;
+
+        ReactionEntity::insert(SeaReactionMapper::entity_into_active_model(reaction))
+            .exec(&transaction)
+            .await?;
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    async fn remove(
+        &self,
+        user_id: Uuid,
+        target: ReactionTarget,
+        target_id: Uuid,
+    ) -> Result<(), Box<dyn Error>> {
+        let transaction = self.sea_service.db.begin().await?;
+
+        let previous = ReactionEntity::find_by_id((user_id, target.code().to_owned(), target_id))
+            .one(&transaction)
+            .await?;
+
+        if let Some(previous) = previous {
+            decrement_count(&transaction, target, target_id, &previous.kind).await?;
+
+            ReactionEntity::delete_by_id((previous.user_id, previous.target, previous.target_id))
+                .exec(&transaction)
+                .await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    async fn find_counts(
+        &self,
+        target: ReactionTarget,
+        target_ids: Vec<Uuid>,
+    ) -> Result<HashMap<Uuid, BTreeMap<String, u64>>, Box<dyn Error>> {
+        if target_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let counts = ReactionCountEntity::find()
+            .filter(ReactionCountColumn::Target.eq(target.code()))
+            .filter(ReactionCountColumn::TargetId.is_in(target_ids))
+            .filter(ReactionCountColumn::Amount.gt(0))
+            .all(&self.sea_service.db)
+            .await?;
+
+        let mut counts_by_target: HashMap<Uuid, BTreeMap<String, u64>> = HashMap::new();
+
+        for count in counts {
+            counts_by_target
+                .entry(count.target_id)
+                .or_default()
+                .insert(count.kind, count.amount as u64);
+        }
+
+        Ok(counts_by_target)
+    }
+
+    async fn find_many_by_user_id(
+        &self,
+        user_id: Uuid,
+        target: ReactionTarget,
+        target_ids: Vec<Uuid>,
+    ) -> Result<Vec<Reaction>, Box<dyn Error>> {
+        if target_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let reactions = ReactionEntity::find()
+            .filter(ReactionColumn::UserId.eq(user_id))
+            .filter(ReactionColumn::Target.eq(target.code()))
+            .filter(ReactionColumn::TargetId.is_in(target_ids))
+            .all(&self.sea_service.db)
+            .await?;
+
+        Ok(reactions
+            .into_iter()
+            .filter_map(SeaReactionMapper::model_into_entity)
+            .collect())
+    }
+
+    async fn count_given_by_user(
+        &self,
+        user_id: Uuid,
+    ) -> Result<BTreeMap<String, u64>, Box<dyn Error>> {
+        let counts = ReactionEntity::find()
+            .select_only()
+            .column(ReactionColumn::Kind)
+            .column_as(ReactionColumn::TargetId.count(), "amount")
+            .filter(ReactionColumn::UserId.eq(user_id))
+            .group_by(ReactionColumn::Kind)
+            .into_tuple::<(String, i64)>()
+            .all(&self.sea_service.db)
+            .await?;
+
+        Ok(counts
+            .into_iter()
+            .map(|(kind, amount)| (kind, amount as u64))
+            .collect())
+    }
+
+    async fn count_received_by_author(
+        &self,
+        author_id: Uuid,
+    ) -> Result<BTreeMap<String, u64>, Box<dyn Error>> {
+        let authored_articles = Query::select()
+            .column(ArticleColumn::Id)
+            .from(ArticleEntity)
+            .and_where(ArticleColumn::AuthorId.eq(author_id))
+            .and_where(ArticleColumn::Approved.eq(true))
+            .to_owned();
+
+        let authored_comments = Query::select()
+            .column(CommentColumn::Id)
+            .from(CommentEntity)
+            .and_where(CommentColumn::AuthorId.eq(author_id))
+            .and_where(CommentColumn::IsActive.eq(true))
+            .to_owned();
+
+        let counts = ReactionEntity::find()
+            .select_only()
+            .column(ReactionColumn::Kind)
+            .column_as(ReactionColumn::UserId.count(), "amount")
+            .filter(
+                Condition::any()
+                    .add(
+                        Condition::all()
+                            .add(ReactionColumn::Target.eq(ReactionTarget::Article.code()))
+                            .add(ReactionColumn::TargetId.in_subquery(authored_articles)),
+                    )
+                    .add(
+                        Condition::all()
+                            .add(ReactionColumn::Target.eq(ReactionTarget::Comment.code()))
+                            .add(ReactionColumn::TargetId.in_subquery(authored_comments)),
+                    ),
+            )
+            .group_by(ReactionColumn::Kind)
+            .into_tuple::<(String, i64)>()
+            .all(&self.sea_service.db)
+            .await?;
+
+        Ok(counts
+            .into_iter()
+            .map(|(kind, amount)| (kind, amount as u64))
+            .collect())
+    }
+}
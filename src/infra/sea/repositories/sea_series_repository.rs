@@ -0,0 +1,141 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, IntoActiveValue, QueryFilter, QueryOrder,
+    QueryTrait, TransactionTrait,
+};
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::series::Series;
+use crate::domain::domain_entities::slug::Slug;
+use crate::domain::repositories::series_repository::SeriesRepositoryTrait;
+use crate::infra::sea::mappers::sea_article_mapper::SeaArticleMapper;
+use crate::infra::sea::mappers::sea_series_mapper::SeaSeriesMapper;
+use crate::infra::sea::mappers::SeaMapper;
+use crate::infra::sea::sea_service::SeaService;
+
+use entities::article::Column as ArticleColumn;
+use entities::article::Entity as ArticleEntity;
+use entities::series::Column as SeriesColumn;
+use entities::series::Entity as SeriesEntity;
+use entities::series_article::ActiveModel as SeriesArticleActiveModel;
+use entities::series_article::Column as SeriesArticleColumn;
+use entities::series_article::Entity as SeriesArticleEntity;
+
+pub struct SeaSeriesRepository<'a> {
+    sea_service: &'a SeaService,
+}
+
+impl<'a> SeaSeriesRepository<'a> {
+    pub fn new(sea_service: &'a SeaService) -> Self {
+        SeaSeriesRepository { sea_service }
+    }
+}
+
+#[async_trait]
+impl SeriesRepositoryTrait for SeaSeriesRepository<'_> {
+    async fn create(&self, series: Series) -> Result<Series, Box<dyn Error>> {
+        let series = SeaSeriesMapper::entity_into_active_model(series)
+            .insert(&self.sea_service.db)
+            .await?;
+
+        Ok(SeaSeriesMapper::model_into_entity(series))
+    }
+
+    async fn save(&self, series: Series) -> Result<Series, Box<dyn Error>> {
+        let series = SeaSeriesMapper::entity_into_active_model(series)
+            .update(&self.sea_service.db)
+            .await?;
+
+        Ok(SeaSeriesMapper::model_into_entity(series))
+    }
+
+    async fn delete(&self, series: Series) -> Result<(), Box<dyn Error>> {
+        SeriesEntity::delete_by_id(series.id())
+            .exec(&self.sea_service.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Series>, Box<dyn Error>> {
+        Ok(SeriesEntity::find_by_id(id)
+            .one(&self.sea_service.db)
+            .await?
+            .map(SeaSeriesMapper::model_into_entity))
+    }
+
+    async fn find_by_slug(&self, slug: &Slug) -> Result<Option<Series>, Box<dyn Error>> {
+        Ok(SeriesEntity::find()
+            .filter(SeriesColumn::Slug.eq(slug.to_string()))
+            .one(&self.sea_service.db)
+            .await?
+            .map(SeaSeriesMapper::model_into_entity))
+    }
+
+    async fn find_by_article_id(&self, article_id: Uuid) -> Result<Option<Series>, Box<dyn Error>> {
+        let series = SeriesArticleEntity::find()
+            .filter(SeriesArticleColumn::ArticleId.eq(article_id))
+            .find_also_related(SeriesEntity)
+            .one(&self.sea_service.db)
+            .await?;
+
+        Ok(series
+            .and_then(|(_, series)| series)
+            .map(SeaSeriesMapper::model_into_entity))
+    }
+
+    async fn find_articles(
+        &self,
+        series_id: Uuid,
+        only_approved: bool,
+    ) -> Result<Vec<Article>, Box<dyn Error>> {
+        let articles = SeriesArticleEntity::find()
+            .filter(SeriesArticleColumn::SeriesId.eq(series_id))
+            .order_by_asc(SeriesArticleColumn::Position)
+            .find_also_related(ArticleEntity)
+            .apply_if(only_approved.then_some(true), |query_builder, approved| {
+                query_builder.filter(ArticleColumn::Approved.eq(approved))
+            })
+            .all(&self.sea_service.db)
+            .await?
+            .into_iter()
+            .filter_map(|(_, article)| article.map(SeaArticleMapper::model_into_entity))
+            .collect();
+
+        Ok(articles)
+    }
+
+    async fn set_articles(
+        &self,
+        series_id: Uuid,
+        articles_ids: Vec<Uuid>,
+    ) -> Result<(), Box<dyn Error>> {
+        let transaction = self.sea_service.db.begin().await?;
+
+        SeriesArticleEntity::delete_many()
+            .filter(SeriesArticleColumn::SeriesId.eq(series_id))
+            .exec(&transaction)
+            .await?;
+
+        if !articles_ids.is_empty() {
+            let memberships = articles_ids
+                .into_iter()
+                .enumerate()
+                .map(|(position, article_id)| SeriesArticleActiveModel {
+                    series_id: series_id.into_active_value(),
+                    article_id: article_id.into_active_value(),
+                    position: (position as i32).into_active_value(),
+                });
+
+            SeriesArticleEntity::insert_many(memberships)
+                .exec(&transaction)
+                .await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+}
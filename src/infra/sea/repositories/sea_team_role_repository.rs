@@ -2,18 +2,18 @@ use std::error::Error;
 
 use async_trait::async_trait;
 use migration::{Expr, Func};
-use sea_orm::{
-    ActiveModelTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
-};
+use sea_orm::{ActiveModelTrait, EntityTrait, PaginatorTrait, QueryFilter, QuerySelect};
 use uuid::Uuid;
 
 use crate::core::pagination::PaginationParameters;
 use crate::domain::domain_entities::team_role::TeamRole;
 use crate::domain::repositories::team_role_repository::{
-    FindManyTeamRolesResponse, TeamRoleQueryType, TeamRoleRepositoryTrait,
+    FindManyTeamRolesResponse, TeamRoleQuery, TeamRoleQueryType, TeamRoleRepositoryTrait,
+    TeamRoleSortField,
 };
 use crate::infra::sea::mappers::sea_team_role_mapper::SeaTeamRoleMapper;
 use crate::infra::sea::mappers::SeaMapper;
+use crate::infra::sea::query_spec::{apply_filters, apply_sort, SeaSortField};
 use crate::infra::sea::sea_service::SeaService;
 
 use entities::team_role::Column as TeamRoleColumn;
@@ -72,30 +72,34 @@ impl TeamRoleRepositoryTrait for SeaTeamRoleRepository<'_> {
 
     async fn find_many(
         &self,
-        params: PaginationParameters<TeamRoleQueryType>,
+        params: PaginationParameters<TeamRoleQuery>,
     ) -> Result<FindManyTeamRolesResponse, Box<dyn Error>> {
         let current_page = params.page as u64;
         let items_per_page = params.items_per_page as u64;
 
         let leap = (&current_page - 1) * items_per_page;
 
-        let team_roles_response = TeamRoleEntity::find()
-            .order_by_desc(TeamRoleColumn::CreatedAt)
-            .apply_if(params.clone().query, |query_builder, query| {
-                self.find_many_get_filters(query_builder, query)
-            })
-            .limit(items_per_page)
-            .offset(leap)
-            .all(&self.sea_service.db)
-            .await?;
-
-        let team_roles_count = TeamRoleEntity::find()
-            .apply_if(params.query, |query_builder, query| {
-                self.find_many_get_filters(query_builder, query)
-            })
-            .offset(leap)
-            .count(&self.sea_service.db)
-            .await?;
+        let team_roles_response = apply_sort(
+            apply_filters(
+                TeamRoleEntity::find(),
+                params.query.as_ref(),
+                |query_builder, query| self.find_many_get_filters(query_builder, query),
+            ),
+            params.query.as_ref(),
+            TeamRoleColumn::CreatedAt,
+        )
+        .limit(items_per_page)
+        .offset(leap)
+        .all(&self.sea_service.db)
+        .await?;
+
+        let team_roles_count = apply_filters(
+            TeamRoleEntity::find(),
+            params.query.as_ref(),
+            |query_builder, query| self.find_many_get_filters(query_builder, query),
+        )
+        .count(&self.sea_service.db)
+        .await?;
 
         let mut team_roles: Vec<TeamRole> = vec![];
 
@@ -122,3 +126,12 @@ impl SeaTeamRoleRepository<'_> {
         }
     }
 }
+
+impl SeaSortField<TeamRoleEntity> for TeamRoleSortField {
+    fn column(&self) -> TeamRoleColumn {
+        match self {
+            TeamRoleSortField::CreatedAt => TeamRoleColumn::CreatedAt,
+            TeamRoleSortField::Title => TeamRoleColumn::Title,
+        }
+    }
+}
@@ -5,17 +5,18 @@ use uuid::Uuid;
 
 use migration::Expr;
 use migration::Func;
-use sea_orm::{
-    ActiveModelTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
-};
+use sea_orm::{ActiveModelTrait, EntityTrait, PaginatorTrait, QueryFilter, QuerySelect};
 
 use crate::core::pagination::PaginationParameters;
 use crate::domain::domain_entities::team_user::TeamUser;
 use crate::domain::repositories::team_user_repository::FindManyTeamUsersResponse;
+use crate::domain::repositories::team_user_repository::TeamUserQuery;
 use crate::domain::repositories::team_user_repository::TeamUserQueryType;
 use crate::domain::repositories::team_user_repository::TeamUserRepositoryTrait;
+use crate::domain::repositories::team_user_repository::TeamUserSortField;
 use crate::infra::sea::mappers::sea_team_user_mapper::SeaTeamUserMapper;
 use crate::infra::sea::mappers::SeaMapper;
+use crate::infra::sea::query_spec::{apply_filters, apply_sort, SeaSortField};
 use crate::infra::sea::sea_service::SeaService;
 
 use entities::team_user::Column as TeamUserColumn;
@@ -56,6 +57,18 @@ impl TeamUserRepositoryTrait for SeaTeamUserRepository<'_> {
         }
     }
 
+    async fn find_by_nickname(&self, nickname: &str) -> Result<Option<TeamUser>, Box<dyn Error>> {
+        let team_user = TeamUserEntity::find()
+            .filter(
+                Expr::expr(Func::lower(Expr::col(TeamUserColumn::Nickname)))
+                    .eq(nickname.to_lowercase()),
+            )
+            .one(&self.sea_service.db)
+            .await?;
+
+        Ok(team_user.map(SeaTeamUserMapper::model_into_entity))
+    }
+
     async fn save(&self, team_user: TeamUser) -> Result<TeamUser, Box<dyn Error>> {
         let team_user = SeaTeamUserMapper::entity_into_active_model(team_user);
 
@@ -74,30 +87,34 @@ impl TeamUserRepositoryTrait for SeaTeamUserRepository<'_> {
 
     async fn find_many(
         &self,
-        params: PaginationParameters<TeamUserQueryType>,
+        params: PaginationParameters<TeamUserQuery>,
     ) -> Result<FindManyTeamUsersResponse, Box<dyn Error>> {
         let current_page = params.page as u64;
         let items_per_page = params.items_per_page as u64;
 
         let leap = (&current_page - 1) * items_per_page;
 
-        let team_users_response = TeamUserEntity::find()
-            .order_by_desc(TeamUserColumn::CreatedAt)
-            .apply_if(params.clone().query, |query_builder, query| {
-                self.find_many_get_filters(query_builder, query)
-            })
-            .limit(items_per_page)
-            .offset(leap)
-            .all(&self.sea_service.db)
-            .await?;
-
-        let team_users_count = TeamUserEntity::find()
-            .apply_if(params.query, |query_builder, query| {
-                self.find_many_get_filters(query_builder, query)
-            })
-            .offset(leap)
-            .count(&self.sea_service.db)
-            .await?;
+        let team_users_response = apply_sort(
+            apply_filters(
+                TeamUserEntity::find(),
+                params.query.as_ref(),
+                |query_builder, query| self.find_many_get_filters(query_builder, query),
+            ),
+            params.query.as_ref(),
+            TeamUserColumn::CreatedAt,
+        )
+        .limit(items_per_page)
+        .offset(leap)
+        .all(&self.sea_service.db)
+        .await?;
+
+        let team_users_count = apply_filters(
+            TeamUserEntity::find(),
+            params.query.as_ref(),
+            |query_builder, query| self.find_many_get_filters(query_builder, query),
+        )
+        .count(&self.sea_service.db)
+        .await?;
 
         let mut team_users: Vec<TeamUser> = vec![];
 
@@ -128,3 +145,12 @@ impl SeaTeamUserRepository<'_> {
         }
     }
 }
+
+impl SeaSortField<TeamUserEntity> for TeamUserSortField {
+    fn column(&self) -> TeamUserColumn {
+        match self {
+            TeamUserSortField::CreatedAt => TeamUserColumn::CreatedAt,
+            TeamUserSortField::Nickname => TeamUserColumn::Nickname,
+        }
+    }
+}
@@ -1,21 +1,39 @@
 use std::error::Error;
 
 use crate::core::pagination::PaginationParameters;
+use crate::domain::domain_entities::reaction::ReactionTarget;
 use crate::domain::domain_entities::user::User;
-use crate::domain::repositories::user_repository::{FindManyUsersResponse, UserRepositoryTrait};
+use crate::domain::repositories::user_repository::{
+    FindManyUsersResponse, UserQuery, UserRepositoryTrait, UserSortField,
+};
 use crate::infra::sea::mappers::sea_role_mapper::SeaRoleMapper;
 use crate::infra::sea::mappers::sea_user_mapper::SeaUserMapper;
 use crate::infra::sea::mappers::SeaMapper;
+use crate::infra::sea::query_spec::{
+    apply_filters, apply_sort, date_range_condition, SeaSortField,
+};
+use crate::infra::sea::repositories::sea_reaction_repository::decrement_count;
 use crate::{
     domain::repositories::user_repository::UserQueryType, infra::sea::sea_service::SeaService,
 };
 use async_trait::async_trait;
+use entities::announcement::Column as AnnouncementColumn;
+use entities::article::Column as ArticleColumn;
+use entities::blocked_word::Column as BlockedWordColumn;
+use entities::comment::Column as CommentColumn;
+use entities::comment_edit::Column as CommentEditColumn;
+use entities::comment_report::Column as CommentReportColumn;
+use entities::comment_review::Column as CommentReviewColumn;
+use entities::media::Column as MediaColumn;
+use entities::reaction::{Column as ReactionColumn, Entity as ReactionEntity};
+use entities::series::Column as SeriesColumn;
 use entities::user::{Column as UserColumn, Entity as UserEntity};
 use migration::{Alias, Expr, Func};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, EntityTrait, IntoSimpleExpr, PaginatorTrait, QueryFilter,
-    QueryOrder, QuerySelect, QueryTrait,
+    ActiveModelTrait, ColumnTrait, DatabaseTransaction, DbErr, EntityTrait, IntoSimpleExpr,
+    PaginatorTrait, QueryFilter, QuerySelect, TransactionTrait,
 };
+use std::str::FromStr;
 use uuid::Uuid;
 
 pub struct SeaUserRepository<'a> {
@@ -84,32 +102,96 @@ impl UserRepositoryTrait for SeaUserRepository<'_> {
         Ok(user)
     }
 
+    async fn delete_transferring_content(
+        &self,
+        user: User,
+        heir_id: Uuid,
+    ) -> Result<(), Box<dyn Error>> {
+        let user_id = user.id();
+
+        let transaction = self.sea_service.db.begin().await?;
+
+        transfer(&transaction, ArticleColumn::AuthorId, user_id, heir_id).await?;
+        transfer(&transaction, CommentColumn::AuthorId, user_id, heir_id).await?;
+        transfer(&transaction, CommentEditColumn::EditorId, user_id, heir_id).await?;
+        transfer(
+            &transaction,
+            CommentReviewColumn::AuthorId,
+            user_id,
+            heir_id,
+        )
+        .await?;
+        transfer(
+            &transaction,
+            CommentReviewColumn::ReviewedBy,
+            user_id,
+            heir_id,
+        )
+        .await?;
+        transfer(&transaction, CommentReportColumn::UserId, user_id, heir_id).await?;
+        transfer(
+            &transaction,
+            CommentReportColumn::SolvedBy,
+            user_id,
+            heir_id,
+        )
+        .await?;
+        transfer(&transaction, AnnouncementColumn::AuthorId, user_id, heir_id).await?;
+        transfer(&transaction, BlockedWordColumn::AuthorId, user_id, heir_id).await?;
+        transfer(&transaction, SeriesColumn::AuthorId, user_id, heir_id).await?;
+        transfer(&transaction, MediaColumn::UploaderId, user_id, heir_id).await?;
+
+        // the reactions themselves are deleted along with the user, but their counts must be kept right
+        let reactions = ReactionEntity::find()
+            .filter(ReactionColumn::UserId.eq(user_id))
+            .all(&transaction)
+            .await?;
+
+        for reaction in reactions {
+            let Ok(target) = ReactionTarget::from_str(&reaction.target) else {
+                continue;
+            };
+
+            decrement_count(&transaction, target, reaction.target_id, &reaction.kind).await?;
+        }
+
+        UserEntity::delete_by_id(user_id).exec(&transaction).await?;
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
     async fn find_many(
         &self,
-        params: PaginationParameters<UserQueryType>,
+        params: PaginationParameters<UserQuery>,
     ) -> Result<FindManyUsersResponse, Box<dyn Error>> {
         let current_page = params.page as u64;
         let items_per_page = params.items_per_page as u64;
 
         let leap = (&current_page - 1) * items_per_page;
 
-        let users_response = UserEntity::find()
-            .order_by_desc(UserColumn::CreatedAt)
-            .apply_if(params.clone().query, |query_builder, query| {
-                self.find_many_get_filters(query_builder, query)
-            })
-            .limit(items_per_page)
-            .offset(leap)
-            .all(&self.sea_service.db)
-            .await?;
+        let users_response = apply_sort(
+            apply_filters(
+                UserEntity::find(),
+                params.query.as_ref(),
+                |query_builder, query| self.find_many_get_filters(query_builder, query),
+            ),
+            params.query.as_ref(),
+            UserColumn::CreatedAt,
+        )
+        .limit(items_per_page)
+        .offset(leap)
+        .all(&self.sea_service.db)
+        .await?;
 
-        let users_count = UserEntity::find()
-            .apply_if(params.query, |query_builder, query| {
-                self.find_many_get_filters(query_builder, query)
-            })
-            .offset(leap)
-            .count(&self.sea_service.db)
-            .await?;
+        let users_count = apply_filters(
+            UserEntity::find(),
+            params.query.as_ref(),
+            |query_builder, query| self.find_many_get_filters(query_builder, query),
+        )
+        .count(&self.sea_service.db)
+        .await?;
 
         let mut users: Vec<User> = vec![];
 
@@ -141,6 +223,39 @@ impl SeaUserRepository<'_> {
                 .eq(SeaRoleMapper::into_model(content));
                 query_builder.filter(filter)
             }
+            UserQueryType::CreatedAt(range) => {
+                query_builder.filter(date_range_condition(UserColumn::CreatedAt, range))
+            }
+        }
+    }
+}
+
+/// Points the column's rows that reference the user to the heir instead.
+async fn transfer<C>(
+    transaction: &DatabaseTransaction,
+    column: C,
+    user_id: Uuid,
+    heir_id: Uuid,
+) -> Result<(), DbErr>
+where
+    C: ColumnTrait,
+    C::EntityName: EntityTrait<Column = C>,
+{
+    C::EntityName::update_many()
+        .col_expr(column, Expr::value(heir_id))
+        .filter(column.eq(user_id))
+        .exec(transaction)
+        .await?;
+
+    Ok(())
+}
+
+impl SeaSortField<UserEntity> for UserSortField {
+    fn column(&self) -> UserColumn {
+        match self {
+            UserSortField::CreatedAt => UserColumn::CreatedAt,
+            UserSortField::Nickname => UserColumn::Nickname,
+            UserSortField::LastLogin => UserColumn::LastLogin,
         }
     }
 }
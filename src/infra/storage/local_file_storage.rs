@@ -0,0 +1,102 @@
+use std::error::Error;
+use std::path::{Component, Path, PathBuf};
+
+use async_trait::async_trait;
+
+use crate::domain::storage::file_storage::FileStorageTrait;
+use crate::util::SiteUrl;
+use crate::ENV_VARS;
+
+pub const DEFAULT_UPLOADS_DIR: &str = "uploads";
+/// Path under which the server exposes the uploads folder.
+pub const UPLOADS_ROUTE: &str = "/uploads";
+
+/// Stores files on a folder of the server's filesystem (`UPLOADS_DIR`), which are then served under `/uploads`,
+/// or on `UPLOADS_URL` if it's set (e.g. when they're served by a reverse proxy).
+#[derive(Clone)]
+pub struct LocalFileStorage {
+    root: PathBuf,
+    public_url: String,
+}
+
+impl Default for LocalFileStorage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocalFileStorage {
+    pub fn new() -> Self {
+        let public_url = match &ENV_VARS.uploads_url {
+            Some(url) if !url.is_empty() => url.trim_end_matches('/').to_owned(),
+            _ => SiteUrl::absolute(UPLOADS_ROUTE),
+        };
+
+        LocalFileStorage {
+            root: Self::root(),
+            public_url,
+        }
+    }
+
+    pub fn root() -> PathBuf {
+        PathBuf::from(
+            ENV_VARS
+                .uploads_dir
+                .as_deref()
+                .unwrap_or(DEFAULT_UPLOADS_DIR),
+        )
+    }
+
+    /// Keys must never escape the storage's root folder.
+    fn path_of(&self, key: &str) -> Result<PathBuf, Box<dyn Error>> {
+        let relative = Path::new(key);
+
+        if !relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return Err(format!("Invalid storage key: '{key}'.").into());
+        }
+
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl FileStorageTrait for LocalFileStorage {
+    async fn put(
+        &self,
+        key: &str,
+        content: Vec<u8>,
+        _content_type: &str,
+    ) -> Result<(), Box<dyn Error>> {
+        let path = self.path_of(key)?;
+
+        if let Some(folder) = path.parent() {
+            tokio::fs::create_dir_all(folder).await?;
+        }
+
+        tokio::fs::write(path, content).await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), Box<dyn Error>> {
+        let path = self.path_of(key)?;
+
+        match tokio::fs::remove_file(path).await {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(Box::new(err)),
+            _ => Ok(()),
+        }
+    }
+
+    fn url(&self, key: &str) -> String {
+        format!("{}/{key}", self.public_url)
+    }
+
+    fn key_from_url(&self, url: &str) -> Option<String> {
+        url.strip_prefix(&self.public_url)?
+            .strip_prefix('/')
+            .map(str::to_owned)
+    }
+}
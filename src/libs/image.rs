@@ -0,0 +1,58 @@
+use std::io::Cursor;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::{ImageError, ImageFormat, ImageReader};
+
+const THUMBNAIL_JPEG_QUALITY: u8 = 85;
+
+pub struct ImageHelper;
+
+impl ImageHelper {
+    /// Sniffs the image's format from its content, regardless of what the client claims it to be.
+    /// Returns its MIME type and file extension.
+    pub fn detect_format(content: &[u8]) -> Option<(&'static str, &'static str)> {
+        let format = image::guess_format(content).ok()?;
+
+        let extension = match format {
+            ImageFormat::Jpeg => "jpg",
+            ImageFormat::Png => "png",
+            ImageFormat::WebP => "webp",
+            ImageFormat::Gif => "gif",
+            _ => return None,
+        };
+
+        Some((format.to_mime_type(), extension))
+    }
+
+    /// Reads the image's width and height from its header, without decoding it.
+    pub fn dimensions(content: &[u8]) -> Result<(u32, u32), ImageError> {
+        ImageReader::new(Cursor::new(content))
+            .with_guessed_format()?
+            .into_dimensions()
+    }
+
+    /// Resizes and crops the image to exactly fill each of the given dimensions (width and height), encoding the
+    /// results as JPEG. The image is decoded only once.
+    pub fn thumbnails(
+        content: &[u8],
+        dimensions: &[(u32, u32)],
+    ) -> Result<Vec<Vec<u8>>, ImageError> {
+        let image = image::load_from_memory(content)?;
+
+        dimensions
+            .iter()
+            .map(|(width, height)| {
+                let thumbnail = image
+                    .resize_to_fill(*width, *height, FilterType::Lanczos3)
+                    .into_rgb8();
+
+                let mut encoded = Cursor::new(Vec::new());
+                JpegEncoder::new_with_quality(&mut encoded, THUMBNAIL_JPEG_QUALITY)
+                    .encode_image(&thumbnail)?;
+
+                Ok(encoded.into_inner())
+            })
+            .collect()
+    }
+}
@@ -1,2 +1,4 @@
 pub mod custom_validators;
+pub mod image;
+pub mod text;
 pub mod time;
@@ -0,0 +1,38 @@
+use std::collections::HashSet;
+
+pub struct TextHelper;
+
+impl TextHelper {
+    /// Splits the text on anything that isn't alphanumeric, lowercasing the words.
+    pub fn normalized_words(text: &str) -> impl Iterator<Item = String> + '_ {
+        text.split(|char: char| !char.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(|word| word.to_lowercase())
+    }
+
+    /// Same as Postgres' `pg_trgm`: every word is padded with two spaces before and one after it.
+    pub fn trigrams(text: &str) -> HashSet<String> {
+        let mut trigrams = HashSet::new();
+
+        for word in Self::normalized_words(text) {
+            let padded_word = format!("  {word} ").chars().collect::<Vec<char>>();
+
+            for trigram in padded_word.windows(3) {
+                trigrams.insert(trigram.iter().collect());
+            }
+        }
+
+        trigrams
+    }
+
+    /// How much two sets overlap, from 0 (nothing in common, or both empty) to 1 (equal).
+    pub fn jaccard_index(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+        let union = a.union(b).count();
+
+        if union == 0 {
+            return 0.0;
+        }
+
+        a.intersection(b).count() as f64 / union as f64
+    }
+}
@@ -1,12 +1,30 @@
-use chrono::{FixedOffset, NaiveDateTime, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Utc};
 
 pub struct TimeHelper;
 
 impl TimeHelper {
     pub fn now() -> NaiveDateTime {
-        // Brasilia timezone (-03:00)
-        let offset = FixedOffset::west_opt(3 * 60 * 60).unwrap();
+        Utc::now().with_timezone(&Self::offset()).naive_local()
+    }
+
+    /// Attaches the offset used by `now` to a datetime, for the cases in which it must be explicit (e.g. feeds).
+    pub fn with_offset(datetime: NaiveDateTime) -> DateTime<FixedOffset> {
+        datetime
+            .and_local_timezone(Self::offset())
+            .single()
+            .expect("a fixed offset should never be ambiguous")
+    }
 
-        Utc::now().with_timezone(&offset).naive_local()
+    /// Converts an UTC datetime into the same local time `now` uses.
+    pub fn from_utc(datetime: NaiveDateTime) -> NaiveDateTime {
+        datetime
+            .and_utc()
+            .with_timezone(&Self::offset())
+            .naive_local()
+    }
+
+    fn offset() -> FixedOffset {
+        // Brasilia timezone (-03:00)
+        FixedOffset::west_opt(3 * 60 * 60).unwrap()
     }
 }
@@ -1,4 +1,4 @@
-use crate::infra::http::routes::{api::ApiRoutes, route::RouteTrait};
+use crate::infra::http::routes::{api::ApiRoutes, public::PublicRoutes, route::RouteTrait};
 use crate::infra::sea::sea_service::SeaService;
 use actix_web::dev::{ServiceFactory, ServiceRequest, ServiceResponse};
 use actix_web::{middleware, web, App, HttpResponse};
@@ -21,6 +21,7 @@ impl ServerFactory {
                 middleware::TrailingSlash::Trim,
             ))
             .configure(ApiRoutes::register)
+            .configure(PublicRoutes::register)
             .app_data(web::JsonConfig::default().error_handler(|err, _req| {
                 actix_web::error::InternalError::from_response(
                     "",
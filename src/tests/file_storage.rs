@@ -0,0 +1,38 @@
+use crate::domain::storage::file_storage::MockFileStorageTrait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+pub const FAKE_STORAGE_URL: &str = "https://localhost/uploads";
+
+pub type FileStore = Arc<Mutex<HashMap<String, Vec<u8>>>>;
+
+/// An in-memory storage, whose files are keyed by their storage keys.
+pub fn get_file_storage() -> (FileStore, MockFileStorageTrait) {
+    let files: FileStore = Arc::new(Mutex::new(HashMap::new()));
+
+    let mut storage = MockFileStorageTrait::new();
+
+    let files_clone = Arc::clone(&files);
+    storage.expect_put().returning(move |key, content, _| {
+        files_clone.lock().unwrap().insert(key.to_owned(), content);
+        Ok(())
+    });
+
+    let files_clone = Arc::clone(&files);
+    storage.expect_delete().returning(move |key| {
+        files_clone.lock().unwrap().remove(key);
+        Ok(())
+    });
+
+    storage
+        .expect_url()
+        .returning(|key| format!("{FAKE_STORAGE_URL}/{key}"));
+
+    storage.expect_key_from_url().returning(|url| {
+        url.strip_prefix(FAKE_STORAGE_URL)?
+            .strip_prefix('/')
+            .map(str::to_owned)
+    });
+
+    (files, storage)
+}
@@ -1,2 +1,6 @@
 #[cfg(test)]
+pub mod file_storage;
+#[cfg(test)]
+pub mod query_spec;
+#[cfg(test)]
 pub mod repositories;
@@ -0,0 +1,67 @@
+use std::cmp::Ordering;
+
+use chrono::NaiveDateTime as DateTime;
+
+use crate::core::pagination::{Cursor, CursorDirection};
+use crate::core::query::QuerySpec;
+
+/// Keeps the items matching every filter of the query, ordered by its sorts.
+/// Unlike the Sea repositories, the insertion order is kept when there's no sort.
+pub fn apply_query_spec<T, F, SF>(
+    items: Vec<T>,
+    query: Option<&QuerySpec<F, SF>>,
+    matches: impl Fn(&T, &F) -> bool,
+    compare_by: impl Fn(&T, &T, &SF) -> Ordering,
+) -> Vec<T> {
+    let Some(query) = query else {
+        return items;
+    };
+
+    let mut items: Vec<T> = items
+        .into_iter()
+        .filter(|item| query.filters.iter().all(|filter| matches(item, filter)))
+        .collect();
+
+    items.sort_by(|a, b| query.compare(a, b, &compare_by));
+
+    items
+}
+
+/// Keeps the items matching every filter and following the cursor in its direction, just like `apply_cursor` does,
+/// including the extra item.
+pub fn select_by_cursor<T, F, ID: Ord + Clone>(
+    items: Vec<T>,
+    filters: &[F],
+    cursor: Option<&Cursor<ID>>,
+    items_per_page: u32,
+    matches: impl Fn(&T, &F) -> bool,
+    key: impl Fn(&T) -> (DateTime, ID),
+) -> Vec<T> {
+    let direction = cursor.map_or(CursorDirection::Next, |cursor| cursor.direction);
+
+    let mut items: Vec<T> = items
+        .into_iter()
+        .filter(|item| filters.iter().all(|filter| matches(item, filter)))
+        .filter(|item| match cursor {
+            None => true,
+            Some(cursor) => {
+                let position = key(item).cmp(&(cursor.created_at, cursor.id.clone()));
+
+                match direction {
+                    CursorDirection::Next => position == Ordering::Less,
+                    CursorDirection::Prev => position == Ordering::Greater,
+                }
+            }
+        })
+        .collect();
+
+    items.sort_by_key(|item| key(item));
+
+    if direction == CursorDirection::Next {
+        items.reverse();
+    }
+
+    items.truncate(items_per_page as usize + 1);
+
+    items
+}
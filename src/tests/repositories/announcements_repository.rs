@@ -1,10 +1,11 @@
 use crate::domain::repositories::announcements_repository::{
-    FindManyAnnouncementsResponse, MockAnnouncementRepositoryTrait,
+    AnnouncementSortField, FindManyAnnouncementsResponse, MockAnnouncementRepositoryTrait,
 };
 use crate::domain::{
     domain_entities::announcement::Announcement,
     repositories::announcements_repository::AnnouncementQueryType,
 };
+use crate::tests::query_spec::apply_query_spec;
 use std::sync::{Arc, Mutex};
 
 pub fn get_announcements_repository() -> (
@@ -66,28 +67,30 @@ pub fn get_announcements_repository() -> (
 
     let db_clone = db.clone();
     repository.expect_find_many().returning(move |params| {
-        let db = db_clone.lock().unwrap();
+        let mut selected = db_clone.lock().unwrap().clone();
 
-        let mut selected = match params.query {
-            None => db.iter().cloned().collect::<Vec<Announcement>>(),
-            Some(query) => match query {
-                AnnouncementQueryType::Description(value) => db
-                    .iter()
-                    .filter(|announcement| {
-                        announcement
-                            .description()
-                            .to_lowercase()
-                            .contains(&value.to_lowercase())
-                    })
-                    .cloned()
-                    .collect::<Vec<Announcement>>(),
+        // the default order, which the sorts of the query take precedence over
+        selected.sort_by(|a, b| b.created_at().cmp(a.created_at()));
+
+        let selected = apply_query_spec(
+            selected,
+            params.query.as_ref(),
+            |announcement, query| match query {
+                AnnouncementQueryType::Description(value) => announcement
+                    .description()
+                    .to_lowercase()
+                    .contains(&value.to_lowercase()),
+                AnnouncementQueryType::CreatedAt(range) => {
+                    range.contains(announcement.created_at())
+                }
             },
-        };
+            |a, b, field| match field {
+                AnnouncementSortField::CreatedAt => a.created_at().cmp(b.created_at()),
+            },
+        );
 
         let total_items = selected.len() as u64;
 
-        selected.sort_by(|a, b| b.created_at().cmp(a.created_at()));
-
         let selected = selected
             .into_iter()
             .skip(((params.page - 1) * params.items_per_page) as usize)
@@ -0,0 +1,80 @@
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::article_coauthor::ArticleCoauthor;
+use crate::domain::domain_entities::user::User;
+use crate::domain::repositories::article_coauthor_repository::MockArticleCoauthorRepositoryTrait;
+use crate::error::DomainError;
+use std::sync::{Arc, Mutex};
+
+pub fn get_article_coauthor_repository(
+    users_db: Arc<Mutex<Vec<User>>>,
+    articles_db: Arc<Mutex<Vec<Article>>>,
+) -> (
+    Arc<Mutex<Vec<ArticleCoauthor>>>,
+    MockArticleCoauthorRepositoryTrait,
+) {
+    let db: Arc<Mutex<Vec<ArticleCoauthor>>> = Arc::new(Mutex::new(vec![]));
+    let mut repository = MockArticleCoauthorRepositoryTrait::new();
+
+    let db_clone = Arc::clone(&db);
+    repository
+        .expect_find_by_article_id()
+        .returning(move |article_id| {
+            Ok(db_clone
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|coauthor| coauthor.article_id() == article_id)
+                .cloned()
+                .collect())
+        });
+
+    let db_clone = Arc::clone(&db);
+    repository
+        .expect_find_with_users_by_article_id()
+        .returning(move |article_id| {
+            let users = users_db.lock().unwrap();
+
+            Ok(db_clone
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|coauthor| coauthor.article_id() == article_id)
+                .filter_map(|coauthor| {
+                    users
+                        .iter()
+                        .find(|user| user.id() == coauthor.user_id())
+                        .map(|user| (coauthor.clone(), user.clone()))
+                })
+                .collect())
+        });
+
+    let db_clone = Arc::clone(&db);
+    repository
+        .expect_is_coauthor()
+        .returning(move |article_id, user_id| {
+            Ok(db_clone.lock().unwrap().iter().any(|coauthor| {
+                coauthor.article_id() == article_id && coauthor.user_id() == user_id
+            }))
+        });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_save_article_with_coauthors().returning(
+        move |article: Article, coauthors| {
+            let mut articles = articles_db.lock().unwrap();
+            let stored = articles
+                .iter_mut()
+                .find(|stored| stored.id() == article.id())
+                .ok_or_else(|| Box::new(DomainError::resource_not_found_err()))?;
+            *stored = article.clone();
+
+            let mut db = db_clone.lock().unwrap();
+
+            db.retain(|coauthor| coauthor.article_id() != article.id());
+            db.extend(coauthors);
+
+            Ok(article)
+        },
+    );
+
+    (db, repository)
+}
@@ -1,9 +1,11 @@
 use crate::core::pagination::PaginationParameters;
 use crate::domain::domain_entities::article::Article;
+use crate::domain::politics::article_politics::ArticlePolitics;
 use crate::domain::repositories::article_repository::{
-    ArticleQueryType, FindManyArticlesResponse, MockArticleRepositoryTrait,
+    ArticleQueryType, ArticleSortField, FindManyArticlesResponse, MockArticleRepositoryTrait,
 };
 use crate::error::DomainError;
+use crate::tests::query_spec::{apply_query_spec, select_by_cursor};
 use std::sync::{Arc, Mutex};
 
 pub fn get_article_repository() -> (Arc<Mutex<Vec<Article>>>, MockArticleRepositoryTrait) {
@@ -28,40 +30,15 @@ pub fn get_article_repository() -> (Arc<Mutex<Vec<Article>>>, MockArticleReposit
                 query,
             } = params;
 
-            let mut articles: Vec<Article> = Vec::new();
-
-            if query.is_some() {
-                let query = query.unwrap();
-                match query {
-                    ArticleQueryType::Title(content) => {
-                        for item in db_clone.lock().unwrap().iter() {
-                            if item
-                                .title()
-                                .to_lowercase()
-                                .contains(&content.clone().to_lowercase())
-                            {
-                                articles.push(item.clone());
-                            }
-                        }
-                    }
-                    ArticleQueryType::Author(content) => {
-                        for item in db_clone.lock().unwrap().iter() {
-                            if item.author_id().eq(&content) {
-                                articles.push(item.clone());
-                            }
-                        }
-                    }
-                    ArticleQueryType::Tag(tag_id) => {
-                        for item in db_clone.lock().unwrap().iter() {
-                            if item.tag_id().unwrap().eq(&tag_id) {
-                                articles.push(item.clone());
-                            }
-                        }
-                    }
-                }
-            } else {
-                articles = db_clone.lock().unwrap().clone();
-            }
+            let mut articles = apply_query_spec(
+                db_clone.lock().unwrap().clone(),
+                query.as_ref(),
+                article_matches,
+                |a, b, field| match field {
+                    ArticleSortField::CreatedAt => a.created_at().cmp(&b.created_at()),
+                    ArticleSortField::Title => a.title().cmp(b.title()),
+                },
+            );
 
             if approved_status_filter.is_some() {
                 let approved_filter: bool = approved_status_filter.unwrap();
@@ -89,6 +66,28 @@ pub fn get_article_repository() -> (Arc<Mutex<Vec<Article>>>, MockArticleReposit
             ))
         });
 
+    let db_clone = Arc::clone(&db);
+    repository
+        .expect_find_many_by_cursor()
+        .returning(move |params, approved_status_filter| {
+            let articles = db_clone
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|article| approved_status_filter.is_none_or(|a| article.approved() == a))
+                .cloned()
+                .collect();
+
+            Ok(select_by_cursor(
+                articles,
+                &params.filters,
+                params.cursor.as_ref(),
+                params.items_per_page,
+                article_matches,
+                |article| (article.created_at(), article.id()),
+            ))
+        });
+
     let db_clone = Arc::clone(&db);
     repository.expect_find_by_id().returning(move |id| {
         for article in db_clone.lock().unwrap().iter() {
@@ -121,6 +120,23 @@ pub fn get_article_repository() -> (Arc<Mutex<Vec<Article>>>, MockArticleReposit
             }
         });
 
+    let db_clone = Arc::clone(&db);
+    repository
+        .expect_find_many_by_author()
+        .returning(move |author_id| {
+            let mut articles: Vec<Article> = db_clone
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|article| article.author_id() == author_id)
+                .cloned()
+                .collect();
+
+            articles.sort_by_key(|article| std::cmp::Reverse(article.created_at()));
+
+            Ok(articles)
+        });
+
     let db_clone = Arc::clone(&db);
     repository
         .expect_find_by_slug()
@@ -138,15 +154,82 @@ pub fn get_article_repository() -> (Arc<Mutex<Vec<Article>>>, MockArticleReposit
         });
 
     let db_clone = Arc::clone(&db);
-    repository.expect_get_home_articles().returning(move || {
-        let mut articles = db_clone.lock().unwrap().clone();
-        articles.sort_by(|a, b| b.created_at().partial_cmp(&a.created_at()).unwrap());
+    repository
+        .expect_find_pinned_articles()
+        .returning(move |now, limit| {
+            let mut articles = db_clone
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|article| {
+                    article.approved() && ArticlePolitics::article_is_pinned(article, now)
+                })
+                .cloned()
+                .collect::<Vec<Article>>();
+
+            articles.sort_by(|a, b| {
+                a.pin_order()
+                    .cmp(&b.pin_order())
+                    .then(b.created_at().cmp(&a.created_at()))
+            });
+
+            Ok(articles.into_iter().take(limit as usize).collect())
+        });
 
-        let articles = &articles[0..=2];
-        let articles = articles.iter().map(|article| article.to_owned()).collect();
+    let db_clone = Arc::clone(&db);
+    repository
+        .expect_find_related_candidates()
+        .returning(move |article, since, limit| {
+            let mut articles = db_clone
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|candidate| candidate.approved() && candidate.id() != article.id())
+                .filter(|candidate| {
+                    (article.tag_id().is_some() && candidate.tag_id() == article.tag_id())
+                        || candidate.author_id() == article.author_id()
+                        || candidate.created_at() >= since
+                })
+                .cloned()
+                .collect::<Vec<Article>>();
+
+            articles.sort_by_key(|b| std::cmp::Reverse(b.created_at()));
+
+            Ok(articles.into_iter().take(limit as usize).collect())
+        });
 
-        Ok(articles)
-    });
+    let db_clone = Arc::clone(&db);
+    repository
+        .expect_get_home_articles()
+        .returning(move |limit| {
+            let mut articles = db_clone
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|article| article.approved())
+                .cloned()
+                .collect::<Vec<Article>>();
+
+            articles.sort_by(|a, b| {
+                b.featured()
+                    .cmp(&a.featured())
+                    .then(b.created_at().cmp(&a.created_at()))
+            });
+
+            Ok(articles.into_iter().take(limit as usize).collect())
+        });
 
     (db, repository)
 }
+
+fn article_matches(article: &Article, query: &ArticleQueryType) -> bool {
+    match query {
+        ArticleQueryType::Title(content) => article
+            .title()
+            .to_lowercase()
+            .contains(&content.to_lowercase()),
+        ArticleQueryType::Author(author_id) => article.author_id().eq(author_id),
+        ArticleQueryType::Tag(tag_id) => article.tag_id().eq(&Some(*tag_id)),
+        ArticleQueryType::CreatedAt(range) => range.contains(&article.created_at()),
+    }
+}
@@ -1,9 +1,11 @@
 use crate::core::pagination::PaginationParameters;
 use crate::domain::domain_entities::article_tag::ArticleTag;
 use crate::domain::repositories::article_tag_repository::{
-    ArticleTagQueryType, FindManyArticleTagsResponse, MockArticleTagRepositoryTrait,
+    ArticleTagQueryType, ArticleTagSortField, FindManyArticleTagsResponse,
+    MockArticleTagRepositoryTrait,
 };
 use crate::error::DomainError;
+use crate::tests::query_spec::apply_query_spec;
 use std::sync::{Arc, Mutex};
 
 pub fn get_article_tag_repository() -> (Arc<Mutex<Vec<ArticleTag>>>, MockArticleTagRepositoryTrait)
@@ -86,23 +88,21 @@ pub fn get_article_tag_repository() -> (Arc<Mutex<Vec<ArticleTag>>>, MockArticle
             query,
         } = params;
 
-        let mut tags: Vec<ArticleTag> = Vec::new();
+        let tags = apply_query_spec(
+            db_clone.lock().unwrap().clone(),
+            query.as_ref(),
+            |item, query| {
+                let ArticleTagQueryType::Value(value) = query;
 
-        if query.is_some() {
-            let ArticleTagQueryType::Value(value) = query.unwrap();
-
-            for item in db_clone.lock().unwrap().iter() {
-                if item
-                    .value()
+                item.value()
                     .to_lowercase()
-                    .contains(&value.clone().to_lowercase()[..])
-                {
-                    tags.push(item.clone());
-                }
-            }
-        } else {
-            tags = db_clone.lock().unwrap().clone();
-        }
+                    .contains(&value.to_lowercase()[..])
+            },
+            |a, b, field| match field {
+                ArticleTagSortField::Id => a.id().cmp(&b.id()),
+                ArticleTagSortField::Value => a.value().cmp(b.value()),
+            },
+        );
 
         let total_of_items_before_paginating = tags.len();
 
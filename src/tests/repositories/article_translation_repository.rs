@@ -0,0 +1,71 @@
+use crate::domain::domain_entities::article_translation::ArticleTranslation;
+use crate::domain::repositories::article_translation_repository::MockArticleTranslationRepositoryTrait;
+use std::sync::{Arc, Mutex};
+
+pub fn get_article_translation_repository() -> (
+    Arc<Mutex<Vec<ArticleTranslation>>>,
+    MockArticleTranslationRepositoryTrait,
+) {
+    let db: Arc<Mutex<Vec<ArticleTranslation>>> = Arc::new(Mutex::new(vec![]));
+    let mut repository = MockArticleTranslationRepositoryTrait::new();
+
+    let db_clone = Arc::clone(&db);
+    repository
+        .expect_find_by_article_id_and_locale()
+        .returning(move |article_id, locale| {
+            Ok(db_clone
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|translation| {
+                    translation.article_id() == article_id && translation.locale() == locale
+                })
+                .cloned())
+        });
+
+    let db_clone = Arc::clone(&db);
+    repository
+        .expect_find_many_by_article_ids()
+        .returning(move |article_ids| {
+            Ok(db_clone
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|translation| article_ids.contains(&translation.article_id()))
+                .cloned()
+                .collect())
+        });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_by_slug().returning(move |slug| {
+        Ok(db_clone
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|translation| translation.slug().eq(slug))
+            .cloned())
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_save().returning(move |translation| {
+        let mut db = db_clone.lock().unwrap();
+
+        db.retain(|item| {
+            item.article_id() != translation.article_id() || item.locale() != translation.locale()
+        });
+        db.push(translation.clone());
+
+        Ok(translation)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_delete().returning(move |translation| {
+        db_clone.lock().unwrap().retain(|item| {
+            item.article_id() != translation.article_id() || item.locale() != translation.locale()
+        });
+
+        Ok(())
+    });
+
+    (db, repository)
+}
@@ -0,0 +1,57 @@
+use crate::domain::domain_entities::blocked_word::BlockedWord;
+use crate::domain::repositories::blocked_word_repository::MockBlockedWordRepositoryTrait;
+use crate::error::DomainError;
+use std::sync::{Arc, Mutex};
+
+pub fn get_blocked_word_repository(
+) -> (Arc<Mutex<Vec<BlockedWord>>>, MockBlockedWordRepositoryTrait) {
+    let db: Arc<Mutex<Vec<BlockedWord>>> = Arc::new(Mutex::new(vec![]));
+    let mut repository = MockBlockedWordRepositoryTrait::new();
+
+    let db_c = db.clone();
+    repository.expect_create().returning(move |blocked_word| {
+        db_c.lock().unwrap().push(blocked_word.clone());
+        Ok(blocked_word)
+    });
+
+    let db_c = db.clone();
+    repository.expect_find_by_id().returning(move |id| {
+        let blocked_word = db_c
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|blocked_word| blocked_word.id() == id)
+            .cloned();
+
+        Ok(blocked_word)
+    });
+
+    let db_c = db.clone();
+    repository
+        .expect_find_all()
+        .returning(move || Ok(db_c.lock().unwrap().clone()));
+
+    let db_c = db.clone();
+    repository.expect_save().returning(move |blocked_word| {
+        let mut db = db_c.lock().unwrap();
+
+        match db.iter().position(|item| item.id() == blocked_word.id()) {
+            Some(index) => {
+                db[index] = blocked_word.clone();
+                Ok(blocked_word)
+            }
+            None => Err(Box::new(DomainError::resource_not_found_err())),
+        }
+    });
+
+    let db_c = db.clone();
+    repository.expect_delete().returning(move |blocked_word| {
+        db_c.lock()
+            .unwrap()
+            .retain(|item| item.id() != blocked_word.id());
+
+        Ok(())
+    });
+
+    (db, repository)
+}
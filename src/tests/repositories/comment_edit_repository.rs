@@ -0,0 +1,34 @@
+use crate::domain::domain_entities::comment_edit::CommentEdit;
+use crate::domain::repositories::comment_edit_repository::MockCommentEditRepositoryTrait;
+use std::sync::{Arc, Mutex};
+
+pub fn get_comment_edit_repository(
+) -> (Arc<Mutex<Vec<CommentEdit>>>, MockCommentEditRepositoryTrait) {
+    let db: Arc<Mutex<Vec<CommentEdit>>> = Arc::new(Mutex::new(vec![]));
+    let mut repository = MockCommentEditRepositoryTrait::new();
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_create().returning(move |comment_edit| {
+        db_clone.lock().unwrap().push(comment_edit.clone());
+        Ok(comment_edit)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository
+        .expect_find_many_by_comment_id()
+        .returning(move |comment_id| {
+            let mut comment_edits: Vec<CommentEdit> = db_clone
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|comment_edit| comment_edit.comment_id() == comment_id)
+                .cloned()
+                .collect();
+
+            comment_edits.reverse();
+
+            Ok(comment_edits)
+        });
+
+    (db, repository)
+}
@@ -0,0 +1,66 @@
+use crate::domain::domain_entities::comment_mention::CommentMention;
+use crate::domain::repositories::comment_mention_repository::{
+    FindManyCommentMentionsResponse, MockCommentMentionRepositoryTrait,
+};
+use std::sync::{Arc, Mutex};
+
+pub fn get_comment_mention_repository() -> (
+    Arc<Mutex<Vec<CommentMention>>>,
+    MockCommentMentionRepositoryTrait,
+) {
+    let db: Arc<Mutex<Vec<CommentMention>>> = Arc::new(Mutex::new(vec![]));
+    let mut repository = MockCommentMentionRepositoryTrait::new();
+
+    let db_clone = Arc::clone(&db);
+    repository
+        .expect_set_mentions()
+        .returning(move |comment_id, mentions| {
+            let mut db = db_clone.lock().unwrap();
+
+            db.retain(|mention| mention.comment_id() != comment_id);
+            db.extend(mentions);
+
+            Ok(())
+        });
+
+    let db_clone = Arc::clone(&db);
+    repository
+        .expect_find_many_by_comment_ids()
+        .returning(move |comment_ids| {
+            Ok(db_clone
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|mention| comment_ids.contains(&mention.comment_id()))
+                .cloned()
+                .collect())
+        });
+
+    let db_clone = Arc::clone(&db);
+    repository
+        .expect_find_many_by_user_id()
+        .returning(move |user_id, params| {
+            let mentions: Vec<CommentMention> = db_clone
+                .lock()
+                .unwrap()
+                .iter()
+                .rev()
+                .filter(|mention| mention.user_id() == user_id)
+                .cloned()
+                .collect();
+
+            let total_items = mentions.len() as u64;
+            let leap = ((params.page - 1) * params.items_per_page) as usize;
+
+            Ok(FindManyCommentMentionsResponse(
+                mentions
+                    .into_iter()
+                    .skip(leap)
+                    .take(params.items_per_page as usize)
+                    .collect(),
+                total_items,
+            ))
+        });
+
+    (db, repository)
+}
@@ -0,0 +1,143 @@
+use crate::domain::domain_entities::comment::Comment;
+use crate::domain::domain_entities::comment_report::{
+    CommentReport, CommentReportIdTrait, CommentReportTrait, ReportResolution,
+};
+use crate::domain::repositories::comment_report_repository::MockCommentReportRepositoryTrait;
+use crate::error::DomainError;
+use std::sync::{Arc, Mutex};
+
+pub fn get_comment_report_repository(
+    comments_db: Arc<Mutex<Vec<Comment>>>,
+) -> (
+    Arc<Mutex<Vec<CommentReport>>>,
+    MockCommentReportRepositoryTrait,
+) {
+    let db: Arc<Mutex<Vec<CommentReport>>> = Arc::new(Mutex::new(vec![]));
+    let mut repository = MockCommentReportRepositoryTrait::new();
+
+    let db_c = db.clone();
+    repository.expect_create().returning(move |draft| {
+        let mut db = db_c.lock().unwrap();
+        let comment_report = draft.to_comment_report(db.len() as i32 + 1);
+
+        db.push(comment_report.clone());
+        Ok(comment_report)
+    });
+
+    let db_c = db.clone();
+    repository.expect_find_by_id().returning(move |id| {
+        Ok(db_c
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|report| report.id() == id)
+            .cloned())
+    });
+
+    let db_c = db.clone();
+    repository
+        .expect_find_many_unsolved_by_comment_id()
+        .returning(move |comment_id| {
+            Ok(db_c
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|report| {
+                    report.comment_id() == Some(comment_id) && report.solved_by().is_none()
+                })
+                .cloned()
+                .collect())
+        });
+
+    let db_c = db.clone();
+    repository
+        .expect_find_many_by_user_id()
+        .returning(move |user_id| {
+            Ok(db_c
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|report| report.user_id() == user_id)
+                .cloned()
+                .collect())
+        });
+
+    let db_c = db.clone();
+    repository.expect_save().returning(move |comment_report| {
+        let mut db = db_c.lock().unwrap();
+
+        match db.iter().position(|item| item.id() == comment_report.id()) {
+            Some(index) => {
+                db[index] = comment_report.clone();
+                Ok(comment_report)
+            }
+            None => Err(Box::new(DomainError::resource_not_found_err())),
+        }
+    });
+
+    let db_c = db.clone();
+    repository
+        .expect_save_many_hiding_comment()
+        .returning(move |comment_id, comment_reports| {
+            let mut db = db_c.lock().unwrap();
+
+            for comment_report in comment_reports.iter() {
+                let Some(index) = db.iter().position(|item| item.id() == comment_report.id())
+                else {
+                    return Err(Box::new(DomainError::resource_not_found_err()));
+                };
+
+                db[index] = comment_report.clone();
+            }
+
+            if let Some(comment) = comments_db
+                .lock()
+                .unwrap()
+                .iter_mut()
+                .find(|comment| comment.id() == comment_id)
+            {
+                comment.set_is_active(false);
+            }
+
+            Ok(comment_reports)
+        });
+
+    // the reported comments live elsewhere, so only the reports are solved here
+    let db_c = db.clone();
+    repository
+        .expect_solve()
+        .returning(move |mut comment_report| {
+            let mut db = db_c.lock().unwrap();
+
+            let Some(index) = db.iter().position(|item| item.id() == comment_report.id()) else {
+                return Err(Box::new(DomainError::resource_not_found_err()));
+            };
+
+            let resolution = comment_report.resolution();
+            let comment_id = comment_report.comment_id();
+
+            if let Some(resolution) = resolution.filter(ReportResolution::acts_on_comment) {
+                for report in db.iter_mut().filter(|report| {
+                    report.comment_id() == comment_id && report.solved_by().is_none()
+                }) {
+                    report.resolve(comment_report.solved_by().unwrap(), resolution, None);
+                }
+            }
+
+            if resolution == Some(ReportResolution::CommentDeleted) {
+                comment_report.set_comment_id(None);
+
+                for report in db
+                    .iter_mut()
+                    .filter(|report| report.comment_id() == comment_id)
+                {
+                    report.set_comment_id(None);
+                }
+            }
+
+            db[index] = comment_report.clone();
+            Ok(comment_report)
+        });
+
+    (db, repository)
+}
@@ -0,0 +1,132 @@
+use crate::domain::domain_entities::comment::Comment;
+use crate::domain::repositories::comment_repository::MockCommentRepositoryTrait;
+use crate::error::DomainError;
+use std::sync::{Arc, Mutex};
+
+pub fn get_comment_repository() -> (Arc<Mutex<Vec<Comment>>>, MockCommentRepositoryTrait) {
+    let db: Arc<Mutex<Vec<Comment>>> = Arc::new(Mutex::new(vec![]));
+    let mut repository = MockCommentRepositoryTrait::new();
+
+    let db_c = db.clone();
+    repository.expect_create().returning(move |comment| {
+        db_c.lock().unwrap().push(comment.clone());
+        Ok(comment)
+    });
+
+    let db_c = db.clone();
+    repository.expect_find_by_id().returning(move |id| {
+        let comment = db_c
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|comment| comment.id() == id)
+            .cloned();
+
+        Ok(comment)
+    });
+
+    let db_c = db.clone();
+    repository
+        .expect_find_many_by_author_since()
+        .returning(move |author_id, since| {
+            let comments = db_c
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|comment| comment.author_id() == author_id && comment.created_at() >= since)
+                .cloned()
+                .collect();
+
+            Ok(comments)
+        });
+
+    let db_c = db.clone();
+    repository
+        .expect_find_many_by_author()
+        .returning(move |author_id| {
+            let mut comments: Vec<Comment> = db_c
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|comment| comment.author_id() == author_id)
+                .cloned()
+                .collect();
+
+            comments.sort_by_key(|comment| std::cmp::Reverse(comment.created_at()));
+
+            Ok(comments)
+        });
+
+    let db_c = db.clone();
+    repository
+        .expect_count_active_by_author()
+        .returning(move |author_id| {
+            let count = db_c
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|comment| comment.author_id() == author_id && comment.is_active())
+                .count();
+
+            Ok(count as u64)
+        });
+
+    // the approval of the commented articles is left to the callers
+    let db_c = db.clone();
+    repository
+        .expect_find_recent_active_by_author()
+        .returning(move |author_id, limit| {
+            let mut comments: Vec<Comment> = db_c
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|comment| comment.author_id() == author_id && comment.is_active())
+                .cloned()
+                .collect();
+
+            comments.sort_by_key(|comment| std::cmp::Reverse(comment.created_at()));
+            comments.truncate(limit as usize);
+
+            Ok(comments)
+        });
+
+    let db_c = db.clone();
+    repository.expect_save().returning(move |comment| {
+        let mut db = db_c.lock().unwrap();
+
+        match db.iter().position(|item| item.id() == comment.id()) {
+            Some(index) => {
+                db[index] = comment.clone();
+                Ok(comment)
+            }
+            None => Err(Box::new(DomainError::resource_not_found_err())),
+        }
+    });
+
+    let db_c = db.clone();
+    repository.expect_delete().returning(move |comment| {
+        let mut db = db_c.lock().unwrap();
+
+        db.retain(|item| item.id() != comment.id());
+
+        for reply in db
+            .iter_mut()
+            .filter(|item| item.parent_id() == Some(comment.id()))
+        {
+            *reply = Comment::new_from_existing(
+                reply.id(),
+                reply.article_id(),
+                None,
+                reply.author_id(),
+                reply.content().to_owned(),
+                reply.is_active(),
+                reply.created_at(),
+                reply.updated_at(),
+            );
+        }
+
+        Ok(())
+    });
+
+    (db, repository)
+}
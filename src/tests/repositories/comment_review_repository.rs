@@ -0,0 +1,154 @@
+use crate::domain::domain_entities::comment::Comment;
+use crate::domain::domain_entities::comment_review::CommentReview;
+use crate::domain::domain_entities::role::Role;
+use crate::domain::domain_entities::user::User;
+use crate::domain::repositories::comment_review_repository::{
+    AuthorTrust, FindManyPendingCommentsResponse, MockCommentReviewRepositoryTrait,
+};
+use crate::error::DomainError;
+use std::sync::{Arc, Mutex};
+
+pub fn get_comment_review_repository(
+    comments_db: Arc<Mutex<Vec<Comment>>>,
+    users_db: Arc<Mutex<Vec<User>>>,
+) -> (
+    Arc<Mutex<Vec<CommentReview>>>,
+    MockCommentReviewRepositoryTrait,
+) {
+    let db: Arc<Mutex<Vec<CommentReview>>> = Arc::new(Mutex::new(vec![]));
+    let mut repository = MockCommentReviewRepositoryTrait::new();
+
+    let db_c = db.clone();
+    repository.expect_create().returning(move |comment_review| {
+        db_c.lock().unwrap().push(comment_review.clone());
+        Ok(comment_review)
+    });
+
+    let db_c = db.clone();
+    repository
+        .expect_find_many_by_comment_ids()
+        .returning(move |comment_ids| {
+            let comment_reviews = db_c
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|review| comment_ids.contains(&review.comment_id()))
+                .cloned()
+                .collect();
+
+            Ok(comment_reviews)
+        });
+
+    let db_c = db.clone();
+    let comments_db_c = comments_db.clone();
+    repository
+        .expect_find_many_pending_comments()
+        .returning(move |params| {
+            let comments = comments_db_c.lock().unwrap();
+
+            let pending_comments: Vec<Comment> = db_c
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|review| review.is_pending())
+                .filter_map(|review| {
+                    comments
+                        .iter()
+                        .find(|comment| comment.id() == review.comment_id())
+                        .cloned()
+                })
+                .collect();
+
+            let total = pending_comments.len() as u64;
+            let leap = ((params.page - 1) * params.items_per_page) as usize;
+
+            Ok(FindManyPendingCommentsResponse(
+                pending_comments
+                    .into_iter()
+                    .skip(leap)
+                    .take(params.items_per_page as usize)
+                    .collect(),
+                total,
+            ))
+        });
+
+    let db_c = db.clone();
+    repository
+        .expect_author_has_approved_review()
+        .returning(move |author_id| {
+            Ok(db_c
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|review| review.author_id() == author_id && review.approved() == Some(true)))
+        });
+
+    let db_c = db.clone();
+    repository
+        .expect_find_many_authors_trust()
+        .returning(move |author_ids| {
+            let reviews = db_c.lock().unwrap();
+
+            Ok(users_db
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|user| author_ids.contains(&user.id()))
+                .map(|user| AuthorTrust {
+                    author_id: user.id(),
+                    role: user.role().unwrap_or(Role::User),
+                    has_approved_review: reviews.iter().any(|review| {
+                        review.author_id() == user.id() && review.approved() == Some(true)
+                    }),
+                })
+                .collect())
+        });
+
+    let db_c = db.clone();
+    repository.expect_save().returning(move |comment_review| {
+        let mut db = db_c.lock().unwrap();
+
+        match db
+            .iter()
+            .position(|item| item.comment_id() == comment_review.comment_id())
+        {
+            Some(index) => {
+                db[index] = comment_review.clone();
+                Ok(comment_review)
+            }
+            None => Err(Box::new(DomainError::resource_not_found_err())),
+        }
+    });
+
+    let db_c = db.clone();
+    repository
+        .expect_save_many_publishing_approved()
+        .returning(move |comment_reviews| {
+            let mut db = db_c.lock().unwrap();
+            let mut comments = comments_db.lock().unwrap();
+
+            for comment_review in comment_reviews.iter() {
+                let Some(index) = db
+                    .iter()
+                    .position(|item| item.comment_id() == comment_review.comment_id())
+                else {
+                    return Err(Box::new(DomainError::resource_not_found_err()));
+                };
+
+                db[index] = comment_review.clone();
+
+                if comment_review.approved() == Some(true) {
+                    if let Some(comment) = comments
+                        .iter_mut()
+                        .find(|comment| comment.id() == comment_review.comment_id())
+                    {
+                        comment.set_is_active(true);
+                    }
+                }
+            }
+
+            Ok(comment_reviews)
+        });
+
+    (db, repository)
+}
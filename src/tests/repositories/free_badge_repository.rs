@@ -1,6 +1,6 @@
 use crate::domain::domain_entities::free_badge::FreeBadge;
 use crate::domain::repositories::free_badge_repository::{
-    FindManyFreeBadgesResponse, MockFreeBadgeRepositoryTrait,
+    FindManyFreeBadgesResponse, FreeBadgeQuery, MockFreeBadgeRepositoryTrait,
 };
 use std::sync::{Arc, Mutex};
 
@@ -50,7 +50,18 @@ pub fn get_free_badge_repository() -> (Arc<Mutex<Vec<FreeBadge>>>, MockFreeBadge
 
     let db_clone = Arc::clone(&db);
     repository.expect_find_many().returning(move |params| {
-        let badges: Vec<FreeBadge> = db_clone.lock().unwrap().clone();
+        let badges: Vec<FreeBadge> = db_clone
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|badge| match params.query {
+                Some(FreeBadgeQuery::AvailableAt(moment)) => badge
+                    .available_until()
+                    .is_none_or(|available_until| available_until > moment),
+                None => true,
+            })
+            .cloned()
+            .collect();
 
         let total_of_items_before_paginating = badges.len();
 
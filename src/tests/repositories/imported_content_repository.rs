@@ -0,0 +1,34 @@
+use crate::domain::domain_entities::imported_content::ImportedContent;
+use crate::domain::repositories::imported_content_repository::MockImportedContentRepositoryTrait;
+use std::sync::{Arc, Mutex};
+
+pub fn get_imported_content_repository() -> (
+    Arc<Mutex<Vec<ImportedContent>>>,
+    MockImportedContentRepositoryTrait,
+) {
+    let db: Arc<Mutex<Vec<ImportedContent>>> = Arc::new(Mutex::new(vec![]));
+    let mut repository = MockImportedContentRepositoryTrait::new();
+
+    let db_c = db.clone();
+    repository
+        .expect_create()
+        .returning(move |imported_content| {
+            db_c.lock().unwrap().push(imported_content.clone());
+            Ok(imported_content)
+        });
+
+    let db_c = db.clone();
+    repository
+        .expect_find_many_by_source()
+        .returning(move |source| {
+            Ok(db_c
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|imported_content| imported_content.source() == source)
+                .cloned()
+                .collect())
+        });
+
+    (db, repository)
+}
@@ -0,0 +1,109 @@
+use crate::domain::domain_entities::media::{Media, MediaUsage};
+use crate::domain::repositories::media_repository::{
+    FindManyMediaResponse, MediaQueryType, MediaSortField, MockMediaRepositoryTrait,
+};
+use crate::tests::query_spec::apply_query_spec;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+pub type MediaUsagesStore = Arc<Mutex<HashMap<String, Vec<MediaUsage>>>>;
+
+/// Besides the media, returns the usages `find_usages` answers with, keyed by URL.
+pub fn get_media_repository() -> (
+    Arc<Mutex<Vec<Media>>>,
+    MediaUsagesStore,
+    MockMediaRepositoryTrait,
+) {
+    let db: Arc<Mutex<Vec<Media>>> = Arc::new(Mutex::new(vec![]));
+    let usages_db: MediaUsagesStore = Arc::new(Mutex::new(HashMap::new()));
+
+    let mut repository = MockMediaRepositoryTrait::new();
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_create().returning(move |media| {
+        db_clone.lock().unwrap().push(media.clone());
+        Ok(media)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_save().returning(move |media| {
+        for item in db_clone.lock().unwrap().iter_mut() {
+            if item.id() == media.id() {
+                *item = media.clone();
+            }
+        }
+
+        Ok(media)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_delete().returning(move |media| {
+        db_clone
+            .lock()
+            .unwrap()
+            .retain(|item| item.id() != media.id());
+        Ok(())
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_by_id().returning(move |id| {
+        Ok(db_clone
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|media| media.id() == id)
+            .cloned())
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_many().returning(move |params| {
+        let mut media: Vec<Media> = db_clone.lock().unwrap().clone();
+
+        // the default order, which the sorts of the query take precedence over
+        media.sort_by_key(|media| std::cmp::Reverse(media.created_at()));
+
+        let media = apply_query_spec(
+            media,
+            params.query.as_ref(),
+            |media, query| match query {
+                MediaQueryType::Text(text) => {
+                    let text = text.to_lowercase();
+                    media.file_name().to_lowercase().contains(&text)
+                        || media.alt_text().to_lowercase().contains(&text)
+                }
+                MediaQueryType::Tag(tag) => media.tags().contains(&tag.trim().to_lowercase()),
+                MediaQueryType::Uploader(uploader_id) => media.uploader_id() == *uploader_id,
+                MediaQueryType::CreatedAt(range) => range.contains(&media.created_at()),
+            },
+            |a, b, field| match field {
+                MediaSortField::CreatedAt => a.created_at().cmp(&b.created_at()),
+                MediaSortField::FileName => a.file_name().cmp(b.file_name()),
+                MediaSortField::Size => a.size().cmp(&b.size()),
+            },
+        );
+
+        let total = media.len() as u64;
+        let leap = ((params.page - 1) * params.items_per_page) as usize;
+
+        Ok(FindManyMediaResponse(
+            media
+                .into_iter()
+                .skip(leap)
+                .take(params.items_per_page as usize)
+                .collect(),
+            total,
+        ))
+    });
+
+    let usages_db_clone = Arc::clone(&usages_db);
+    repository.expect_find_usages().returning(move |url| {
+        Ok(usages_db_clone
+            .lock()
+            .unwrap()
+            .get(url)
+            .cloned()
+            .unwrap_or_default())
+    });
+
+    (db, usages_db, repository)
+}
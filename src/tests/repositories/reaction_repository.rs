@@ -0,0 +1,113 @@
+use crate::domain::domain_entities::reaction::Reaction;
+use crate::domain::repositories::reaction_repository::MockReactionRepositoryTrait;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+
+pub fn get_reaction_repository() -> (Arc<Mutex<Vec<Reaction>>>, MockReactionRepositoryTrait) {
+    let db: Arc<Mutex<Vec<Reaction>>> = Arc::new(Mutex::new(vec![]));
+    let mut repository = MockReactionRepositoryTrait::new();
+
+    let db_clone = Arc::clone(&db);
+    repository
+        .expect_find()
+        .returning(move |user_id, target, target_id| {
+            Ok(db_clone
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|reaction| {
+                    reaction.user_id() == user_id
+                        && reaction.target() == target
+                        && reaction.target_id() == target_id
+                })
+                .cloned())
+        });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_set().returning(move |new_reaction| {
+        let mut db = db_clone.lock().unwrap();
+
+        db.retain(|reaction| {
+            reaction.user_id() != new_reaction.user_id()
+                || reaction.target() != new_reaction.target()
+                || reaction.target_id() != new_reaction.target_id()
+        });
+        db.push(new_reaction);
+
+        Ok(())
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository
+        .expect_remove()
+        .returning(move |user_id, target, target_id| {
+            db_clone.lock().unwrap().retain(|reaction| {
+                reaction.user_id() != user_id
+                    || reaction.target() != target
+                    || reaction.target_id() != target_id
+            });
+
+            Ok(())
+        });
+
+    let db_clone = Arc::clone(&db);
+    repository
+        .expect_find_counts()
+        .returning(move |target, target_ids| {
+            let mut counts: HashMap<_, BTreeMap<String, u64>> = HashMap::new();
+
+            for reaction in db_clone.lock().unwrap().iter().filter(|reaction| {
+                reaction.target() == target && target_ids.contains(&reaction.target_id())
+            }) {
+                *counts
+                    .entry(reaction.target_id())
+                    .or_default()
+                    .entry(reaction.kind().to_owned())
+                    .or_default() += 1;
+            }
+
+            Ok(counts)
+        });
+
+    let db_clone = Arc::clone(&db);
+    repository
+        .expect_find_many_by_user_id()
+        .returning(move |user_id, target, target_ids| {
+            Ok(db_clone
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|reaction| {
+                    reaction.user_id() == user_id
+                        && reaction.target() == target
+                        && target_ids.contains(&reaction.target_id())
+                })
+                .cloned()
+                .collect())
+        });
+
+    let db_clone = Arc::clone(&db);
+    repository
+        .expect_count_given_by_user()
+        .returning(move |user_id| {
+            let mut counts: BTreeMap<String, u64> = BTreeMap::new();
+
+            for reaction in db_clone
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|reaction| reaction.user_id() == user_id)
+            {
+                *counts.entry(reaction.kind().to_owned()).or_default() += 1;
+            }
+
+            Ok(counts)
+        });
+
+    // the mocked repository doesn't know who authored the targets
+    repository
+        .expect_count_received_by_author()
+        .returning(|_| Ok(BTreeMap::new()));
+
+    (db, repository)
+}
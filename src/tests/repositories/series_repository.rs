@@ -0,0 +1,133 @@
+use crate::domain::domain_entities::article::Article;
+use crate::domain::domain_entities::series::Series;
+use crate::domain::repositories::series_repository::MockSeriesRepositoryTrait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+/// Maps every series' id to the ids of its articles, in order.
+pub type SeriesArticlesDb = Arc<Mutex<HashMap<Uuid, Vec<Uuid>>>>;
+
+pub fn get_series_repository(
+    articles_db: Arc<Mutex<Vec<Article>>>,
+) -> (
+    Arc<Mutex<Vec<Series>>>,
+    SeriesArticlesDb,
+    MockSeriesRepositoryTrait,
+) {
+    let db: Arc<Mutex<Vec<Series>>> = Arc::new(Mutex::new(vec![]));
+    let series_articles_db: SeriesArticlesDb = Arc::new(Mutex::new(HashMap::new()));
+    let mut repository = MockSeriesRepositoryTrait::new();
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_create().returning(move |series: Series| {
+        db_clone.lock().unwrap().push(series.clone());
+        Ok(series)
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_save().returning(move |series: Series| {
+        let mut db = db_clone.lock().unwrap();
+
+        for item in db.iter_mut() {
+            if item.id() == series.id() {
+                *item = series.clone();
+            }
+        }
+
+        Ok(series)
+    });
+
+    let db_clone = Arc::clone(&db);
+    let series_articles_db_clone = Arc::clone(&series_articles_db);
+    repository.expect_delete().returning(move |series: Series| {
+        db_clone
+            .lock()
+            .unwrap()
+            .retain(|item| item.id() != series.id());
+
+        series_articles_db_clone
+            .lock()
+            .unwrap()
+            .remove(&series.id());
+
+        Ok(())
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_by_id().returning(move |id| {
+        Ok(db_clone
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|series| series.id() == id)
+            .cloned())
+    });
+
+    let db_clone = Arc::clone(&db);
+    repository.expect_find_by_slug().returning(move |slug| {
+        Ok(db_clone
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|series| series.slug().eq(slug))
+            .cloned())
+    });
+
+    let db_clone = Arc::clone(&db);
+    let series_articles_db_clone = Arc::clone(&series_articles_db);
+    repository
+        .expect_find_by_article_id()
+        .returning(move |article_id| {
+            let series_id = series_articles_db_clone
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|(_, articles_ids)| articles_ids.contains(&article_id))
+                .map(|(series_id, _)| *series_id);
+
+            Ok(series_id.and_then(|series_id| {
+                db_clone
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .find(|series| series.id() == series_id)
+                    .cloned()
+            }))
+        });
+
+    let series_articles_db_clone = Arc::clone(&series_articles_db);
+    repository
+        .expect_find_articles()
+        .returning(move |series_id, only_approved| {
+            let articles_ids = series_articles_db_clone
+                .lock()
+                .unwrap()
+                .get(&series_id)
+                .cloned()
+                .unwrap_or_default();
+
+            let articles = articles_db.lock().unwrap();
+
+            Ok(articles_ids
+                .iter()
+                .filter_map(|id| articles.iter().find(|article| article.id() == *id))
+                .filter(|article| !only_approved || article.approved())
+                .cloned()
+                .collect())
+        });
+
+    let series_articles_db_clone = Arc::clone(&series_articles_db);
+    repository
+        .expect_set_articles()
+        .returning(move |series_id, articles_ids| {
+            series_articles_db_clone
+                .lock()
+                .unwrap()
+                .insert(series_id, articles_ids);
+
+            Ok(())
+        });
+
+    (db, series_articles_db, repository)
+}
@@ -1,8 +1,10 @@
 use crate::core::pagination::PaginationParameters;
 use crate::domain::domain_entities::user::User;
 use crate::domain::repositories::user_repository::{
-    FindManyUsersResponse, MockUserRepositoryTrait, UserQueryType,
+    FindManyUsersResponse, MockUserRepositoryTrait, UserQueryType, UserSortField,
 };
+use crate::tests::query_spec::apply_query_spec;
+use std::cmp::Ordering;
 use std::sync::{Arc, Mutex};
 
 pub fn get_user_repository() -> (Arc<Mutex<Vec<User>>>, MockUserRepositoryTrait) {
@@ -23,27 +25,19 @@ pub fn get_user_repository() -> (Arc<Mutex<Vec<User>>>, MockUserRepositoryTrait)
             query,
         } = params;
 
-        let db = db_c.lock().unwrap();
-        let users = match query {
-            None => db.clone(),
-            Some(query) => match query {
-                UserQueryType::Nickname(nickname) => db
-                    .iter()
-                    .filter(|user| {
-                        user.nickname()
-                            .to_lowercase()
-                            .contains(&nickname.to_lowercase())
-                    })
-                    .cloned()
-                    .collect::<Vec<User>>(),
-
-                UserQueryType::Role(role) => db
-                    .iter()
-                    .filter(|user| user.role().unwrap().eq(&role))
-                    .cloned()
-                    .collect::<Vec<User>>(),
+        let users = apply_query_spec(
+            db_c.lock().unwrap().clone(),
+            query.as_ref(),
+            |user, query| match query {
+                UserQueryType::Nickname(nickname) => user
+                    .nickname()
+                    .to_lowercase()
+                    .contains(&nickname.to_lowercase()),
+                UserQueryType::Role(role) => user.role().unwrap().eq(role),
+                UserQueryType::CreatedAt(range) => range.contains(&user.created_at()),
             },
-        };
+            compare_users,
+        );
 
         let total_before_paginating: usize = users.len();
         let leap = (page - 1) * items_per_page;
@@ -105,5 +99,23 @@ pub fn get_user_repository() -> (Arc<Mutex<Vec<User>>>, MockUserRepositoryTrait)
         Ok(p_user)
     });
 
+    // the content of the users is kept elsewhere, so only the users themselves go away
+    let db_c = db.clone();
+    repository
+        .expect_delete_transferring_content()
+        .returning(move |p_user, _| {
+            db_c.lock().unwrap().retain(|user| user.id() != p_user.id());
+
+            Ok(())
+        });
+
     (db, repository)
 }
+
+fn compare_users(a: &User, b: &User, field: &UserSortField) -> Ordering {
+    match field {
+        UserSortField::CreatedAt => a.created_at().cmp(&b.created_at()),
+        UserSortField::Nickname => a.nickname().cmp(b.nickname()),
+        UserSortField::LastLogin => a.last_login().cmp(&b.last_login()),
+    }
+}
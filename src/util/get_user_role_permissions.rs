@@ -12,6 +12,12 @@ pub enum RolePermissions {
     SeeUnapprovedArticle,
     ChangeArticleAuthor,
     DeleteArticle,
+    PinArticle,
+    ChangeArticleCommentMode,
+
+    CreateSeries,
+    UpdateSeries,
+    DeleteSeries,
 
     InactivateComment,
     DeleteComment,
@@ -19,6 +25,10 @@ pub enum RolePermissions {
     SolveReport,
     DeleteReport,
 
+    CreateBlockedWord,
+    UpdateBlockedWord,
+    DeleteBlockedWord,
+
     CreateNewTeamRole,
     UpdateTeamRole,
     DeleteTeamRole,
@@ -38,6 +48,10 @@ pub enum RolePermissions {
     CreateAnnouncement,
     UpdateAnnouncement,
     DeleteAnnouncement,
+
+    UploadMedia,
+    UpdateMedia,
+    DeleteMedia,
 }
 
 impl RolePermissions {
@@ -51,15 +65,37 @@ impl RolePermissions {
             CreateFreeBadge,
             UpdateFreeBadge,
             DeleteFreeBadge,
+            //
+            UploadMedia,
         ];
         let perms_editor = [
             &perms_writer[..],
-            &[UpdateArticle, ApproveArticle, SeeUnapprovedArticle],
+            &[
+                UpdateArticle,
+                ApproveArticle,
+                SeeUnapprovedArticle,
+                PinArticle,
+                ChangeArticleCommentMode,
+                //
+                CreateSeries,
+                UpdateSeries,
+                //
+                UpdateMedia,
+                DeleteMedia,
+            ],
         ]
         .concat();
         let perms_coord = [
             &perms_editor[..],
-            &[DisapproveArticle, InactivateComment, SolveReport],
+            &[
+                DisapproveArticle,
+                InactivateComment,
+                SolveReport,
+                //
+                CreateBlockedWord,
+                UpdateBlockedWord,
+                DeleteBlockedWord,
+            ],
         ]
         .concat();
         let perms_admin = [
@@ -81,6 +117,7 @@ impl RolePermissions {
                 ChangeUserPassword,
                 //
                 DeleteArticle,
+                DeleteSeries,
                 DeleteReport,
                 //
                 CreateNewTeamRole,
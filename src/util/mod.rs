@@ -1,9 +1,11 @@
 mod get_user_role_permissions;
 mod service_internal_error_factory;
+mod site_url;
 mod verify_user_role_has_permission;
 mod verify_user_role_hierarchy;
 
 pub use get_user_role_permissions::RolePermissions;
 pub use service_internal_error_factory::generate_service_internal_error;
+pub use site_url::SiteUrl;
 pub use verify_user_role_has_permission::exec as verify_role_has_permission;
 pub use verify_user_role_hierarchy::exec as verify_role_hierarchy_matches;
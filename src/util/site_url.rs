@@ -0,0 +1,54 @@
+use crate::ENV_VARS;
+
+/// Builds absolute URLs to the fan-site's pages, for the cases in which relative ones won't do
+/// (e.g. feeds and sitemaps).
+pub struct SiteUrl;
+
+impl SiteUrl {
+    /// The site's root URL, without a trailing slash. `https` is assumed unless `DOMAIN` already has a scheme.
+    pub fn base() -> String {
+        let domain = ENV_VARS.domain.trim_end_matches('/');
+
+        if domain.starts_with("http://") || domain.starts_with("https://") {
+            return domain.to_owned();
+        }
+
+        format!("https://{domain}")
+    }
+
+    pub fn absolute(path: &str) -> String {
+        format!("{}/{}", Self::base(), path.trim_start_matches('/'))
+    }
+
+    pub fn article(slug: &str) -> String {
+        Self::absolute(&format!("articles/{slug}"))
+    }
+
+    pub fn tag(tag_id: i32) -> String {
+        Self::absolute(&format!("articles?tag={tag_id}"))
+    }
+
+    pub fn free_badge(free_badge_id: &str) -> String {
+        Self::absolute(&format!("free-badges/{free_badge_id}"))
+    }
+
+    pub fn user(nickname: &str) -> String {
+        Self::absolute(&format!("users/{}", Self::encode_path_segment(nickname)))
+    }
+
+    /// Percent-encodes everything but unreserved characters, as nicknames may contain `?`, `;`, `@` and the like.
+    pub fn encode_path_segment(segment: &str) -> String {
+        let mut encoded = String::with_capacity(segment.len());
+
+        for byte in segment.bytes() {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                    encoded.push(byte as char)
+                }
+                _ => encoded.push_str(&format!("%{byte:02X}")),
+            }
+        }
+
+        encoded
+    }
+}